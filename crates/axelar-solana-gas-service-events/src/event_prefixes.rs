@@ -13,3 +13,14 @@ pub const SPL_PAID_FOR_CONTRACT_CALL: &[u8] = b"spl token paid for contract call
 pub const SPL_GAS_ADDED: &[u8] = b"spl token gas added";
 /// Prefix emitted when SPL token gas is refunded.
 pub const SPL_GAS_REFUNDED: &[u8] = b"spl token refunded";
+
+/// Prefix emitted when a transfer of the operatorship of a config is proposed.
+pub const OPERATORSHIP_PROPOSED: &[u8] = b"operatorship proposed";
+/// Prefix emitted when the operatorship of a config is transferred.
+pub const OPERATORSHIP_TRANSFERRED: &[u8] = b"operatorship transferred";
+/// Prefix emitted when roles are added to a user of a config.
+pub const ROLES_ADDED: &[u8] = b"roles added";
+/// Prefix emitted when roles are removed from a user of a config.
+pub const ROLES_REMOVED: &[u8] = b"roles removed";
+/// Prefix emitted when the fees accrued in a token are swept to its treasury.
+pub const FEES_SWEPT: &[u8] = b"fees swept";
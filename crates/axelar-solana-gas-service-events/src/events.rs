@@ -1,8 +1,9 @@
 //! Events emitted by the Axelar Solana Gas service
 
+use axelar_message_primitives::MessageId;
 use solana_program::pubkey::Pubkey;
 
-use event_utils::{read_array, read_string, read_u64, EventParseError};
+use event_utils::{read_array, read_i64, read_string, read_u64, read_u8, EventParseError};
 
 /// Even emitted by the Axelar Solana Gas service
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
@@ -19,6 +20,16 @@ pub enum GasServiceEvent {
     SplGasAdded(SplGasAddedEvent),
     /// Event when an SPL token was refunded
     SplGasRefunded(SplGasRefundedEvent),
+    /// Event when a transfer of the operatorship of a config was proposed
+    OperatorshipProposed(OperatorshipProposedEvent),
+    /// Event when the operatorship of a config was transferred
+    OperatorshipTransferred(OperatorshipTransferredEvent),
+    /// Event when roles were added to a user of a config
+    RolesAdded(RolesChangedEvent),
+    /// Event when roles were removed from a user of a config
+    RolesRemoved(RolesChangedEvent),
+    /// Event when the fees accrued in a token were swept to its treasury
+    FeesSwept(FeesSweptEvent),
 }
 
 /// Represents the event emitted when native gas is paid for a contract call.
@@ -96,10 +107,8 @@ impl NativeGasPaidForContractCallEvent {
 pub struct NativeGasAddedEvent {
     /// The Gas service config PDA
     pub config_pda: Pubkey,
-    /// Solana transaction signature
-    pub tx_hash: [u8; 64],
-    /// index of the log
-    pub log_index: u64,
+    /// The Axelar message ID of the contract call
+    pub message_id: MessageId,
     /// The refund address
     pub refund_address: Pubkey,
     /// amount of SOL
@@ -117,13 +126,10 @@ impl NativeGasAddedEvent {
             .ok_or(EventParseError::MissingData("config_pda"))?;
         let config_pda = Pubkey::new_from_array(read_array::<32>("config_pda", &config_pda_data)?);
 
-        let tx_hash_data = data.next().ok_or(EventParseError::MissingData("tx_hash"))?;
-        let tx_hash = read_array::<64>("tx_hash", &tx_hash_data)?;
-
-        let log_index_data = data
+        let message_id_data = data
             .next()
-            .ok_or(EventParseError::MissingData("log_index"))?;
-        let log_index = read_u64("log_index", &log_index_data)?;
+            .ok_or(EventParseError::MissingData("message_id"))?;
+        let message_id = read_message_id("message_id", message_id_data)?;
 
         let refund_address_data = data
             .next()
@@ -138,8 +144,7 @@ impl NativeGasAddedEvent {
 
         Ok(Self {
             config_pda,
-            tx_hash,
-            log_index,
+            message_id,
             refund_address,
             gas_fee_amount,
         })
@@ -149,12 +154,10 @@ impl NativeGasAddedEvent {
 /// Represents the event emitted when native gas is refunded.
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct NativeGasRefundedEvent {
-    /// Solana transaction signature
-    pub tx_hash: [u8; 64],
+    /// The Axelar message ID of the contract call
+    pub message_id: MessageId,
     /// The Gas service config PDA
     pub config_pda: Pubkey,
-    /// The log index
-    pub log_index: u64,
     /// The receiver of the refund
     pub receiver: Pubkey,
     /// amount of SOL
@@ -167,19 +170,16 @@ impl NativeGasRefundedEvent {
     /// # Errors
     /// - if the data could not be parsed into an event
     pub fn new<I: Iterator<Item = Vec<u8>>>(mut data: I) -> Result<Self, EventParseError> {
-        let tx_hash_data = data.next().ok_or(EventParseError::MissingData("tx_hash"))?;
-        let tx_hash = read_array::<64>("tx_hash", &tx_hash_data)?;
+        let message_id_data = data
+            .next()
+            .ok_or(EventParseError::MissingData("message_id"))?;
+        let message_id = read_message_id("message_id", message_id_data)?;
 
         let config_pda_data = data
             .next()
             .ok_or(EventParseError::MissingData("config_pda"))?;
         let config_pda = Pubkey::new_from_array(read_array::<32>("config_pda", &config_pda_data)?);
 
-        let log_index_data = data
-            .next()
-            .ok_or(EventParseError::MissingData("log_index"))?;
-        let log_index = read_u64("log_index", &log_index_data)?;
-
         let receiver_data = data
             .next()
             .ok_or(EventParseError::MissingData("receiver"))?;
@@ -189,9 +189,8 @@ impl NativeGasRefundedEvent {
         let fees = read_u64("fees", &fees_data)?;
 
         Ok(Self {
-            tx_hash,
+            message_id,
             config_pda,
-            log_index,
             receiver,
             fees,
         })
@@ -303,10 +302,8 @@ pub struct SplGasAddedEvent {
     pub mint: Pubkey,
     /// The token program id
     pub token_program_id: Pubkey,
-    /// Solana transaction signature
-    pub tx_hash: [u8; 64],
-    /// index of the log
-    pub log_index: u64,
+    /// The Axelar message ID of the contract call
+    pub message_id: MessageId,
     /// The refund address
     pub refund_address: Pubkey,
     /// amount of SOL
@@ -339,13 +336,10 @@ impl SplGasAddedEvent {
         let token_program_id =
             Pubkey::new_from_array(read_array::<32>("token_program_id", &token_program_id)?);
 
-        let tx_hash_data = data.next().ok_or(EventParseError::MissingData("tx_hash"))?;
-        let tx_hash = read_array::<64>("tx_hash", &tx_hash_data)?;
-
-        let log_index_data = data
+        let message_id_data = data
             .next()
-            .ok_or(EventParseError::MissingData("log_index"))?;
-        let log_index = read_u64("log_index", &log_index_data)?;
+            .ok_or(EventParseError::MissingData("message_id"))?;
+        let message_id = read_message_id("message_id", message_id_data)?;
 
         let refund_address_data = data
             .next()
@@ -363,8 +357,7 @@ impl SplGasAddedEvent {
             config_pda_ata,
             mint,
             token_program_id,
-            tx_hash,
-            log_index,
+            message_id,
             refund_address,
             gas_fee_amount,
         })
@@ -380,12 +373,10 @@ pub struct SplGasRefundedEvent {
     pub mint: Pubkey,
     /// The token program id
     pub token_program_id: Pubkey,
-    /// Solana transaction signature
-    pub tx_hash: [u8; 64],
+    /// The Axelar message ID of the contract call
+    pub message_id: MessageId,
     /// The Gas service config PDA
     pub config_pda: Pubkey,
-    /// The log index
-    pub log_index: u64,
     /// The receiver of the refund
     pub receiver: Pubkey,
     /// amount of SOL
@@ -398,8 +389,10 @@ impl SplGasRefundedEvent {
     /// # Errors
     /// - if the data could not be parsed into an event
     pub fn new<I: Iterator<Item = Vec<u8>>>(mut data: I) -> Result<Self, EventParseError> {
-        let tx_hash_data = data.next().ok_or(EventParseError::MissingData("tx_hash"))?;
-        let tx_hash = read_array::<64>("tx_hash", &tx_hash_data)?;
+        let message_id_data = data
+            .next()
+            .ok_or(EventParseError::MissingData("message_id"))?;
+        let message_id = read_message_id("message_id", message_id_data)?;
 
         let config_pda_data = data
             .next()
@@ -421,11 +414,6 @@ impl SplGasRefundedEvent {
         let token_program_id =
             Pubkey::new_from_array(read_array::<32>("token_program_id", &token_program_id)?);
 
-        let log_index_data = data
-            .next()
-            .ok_or(EventParseError::MissingData("log_index"))?;
-        let log_index = read_u64("log_index", &log_index_data)?;
-
         let receiver_data = data
             .next()
             .ok_or(EventParseError::MissingData("receiver"))?;
@@ -438,11 +426,196 @@ impl SplGasRefundedEvent {
             config_pda_ata,
             mint,
             token_program_id,
-            tx_hash,
+            message_id,
             config_pda,
-            log_index,
             receiver,
             fees,
         })
     }
 }
+
+/// Represents the event emitted when a transfer of the operatorship of a config is proposed.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct OperatorshipProposedEvent {
+    /// The Gas service config PDA
+    pub config_pda: Pubkey,
+    /// The current operator
+    pub operator: Pubkey,
+    /// The proposed operator
+    pub proposed_operator: Pubkey,
+    /// Unix timestamp after which the proposal can no longer be accepted
+    pub expires_at: Option<i64>,
+}
+
+impl OperatorshipProposedEvent {
+    /// Construct a new event from byte slices
+    ///
+    /// # Errors
+    /// - if the data could not be parsed into an event
+    pub fn new<I: Iterator<Item = Vec<u8>>>(mut data: I) -> Result<Self, EventParseError> {
+        let config_pda_data = data
+            .next()
+            .ok_or(EventParseError::MissingData("config_pda"))?;
+        let config_pda = Pubkey::new_from_array(read_array::<32>("config_pda", &config_pda_data)?);
+
+        let operator_data = data
+            .next()
+            .ok_or(EventParseError::MissingData("operator"))?;
+        let operator = Pubkey::new_from_array(read_array::<32>("operator", &operator_data)?);
+
+        let proposed_operator_data = data
+            .next()
+            .ok_or(EventParseError::MissingData("proposed_operator"))?;
+        let proposed_operator = Pubkey::new_from_array(read_array::<32>(
+            "proposed_operator",
+            &proposed_operator_data,
+        )?);
+
+        let expires_at_data = data
+            .next()
+            .ok_or(EventParseError::MissingData("expires_at"))?;
+        let expires_at = if expires_at_data.is_empty() {
+            None
+        } else {
+            Some(read_i64("expires_at", &expires_at_data)?)
+        };
+
+        Ok(Self {
+            config_pda,
+            operator,
+            proposed_operator,
+            expires_at,
+        })
+    }
+}
+
+/// Represents the event emitted when the operatorship of a config is transferred.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct OperatorshipTransferredEvent {
+    /// The Gas service config PDA
+    pub config_pda: Pubkey,
+    /// The previous operator
+    pub previous_operator: Pubkey,
+    /// The new operator
+    pub operator: Pubkey,
+}
+
+impl OperatorshipTransferredEvent {
+    /// Construct a new event from byte slices
+    ///
+    /// # Errors
+    /// - if the data could not be parsed into an event
+    pub fn new<I: Iterator<Item = Vec<u8>>>(mut data: I) -> Result<Self, EventParseError> {
+        let config_pda_data = data
+            .next()
+            .ok_or(EventParseError::MissingData("config_pda"))?;
+        let config_pda = Pubkey::new_from_array(read_array::<32>("config_pda", &config_pda_data)?);
+
+        let previous_operator_data = data
+            .next()
+            .ok_or(EventParseError::MissingData("previous_operator"))?;
+        let previous_operator = Pubkey::new_from_array(read_array::<32>(
+            "previous_operator",
+            &previous_operator_data,
+        )?);
+
+        let operator_data = data
+            .next()
+            .ok_or(EventParseError::MissingData("operator"))?;
+        let operator = Pubkey::new_from_array(read_array::<32>("operator", &operator_data)?);
+
+        Ok(Self {
+            config_pda,
+            previous_operator,
+            operator,
+        })
+    }
+}
+
+/// Represents the event emitted when roles are added to or removed from a user of a config.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct RolesChangedEvent {
+    /// The Gas service config PDA
+    pub config_pda: Pubkey,
+    /// The user whose roles changed
+    pub user: Pubkey,
+    /// The bits of the roles that were added or removed
+    pub roles: u8,
+}
+
+impl RolesChangedEvent {
+    /// Construct a new event from byte slices
+    ///
+    /// # Errors
+    /// - if the data could not be parsed into an event
+    pub fn new<I: Iterator<Item = Vec<u8>>>(mut data: I) -> Result<Self, EventParseError> {
+        let config_pda_data = data
+            .next()
+            .ok_or(EventParseError::MissingData("config_pda"))?;
+        let config_pda = Pubkey::new_from_array(read_array::<32>("config_pda", &config_pda_data)?);
+
+        let user_data = data.next().ok_or(EventParseError::MissingData("user"))?;
+        let user = Pubkey::new_from_array(read_array::<32>("user", &user_data)?);
+
+        let roles_data = data.next().ok_or(EventParseError::MissingData("roles"))?;
+        let roles = read_u8("roles", &roles_data)?;
+
+        Ok(Self {
+            config_pda,
+            user,
+            roles,
+        })
+    }
+}
+
+/// Represents the event emitted when the fees accrued in a token are swept to its treasury.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct FeesSweptEvent {
+    /// The Gas service config PDA
+    pub config_pda: Pubkey,
+    /// The swept token, the system program for native SOL
+    pub token: Pubkey,
+    /// The account receiving the swept fees
+    pub destination: Pubkey,
+    /// The total amount swept
+    pub amount: u64,
+}
+
+impl FeesSweptEvent {
+    /// Construct a new event from byte slices
+    ///
+    /// # Errors
+    /// - if the data could not be parsed into an event
+    pub fn new<I: Iterator<Item = Vec<u8>>>(mut data: I) -> Result<Self, EventParseError> {
+        let config_pda_data = data
+            .next()
+            .ok_or(EventParseError::MissingData("config_pda"))?;
+        let config_pda = Pubkey::new_from_array(read_array::<32>("config_pda", &config_pda_data)?);
+
+        let token_data = data.next().ok_or(EventParseError::MissingData("token"))?;
+        let token = Pubkey::new_from_array(read_array::<32>("token", &token_data)?);
+
+        let destination_data = data
+            .next()
+            .ok_or(EventParseError::MissingData("destination"))?;
+        let destination =
+            Pubkey::new_from_array(read_array::<32>("destination", &destination_data)?);
+
+        let amount_data = data.next().ok_or(EventParseError::MissingData("amount"))?;
+        let amount = read_u64("amount", &amount_data)?;
+
+        Ok(Self {
+            config_pda,
+            token,
+            destination,
+            amount,
+        })
+    }
+}
+
+/// Reads a message ID in the Axelar format, see [`MessageId`].
+fn read_message_id(field: &'static str, data: Vec<u8>) -> Result<MessageId, EventParseError> {
+    read_string(field, data)?
+        .parse()
+        .map_err(|_err| EventParseError::InvalidData(field))
+}
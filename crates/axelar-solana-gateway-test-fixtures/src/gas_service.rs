@@ -1,13 +1,20 @@
 //! Utilities for working with the Axelar gas service
 
 use crate::base::TestFixture;
+use axelar_solana_gas_service::state::{GasPayment, GasReserve};
 use axelar_solana_gas_service_events::events::GasServiceEvent;
 use axelar_solana_gateway::BytemuckedPda;
 use gateway_event_stack::{MatchContext, ProgramInvocationState};
 use solana_program_test::{tokio, BanksTransactionResultWithMetadata};
 use solana_sdk::{
-    account::ReadableAccount, keccak, program_pack::Pack, pubkey::Pubkey, signature::Keypair,
-    signer::Signer, system_instruction,
+    account::{Account, ReadableAccount},
+    clock::Clock,
+    keccak,
+    program_pack::Pack,
+    pubkey::Pubkey,
+    signature::Keypair,
+    signer::Signer,
+    system_instruction,
 };
 use spl_associated_token_account::get_associated_token_address_with_program_id;
 use spl_token_2022::{extension::ExtensionType, state::Mint};
@@ -92,6 +99,31 @@ impl TestFixture {
             .await
     }
 
+    /// accept `mint` to pay for gas to the config of the gas service
+    pub async fn allow_gas_token(
+        &mut self,
+        utils: &GasServiceUtils,
+        mint: &Pubkey,
+        min_payment: u64,
+        lamports_to_token_rate: u64,
+    ) -> Result<BanksTransactionResultWithMetadata, BanksTransactionResultWithMetadata> {
+        let ix = axelar_solana_gas_service::instructions::allow_gas_token_instruction(
+            &axelar_solana_gas_service::ID,
+            &self.payer.pubkey(),
+            &utils.operator.pubkey(),
+            &utils.config_pda,
+            mint,
+            min_payment,
+            lamports_to_token_rate,
+        )
+        .unwrap();
+        self.send_tx_with_custom_signers(
+            &[ix],
+            &[self.payer.insecure_clone(), utils.operator.insecure_clone()],
+        )
+        .await
+    }
+
     /// Initialize a new token mint
     #[allow(clippy::disallowed_methods)]
     pub async fn init_new_mint(
@@ -256,6 +288,110 @@ impl TestFixture {
         let config = axelar_solana_gas_service::state::Config::read(acc.data()).unwrap();
         *config
     }
+
+    /// Set up the gas payment ledger of a payment of `paid` tokens refundable to
+    /// `refund_address`, held in the gas reserve of the token, as if it was paid
+    /// through the gas service now, and return its PDA
+    pub async fn init_gas_payment(
+        &mut self,
+        utils: &GasServiceUtils,
+        token: &Pubkey,
+        payment_key: [u8; 32],
+        refund_address: Pubkey,
+        paid: u64,
+    ) -> Pubkey {
+        let (gas_payment_pda, bump) = axelar_solana_gas_service::get_gas_payment_pda(
+            &axelar_solana_gas_service::ID,
+            &utils.config_pda,
+            token,
+            &payment_key,
+            &refund_address,
+        );
+        let now = self.get_sysvar::<Clock>().await.unix_timestamp;
+        let gas_payment = GasPayment {
+            config_pda: utils.config_pda,
+            token: *token,
+            payment_key,
+            refund_address,
+            paid,
+            added: 0,
+            refunded: 0,
+            released: 0,
+            refundable_until: now + axelar_solana_gas_service::REFUND_WINDOW,
+            message_id_hash: [0; 32],
+            bump,
+            padding: [0; 7],
+        };
+        let mut data = vec![0; core::mem::size_of::<GasPayment>()];
+        gas_payment.write(&mut data).unwrap();
+
+        let lamports = self.get_rent(data.len()).await;
+        self.set_account_state(
+            &gas_payment_pda,
+            Account {
+                lamports,
+                data,
+                owner: axelar_solana_gas_service::ID,
+                executable: false,
+                rent_epoch: 0,
+            },
+        );
+
+        let (gas_reserve_pda, bump) = axelar_solana_gas_service::get_gas_reserve_pda(
+            &axelar_solana_gas_service::ID,
+            &utils.config_pda,
+            token,
+        );
+        let refundable = self
+            .try_get_account_no_checks(&gas_reserve_pda)
+            .await
+            .unwrap()
+            .map_or(0, |acc| GasReserve::read(acc.data()).unwrap().refundable);
+        let gas_reserve = GasReserve {
+            config_pda: utils.config_pda,
+            token: *token,
+            refundable: refundable + paid,
+            bump,
+            padding: [0; 7],
+        };
+        let mut data = vec![0; core::mem::size_of::<GasReserve>()];
+        gas_reserve.write(&mut data).unwrap();
+
+        let lamports = self.get_rent(data.len()).await;
+        self.set_account_state(
+            &gas_reserve_pda,
+            Account {
+                lamports,
+                data,
+                owner: axelar_solana_gas_service::ID,
+                executable: false,
+                rent_epoch: 0,
+            },
+        );
+
+        gas_payment_pda
+    }
+
+    /// get the gas payment ledger state
+    pub async fn gas_payment_state(&mut self, gas_payment_pda: Pubkey) -> GasPayment {
+        let acc = self
+            .get_account(&gas_payment_pda, &axelar_solana_gas_service::ID)
+            .await;
+        *GasPayment::read(acc.data()).unwrap()
+    }
+
+    /// get the refundable gas held in the gas reserve of a token
+    pub async fn gas_reserve_refundable(&mut self, config_pda: &Pubkey, token: &Pubkey) -> u64 {
+        let (gas_reserve_pda, _) = axelar_solana_gas_service::get_gas_reserve_pda(
+            &axelar_solana_gas_service::ID,
+            config_pda,
+            token,
+        );
+        let acc = self
+            .get_account(&gas_reserve_pda, &axelar_solana_gas_service::ID)
+            .await;
+        GasReserve::read(acc.data()).unwrap().refundable
+    }
 }
 
 /// Get events emitted by the `GasService`
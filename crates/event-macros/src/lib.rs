@@ -34,7 +34,7 @@ fn get_u8_array_size(ty: &Type) -> Option<usize> {
     }
 }
 
-fn is_vec_u8(ty: &Type) -> bool {
+fn vec_element_is(ty: &Type, element: &str) -> bool {
     let Type::Path(type_path) = ty else {
         return false;
     };
@@ -56,8 +56,43 @@ fn is_vec_u8(ty: &Type) -> bool {
     let Type::Path(ref inner_path) = inner_ty else {
         return false;
     };
-    let segments = &inner_path.path.segments;
-    segments.len() == 1 && segments[0].ident == "u8"
+    inner_path
+        .path
+        .segments
+        .last()
+        .is_some_and(|inner_segment| inner_segment.ident == element)
+}
+
+fn is_vec_u8(ty: &Type) -> bool {
+    vec_element_is(ty, "u8")
+}
+
+fn is_vec_pubkey(ty: &Type) -> bool {
+    vec_element_is(ty, "Pubkey")
+}
+
+/// Returns the name of the integer type wrapped in an `Option`, if the type is one.
+fn get_option_integer_ident_str(ty: &Type) -> Option<String> {
+    let Type::Path(type_path) = ty else {
+        return None;
+    };
+    let segment = type_path.path.segments.last()?;
+    if segment.ident != "Option" {
+        return None;
+    }
+    let PathArguments::AngleBracketed(ref args) = segment.arguments else {
+        return None;
+    };
+    let Some(GenericArgument::Type(inner_ty)) = args.args.first() else {
+        return None;
+    };
+
+    get_simple_type_ident_str(inner_ty).filter(|name| {
+        matches!(
+            name.as_str(),
+            "u8" | "u16" | "u32" | "u64" | "u128" | "i8" | "i16" | "i32" | "i64" | "i128"
+        )
+    })
 }
 
 fn get_simple_type_ident_str(ty: &Type) -> Option<String> {
@@ -98,6 +133,7 @@ pub fn derive_event(input: TokenStream) -> TokenStream {
     let discriminant_tokens = quote! { &[ #(#discriminant),* ] };
 
     let mut emit_slices = Vec::new();
+    let mut emit_buffers = Vec::new();
     emit_slices.push(quote! { Self::DISC });
 
     for field in fields {
@@ -106,12 +142,34 @@ pub fn derive_event(input: TokenStream) -> TokenStream {
 
         let slice_expr = if get_u8_array_size(ty).is_some() || is_vec_u8(ty) {
             quote! { &self.#field_ident[..] }
+        } else if is_vec_pubkey(ty) {
+            // The keys are logged concatenated in order, as a single segment.
+            emit_buffers.push(quote! {
+                let #field_ident: ::std::vec::Vec<u8> = self
+                    .#field_ident
+                    .iter()
+                    .flat_map(|key| key.to_bytes())
+                    .collect();
+            });
+            quote! { &#field_ident[..] }
+        } else if get_option_integer_ident_str(ty).is_some() {
+            // A `0` byte when absent, a `1` byte followed by the value when present.
+            emit_buffers.push(quote! {
+                let #field_ident: ::std::vec::Vec<u8> = match self.#field_ident {
+                    Some(value) => ::core::iter::once(1_u8)
+                        .chain(value.to_le_bytes())
+                        .collect(),
+                    None => ::std::vec![0_u8],
+                };
+            });
+            quote! { &#field_ident[..] }
         } else if let Some(type_name) = get_simple_type_ident_str(ty) {
             match type_name.as_str() {
                 "Pubkey" => quote! { self.#field_ident.as_ref() },
                 "String" => quote! { self.#field_ident.as_bytes() },
+                "bool" => quote! { &[u8::from(self.#field_ident)][..] },
                 "u8" | "u16" | "u32" | "u64" | "u128" | "i8" | "i16" | "i32" | "i64" | "i128"
-                | "bool" | "f32" | "f64" | "U256" => {
+                | "f32" | "f64" | "U256" => {
                     quote! { &(self.#field_ident.to_le_bytes()[..]) }
                 }
                 _ => {
@@ -133,6 +191,7 @@ pub fn derive_event(input: TokenStream) -> TokenStream {
 
     let emit_impl = quote! {
         fn emit(&self) {
+            #(#emit_buffers)*
             ::solana_program::log::sol_log_data(&[
                 #(#emit_slices),*
             ]);
@@ -158,6 +217,13 @@ pub fn derive_event(input: TokenStream) -> TokenStream {
             quote! { ::event_utils::read_array::<#size>(#field_name_str, &segment_data)? }
         } else if is_vec_u8(ty) {
             quote! { segment_data }
+        } else if is_vec_pubkey(ty) {
+            quote! { ::event_utils::read_pubkeys(#field_name_str, &segment_data)? }
+        } else if let Some(type_name) = get_option_integer_ident_str(ty) {
+            let read_fn = quote::format_ident!("read_{}", type_name);
+            quote! {
+                ::event_utils::read_option(#field_name_str, &segment_data, ::event_utils::#read_fn)?
+            }
         } else if let Some(type_name) = get_simple_type_ident_str(ty) {
             match type_name.as_str() {
                 "String" => quote! { ::event_utils::read_string(#field_name_str, segment_data)? },
@@ -129,6 +129,42 @@ pub fn read_pubkey(field: &'static str, data: &[u8]) -> Result<Pubkey, EventPars
     Ok(Pubkey::new_from_array(bytes))
 }
 
+/// Tries to read a list of [`Pubkey`]s, concatenated in order, from the provided data slice.
+///
+/// # Errors
+///
+/// In case the size of `data` isn't a multiple of the length of a [`Pubkey`].
+pub fn read_pubkeys(field: &'static str, data: &[u8]) -> Result<Vec<Pubkey>, EventParseError> {
+    let chunks = data.chunks_exact(32);
+    if !chunks.remainder().is_empty() {
+        return Err(EventParseError::InvalidLength {
+            field,
+            expected: data.len().saturating_sub(chunks.remainder().len()),
+            actual: data.len(),
+        });
+    }
+
+    chunks.map(|chunk| read_pubkey(field, chunk)).collect()
+}
+
+/// Tries to read an [`Option`] from the provided data slice, logged as a `0` byte when absent
+/// or as a `1` byte followed by the value when present.
+///
+/// # Errors
+///
+/// In case the data doesn't start with a valid tag or the value cannot be read.
+pub fn read_option<T>(
+    field: &'static str,
+    data: &[u8],
+    read: impl FnOnce(&'static str, &[u8]) -> Result<T, EventParseError>,
+) -> Result<Option<T>, EventParseError> {
+    match data.split_first() {
+        Some((0, [])) => Ok(None),
+        Some((1, value)) => read(field, value).map(Some),
+        _ => Err(EventParseError::InvalidData(field)),
+    }
+}
+
 /// Tries to read a [`Vec<u8>`] from the provided data slice.
 ///
 /// # Errors
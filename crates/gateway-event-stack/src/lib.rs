@@ -1,5 +1,6 @@
 //! Parse Solana events from transaction data
 
+use axelar_message_primitives::MessageId;
 use axelar_solana_gas_service_events::events::GasServiceEvent;
 use axelar_solana_gateway::processor::GatewayEvent;
 use base64::{engine::general_purpose, Engine};
@@ -98,6 +99,24 @@ where
     program_stack
 }
 
+/// Derives the Axelar message ID of the event logged at `log_index`, such as a gateway
+/// `CallContract` event, from the logs of the transaction with the given `signature`.
+///
+/// The instruction indexes are reconstructed from the program invocation logs, so the logs must
+/// not be truncated.
+///
+/// # Returns
+///
+/// `None` if `log_index` is out of bounds or not logged within a program invocation.
+#[must_use]
+pub fn log_message_id<T: AsRef<str>>(
+    signature: [u8; 64],
+    logs: &[T],
+    log_index: usize,
+) -> Option<MessageId> {
+    axelar_solana_gateway::processor::call_contract_message_id(signature, logs, log_index)
+}
+
 #[inline]
 /// Decodes a Base64-encoded string into bytes.
 #[must_use]
@@ -184,8 +203,9 @@ where
 {
     use axelar_solana_gas_service_events::event_prefixes::*;
     use axelar_solana_gas_service_events::events::{
-        NativeGasAddedEvent, NativeGasPaidForContractCallEvent, NativeGasRefundedEvent,
-        SplGasAddedEvent, SplGasPaidForContractCallEvent, SplGasRefundedEvent,
+        FeesSweptEvent, NativeGasAddedEvent, NativeGasPaidForContractCallEvent,
+        NativeGasRefundedEvent, OperatorshipProposedEvent, OperatorshipTransferredEvent,
+        RolesChangedEvent, SplGasAddedEvent, SplGasPaidForContractCallEvent, SplGasRefundedEvent,
     };
     use event_utils::EventParseError;
 
@@ -224,6 +244,26 @@ where
             let event = SplGasRefundedEvent::new(logs)?;
             GasServiceEvent::SplGasRefunded(event)
         }
+        OPERATORSHIP_PROPOSED => {
+            let event = OperatorshipProposedEvent::new(logs)?;
+            GasServiceEvent::OperatorshipProposed(event)
+        }
+        OPERATORSHIP_TRANSFERRED => {
+            let event = OperatorshipTransferredEvent::new(logs)?;
+            GasServiceEvent::OperatorshipTransferred(event)
+        }
+        ROLES_ADDED => {
+            let event = RolesChangedEvent::new(logs)?;
+            GasServiceEvent::RolesAdded(event)
+        }
+        ROLES_REMOVED => {
+            let event = RolesChangedEvent::new(logs)?;
+            GasServiceEvent::RolesRemoved(event)
+        }
+        FEES_SWEPT => {
+            let event = FeesSweptEvent::new(logs)?;
+            GasServiceEvent::FeesSwept(event)
+        }
         _ => {
             return Err(EventParseError::Other("unsupported discrimintant"));
         }
@@ -456,4 +496,45 @@ mod tests {
 
         assert_eq!(result, expected);
     }
+
+    #[test]
+    fn test_log_message_id() {
+        let logs = vec![
+            "Program ComputeBudget111111111111111111111111111111 invoke [1]",
+            "Program ComputeBudget111111111111111111111111111111 success",
+            "Program gasHQkvaC4jTD2MQpAuEN3RdNwde2Ym5E5QNDoh6m6G invoke [1]",
+            "Program log: Instruction: Native",
+            "Program 11111111111111111111111111111111 invoke [2]",
+            "Program 11111111111111111111111111111111 success",
+            "Program data: bmF0aXZlIGdhcyBwYWlkIGZvciBjb250cmFjdCBjYWxs",
+            "Program gasHQkvaC4jTD2MQpAuEN3RdNwde2Ym5E5QNDoh6m6G success",
+            "Program mem7LhKWbKydCPk1TwNzeCvVSpoVx2mqxNuvjGgWAbG invoke [1]",
+            "Program log: Instruction: SendToGateway",
+            "Program 11111111111111111111111111111111 invoke [2]",
+            "Program 11111111111111111111111111111111 success",
+            "Program gtwLjHAsfKAR6GWB4hzTUAA1w4SDdFMKamtGA5ttMEe invoke [2]",
+            "Program data: Y2FsbCBjb250cmFjdF9fXw==",
+            "Program gtwLjHAsfKAR6GWB4hzTUAA1w4SDdFMKamtGA5ttMEe consumed 4799 of 386578 compute units",
+            "Program gtwLjHAsfKAR6GWB4hzTUAA1w4SDdFMKamtGA5ttMEe success",
+            "Program mem7LhKWbKydCPk1TwNzeCvVSpoVx2mqxNuvjGgWAbG success",
+        ];
+        let signature = [42; 64];
+
+        // Logged by a top-level instruction, after one of its inner instructions
+        assert_eq!(
+            log_message_id(signature, &logs, 6),
+            Some(MessageId::new(signature, 1, 0))
+        );
+        // Logged by the second inner instruction of a top-level instruction
+        assert_eq!(
+            log_message_id(signature, &logs, 13),
+            Some(MessageId::new(signature, 2, 2))
+        );
+        assert!(log_message_id(signature, &logs, 13)
+            .unwrap()
+            .to_string()
+            .ends_with("-2.2"));
+        assert_eq!(log_message_id(signature, &logs, logs.len()), None);
+        assert_eq!(log_message_id(signature, &["Program log: orphan"], 0), None);
+    }
 }
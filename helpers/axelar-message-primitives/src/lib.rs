@@ -1,9 +1,11 @@
 mod address;
 mod destination_program_id;
+mod message_id;
 mod payload;
 mod u256;
 
 pub use address::*;
 pub use destination_program_id::*;
+pub use message_id::*;
 pub use payload::*;
 pub use u256::*;
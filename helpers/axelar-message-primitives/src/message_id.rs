@@ -0,0 +1,158 @@
+//! Message ID
+use core::fmt;
+use core::str::FromStr;
+
+use thiserror::Error;
+
+/// Error variants for [MessageIdError].
+#[derive(Error, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MessageIdError {
+    /// When the message ID isn't `<signature>-<ix_index>.<inner_ix_index>`.
+    #[error("Message ID must be formatted as <signature>-<ix_index>.<inner_ix_index>")]
+    InvalidFormat,
+
+    /// When the signature isn't a base58 encoded 64-byte transaction signature.
+    #[error("Invalid transaction signature in message ID")]
+    InvalidSignature,
+
+    /// When an instruction index isn't a canonical decimal `u32`.
+    #[error("Invalid instruction index in message ID")]
+    InvalidIndex,
+}
+
+/// Identifies a message sent from Solana, in the Axelar message ID format
+/// `<signature>-<ix_index>.<inner_ix_index>`.
+///
+/// The signature is the base58 encoded signature of the transaction, `ix_index` the index of
+/// the top-level instruction in the transaction and `inner_ix_index` the 1-based position of the
+/// emitting instruction among the inner instructions of that top-level instruction, 0 when the
+/// top-level instruction emits the message itself.
+#[derive(Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct MessageId {
+    /// The transaction signature.
+    pub signature: [u8; 64],
+    /// The index of the top-level instruction in the transaction.
+    pub ix_index: u32,
+    /// The position of the emitting instruction among the inner instructions.
+    pub inner_ix_index: u32,
+}
+
+impl MessageId {
+    /// Creates a new [MessageId].
+    pub fn new(signature: [u8; 64], ix_index: u32, inner_ix_index: u32) -> Self {
+        Self {
+            signature,
+            ix_index,
+            inner_ix_index,
+        }
+    }
+}
+
+impl fmt::Display for MessageId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}-{}.{}",
+            bs58::encode(self.signature).into_string(),
+            self.ix_index,
+            self.inner_ix_index
+        )
+    }
+}
+
+impl fmt::Debug for MessageId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "MessageId({self})")
+    }
+}
+
+impl FromStr for MessageId {
+    type Err = MessageIdError;
+
+    /// Parses a message ID, only accepting its canonical form.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (signature, indexes) = s.split_once('-').ok_or(MessageIdError::InvalidFormat)?;
+        let (ix_index, inner_ix_index) = indexes
+            .split_once('.')
+            .ok_or(MessageIdError::InvalidFormat)?;
+
+        let signature = bs58::decode(signature)
+            .into_vec()
+            .map_err(|_err| MessageIdError::InvalidSignature)?
+            .try_into()
+            .map_err(|_err| MessageIdError::InvalidSignature)?;
+
+        Ok(Self {
+            signature,
+            ix_index: parse_index(ix_index)?,
+            inner_ix_index: parse_index(inner_ix_index)?,
+        })
+    }
+}
+
+/// Parses a decimal index, rejecting signs and leading zeros so that each message has a single
+/// ID.
+fn parse_index(index: &str) -> Result<u32, MessageIdError> {
+    let canonical = index == "0" || (!index.starts_with('0') && !index.is_empty());
+    if !canonical || !index.bytes().all(|byte| byte.is_ascii_digit()) {
+        return Err(MessageIdError::InvalidIndex);
+    }
+
+    index.parse().map_err(|_err| MessageIdError::InvalidIndex)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn message_id_round_trip() {
+        let message_id = MessageId::new([7; 64], 2, 13);
+
+        let formatted = message_id.to_string();
+
+        assert!(formatted.ends_with("-2.13"));
+        assert_eq!(formatted.parse::<MessageId>().unwrap(), message_id);
+    }
+
+    #[test]
+    fn message_id_rejects_invalid_format() {
+        let signature = bs58::encode([7; 64]).into_string();
+
+        assert_eq!(
+            signature.parse::<MessageId>(),
+            Err(MessageIdError::InvalidFormat)
+        );
+        assert_eq!(
+            format!("{signature}-2").parse::<MessageId>(),
+            Err(MessageIdError::InvalidFormat)
+        );
+    }
+
+    #[test]
+    fn message_id_rejects_invalid_signature() {
+        let short_signature = bs58::encode([7; 32]).into_string();
+
+        assert_eq!(
+            format!("{short_signature}-0.0").parse::<MessageId>(),
+            Err(MessageIdError::InvalidSignature)
+        );
+        assert_eq!(
+            "0x1234-0.0".parse::<MessageId>(),
+            Err(MessageIdError::InvalidSignature)
+        );
+    }
+
+    #[test]
+    fn message_id_rejects_non_canonical_indexes() {
+        let signature = bs58::encode([7; 64]).into_string();
+
+        for indexes in ["01.0", "+1.0", "1.", "1.-1", "1.4294967296"] {
+            assert_eq!(
+                format!("{signature}-{indexes}").parse::<MessageId>(),
+                Err(MessageIdError::InvalidIndex),
+                "{indexes}"
+            );
+        }
+    }
+}
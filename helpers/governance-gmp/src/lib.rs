@@ -1,6 +1,8 @@
 pub use alloy_primitives;
 use alloy_sol_types::sol;
 
+pub mod upgrade;
+
 sol! {
 
     /// The available governance commands See https://github.com/axelarnetwork/axelar-gmp-sdk-solidity/blob/main/contracts/governance/AxelarServiceGovernance.sol#L14-L19
@@ -28,6 +30,30 @@ sol! {
         /// The time after which the proposal can be executed.
         uint256 eta;
     }
+
+    /// The acknowledgements a governance program can send back to the Axelar
+    /// governance infrastructure.
+    #[derive(Debug, PartialEq)]
+    enum GovernanceAcknowledgement {
+        ProposalExecuted
+    }
+
+    /// The payload of an acknowledgement. The proposal fields are the ones of the
+    /// [`GovernanceCommandPayload`] that scheduled the proposal.
+    #[derive(Debug, PartialEq)]
+    #[repr(C)]
+    struct GovernanceAcknowledgementPayload {
+        /// The type of the acknowledgement
+        GovernanceAcknowledgement acknowledgement;
+        /// The hash of the proposal, as calculated by the governance program.
+        bytes32 proposal_hash;
+        /// The target address of the proposal.
+        bytes target;
+        /// The call data of the proposal.
+        bytes call_data;
+        /// The value of native token sent by the proposal.
+        uint256 native_value;
+    }
 }
 
 #[cfg(test)]
@@ -60,6 +86,22 @@ mod tests {
         assert_eq!(expected, hex::encode(command.abi_encode()))
     }
 
+    #[test]
+    fn acknowledgement_payload_round_trips() {
+        let acknowledgement = GovernanceAcknowledgementPayload {
+            acknowledgement: GovernanceAcknowledgement::ProposalExecuted,
+            proposal_hash: [1; 32].into(),
+            target: TARGET_ADDR.into(),
+            call_data: sample_call_data().into(),
+            native_value: Uint::from(NATIVE_VALUE),
+        };
+
+        let decoded =
+            GovernanceAcknowledgementPayload::abi_decode(&acknowledgement.abi_encode(), true)
+                .unwrap();
+        assert_eq!(acknowledgement, decoded);
+    }
+
     #[allow(deprecated)]
     fn sample_call_data() -> Vec<u8> {
         let function = Function {
@@ -0,0 +1,238 @@
+//! Helpers for composing Solana program upgrade proposals from EVM.
+//!
+//! The Solana governance program executes program upgrades itself, so the
+//! proposal targets the governance program, each upgrade being an
+//! `UpgradeProgram` governance instruction. The call data of a proposal with
+//! several upgrades is the borsh encoded list of proposal instructions, while
+//! a single upgrade keeps the format of single-instruction proposals: the borsh
+//! encoded call data of its instruction. This module encodes such call data
+//! without depending on any Solana crate. All the addresses are the raw 32
+//! bytes of the Solana public keys.
+//!
+//! Upgrading a program hashes the whole buffer, so the Solana transaction
+//! executing such proposals should request the maximum compute budget
+//! (1_400_000 CU) through the compute budget program.
+
+use core::num::TryFromIntError;
+
+use alloy_primitives::{Bytes, Uint};
+
+use crate::{GovernanceCommand, GovernanceCommandPayload};
+
+/// The BPF upgradeable loader program address
+/// (`BPFLoaderUpgradeab1e11111111111111111111111`).
+pub const BPF_LOADER_UPGRADEABLE_ID: [u8; 32] = [
+    2, 168, 246, 145, 78, 136, 161, 176, 226, 16, 21, 62, 247, 99, 174, 43, 0, 194, 185, 61, 22,
+    193, 36, 210, 192, 83, 122, 16, 4, 128, 0, 0,
+];
+
+/// The rent sysvar address (`SysvarRent111111111111111111111111111111111`).
+pub const SYSVAR_RENT_ID: [u8; 32] = [
+    6, 167, 213, 23, 25, 44, 92, 81, 33, 140, 201, 76, 61, 74, 241, 127, 88, 218, 238, 8, 155, 161,
+    253, 68, 227, 219, 217, 138, 0, 0, 0, 0,
+];
+
+/// The clock sysvar address (`SysvarC1ock11111111111111111111111111111111`).
+pub const SYSVAR_CLOCK_ID: [u8; 32] = [
+    6, 167, 213, 23, 24, 199, 116, 201, 40, 86, 99, 152, 105, 29, 94, 182, 139, 94, 184, 163, 155,
+    75, 109, 92, 115, 85, 91, 33, 0, 0, 0, 0,
+];
+
+/// The borsh discriminant of the `UpgradeProgram` variant of the Solana
+/// governance program instructions.
+pub const UPGRADE_PROGRAM_INSTRUCTION_INDEX: u8 = 6;
+
+/// A program upgrade to be executed by the Solana governance program.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ProgramUpgrade {
+    /// The program to upgrade.
+    pub program: [u8; 32],
+    /// The program data account of the program to upgrade. It's the PDA
+    /// derived from the program address and the upgradeable loader.
+    pub program_data: [u8; 32],
+    /// The buffer account holding the new program bytes. Its upgrade
+    /// authority must be the governance config PDA.
+    pub buffer: [u8; 32],
+    /// The account receiving the lamports of the buffer after the upgrade.
+    pub spill: [u8; 32],
+    /// The keccak256 hash of the buffer data after its metadata. The upgrade
+    /// fails if the buffer doesn't match it.
+    pub buffer_hash: [u8; 32],
+}
+
+impl ProgramUpgrade {
+    /// Appends the borsh encoded governance proposal instruction for this
+    /// upgrade to `out`.
+    fn encode_proposal_instruction(
+        &self,
+        governance_program: &[u8; 32],
+        governance_config: &[u8; 32],
+        out: &mut Vec<u8>,
+    ) -> Result<(), TryFromIntError> {
+        // Target address
+        out.extend_from_slice(governance_program);
+        self.encode_call_data(governance_program, governance_config, out)?;
+        // Native value
+        out.extend_from_slice(&[0; 32]);
+        Ok(())
+    }
+
+    /// Appends the borsh encoded call data of the governance proposal
+    /// instruction for this upgrade to `out`.
+    fn encode_call_data(
+        &self,
+        governance_program: &[u8; 32],
+        governance_config: &[u8; 32],
+        out: &mut Vec<u8>,
+    ) -> Result<(), TryFromIntError> {
+        // (pubkey, is_signer, is_writable)
+        let accounts = [
+            (governance_config, true, false),
+            (&self.program_data, false, true),
+            (&self.program, false, true),
+            (&self.buffer, false, true),
+            (&self.spill, false, true),
+            (&SYSVAR_RENT_ID, false, false),
+            (&SYSVAR_CLOCK_ID, false, false),
+            (&BPF_LOADER_UPGRADEABLE_ID, false, false),
+            (governance_program, false, false),
+        ];
+
+        let mut instruction_data = Vec::with_capacity(65);
+        instruction_data.push(UPGRADE_PROGRAM_INSTRUCTION_INDEX);
+        instruction_data.extend_from_slice(&self.program);
+        instruction_data.extend_from_slice(&self.buffer_hash);
+
+        // Solana accounts
+        encode_len(accounts.len(), out)?;
+        for (pubkey, is_signer, is_writable) in accounts {
+            out.extend_from_slice(pubkey);
+            out.push(u8::from(is_signer));
+            out.push(u8::from(is_writable));
+        }
+        // No native value receiver account
+        out.push(0);
+        // Call data
+        encode_len(instruction_data.len(), out)?;
+        out.extend_from_slice(&instruction_data);
+        Ok(())
+    }
+}
+
+/// Encodes the call data of a proposal executing the given program upgrades
+/// atomically, in order. A single upgrade is encoded in the format of
+/// single-instruction proposals, see the [module docs](self).
+///
+/// # Errors
+///
+/// If the number of upgrades doesn't fit in the `u32` borsh length prefix.
+pub fn upgrade_call_data(
+    governance_program: &[u8; 32],
+    governance_config: &[u8; 32],
+    upgrades: &[ProgramUpgrade],
+) -> Result<Vec<u8>, TryFromIntError> {
+    let mut out = Vec::new();
+    if let [upgrade] = upgrades {
+        upgrade.encode_call_data(governance_program, governance_config, &mut out)?;
+        return Ok(out);
+    }
+
+    encode_len(upgrades.len(), &mut out)?;
+    for upgrade in upgrades {
+        upgrade.encode_proposal_instruction(governance_program, governance_config, &mut out)?;
+    }
+    Ok(out)
+}
+
+/// Builds the `ScheduleTimeLockProposal` command payload for a proposal
+/// executing the given program upgrades atomically, in order.
+///
+/// # Errors
+///
+/// See [`upgrade_call_data`].
+pub fn schedule_upgrade_payload(
+    governance_program: &[u8; 32],
+    governance_config: &[u8; 32],
+    upgrades: &[ProgramUpgrade],
+    eta: u64,
+) -> Result<GovernanceCommandPayload, TryFromIntError> {
+    Ok(GovernanceCommandPayload {
+        command: GovernanceCommand::ScheduleTimeLockProposal,
+        target: Bytes::copy_from_slice(governance_program),
+        call_data: upgrade_call_data(governance_program, governance_config, upgrades)?.into(),
+        native_value: Uint::ZERO,
+        eta: Uint::from(eta),
+    })
+}
+
+fn encode_len(len: usize, out: &mut Vec<u8>) -> Result<(), TryFromIntError> {
+    let len = u32::try_from(len)?;
+    out.extend_from_slice(&len.to_le_bytes());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use axelar_solana_governance::instructions::builder::upgrade_program_instruction;
+    use axelar_solana_governance::state::proposal::ExecuteProposalData;
+    use axelar_solana_governance::state::GovernanceConfig;
+    use solana_program::bpf_loader_upgradeable;
+    use solana_program::pubkey::Pubkey;
+
+    use super::*;
+
+    /// Returns the upgrades composed from EVM, along with the proposal the
+    /// governance program builds for them.
+    fn sample_upgrades(count: u8) -> (Vec<ProgramUpgrade>, ExecuteProposalData) {
+        let spill = Pubkey::new_unique();
+        let (upgrades, instructions) = (0..count)
+            .map(|index| {
+                let program = Pubkey::new_unique();
+                let buffer = Pubkey::new_unique();
+                let buffer_hash = [index; 32];
+                let upgrade = ProgramUpgrade {
+                    program: program.to_bytes(),
+                    program_data: bpf_loader_upgradeable::get_program_data_address(&program)
+                        .to_bytes(),
+                    buffer: buffer.to_bytes(),
+                    spill: spill.to_bytes(),
+                    buffer_hash,
+                };
+                let instruction =
+                    upgrade_program_instruction(&program, &buffer, buffer_hash, &spill);
+                (upgrade, instruction)
+            })
+            .unzip();
+
+        (upgrades, ExecuteProposalData::new(instructions))
+    }
+
+    fn call_data(upgrades: &[ProgramUpgrade]) -> Vec<u8> {
+        upgrade_call_data(
+            &axelar_solana_governance::ID.to_bytes(),
+            &GovernanceConfig::pda().0.to_bytes(),
+            upgrades,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn single_upgrade_is_encoded_as_a_single_instruction_proposal() {
+        let (upgrades, proposal_data) = sample_upgrades(1);
+
+        let call_data = call_data(&upgrades);
+
+        assert_eq!(call_data, proposal_data.encoded_call_data());
+        assert_ne!(call_data, borsh::to_vec(&proposal_data).unwrap());
+    }
+
+    #[test]
+    fn several_upgrades_are_encoded_as_a_multi_instruction_proposal() {
+        let (upgrades, proposal_data) = sample_upgrades(3);
+
+        let call_data = call_data(&upgrades);
+
+        assert_eq!(call_data, borsh::to_vec(&proposal_data).unwrap());
+        assert_eq!(call_data, proposal_data.encoded_call_data());
+    }
+}
@@ -17,6 +17,7 @@ pub enum GMPPayload {
     ReceiveFromHub(ReceiveFromHub),
     LinkToken(LinkToken),
     RegisterTokenMetadata(RegisterTokenMetadata),
+    RegisterTokenMetadataUpdate(RegisterTokenMetadataUpdate),
 }
 
 sol! {
@@ -124,6 +125,25 @@ sol! {
         uint8 decimals;
     }
 
+    /// This message extends `RegisterTokenMetadata` to propagate updates to the metadata of an
+    /// interchain token to its counterparts on other chains. It shares its selector and starts
+    /// with the same fields, the update being told apart by the offset of `token_address`.
+    #[derive(Debug, PartialEq)]
+    #[repr(C)]
+    struct RegisterTokenMetadataUpdate {
+        /// Will always have a value of 6
+        uint256 selector;
+        /// The token address on the source chain
+        bytes token_address;
+        /// The number of decimals for the token
+        uint8 decimals;
+        /// The interchainTokenId of the token being updated
+        bytes32 token_id;
+        /// The new name for the token
+        string name;
+        /// The new symbol for the token
+        string symbol;
+    }
 
 }
 
@@ -151,6 +171,23 @@ impl RegisterTokenMetadata {
     pub const MESSAGE_TYPE_ID: u8 = 6;
 }
 
+impl RegisterTokenMetadataUpdate {
+    pub const MESSAGE_TYPE_ID: u8 = RegisterTokenMetadata::MESSAGE_TYPE_ID;
+
+    /// The size of the head of the encoded message, where the dynamic
+    /// `token_address` starts.
+    const HEAD_SIZE: usize = 6 * 32;
+
+    /// Whether the given encoded `RegisterTokenMetadata` message carries a
+    /// metadata update.
+    fn is_update(bytes: &[u8]) -> bool {
+        bytes
+            .get(32..64)
+            .and_then(|offset| alloy_primitives::U256::abi_decode(offset, true).ok())
+            .is_some_and(|offset| offset == alloy_primitives::U256::from(Self::HEAD_SIZE))
+    }
+}
+
 impl GMPPayload {
     pub fn decode(bytes: &[u8]) -> Result<Self, alloy_sol_types::Error> {
         let variant = alloy_primitives::U256::abi_decode(&bytes[0..32], true)?;
@@ -168,6 +205,13 @@ impl GMPPayload {
             ReceiveFromHub::MESSAGE_TYPE_ID => Ok(GMPPayload::ReceiveFromHub(
                 ReceiveFromHub::abi_decode_params(bytes, true)?,
             )),
+            RegisterTokenMetadata::MESSAGE_TYPE_ID
+                if RegisterTokenMetadataUpdate::is_update(bytes) =>
+            {
+                Ok(GMPPayload::RegisterTokenMetadataUpdate(
+                    RegisterTokenMetadataUpdate::abi_decode_params(bytes, true)?,
+                ))
+            }
             RegisterTokenMetadata::MESSAGE_TYPE_ID => Ok(GMPPayload::RegisterTokenMetadata(
                 RegisterTokenMetadata::abi_decode_params(bytes, true)?,
             )),
@@ -188,6 +232,7 @@ impl GMPPayload {
             GMPPayload::ReceiveFromHub(data) => data.abi_encode_params(),
             GMPPayload::LinkToken(data) => data.abi_encode_params(),
             GMPPayload::RegisterTokenMetadata(data) => data.abi_encode_params(),
+            GMPPayload::RegisterTokenMetadataUpdate(data) => data.abi_encode_params(),
         }
     }
 
@@ -198,6 +243,7 @@ impl GMPPayload {
             GMPPayload::SendToHub(inner) => GMPPayload::decode(&inner.payload)?.token_id(),
             GMPPayload::ReceiveFromHub(inner) => GMPPayload::decode(&inner.payload)?.token_id(),
             GMPPayload::LinkToken(data) => Ok(*data.token_id),
+            GMPPayload::RegisterTokenMetadataUpdate(data) => Ok(*data.token_id),
             GMPPayload::RegisterTokenMetadata(_) => Err(alloy_sol_types::Error::Other(
                 Cow::Borrowed("RegisterTokenMetadata does not have a token_id"),
             )),
@@ -328,4 +374,32 @@ mod tests {
             "encode-decode should be idempotent"
         );
     }
+
+    #[test]
+    fn register_token_metadata_update_encode_decode() {
+        let payload = GMPPayload::RegisterTokenMetadataUpdate(RegisterTokenMetadataUpdate {
+            selector: U256::from(RegisterTokenMetadataUpdate::MESSAGE_TYPE_ID),
+            token_address: vec![1; 32].into(),
+            decimals: 9,
+            token_id: [42; 32].into(),
+            name: "New Token Name".to_owned(),
+            symbol: "NTN".to_owned(),
+        });
+
+        let decoded = GMPPayload::decode(&payload.encode()).unwrap();
+
+        assert_eq!(decoded, payload);
+        assert_eq!(decoded.token_id().unwrap(), [42; 32]);
+    }
+
+    #[test]
+    fn register_token_metadata_is_not_decoded_as_update() {
+        let payload = GMPPayload::RegisterTokenMetadata(RegisterTokenMetadata {
+            selector: U256::from(RegisterTokenMetadata::MESSAGE_TYPE_ID),
+            token_address: vec![1; 32].into(),
+            decimals: 9,
+        });
+
+        assert_eq!(GMPPayload::decode(&payload.encode()).unwrap(), payload);
+    }
 }
@@ -0,0 +1,68 @@
+//! Sources of on-chain accounts used to build ITS instructions.
+
+use core::future::Future;
+use core::hash::BuildHasher;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+use solana_banks_client::BanksClient;
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_sdk::account::Account;
+use solana_sdk::program_error::ProgramError;
+use solana_sdk::pubkey::Pubkey;
+
+/// Fetches the accounts needed to build ITS instructions.
+///
+/// Implemented for [`RpcClient`], [`BanksClient`] and an in-memory
+/// [`HashMap`], which allows building instructions offline or on top of a
+/// custom caching layer.
+pub trait AccountFetcher {
+    /// Fetches the account stored at the given address.
+    ///
+    /// Returns `Ok(None)` if the account doesn't exist.
+    ///
+    /// # Errors
+    ///
+    /// If the account cannot be fetched.
+    fn get_account(
+        &self,
+        address: &Pubkey,
+    ) -> impl Future<Output = Result<Option<Account>, ProgramError>> + Send;
+}
+
+impl AccountFetcher for RpcClient {
+    async fn get_account(&self, address: &Pubkey) -> Result<Option<Account>, ProgramError> {
+        self.get_account_with_commitment(address, self.commitment())
+            .await
+            .map(|response| response.value)
+            .map_err(|_err| ProgramError::InvalidAccountData)
+    }
+}
+
+impl AccountFetcher for BanksClient {
+    async fn get_account(&self, address: &Pubkey) -> Result<Option<Account>, ProgramError> {
+        // `BanksClient` needs a mutable reference, but clones share the same connection.
+        self.clone()
+            .get_account(*address)
+            .await
+            .map_err(|_err| ProgramError::InvalidAccountData)
+    }
+}
+
+impl<S: BuildHasher + Sync> AccountFetcher for HashMap<Pubkey, Account, S> {
+    async fn get_account(&self, address: &Pubkey) -> Result<Option<Account>, ProgramError> {
+        Ok(self.get(address).cloned())
+    }
+}
+
+impl<T: AccountFetcher + Sync + ?Sized> AccountFetcher for &T {
+    async fn get_account(&self, address: &Pubkey) -> Result<Option<Account>, ProgramError> {
+        (**self).get_account(address).await
+    }
+}
+
+impl<T: AccountFetcher + Send + Sync + ?Sized> AccountFetcher for Arc<T> {
+    async fn get_account(&self, address: &Pubkey) -> Result<Option<Account>, ProgramError> {
+        (**self).get_account(address).await
+    }
+}
@@ -1,35 +1,56 @@
 //! Helper crate for building ITS instructions.
 
-use core::ops::Deref;
+mod account_fetcher;
+
+pub use account_fetcher::AccountFetcher;
 
 use axelar_executable::AxelarMessagePayload;
 use axelar_solana_encoding::types::messages::Message;
 use axelar_solana_its::instruction::ItsGmpInstructionInputs;
-use axelar_solana_its::state::token_manager::TokenManager;
+use axelar_solana_its::state::flow_limit::FlowSlot;
+use axelar_solana_its::state::token_manager::{TokenManager, Type as TokenManagerType};
+use axelar_solana_its::Roles;
 use borsh::BorshDeserialize;
 use interchain_token_transfer_gmp::GMPPayload;
-use solana_client::nonblocking::rpc_client::RpcClient;
+use role_management::state::{RoleIndex, UserRoles};
+use solana_client::rpc_filter::{Memcmp, RpcFilterType};
+use solana_sdk::account::Account;
 use solana_sdk::clock::Clock;
-use solana_sdk::instruction::Instruction;
+use solana_sdk::instruction::{AccountMeta, Instruction};
 use solana_sdk::program_error::ProgramError;
+use solana_sdk::program_pack::Pack as _;
 use solana_sdk::pubkey::Pubkey;
 use solana_sdk::sysvar::clock;
+use spl_associated_token_account::get_associated_token_address_with_program_id;
+use spl_token_2022::extension::{transfer_hook, StateWithExtensions};
+use spl_token_2022::offchain;
+use spl_token_2022::state::Mint;
 
 /// Creates a [`InterchainTokenServiceInstruction::ItsGmpPayload`] instruction.
 ///
+/// The accounts needed to derive the instruction accounts, such as the clock,
+/// the `TokenManager` and the mint, are fetched through the given
+/// [`AccountFetcher`].
+///
+/// Instructions can be built for every message ITS delivers to Solana,
+/// including `RegisterTokenMetadata` messages carrying a metadata update.
+///
 /// # Errors
 ///
-/// If serialization fails.
-pub async fn build_its_gmp_instruction<C>(
+/// If serialization fails or if a required account cannot be fetched. Plain
+/// `RegisterTokenMetadata` messages are only ever sent to the ITS Hub and
+/// don't identify a token, so they are rejected with
+/// [`ProgramError::InvalidArgument`].
+pub async fn build_its_gmp_instruction<F>(
     payer: Pubkey,
     gateway_incoming_message_pda: Pubkey,
     gateway_message_payload_pda: Pubkey,
     message: Message,
     abi_payload: Vec<u8>,
-    rpc_client: C,
+    fetcher: F,
 ) -> Result<Instruction, ProgramError>
 where
-    C: Deref<Target = RpcClient> + Send + Sync,
+    F: AccountFetcher + Send + Sync,
 {
     let payload = GMPPayload::decode(&abi_payload).map_err(|_err| ProgramError::InvalidArgument)?;
     ensure_payer_is_not_forwarded(payer, &payload)?;
@@ -41,16 +62,25 @@ where
             .map_err(|_err| ProgramError::InvalidArgument)?,
     );
 
-    let clock_account = rpc_client
-        .get_account(&clock::id())
-        .await
-        .map_err(|_err| ProgramError::InvalidAccountData)?;
+    let clock_account = fetch_existing_account(&fetcher, &clock::id()).await?;
     let clock: Clock = bincode::deserialize(&clock_account.data)
         .map_err(|_err| ProgramError::InvalidAccountData)?;
     let timestamp = clock.unix_timestamp;
 
+    let flow_epoch = match fetcher.get_account(&token_manager_pda).await? {
+        Some(token_manager_account) => Some(
+            TokenManager::from_account_data(&token_manager_pda, &token_manager_account.data)?
+                .flow_limit
+                .flow_epoch(timestamp)?,
+        ),
+        None => None,
+    };
+
     let (mint, token_program) =
-        try_infer_mint_and_program(&token_manager_pda, &payload, rpc_client).await?;
+        try_infer_mint_and_program(&token_manager_pda, &payload, &fetcher).await?;
+    let transfer_hook_accounts =
+        resolve_transfer_hook_accounts(&token_manager_pda, &payload, token_program, &fetcher)
+            .await?;
 
     let inputs = ItsGmpInstructionInputs::builder()
         .payer(payer)
@@ -60,29 +90,149 @@ where
         .payload(payload)
         .token_program(token_program)
         .mint_opt(mint)
-        .timestamp(timestamp)
+        .flow_epoch_opt(flow_epoch)
+        .transfer_hook_accounts(transfer_hook_accounts)
         .build();
 
     axelar_solana_its::instruction::its_gmp_payload(inputs)
 }
 
+/// Filters selecting the [`FlowSlot`] PDAs of the given token among the ITS
+/// program accounts. Meant to be used with `getProgramAccounts` to list the
+/// candidates passed to [`closable_flow_slots`].
+#[must_use]
+pub fn flow_slot_filters(token_id: [u8; 32]) -> Vec<RpcFilterType> {
+    let (its_root_pda, _) = axelar_solana_its::find_its_root_pda();
+    let (token_manager_pda, _) =
+        axelar_solana_its::find_token_manager_pda(&its_root_pda, &token_id);
+
+    vec![
+        RpcFilterType::DataSize(FlowSlot::LEN as u64),
+        RpcFilterType::Memcmp(Memcmp::new_base58_encoded(0, token_manager_pda.as_ref())),
+    ]
+}
+
+/// Lists the [`FlowSlot`] PDAs of the given token that belong to past epochs and
+/// can be closed with [`axelar_solana_its::instruction::close_flow_slot`].
+///
+/// The slots are picked among the given program accounts, which can be listed
+/// with `getProgramAccounts` and [`flow_slot_filters`], while the
+/// `TokenManager` and the clock are fetched through the given
+/// [`AccountFetcher`]. Accounts that aren't flow slots of the token are
+/// ignored.
+///
+/// Returns the address of each closable slot alongside its content. Slots
+/// created by older versions of the program don't record their token manager
+/// and are not listed; they can still be closed by deriving their address with
+/// [`axelar_solana_its::find_flow_slot_pda`] for the epochs of interest.
+///
+/// # Errors
+///
+/// If the `TokenManager` or the clock cannot be fetched, or if any of the
+/// fetched accounts fails to deserialize.
+pub async fn closable_flow_slots<F, I>(
+    token_id: [u8; 32],
+    program_accounts: I,
+    fetcher: F,
+) -> Result<Vec<(Pubkey, FlowSlot)>, ProgramError>
+where
+    F: AccountFetcher + Send + Sync,
+    I: IntoIterator<Item = (Pubkey, Account)>,
+{
+    let (its_root_pda, _) = axelar_solana_its::find_its_root_pda();
+    let (token_manager_pda, _) =
+        axelar_solana_its::find_token_manager_pda(&its_root_pda, &token_id);
+    let token_manager_account = fetch_existing_account(&fetcher, &token_manager_pda).await?;
+    let token_manager =
+        TokenManager::from_account_data(&token_manager_pda, &token_manager_account.data)?;
+
+    let clock_account = fetch_existing_account(&fetcher, &clock::id()).await?;
+    let clock: Clock = bincode::deserialize(&clock_account.data)
+        .map_err(|_err| ProgramError::InvalidAccountData)?;
+
+    let mut closable = Vec::new();
+    for (address, account) in program_accounts {
+        let is_flow_slot_of_token = account.owner == axelar_solana_its::id()
+            && account.data.len() == FlowSlot::LEN
+            && account.data.starts_with(token_manager_pda.as_ref());
+        if !is_flow_slot_of_token {
+            continue;
+        }
+
+        let flow_slot = FlowSlot::try_from_slice(&account.data)?;
+        if flow_slot.is_closable(&token_manager.flow_limit, clock.unix_timestamp)? {
+            closable.push((address, flow_slot));
+        }
+    }
+
+    closable.sort_by_key(|(_, flow_slot)| flow_slot.epoch);
+
+    Ok(closable)
+}
+
+/// Returns the role table of a resource of ITS, either the ITS root PDA or a
+/// `TokenManager` PDA.
+///
+/// The users are listed from the role index of the resource, while their roles
+/// are read from their roles PDA, which also carries the details of any
+/// time-bound or usage-limited grants. Returns an empty table if the resource
+/// has no role index.
+///
+/// # Errors
+///
+/// If any of the accounts cannot be fetched or fails to deserialize.
+pub async fn role_table<F>(
+    resource: Pubkey,
+    fetcher: F,
+) -> Result<Vec<(Pubkey, UserRoles<Roles>)>, ProgramError>
+where
+    F: AccountFetcher + Send + Sync,
+{
+    let (role_index_pda, _) =
+        role_management::find_role_index_pda(&axelar_solana_its::id(), &resource);
+    let Some(role_index_account) = fetcher.get_account(&role_index_pda).await? else {
+        return Ok(Vec::new());
+    };
+    let role_index = RoleIndex::<Roles>::try_from_slice(&role_index_account.data)?;
+
+    let mut table = Vec::with_capacity(role_index.holders().len());
+    for holder in role_index.holders() {
+        let (user_roles_pda, _) =
+            role_management::find_user_roles_pda(&axelar_solana_its::id(), &resource, &holder.user);
+
+        if let Some(user_roles_account) = fetcher.get_account(&user_roles_pda).await? {
+            let user_roles = UserRoles::<Roles>::try_from_slice(&user_roles_account.data)?;
+            table.push((holder.user, user_roles));
+        }
+    }
+
+    Ok(table)
+}
+
+async fn fetch_existing_account<F>(fetcher: &F, address: &Pubkey) -> Result<Account, ProgramError>
+where
+    F: AccountFetcher + Sync,
+{
+    fetcher
+        .get_account(address)
+        .await?
+        .ok_or(ProgramError::InvalidAccountData)
+}
+
 #[async_recursion::async_recursion]
-async fn try_infer_mint_and_program<C>(
+async fn try_infer_mint_and_program<F>(
     token_manager_pda: &Pubkey,
     payload: &GMPPayload,
-    rpc_client: C,
+    fetcher: &F,
 ) -> Result<(Option<Pubkey>, Pubkey), ProgramError>
 where
-    C: Deref<Target = RpcClient> + Send + Sync,
+    F: AccountFetcher + Send + Sync,
 {
     match payload {
         GMPPayload::InterchainTransfer(_) => {
-            let token_manager_data = rpc_client
-                .get_account_data(token_manager_pda)
-                .await
-                .map_err(|_err| ProgramError::InvalidAccountData)?;
-
-            let token_manager = TokenManager::try_from_slice(&token_manager_data)?;
+            let token_manager_account = fetch_existing_account(fetcher, token_manager_pda).await?;
+            let token_manager =
+                TokenManager::from_account_data(token_manager_pda, &token_manager_account.data)?;
             let token_mint = Pubkey::new_from_array(
                 token_manager
                     .token_address
@@ -90,38 +240,118 @@ where
                     .try_into()
                     .map_err(|_err| ProgramError::InvalidAccountData)?,
             );
-            let token_program = rpc_client
-                .get_account(&token_mint)
-                .await
-                .map_err(|_err| ProgramError::InvalidAccountData)?
-                .owner;
+            let token_program = fetch_existing_account(fetcher, &token_mint).await?.owner;
 
             Ok((Some(token_mint), token_program))
         }
-        GMPPayload::DeployInterchainToken(_) => Ok((None, spl_token_2022::id())),
+        GMPPayload::DeployInterchainToken(_) | GMPPayload::RegisterTokenMetadataUpdate(_) => {
+            Ok((None, spl_token_2022::id()))
+        }
         GMPPayload::RegisterTokenMetadata(_) | GMPPayload::SendToHub(_) => {
             return Err(ProgramError::InvalidArgument)
         }
         GMPPayload::ReceiveFromHub(inner) => {
             let inner_payload =
                 GMPPayload::decode(&inner.payload).map_err(|_err| ProgramError::InvalidArgument)?;
-            try_infer_mint_and_program(token_manager_pda, &inner_payload, rpc_client).await
+            try_infer_mint_and_program(token_manager_pda, &inner_payload, fetcher).await
         }
         GMPPayload::LinkToken(link_payload) => {
             let token_mint = Pubkey::try_from(link_payload.destination_token_address.as_ref())
                 .map_err(|_err| ProgramError::InvalidInstructionData)?;
 
-            let token_program = rpc_client
-                .get_account(&token_mint)
-                .await
-                .map_err(|_err| ProgramError::InvalidAccountData)?
-                .owner;
+            let token_program = fetch_existing_account(fetcher, &token_mint).await?.owner;
 
             Ok((Some(token_mint), token_program))
         }
     }
 }
 
+/// Resolves the extra accounts required by the transfer hook of the mint when
+/// an incoming transfer unlocks tokens from the `TokenManager`.
+///
+/// Returns no accounts for any other message, token manager type or mint.
+#[async_recursion::async_recursion]
+async fn resolve_transfer_hook_accounts<F>(
+    token_manager_pda: &Pubkey,
+    payload: &GMPPayload,
+    token_program: Pubkey,
+    fetcher: &F,
+) -> Result<Vec<AccountMeta>, ProgramError>
+where
+    F: AccountFetcher + Send + Sync,
+{
+    let transfer = match payload {
+        GMPPayload::InterchainTransfer(transfer) => transfer,
+        GMPPayload::ReceiveFromHub(inner) => {
+            let inner_payload =
+                GMPPayload::decode(&inner.payload).map_err(|_err| ProgramError::InvalidArgument)?;
+            return resolve_transfer_hook_accounts(
+                token_manager_pda,
+                &inner_payload,
+                token_program,
+                fetcher,
+            )
+            .await;
+        }
+        GMPPayload::DeployInterchainToken(_)
+        | GMPPayload::RegisterTokenMetadataUpdate(_)
+        | GMPPayload::RegisterTokenMetadata(_)
+        | GMPPayload::SendToHub(_)
+        | GMPPayload::LinkToken(_) => return Ok(Vec::new()),
+    };
+
+    let token_manager_account = fetch_existing_account(fetcher, token_manager_pda).await?;
+    let token_manager =
+        TokenManager::from_account_data(token_manager_pda, &token_manager_account.data)?;
+    if !matches!(
+        token_manager.ty,
+        TokenManagerType::LockUnlock | TokenManagerType::LockUnlockFee
+    ) {
+        return Ok(Vec::new());
+    }
+
+    let mint = token_manager.token_address;
+    let mint_account = fetch_existing_account(fetcher, &mint).await?;
+    let mint_state = StateWithExtensions::<Mint>::unpack(&mint_account.data)?;
+    if transfer_hook::get_program_id(&mint_state).is_none() {
+        return Ok(Vec::new());
+    }
+
+    let destination_address = Pubkey::try_from(transfer.destination_address.as_ref())
+        .map_err(|_err| ProgramError::InvalidInstructionData)?;
+    // Tokens sent along with data are unlocked to the ATA of the destination program.
+    let destination = if transfer.data.is_empty() {
+        destination_address
+    } else {
+        get_associated_token_address_with_program_id(&destination_address, &mint, &token_program)
+    };
+    let amount =
+        u64::try_from(transfer.amount).map_err(|_err| ProgramError::InvalidInstructionData)?;
+
+    let instruction = offchain::create_transfer_checked_instruction_with_extra_metas(
+        &token_program,
+        &token_manager.associated_token_account,
+        &mint,
+        &destination,
+        token_manager_pda,
+        &[],
+        amount,
+        mint_state.base.decimals,
+        |address| async move {
+            fetcher
+                .get_account(&address)
+                .await
+                .map(|account| account.map(|account| account.data))
+                .map_err(Into::into)
+        },
+    )
+    .await
+    .map_err(|_err| ProgramError::InvalidAccountData)?;
+
+    // The first four accounts are the ones of the transfer itself.
+    Ok(instruction.accounts.into_iter().skip(4).collect())
+}
+
 fn ensure_payer_is_not_forwarded(payer: Pubkey, payload: &GMPPayload) -> Result<(), ProgramError> {
     match payload {
         GMPPayload::InterchainTransfer(transfer) => {
@@ -142,8 +372,278 @@ fn ensure_payer_is_not_forwarded(payer: Pubkey, payload: &GMPPayload) -> Result<
         }
         GMPPayload::LinkToken(_)
         | GMPPayload::RegisterTokenMetadata(_)
-        | GMPPayload::DeployInterchainToken(_) => {}
+        | GMPPayload::DeployInterchainToken(_)
+        | GMPPayload::RegisterTokenMetadataUpdate(_) => {}
     }
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use axelar_solana_encoding::types::messages::CrossChainId;
+    use axelar_solana_its::state::flow_limit::DEFAULT_EPOCH_TIME;
+    use interchain_token_transfer_gmp::alloy_primitives::{Bytes, U256};
+    use interchain_token_transfer_gmp::{
+        DeployInterchainToken, InterchainTransfer, LinkToken, ReceiveFromHub,
+        RegisterTokenMetadataUpdate,
+    };
+    use solana_sdk::account::Account;
+    use solana_sdk::sysvar;
+
+    use super::*;
+
+    fn clock_only_fetcher() -> HashMap<Pubkey, Account> {
+        let clock_account = Account {
+            lamports: 1,
+            data: bincode::serialize(&Clock::default()).unwrap(),
+            owner: sysvar::id(),
+            executable: false,
+            rent_epoch: 0,
+        };
+
+        HashMap::from([(clock::id(), clock_account)])
+    }
+
+    async fn build(
+        payload: GMPPayload,
+        fetcher: &HashMap<Pubkey, Account>,
+    ) -> Result<Instruction, ProgramError> {
+        let wrapped = GMPPayload::ReceiveFromHub(ReceiveFromHub {
+            selector: U256::from(ReceiveFromHub::MESSAGE_TYPE_ID),
+            source_chain: "ethereum".to_owned(),
+            payload: payload.encode().into(),
+        });
+        let message = Message {
+            cc_id: CrossChainId {
+                chain: "axelar".to_owned(),
+                id: "message-id".to_owned(),
+            },
+            source_address: "its-hub".to_owned(),
+            destination_chain: "solana".to_owned(),
+            destination_address: axelar_solana_its::id().to_string(),
+            payload_hash: [0; 32],
+        };
+
+        build_its_gmp_instruction(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            message,
+            wrapped.encode(),
+            fetcher,
+        )
+        .await
+    }
+
+    #[tokio::test]
+    async fn deploy_interchain_token_only_needs_the_clock() {
+        let payload = GMPPayload::DeployInterchainToken(DeployInterchainToken {
+            selector: U256::from(DeployInterchainToken::MESSAGE_TYPE_ID),
+            token_id: [1; 32].into(),
+            name: "Test Token".to_owned(),
+            symbol: "TT".to_owned(),
+            decimals: 9,
+            minter: Bytes::new(),
+        });
+
+        let instruction = build(payload, &clock_only_fetcher()).await.unwrap();
+
+        assert!(instruction
+            .accounts
+            .iter()
+            .any(|account| account.pubkey == spl_token_2022::id()));
+    }
+
+    #[tokio::test]
+    async fn link_token_uses_the_owner_of_the_mint_as_token_program() {
+        let mint = Pubkey::new_unique();
+        let token_program = Pubkey::new_unique();
+        let mut fetcher = clock_only_fetcher();
+        fetcher.insert(
+            mint,
+            Account {
+                lamports: 1,
+                data: vec![0; 82],
+                owner: token_program,
+                executable: false,
+                rent_epoch: 0,
+            },
+        );
+
+        let payload = GMPPayload::LinkToken(LinkToken {
+            selector: U256::from(LinkToken::MESSAGE_TYPE_ID),
+            token_id: [2; 32].into(),
+            token_manager_type: U256::from(2_u8),
+            source_token_address: Bytes::from(vec![3; 20]),
+            destination_token_address: mint.to_bytes().into(),
+            link_params: Bytes::new(),
+        });
+
+        let instruction = build(payload, &fetcher).await.unwrap();
+
+        assert!(instruction
+            .accounts
+            .iter()
+            .any(|account| account.pubkey == mint));
+        assert!(instruction
+            .accounts
+            .iter()
+            .any(|account| account.pubkey == token_program));
+    }
+
+    #[tokio::test]
+    async fn interchain_transfer_fails_without_token_manager() {
+        let payload = GMPPayload::InterchainTransfer(InterchainTransfer {
+            selector: U256::from(InterchainTransfer::MESSAGE_TYPE_ID),
+            token_id: [4; 32].into(),
+            source_address: Bytes::from(vec![5; 20]),
+            destination_address: Pubkey::new_unique().to_bytes().into(),
+            amount: U256::from(100_u64),
+            data: Bytes::new(),
+        });
+
+        let result = build(payload, &clock_only_fetcher()).await;
+
+        assert_eq!(result, Err(ProgramError::InvalidAccountData));
+    }
+
+    #[tokio::test]
+    async fn register_token_metadata_update_only_needs_the_clock() {
+        let payload = GMPPayload::RegisterTokenMetadataUpdate(RegisterTokenMetadataUpdate {
+            selector: U256::from(RegisterTokenMetadataUpdate::MESSAGE_TYPE_ID),
+            token_address: Bytes::from(vec![6; 20]),
+            decimals: 18,
+            token_id: [6; 32].into(),
+            name: "Test Token".to_owned(),
+            symbol: "TT".to_owned(),
+        });
+
+        let instruction = build(payload, &clock_only_fetcher()).await.unwrap();
+
+        assert!(instruction
+            .accounts
+            .iter()
+            .any(|account| account.pubkey == sysvar::instructions::id()));
+        assert!(instruction
+            .accounts
+            .iter()
+            .any(|account| account.pubkey == spl_token_2022::id()));
+    }
+
+    #[tokio::test]
+    async fn closable_flow_slots_are_picked_among_program_accounts() {
+        let token_id = [7; 32];
+        let (its_root_pda, _) = axelar_solana_its::find_its_root_pda();
+        let (token_manager_pda, _) =
+            axelar_solana_its::find_token_manager_pda(&its_root_pda, &token_id);
+        let program_account = |data: Vec<u8>| Account {
+            lamports: 1,
+            data,
+            owner: axelar_solana_its::id(),
+            executable: false,
+            rent_epoch: 0,
+        };
+        let flow_slot = |token_manager: Pubkey, epoch: u64| {
+            program_account(
+                borsh::to_vec(&FlowSlot {
+                    token_manager,
+                    epoch,
+                    epoch_duration: DEFAULT_EPOCH_TIME.as_secs(),
+                    flow_in: 0,
+                    flow_out: 0,
+                    bump: 255,
+                })
+                .unwrap(),
+            )
+        };
+
+        let token_manager = TokenManager::new(
+            TokenManagerType::LockUnlock,
+            token_id,
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            255,
+        );
+        let mut fetcher = clock_only_fetcher();
+        let clock = Clock {
+            unix_timestamp: i64::try_from(token_manager.flow_limit.epoch_duration * 3).unwrap(),
+            ..Clock::default()
+        };
+        fetcher.get_mut(&clock::id()).unwrap().data = bincode::serialize(&clock).unwrap();
+        fetcher.insert(
+            token_manager_pda,
+            program_account(borsh::to_vec(&token_manager).unwrap()),
+        );
+
+        let (past, current, other_token) = (
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+        );
+        let program_accounts = vec![
+            (current, flow_slot(token_manager_pda, 3)),
+            (past, flow_slot(token_manager_pda, 1)),
+            (other_token, flow_slot(Pubkey::new_unique(), 1)),
+        ];
+
+        let closable = closable_flow_slots(token_id, program_accounts, &fetcher)
+            .await
+            .unwrap();
+
+        assert_eq!(
+            closable
+                .iter()
+                .map(|(address, _)| *address)
+                .collect::<Vec<_>>(),
+            vec![past]
+        );
+    }
+
+    #[tokio::test]
+    async fn role_table_lists_indexed_users() {
+        let resource = Pubkey::new_unique();
+        let alice = Pubkey::new_unique();
+        let bob = Pubkey::new_unique();
+        let program_account = |data: Vec<u8>| Account {
+            lamports: 1,
+            data,
+            owner: axelar_solana_its::id(),
+            executable: false,
+            rent_epoch: 0,
+        };
+
+        let mut role_index = RoleIndex::new(255);
+        role_index.set(alice, Roles::OPERATOR | Roles::FLOW_LIMITER);
+        role_index.set(bob, Roles::MINTER);
+
+        let (role_index_pda, _) =
+            role_management::find_role_index_pda(&axelar_solana_its::id(), &resource);
+        let (alice_roles_pda, alice_bump) =
+            role_management::find_user_roles_pda(&axelar_solana_its::id(), &resource, &alice);
+        let alice_roles = UserRoles::new(Roles::OPERATOR | Roles::FLOW_LIMITER, alice_bump);
+
+        // Bob's roles account is missing, so he isn't part of the table.
+        let fetcher = HashMap::from([
+            (
+                role_index_pda,
+                program_account(borsh::to_vec(&role_index).unwrap()),
+            ),
+            (
+                alice_roles_pda,
+                program_account(borsh::to_vec(&alice_roles).unwrap()),
+            ),
+        ]);
+
+        let table = role_table(resource, &fetcher).await.unwrap();
+
+        assert_eq!(table, vec![(alice, alice_roles)]);
+        assert!(role_table(Pubkey::new_unique(), &fetcher)
+            .await
+            .unwrap()
+            .is_empty());
+    }
+}
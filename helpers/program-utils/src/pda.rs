@@ -313,6 +313,25 @@ where
     }
 }
 
+/// Deserializes a trailing field, falling back to its default value if the
+/// data ends before it. Allows fields to be appended to existing accounts.
+///
+/// # Errors
+///
+/// If the data following the end of the account cannot be deserialized.
+pub fn deserialize_or_default<T, R>(reader: &mut R) -> std::io::Result<T>
+where
+    T: BorshDeserialize + Default,
+    R: std::io::Read,
+{
+    let mut first_byte = [0_u8; 1];
+    if reader.read(&mut first_byte)? == 0 {
+        return Ok(T::default());
+    }
+
+    T::deserialize_reader(&mut std::io::Read::chain(first_byte.as_slice(), reader))
+}
+
 /// A trait for types that can be safely converted to and from byte slices using `bytemuck`.
 pub trait BytemuckedPda: Sized + NoUninit + AnyBitPattern {
     /// Reads an immutable reference to `Self` from a byte slice.
@@ -1,4 +1,5 @@
 //! Role management crate for the Solana blockchain.
+use solana_program::hash::hashv;
 use solana_program::pubkey::Pubkey;
 
 pub mod processor;
@@ -11,6 +12,12 @@ pub mod seed_prefixes {
 
     /// The seed prefix for deriving the role proposal PDA
     pub const ROLE_PROPOSAL_SEED: &[u8] = b"role-proposal";
+
+    /// The seed prefix for deriving the role index PDA
+    pub const ROLE_INDEX_SEED: &[u8] = b"role-index";
+
+    /// The seed prefix for deriving the multisig PDA
+    pub const MULTISIG_SEED: &[u8] = b"multisig";
 }
 
 /// Tries to create the PDA for `UserRoles` using the provided bump,
@@ -130,3 +137,102 @@ pub fn find_roles_proposal_pda(
 ) -> (Pubkey, u8) {
     roles_proposal_pda(program_id, resource, from, to, None)
 }
+
+/// Tries to create the PDA for `RoleIndex` using the provided bump,
+/// falling back to `find_program_address` if the bump is `None` or invalid.
+#[must_use]
+pub fn role_index_pda(
+    program_id: &Pubkey,
+    resource: &Pubkey,
+    maybe_bump: Option<u8>,
+) -> (Pubkey, u8) {
+    maybe_bump
+        .and_then(|bump| {
+            Pubkey::create_program_address(
+                &[seed_prefixes::ROLE_INDEX_SEED, resource.as_ref(), &[bump]],
+                program_id,
+            )
+            .map(|pubkey| (pubkey, bump))
+            .ok()
+        })
+        .unwrap_or_else(|| {
+            Pubkey::find_program_address(
+                &[seed_prefixes::ROLE_INDEX_SEED, resource.as_ref()],
+                program_id,
+            )
+        })
+}
+
+/// Tries to create the PDA for `RoleIndex` using the provided bump,
+/// falling back to `find_program_address` if the bump is invalid.
+#[inline]
+#[must_use]
+pub fn create_role_index_pda(program_id: &Pubkey, resource: &Pubkey, bump: u8) -> (Pubkey, u8) {
+    role_index_pda(program_id, resource, Some(bump))
+}
+
+/// Derives the PDA for a `RoleIndex` account.
+#[inline]
+#[must_use]
+pub fn find_role_index_pda(program_id: &Pubkey, resource: &Pubkey) -> (Pubkey, u8) {
+    role_index_pda(program_id, resource, None)
+}
+
+/// Hashes the configuration of a multisig, used to derive its PDA.
+#[must_use]
+pub fn multisig_config_hash(signers: &[Pubkey], threshold: u8) -> [u8; 32] {
+    let mut data: Vec<&[u8]> = Vec::with_capacity(signers.len().saturating_add(1));
+    let threshold = [threshold];
+    data.push(&threshold);
+    data.extend(signers.iter().map(Pubkey::as_ref));
+
+    hashv(&data).to_bytes()
+}
+
+/// Tries to create the PDA for `Multisig` using the provided bump,
+/// falling back to `find_program_address` if the bump is `None` or invalid.
+#[must_use]
+pub fn multisig_pda(
+    program_id: &Pubkey,
+    signers: &[Pubkey],
+    threshold: u8,
+    maybe_bump: Option<u8>,
+) -> (Pubkey, u8) {
+    let config_hash = multisig_config_hash(signers, threshold);
+
+    maybe_bump
+        .and_then(|bump| {
+            Pubkey::create_program_address(
+                &[seed_prefixes::MULTISIG_SEED, config_hash.as_ref(), &[bump]],
+                program_id,
+            )
+            .map(|pubkey| (pubkey, bump))
+            .ok()
+        })
+        .unwrap_or_else(|| {
+            Pubkey::find_program_address(
+                &[seed_prefixes::MULTISIG_SEED, config_hash.as_ref()],
+                program_id,
+            )
+        })
+}
+
+/// Tries to create the PDA for `Multisig` using the provided bump,
+/// falling back to `find_program_address` if the bump is invalid.
+#[inline]
+#[must_use]
+pub fn create_multisig_pda(
+    program_id: &Pubkey,
+    signers: &[Pubkey],
+    threshold: u8,
+    bump: u8,
+) -> (Pubkey, u8) {
+    multisig_pda(program_id, signers, threshold, Some(bump))
+}
+
+/// Derives the PDA for a `Multisig` account.
+#[inline]
+#[must_use]
+pub fn find_multisig_pda(program_id: &Pubkey, signers: &[Pubkey], threshold: u8) -> (Pubkey, u8) {
+    multisig_pda(program_id, signers, threshold, None)
+}
@@ -2,31 +2,36 @@
 use program_utils::pda::{close_pda, BorshPda};
 use solana_program::account_info::AccountInfo;
 use solana_program::bpf_loader_upgradeable::UpgradeableLoaderState;
+use solana_program::clock::Clock;
 use solana_program::entrypoint::ProgramResult;
 use solana_program::program_error::ProgramError;
 use solana_program::pubkey::Pubkey;
+use solana_program::sysvar::Sysvar;
 use solana_program::{bpf_loader_upgradeable, msg};
 
 use crate::seed_prefixes;
-use crate::state::{RoleProposal, RolesFlags, UserRoles};
+use crate::state::{Multisig, RoleGrant, RoleIndex, RoleProposal, RolesFlags, UserRoles};
 
-/// Propose a role transfer from one user to another.
+/// Propose a role transfer from one user to another, optionally expiring at
+/// the given unix timestamp.
 ///
 /// # Errors
 ///
 /// [`ProgramError`] is returned as a result of failed operations.
 pub fn propose<F: RolesFlags>(
     program_id: &Pubkey,
-    accounts: RoleTransferWithProposalAccounts<'_>,
+    accounts: RoleProposeAccounts<'_>,
     roles: F,
-    required_payer_roles: F,
+    required_authority_roles: F,
+    expires_at: Option<i64>,
 ) -> ProgramResult {
-    ensure_signer_roles(
+    ensure_signer_or_multisig_roles(
         program_id,
         accounts.resource,
-        accounts.payer,
-        accounts.payer_roles_account,
-        required_payer_roles,
+        accounts.authority,
+        accounts.authority_roles_account,
+        required_authority_roles,
+        accounts.signers,
     )?;
 
     ensure_roles(
@@ -37,6 +42,12 @@ pub fn propose<F: RolesFlags>(
         roles,
     )?;
 
+    // Transferring roles held through a grant would make them permanent.
+    if !UserRoles::<F>::load(accounts.origin_roles_account)?.contains(roles) {
+        msg!("Only roles held permanently can be transferred");
+        return Err(ProgramError::InvalidArgument);
+    }
+
     ensure_proper_account::<F>(
         program_id,
         accounts.resource,
@@ -59,8 +70,14 @@ pub fn propose<F: RolesFlags>(
     let proposal = RoleProposal {
         roles,
         bump: proposal_pda_bump,
+        expires_at,
     };
 
+    if proposal.is_expired(Clock::get()?.unix_timestamp) {
+        msg!("Trying to create a proposal that is already expired");
+        return Err(ProgramError::InvalidArgument);
+    }
+
     proposal.init(
         program_id,
         accounts.system_account,
@@ -87,7 +104,7 @@ pub fn accept<F: RolesFlags>(
     program_id: &Pubkey,
     accounts: RoleTransferWithProposalAccounts<'_>,
     roles: F,
-    required_payer_roles: F,
+    required_authority_roles: F,
 ) -> ProgramResult {
     let proposal_pda_bump = RoleProposal::<F>::load(accounts.proposal_account)?.bump;
     let (derived_proposal_pda, _) = crate::create_roles_proposal_pda(
@@ -103,22 +120,37 @@ pub fn accept<F: RolesFlags>(
         return Err(ProgramError::InvalidArgument);
     }
 
+    if accounts.authority.key != accounts.destination_user_account.key {
+        msg!("Only the user the roles were proposed to can accept them");
+        return Err(ProgramError::InvalidArgument);
+    }
+
     let proposal = RoleProposal::<F>::load(accounts.proposal_account)?;
     if !proposal.roles.contains(roles) {
         msg!("Trying to accept a role that hasn't been proposed");
         return Err(ProgramError::InvalidArgument);
     }
 
+    if proposal.is_expired(Clock::get()?.unix_timestamp) {
+        msg!("Role proposal has expired");
+        return Err(ProgramError::InvalidArgument);
+    }
+
     let proposal_account = accounts.proposal_account;
     let role_remove_accounts = RoleRemoveAccounts::from(accounts);
     let role_add_accounts = RoleAddAccounts::from(accounts);
 
-    add(program_id, role_add_accounts, roles, required_payer_roles)?;
+    add(
+        program_id,
+        role_add_accounts,
+        roles,
+        required_authority_roles,
+    )?;
     remove(
         program_id,
         role_remove_accounts,
         roles,
-        required_payer_roles,
+        required_authority_roles,
     )?;
 
     close_pda(accounts.origin_user_account, proposal_account)?;
@@ -126,8 +158,56 @@ pub fn accept<F: RolesFlags>(
     Ok(())
 }
 
+/// Cancel a role transfer proposal, returning its rent to the user the roles
+/// were proposed from.
+///
+/// The proposer, either signing or a [`Multisig`] approved by enough of its
+/// members, can cancel the proposal at any time, anyone else only once it has
+/// expired.
+///
+/// # Errors
+///
+/// [`ProgramError`] is returned as a result of failed operations.
+pub fn cancel<F: RolesFlags>(
+    program_id: &Pubkey,
+    accounts: RoleCancelProposalAccounts<'_>,
+) -> ProgramResult {
+    let proposal = RoleProposal::<F>::load(accounts.proposal_account)?;
+    let (derived_proposal_pda, _) = crate::create_roles_proposal_pda(
+        program_id,
+        accounts.resource.key,
+        accounts.origin_user_account.key,
+        accounts.destination_user_account.key,
+        proposal.bump,
+    );
+
+    if derived_proposal_pda != *accounts.proposal_account.key {
+        msg!("Derived PDA doesn't match given proposal account address");
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let is_proposer = (accounts.payer.is_signer
+        && accounts.payer.key == accounts.origin_user_account.key)
+        || (accounts.origin_user_account.owner == program_id
+            && ensure_multisig_approval(
+                program_id,
+                accounts.origin_user_account,
+                accounts.signers,
+            )
+            .is_ok());
+    if !is_proposer && !proposal.is_expired(Clock::get()?.unix_timestamp) {
+        msg!("Only the proposer can cancel a proposal before it expires");
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    close_pda(accounts.origin_user_account, accounts.proposal_account)
+}
+
 /// Add roles to a user.
 ///
+/// The authority needs to hold the required roles permanently, so roles held
+/// through a grant can't be handed over for good.
+///
 /// # Errors
 ///
 /// [`ProgramError`] is returned as a result of failed operations.
@@ -135,15 +215,17 @@ pub fn add<F: RolesFlags>(
     program_id: &Pubkey,
     accounts: RoleAddAccounts<'_>,
     roles: F,
-    required_payer_roles: F,
+    required_authority_roles: F,
 ) -> ProgramResult {
-    ensure_signer_roles(
+    ensure_signer_or_multisig_roles(
         program_id,
         accounts.resource,
-        accounts.payer,
-        accounts.payer_roles_account,
-        required_payer_roles,
+        accounts.authority,
+        accounts.authority_roles_account,
+        required_authority_roles,
+        accounts.signers,
     )?;
+    ensure_permanent_roles(accounts.authority_roles_account, required_authority_roles)?;
 
     ensure_proper_account::<F>(
         program_id,
@@ -152,8 +234,111 @@ pub fn add<F: RolesFlags>(
         accounts.destination_roles_account,
     )?;
 
+    update_or_init_roles(program_id, accounts, |user_roles| user_roles.add(roles))
+}
+
+/// Grant roles to a user for a limited time or number of uses.
+///
+/// The authority needs to hold the required roles permanently, roles held
+/// through a grant don't allow granting roles.
+///
+/// # Errors
+///
+/// [`ProgramError`] is returned as a result of failed operations.
+pub fn grant<F: RolesFlags>(
+    program_id: &Pubkey,
+    accounts: RoleAddAccounts<'_>,
+    grant: RoleGrant<F>,
+    required_authority_roles: F,
+) -> ProgramResult {
+    ensure_signer_or_multisig_roles(
+        program_id,
+        accounts.resource,
+        accounts.authority,
+        accounts.authority_roles_account,
+        required_authority_roles,
+        accounts.signers,
+    )?;
+
+    ensure_permanent_roles(accounts.authority_roles_account, required_authority_roles)?;
+
+    ensure_proper_account::<F>(
+        program_id,
+        accounts.resource,
+        accounts.destination_user_account,
+        accounts.destination_roles_account,
+    )?;
+
+    if grant.roles.is_empty() {
+        msg!("Trying to grant an empty set of roles");
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    if !grant.is_active(Clock::get()?.unix_timestamp) {
+        msg!("Trying to grant roles that are already expired");
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    update_or_init_roles(program_id, accounts, |user_roles| user_roles.grant(grant))
+}
+
+/// Remove roles from a user.
+///
+/// # Errors
+///
+/// [`ProgramError`] is returned as a result of failed operations.
+pub fn remove<F: RolesFlags>(
+    program_id: &Pubkey,
+    accounts: RoleRemoveAccounts<'_>,
+    roles: F,
+    required_authority_roles: F,
+) -> ProgramResult {
+    ensure_signer_or_multisig_roles(
+        program_id,
+        accounts.resource,
+        accounts.authority,
+        accounts.authority_roles_account,
+        required_authority_roles,
+        accounts.signers,
+    )?;
+
+    ensure_proper_account::<F>(
+        program_id,
+        accounts.resource,
+        accounts.origin_user_account,
+        accounts.origin_roles_account,
+    )?;
+
+    if let Ok(mut destination_user_roles) = UserRoles::load(accounts.origin_roles_account) {
+        destination_user_roles.remove(roles);
+        destination_user_roles.store(
+            accounts.payer,
+            accounts.origin_roles_account,
+            accounts.system_account,
+        )?;
+    } else {
+        msg!("Trying to remove roles from a user that doesn't have any");
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    update_index::<F>(
+        program_id,
+        accounts.system_account,
+        accounts.payer,
+        accounts.resource,
+        accounts.origin_user_account,
+        accounts.origin_roles_account,
+        accounts.index_account,
+    )
+}
+
+fn update_or_init_roles<F: RolesFlags>(
+    program_id: &Pubkey,
+    accounts: RoleAddAccounts<'_>,
+    update: impl FnOnce(&mut UserRoles<F>),
+) -> ProgramResult {
     if let Ok(mut destination_user_roles) = UserRoles::load(accounts.destination_roles_account) {
-        destination_user_roles.add(roles);
+        update(&mut destination_user_roles);
         destination_user_roles.store(
             accounts.payer,
             accounts.destination_roles_account,
@@ -178,7 +363,9 @@ pub fn add<F: RolesFlags>(
             &[destination_roles_pda_bump],
         ];
 
-        UserRoles::new(roles, destination_roles_pda_bump).init(
+        let mut destination_user_roles = UserRoles::new(F::empty(), destination_roles_pda_bump);
+        update(&mut destination_user_roles);
+        destination_user_roles.init(
             program_id,
             accounts.system_account,
             accounts.payer,
@@ -187,52 +374,159 @@ pub fn add<F: RolesFlags>(
         )?;
     }
 
-    Ok(())
+    update_index::<F>(
+        program_id,
+        accounts.system_account,
+        accounts.payer,
+        accounts.resource,
+        accounts.destination_user_account,
+        accounts.destination_roles_account,
+        accounts.index_account,
+    )
 }
 
-/// Remove roles from a user.
+/// Record the current roles of a user in the role index of the resource,
+/// creating the index if needed.
+///
+/// Anyone can call this, as the roles are read from the roles account of the
+/// user. This allows indexing roles that were set up without going through
+/// this crate, for example when a resource is created.
 ///
 /// # Errors
 ///
 /// [`ProgramError`] is returned as a result of failed operations.
-pub fn remove<F: RolesFlags>(
+pub fn sync_index<F: RolesFlags>(
     program_id: &Pubkey,
-    accounts: RoleRemoveAccounts<'_>,
-    roles: F,
-    required_payer_roles: F,
+    accounts: RoleSyncIndexAccounts<'_>,
 ) -> ProgramResult {
-    ensure_signer_roles(
+    ensure_proper_account::<F>(
         program_id,
         accounts.resource,
-        accounts.payer,
-        accounts.payer_roles_account,
-        required_payer_roles,
+        accounts.user_account,
+        accounts.roles_account,
     )?;
 
+    update_index::<F>(
+        program_id,
+        accounts.system_account,
+        accounts.payer,
+        accounts.resource,
+        accounts.user_account,
+        accounts.roles_account,
+        accounts.index_account,
+    )
+}
+
+fn update_index<'a, F: RolesFlags>(
+    program_id: &Pubkey,
+    system_account: &AccountInfo<'a>,
+    payer: &AccountInfo<'a>,
+    resource: &AccountInfo<'a>,
+    user: &AccountInfo<'a>,
+    roles_account: &AccountInfo<'a>,
+    index_account: &AccountInfo<'a>,
+) -> ProgramResult {
+    let roles = if roles_account.data_is_empty() {
+        F::empty()
+    } else {
+        UserRoles::<F>::load(roles_account)?.all_roles()
+    };
+
+    if index_account.data_is_empty() {
+        if roles.is_empty() {
+            return Ok(());
+        }
+
+        let (index_pda, index_pda_bump) = crate::find_role_index_pda(program_id, resource.key);
+        if index_pda != *index_account.key {
+            msg!("Derived PDA doesn't match given role index account address");
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        let mut index = RoleIndex::new(index_pda_bump);
+        index.set(*user.key, roles);
+        index.init(
+            program_id,
+            system_account,
+            payer,
+            index_account,
+            &[
+                seed_prefixes::ROLE_INDEX_SEED,
+                resource.key.as_ref(),
+                &[index_pda_bump],
+            ],
+        )
+    } else {
+        let mut index = RoleIndex::<F>::load(index_account)?;
+        let (index_pda, _) = crate::create_role_index_pda(program_id, resource.key, index.bump());
+        if index_pda != *index_account.key {
+            msg!("Derived PDA doesn't match given role index account address");
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        index.set(*user.key, roles);
+        index.store(payer, index_account, system_account)
+    }
+}
+
+/// Remove the expired and exhausted grants of a user, closing the roles
+/// account if the user doesn't hold any roles anymore. The rent of a closed
+/// account is returned to the user.
+///
+/// Anyone can call this, as it only removes roles that are no longer in
+/// effect.
+///
+/// # Errors
+///
+/// [`ProgramError`] is returned as a result of failed operations.
+pub fn cleanup<F: RolesFlags>(
+    program_id: &Pubkey,
+    accounts: RoleCleanupAccounts<'_>,
+) -> ProgramResult {
     ensure_proper_account::<F>(
         program_id,
         accounts.resource,
-        accounts.origin_user_account,
-        accounts.origin_roles_account,
+        accounts.user_account,
+        accounts.roles_account,
     )?;
 
-    if let Ok(mut destination_user_roles) = UserRoles::load(accounts.origin_roles_account) {
-        destination_user_roles.remove(roles);
-        destination_user_roles.store(
+    let Ok(mut user_roles) = UserRoles::<F>::load(accounts.roles_account) else {
+        msg!("User roles account not found");
+        return Err(ProgramError::InvalidArgument);
+    };
+
+    if !user_roles.prune(Clock::get()?.unix_timestamp) {
+        msg!("User doesn't have any expired roles");
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    if user_roles.is_empty() {
+        close_pda(accounts.user_account, accounts.roles_account)?;
+    } else {
+        user_roles.store(
             accounts.payer,
-            accounts.origin_roles_account,
+            accounts.roles_account,
             accounts.system_account,
         )?;
-    } else {
-        msg!("Trying to remove roles from a user that doesn't have any");
-        return Err(ProgramError::InvalidArgument);
     }
 
-    Ok(())
+    update_index::<F>(
+        program_id,
+        accounts.system_account,
+        accounts.payer,
+        accounts.resource,
+        accounts.user_account,
+        accounts.roles_account,
+        accounts.index_account,
+    )
 }
 
 /// Ensure a user has the required roles on a resource.
 ///
+/// Roles held through grants with a limited number of uses are used up, see
+/// [`UserRoles::use_roles`], in which case the roles account needs to be
+/// writable.
+///
 /// # Errors
 ///
 /// [`ProgramError`] is returned as a result of failed operations.
@@ -243,7 +537,7 @@ pub fn ensure_roles<F: RolesFlags>(
     roles_account: &AccountInfo<'_>,
     roles: F,
 ) -> ProgramResult {
-    let Ok(user_roles) = UserRoles::load(roles_account) else {
+    let Ok(mut user_roles) = UserRoles::load(roles_account) else {
         if roles.eq(&F::empty()) {
             return Ok(());
         }
@@ -252,13 +546,33 @@ pub fn ensure_roles<F: RolesFlags>(
         return Err(ProgramError::InvalidArgument);
     };
 
-    if !user_roles.contains(roles) {
+    let held_permanently = user_roles.contains(roles);
+    if !held_permanently
+        && (user_roles.grants().is_empty()
+            || !user_roles.use_roles(roles, Clock::get()?.unix_timestamp))
+    {
         msg!("User doesn't have the required roles");
         return Err(ProgramError::InvalidArgument);
     }
 
     ensure_proper_account::<F>(program_id, resource, user, roles_account)?;
 
+    if held_permanently {
+        return Ok(());
+    }
+
+    let serialized_data = borsh::to_vec(&user_roles)?;
+    if **roles_account.try_borrow_data()? == *serialized_data {
+        return Ok(());
+    }
+
+    // Using up grants doesn't change the size of the account, no rent is needed.
+    let mut account_data = roles_account.try_borrow_mut_data()?;
+    if account_data.len() != serialized_data.len() {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    account_data.copy_from_slice(&serialized_data);
+
     Ok(())
 }
 
@@ -281,6 +595,120 @@ pub fn ensure_signer_roles<F: RolesFlags>(
     ensure_roles(program_id, resource, signer, roles_account, roles)
 }
 
+/// Ensure a user has the required roles on a resource and is either a signer
+/// or a [`Multisig`] approved by enough of its members among the signers of
+/// the given accounts.
+///
+/// # Errors
+///
+/// [`ProgramError`] is returned as a result of failed operations.
+pub fn ensure_signer_or_multisig_roles<F: RolesFlags>(
+    program_id: &Pubkey,
+    resource: &AccountInfo<'_>,
+    signer: &AccountInfo<'_>,
+    roles_account: &AccountInfo<'_>,
+    roles: F,
+    accounts: &[AccountInfo<'_>],
+) -> ProgramResult {
+    if !signer.is_signer {
+        ensure_multisig_approval(program_id, signer, accounts)?;
+    }
+
+    ensure_roles(program_id, resource, signer, roles_account, roles)
+}
+
+/// Ensure the roles are held permanently, and not only through a grant for a
+/// limited time or number of uses.
+fn ensure_permanent_roles<F: RolesFlags>(
+    roles_account: &AccountInfo<'_>,
+    roles: F,
+) -> ProgramResult {
+    if !roles.is_empty() && !UserRoles::<F>::load(roles_account)?.contains(roles) {
+        msg!("Only roles held permanently allow handing over roles");
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    Ok(())
+}
+
+/// Create a [`Multisig`] which can hold roles on any resource of the program.
+///
+/// # Errors
+///
+/// [`ProgramError`] is returned as a result of failed operations.
+pub fn create_multisig(
+    program_id: &Pubkey,
+    accounts: MultisigCreateAccounts<'_>,
+    signers: Vec<Pubkey>,
+    threshold: u8,
+) -> ProgramResult {
+    let (multisig_pda, multisig_pda_bump) =
+        crate::find_multisig_pda(program_id, &signers, threshold);
+
+    if multisig_pda != *accounts.multisig_account.key {
+        msg!("Derived PDA doesn't match given multisig account address");
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let config_hash = crate::multisig_config_hash(&signers, threshold);
+    let multisig = Multisig::new(signers, threshold, multisig_pda_bump)?;
+
+    multisig.init(
+        program_id,
+        accounts.system_account,
+        accounts.payer,
+        accounts.multisig_account,
+        &[
+            seed_prefixes::MULTISIG_SEED,
+            config_hash.as_ref(),
+            &[multisig_pda_bump],
+        ],
+    )
+}
+
+/// Ensure the given account is a [`Multisig`] approved by enough of its
+/// members among the signers of the given accounts.
+///
+/// # Errors
+///
+/// If the account isn't a multisig of the program or the threshold isn't
+/// reached.
+pub fn ensure_multisig_approval(
+    program_id: &Pubkey,
+    multisig_account: &AccountInfo<'_>,
+    accounts: &[AccountInfo<'_>],
+) -> ProgramResult {
+    if multisig_account.owner != program_id || multisig_account.data_is_empty() {
+        msg!("Account is neither a signer nor a multisig");
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let multisig = Multisig::load(multisig_account)?;
+    let (derived_pda, _) = crate::create_multisig_pda(
+        program_id,
+        multisig.signers(),
+        multisig.threshold(),
+        multisig.bump(),
+    );
+
+    if derived_pda != *multisig_account.key {
+        msg!("Derived PDA doesn't match given multisig account address");
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let signers = accounts
+        .iter()
+        .filter(|account| account.is_signer)
+        .map(|account| account.key);
+
+    if !multisig.is_approved_by(signers) {
+        msg!("Multisig threshold not reached");
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    Ok(())
+}
+
 /// Ensure the given account is the upgrade authority of the program.
 ///
 /// This is the Solana equivalent of a contract owner.
@@ -361,17 +789,43 @@ pub fn ensure_proper_account<F: RolesFlags>(
     Ok(())
 }
 
+#[derive(Debug, Clone, Copy)]
+pub struct RoleProposeAccounts<'a> {
+    pub system_account: &'a AccountInfo<'a>,
+    pub payer: &'a AccountInfo<'a>,
+    /// The account holding the roles required for the change, either a signer
+    /// or a [`Multisig`] approved by enough of its members.
+    pub authority: &'a AccountInfo<'a>,
+    pub authority_roles_account: &'a AccountInfo<'a>,
+    /// The accounts checked for the signatures of the members of the
+    /// authority, when it's a [`Multisig`].
+    pub signers: &'a [AccountInfo<'a>],
+    pub resource: &'a AccountInfo<'a>,
+    pub destination_user_account: &'a AccountInfo<'a>,
+    pub destination_roles_account: &'a AccountInfo<'a>,
+    pub origin_user_account: &'a AccountInfo<'a>,
+    pub origin_roles_account: &'a AccountInfo<'a>,
+    pub proposal_account: &'a AccountInfo<'a>,
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct RoleTransferWithProposalAccounts<'a> {
     pub system_account: &'a AccountInfo<'a>,
     pub payer: &'a AccountInfo<'a>,
-    pub payer_roles_account: &'a AccountInfo<'a>,
+    /// The account holding the roles required for the change, either a signer
+    /// or a [`Multisig`] approved by enough of its members.
+    pub authority: &'a AccountInfo<'a>,
+    pub authority_roles_account: &'a AccountInfo<'a>,
+    /// The accounts checked for the signatures of the members of the
+    /// authority, when it's a [`Multisig`].
+    pub signers: &'a [AccountInfo<'a>],
     pub resource: &'a AccountInfo<'a>,
     pub destination_user_account: &'a AccountInfo<'a>,
     pub destination_roles_account: &'a AccountInfo<'a>,
     pub origin_user_account: &'a AccountInfo<'a>,
     pub origin_roles_account: &'a AccountInfo<'a>,
     pub proposal_account: &'a AccountInfo<'a>,
+    pub index_account: &'a AccountInfo<'a>,
 }
 
 impl<'a> From<RoleTransferWithProposalAccounts<'a>> for RoleRemoveAccounts<'a> {
@@ -379,10 +833,13 @@ impl<'a> From<RoleTransferWithProposalAccounts<'a>> for RoleRemoveAccounts<'a> {
         Self {
             system_account: value.system_account,
             payer: value.payer,
-            payer_roles_account: value.payer_roles_account,
+            authority: value.authority,
+            authority_roles_account: value.authority_roles_account,
+            signers: value.signers,
             resource: value.resource,
             origin_user_account: value.origin_user_account,
             origin_roles_account: value.origin_roles_account,
+            index_account: value.index_account,
         }
     }
 }
@@ -392,32 +849,88 @@ impl<'a> From<RoleTransferWithProposalAccounts<'a>> for RoleAddAccounts<'a> {
         Self {
             system_account: value.system_account,
             payer: value.payer,
-            payer_roles_account: value.payer_roles_account,
+            authority: value.authority,
+            authority_roles_account: value.authority_roles_account,
+            signers: value.signers,
             resource: value.resource,
             destination_user_account: value.destination_user_account,
             destination_roles_account: value.destination_roles_account,
+            index_account: value.index_account,
         }
     }
 }
 
+#[derive(Debug, Clone, Copy)]
+pub struct RoleCancelProposalAccounts<'a> {
+    pub payer: &'a AccountInfo<'a>,
+    /// The accounts checked for the signatures of the members of the proposer,
+    /// when it's a [`Multisig`].
+    pub signers: &'a [AccountInfo<'a>],
+    pub resource: &'a AccountInfo<'a>,
+    pub origin_user_account: &'a AccountInfo<'a>,
+    pub destination_user_account: &'a AccountInfo<'a>,
+    pub proposal_account: &'a AccountInfo<'a>,
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct RoleAddAccounts<'a> {
     pub system_account: &'a AccountInfo<'a>,
     pub payer: &'a AccountInfo<'a>,
-    pub payer_roles_account: &'a AccountInfo<'a>,
+    /// The account holding the roles required for the change, either a signer
+    /// or a [`Multisig`] approved by enough of its members.
+    pub authority: &'a AccountInfo<'a>,
+    pub authority_roles_account: &'a AccountInfo<'a>,
+    /// The accounts checked for the signatures of the members of the
+    /// authority, when it's a [`Multisig`].
+    pub signers: &'a [AccountInfo<'a>],
     pub resource: &'a AccountInfo<'a>,
     pub destination_user_account: &'a AccountInfo<'a>,
     pub destination_roles_account: &'a AccountInfo<'a>,
+    pub index_account: &'a AccountInfo<'a>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct RoleCleanupAccounts<'a> {
+    pub system_account: &'a AccountInfo<'a>,
+    pub payer: &'a AccountInfo<'a>,
+    pub resource: &'a AccountInfo<'a>,
+    pub user_account: &'a AccountInfo<'a>,
+    pub roles_account: &'a AccountInfo<'a>,
+    pub index_account: &'a AccountInfo<'a>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct RoleSyncIndexAccounts<'a> {
+    pub system_account: &'a AccountInfo<'a>,
+    pub payer: &'a AccountInfo<'a>,
+    pub resource: &'a AccountInfo<'a>,
+    pub user_account: &'a AccountInfo<'a>,
+    pub roles_account: &'a AccountInfo<'a>,
+    pub index_account: &'a AccountInfo<'a>,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct MultisigCreateAccounts<'a> {
+    pub system_account: &'a AccountInfo<'a>,
+    pub payer: &'a AccountInfo<'a>,
+    pub multisig_account: &'a AccountInfo<'a>,
 }
 
 #[derive(Debug, Clone, Copy)]
 pub struct RoleRemoveAccounts<'a> {
     pub system_account: &'a AccountInfo<'a>,
     pub payer: &'a AccountInfo<'a>,
-    pub payer_roles_account: &'a AccountInfo<'a>,
+    /// The account holding the roles required for the change, either a signer
+    /// or a [`Multisig`] approved by enough of its members.
+    pub authority: &'a AccountInfo<'a>,
+    pub authority_roles_account: &'a AccountInfo<'a>,
+    /// The accounts checked for the signatures of the members of the
+    /// authority, when it's a [`Multisig`].
+    pub signers: &'a [AccountInfo<'a>],
     pub resource: &'a AccountInfo<'a>,
     pub origin_user_account: &'a AccountInfo<'a>,
     pub origin_roles_account: &'a AccountInfo<'a>,
+    pub index_account: &'a AccountInfo<'a>,
 }
 
 #[cfg(test)]
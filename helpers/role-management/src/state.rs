@@ -5,11 +5,12 @@ use core::mem::size_of;
 
 use bitflags::Flags;
 use borsh::{BorshDeserialize, BorshSerialize};
-use program_utils::pda::BorshPda;
+use program_utils::pda::{deserialize_or_default, BorshPda};
 use solana_program::{
     msg,
     program_error::ProgramError,
     program_pack::{Pack, Sealed},
+    pubkey::Pubkey,
 };
 
 /// Flags representing the roles that can be assigned to a user. Users shouldn't
@@ -38,11 +39,15 @@ where
 }
 
 /// Roles assigned to a user on a specific resource.
-#[derive(Debug, Eq, PartialEq, Clone, BorshSerialize, BorshDeserialize)]
+///
+/// Besides the roles held permanently, a user can hold [`RoleGrant`]s which
+/// expire at a given time or after a given number of uses.
+#[derive(Debug, Eq, PartialEq, Clone, BorshSerialize)]
 #[non_exhaustive]
 pub struct UserRoles<F: RolesFlags> {
     roles: F,
     bump: u8,
+    grants: Vec<RoleGrant<F>>,
 }
 
 impl<F> UserRoles<F>
@@ -52,24 +57,121 @@ where
     /// Creates a new instance of `UserRoles`.
     #[must_use]
     pub const fn new(roles: F, bump: u8) -> Self {
-        Self { roles, bump }
+        Self {
+            roles,
+            bump,
+            grants: Vec::new(),
+        }
     }
 
-    /// Checks if the user has the provided role.
+    /// Checks if the user permanently holds the provided role.
+    ///
+    /// Use [`Self::contains_at`] to also take the grants of the user into
+    /// account.
     #[must_use]
     pub fn contains(&self, role: F) -> bool {
         self.roles.contains(role)
     }
 
+    /// Checks if the user holds the provided role at the given timestamp,
+    /// either permanently or through an active grant.
+    #[must_use]
+    pub fn contains_at(&self, role: F, timestamp: i64) -> bool {
+        self.grants
+            .iter()
+            .filter(|grant| grant.is_active(timestamp))
+            .fold(self.roles, |roles, grant| roles.union(grant.roles))
+            .contains(role)
+    }
+
     /// Adds a role to the user.
     pub fn add(&mut self, role: F) {
         self.roles.insert(role);
     }
 
-    /// Removes a role from the user.
+    /// Grants roles to the user for a limited time or number of uses.
+    pub fn grant(&mut self, grant: RoleGrant<F>) {
+        self.grants.push(grant);
+    }
+
+    /// Removes a role from the user, including from any grants.
     #[allow(clippy::arithmetic_side_effects)]
     pub fn remove(&mut self, role: F) {
         self.roles.remove(role);
+
+        for grant in &mut self.grants {
+            grant.roles.remove(role);
+        }
+
+        self.grants.retain(|grant| !grant.roles.is_empty());
+    }
+
+    /// Uses the provided role at the given timestamp, decrementing the
+    /// remaining uses of the grants it is held through.
+    ///
+    /// Roles held permanently or through grants without a usage limit are
+    /// used first. Returns `false`, without using any grant, if the user
+    /// doesn't hold the role.
+    pub fn use_roles(&mut self, role: F, timestamp: i64) -> bool {
+        if !self.contains_at(role, timestamp) {
+            return false;
+        }
+
+        let mut missing = self
+            .grants
+            .iter()
+            .filter(|grant| grant.is_active(timestamp) && grant.remaining_uses.is_none())
+            .fold(role.difference(self.roles), |missing, grant| {
+                missing.difference(grant.roles)
+            });
+
+        for grant in &mut self.grants {
+            if missing.is_empty() {
+                break;
+            }
+
+            if grant.is_active(timestamp) && grant.roles.intersects(missing) {
+                if let Some(remaining_uses) = grant.remaining_uses.as_mut() {
+                    *remaining_uses = remaining_uses.saturating_sub(1);
+                }
+
+                missing.remove(grant.roles);
+            }
+        }
+
+        true
+    }
+
+    /// Removes the grants that are no longer active at the given timestamp.
+    ///
+    /// Returns whether any grant was removed.
+    pub fn prune(&mut self, timestamp: i64) -> bool {
+        let grants = self.grants.len();
+        self.grants.retain(|grant| grant.is_active(timestamp));
+
+        self.grants.len() != grants
+    }
+
+    /// The grants held by the user, including the inactive ones that haven't
+    /// been pruned yet.
+    #[must_use]
+    pub fn grants(&self) -> &[RoleGrant<F>] {
+        &self.grants
+    }
+
+    /// The roles held permanently together with the roles of all grants,
+    /// whether they are still active or not.
+    #[must_use]
+    pub fn all_roles(&self) -> F {
+        self.grants
+            .iter()
+            .fold(self.roles, |roles, grant| roles.union(grant.roles))
+    }
+
+    /// Whether the user holds no roles at all.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.roles.is_empty() && self.grants.is_empty()
     }
 
     /// The bump associated with the PDA where this data is stored.
@@ -79,11 +181,71 @@ where
     }
 }
 
+/// Roles granted to a user for a limited time or a limited number of uses.
+#[derive(Debug, Eq, PartialEq, Copy, Clone, BorshSerialize, BorshDeserialize)]
+pub struct RoleGrant<F: RolesFlags> {
+    /// The granted roles.
+    pub roles: F,
+
+    /// Unix timestamp from which the roles are no longer granted. `None` means
+    /// the grant doesn't expire.
+    pub expires_at: Option<i64>,
+
+    /// The number of times the roles can still be used. `None` means the
+    /// number of uses is not limited.
+    pub remaining_uses: Option<u64>,
+}
+
+impl<F> RoleGrant<F>
+where
+    F: RolesFlags,
+{
+    /// Creates a new instance of `RoleGrant`.
+    #[must_use]
+    pub const fn new(roles: F, expires_at: Option<i64>, remaining_uses: Option<u64>) -> Self {
+        Self {
+            roles,
+            expires_at,
+            remaining_uses,
+        }
+    }
+
+    /// Whether the grant is in effect at the given timestamp.
+    #[must_use]
+    pub fn is_active(&self, timestamp: i64) -> bool {
+        let not_expired = self
+            .expires_at
+            .is_none_or(|expires_at| timestamp < expires_at);
+        let not_exhausted = self.remaining_uses != Some(0);
+
+        not_expired && not_exhausted
+    }
+}
+
+impl<F> BorshDeserialize for UserRoles<F>
+where
+    F: RolesFlags,
+{
+    fn deserialize_reader<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let roles = F::deserialize_reader(reader)?;
+        let bump = u8::deserialize_reader(reader)?;
+        // Accounts created before grants were introduced end here.
+        let grants = deserialize_or_default(reader)?;
+
+        Ok(Self {
+            roles,
+            bump,
+            grants,
+        })
+    }
+}
+
 impl<F> Pack for UserRoles<F>
 where
     F: RolesFlags,
 {
-    const LEN: usize = size_of::<F>() + size_of::<u8>();
+    /// The length of a `UserRoles` without grants.
+    const LEN: usize = size_of::<F>() + size_of::<u8>() + size_of::<u32>();
 
     #[allow(clippy::unwrap_used)]
     fn pack_into_slice(&self, mut dst: &mut [u8]) {
@@ -107,20 +269,55 @@ impl<F> Sealed for UserRoles<F> where F: RolesFlags {}
 impl<F> BorshPda for UserRoles<F> where F: RolesFlags {}
 
 /// Proposal to transfer roles to a user.
-#[derive(Debug, Eq, PartialEq, Copy, Clone, BorshSerialize, BorshDeserialize)]
+#[derive(Debug, Eq, PartialEq, Copy, Clone, BorshSerialize)]
 pub struct RoleProposal<F: RolesFlags> {
     /// The roles to be transferred.
     pub roles: F,
 
     /// The bump associated with the PDA where this data is stored.
     pub bump: u8,
+
+    /// The unix timestamp after which the proposal can no longer be accepted,
+    /// if any.
+    pub expires_at: Option<i64>,
+}
+
+impl<F> RoleProposal<F>
+where
+    F: RolesFlags,
+{
+    /// Whether the proposal has expired at the given timestamp.
+    #[must_use]
+    pub fn is_expired(&self, timestamp: i64) -> bool {
+        self.expires_at
+            .is_some_and(|expires_at| timestamp >= expires_at)
+    }
+}
+
+impl<F> BorshDeserialize for RoleProposal<F>
+where
+    F: RolesFlags,
+{
+    fn deserialize_reader<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let roles = F::deserialize_reader(reader)?;
+        let bump = u8::deserialize_reader(reader)?;
+        // Proposals created before expiry was introduced end here.
+        let expires_at = deserialize_or_default(reader)?;
+
+        Ok(Self {
+            roles,
+            bump,
+            expires_at,
+        })
+    }
 }
 
 impl<F> Pack for RoleProposal<F>
 where
     F: RolesFlags,
 {
-    const LEN: usize = size_of::<F>();
+    /// The length of a `RoleProposal` with an expiry.
+    const LEN: usize = size_of::<F>() + size_of::<u8>() + size_of::<u8>() + size_of::<i64>();
 
     #[allow(clippy::unwrap_used)]
     fn pack_into_slice(&self, mut dst: &mut [u8]) {
@@ -143,6 +340,177 @@ where
 impl<F> Sealed for RoleProposal<F> where F: RolesFlags {}
 impl<F> BorshPda for RoleProposal<F> where F: RolesFlags {}
 
+/// Index of the users holding roles on a resource, allowing the roles on a
+/// resource to be enumerated without scanning all the accounts of the program.
+///
+/// The roles of a user in the index include the roles of all their grants,
+/// so users whose grants expired remain listed until their roles are cleaned
+/// up.
+#[derive(Debug, Eq, PartialEq, Clone, BorshSerialize, BorshDeserialize)]
+pub struct RoleIndex<F: RolesFlags> {
+    holders: Vec<RoleHolder<F>>,
+    bump: u8,
+}
+
+impl<F> RoleIndex<F>
+where
+    F: RolesFlags,
+{
+    /// Creates a new, empty, instance of `RoleIndex`.
+    #[must_use]
+    pub const fn new(bump: u8) -> Self {
+        Self {
+            holders: Vec::new(),
+            bump,
+        }
+    }
+
+    /// The users holding roles on the resource.
+    #[must_use]
+    pub fn holders(&self) -> &[RoleHolder<F>] {
+        &self.holders
+    }
+
+    /// The roles of the given user, if the user is listed.
+    #[must_use]
+    pub fn roles_of(&self, user: &Pubkey) -> Option<F> {
+        self.holders
+            .iter()
+            .find(|holder| holder.user == *user)
+            .map(|holder| holder.roles)
+    }
+
+    /// Records the roles of a user, removing the user from the index if the
+    /// roles are empty.
+    pub fn set(&mut self, user: Pubkey, roles: F) {
+        let position = self.holders.iter().position(|holder| holder.user == user);
+
+        match (position, roles.is_empty()) {
+            (Some(position), true) => {
+                self.holders.remove(position);
+            }
+            (Some(position), false) => {
+                if let Some(holder) = self.holders.get_mut(position) {
+                    holder.roles = roles;
+                }
+            }
+            (None, false) => self.holders.push(RoleHolder { user, roles }),
+            (None, true) => {}
+        }
+    }
+
+    /// The bump associated with the PDA where this data is stored.
+    #[must_use]
+    pub const fn bump(&self) -> u8 {
+        self.bump
+    }
+}
+
+impl<F> BorshPda for RoleIndex<F> where F: RolesFlags {}
+
+/// A user listed in a [`RoleIndex`].
+#[derive(Debug, Eq, PartialEq, Copy, Clone, BorshSerialize, BorshDeserialize)]
+pub struct RoleHolder<F: RolesFlags> {
+    /// The user holding the roles.
+    pub user: Pubkey,
+
+    /// The roles held by the user.
+    pub roles: F,
+}
+
+/// A native M-of-N multisig which can hold roles like any other user.
+///
+/// The multisig is a PDA derived from its configuration, see
+/// [`crate::find_multisig_pda`], thus its members and threshold can't be
+/// changed. To rotate members, a new multisig needs to be created and the
+/// roles transferred to it.
+#[derive(Debug, Eq, PartialEq, Clone, BorshSerialize, BorshDeserialize)]
+pub struct Multisig {
+    signers: Vec<Pubkey>,
+    threshold: u8,
+    bump: u8,
+}
+
+impl Multisig {
+    /// The maximum number of members of a multisig.
+    pub const MAX_SIGNERS: usize = 11;
+
+    /// Creates a new instance of `Multisig`.
+    ///
+    /// # Errors
+    ///
+    /// If the threshold is zero or greater than the number of signers, if
+    /// there are more than [`Self::MAX_SIGNERS`] signers or if a signer is
+    /// duplicated.
+    pub fn new(signers: Vec<Pubkey>, threshold: u8, bump: u8) -> Result<Self, ProgramError> {
+        if threshold == 0 || usize::from(threshold) > signers.len() {
+            msg!("Multisig threshold must be between 1 and the number of signers");
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        if signers.len() > Self::MAX_SIGNERS {
+            msg!(
+                "Multisig can't have more than {} signers",
+                Self::MAX_SIGNERS
+            );
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        if signers.iter().enumerate().any(|(i, signer)| {
+            signers
+                .iter()
+                .skip(i.saturating_add(1))
+                .any(|s| s == signer)
+        }) {
+            msg!("Multisig signers must be unique");
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        Ok(Self {
+            signers,
+            threshold,
+            bump,
+        })
+    }
+
+    /// The members of the multisig.
+    #[must_use]
+    pub fn signers(&self) -> &[Pubkey] {
+        &self.signers
+    }
+
+    /// The number of members required to approve an action.
+    #[must_use]
+    pub const fn threshold(&self) -> u8 {
+        self.threshold
+    }
+
+    /// Whether the given keys include enough members to reach the threshold.
+    /// Keys are counted once, no matter how many times they're given.
+    #[must_use]
+    pub fn is_approved_by<'k, I>(&self, keys: I) -> bool
+    where
+        I: IntoIterator<Item = &'k Pubkey>,
+    {
+        let mut approved = Vec::with_capacity(self.signers.len());
+        for key in keys {
+            if self.signers.contains(key) && !approved.contains(&key) {
+                approved.push(key);
+            }
+        }
+
+        approved.len() >= usize::from(self.threshold)
+    }
+
+    /// The bump associated with the PDA where this data is stored.
+    #[must_use]
+    pub const fn bump(&self) -> u8 {
+        self.bump
+    }
+}
+
+impl BorshPda for Multisig {}
+
 #[cfg(test)]
 mod tests {
     use bitflags::bitflags;
@@ -192,10 +560,7 @@ mod tests {
 
     #[test]
     fn test_user_roles_round_trip() {
-        let original = UserRoles {
-            roles: Roles::MINTER | Roles::OPERATOR,
-            bump: 42,
-        };
+        let original = UserRoles::new(Roles::MINTER | Roles::OPERATOR, 42);
 
         let serialized = to_vec(&original).unwrap();
         let deserialized = UserRoles::<Roles>::try_from_slice(&serialized).unwrap();
@@ -206,6 +571,33 @@ mod tests {
         assert!(deserialized.contains(Roles::MINTER | Roles::OPERATOR));
     }
 
+    #[test]
+    fn test_user_roles_without_grants_deserializes() {
+        let serialized = to_vec(&(Roles::OPERATOR, 42_u8)).unwrap();
+        let deserialized = UserRoles::<Roles>::try_from_slice(&serialized).unwrap();
+
+        assert_eq!(deserialized, UserRoles::new(Roles::OPERATOR, 42));
+    }
+
+    #[test]
+    fn test_role_proposal_expiry() {
+        let legacy = to_vec(&(Roles::OPERATOR, 42_u8)).unwrap();
+        let proposal = RoleProposal::<Roles>::try_from_slice(&legacy).unwrap();
+        assert_eq!(proposal.expires_at, None);
+        assert!(!proposal.is_expired(i64::MAX));
+
+        let proposal = RoleProposal {
+            roles: Roles::OPERATOR,
+            bump: 42,
+            expires_at: Some(100),
+        };
+        let deserialized =
+            RoleProposal::<Roles>::try_from_slice(&to_vec(&proposal).unwrap()).unwrap();
+        assert_eq!(deserialized, proposal);
+        assert!(!proposal.is_expired(99));
+        assert!(proposal.is_expired(100));
+    }
+
     #[test]
     fn test_roles_bitflags() {
         let roles_list = vec![
@@ -219,7 +611,7 @@ mod tests {
         ];
 
         for roles in roles_list {
-            let original = UserRoles { roles, bump: 0 };
+            let original = UserRoles::new(roles, 0);
 
             let serialized = to_vec(&original).unwrap();
             let deserialized = UserRoles::<Roles>::try_from_slice(&serialized).unwrap();
@@ -227,4 +619,109 @@ mod tests {
             assert_eq!(original, deserialized);
         }
     }
+
+    #[test]
+    fn test_expired_grants_are_ignored() {
+        let mut user_roles = UserRoles::new(Roles::OPERATOR, 0);
+        user_roles.grant(RoleGrant::new(Roles::FLOW_LIMITER, Some(100), None));
+
+        assert!(!user_roles.contains(Roles::FLOW_LIMITER));
+        assert!(user_roles.contains_at(Roles::OPERATOR | Roles::FLOW_LIMITER, 99));
+        assert!(!user_roles.contains_at(Roles::FLOW_LIMITER, 100));
+
+        assert!(!user_roles.prune(99));
+        assert!(user_roles.prune(100));
+        assert!(user_roles.grants().is_empty());
+        assert!(user_roles.contains(Roles::OPERATOR));
+    }
+
+    #[test]
+    fn test_use_roles_consumes_limited_grants_last() {
+        let mut user_roles = UserRoles::new(Roles::empty(), 0);
+        user_roles.grant(RoleGrant::new(Roles::MINTER, None, Some(2)));
+        user_roles.grant(RoleGrant::new(Roles::FLOW_LIMITER, None, Some(1)));
+        user_roles.grant(RoleGrant::new(Roles::MINTER, Some(100), None));
+
+        assert!(user_roles.use_roles(Roles::MINTER | Roles::FLOW_LIMITER, 0));
+        assert_eq!(user_roles.grants().first().unwrap().remaining_uses, Some(2));
+        assert_eq!(user_roles.grants().get(1).unwrap().remaining_uses, Some(0));
+        assert!(!user_roles.use_roles(Roles::FLOW_LIMITER, 0));
+
+        assert!(user_roles.use_roles(Roles::MINTER, 100));
+        assert!(user_roles.use_roles(Roles::MINTER, 100));
+        assert!(!user_roles.use_roles(Roles::MINTER, 100));
+        assert!(user_roles.use_roles(Roles::MINTER, 99));
+
+        assert!(user_roles.prune(100));
+        assert!(user_roles.is_empty());
+    }
+
+    #[test]
+    fn test_remove_strips_grants() {
+        let mut user_roles = UserRoles::new(Roles::MINTER, 0);
+        user_roles.grant(RoleGrant::new(
+            Roles::MINTER | Roles::FLOW_LIMITER,
+            Some(100),
+            None,
+        ));
+        user_roles.grant(RoleGrant::new(Roles::MINTER, None, Some(1)));
+
+        user_roles.remove(Roles::MINTER);
+
+        assert!(!user_roles.contains_at(Roles::MINTER, 0));
+        assert!(user_roles.contains_at(Roles::FLOW_LIMITER, 0));
+        assert_eq!(user_roles.grants().len(), 1);
+    }
+
+    #[test]
+    fn test_role_index_set() {
+        let alice = Pubkey::new_unique();
+        let bob = Pubkey::new_unique();
+        let mut index = RoleIndex::new(0);
+
+        index.set(alice, Roles::OPERATOR);
+        index.set(bob, Roles::MINTER);
+        index.set(alice, Roles::OPERATOR | Roles::FLOW_LIMITER);
+
+        assert_eq!(index.holders().len(), 2);
+        assert_eq!(
+            index.roles_of(&alice),
+            Some(Roles::OPERATOR | Roles::FLOW_LIMITER)
+        );
+
+        index.set(bob, Roles::empty());
+
+        assert_eq!(index.roles_of(&bob), None);
+        assert_eq!(index.holders().len(), 1);
+
+        let serialized = to_vec(&index).unwrap();
+        let deserialized = RoleIndex::<Roles>::try_from_slice(&serialized).unwrap();
+        assert_eq!(index, deserialized);
+    }
+
+    #[test]
+    fn test_multisig_approval() {
+        let first = Pubkey::new_unique();
+        let second = Pubkey::new_unique();
+        let signers = vec![first, second, Pubkey::new_unique()];
+        let outsider = Pubkey::new_unique();
+        let multisig = Multisig::new(signers.clone(), 2, 255).unwrap();
+
+        assert!(!multisig.is_approved_by([&first]));
+        assert!(!multisig.is_approved_by([&first, &first]));
+        assert!(!multisig.is_approved_by([&first, &outsider]));
+        assert!(multisig.is_approved_by([&first, &outsider, &second]));
+
+        assert!(Multisig::new(signers.clone(), 0, 255).is_err());
+        assert!(Multisig::new(signers.clone(), 4, 255).is_err());
+        assert!(Multisig::new(vec![first, second, first], 2, 255).is_err());
+        assert!(Multisig::new(
+            core::iter::repeat_with(Pubkey::new_unique)
+                .take(Multisig::MAX_SIGNERS.saturating_add(1))
+                .collect(),
+            1,
+            255
+        )
+        .is_err());
+    }
 }
@@ -5,6 +5,11 @@
 use borsh::{BorshDeserialize, BorshSerialize};
 use solana_program::program_error::ProgramError;
 use solana_program::system_program;
+
+use crate::{
+    gas_payment_key, get_gas_message_pda, get_gas_oracle_pda, get_gas_payment_pda,
+    get_gas_reserve_pda, get_gas_token_pda, get_treasury_pda, Roles, NATIVE_GAS_TOKEN,
+};
 use solana_program::{
     instruction::{AccountMeta, Instruction},
     pubkey::Pubkey,
@@ -21,6 +26,10 @@ pub enum GasServiceInstruction {
     /// 1. `[]` The `operator` account of this PDA.
     /// 2. `[writable]` The `config_pda` account to be created.
     /// 3. `[]` The `system_program` account.
+    /// 4. `[writable]` The `operator_roles_pda` account, granted the operator, collector and
+    ///    refunder roles.
+    /// 5. `[writable]` The `role_index_pda` account of the config, recording the roles of the
+    ///    operator.
     Initialize {
         /// A unique 32-byte array used as a seed in deriving the config PDA.
         salt: [u8; 32],
@@ -31,6 +40,278 @@ pub enum GasServiceInstruction {
 
     /// Use SOL to pay for gas-related operations.
     Native(PayWithNativeToken),
+
+    /// Set the account allowed to update the gas prices of a destination chain, creating the
+    /// gas oracle of the chain if it doesn't exist yet (operator only).
+    ///
+    /// Accounts expected:
+    /// 0. `[signer, writable]` The account (`payer`) paying for PDA creation
+    /// 1. `[signer]` The `operator` account of the config PDA.
+    /// 2. `[]` The `config_pda` account.
+    /// 3. `[writable]` The `gas_oracle_pda` account of the destination chain.
+    /// 4. `[]` The `system_program` account.
+    /// 5. `[]` The `operator_roles_pda` account.
+    SetGasOracleUpdater {
+        /// The destination chain the gas oracle quotes fees for.
+        destination_chain: String,
+        /// The account allowed to update the gas prices.
+        updater: Pubkey,
+    },
+
+    /// Update the gas prices of a destination chain (gas oracle updater only).
+    ///
+    /// Accounts expected:
+    /// 0. `[signer]` The `updater` account of the gas oracle.
+    /// 1. `[]` The `config_pda` account.
+    /// 2. `[writable]` The `gas_oracle_pda` account of the destination chain.
+    UpdateGasOracle {
+        /// The destination chain the gas oracle quotes fees for.
+        destination_chain: String,
+        /// Fee charged for every contract call, in units of the destination chain gas token.
+        base_fee: u64,
+        /// Execution cost of every payload byte, in units of the destination chain gas token.
+        fee_per_byte: u64,
+        /// Lamports worth [`crate::state::GasOracle::RATE_PRECISION`] units of the destination
+        /// chain gas token.
+        token_to_sol_rate: u64,
+    },
+
+    /// Quote the gas fee, in lamports, of a contract call to a destination chain. The quote is
+    /// returned as a little-endian `u64` through the return data.
+    ///
+    /// Accounts expected:
+    /// 0. `[]` The `config_pda` account.
+    /// 1. `[]` The `gas_oracle_pda` account of the destination chain.
+    EstimateGasFee {
+        /// The destination chain of the contract call.
+        destination_chain: String,
+        /// The length of the payload of the contract call, in bytes.
+        payload_len: u64,
+    },
+
+    /// Propose to transfer the operatorship of the config to another account (operator only).
+    ///
+    /// Accounts expected:
+    /// 0. `[]` The `system_program` account.
+    /// 1. `[signer, writable]` The current `operator` account, paying for the proposal.
+    /// 2. `[]` The `operator_roles_pda` account.
+    /// 3. `[]` The `config_pda` account.
+    /// 4. `[]` The `proposed_operator` account.
+    /// 5. `[]` The `proposed_operator_roles_pda` account.
+    /// 6. `[writable]` The `proposal_pda` account.
+    ProposeOperatorship {
+        /// Unix timestamp after which the proposal can no longer be accepted.
+        expires_at: Option<i64>,
+    },
+
+    /// Accept a pending operatorship proposal, making the signer the operator of the config.
+    ///
+    /// Accounts expected:
+    /// 0. `[]` The `system_program` account.
+    /// 1. `[signer, writable]` The proposed `operator` account.
+    /// 2. `[writable]` The `operator_roles_pda` account of the proposed operator.
+    /// 3. `[writable]` The `config_pda` account.
+    /// 4. `[writable]` The `previous_operator` account, receiving the proposal rent.
+    /// 5. `[writable]` The `previous_operator_roles_pda` account.
+    /// 6. `[writable]` The `proposal_pda` account.
+    /// 7. `[writable]` The `role_index_pda` account of the config, updated with the new roles.
+    AcceptOperatorship,
+
+    /// Add the collector and/or refunder roles to an account (operator only).
+    ///
+    /// Accounts expected:
+    /// 0. `[]` The `system_program` account.
+    /// 1. `[signer, writable]` The `operator` account, paying for the roles PDA creation.
+    /// 2. `[]` The `operator_roles_pda` account.
+    /// 3. `[]` The `config_pda` account.
+    /// 4. `[]` The `user` account.
+    /// 5. `[writable]` The `user_roles_pda` account.
+    /// 6. `[writable]` The `role_index_pda` account of the config, updated with the new roles.
+    AddRoles {
+        /// The roles to add, only [`Roles::COLLECTOR`] and [`Roles::REFUNDER`] are allowed.
+        roles: Roles,
+    },
+
+    /// Remove the collector and/or refunder roles from an account (operator only).
+    ///
+    /// Accounts expected:
+    /// 0. `[]` The `system_program` account.
+    /// 1. `[signer, writable]` The `operator` account.
+    /// 2. `[]` The `operator_roles_pda` account.
+    /// 3. `[]` The `config_pda` account.
+    /// 4. `[]` The `user` account.
+    /// 5. `[writable]` The `user_roles_pda` account.
+    /// 6. `[writable]` The `role_index_pda` account of the config, updated with the new roles.
+    RemoveRoles {
+        /// The roles to remove, only [`Roles::COLLECTOR`] and [`Roles::REFUNDER`] are allowed.
+        roles: Roles,
+    },
+
+    /// Accept an SPL token to pay for gas, or update its minimum payment and rate if it's already
+    /// accepted (operator only).
+    ///
+    /// Accounts expected:
+    /// 0. `[signer, writable]` The account (`payer`) paying for PDA creation
+    /// 1. `[signer]` The `operator` account of the config PDA.
+    /// 2. `[]` The `config_pda` account.
+    /// 3. `[]` The `mint` account of the token.
+    /// 4. `[writable]` The `gas_token_pda` account of the token.
+    /// 5. `[]` The `system_program` account.
+    /// 6. `[]` The `operator_roles_pda` account.
+    AllowGasToken {
+        /// The minimum amount, in base units of the token, of a payment for a contract call.
+        min_payment: u64,
+        /// Base units of the token worth [`crate::state::GasOracle::RATE_PRECISION`] lamports,
+        /// used to check payments against the gas oracle quotes. Zero if payments in the token
+        /// can't be checked.
+        lamports_to_token_rate: u64,
+    },
+
+    /// Stop accepting an SPL token to pay for gas (operator only). Fees already paid in the
+    /// token can still be collected and refunded.
+    ///
+    /// Accounts expected:
+    /// 0. `[signer, writable]` The `operator` account of the config PDA, receiving the rent.
+    /// 1. `[]` The `config_pda` account.
+    /// 2. `[writable]` The `gas_token_pda` account of the token.
+    /// 3. `[]` The `operator_roles_pda` account.
+    DisallowGasToken,
+
+    /// Report the balance held by the config in each of the given SPL tokens. The balances are
+    /// returned as a borsh-encoded `Vec<GasTokenBalance>` through the return data, which limits
+    /// a single query to 25 tokens.
+    ///
+    /// Accounts expected:
+    /// 0. `[]` The `config_pda` account.
+    /// 1. ..`[]` Pairs of the `gas_token_pda` account of a token, followed by the token account
+    ///    of the config in that token.
+    GasTokenBalances,
+
+    /// Pay gas for a contract call and send it through the gateway, atomically. The gas payment
+    /// is made for the same destination chain, destination address and payload hash as the
+    /// gateway `CallContract`.
+    ///
+    /// Usable directly by wallets, or through CPI by programs, in which case the signing PDA of
+    /// the calling program must sign.
+    ///
+    /// Accounts expected:
+    /// 0. `[signer?]` The `sender` account, a signer unless it's a program.
+    /// 1. `[signer?]` The `signing_pda` of the sender program, or the gateway program ID when
+    ///    the sender is a wallet.
+    /// 2. `[]` The `gateway_root_pda` account.
+    /// 3. `[]` The `gateway_program` account.
+    /// 4. .. The accounts expected by `ForContractCall` of [`PayWithNativeToken`] or
+    ///    [`PayWithSplToken`], depending on `gas`.
+    CallContractWithGas {
+        /// The target blockchain for the contract call.
+        destination_chain: String,
+        /// The destination contract address on the target chain.
+        destination_contract_address: String,
+        /// The payload of the contract call.
+        payload: Vec<u8>,
+        /// The bump of the signing PDA of the sender program, ignored for wallets.
+        signing_pda_bump: u8,
+        /// Where refunds should be sent.
+        refund_address: Pubkey,
+        /// Additional parameters for the contract call.
+        params: Vec<u8>,
+        /// How the gas is paid.
+        gas: CallContractGas,
+    },
+
+    /// Configure where the fees accrued in a token are swept to, the balance left to the config
+    /// on top of the gas held for refunds, and how often the token can be swept (operator only).
+    ///
+    /// Accounts expected:
+    /// 0. `[signer, writable]` The account (`payer`) paying for PDA creation
+    /// 1. `[signer]` The `operator` account of the config PDA.
+    /// 2. `[]` The `config_pda` account.
+    /// 3. `[]` The `mint` account of the token, or the system program for native SOL.
+    /// 4. `[writable]` The `treasury_pda` account of the token.
+    /// 5. `[]` The `system_program` account.
+    /// 6. `[]` The `operator_roles_pda` account.
+    SetTreasury {
+        /// The account receiving the swept fees, a token account for SPL tokens.
+        destination: Pubkey,
+        /// The balance left to the config on top of the refundable gas of the token, in base
+        /// units of the token.
+        keep_minimum: u64,
+        /// The minimum number of seconds between two sweeps of the token.
+        sweep_interval: i64,
+    },
+
+    /// Sweep the fees accrued in each of the given tokens to their treasury destination, leaving
+    /// the gas held for refunds and the keep-minimum to the config. Anyone can sweep, each token
+    /// at most once per sweep interval of its treasury; the tokens not due yet are skipped.
+    ///
+    /// Accounts expected:
+    /// 0. `[writable]` The `config_pda` account.
+    /// 1. .. For each token, the `[writable]` `treasury_pda` account, the `[writable]`
+    ///    destination account and the `gas_reserve_pda` account of the token. For an SPL token,
+    ///    these are followed by the `[writable]` token account of the config, the `mint` account
+    ///    and the `token_program` account.
+    SweepFees,
+
+    /// Grant the operator, collector and refunder roles to the operator of a config initialized
+    /// before roles existed, and record the roles of the operator in the role index of the
+    /// config (operator only). The roles of an operator that already has them are only indexed.
+    ///
+    /// Accounts expected:
+    /// 0. `[signer, writable]` The account (`payer`) paying for PDA creation
+    /// 1. `[signer]` The `operator` account of the config PDA.
+    /// 2. `[]` The `config_pda` account.
+    /// 3. `[]` The `system_program` account.
+    /// 4. `[writable]` The `operator_roles_pda` account.
+    /// 5. `[writable]` The `role_index_pda` account of the config.
+    MigrateOperatorRoles,
+
+    /// Release what is left of a payment whose refund window is over from the gas held for
+    /// refunds, so that it can be collected and swept. Anyone can release a payment, see
+    /// [`crate::REFUND_WINDOW`].
+    ///
+    /// Accounts expected:
+    /// 0. `[]` The `config_pda` account.
+    /// 1. `[writable]` The `gas_payment_pda` ledger account of the payment.
+    /// 2. `[writable]` The `gas_reserve_pda` account of the token of the payment.
+    ReleaseGas,
+}
+
+/// A token swept by [`sweep_fees_instruction`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SweptToken {
+    /// Native SOL.
+    Native {
+        /// The account receiving the swept lamports.
+        destination: Pubkey,
+    },
+    /// An SPL token.
+    Spl {
+        /// The mint of the token.
+        mint: Pubkey,
+        /// The token program of the mint.
+        token_program_id: Pubkey,
+        /// The token account of the config the fees are swept from.
+        config_pda_ata: Pubkey,
+        /// The token account receiving the swept fees.
+        destination: Pubkey,
+    },
+}
+
+/// The gas paid by [`GasServiceInstruction::CallContractWithGas`].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, BorshDeserialize, BorshSerialize)]
+pub enum CallContractGas {
+    /// Pay with native SOL.
+    Native {
+        /// The amount of SOL to pay as gas fees.
+        gas_fee_amount: u64,
+    },
+    /// Pay with SPL tokens.
+    Spl {
+        /// The amount of tokens to pay as gas fees.
+        gas_fee_amount: u64,
+        /// The decimals for the mint
+        decimals: u8,
+    },
 }
 
 /// Instructions related to paying gas fees with SPL tokens.
@@ -38,6 +319,29 @@ pub enum GasServiceInstruction {
 #[derive(Clone, Debug, PartialEq, Eq, BorshDeserialize, BorshSerialize)]
 pub enum PayWithSplToken {
     /// Pay gas fees for a contract call using SPL tokens.
+    ///
+    /// Accounts expected:
+    /// 0. `[signer]` The `sender` account, owner of the token account paying the gas fee.
+    /// 1. `[writable]` The `sender_ata` token account paying the gas fee.
+    /// 2. `[]` The `config_pda` account.
+    /// 3. `[writable]` The `config_pda_ata` token account that receives the tokens.
+    /// 4. `[]` The `mint` account of the token.
+    /// 5. `[]` The `token_program` account.
+    /// 6. `[]` The `gas_token_pda` account, which must exist for the token to be accepted.
+    /// 7. `[writable]` The `gas_payment_pda` ledger account of the payment, see
+    ///    [`crate::get_gas_payment_pda`].
+    /// 8. `[signer, writable]` The `payer` account funding the `gas_payment_pda` on the first
+    ///    payment for the contract call, and the `gas_reserve_pda` on the first payment in the
+    ///    token.
+    /// 9. `[]` The `system_program` account.
+    /// 10. `[writable]` The `gas_reserve_pda` account of the token.
+    /// 11. `[]` The `gas_oracle_pda` account of the destination chain, only if
+    ///     `checked_payload_len` is set.
+    /// 12. ..`[signer]` The signers of a multisig `sender`.
+    ///
+    /// Payments below the minimum payment of the token are rejected. The payment can only be
+    /// topped up and refunded through its ledger account, refunds going to `refund_address`.
+    /// Identical payments accumulate in the same ledger.
     ForContractCall {
         /// The target blockchain (e.g., "ethereum") for the contract call.
         destination_chain: String,
@@ -53,14 +357,34 @@ pub enum PayWithSplToken {
         decimals: u8,
         /// Where refunds should be sent
         refund_address: Pubkey,
+        /// The length of the payload, in bytes. When set, payments below the fee quoted by the
+        /// gas oracle of the destination chain for such a payload, converted with the rate of the
+        /// token, are rejected.
+        checked_payload_len: Option<u64>,
     },
 
     /// Add more gas (SPL tokens) to an existing contract call.
+    ///
+    /// Accounts expected:
+    /// 0. `[signer]` The `sender` account, owner of the token account paying the gas fee.
+    /// 1. `[writable]` The `sender_ata` token account paying the gas fee.
+    /// 2. `[]` The `config_pda` account.
+    /// 3. `[writable]` The `config_pda_ata` token account that receives the tokens.
+    /// 4. `[]` The `mint` account of the token.
+    /// 5. `[]` The `token_program` account.
+    /// 6. `[]` The `gas_token_pda` account, which must exist for the token to be accepted.
+    /// 7. `[writable]` The `gas_payment_pda` ledger account of the payment, which must have
+    ///    the same refund address.
+    /// 8. `[signer, writable]` The `payer` account funding the `gas_reserve_pda` if needed.
+    /// 9. `[]` The `system_program` account.
+    /// 10. `[writable]` The `gas_reserve_pda` account of the token.
+    /// 11. ..`[signer]` The signers of a multisig `sender`.
+    ///
+    /// Top ups don't bind the message ID to the ledger, only refunds do, but once bound they
+    /// must use the same message ID.
     AddGas {
-        /// A 64-byte unique transaction identifier.
-        tx_hash: [u8; 64],
-        /// The index of the log entry in the transaction.
-        log_index: u64,
+        /// The Axelar message ID of the contract call, `<signature>-<ix_index>.<inner_ix_index>`.
+        message_id: String,
         /// The additional SPL tokens to add as gas.
         gas_fee_amount: u64,
         /// The decimals for the mint
@@ -69,7 +393,18 @@ pub enum PayWithSplToken {
         refund_address: Pubkey,
     },
 
-    /// Collect fees that have accrued in SPL tokens (operator only).
+    /// Collect fees that have accrued in SPL tokens (collector only).
+    ///
+    /// Accounts expected:
+    /// 0. `[signer]` The `collector` account authorized to collect fees.
+    /// 1. `[writable]` The `receiver` token account.
+    /// 2. `[]` The `config_pda` account.
+    /// 3. `[writable]` The `config_pda_ata` token account the fees are collected from.
+    /// 4. `[]` The `mint` account of the token.
+    /// 5. `[]` The `token_program` account.
+    /// 6. `[]` The `collector_roles_pda` account.
+    /// 7. `[]` The `gas_reserve_pda` account of the token. The gas it holds for refunds can't
+    ///    be collected.
     CollectFees {
         /// The amount of SPL tokens to be collected as fees.
         amount: u64,
@@ -77,12 +412,28 @@ pub enum PayWithSplToken {
         decimals: u8,
     },
 
-    /// Refund previously collected SPL token fees (operator only).
+    /// Refund previously collected SPL token fees (refunder only).
+    ///
+    /// The refunded amount can't exceed what was paid and added for the payment, minus what was
+    /// already refunded for it. The first refund of a payment binds the message ID to its
+    /// ledger, later top ups and refunds must use the same message ID.
+    ///
+    /// Accounts expected:
+    /// 0. `[signer, writable]` The `refunder` account authorized to issue refunds, funding the
+    ///    `gas_message_pda` when the message ID gets bound to the payment.
+    /// 1. `[writable]` The `receiver` token account, owned by the refund address of the payment.
+    /// 2. `[]` The `config_pda` account.
+    /// 3. `[writable]` The `config_pda_ata` token account the tokens are refunded from.
+    /// 4. `[]` The `mint` account of the token.
+    /// 5. `[]` The `token_program` account.
+    /// 6. `[writable]` The `gas_payment_pda` ledger account of the payment.
+    /// 7. `[]` The `refunder_roles_pda` account.
+    /// 8. `[writable]` The `gas_message_pda` account of the message ID.
+    /// 9. `[]` The `system_program` account.
+    /// 10. `[writable]` The `gas_reserve_pda` account of the token.
     Refund {
-        /// A 64-byte unique transaction identifier
-        tx_hash: [u8; 64],
-        /// The index of the log entry in the transaction
-        log_index: u64,
+        /// The Axelar message ID of the contract call, `<signature>-<ix_index>.<inner_ix_index>`.
+        message_id: String,
         /// The amount of SPL tokens to be refunded
         fees: u64,
         /// The decimals for the mint
@@ -100,6 +451,16 @@ pub enum PayWithNativeToken {
     /// 0. `[signer, writable]` The account (`payer`) paying the gas fee in lamports.
     /// 1. `[writable]` The `config_pda` account that receives the lamports.
     /// 2. `[]` The `system_program` account.
+    /// 3. `[]` The `gas_oracle_pda` account of the destination chain, only if
+    ///    `checked_payload_len` is set.
+    /// 4. `[writable]` The `gas_payment_pda` ledger account of the payment, see
+    ///    [`crate::get_gas_payment_pda`], funded by the `payer` on the first payment for the
+    ///    contract call.
+    /// 5. `[writable]` The `gas_reserve_pda` account of native SOL, funded by the `payer` on the
+    ///    first payment.
+    ///
+    /// The payment can only be topped up and refunded through its ledger account, refunds going
+    /// to `refund_address`. Identical payments accumulate in the same ledger.
     ForContractCall {
         /// The target blockchain for the contract call.
         destination_chain: String,
@@ -113,6 +474,9 @@ pub enum PayWithNativeToken {
         params: Vec<u8>,
         /// The amount of SOL to pay as gas fees.
         gas_fee_amount: u64,
+        /// The length of the payload, in bytes. When set, payments below the fee quoted by the
+        /// gas oracle of the destination chain for such a payload are rejected.
+        checked_payload_len: Option<u64>,
     },
 
     /// Add more native SOL gas to an existing transaction.
@@ -121,39 +485,55 @@ pub enum PayWithNativeToken {
     /// 1. `[signer, writable]` The account (`sender`) providing the additional lamports.
     /// 2. `[writable]` The `config_pda` account that receives the additional lamports.
     /// 3. `[]` The `system_program` account.
+    /// 4. `[writable]` The `gas_payment_pda` ledger account of the payment, which must have
+    ///    the same refund address.
+    /// 5. `[writable]` The `gas_reserve_pda` account of native SOL.
+    ///
+    /// Top ups don't bind the message ID to the ledger, only refunds do, but once bound they
+    /// must use the same message ID.
     AddGas {
-        /// A 64-byte unique transaction identifier.
-        tx_hash: [u8; 64],
-        /// The index of the log entry in the transaction.
-        log_index: u64,
+        /// The Axelar message ID of the contract call, `<signature>-<ix_index>.<inner_ix_index>`.
+        message_id: String,
         /// The additional SOL to add as gas.
         gas_fee_amount: u64,
         /// Where refunds should be sent.
         refund_address: Pubkey,
     },
 
-    /// Collect accrued native SOL fees (operator only).
+    /// Collect accrued native SOL fees (collector only).
     ///
     /// Accounts expected:
-    /// 1. `[signer, read-only]` The `operator` account authorized to collect fees.
+    /// 1. `[signer, read-only]` The `collector` account authorized to collect fees.
     /// 2. `[writable]` The `config_pda` account holding the accrued lamports to collect.
     /// 3. `[writable]` The `receiver` account where the collected lamports will be sent.
+    /// 4. `[]` The `collector_roles_pda` account.
+    /// 5. `[]` The `gas_reserve_pda` account of native SOL. The gas it holds for refunds can't
+    ///    be collected.
     CollectFees {
         /// The amount of SOL to collect as fees.
         amount: u64,
     },
 
-    /// Refund previously collected native SOL fees (operator only).
+    /// Refund previously collected native SOL fees (refunder only).
     ///
     /// Accounts expected:
-    /// 1. `[signer, read-only]` The `operator` account authorized to issue refunds.
-    /// 2. `[writable]` The `receiver` account that will receive the refunded lamports.
+    /// 1. `[signer, writable]` The `refunder` account authorized to issue refunds, funding the
+    ///    `gas_message_pda` when the message ID gets bound to the payment.
+    /// 2. `[writable]` The `receiver` account that will receive the refunded lamports, the
+    ///    refund address of the payment.
     /// 3. `[writable]` The `config_pda` account from which lamports are refunded.
+    /// 4. `[writable]` The `gas_payment_pda` ledger account of the payment.
+    /// 5. `[]` The `refunder_roles_pda` account.
+    /// 6. `[writable]` The `gas_message_pda` account of the message ID.
+    /// 7. `[]` The `system_program` account.
+    /// 8. `[writable]` The `gas_reserve_pda` account of native SOL.
+    ///
+    /// The refunded amount can't exceed what was paid and added for the payment, minus what was
+    /// already refunded for it. The first refund of a payment binds the message ID to its
+    /// ledger, later top ups and refunds must use the same message ID.
     Refund {
-        /// A 64-byte unique transaction identifier.
-        tx_hash: [u8; 64],
-        /// The index of the log entry in the transaction.
-        log_index: u64,
+        /// The Axelar message ID of the contract call, `<signature>-<ix_index>.<inner_ix_index>`.
+        message_id: String,
         /// The amount of SOL to be refunded.
         fees: u64,
     },
@@ -172,22 +552,64 @@ pub fn init_config(
 ) -> Result<Instruction, ProgramError> {
     let ix_data = borsh::to_vec(&GasServiceInstruction::Initialize { salt })?;
 
-    let accounts = vec![
-        AccountMeta::new(*payer, true),
-        AccountMeta::new_readonly(*operator, true),
-        AccountMeta::new(*config_pda, false),
-        AccountMeta::new_readonly(system_program::ID, false),
-    ];
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts: operator_setup_accounts(program_id, payer, operator, config_pda, true),
+        data: ix_data,
+    })
+}
+
+/// Builds an instruction for the operator of a config initialized before roles existed to
+/// create and index its roles.
+///
+/// # Errors
+/// - ix data cannot be serialized
+pub fn migrate_operator_roles_instruction(
+    program_id: &Pubkey,
+    payer: &Pubkey,
+    operator: &Pubkey,
+    config_pda: &Pubkey,
+) -> Result<Instruction, ProgramError> {
+    let ix_data = borsh::to_vec(&GasServiceInstruction::MigrateOperatorRoles)?;
 
     Ok(Instruction {
         program_id: *program_id,
-        accounts,
+        accounts: operator_setup_accounts(program_id, payer, operator, config_pda, false),
         data: ix_data,
     })
 }
 
+fn operator_setup_accounts(
+    program_id: &Pubkey,
+    payer: &Pubkey,
+    operator: &Pubkey,
+    config_pda: &Pubkey,
+    config_pda_writable: bool,
+) -> Vec<AccountMeta> {
+    let (operator_roles_pda, _) =
+        role_management::find_user_roles_pda(program_id, config_pda, operator);
+    let (role_index_pda, _) = role_management::find_role_index_pda(program_id, config_pda);
+
+    vec![
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(*operator, true),
+        if config_pda_writable {
+            AccountMeta::new(*config_pda, false)
+        } else {
+            AccountMeta::new_readonly(*config_pda, false)
+        },
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new(operator_roles_pda, false),
+        AccountMeta::new(role_index_pda, false),
+    ]
+}
+
 /// Builds an instruction to pay native SOL for a contract call.
 ///
+/// The payment is recorded in its ledger PDA, see [`get_gas_payment_pda`], so it can later be
+/// topped up and refunded. When `checked_payload_len` is set, the payment is checked against the
+/// fee quoted by the gas oracle of the destination chain.
+///
 /// # Errors
 /// - ix data cannot be serialized
 #[allow(clippy::too_many_arguments)]
@@ -201,7 +623,18 @@ pub fn pay_native_for_contract_call_instruction(
     refund_address: Pubkey,
     params: Vec<u8>,
     gas_fee_amount: u64,
+    checked_payload_len: Option<u64>,
 ) -> Result<Instruction, ProgramError> {
+    let gas_oracle_pda = checked_payload_len
+        .map(|_| get_gas_oracle_pda(program_id, config_pda, &destination_chain).0);
+    let (gas_payment_pda, _) = get_gas_payment_pda(
+        program_id,
+        config_pda,
+        &NATIVE_GAS_TOKEN,
+        &gas_payment_key(&destination_chain, &destination_address, &payload_hash),
+        &refund_address,
+    );
+    let (gas_reserve_pda, _) = get_gas_reserve_pda(program_id, config_pda, &NATIVE_GAS_TOKEN);
     let ix_data = borsh::to_vec(&GasServiceInstruction::Native(
         PayWithNativeToken::ForContractCall {
             destination_chain,
@@ -210,15 +643,23 @@ pub fn pay_native_for_contract_call_instruction(
             refund_address,
             params,
             gas_fee_amount,
+            checked_payload_len,
         },
     ))?;
 
-    let accounts = vec![
+    let mut accounts = vec![
         AccountMeta::new(*payer, true),
         AccountMeta::new(*config_pda, false),
         AccountMeta::new_readonly(system_program::ID, false),
     ];
 
+    if let Some(gas_oracle_pda) = gas_oracle_pda {
+        accounts.push(AccountMeta::new_readonly(gas_oracle_pda, false));
+    }
+
+    accounts.push(AccountMeta::new(gas_payment_pda, false));
+    accounts.push(AccountMeta::new(gas_reserve_pda, false));
+
     Ok(Instruction {
         program_id: *program_id,
         accounts,
@@ -230,18 +671,19 @@ pub fn pay_native_for_contract_call_instruction(
 ///
 /// # Errors
 /// - ix data cannot be serialized
+#[allow(clippy::too_many_arguments)]
 pub fn add_native_gas_instruction(
     program_id: &Pubkey,
     sender: &Pubkey,
     config_pda: &Pubkey,
-    tx_hash: [u8; 64],
-    log_index: u64,
+    gas_payment_pda: &Pubkey,
+    message_id: String,
     gas_fee_amount: u64,
     refund_address: Pubkey,
 ) -> Result<Instruction, ProgramError> {
+    let (gas_reserve_pda, _) = get_gas_reserve_pda(program_id, config_pda, &NATIVE_GAS_TOKEN);
     let ix_data = borsh::to_vec(&GasServiceInstruction::Native(PayWithNativeToken::AddGas {
-        tx_hash,
-        log_index,
+        message_id,
         gas_fee_amount,
         refund_address,
     }))?;
@@ -250,6 +692,8 @@ pub fn add_native_gas_instruction(
         AccountMeta::new(*sender, true),
         AccountMeta::new(*config_pda, false),
         AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new(*gas_payment_pda, false),
+        AccountMeta::new(gas_reserve_pda, false),
     ];
 
     Ok(Instruction {
@@ -259,25 +703,30 @@ pub fn add_native_gas_instruction(
     })
 }
 
-/// Builds an instruction for the operator to collect native SOL fees.
+/// Builds an instruction for a collector to collect native SOL fees.
 ///
 /// # Errors
 /// - ix data cannot be serialized
 pub fn collect_native_fees_instruction(
     program_id: &Pubkey,
-    operator: &Pubkey,
+    collector: &Pubkey,
     config_pda: &Pubkey,
     receiver: &Pubkey,
     amount: u64,
 ) -> Result<Instruction, ProgramError> {
+    let (collector_roles_pda, _) =
+        role_management::find_user_roles_pda(program_id, config_pda, collector);
+    let (gas_reserve_pda, _) = get_gas_reserve_pda(program_id, config_pda, &NATIVE_GAS_TOKEN);
     let ix_data = borsh::to_vec(&GasServiceInstruction::Native(
         PayWithNativeToken::CollectFees { amount },
     ))?;
 
     let accounts = vec![
-        AccountMeta::new_readonly(*operator, true),
+        AccountMeta::new_readonly(*collector, true),
         AccountMeta::new(*config_pda, false),
         AccountMeta::new(*receiver, false),
+        AccountMeta::new_readonly(collector_roles_pda, false),
+        AccountMeta::new_readonly(gas_reserve_pda, false),
     ];
 
     Ok(Instruction {
@@ -287,29 +736,38 @@ pub fn collect_native_fees_instruction(
     })
 }
 
-/// Builds an instruction for the operator to refund previously collected native SOL fees.
+/// Builds an instruction for a refunder to refund previously collected native SOL fees.
 ///
 /// # Errors
 /// - ix data cannot be serialized
+#[allow(clippy::too_many_arguments)]
 pub fn refund_native_fees_instruction(
     program_id: &Pubkey,
-    operator: &Pubkey,
+    refunder: &Pubkey,
     receiver: &Pubkey,
     config_pda: &Pubkey,
-    tx_hash: [u8; 64],
-    log_index: u64,
+    gas_payment_pda: &Pubkey,
+    message_id: String,
     fees: u64,
 ) -> Result<Instruction, ProgramError> {
+    let (refunder_roles_pda, _) =
+        role_management::find_user_roles_pda(program_id, config_pda, refunder);
+    let (gas_message_pda, _) = get_gas_message_pda(program_id, config_pda, &message_id);
+    let (gas_reserve_pda, _) = get_gas_reserve_pda(program_id, config_pda, &NATIVE_GAS_TOKEN);
     let ix_data = borsh::to_vec(&GasServiceInstruction::Native(PayWithNativeToken::Refund {
-        tx_hash,
-        log_index,
+        message_id,
         fees,
     }))?;
 
     let accounts = vec![
-        AccountMeta::new_readonly(*operator, true),
+        AccountMeta::new(*refunder, true),
         AccountMeta::new(*receiver, false),
         AccountMeta::new(*config_pda, false),
+        AccountMeta::new(*gas_payment_pda, false),
+        AccountMeta::new_readonly(refunder_roles_pda, false),
+        AccountMeta::new(gas_message_pda, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new(gas_reserve_pda, false),
     ];
 
     Ok(Instruction {
@@ -321,6 +779,11 @@ pub fn refund_native_fees_instruction(
 
 /// Builds an instruction to pay with SPL tokens for a contract call.
 ///
+/// The payment is recorded in its ledger PDA, see [`get_gas_payment_pda`], funded by
+/// `gas_payment_payer` on the first payment, so it can later be topped up and refunded. When
+/// `checked_payload_len` is set, the payment is checked against the fee quoted by the gas oracle
+/// of the destination chain.
+///
 /// # Errors
 /// - ix data cannot be serialized
 #[allow(clippy::too_many_arguments)]
@@ -340,7 +803,20 @@ pub fn pay_spl_for_contract_call_instruction(
     gas_fee_amount: u64,
     signer_pubkeys: &[Pubkey],
     decimals: u8,
+    gas_payment_payer: &Pubkey,
+    checked_payload_len: Option<u64>,
 ) -> Result<Instruction, ProgramError> {
+    let (gas_token_pda, _) = get_gas_token_pda(program_id, config_pda, mint);
+    let (gas_payment_pda, _) = get_gas_payment_pda(
+        program_id,
+        config_pda,
+        mint,
+        &gas_payment_key(&destination_chain, &destination_address, &payload_hash),
+        &refund_address,
+    );
+    let (gas_reserve_pda, _) = get_gas_reserve_pda(program_id, config_pda, mint);
+    let gas_oracle_pda = checked_payload_len
+        .map(|_| get_gas_oracle_pda(program_id, config_pda, &destination_chain).0);
     let ix_data = borsh::to_vec(&GasServiceInstruction::SplToken(
         PayWithSplToken::ForContractCall {
             destination_chain,
@@ -350,6 +826,7 @@ pub fn pay_spl_for_contract_call_instruction(
             params,
             decimals,
             gas_fee_amount,
+            checked_payload_len,
         },
     ))?;
 
@@ -360,8 +837,15 @@ pub fn pay_spl_for_contract_call_instruction(
         AccountMeta::new(*config_pda_ata, false),
         AccountMeta::new_readonly(*mint, false),
         AccountMeta::new_readonly(*token_program_id, false),
+        AccountMeta::new_readonly(gas_token_pda, false),
+        AccountMeta::new(gas_payment_pda, false),
+        AccountMeta::new(*gas_payment_payer, true),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new(gas_reserve_pda, false),
     ];
-
+    if let Some(gas_oracle_pda) = gas_oracle_pda {
+        accounts.push(AccountMeta::new_readonly(gas_oracle_pda, false));
+    }
     for signer_pubkey in signer_pubkeys {
         accounts.push(AccountMeta::new_readonly(*signer_pubkey, true));
     }
@@ -386,16 +870,18 @@ pub fn add_spl_gas_instruction(
     config_pda_ata: &Pubkey,
     mint: &Pubkey,
     token_program_id: &Pubkey,
+    gas_payment_pda: &Pubkey,
+    payer: &Pubkey,
     signer_pubkeys: &[Pubkey],
-    tx_hash: [u8; 64],
-    log_index: u64,
+    message_id: String,
     gas_fee_amount: u64,
     refund_address: Pubkey,
     decimals: u8,
 ) -> Result<Instruction, ProgramError> {
+    let (gas_token_pda, _) = get_gas_token_pda(program_id, config_pda, mint);
+    let (gas_reserve_pda, _) = get_gas_reserve_pda(program_id, config_pda, mint);
     let ix_data = borsh::to_vec(&GasServiceInstruction::SplToken(PayWithSplToken::AddGas {
-        tx_hash,
-        log_index,
+        message_id,
         decimals,
         gas_fee_amount,
         refund_address,
@@ -408,6 +894,11 @@ pub fn add_spl_gas_instruction(
         AccountMeta::new(*config_pda_ata, false),
         AccountMeta::new_readonly(*mint, false),
         AccountMeta::new_readonly(*token_program_id, false),
+        AccountMeta::new_readonly(gas_token_pda, false),
+        AccountMeta::new(*gas_payment_pda, false),
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new(gas_reserve_pda, false),
     ];
     for signer_pubkey in signer_pubkeys {
         accounts.push(AccountMeta::new_readonly(*signer_pubkey, true));
@@ -420,14 +911,14 @@ pub fn add_spl_gas_instruction(
     })
 }
 
-/// Builds an instruction for the operator to collect SPL fees.
+/// Builds an instruction for a collector to collect SPL fees.
 ///
 /// # Errors
 /// - ix data cannot be serialized
 #[allow(clippy::too_many_arguments)]
 pub fn collect_spl_fees_instruction(
     program_id: &Pubkey,
-    operator: &Pubkey,
+    collector: &Pubkey,
     token_program_id: &Pubkey,
     mint: &Pubkey,
     config_pda: &Pubkey,
@@ -436,17 +927,22 @@ pub fn collect_spl_fees_instruction(
     amount: u64,
     decimals: u8,
 ) -> Result<Instruction, ProgramError> {
+    let (collector_roles_pda, _) =
+        role_management::find_user_roles_pda(program_id, config_pda, collector);
+    let (gas_reserve_pda, _) = get_gas_reserve_pda(program_id, config_pda, mint);
     let ix_data = borsh::to_vec(&GasServiceInstruction::SplToken(
         PayWithSplToken::CollectFees { amount, decimals },
     ))?;
 
     let accounts = vec![
-        AccountMeta::new_readonly(*operator, true),
+        AccountMeta::new_readonly(*collector, true),
         AccountMeta::new(*receiver, false),
         AccountMeta::new_readonly(*config_pda, false),
         AccountMeta::new(*config_pda_ata, false),
         AccountMeta::new_readonly(*mint, false),
         AccountMeta::new_readonly(*token_program_id, false),
+        AccountMeta::new_readonly(collector_roles_pda, false),
+        AccountMeta::new_readonly(gas_reserve_pda, false),
     ];
 
     Ok(Instruction {
@@ -456,38 +952,653 @@ pub fn collect_spl_fees_instruction(
     })
 }
 
-/// Builds an instruction for the operator to refund previously collected SPL fees.
+/// Builds an instruction for a refunder to refund previously collected SPL fees.
 ///
 /// # Errors
 /// - ix data cannot be serialized
 #[allow(clippy::too_many_arguments)]
 pub fn refund_spl_fees_instruction(
     program_id: &Pubkey,
-    operator: &Pubkey,
+    refunder: &Pubkey,
     token_program_id: &Pubkey,
     mint: &Pubkey,
     config_pda: &Pubkey,
     config_pda_ata: &Pubkey,
     receiver: &Pubkey,
-    tx_hash: [u8; 64],
-    log_index: u64,
+    gas_payment_pda: &Pubkey,
+    message_id: String,
     fees: u64,
     decimals: u8,
 ) -> Result<Instruction, ProgramError> {
+    let (refunder_roles_pda, _) =
+        role_management::find_user_roles_pda(program_id, config_pda, refunder);
+    let (gas_message_pda, _) = get_gas_message_pda(program_id, config_pda, &message_id);
+    let (gas_reserve_pda, _) = get_gas_reserve_pda(program_id, config_pda, mint);
     let ix_data = borsh::to_vec(&GasServiceInstruction::SplToken(PayWithSplToken::Refund {
         decimals,
-        tx_hash,
-        log_index,
+        message_id,
         fees,
     }))?;
 
     let accounts = vec![
-        AccountMeta::new_readonly(*operator, true),
+        AccountMeta::new(*refunder, true),
         AccountMeta::new(*receiver, false),
         AccountMeta::new_readonly(*config_pda, false),
         AccountMeta::new(*config_pda_ata, false),
         AccountMeta::new_readonly(*mint, false),
         AccountMeta::new_readonly(*token_program_id, false),
+        AccountMeta::new(*gas_payment_pda, false),
+        AccountMeta::new_readonly(refunder_roles_pda, false),
+        AccountMeta::new(gas_message_pda, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new(gas_reserve_pda, false),
+    ];
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data: ix_data,
+    })
+}
+
+/// Builds an instruction for the operator to set the updater of the gas oracle of a
+/// destination chain.
+///
+/// # Errors
+/// - ix data cannot be serialized
+pub fn set_gas_oracle_updater_instruction(
+    program_id: &Pubkey,
+    payer: &Pubkey,
+    operator: &Pubkey,
+    config_pda: &Pubkey,
+    destination_chain: String,
+    updater: Pubkey,
+) -> Result<Instruction, ProgramError> {
+    let (gas_oracle_pda, _) = get_gas_oracle_pda(program_id, config_pda, &destination_chain);
+    let (operator_roles_pda, _) =
+        role_management::find_user_roles_pda(program_id, config_pda, operator);
+    let ix_data = borsh::to_vec(&GasServiceInstruction::SetGasOracleUpdater {
+        destination_chain,
+        updater,
+    })?;
+
+    let accounts = vec![
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(*operator, true),
+        AccountMeta::new_readonly(*config_pda, false),
+        AccountMeta::new(gas_oracle_pda, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new_readonly(operator_roles_pda, false),
+    ];
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data: ix_data,
+    })
+}
+
+/// Builds an instruction for the gas oracle updater to update the gas prices of a destination
+/// chain.
+///
+/// # Errors
+/// - ix data cannot be serialized
+pub fn update_gas_oracle_instruction(
+    program_id: &Pubkey,
+    updater: &Pubkey,
+    config_pda: &Pubkey,
+    destination_chain: String,
+    base_fee: u64,
+    fee_per_byte: u64,
+    token_to_sol_rate: u64,
+) -> Result<Instruction, ProgramError> {
+    let (gas_oracle_pda, _) = get_gas_oracle_pda(program_id, config_pda, &destination_chain);
+    let ix_data = borsh::to_vec(&GasServiceInstruction::UpdateGasOracle {
+        destination_chain,
+        base_fee,
+        fee_per_byte,
+        token_to_sol_rate,
+    })?;
+
+    let accounts = vec![
+        AccountMeta::new_readonly(*updater, true),
+        AccountMeta::new_readonly(*config_pda, false),
+        AccountMeta::new(gas_oracle_pda, false),
+    ];
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data: ix_data,
+    })
+}
+
+/// Builds an instruction to quote the gas fee of a contract call to a destination chain.
+///
+/// # Errors
+/// - ix data cannot be serialized
+pub fn estimate_gas_fee_instruction(
+    program_id: &Pubkey,
+    config_pda: &Pubkey,
+    destination_chain: String,
+    payload_len: u64,
+) -> Result<Instruction, ProgramError> {
+    let (gas_oracle_pda, _) = get_gas_oracle_pda(program_id, config_pda, &destination_chain);
+    let ix_data = borsh::to_vec(&GasServiceInstruction::EstimateGasFee {
+        destination_chain,
+        payload_len,
+    })?;
+
+    let accounts = vec![
+        AccountMeta::new_readonly(*config_pda, false),
+        AccountMeta::new_readonly(gas_oracle_pda, false),
+    ];
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data: ix_data,
+    })
+}
+
+/// Builds an instruction for the operator to propose transferring the operatorship of the
+/// config to `proposed_operator`.
+///
+/// # Errors
+/// - ix data cannot be serialized
+pub fn propose_operatorship_instruction(
+    program_id: &Pubkey,
+    operator: &Pubkey,
+    config_pda: &Pubkey,
+    proposed_operator: &Pubkey,
+    expires_at: Option<i64>,
+) -> Result<Instruction, ProgramError> {
+    let (operator_roles_pda, _) =
+        role_management::find_user_roles_pda(program_id, config_pda, operator);
+    let (proposed_operator_roles_pda, _) =
+        role_management::find_user_roles_pda(program_id, config_pda, proposed_operator);
+    let (proposal_pda, _) = role_management::find_roles_proposal_pda(
+        program_id,
+        config_pda,
+        operator,
+        proposed_operator,
+    );
+    let ix_data = borsh::to_vec(&GasServiceInstruction::ProposeOperatorship { expires_at })?;
+
+    let accounts = vec![
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new(*operator, true),
+        AccountMeta::new_readonly(operator_roles_pda, false),
+        AccountMeta::new_readonly(*config_pda, false),
+        AccountMeta::new_readonly(*proposed_operator, false),
+        AccountMeta::new_readonly(proposed_operator_roles_pda, false),
+        AccountMeta::new(proposal_pda, false),
+    ];
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data: ix_data,
+    })
+}
+
+/// Builds an instruction for the proposed operator to accept the operatorship of the config
+/// from `previous_operator`.
+///
+/// # Errors
+/// - ix data cannot be serialized
+pub fn accept_operatorship_instruction(
+    program_id: &Pubkey,
+    operator: &Pubkey,
+    config_pda: &Pubkey,
+    previous_operator: &Pubkey,
+) -> Result<Instruction, ProgramError> {
+    let (operator_roles_pda, _) =
+        role_management::find_user_roles_pda(program_id, config_pda, operator);
+    let (previous_operator_roles_pda, _) =
+        role_management::find_user_roles_pda(program_id, config_pda, previous_operator);
+    let (proposal_pda, _) = role_management::find_roles_proposal_pda(
+        program_id,
+        config_pda,
+        previous_operator,
+        operator,
+    );
+    let (role_index_pda, _) = role_management::find_role_index_pda(program_id, config_pda);
+    let ix_data = borsh::to_vec(&GasServiceInstruction::AcceptOperatorship)?;
+
+    let accounts = vec![
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new(*operator, true),
+        AccountMeta::new(operator_roles_pda, false),
+        AccountMeta::new(*config_pda, false),
+        AccountMeta::new(*previous_operator, false),
+        AccountMeta::new(previous_operator_roles_pda, false),
+        AccountMeta::new(proposal_pda, false),
+        AccountMeta::new(role_index_pda, false),
+    ];
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data: ix_data,
+    })
+}
+
+/// Builds an instruction for the operator to add the collector and/or refunder roles to `user`.
+///
+/// # Errors
+/// - ix data cannot be serialized
+pub fn add_roles_instruction(
+    program_id: &Pubkey,
+    operator: &Pubkey,
+    config_pda: &Pubkey,
+    user: &Pubkey,
+    roles: Roles,
+) -> Result<Instruction, ProgramError> {
+    let ix_data = borsh::to_vec(&GasServiceInstruction::AddRoles { roles })?;
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts: roles_accounts(program_id, operator, config_pda, user),
+        data: ix_data,
+    })
+}
+
+/// Builds an instruction for the operator to remove the collector and/or refunder roles from
+/// `user`.
+///
+/// # Errors
+/// - ix data cannot be serialized
+pub fn remove_roles_instruction(
+    program_id: &Pubkey,
+    operator: &Pubkey,
+    config_pda: &Pubkey,
+    user: &Pubkey,
+    roles: Roles,
+) -> Result<Instruction, ProgramError> {
+    let ix_data = borsh::to_vec(&GasServiceInstruction::RemoveRoles { roles })?;
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts: roles_accounts(program_id, operator, config_pda, user),
+        data: ix_data,
+    })
+}
+
+fn roles_accounts(
+    program_id: &Pubkey,
+    operator: &Pubkey,
+    config_pda: &Pubkey,
+    user: &Pubkey,
+) -> Vec<AccountMeta> {
+    let (operator_roles_pda, _) =
+        role_management::find_user_roles_pda(program_id, config_pda, operator);
+    let (user_roles_pda, _) = role_management::find_user_roles_pda(program_id, config_pda, user);
+    let (role_index_pda, _) = role_management::find_role_index_pda(program_id, config_pda);
+
+    vec![
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new(*operator, true),
+        AccountMeta::new_readonly(operator_roles_pda, false),
+        AccountMeta::new_readonly(*config_pda, false),
+        AccountMeta::new_readonly(*user, false),
+        AccountMeta::new(user_roles_pda, false),
+        AccountMeta::new(role_index_pda, false),
+    ]
+}
+
+/// Builds an instruction for the operator to accept `mint` to pay for gas, with the given
+/// minimum payment and rate to check payments against the gas oracle quotes.
+///
+/// # Errors
+/// - ix data cannot be serialized
+pub fn allow_gas_token_instruction(
+    program_id: &Pubkey,
+    payer: &Pubkey,
+    operator: &Pubkey,
+    config_pda: &Pubkey,
+    mint: &Pubkey,
+    min_payment: u64,
+    lamports_to_token_rate: u64,
+) -> Result<Instruction, ProgramError> {
+    let (gas_token_pda, _) = get_gas_token_pda(program_id, config_pda, mint);
+    let (operator_roles_pda, _) =
+        role_management::find_user_roles_pda(program_id, config_pda, operator);
+    let ix_data = borsh::to_vec(&GasServiceInstruction::AllowGasToken {
+        min_payment,
+        lamports_to_token_rate,
+    })?;
+
+    let accounts = vec![
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(*operator, true),
+        AccountMeta::new_readonly(*config_pda, false),
+        AccountMeta::new_readonly(*mint, false),
+        AccountMeta::new(gas_token_pda, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new_readonly(operator_roles_pda, false),
+    ];
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data: ix_data,
+    })
+}
+
+/// Builds an instruction for the operator to stop accepting `mint` to pay for gas.
+///
+/// # Errors
+/// - ix data cannot be serialized
+pub fn disallow_gas_token_instruction(
+    program_id: &Pubkey,
+    operator: &Pubkey,
+    config_pda: &Pubkey,
+    mint: &Pubkey,
+) -> Result<Instruction, ProgramError> {
+    let (gas_token_pda, _) = get_gas_token_pda(program_id, config_pda, mint);
+    let (operator_roles_pda, _) =
+        role_management::find_user_roles_pda(program_id, config_pda, operator);
+    let ix_data = borsh::to_vec(&GasServiceInstruction::DisallowGasToken)?;
+
+    let accounts = vec![
+        AccountMeta::new(*operator, true),
+        AccountMeta::new_readonly(*config_pda, false),
+        AccountMeta::new(gas_token_pda, false),
+        AccountMeta::new_readonly(operator_roles_pda, false),
+    ];
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data: ix_data,
+    })
+}
+
+/// Builds an instruction to report the balances held by the config in the given tokens, each
+/// given as its mint and the token account of the config in that mint.
+///
+/// # Errors
+/// - ix data cannot be serialized
+pub fn gas_token_balances_instruction(
+    program_id: &Pubkey,
+    config_pda: &Pubkey,
+    tokens: &[(Pubkey, Pubkey)],
+) -> Result<Instruction, ProgramError> {
+    let ix_data = borsh::to_vec(&GasServiceInstruction::GasTokenBalances)?;
+
+    let mut accounts = vec![AccountMeta::new_readonly(*config_pda, false)];
+    for (mint, config_pda_ata) in tokens {
+        let (gas_token_pda, _) = get_gas_token_pda(program_id, config_pda, mint);
+        accounts.push(AccountMeta::new_readonly(gas_token_pda, false));
+        accounts.push(AccountMeta::new_readonly(*config_pda_ata, false));
+    }
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data: ix_data,
+    })
+}
+
+/// Builds an instruction to pay native SOL for a contract call and send it through the gateway.
+///
+/// `sender_call_contract_pda` is the signing PDA and its bump when the sender is a program,
+/// `None` when it's a wallet. The payment is recorded in its ledger PDA, see
+/// [`get_gas_payment_pda`].
+///
+/// # Errors
+/// - ix data cannot be serialized
+#[allow(clippy::too_many_arguments)]
+pub fn call_contract_with_native_gas_instruction(
+    program_id: &Pubkey,
+    gateway_root_pda: &Pubkey,
+    sender: &Pubkey,
+    sender_call_contract_pda: Option<(Pubkey, u8)>,
+    payer: &Pubkey,
+    config_pda: &Pubkey,
+    destination_chain: String,
+    destination_contract_address: String,
+    payload: Vec<u8>,
+    refund_address: Pubkey,
+    params: Vec<u8>,
+    gas_fee_amount: u64,
+) -> Result<Instruction, ProgramError> {
+    let pay_ix = pay_native_for_contract_call_instruction(
+        program_id,
+        payer,
+        config_pda,
+        destination_chain.clone(),
+        destination_contract_address.clone(),
+        solana_program::keccak::hash(&payload).to_bytes(),
+        refund_address,
+        params.clone(),
+        gas_fee_amount,
+        None,
+    )?;
+
+    call_contract_with_gas_instruction(
+        program_id,
+        gateway_root_pda,
+        sender,
+        sender_call_contract_pda,
+        GasServiceInstruction::CallContractWithGas {
+            destination_chain,
+            destination_contract_address,
+            payload,
+            signing_pda_bump: sender_call_contract_pda.map_or(0, |(_, bump)| bump),
+            refund_address,
+            params,
+            gas: CallContractGas::Native { gas_fee_amount },
+        },
+        pay_ix.accounts,
+    )
+}
+
+/// Builds an instruction to pay with SPL tokens for a contract call and send it through the
+/// gateway.
+///
+/// `sender_call_contract_pda` is the signing PDA and its bump when the sender is a program,
+/// `None` when it's a wallet. The payment is recorded in its ledger PDA, see
+/// [`get_gas_payment_pda`], funded by the `payer`.
+///
+/// # Errors
+/// - ix data cannot be serialized
+#[allow(clippy::too_many_arguments)]
+pub fn call_contract_with_spl_gas_instruction(
+    program_id: &Pubkey,
+    gateway_root_pda: &Pubkey,
+    sender: &Pubkey,
+    sender_call_contract_pda: Option<(Pubkey, u8)>,
+    payer: &Pubkey,
+    payer_ata: &Pubkey,
+    config_pda: &Pubkey,
+    config_pda_ata: &Pubkey,
+    mint: &Pubkey,
+    token_program_id: &Pubkey,
+    destination_chain: String,
+    destination_contract_address: String,
+    payload: Vec<u8>,
+    refund_address: Pubkey,
+    params: Vec<u8>,
+    gas_fee_amount: u64,
+    decimals: u8,
+) -> Result<Instruction, ProgramError> {
+    let pay_ix = pay_spl_for_contract_call_instruction(
+        program_id,
+        payer,
+        payer_ata,
+        config_pda,
+        config_pda_ata,
+        mint,
+        token_program_id,
+        destination_chain.clone(),
+        destination_contract_address.clone(),
+        solana_program::keccak::hash(&payload).to_bytes(),
+        refund_address,
+        params.clone(),
+        gas_fee_amount,
+        &[],
+        decimals,
+        payer,
+        None,
+    )?;
+
+    call_contract_with_gas_instruction(
+        program_id,
+        gateway_root_pda,
+        sender,
+        sender_call_contract_pda,
+        GasServiceInstruction::CallContractWithGas {
+            destination_chain,
+            destination_contract_address,
+            payload,
+            signing_pda_bump: sender_call_contract_pda.map_or(0, |(_, bump)| bump),
+            refund_address,
+            params,
+            gas: CallContractGas::Spl {
+                gas_fee_amount,
+                decimals,
+            },
+        },
+        pay_ix.accounts,
+    )
+}
+
+fn call_contract_with_gas_instruction(
+    program_id: &Pubkey,
+    gateway_root_pda: &Pubkey,
+    sender: &Pubkey,
+    sender_call_contract_pda: Option<(Pubkey, u8)>,
+    instruction: GasServiceInstruction,
+    gas_accounts: Vec<AccountMeta>,
+) -> Result<Instruction, ProgramError> {
+    let ix_data = borsh::to_vec(&instruction)?;
+
+    let mut accounts = vec![
+        AccountMeta::new_readonly(*sender, sender_call_contract_pda.is_none()),
+        AccountMeta::new_readonly(
+            sender_call_contract_pda.map_or(axelar_solana_gateway::ID, |(pda, _)| pda),
+            sender_call_contract_pda.is_some(),
+        ),
+        AccountMeta::new_readonly(*gateway_root_pda, false),
+        AccountMeta::new_readonly(axelar_solana_gateway::ID, false),
+    ];
+    accounts.extend(gas_accounts);
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data: ix_data,
+    })
+}
+
+/// Builds an instruction for the operator to configure the treasury of `token`, where `token`
+/// is [`crate::NATIVE_GAS_TOKEN`] for native SOL.
+///
+/// # Errors
+/// - ix data cannot be serialized
+pub fn set_treasury_instruction(
+    program_id: &Pubkey,
+    payer: &Pubkey,
+    operator: &Pubkey,
+    config_pda: &Pubkey,
+    token: &Pubkey,
+    destination: Pubkey,
+    keep_minimum: u64,
+    sweep_interval: i64,
+) -> Result<Instruction, ProgramError> {
+    let (treasury_pda, _) = get_treasury_pda(program_id, config_pda, token);
+    let (operator_roles_pda, _) =
+        role_management::find_user_roles_pda(program_id, config_pda, operator);
+    let ix_data = borsh::to_vec(&GasServiceInstruction::SetTreasury {
+        destination,
+        keep_minimum,
+        sweep_interval,
+    })?;
+
+    let accounts = vec![
+        AccountMeta::new(*payer, true),
+        AccountMeta::new_readonly(*operator, true),
+        AccountMeta::new_readonly(*config_pda, false),
+        AccountMeta::new_readonly(*token, false),
+        AccountMeta::new(treasury_pda, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new_readonly(operator_roles_pda, false),
+    ];
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data: ix_data,
+    })
+}
+
+/// Builds an instruction to sweep the fees accrued in the given tokens to their treasuries.
+///
+/// # Errors
+/// - ix data cannot be serialized
+pub fn sweep_fees_instruction(
+    program_id: &Pubkey,
+    config_pda: &Pubkey,
+    tokens: &[SweptToken],
+) -> Result<Instruction, ProgramError> {
+    let ix_data = borsh::to_vec(&GasServiceInstruction::SweepFees)?;
+
+    let mut accounts = vec![AccountMeta::new(*config_pda, false)];
+    for token in tokens {
+        match *token {
+            SweptToken::Native { destination } => {
+                let (treasury_pda, _) = get_treasury_pda(program_id, config_pda, &NATIVE_GAS_TOKEN);
+                let (gas_reserve_pda, _) =
+                    get_gas_reserve_pda(program_id, config_pda, &NATIVE_GAS_TOKEN);
+                accounts.push(AccountMeta::new(treasury_pda, false));
+                accounts.push(AccountMeta::new(destination, false));
+                accounts.push(AccountMeta::new_readonly(gas_reserve_pda, false));
+            }
+            SweptToken::Spl {
+                mint,
+                token_program_id,
+                config_pda_ata,
+                destination,
+            } => {
+                let (treasury_pda, _) = get_treasury_pda(program_id, config_pda, &mint);
+                let (gas_reserve_pda, _) = get_gas_reserve_pda(program_id, config_pda, &mint);
+                accounts.push(AccountMeta::new(treasury_pda, false));
+                accounts.push(AccountMeta::new(destination, false));
+                accounts.push(AccountMeta::new_readonly(gas_reserve_pda, false));
+                accounts.push(AccountMeta::new(config_pda_ata, false));
+                accounts.push(AccountMeta::new_readonly(mint, false));
+                accounts.push(AccountMeta::new_readonly(token_program_id, false));
+            }
+        }
+    }
+
+    Ok(Instruction {
+        program_id: *program_id,
+        accounts,
+        data: ix_data,
+    })
+}
+
+/// Builds an instruction to release the gas of a payment past its refund window from the gas
+/// held for refunds.
+///
+/// # Errors
+/// - ix data cannot be serialized
+pub fn release_gas_instruction(
+    program_id: &Pubkey,
+    config_pda: &Pubkey,
+    gas_payment_pda: &Pubkey,
+    token: &Pubkey,
+) -> Result<Instruction, ProgramError> {
+    let (gas_reserve_pda, _) = get_gas_reserve_pda(program_id, config_pda, token);
+    let ix_data = borsh::to_vec(&GasServiceInstruction::ReleaseGas)?;
+
+    let accounts = vec![
+        AccountMeta::new_readonly(*config_pda, false),
+        AccountMeta::new(*gas_payment_pda, false),
+        AccountMeta::new(gas_reserve_pda, false),
     ];
 
     Ok(Instruction {
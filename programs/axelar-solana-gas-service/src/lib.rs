@@ -7,6 +7,8 @@ pub mod state;
 
 // Export current sdk types for downstream users building with a different sdk
 // version.
+use bitflags::bitflags;
+use borsh::{BorshDeserialize, BorshSerialize};
 use program_utils::ensure_single_feature;
 pub use solana_program;
 use solana_program::msg;
@@ -31,8 +33,68 @@ solana_program::declare_id!("gas1111111111111111111111111111111111111111");
 pub mod seed_prefixes {
     /// The seed used when deriving the configuration PDA.
     pub const CONFIG_SEED: &[u8] = b"gas-service";
+    /// The seed used when deriving the gas oracle PDA of a destination chain.
+    pub const GAS_ORACLE_SEED: &[u8] = b"gas-oracle";
+    /// The seed used when deriving the PDA allowing an SPL token to be used to pay for gas.
+    pub const GAS_TOKEN_SEED: &[u8] = b"gas-token";
+    /// The seed used when deriving the treasury PDA of a token.
+    pub const TREASURY_SEED: &[u8] = b"treasury";
+    /// The seed used when deriving the ledger PDA of a gas payment.
+    pub const GAS_PAYMENT_SEED: &[u8] = b"gas-payment";
+    /// The seed used when deriving the PDA binding a message ID to the ledger of its payment.
+    pub const GAS_MESSAGE_SEED: &[u8] = b"gas-message";
+    /// The seed used when deriving the gas reserve PDA of a token.
+    pub const GAS_RESERVE_SEED: &[u8] = b"gas-reserve";
 }
 
+bitflags! {
+    /// Roles that can be assigned to a user on a gas service config.
+    #[derive(Debug, Eq, PartialEq, Clone, Copy)]
+    pub struct Roles: u8 {
+        /// Can transfer operatorship and manage the other roles.
+        const OPERATOR = 0b0000_0001;
+
+        /// Can collect the accumulated fees.
+        const COLLECTOR = 0b0000_0010;
+
+        /// Can refund gas payments.
+        const REFUNDER = 0b0000_0100;
+    }
+}
+
+impl PartialEq<u8> for Roles {
+    fn eq(&self, other: &u8) -> bool {
+        self.bits().eq(other)
+    }
+}
+
+impl PartialEq<Roles> for u8 {
+    fn eq(&self, other: &Roles) -> bool {
+        self.eq(&other.bits())
+    }
+}
+
+impl BorshSerialize for Roles {
+    fn serialize<W: std::io::prelude::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        self.bits().serialize(writer)
+    }
+}
+
+impl BorshDeserialize for Roles {
+    fn deserialize_reader<R: std::io::prelude::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let byte = u8::deserialize_reader(reader)?;
+        Ok(Self::from_bits_truncate(byte))
+    }
+}
+
+/// The token recorded in the gas payment ledger for payments made in native SOL.
+pub const NATIVE_GAS_TOKEN: Pubkey = solana_program::system_program::ID;
+
+/// How long, in seconds, the gas of a payment can be refunded after its last payment or top up.
+/// Past it, the gas is no longer held for refunds once released, see
+/// [`instructions::GasServiceInstruction::ReleaseGas`].
+pub const REFUND_WINDOW: i64 = 30 * 24 * 60 * 60;
+
 /// Checks that the provided `program_id` matches the current program’s ID.
 ///
 /// # Errors
@@ -82,3 +144,241 @@ pub fn assert_valid_config_pda(
         Err(ProgramError::IncorrectProgramId)
     }
 }
+
+/// Computes the key of the contract call a gas payment is for, recorded in its ledger.
+#[inline]
+#[must_use]
+pub fn gas_payment_key(
+    destination_chain: &str,
+    destination_address: &str,
+    payload_hash: &[u8; 32],
+) -> [u8; 32] {
+    solana_program::hash::hashv(&[
+        destination_chain.as_bytes(),
+        destination_address.as_bytes(),
+        payload_hash,
+    ])
+    .to_bytes()
+}
+
+/// Computes the hash of a destination chain name, used to derive its gas oracle PDA.
+#[inline]
+#[must_use]
+pub fn chain_hash(destination_chain: &str) -> [u8; 32] {
+    solana_program::hash::hash(destination_chain.as_bytes()).to_bytes()
+}
+
+/// Computes the hash of an Axelar message ID, used to derive its gas message PDA.
+#[inline]
+#[must_use]
+pub fn message_id_hash(message_id: &str) -> [u8; 32] {
+    solana_program::hash::hash(message_id.as_bytes()).to_bytes()
+}
+
+/// Derives the ledger PDA of the gas paid in `token` for the contract call of `payment_key` (see
+/// [`gas_payment_key`]) with `refund_address`, for the given config. `token` is
+/// [`NATIVE_GAS_TOKEN`] for native SOL.
+#[inline]
+#[must_use]
+pub fn get_gas_payment_pda(
+    program_id: &Pubkey,
+    config_pda: &Pubkey,
+    token: &Pubkey,
+    payment_key: &[u8; 32],
+    refund_address: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            seed_prefixes::GAS_PAYMENT_SEED,
+            config_pda.as_ref(),
+            token.as_ref(),
+            payment_key,
+            refund_address.as_ref(),
+        ],
+        program_id,
+    )
+}
+
+/// Derives the PDA binding `message_id` to the ledger of the gas payment made for it, for the
+/// given config.
+#[inline]
+#[must_use]
+pub fn get_gas_message_pda(
+    program_id: &Pubkey,
+    config_pda: &Pubkey,
+    message_id: &str,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            seed_prefixes::GAS_MESSAGE_SEED,
+            config_pda.as_ref(),
+            &message_id_hash(message_id),
+        ],
+        program_id,
+    )
+}
+
+/// Derives the gas oracle PDA of `destination_chain` for the given config.
+#[inline]
+#[must_use]
+pub fn get_gas_oracle_pda(
+    program_id: &Pubkey,
+    config_pda: &Pubkey,
+    destination_chain: &str,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            seed_prefixes::GAS_ORACLE_SEED,
+            config_pda.as_ref(),
+            &chain_hash(destination_chain),
+        ],
+        program_id,
+    )
+}
+
+/// Checks that the given `expected_pubkey` matches the gas oracle PDA for the provided
+/// parameters.
+///
+/// # Errors
+///
+/// - if the seeds + bump don't result in a valid PDA
+/// - if the derived PDA does not match the `expected_pubkey`.
+#[inline]
+pub fn assert_valid_gas_oracle_pda(
+    bump: u8,
+    config_pda: &Pubkey,
+    chain_hash: &[u8; 32],
+    expected_pubkey: &Pubkey,
+) -> Result<(), ProgramError> {
+    let derived_pubkey = Pubkey::create_program_address(
+        &[
+            seed_prefixes::GAS_ORACLE_SEED,
+            config_pda.as_ref(),
+            chain_hash,
+            &[bump],
+        ],
+        &crate::ID,
+    )
+    .map_err(|_err| ProgramError::InvalidSeeds)?;
+
+    if &derived_pubkey == expected_pubkey {
+        Ok(())
+    } else {
+        msg!("Error: Invalid Gas Oracle PDA");
+        Err(ProgramError::InvalidSeeds)
+    }
+}
+
+/// Derives the PDA allowing `mint` to be used to pay for gas to the given config.
+#[inline]
+#[must_use]
+pub fn get_gas_token_pda(program_id: &Pubkey, config_pda: &Pubkey, mint: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            seed_prefixes::GAS_TOKEN_SEED,
+            config_pda.as_ref(),
+            mint.as_ref(),
+        ],
+        program_id,
+    )
+}
+
+/// Checks that the given `expected_pubkey` matches the gas token PDA for the provided
+/// parameters.
+///
+/// # Errors
+///
+/// - if the seeds + bump don't result in a valid PDA
+/// - if the derived PDA does not match the `expected_pubkey`.
+#[inline]
+pub fn assert_valid_gas_token_pda(
+    bump: u8,
+    config_pda: &Pubkey,
+    mint: &Pubkey,
+    expected_pubkey: &Pubkey,
+) -> Result<(), ProgramError> {
+    let derived_pubkey = Pubkey::create_program_address(
+        &[
+            seed_prefixes::GAS_TOKEN_SEED,
+            config_pda.as_ref(),
+            mint.as_ref(),
+            &[bump],
+        ],
+        &crate::ID,
+    )
+    .map_err(|_err| ProgramError::InvalidSeeds)?;
+
+    if &derived_pubkey == expected_pubkey {
+        Ok(())
+    } else {
+        msg!("Error: Invalid Gas Token PDA");
+        Err(ProgramError::InvalidSeeds)
+    }
+}
+
+/// Derives the treasury PDA of `token` for the given config, where `token` is
+/// [`NATIVE_GAS_TOKEN`] for native SOL.
+#[inline]
+#[must_use]
+pub fn get_treasury_pda(program_id: &Pubkey, config_pda: &Pubkey, token: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            seed_prefixes::TREASURY_SEED,
+            config_pda.as_ref(),
+            token.as_ref(),
+        ],
+        program_id,
+    )
+}
+
+/// Derives the gas reserve PDA of `token` for the given config, where `token` is
+/// [`NATIVE_GAS_TOKEN`] for native SOL.
+#[inline]
+#[must_use]
+pub fn get_gas_reserve_pda(
+    program_id: &Pubkey,
+    config_pda: &Pubkey,
+    token: &Pubkey,
+) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            seed_prefixes::GAS_RESERVE_SEED,
+            config_pda.as_ref(),
+            token.as_ref(),
+        ],
+        program_id,
+    )
+}
+
+/// Checks that the given `expected_pubkey` matches the treasury PDA for the provided
+/// parameters.
+///
+/// # Errors
+///
+/// - if the seeds + bump don't result in a valid PDA
+/// - if the derived PDA does not match the `expected_pubkey`.
+#[inline]
+pub fn assert_valid_treasury_pda(
+    bump: u8,
+    config_pda: &Pubkey,
+    token: &Pubkey,
+    expected_pubkey: &Pubkey,
+) -> Result<(), ProgramError> {
+    let derived_pubkey = Pubkey::create_program_address(
+        &[
+            seed_prefixes::TREASURY_SEED,
+            config_pda.as_ref(),
+            token.as_ref(),
+            &[bump],
+        ],
+        &crate::ID,
+    )
+    .map_err(|_err| ProgramError::InvalidSeeds)?;
+
+    if &derived_pubkey == expected_pubkey {
+        Ok(())
+    } else {
+        msg!("Error: Invalid Treasury PDA");
+        Err(ProgramError::InvalidSeeds)
+    }
+}
@@ -9,25 +9,42 @@ use crate::{
 };
 
 use self::{
-    initialize::process_initialize_config,
+    call_contract::process_call_contract_with_gas,
+    gas_oracle::{
+        process_estimate_gas_fee, process_set_gas_oracle_updater, process_update_gas_oracle,
+    },
+    gas_payment::process_release_gas,
+    gas_token::{process_allow_gas_token, process_disallow_gas_token, process_gas_token_balances},
+    initialize::{process_initialize_config, process_migrate_operator_roles},
     native::{
         add_native_gas, collect_fees_native, process_pay_native_for_contract_call, refund_native,
     },
+    operator::{
+        process_accept_operatorship, process_add_roles, process_propose_operatorship,
+        process_remove_roles,
+    },
     spl::{add_spl_gas, collect_fees_spl, process_pay_spl_for_contract_call, refund_spl},
+    treasury::{process_set_treasury, process_sweep_fees},
 };
 
+mod call_contract;
+mod gas_oracle;
+mod gas_payment;
+mod gas_token;
 mod initialize;
 mod native;
+mod operator;
 mod spl;
+mod treasury;
 
 /// Processes an instruction.
 ///
 /// # Errors
 /// - if the ix processing resulted in an error
-#[allow(clippy::todo)]
-pub fn process_instruction(
+#[allow(clippy::todo, clippy::too_many_lines)]
+pub fn process_instruction<'a>(
     program_id: &Pubkey,
-    accounts: &[AccountInfo<'_>],
+    accounts: &'a [AccountInfo<'a>],
     input: &[u8],
 ) -> ProgramResult {
     let instruction = GasServiceInstruction::try_from_slice(input)?;
@@ -46,6 +63,7 @@ pub fn process_instruction(
                 params,
                 decimals,
                 refund_address,
+                checked_payload_len,
             } => process_pay_spl_for_contract_call(
                 program_id,
                 accounts,
@@ -56,18 +74,17 @@ pub fn process_instruction(
                 &params,
                 gas_fee_amount,
                 decimals,
+                checked_payload_len,
             ),
             PayWithSplToken::AddGas {
-                tx_hash,
-                log_index,
+                message_id,
                 gas_fee_amount,
                 decimals,
                 refund_address,
             } => add_spl_gas(
                 program_id,
                 accounts,
-                tx_hash,
-                log_index,
+                message_id,
                 gas_fee_amount,
                 refund_address,
                 decimals,
@@ -76,11 +93,10 @@ pub fn process_instruction(
                 collect_fees_spl(program_id, accounts, amount, decimals)
             }
             PayWithSplToken::Refund {
-                tx_hash,
-                log_index,
+                message_id,
                 fees,
                 decimals,
-            } => refund_spl(program_id, accounts, tx_hash, log_index, fees, decimals),
+            } => refund_spl(program_id, accounts, message_id, fees, decimals),
         },
         GasServiceInstruction::Native(ix) => match ix {
             PayWithNativeToken::ForContractCall {
@@ -90,6 +106,7 @@ pub fn process_instruction(
                 refund_address,
                 params,
                 gas_fee_amount,
+                checked_payload_len,
             } => process_pay_native_for_contract_call(
                 program_id,
                 accounts,
@@ -99,28 +116,97 @@ pub fn process_instruction(
                 refund_address,
                 &params,
                 gas_fee_amount,
+                checked_payload_len,
             ),
             PayWithNativeToken::AddGas {
-                tx_hash,
-                log_index,
+                message_id,
                 gas_fee_amount,
                 refund_address,
             } => add_native_gas(
                 program_id,
                 accounts,
-                tx_hash,
-                log_index,
+                message_id,
                 gas_fee_amount,
                 refund_address,
             ),
             PayWithNativeToken::CollectFees { amount } => {
                 collect_fees_native(program_id, accounts, amount)
             }
-            PayWithNativeToken::Refund {
-                tx_hash,
-                log_index,
-                fees,
-            } => refund_native(program_id, accounts, tx_hash, log_index, fees),
+            PayWithNativeToken::Refund { message_id, fees } => {
+                refund_native(program_id, accounts, message_id, fees)
+            }
         },
+        GasServiceInstruction::SetGasOracleUpdater {
+            destination_chain,
+            updater,
+        } => process_set_gas_oracle_updater(program_id, accounts, &destination_chain, updater),
+        GasServiceInstruction::UpdateGasOracle {
+            destination_chain,
+            base_fee,
+            fee_per_byte,
+            token_to_sol_rate,
+        } => process_update_gas_oracle(
+            program_id,
+            accounts,
+            &destination_chain,
+            base_fee,
+            fee_per_byte,
+            token_to_sol_rate,
+        ),
+        GasServiceInstruction::EstimateGasFee {
+            destination_chain,
+            payload_len,
+        } => process_estimate_gas_fee(program_id, accounts, &destination_chain, payload_len),
+        GasServiceInstruction::ProposeOperatorship { expires_at } => {
+            process_propose_operatorship(program_id, accounts, expires_at)
+        }
+        GasServiceInstruction::AcceptOperatorship => {
+            process_accept_operatorship(program_id, accounts)
+        }
+        GasServiceInstruction::AddRoles { roles } => process_add_roles(program_id, accounts, roles),
+        GasServiceInstruction::RemoveRoles { roles } => {
+            process_remove_roles(program_id, accounts, roles)
+        }
+        GasServiceInstruction::AllowGasToken {
+            min_payment,
+            lamports_to_token_rate,
+        } => process_allow_gas_token(program_id, accounts, min_payment, lamports_to_token_rate),
+        GasServiceInstruction::DisallowGasToken => process_disallow_gas_token(program_id, accounts),
+        GasServiceInstruction::GasTokenBalances => process_gas_token_balances(program_id, accounts),
+        GasServiceInstruction::CallContractWithGas {
+            destination_chain,
+            destination_contract_address,
+            payload,
+            signing_pda_bump,
+            refund_address,
+            params,
+            gas,
+        } => process_call_contract_with_gas(
+            program_id,
+            accounts,
+            destination_chain,
+            destination_contract_address,
+            payload,
+            signing_pda_bump,
+            refund_address,
+            &params,
+            gas,
+        ),
+        GasServiceInstruction::SetTreasury {
+            destination,
+            keep_minimum,
+            sweep_interval,
+        } => process_set_treasury(
+            program_id,
+            accounts,
+            destination,
+            keep_minimum,
+            sweep_interval,
+        ),
+        GasServiceInstruction::SweepFees => process_sweep_fees(program_id, accounts),
+        GasServiceInstruction::MigrateOperatorRoles => {
+            process_migrate_operator_roles(program_id, accounts)
+        }
+        GasServiceInstruction::ReleaseGas => process_release_gas(program_id, accounts),
     }
 }
@@ -0,0 +1,90 @@
+//! Gas payment bundled with the gateway `CallContract`.
+
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program::invoke;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+use super::native::process_pay_native_for_contract_call;
+use super::spl::process_pay_spl_for_contract_call;
+use crate::instructions::CallContractGas;
+
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn process_call_contract_with_gas(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo<'_>],
+    destination_chain: String,
+    destination_contract_address: String,
+    payload: Vec<u8>,
+    signing_pda_bump: u8,
+    refund_address: Pubkey,
+    params: &[u8],
+    gas: CallContractGas,
+) -> ProgramResult {
+    let [sender, signing_pda, gateway_root_pda, gateway_program, gas_accounts @ ..] = accounts
+    else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    if gateway_program.key != &axelar_solana_gateway::ID {
+        msg!("Invalid gateway program");
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    // The gas is paid for the very contract call sent through the gateway
+    let payload_hash = solana_program::keccak::hash(&payload).to_bytes();
+    match gas {
+        CallContractGas::Native { gas_fee_amount } => process_pay_native_for_contract_call(
+            program_id,
+            gas_accounts,
+            destination_chain.clone(),
+            destination_contract_address.clone(),
+            payload_hash,
+            refund_address,
+            params,
+            gas_fee_amount,
+            None,
+        )?,
+        CallContractGas::Spl {
+            gas_fee_amount,
+            decimals,
+        } => process_pay_spl_for_contract_call(
+            program_id,
+            gas_accounts,
+            destination_chain.clone(),
+            destination_contract_address.clone(),
+            payload_hash,
+            refund_address,
+            params,
+            gas_fee_amount,
+            decimals,
+            None,
+        )?,
+    }
+
+    // The signer privileges of the sender, or of the signing PDA of the sender program, carry
+    // over to the gateway.
+    let ix = axelar_solana_gateway::instructions::call_contract(
+        *gateway_program.key,
+        *gateway_root_pda.key,
+        *sender.key,
+        sender
+            .executable
+            .then_some((*signing_pda.key, signing_pda_bump)),
+        destination_chain,
+        destination_contract_address,
+        payload,
+    )?;
+
+    invoke(
+        &ix,
+        &[
+            sender.clone(),
+            signing_pda.clone(),
+            gateway_root_pda.clone(),
+            gateway_program.clone(),
+        ],
+    )
+}
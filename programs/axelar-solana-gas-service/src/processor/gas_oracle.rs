@@ -0,0 +1,198 @@
+//! Gas oracle management and fee estimation.
+
+use core::mem::size_of;
+
+use program_utils::pda::{BytemuckedPda, ValidPDA};
+use program_utils::validate_system_account_key;
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::clock::Clock;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program::set_return_data;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+use solana_program::sysvar::Sysvar;
+
+use super::native::try_load_config;
+use super::operator::ensure_operator;
+use crate::state::GasOracle;
+use crate::{assert_valid_gas_oracle_pda, chain_hash, get_gas_oracle_pda, seed_prefixes};
+
+pub(crate) fn process_set_gas_oracle_updater(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo<'_>],
+    destination_chain: &str,
+    updater: Pubkey,
+) -> ProgramResult {
+    let accounts = &mut accounts.iter();
+    let payer = next_account_info(accounts)?;
+    let operator = next_account_info(accounts)?;
+    let config_pda = next_account_info(accounts)?;
+    let gas_oracle_pda = next_account_info(accounts)?;
+    let system_program = next_account_info(accounts)?;
+    let operator_roles_pda = next_account_info(accounts)?;
+
+    validate_system_account_key(system_program.key)?;
+    ensure_operator(program_id, config_pda, operator, operator_roles_pda)?;
+
+    let chain_hash = chain_hash(destination_chain);
+    let (expected_pda, bump) = get_gas_oracle_pda(program_id, config_pda.key, destination_chain);
+    if gas_oracle_pda.key != &expected_pda {
+        msg!("Invalid gas oracle PDA");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    if gas_oracle_pda.data_is_empty() {
+        program_utils::pda::init_pda_raw(
+            payer,
+            gas_oracle_pda,
+            program_id,
+            system_program,
+            size_of::<GasOracle>()
+                .try_into()
+                .map_err(|_err| ProgramError::ArithmeticOverflow)?,
+            &[
+                seed_prefixes::GAS_ORACLE_SEED,
+                config_pda.key.as_ref(),
+                &chain_hash,
+                &[bump],
+            ],
+        )?;
+
+        let mut data = gas_oracle_pda.try_borrow_mut_data()?;
+        let gas_oracle = GasOracle::read_mut(&mut data).ok_or(ProgramError::InvalidAccountData)?;
+        *gas_oracle = GasOracle {
+            config_pda: *config_pda.key,
+            chain_hash,
+            updater,
+            base_fee: 0,
+            fee_per_byte: 0,
+            token_to_sol_rate: 0,
+            updated_at: 0,
+            bump,
+            padding: [0; 7],
+        };
+
+        return Ok(());
+    }
+
+    let mut data = gas_oracle_pda.try_borrow_mut_data()?;
+    let gas_oracle = GasOracle::read_mut(&mut data).ok_or(ProgramError::InvalidAccountData)?;
+    gas_oracle.updater = updater;
+
+    Ok(())
+}
+
+pub(crate) fn process_update_gas_oracle(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo<'_>],
+    destination_chain: &str,
+    base_fee: u64,
+    fee_per_byte: u64,
+    token_to_sol_rate: u64,
+) -> ProgramResult {
+    let accounts = &mut accounts.iter();
+    let updater = next_account_info(accounts)?;
+    let config_pda = next_account_info(accounts)?;
+    let gas_oracle_pda = next_account_info(accounts)?;
+
+    try_load_config(program_id, config_pda)?;
+
+    if !updater.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let gas_oracle = load_gas_oracle(
+        program_id,
+        gas_oracle_pda,
+        config_pda.key,
+        destination_chain,
+    )?;
+
+    if updater.key != &gas_oracle.updater {
+        msg!("Only the gas oracle updater can update the gas prices");
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+
+    let mut data = gas_oracle_pda.try_borrow_mut_data()?;
+    let gas_oracle = GasOracle::read_mut(&mut data).ok_or(ProgramError::InvalidAccountData)?;
+    gas_oracle.base_fee = base_fee;
+    gas_oracle.fee_per_byte = fee_per_byte;
+    gas_oracle.token_to_sol_rate = token_to_sol_rate;
+    gas_oracle.updated_at = Clock::get()?.unix_timestamp;
+
+    Ok(())
+}
+
+pub(crate) fn process_estimate_gas_fee(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo<'_>],
+    destination_chain: &str,
+    payload_len: u64,
+) -> ProgramResult {
+    let accounts = &mut accounts.iter();
+    let config_pda = next_account_info(accounts)?;
+    let gas_oracle_pda = next_account_info(accounts)?;
+
+    try_load_config(program_id, config_pda)?;
+
+    let fee = estimate_gas_fee(
+        program_id,
+        gas_oracle_pda,
+        config_pda.key,
+        destination_chain,
+        payload_len,
+    )?;
+    set_return_data(&fee.to_le_bytes());
+
+    Ok(())
+}
+
+/// Quotes the gas fee, in lamports, of a contract call to `destination_chain` with a payload of
+/// `payload_len` bytes. Oracles whose prices were never set or are stale can't quote fees.
+pub(crate) fn estimate_gas_fee(
+    program_id: &Pubkey,
+    gas_oracle_pda: &AccountInfo<'_>,
+    config_pda: &Pubkey,
+    destination_chain: &str,
+    payload_len: u64,
+) -> Result<u64, ProgramError> {
+    let gas_oracle = load_gas_oracle(program_id, gas_oracle_pda, config_pda, destination_chain)?;
+
+    if !gas_oracle.is_fresh(Clock::get()?.unix_timestamp) {
+        msg!("Gas oracle prices are not set or are stale");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    gas_oracle.estimate_fee(payload_len).ok_or_else(|| {
+        msg!("Gas fee estimate overflows");
+        ProgramError::ArithmeticOverflow
+    })
+}
+
+fn load_gas_oracle(
+    program_id: &Pubkey,
+    gas_oracle_pda: &AccountInfo<'_>,
+    config_pda: &Pubkey,
+    destination_chain: &str,
+) -> Result<GasOracle, ProgramError> {
+    gas_oracle_pda.check_initialized_pda_without_deserialization(program_id)?;
+    let data = gas_oracle_pda.try_borrow_data()?;
+    let gas_oracle = GasOracle::read(&data).ok_or(ProgramError::InvalidAccountData)?;
+
+    assert_valid_gas_oracle_pda(
+        gas_oracle.bump,
+        &gas_oracle.config_pda,
+        &gas_oracle.chain_hash,
+        gas_oracle_pda.key,
+    )?;
+
+    if &gas_oracle.config_pda != config_pda
+        || gas_oracle.chain_hash != chain_hash(destination_chain)
+    {
+        msg!("Gas oracle doesn't match the config or destination chain");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    Ok(*gas_oracle)
+}
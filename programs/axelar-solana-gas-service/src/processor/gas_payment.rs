@@ -0,0 +1,442 @@
+//! Bookkeeping of the per-payment gas ledger.
+
+use core::mem::size_of;
+use core::str::FromStr;
+
+use axelar_message_primitives::MessageId;
+use program_utils::pda::{BytemuckedPda, ValidPDA};
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::clock::Clock;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+use solana_program::sysvar::Sysvar;
+
+use super::native::try_load_config;
+use crate::state::{GasMessage, GasPayment, GasReserve};
+use crate::{
+    get_gas_message_pda, get_gas_payment_pda, get_gas_reserve_pda, message_id_hash, seed_prefixes,
+    REFUND_WINDOW,
+};
+
+pub(crate) fn process_release_gas(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo<'_>],
+) -> ProgramResult {
+    let accounts = &mut accounts.iter();
+    let config_pda = next_account_info(accounts)?;
+    let gas_payment_pda = next_account_info(accounts)?;
+    let gas_reserve_pda = next_account_info(accounts)?;
+
+    try_load_config(program_id, config_pda)?;
+
+    gas_payment_pda.check_initialized_pda_without_deserialization(program_id)?;
+    let mut data = gas_payment_pda.try_borrow_mut_data()?;
+    let gas_payment = GasPayment::read_mut(&mut data).ok_or(ProgramError::InvalidAccountData)?;
+
+    if &gas_payment.config_pda != config_pda.key {
+        msg!("Gas payment doesn't match the config");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    if Clock::get()?.unix_timestamp <= gas_payment.refundable_until {
+        msg!("Gas payment can still be refunded");
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let token = gas_payment.token;
+    let amount = gas_payment.refundable();
+    gas_payment.released = gas_payment
+        .released
+        .checked_add(amount)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    drop(data);
+
+    if amount > 0 {
+        release_gas(program_id, config_pda.key, &token, gas_reserve_pda, amount)?;
+    }
+
+    Ok(())
+}
+
+/// Ensures `message_id` is a message ID in the Axelar format, see [`MessageId`].
+pub(crate) fn ensure_valid_message_id(message_id: &str) -> ProgramResult {
+    if let Err(err) = MessageId::from_str(message_id) {
+        msg!("Invalid message ID: {}", err);
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    Ok(())
+}
+
+/// Records a payment of `amount` in the ledger PDA of the payment, creating it, funded by
+/// `payer`, on the first payment. Further payments for the same contract call, in the same token
+/// and with the same refund address, accumulate in the same ledger, each one reopening its
+/// refund window.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn record_paid<'a>(
+    program_id: &Pubkey,
+    payer: &AccountInfo<'a>,
+    gas_payment_pda: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+    config_pda: &Pubkey,
+    token: &Pubkey,
+    payment_key: [u8; 32],
+    refund_address: Pubkey,
+    amount: u64,
+) -> ProgramResult {
+    let (expected_pda, bump) =
+        get_gas_payment_pda(program_id, config_pda, token, &payment_key, &refund_address);
+    if gas_payment_pda.key != &expected_pda {
+        msg!("Invalid gas payment PDA");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    if gas_payment_pda.data_is_empty() {
+        program_utils::pda::init_pda_raw(
+            payer,
+            gas_payment_pda,
+            program_id,
+            system_program,
+            size_of::<GasPayment>()
+                .try_into()
+                .map_err(|_err| ProgramError::ArithmeticOverflow)?,
+            &[
+                seed_prefixes::GAS_PAYMENT_SEED,
+                config_pda.as_ref(),
+                token.as_ref(),
+                &payment_key,
+                refund_address.as_ref(),
+                &[bump],
+            ],
+        )?;
+
+        let mut data = gas_payment_pda.try_borrow_mut_data()?;
+        let gas_payment =
+            GasPayment::read_mut(&mut data).ok_or(ProgramError::InvalidAccountData)?;
+        *gas_payment = GasPayment {
+            config_pda: *config_pda,
+            token: *token,
+            payment_key,
+            refund_address,
+            paid: 0,
+            added: 0,
+            refunded: 0,
+            released: 0,
+            refundable_until: 0,
+            message_id_hash: [0; 32],
+            bump,
+            padding: [0; 7],
+        };
+    }
+
+    update_gas_payment(
+        program_id,
+        gas_payment_pda,
+        config_pda,
+        token,
+        |gas_payment| {
+            gas_payment.paid = gas_payment
+                .paid
+                .checked_add(amount)
+                .ok_or(ProgramError::ArithmeticOverflow)?;
+            gas_payment.refundable_until = refund_window_end()?;
+            Ok(())
+        },
+    )
+}
+
+/// Records `amount` as added to an existing payment. Gas can only be added with the refund
+/// address of the payment, so that whoever paid is the only one who can get it back, and with
+/// the message ID the payment is bound to, if any. Adding gas never binds a message ID, see
+/// [`bind_message_id`], and reopens the refund window of the payment.
+pub(crate) fn record_added(
+    program_id: &Pubkey,
+    gas_payment_pda: &AccountInfo<'_>,
+    config_pda: &Pubkey,
+    token: &Pubkey,
+    refund_address: &Pubkey,
+    message_id: &str,
+    amount: u64,
+) -> ProgramResult {
+    update_gas_payment(
+        program_id,
+        gas_payment_pda,
+        config_pda,
+        token,
+        |gas_payment| {
+            if &gas_payment.refund_address != refund_address {
+                msg!("Gas can only be added with the refund address of the payment");
+                return Err(ProgramError::InvalidArgument);
+            }
+
+            if gas_payment.message_id_hash != [0; 32]
+                && gas_payment.message_id_hash != message_id_hash(message_id)
+            {
+                msg!("Gas payment is bound to another message ID");
+                return Err(ProgramError::InvalidArgument);
+            }
+
+            gas_payment.added = gas_payment
+                .added
+                .checked_add(amount)
+                .ok_or(ProgramError::ArithmeticOverflow)?;
+            gas_payment.refundable_until = refund_window_end()?;
+            Ok(())
+        },
+    )
+}
+
+/// Records `amount` as refunded to `receiver` for an existing payment, making sure the
+/// receiver is the refund address of the payment, that its refund window isn't over and that no
+/// more is refunded than what was paid and added for it.
+pub(crate) fn record_refunded(
+    program_id: &Pubkey,
+    gas_payment_pda: &AccountInfo<'_>,
+    config_pda: &Pubkey,
+    token: &Pubkey,
+    receiver: &Pubkey,
+    amount: u64,
+) -> ProgramResult {
+    update_gas_payment(
+        program_id,
+        gas_payment_pda,
+        config_pda,
+        token,
+        |gas_payment| {
+            if &gas_payment.refund_address != receiver {
+                msg!("Gas can only be refunded to the refund address of the payment");
+                return Err(ProgramError::InvalidArgument);
+            }
+
+            if Clock::get()?.unix_timestamp > gas_payment.refundable_until {
+                msg!("Refund window of the gas payment is over");
+                return Err(ProgramError::InvalidArgument);
+            }
+
+            if amount > gas_payment.refundable() {
+                msg!("Refund exceeds the gas paid for this payment");
+                return Err(ProgramError::InsufficientFunds);
+            }
+
+            gas_payment.refunded = gas_payment
+                .refunded
+                .checked_add(amount)
+                .ok_or(ProgramError::ArithmeticOverflow)?;
+            Ok(())
+        },
+    )
+}
+
+/// Binds `message_id` to the ledger in `gas_payment_pda` on the first refund of the payment,
+/// creating the gas message PDA of the message ID, funded by `payer`. Creating it fails if the
+/// message ID is already bound to another ledger, and later top ups and refunds of the payment
+/// must use the same message ID.
+///
+/// Only refunds, issued by the refunder, bind message IDs: anyone can top up any payment, so a
+/// top up binding them would let anyone lock a ledger to a wrong message ID, or squat the
+/// message ID of another payment with a ledger of their own.
+///
+/// Must be called after the ledger was checked by [`record_refunded`].
+pub(crate) fn bind_message_id<'a>(
+    program_id: &Pubkey,
+    payer: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+    config_pda: &Pubkey,
+    gas_payment_pda: &AccountInfo<'a>,
+    gas_message_pda: &AccountInfo<'a>,
+    message_id: &str,
+) -> ProgramResult {
+    let message_id_hash = message_id_hash(message_id);
+    let (expected_pda, bump) = get_gas_message_pda(program_id, config_pda, message_id);
+    if gas_message_pda.key != &expected_pda {
+        msg!("Invalid gas message PDA");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    let mut data = gas_payment_pda.try_borrow_mut_data()?;
+    let gas_payment = GasPayment::read_mut(&mut data).ok_or(ProgramError::InvalidAccountData)?;
+
+    if gas_payment.message_id_hash != [0; 32] {
+        if gas_payment.message_id_hash != message_id_hash {
+            msg!("Gas payment is bound to another message ID");
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        return Ok(());
+    }
+
+    if !gas_message_pda.data_is_empty() {
+        msg!("Message ID is bound to another gas payment");
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    gas_payment.message_id_hash = message_id_hash;
+    drop(data);
+
+    program_utils::pda::init_pda_raw(
+        payer,
+        gas_message_pda,
+        program_id,
+        system_program,
+        size_of::<GasMessage>()
+            .try_into()
+            .map_err(|_err| ProgramError::ArithmeticOverflow)?,
+        &[
+            seed_prefixes::GAS_MESSAGE_SEED,
+            config_pda.as_ref(),
+            &message_id_hash,
+            &[bump],
+        ],
+    )?;
+
+    let mut data = gas_message_pda.try_borrow_mut_data()?;
+    let gas_message = GasMessage::read_mut(&mut data).ok_or(ProgramError::InvalidAccountData)?;
+    *gas_message = GasMessage {
+        gas_payment: *gas_payment_pda.key,
+        bump,
+        padding: [0; 7],
+    };
+
+    Ok(())
+}
+
+/// Adds `amount` to the gas held for refunds in `token`, creating the gas reserve PDA of the
+/// token, funded by `payer`, on its first payment.
+pub(crate) fn reserve_gas<'a>(
+    program_id: &Pubkey,
+    payer: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+    config_pda: &Pubkey,
+    token: &Pubkey,
+    gas_reserve_pda: &AccountInfo<'a>,
+    amount: u64,
+) -> ProgramResult {
+    let bump = ensure_gas_reserve_pda(program_id, config_pda, token, gas_reserve_pda)?;
+
+    if gas_reserve_pda.data_is_empty() {
+        program_utils::pda::init_pda_raw(
+            payer,
+            gas_reserve_pda,
+            program_id,
+            system_program,
+            size_of::<GasReserve>()
+                .try_into()
+                .map_err(|_err| ProgramError::ArithmeticOverflow)?,
+            &[
+                seed_prefixes::GAS_RESERVE_SEED,
+                config_pda.as_ref(),
+                token.as_ref(),
+                &[bump],
+            ],
+        )?;
+
+        let mut data = gas_reserve_pda.try_borrow_mut_data()?;
+        let gas_reserve =
+            GasReserve::read_mut(&mut data).ok_or(ProgramError::InvalidAccountData)?;
+        *gas_reserve = GasReserve {
+            config_pda: *config_pda,
+            token: *token,
+            refundable: 0,
+            bump,
+            padding: [0; 7],
+        };
+    }
+
+    let mut data = gas_reserve_pda.try_borrow_mut_data()?;
+    let gas_reserve = GasReserve::read_mut(&mut data).ok_or(ProgramError::InvalidAccountData)?;
+    gas_reserve.refundable = gas_reserve
+        .refundable
+        .checked_add(amount)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    Ok(())
+}
+
+/// Removes `amount` refunded or released in `token` from the gas held for refunds.
+pub(crate) fn release_gas(
+    program_id: &Pubkey,
+    config_pda: &Pubkey,
+    token: &Pubkey,
+    gas_reserve_pda: &AccountInfo<'_>,
+    amount: u64,
+) -> ProgramResult {
+    ensure_gas_reserve_pda(program_id, config_pda, token, gas_reserve_pda)?;
+
+    gas_reserve_pda.check_initialized_pda_without_deserialization(program_id)?;
+    let mut data = gas_reserve_pda.try_borrow_mut_data()?;
+    let gas_reserve = GasReserve::read_mut(&mut data).ok_or(ProgramError::InvalidAccountData)?;
+    gas_reserve.refundable = gas_reserve.refundable.checked_sub(amount).ok_or_else(|| {
+        msg!("Released gas exceeds the gas held for refunds");
+        ProgramError::ArithmeticOverflow
+    })?;
+
+    Ok(())
+}
+
+/// Returns the gas held for refunds in `token`.
+pub(crate) fn reserved_gas(
+    program_id: &Pubkey,
+    config_pda: &Pubkey,
+    token: &Pubkey,
+    gas_reserve_pda: &AccountInfo<'_>,
+) -> Result<u64, ProgramError> {
+    ensure_gas_reserve_pda(program_id, config_pda, token, gas_reserve_pda)?;
+
+    // Nothing was ever paid in the token
+    if gas_reserve_pda.data_is_empty() {
+        return Ok(0);
+    }
+
+    gas_reserve_pda.check_initialized_pda_without_deserialization(program_id)?;
+    let data = gas_reserve_pda.try_borrow_data()?;
+    let gas_reserve = GasReserve::read(&data).ok_or(ProgramError::InvalidAccountData)?;
+    Ok(gas_reserve.refundable)
+}
+
+/// Returns the end of the refund window of a payment or top up made now.
+fn refund_window_end() -> Result<i64, ProgramError> {
+    Clock::get()?
+        .unix_timestamp
+        .checked_add(REFUND_WINDOW)
+        .ok_or(ProgramError::ArithmeticOverflow)
+}
+
+/// Ensures `gas_reserve_pda` is the gas reserve PDA of `token`, returning its bump.
+fn ensure_gas_reserve_pda(
+    program_id: &Pubkey,
+    config_pda: &Pubkey,
+    token: &Pubkey,
+    gas_reserve_pda: &AccountInfo<'_>,
+) -> Result<u8, ProgramError> {
+    let (expected_pda, bump) = get_gas_reserve_pda(program_id, config_pda, token);
+    if gas_reserve_pda.key != &expected_pda {
+        msg!("Invalid gas reserve PDA");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    Ok(bump)
+}
+
+fn update_gas_payment<F>(
+    program_id: &Pubkey,
+    gas_payment_pda: &AccountInfo<'_>,
+    config_pda: &Pubkey,
+    token: &Pubkey,
+    update: F,
+) -> ProgramResult
+where
+    F: FnOnce(&mut GasPayment) -> ProgramResult,
+{
+    gas_payment_pda.check_initialized_pda_without_deserialization(program_id)?;
+    let mut data = gas_payment_pda.try_borrow_mut_data()?;
+    let gas_payment = GasPayment::read_mut(&mut data).ok_or(ProgramError::InvalidAccountData)?;
+
+    if &gas_payment.config_pda != config_pda || &gas_payment.token != token {
+        msg!("Gas payment doesn't match the config or token");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    update(gas_payment)
+}
@@ -0,0 +1,190 @@
+//! Allowlist of the SPL tokens accepted to pay for gas.
+
+use core::mem::size_of;
+
+use program_utils::pda::{close_pda, BytemuckedPda, ValidPDA};
+use program_utils::validate_system_account_key;
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program::set_return_data;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+use spl_token_2022::extension::StateWithExtensions;
+
+use super::native::try_load_config;
+use super::operator::ensure_operator;
+use crate::state::{GasToken, GasTokenBalance};
+use crate::{assert_valid_gas_token_pda, get_gas_token_pda, seed_prefixes};
+
+pub(crate) fn process_allow_gas_token(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo<'_>],
+    min_payment: u64,
+    lamports_to_token_rate: u64,
+) -> ProgramResult {
+    let accounts = &mut accounts.iter();
+    let payer = next_account_info(accounts)?;
+    let operator = next_account_info(accounts)?;
+    let config_pda = next_account_info(accounts)?;
+    let mint = next_account_info(accounts)?;
+    let gas_token_pda = next_account_info(accounts)?;
+    let system_program = next_account_info(accounts)?;
+    let operator_roles_pda = next_account_info(accounts)?;
+
+    validate_system_account_key(system_program.key)?;
+    ensure_operator(program_id, config_pda, operator, operator_roles_pda)?;
+
+    // Check: the mint belongs to a token program
+    spl_token_2022::check_spl_token_program_account(mint.owner)?;
+
+    let (expected_pda, bump) = get_gas_token_pda(program_id, config_pda.key, mint.key);
+    if gas_token_pda.key != &expected_pda {
+        msg!("Invalid gas token PDA");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    if gas_token_pda.data_is_empty() {
+        program_utils::pda::init_pda_raw(
+            payer,
+            gas_token_pda,
+            program_id,
+            system_program,
+            size_of::<GasToken>()
+                .try_into()
+                .map_err(|_err| ProgramError::ArithmeticOverflow)?,
+            &[
+                seed_prefixes::GAS_TOKEN_SEED,
+                config_pda.key.as_ref(),
+                mint.key.as_ref(),
+                &[bump],
+            ],
+        )?;
+
+        let mut data = gas_token_pda.try_borrow_mut_data()?;
+        let gas_token = GasToken::read_mut(&mut data).ok_or(ProgramError::InvalidAccountData)?;
+        *gas_token = GasToken {
+            config_pda: *config_pda.key,
+            mint: *mint.key,
+            min_payment,
+            lamports_to_token_rate,
+            bump,
+            padding: [0; 7],
+        };
+
+        return Ok(());
+    }
+
+    let mut data = gas_token_pda.try_borrow_mut_data()?;
+    let gas_token = GasToken::read_mut(&mut data).ok_or(ProgramError::InvalidAccountData)?;
+    gas_token.min_payment = min_payment;
+    gas_token.lamports_to_token_rate = lamports_to_token_rate;
+
+    Ok(())
+}
+
+pub(crate) fn process_disallow_gas_token(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo<'_>],
+) -> ProgramResult {
+    let accounts = &mut accounts.iter();
+    let operator = next_account_info(accounts)?;
+    let config_pda = next_account_info(accounts)?;
+    let gas_token_pda = next_account_info(accounts)?;
+    let operator_roles_pda = next_account_info(accounts)?;
+
+    ensure_operator(program_id, config_pda, operator, operator_roles_pda)?;
+    load_gas_token(program_id, gas_token_pda, config_pda.key)?;
+
+    close_pda(operator, gas_token_pda)
+}
+
+/// Reports the balance held by the config in each of the given tokens, as a borsh-encoded
+/// `Vec<GasTokenBalance>` through the return data.
+pub(crate) fn process_gas_token_balances(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo<'_>],
+) -> ProgramResult {
+    let (config_pda, token_accounts) = accounts
+        .split_first()
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
+
+    try_load_config(program_id, config_pda)?;
+
+    let token_accounts = token_accounts.chunks_exact(2);
+    if !token_accounts.remainder().is_empty() {
+        msg!("Each gas token PDA must be followed by the token account of the config");
+        return Err(ProgramError::NotEnoughAccountKeys);
+    }
+
+    let mut balances = Vec::with_capacity(token_accounts.len());
+    for pair in token_accounts {
+        let [gas_token_pda, config_pda_ata] = pair else {
+            return Err(ProgramError::NotEnoughAccountKeys);
+        };
+
+        let gas_token = load_gas_token(program_id, gas_token_pda, config_pda.key)?;
+        spl_token_2022::check_spl_token_program_account(config_pda_ata.owner)?;
+        let data = config_pda_ata.try_borrow_data()?;
+        let token_account = StateWithExtensions::<spl_token_2022::state::Account>::unpack(&data)?;
+        if token_account.base.mint != gas_token.mint || token_account.base.owner != *config_pda.key
+        {
+            msg!("Token account isn't held by the config in the gas token");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        balances.push(GasTokenBalance {
+            mint: gas_token.mint,
+            amount: token_account.base.amount,
+        });
+    }
+
+    set_return_data(&borsh::to_vec(&balances)?);
+
+    Ok(())
+}
+
+/// Ensures `mint` is accepted by the config to pay for gas, and returns its allowlist entry.
+pub(crate) fn ensure_gas_token_allowed(
+    program_id: &Pubkey,
+    gas_token_pda: &AccountInfo<'_>,
+    config_pda: &Pubkey,
+    mint: &Pubkey,
+) -> Result<GasToken, ProgramError> {
+    if gas_token_pda.data_is_empty() {
+        msg!("Token isn't accepted to pay for gas");
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let gas_token = load_gas_token(program_id, gas_token_pda, config_pda)?;
+    if &gas_token.mint != mint {
+        msg!("Gas token PDA doesn't match the mint");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    Ok(gas_token)
+}
+
+fn load_gas_token(
+    program_id: &Pubkey,
+    gas_token_pda: &AccountInfo<'_>,
+    config_pda: &Pubkey,
+) -> Result<GasToken, ProgramError> {
+    gas_token_pda.check_initialized_pda_without_deserialization(program_id)?;
+    let data = gas_token_pda.try_borrow_data()?;
+    let gas_token = GasToken::read(&data).ok_or(ProgramError::InvalidAccountData)?;
+
+    assert_valid_gas_token_pda(
+        gas_token.bump,
+        &gas_token.config_pda,
+        &gas_token.mint,
+        gas_token_pda.key,
+    )?;
+
+    if &gas_token.config_pda != config_pda {
+        msg!("Gas token doesn't belong to the config");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    Ok(*gas_token)
+}
@@ -1,19 +1,24 @@
 use core::mem::size_of;
 
-use program_utils::pda::BytemuckedPda;
+use program_utils::pda::{BorshPda, BytemuckedPda};
+use program_utils::validate_system_account_key;
+use role_management::processor::RoleSyncIndexAccounts;
+use role_management::state::UserRoles;
 use solana_program::account_info::{next_account_info, AccountInfo};
 use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
 use solana_program::program_error::ProgramError;
 use solana_program::pubkey::Pubkey;
 use solana_program::system_program;
 
+use super::native::try_load_config;
 use crate::state::Config;
-use crate::{assert_valid_config_pda, get_config_pda, seed_prefixes};
+use crate::{assert_valid_config_pda, get_config_pda, seed_prefixes, Roles};
 
 /// This function is used to initialize a config on the program
-pub(crate) fn process_initialize_config(
+pub(crate) fn process_initialize_config<'a>(
     program_id: &Pubkey,
-    accounts: &[AccountInfo<'_>],
+    accounts: &'a [AccountInfo<'a>],
     salt: [u8; 32],
 ) -> ProgramResult {
     let accounts = &mut accounts.iter();
@@ -21,6 +26,8 @@ pub(crate) fn process_initialize_config(
     let operator = next_account_info(accounts)?;
     let config_pda = next_account_info(accounts)?;
     let system_account = next_account_info(accounts)?;
+    let operator_roles_pda = next_account_info(accounts)?;
+    let role_index_pda = next_account_info(accounts)?;
 
     // Check: System Program Account
     if !system_program::check_id(system_account.key) {
@@ -53,6 +60,114 @@ pub(crate) fn process_initialize_config(
         operator: *operator.key,
         salt,
     };
+    drop(data);
 
-    Ok(())
+    init_operator_roles(
+        program_id,
+        payer,
+        system_account,
+        config_pda,
+        operator,
+        operator_roles_pda,
+    )?;
+
+    role_management::processor::sync_index::<Roles>(
+        program_id,
+        RoleSyncIndexAccounts {
+            system_account,
+            payer,
+            resource: config_pda,
+            user_account: operator,
+            roles_account: operator_roles_pda,
+            index_account: role_index_pda,
+        },
+    )
+}
+
+/// Configs initialized before roles existed have no roles PDA for their operator, so they can't
+/// be operated. This creates it, and records the roles of the operator in the role index of the
+/// config, which is all that's left to do for configs whose operator already has roles.
+pub(crate) fn process_migrate_operator_roles<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+) -> ProgramResult {
+    let accounts = &mut accounts.iter();
+    let payer = next_account_info(accounts)?;
+    let operator = next_account_info(accounts)?;
+    let config_pda = next_account_info(accounts)?;
+    let system_account = next_account_info(accounts)?;
+    let operator_roles_pda = next_account_info(accounts)?;
+    let role_index_pda = next_account_info(accounts)?;
+
+    validate_system_account_key(system_account.key)?;
+
+    let config = try_load_config(program_id, config_pda)?;
+
+    // Check: Operator matches
+    if operator.key != &config.operator {
+        msg!("Only the operator of the config can migrate its roles");
+        return Err(ProgramError::InvalidAccountOwner);
+    }
+
+    // Check: Operator is signer
+    if !operator.is_signer {
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    if operator_roles_pda.data_is_empty() {
+        init_operator_roles(
+            program_id,
+            payer,
+            system_account,
+            config_pda,
+            operator,
+            operator_roles_pda,
+        )?;
+    }
+
+    role_management::processor::sync_index::<Roles>(
+        program_id,
+        RoleSyncIndexAccounts {
+            system_account,
+            payer,
+            resource: config_pda,
+            user_account: operator,
+            roles_account: operator_roles_pda,
+            index_account: role_index_pda,
+        },
+    )
+}
+
+/// The operator starts out holding every role
+fn init_operator_roles<'a>(
+    program_id: &Pubkey,
+    payer: &AccountInfo<'a>,
+    system_account: &AccountInfo<'a>,
+    config_pda: &AccountInfo<'a>,
+    operator: &AccountInfo<'a>,
+    operator_roles_pda: &AccountInfo<'a>,
+) -> ProgramResult {
+    let (expected_pda, operator_roles_bump) =
+        role_management::find_user_roles_pda(program_id, config_pda.key, operator.key);
+    if operator_roles_pda.key != &expected_pda {
+        msg!("Invalid operator roles PDA");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    UserRoles::new(
+        Roles::OPERATOR | Roles::COLLECTOR | Roles::REFUNDER,
+        operator_roles_bump,
+    )
+    .init(
+        program_id,
+        system_account,
+        payer,
+        operator_roles_pda,
+        &[
+            role_management::seed_prefixes::USER_ROLES_SEED,
+            config_pda.key.as_ref(),
+            operator.key.as_ref(),
+            &[operator_roles_bump],
+        ],
+    )
 }
@@ -1,10 +1,16 @@
-use crate::assert_valid_config_pda;
+use super::gas_oracle::estimate_gas_fee;
+use super::gas_payment::{
+    bind_message_id, ensure_valid_message_id, record_added, record_paid, record_refunded,
+    release_gas, reserve_gas, reserved_gas,
+};
 use crate::state::Config;
+use crate::{assert_valid_config_pda, gas_payment_key, Roles, NATIVE_GAS_TOKEN};
 use axelar_solana_gas_service_events::event_prefixes;
 use program_utils::{
     pda::{BytemuckedPda, ValidPDA},
     transfer_lamports, validate_system_account_key,
 };
+use role_management::processor::ensure_signer_roles;
 use solana_program::account_info::{next_account_info, AccountInfo};
 use solana_program::entrypoint::ProgramResult;
 use solana_program::log::sol_log_data;
@@ -12,7 +18,9 @@ use solana_program::msg;
 use solana_program::program::invoke;
 use solana_program::program_error::ProgramError;
 use solana_program::pubkey::Pubkey;
+use solana_program::rent::Rent;
 use solana_program::system_instruction;
+use solana_program::sysvar::Sysvar;
 
 #[allow(clippy::too_many_arguments)]
 pub(crate) fn process_pay_native_for_contract_call(
@@ -24,6 +32,7 @@ pub(crate) fn process_pay_native_for_contract_call(
     refund_address: Pubkey,
     params: &[u8],
     gas_fee_amount: u64,
+    checked_payload_len: Option<u64>,
 ) -> ProgramResult {
     if gas_fee_amount == 0 {
         msg!("Gas fee amount cannot be zero");
@@ -34,16 +43,56 @@ pub(crate) fn process_pay_native_for_contract_call(
     let sender = next_account_info(accounts)?;
     let config_pda = next_account_info(accounts)?;
     let system_program = next_account_info(accounts)?;
+    let gas_oracle_pda = checked_payload_len
+        .map(|_| next_account_info(accounts))
+        .transpose()?;
+    let gas_payment_pda = next_account_info(accounts)?;
+    let gas_reserve_pda = next_account_info(accounts)?;
 
     validate_system_account_key(system_program.key)?;
 
     try_load_config(program_id, config_pda)?;
 
+    if let (Some(gas_oracle_pda), Some(payload_len)) = (gas_oracle_pda, checked_payload_len) {
+        let quote = estimate_gas_fee(
+            program_id,
+            gas_oracle_pda,
+            config_pda.key,
+            &destination_chain,
+            payload_len,
+        )?;
+        if gas_fee_amount < quote {
+            msg!("Gas fee amount is below the quote of {}", quote);
+            return Err(ProgramError::InsufficientFunds);
+        }
+    }
+
     invoke(
         &system_instruction::transfer(sender.key, config_pda.key, gas_fee_amount),
         &[sender.clone(), config_pda.clone(), system_program.clone()],
     )?;
 
+    record_paid(
+        program_id,
+        sender,
+        gas_payment_pda,
+        system_program,
+        config_pda.key,
+        &NATIVE_GAS_TOKEN,
+        gas_payment_key(&destination_chain, &destination_address, &payload_hash),
+        refund_address,
+        gas_fee_amount,
+    )?;
+    reserve_gas(
+        program_id,
+        sender,
+        system_program,
+        config_pda.key,
+        &NATIVE_GAS_TOKEN,
+        gas_reserve_pda,
+        gas_fee_amount,
+    )?;
+
     // Emit an event
     sol_log_data(&[
         event_prefixes::NATIVE_GAS_PAID_FOR_CONTRACT_CALL,
@@ -60,7 +109,7 @@ pub(crate) fn process_pay_native_for_contract_call(
 }
 
 /// Performs all the config checks and returns the config if it is valid
-fn try_load_config(
+pub(crate) fn try_load_config(
     program_id: &Pubkey,
     config_pda: &AccountInfo<'_>,
 ) -> Result<Config, ProgramError> {
@@ -74,8 +123,7 @@ fn try_load_config(
 pub(crate) fn add_native_gas(
     program_id: &Pubkey,
     accounts: &[AccountInfo<'_>],
-    tx_hash: [u8; 64],
-    log_index: u64,
+    message_id: String,
     gas_fee_amount: u64,
     refund_address: Pubkey,
 ) -> ProgramResult {
@@ -84,15 +132,38 @@ pub(crate) fn add_native_gas(
         return Err(ProgramError::InvalidInstructionData);
     }
 
+    ensure_valid_message_id(&message_id)?;
+
     let accounts = &mut accounts.iter();
     let sender = next_account_info(accounts)?;
     let config_pda = next_account_info(accounts)?;
     let system_program = next_account_info(accounts)?;
+    let gas_payment_pda = next_account_info(accounts)?;
+    let gas_reserve_pda = next_account_info(accounts)?;
 
     validate_system_account_key(system_program.key)?;
 
     try_load_config(program_id, config_pda)?;
 
+    record_added(
+        program_id,
+        gas_payment_pda,
+        config_pda.key,
+        &NATIVE_GAS_TOKEN,
+        &refund_address,
+        &message_id,
+        gas_fee_amount,
+    )?;
+    reserve_gas(
+        program_id,
+        sender,
+        system_program,
+        config_pda.key,
+        &NATIVE_GAS_TOKEN,
+        gas_reserve_pda,
+        gas_fee_amount,
+    )?;
+
     invoke(
         &system_instruction::transfer(sender.key, config_pda.key, gas_fee_amount),
         &[sender.clone(), config_pda.clone(), system_program.clone()],
@@ -102,8 +173,7 @@ pub(crate) fn add_native_gas(
     sol_log_data(&[
         event_prefixes::NATIVE_GAS_ADDED,
         &config_pda.key.to_bytes(),
-        &tx_hash,
-        &log_index.to_le_bytes(),
+        message_id.as_bytes(),
         &refund_address.to_bytes(),
         &gas_fee_amount.to_le_bytes(),
     ]);
@@ -122,23 +192,40 @@ pub(crate) fn collect_fees_native(
     }
 
     let accounts = &mut accounts.iter();
-    let operator = next_account_info(accounts)?;
+    let collector = next_account_info(accounts)?;
     let config_pda = next_account_info(accounts)?;
     let receiver = next_account_info(accounts)?;
+    let collector_roles_pda = next_account_info(accounts)?;
+    let gas_reserve_pda = next_account_info(accounts)?;
 
-    {
-        // Check: Valid Config PDA
-        let config = try_load_config(program_id, config_pda)?;
+    // Check: Valid Config PDA
+    try_load_config(program_id, config_pda)?;
 
-        // Check: Operator matches
-        if operator.key != &config.operator {
-            return Err(ProgramError::InvalidAccountOwner);
-        }
-    }
+    // Check: Collector is signer and holds the collector role
+    ensure_signer_roles(
+        program_id,
+        config_pda,
+        collector,
+        collector_roles_pda,
+        Roles::COLLECTOR,
+    )?;
 
-    // Check: Operator is signer
-    if !operator.is_signer {
-        return Err(ProgramError::MissingRequiredSignature);
+    // The gas held for refunds can't be collected
+    let reserved = reserved_gas(
+        program_id,
+        config_pda.key,
+        &NATIVE_GAS_TOKEN,
+        gas_reserve_pda,
+    )?;
+    let rent_exempt_minimum = Rent::get()?.minimum_balance(config_pda.data_len());
+    let collectable = config_pda
+        .lamports()
+        .checked_sub(rent_exempt_minimum)
+        .and_then(|balance| balance.checked_sub(reserved))
+        .unwrap_or(0);
+    if amount > collectable {
+        msg!("Collected fees exceed the balance not held for refunds");
+        return Err(ProgramError::InsufficientFunds);
     }
 
     transfer_lamports(config_pda, receiver, amount)?;
@@ -149,38 +236,67 @@ pub(crate) fn collect_fees_native(
 pub(crate) fn refund_native(
     program_id: &Pubkey,
     accounts: &[AccountInfo<'_>],
-    tx_hash: [u8; 64],
-    log_index: u64,
+    message_id: String,
     fees: u64,
 ) -> ProgramResult {
+    ensure_valid_message_id(&message_id)?;
+
     let accounts = &mut accounts.iter();
-    let operator = next_account_info(accounts)?;
+    let refunder = next_account_info(accounts)?;
     let receiver = next_account_info(accounts)?;
     let config_pda = next_account_info(accounts)?;
+    let gas_payment_pda = next_account_info(accounts)?;
+    let refunder_roles_pda = next_account_info(accounts)?;
+    let gas_message_pda = next_account_info(accounts)?;
+    let system_program = next_account_info(accounts)?;
+    let gas_reserve_pda = next_account_info(accounts)?;
 
-    {
-        // Check: Valid Config PDA
-        let config = try_load_config(program_id, config_pda)?;
+    validate_system_account_key(system_program.key)?;
 
-        // Check: Operator matches
-        if operator.key != &config.operator {
-            return Err(ProgramError::InvalidAccountOwner);
-        }
-    }
+    // Check: Valid Config PDA
+    try_load_config(program_id, config_pda)?;
 
-    // Check: Operator is signer
-    if !operator.is_signer {
-        return Err(ProgramError::MissingRequiredSignature);
-    }
+    // Check: Refunder is signer and holds the refunder role
+    ensure_signer_roles(
+        program_id,
+        config_pda,
+        refunder,
+        refunder_roles_pda,
+        Roles::REFUNDER,
+    )?;
+
+    record_refunded(
+        program_id,
+        gas_payment_pda,
+        config_pda.key,
+        &NATIVE_GAS_TOKEN,
+        receiver.key,
+        fees,
+    )?;
+    bind_message_id(
+        program_id,
+        refunder,
+        system_program,
+        config_pda.key,
+        gas_payment_pda,
+        gas_message_pda,
+        &message_id,
+    )?;
+    release_gas(
+        program_id,
+        config_pda.key,
+        &NATIVE_GAS_TOKEN,
+        gas_reserve_pda,
+        fees,
+    )?;
 
     transfer_lamports(config_pda, receiver, fees)?;
 
     // Emit an event
     sol_log_data(&[
         event_prefixes::NATIVE_GAS_REFUNDED,
-        &tx_hash,
+        message_id.as_bytes(),
         &config_pda.key.to_bytes(),
-        &log_index.to_le_bytes(),
         &receiver.key.to_bytes(),
         &fees.to_le_bytes(),
     ]);
@@ -212,6 +328,7 @@ mod tests {
             refund_address,
             &params,
             gas_fee_amount,
+            None,
         );
 
         assert_eq!(result, Err(ProgramError::InvalidInstructionData));
@@ -221,16 +338,33 @@ mod tests {
     fn test_add_native_gas_cannot_accept_zero_amount() {
         let program_id = Pubkey::new_unique();
         let accounts = vec![];
-        let tx_hash = [0; 64];
-        let log_index = 0;
+        let message_id = String::new();
         let gas_fee_amount = 0;
         let refund_address = Pubkey::new_unique();
 
         let result = add_native_gas(
             &program_id,
             &accounts,
-            tx_hash,
-            log_index,
+            message_id,
+            gas_fee_amount,
+            refund_address,
+        );
+
+        assert_eq!(result, Err(ProgramError::InvalidInstructionData));
+    }
+
+    #[test]
+    fn test_add_native_gas_rejects_invalid_message_id() {
+        let program_id = Pubkey::new_unique();
+        let accounts = vec![];
+        let message_id = "0xdeadbeef-1".to_owned();
+        let gas_fee_amount = 1;
+        let refund_address = Pubkey::new_unique();
+
+        let result = add_native_gas(
+            &program_id,
+            &accounts,
+            message_id,
             gas_fee_amount,
             refund_address,
         );
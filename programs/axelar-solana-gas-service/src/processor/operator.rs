@@ -0,0 +1,239 @@
+//! Operatorship transfer and role management of a gas service config.
+
+use axelar_solana_gas_service_events::event_prefixes;
+use program_utils::pda::BytemuckedPda;
+use role_management::processor::{
+    ensure_signer_roles, RoleAddAccounts, RoleProposeAccounts, RoleRemoveAccounts,
+    RoleTransferWithProposalAccounts,
+};
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::entrypoint::ProgramResult;
+use solana_program::log::sol_log_data;
+use solana_program::msg;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+use super::native::try_load_config;
+use crate::state::Config;
+use crate::Roles;
+
+pub(crate) fn process_propose_operatorship<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    expires_at: Option<i64>,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let system_account = next_account_info(accounts_iter)?;
+    let payer = next_account_info(accounts_iter)?;
+    let payer_roles_account = next_account_info(accounts_iter)?;
+    let resource = next_account_info(accounts_iter)?;
+    let destination_user_account = next_account_info(accounts_iter)?;
+    let destination_roles_account = next_account_info(accounts_iter)?;
+    let proposal_account = next_account_info(accounts_iter)?;
+
+    try_load_config(program_id, resource)?;
+
+    let role_management_accounts = RoleProposeAccounts {
+        system_account,
+        payer,
+        authority: payer,
+        authority_roles_account: payer_roles_account,
+        signers: &[],
+        resource,
+        destination_user_account,
+        destination_roles_account,
+        origin_user_account: payer,
+        origin_roles_account: payer_roles_account,
+        proposal_account,
+    };
+
+    role_management::processor::propose(
+        program_id,
+        role_management_accounts,
+        Roles::OPERATOR,
+        Roles::OPERATOR,
+        expires_at,
+    )?;
+
+    sol_log_data(&[
+        event_prefixes::OPERATORSHIP_PROPOSED,
+        &resource.key.to_bytes(),
+        &payer.key.to_bytes(),
+        &destination_user_account.key.to_bytes(),
+        &expires_at.map(i64::to_le_bytes).unwrap_or_default(),
+    ]);
+
+    Ok(())
+}
+
+/// Only the operator role is transferred, the collector and refunder roles of the previous
+/// operator are left untouched.
+pub(crate) fn process_accept_operatorship<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let system_account = next_account_info(accounts_iter)?;
+    let payer = next_account_info(accounts_iter)?;
+    let payer_roles_account = next_account_info(accounts_iter)?;
+    let resource = next_account_info(accounts_iter)?;
+    let origin_user_account = next_account_info(accounts_iter)?;
+    let origin_roles_account = next_account_info(accounts_iter)?;
+    let proposal_account = next_account_info(accounts_iter)?;
+    let index_account = next_account_info(accounts_iter)?;
+
+    try_load_config(program_id, resource)?;
+
+    let role_management_accounts = RoleTransferWithProposalAccounts {
+        system_account,
+        payer,
+        authority: payer,
+        authority_roles_account: payer_roles_account,
+        signers: &[],
+        resource,
+        destination_user_account: payer,
+        destination_roles_account: payer_roles_account,
+        origin_user_account,
+        origin_roles_account,
+        proposal_account,
+        index_account,
+    };
+
+    role_management::processor::accept(
+        program_id,
+        role_management_accounts,
+        Roles::OPERATOR,
+        Roles::empty(),
+    )?;
+
+    let mut data = resource.try_borrow_mut_data()?;
+    let config = Config::read_mut(&mut data).ok_or(ProgramError::InvalidAccountData)?;
+    config.operator = *payer.key;
+
+    sol_log_data(&[
+        event_prefixes::OPERATORSHIP_TRANSFERRED,
+        &resource.key.to_bytes(),
+        &origin_user_account.key.to_bytes(),
+        &payer.key.to_bytes(),
+    ]);
+
+    Ok(())
+}
+
+pub(crate) fn process_add_roles<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    roles: Roles,
+) -> ProgramResult {
+    ensure_assignable_roles(roles)?;
+
+    let accounts_iter = &mut accounts.iter();
+    let system_account = next_account_info(accounts_iter)?;
+    let payer = next_account_info(accounts_iter)?;
+    let payer_roles_account = next_account_info(accounts_iter)?;
+    let resource = next_account_info(accounts_iter)?;
+    let destination_user_account = next_account_info(accounts_iter)?;
+    let destination_roles_account = next_account_info(accounts_iter)?;
+    let index_account = next_account_info(accounts_iter)?;
+
+    try_load_config(program_id, resource)?;
+
+    let role_management_accounts = RoleAddAccounts {
+        system_account,
+        payer,
+        authority: payer,
+        authority_roles_account: payer_roles_account,
+        signers: &[],
+        resource,
+        destination_user_account,
+        destination_roles_account,
+        index_account,
+    };
+
+    role_management::processor::add(program_id, role_management_accounts, roles, Roles::OPERATOR)?;
+
+    sol_log_data(&[
+        event_prefixes::ROLES_ADDED,
+        &resource.key.to_bytes(),
+        &destination_user_account.key.to_bytes(),
+        &[roles.bits()],
+    ]);
+
+    Ok(())
+}
+
+pub(crate) fn process_remove_roles<'a>(
+    program_id: &Pubkey,
+    accounts: &'a [AccountInfo<'a>],
+    roles: Roles,
+) -> ProgramResult {
+    ensure_assignable_roles(roles)?;
+
+    let accounts_iter = &mut accounts.iter();
+    let system_account = next_account_info(accounts_iter)?;
+    let payer = next_account_info(accounts_iter)?;
+    let payer_roles_account = next_account_info(accounts_iter)?;
+    let resource = next_account_info(accounts_iter)?;
+    let origin_user_account = next_account_info(accounts_iter)?;
+    let origin_roles_account = next_account_info(accounts_iter)?;
+    let index_account = next_account_info(accounts_iter)?;
+
+    try_load_config(program_id, resource)?;
+
+    let role_management_accounts = RoleRemoveAccounts {
+        system_account,
+        payer,
+        authority: payer,
+        authority_roles_account: payer_roles_account,
+        signers: &[],
+        resource,
+        origin_user_account,
+        origin_roles_account,
+        index_account,
+    };
+
+    role_management::processor::remove(
+        program_id,
+        role_management_accounts,
+        roles,
+        Roles::OPERATOR,
+    )?;
+
+    sol_log_data(&[
+        event_prefixes::ROLES_REMOVED,
+        &resource.key.to_bytes(),
+        &origin_user_account.key.to_bytes(),
+        &[roles.bits()],
+    ]);
+
+    Ok(())
+}
+
+/// Ensures `operator` signs and holds the operator role of the config.
+pub(crate) fn ensure_operator(
+    program_id: &Pubkey,
+    config_pda: &AccountInfo<'_>,
+    operator: &AccountInfo<'_>,
+    operator_roles_pda: &AccountInfo<'_>,
+) -> ProgramResult {
+    try_load_config(program_id, config_pda)?;
+
+    ensure_signer_roles(
+        program_id,
+        config_pda,
+        operator,
+        operator_roles_pda,
+        Roles::OPERATOR,
+    )
+}
+
+/// The operator role can only change hands through a proposal, so it can't be added or removed
+/// directly.
+fn ensure_assignable_roles(roles: Roles) -> ProgramResult {
+    if roles.is_empty() || roles.contains(Roles::OPERATOR) {
+        msg!("Only the collector and refunder roles can be added or removed");
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    Ok(())
+}
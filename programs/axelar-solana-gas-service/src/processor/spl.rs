@@ -1,5 +1,7 @@
 use axelar_solana_gas_service_events::event_prefixes;
 use program_utils::pda::{BytemuckedPda, ValidPDA};
+use program_utils::validate_system_account_key;
+use role_management::processor::ensure_signer_roles;
 use solana_program::account_info::{next_account_info, AccountInfo};
 use solana_program::entrypoint::ProgramResult;
 use solana_program::instruction::Instruction;
@@ -11,10 +13,16 @@ use solana_program::program_error::ProgramError;
 use solana_program::program_pack::Pack;
 use solana_program::pubkey::Pubkey;
 
+use super::gas_oracle::estimate_gas_fee;
+use super::gas_payment::{
+    bind_message_id, ensure_valid_message_id, record_added, record_paid, record_refunded,
+    release_gas, reserve_gas, reserved_gas,
+};
+use super::gas_token::ensure_gas_token_allowed;
 use crate::state::Config;
-use crate::{assert_valid_config_pda, seed_prefixes};
+use crate::{assert_valid_config_pda, gas_payment_key, seed_prefixes, Roles};
 
-fn ensure_valid_config_pda_ata(
+pub(crate) fn ensure_valid_config_pda_ata(
     config_pda_ata: &AccountInfo<'_>,
     token_program: &AccountInfo<'_>,
     mint: &AccountInfo<'_>,
@@ -31,6 +39,23 @@ fn ensure_valid_config_pda_ata(
     Ok(())
 }
 
+/// Returns the owner of a token account of `mint` receiving tokens.
+fn receiver_token_account_owner(
+    receiver_account: &AccountInfo<'_>,
+    token_program: &AccountInfo<'_>,
+    mint: &AccountInfo<'_>,
+) -> Result<Pubkey, ProgramError> {
+    if receiver_account.owner != token_program.key {
+        return Err(ProgramError::IncorrectProgramId);
+    }
+    let account_data =
+        spl_token_2022::state::Account::unpack_from_slice(&receiver_account.try_borrow_data()?)?;
+    if account_data.mint != *mint.key {
+        return Err(ProgramError::InvalidAccountData);
+    }
+    Ok(account_data.owner)
+}
+
 fn ensure_valid_config_pda(config_pda: &AccountInfo<'_>, program_id: &Pubkey) -> ProgramResult {
     config_pda.check_initialized_pda_without_deserialization(program_id)?;
     let data = config_pda.try_borrow_data()?;
@@ -40,7 +65,7 @@ fn ensure_valid_config_pda(config_pda: &AccountInfo<'_>, program_id: &Pubkey) ->
 }
 
 #[allow(clippy::too_many_arguments)]
-fn transfer_tokens(
+pub(crate) fn transfer_tokens(
     token_program: &AccountInfo<'_>,
     sender_ata: &AccountInfo<'_>,
     mint: &AccountInfo<'_>,
@@ -77,13 +102,20 @@ pub(crate) fn process_pay_spl_for_contract_call(
     params: &[u8],
     gas_fee_amount: u64,
     decimals: u8,
+    checked_payload_len: Option<u64>,
 ) -> ProgramResult {
     if gas_fee_amount == 0 {
         msg!("Gas fee amount cannot be zero");
         return Err(ProgramError::InvalidInstructionData);
     }
 
-    let (accounts, signer_pubkeys) = accounts.split_at(6);
+    let (accounts, signer_pubkeys) = accounts
+        .split_at_checked(if checked_payload_len.is_some() {
+            12
+        } else {
+            11
+        })
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
     let accounts = &mut accounts.iter();
     let sender = next_account_info(accounts)?;
     let sender_ata = next_account_info(accounts)?;
@@ -91,10 +123,48 @@ pub(crate) fn process_pay_spl_for_contract_call(
     let config_pda_ata = next_account_info(accounts)?;
     let mint = next_account_info(accounts)?;
     let token_program = next_account_info(accounts)?;
+    let gas_token_pda = next_account_info(accounts)?;
+    let gas_payment_pda = next_account_info(accounts)?;
+    let payer = next_account_info(accounts)?;
+    let system_program = next_account_info(accounts)?;
+    let gas_reserve_pda = next_account_info(accounts)?;
+    let gas_oracle_pda = checked_payload_len
+        .map(|_| next_account_info(accounts))
+        .transpose()?;
+
+    validate_system_account_key(system_program.key)?;
 
     // Ensure config_pda is valid
     ensure_valid_config_pda(config_pda, program_id)?;
 
+    // Ensure the token is accepted to pay for gas
+    let gas_token = ensure_gas_token_allowed(program_id, gas_token_pda, config_pda.key, mint.key)?;
+    if gas_fee_amount < gas_token.min_payment {
+        msg!(
+            "Gas fee amount is below the minimum payment of {} for this token",
+            gas_token.min_payment
+        );
+        return Err(ProgramError::InsufficientFunds);
+    }
+
+    if let (Some(gas_oracle_pda), Some(payload_len)) = (gas_oracle_pda, checked_payload_len) {
+        let quote = estimate_gas_fee(
+            program_id,
+            gas_oracle_pda,
+            config_pda.key,
+            &destination_chain,
+            payload_len,
+        )?;
+        let quote = gas_token.lamports_to_tokens(quote).ok_or_else(|| {
+            msg!("The gas oracle quote can't be converted to this token");
+            ProgramError::InvalidArgument
+        })?;
+        if gas_fee_amount < quote {
+            msg!("Gas fee amount is below the quote of {}", quote);
+            return Err(ProgramError::InsufficientFunds);
+        }
+    }
+
     // valid token program
     spl_token_2022::check_spl_token_program_account(token_program.key)?;
 
@@ -123,6 +193,27 @@ pub(crate) fn process_pay_spl_for_contract_call(
         ],
     )?;
 
+    record_paid(
+        program_id,
+        payer,
+        gas_payment_pda,
+        system_program,
+        config_pda.key,
+        mint.key,
+        gas_payment_key(&destination_chain, &destination_address, &payload_hash),
+        refund_address,
+        gas_fee_amount,
+    )?;
+    reserve_gas(
+        program_id,
+        payer,
+        system_program,
+        config_pda.key,
+        mint.key,
+        gas_reserve_pda,
+        gas_fee_amount,
+    )?;
+
     // Emit an event
     sol_log_data(&[
         event_prefixes::SPL_PAID_FOR_CONTRACT_CALL,
@@ -144,8 +235,7 @@ pub(crate) fn process_pay_spl_for_contract_call(
 pub(crate) fn add_spl_gas(
     program_id: &Pubkey,
     accounts: &[AccountInfo<'_>],
-    tx_hash: [u8; 64],
-    log_index: u64,
+    message_id: String,
     gas_fee_amount: u64,
     refund_address: Pubkey,
     decimals: u8,
@@ -155,7 +245,11 @@ pub(crate) fn add_spl_gas(
         return Err(ProgramError::InvalidInstructionData);
     }
 
-    let (accounts, signer_pubkeys) = accounts.split_at(6);
+    ensure_valid_message_id(&message_id)?;
+
+    let (accounts, signer_pubkeys) = accounts
+        .split_at_checked(11)
+        .ok_or(ProgramError::NotEnoughAccountKeys)?;
     let accounts = &mut accounts.iter();
     let sender = next_account_info(accounts)?;
     let sender_ata = next_account_info(accounts)?;
@@ -163,16 +257,45 @@ pub(crate) fn add_spl_gas(
     let config_pda_ata = next_account_info(accounts)?;
     let mint = next_account_info(accounts)?;
     let token_program = next_account_info(accounts)?;
+    let gas_token_pda = next_account_info(accounts)?;
+    let gas_payment_pda = next_account_info(accounts)?;
+    let payer = next_account_info(accounts)?;
+    let system_program = next_account_info(accounts)?;
+    let gas_reserve_pda = next_account_info(accounts)?;
+
+    validate_system_account_key(system_program.key)?;
 
     // Ensure config_pda is valid
     ensure_valid_config_pda(config_pda, program_id)?;
 
+    // Ensure the token is accepted to pay for gas
+    ensure_gas_token_allowed(program_id, gas_token_pda, config_pda.key, mint.key)?;
+
     // valid token program
     spl_token_2022::check_spl_token_program_account(token_program.key)?;
 
     // ensure config_pda_ata is owned by the Token Program and matches expected fields
     ensure_valid_config_pda_ata(config_pda_ata, token_program, mint, config_pda)?;
 
+    record_added(
+        program_id,
+        gas_payment_pda,
+        config_pda.key,
+        mint.key,
+        &refund_address,
+        &message_id,
+        gas_fee_amount,
+    )?;
+    reserve_gas(
+        program_id,
+        payer,
+        system_program,
+        config_pda.key,
+        mint.key,
+        gas_reserve_pda,
+        gas_fee_amount,
+    )?;
+
     let ix = transfer_tokens(
         token_program,
         sender_ata,
@@ -202,8 +325,7 @@ pub(crate) fn add_spl_gas(
         &config_pda_ata.key.to_bytes(),
         &mint.key.to_bytes(),
         &token_program.key.to_bytes(),
-        &tx_hash,
-        &log_index.to_le_bytes(),
+        message_id.as_bytes(),
         &refund_address.to_bytes(),
         &gas_fee_amount.to_le_bytes(),
     ]);
@@ -223,26 +345,28 @@ pub(crate) fn collect_fees_spl(
     }
 
     let accounts = &mut accounts.iter();
-    let operator = next_account_info(accounts)?;
+    let collector = next_account_info(accounts)?;
     let receiver_account = next_account_info(accounts)?;
     let config_pda = next_account_info(accounts)?;
     let config_pda_ata = next_account_info(accounts)?;
     let mint = next_account_info(accounts)?;
     let token_program = next_account_info(accounts)?;
+    let collector_roles_pda = next_account_info(accounts)?;
+    let gas_reserve_pda = next_account_info(accounts)?;
 
     // Ensure config_pda is valid
     ensure_valid_config_pda(config_pda, program_id)?;
     let data = config_pda.try_borrow_data()?;
     let config = Config::read(&data).ok_or(ProgramError::InvalidAccountData)?;
-    // Check: Operator matches
-    if operator.key != &config.operator {
-        return Err(ProgramError::InvalidAccountOwner);
-    }
 
-    // Check: Operator is signer
-    if !operator.is_signer {
-        return Err(ProgramError::MissingRequiredSignature);
-    }
+    // Check: Collector is signer and holds the collector role
+    ensure_signer_roles(
+        program_id,
+        config_pda,
+        collector,
+        collector_roles_pda,
+        Roles::COLLECTOR,
+    )?;
 
     // valid token program
     spl_token_2022::check_spl_token_program_account(token_program.key)?;
@@ -250,6 +374,17 @@ pub(crate) fn collect_fees_spl(
     // ensure config_pda_ata is owned by the Token Program and matches expected fields
     ensure_valid_config_pda_ata(config_pda_ata, token_program, mint, config_pda)?;
 
+    // The gas held for refunds can't be collected
+    let reserved = reserved_gas(program_id, config_pda.key, mint.key, gas_reserve_pda)?;
+    let balance =
+        spl_token_2022::state::Account::unpack_from_slice(&config_pda_ata.try_borrow_data()?)?
+            .amount;
+    let collectable = balance.checked_sub(reserved).unwrap_or(0);
+    if amount > collectable {
+        msg!("Collected fees exceed the balance not held for refunds");
+        return Err(ProgramError::InsufficientFunds);
+    }
+
     let ix = transfer_tokens(
         token_program,
         config_pda_ata,
@@ -279,8 +414,7 @@ pub(crate) fn collect_fees_spl(
 pub(crate) fn refund_spl(
     program_id: &Pubkey,
     accounts: &[AccountInfo<'_>],
-    tx_hash: [u8; 64],
-    log_index: u64,
+    message_id: String,
     fees: u64,
     decimals: u8,
 ) -> ProgramResult {
@@ -289,27 +423,36 @@ pub(crate) fn refund_spl(
         return Err(ProgramError::InvalidInstructionData);
     }
 
+    ensure_valid_message_id(&message_id)?;
+
     let accounts = &mut accounts.iter();
-    let operator = next_account_info(accounts)?;
+    let refunder = next_account_info(accounts)?;
     let receiver_account = next_account_info(accounts)?;
     let config_pda = next_account_info(accounts)?;
     let config_pda_ata = next_account_info(accounts)?;
     let mint = next_account_info(accounts)?;
     let token_program = next_account_info(accounts)?;
+    let gas_payment_pda = next_account_info(accounts)?;
+    let refunder_roles_pda = next_account_info(accounts)?;
+    let gas_message_pda = next_account_info(accounts)?;
+    let system_program = next_account_info(accounts)?;
+    let gas_reserve_pda = next_account_info(accounts)?;
+
+    validate_system_account_key(system_program.key)?;
 
     // Ensure config_pda is valid
     ensure_valid_config_pda(config_pda, program_id)?;
     let data = config_pda.try_borrow_data()?;
     let config = Config::read(&data).ok_or(ProgramError::InvalidAccountData)?;
-    // Check: Operator matches
-    if operator.key != &config.operator {
-        return Err(ProgramError::InvalidAccountOwner);
-    }
 
-    // Check: Operator is signer
-    if !operator.is_signer {
-        return Err(ProgramError::MissingRequiredSignature);
-    }
+    // Check: Refunder is signer and holds the refunder role
+    ensure_signer_roles(
+        program_id,
+        config_pda,
+        refunder,
+        refunder_roles_pda,
+        Roles::REFUNDER,
+    )?;
 
     // valid token program
     spl_token_2022::check_spl_token_program_account(token_program.key)?;
@@ -317,6 +460,27 @@ pub(crate) fn refund_spl(
     // ensure config_pda_ata is owned by the Token Program and matches expected fields
     ensure_valid_config_pda_ata(config_pda_ata, token_program, mint, config_pda)?;
 
+    // The tokens can only be refunded to an account of the refund address of the payment
+    let receiver = receiver_token_account_owner(receiver_account, token_program, mint)?;
+    record_refunded(
+        program_id,
+        gas_payment_pda,
+        config_pda.key,
+        mint.key,
+        &receiver,
+        fees,
+    )?;
+    bind_message_id(
+        program_id,
+        refunder,
+        system_program,
+        config_pda.key,
+        gas_payment_pda,
+        gas_message_pda,
+        &message_id,
+    )?;
+    release_gas(program_id, config_pda.key, mint.key, gas_reserve_pda, fees)?;
+
     let ix = transfer_tokens(
         token_program,
         config_pda_ata,
@@ -343,12 +507,11 @@ pub(crate) fn refund_spl(
     // Emit an event
     sol_log_data(&[
         event_prefixes::SPL_GAS_REFUNDED,
-        &tx_hash,
+        message_id.as_bytes(),
         &config_pda.key.to_bytes(),
         &config_pda_ata.key.to_bytes(),
         &mint.key.to_bytes(),
         &token_program.key.to_bytes(),
-        &log_index.to_le_bytes(),
         &receiver_account.key.to_bytes(),
         &fees.to_le_bytes(),
     ]);
@@ -382,6 +545,7 @@ mod tests {
             &params,
             gas_fee_amount,
             decimals,
+            None,
         );
 
         assert_eq!(result, Err(ProgramError::InvalidInstructionData));
@@ -391,8 +555,7 @@ mod tests {
     fn test_add_spl_gas_cannot_add_zero_gas_fee() {
         let program_id = Pubkey::new_unique();
         let accounts = vec![];
-        let tx_hash = [0; 64];
-        let log_index = 0;
+        let message_id = String::new();
         let gas_fee_amount = 0;
         let refund_address = Pubkey::new_unique();
         let decimals = 0;
@@ -400,8 +563,7 @@ mod tests {
         let result = add_spl_gas(
             &program_id,
             &accounts,
-            tx_hash,
-            log_index,
+            message_id,
             gas_fee_amount,
             refund_address,
             decimals,
@@ -426,12 +588,11 @@ mod tests {
     fn test_refund_spl_cannot_refund_zero_gas_fee() {
         let program_id = Pubkey::new_unique();
         let accounts = vec![];
-        let tx_hash = [0; 64];
-        let log_index = 0;
+        let message_id = String::new();
         let fees = 0;
         let decimals = 0;
 
-        let result = refund_spl(&program_id, &accounts, tx_hash, log_index, fees, decimals);
+        let result = refund_spl(&program_id, &accounts, message_id, fees, decimals);
 
         assert_eq!(result, Err(ProgramError::InvalidInstructionData));
     }
@@ -0,0 +1,279 @@
+//! Sweeping of the accrued fees to the treasury of each token.
+
+use core::mem::size_of;
+
+use axelar_solana_gas_service_events::event_prefixes;
+use program_utils::pda::{BytemuckedPda, ValidPDA};
+use program_utils::{transfer_lamports, validate_system_account_key};
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::clock::Clock;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::log::sol_log_data;
+use solana_program::msg;
+use solana_program::program::invoke_signed;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+use solana_program::rent::Rent;
+use solana_program::sysvar::Sysvar;
+use spl_token_2022::extension::StateWithExtensions;
+use spl_token_2022::state::{Account, Mint};
+
+use super::gas_payment::reserved_gas;
+use super::native::try_load_config;
+use super::operator::ensure_operator;
+use super::spl::{ensure_valid_config_pda_ata, transfer_tokens};
+use crate::state::{Config, Treasury};
+use crate::{assert_valid_treasury_pda, get_treasury_pda, seed_prefixes, NATIVE_GAS_TOKEN};
+
+pub(crate) fn process_set_treasury(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo<'_>],
+    destination: Pubkey,
+    keep_minimum: u64,
+    sweep_interval: i64,
+) -> ProgramResult {
+    if sweep_interval < 0 {
+        msg!("Sweep interval cannot be negative");
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let accounts = &mut accounts.iter();
+    let payer = next_account_info(accounts)?;
+    let operator = next_account_info(accounts)?;
+    let config_pda = next_account_info(accounts)?;
+    let token = next_account_info(accounts)?;
+    let treasury_pda = next_account_info(accounts)?;
+    let system_program = next_account_info(accounts)?;
+    let operator_roles_pda = next_account_info(accounts)?;
+
+    validate_system_account_key(system_program.key)?;
+    ensure_operator(program_id, config_pda, operator, operator_roles_pda)?;
+
+    // Check: the token is native SOL or a mint belonging to a token program
+    if token.key != &NATIVE_GAS_TOKEN {
+        spl_token_2022::check_spl_token_program_account(token.owner)?;
+    }
+
+    let (expected_pda, bump) = get_treasury_pda(program_id, config_pda.key, token.key);
+    if treasury_pda.key != &expected_pda {
+        msg!("Invalid treasury PDA");
+        return Err(ProgramError::InvalidSeeds);
+    }
+
+    // The schedule of an existing treasury carries over
+    let last_swept_at = if treasury_pda.data_is_empty() {
+        program_utils::pda::init_pda_raw(
+            payer,
+            treasury_pda,
+            program_id,
+            system_program,
+            size_of::<Treasury>()
+                .try_into()
+                .map_err(|_err| ProgramError::ArithmeticOverflow)?,
+            &[
+                seed_prefixes::TREASURY_SEED,
+                config_pda.key.as_ref(),
+                token.key.as_ref(),
+                &[bump],
+            ],
+        )?;
+        0
+    } else {
+        load_treasury(program_id, treasury_pda, config_pda.key)?.last_swept_at
+    };
+
+    let mut data = treasury_pda.try_borrow_mut_data()?;
+    let treasury = Treasury::read_mut(&mut data).ok_or(ProgramError::InvalidAccountData)?;
+    *treasury = Treasury {
+        config_pda: *config_pda.key,
+        token: *token.key,
+        destination,
+        keep_minimum,
+        sweep_interval,
+        last_swept_at,
+        bump,
+        padding: [0; 7],
+    };
+
+    Ok(())
+}
+
+pub(crate) fn process_sweep_fees(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo<'_>],
+) -> ProgramResult {
+    let accounts = &mut accounts.iter();
+    let config_pda = next_account_info(accounts)?;
+
+    let config = try_load_config(program_id, config_pda)?;
+    let now = Clock::get()?.unix_timestamp;
+
+    loop {
+        let Some(treasury_pda) = accounts.next() else {
+            break;
+        };
+        let destination = next_account_info(accounts)?;
+        let gas_reserve_pda = next_account_info(accounts)?;
+
+        let treasury = load_treasury(program_id, treasury_pda, config_pda.key)?;
+        if destination.key != &treasury.destination {
+            msg!("Destination doesn't match the treasury");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        // The gas held for refunds is never swept
+        let keep_minimum = treasury.keep_minimum.saturating_add(reserved_gas(
+            program_id,
+            config_pda.key,
+            &treasury.token,
+            gas_reserve_pda,
+        )?);
+
+        let spl_accounts = if treasury.token == NATIVE_GAS_TOKEN {
+            None
+        } else {
+            Some((
+                next_account_info(accounts)?,
+                next_account_info(accounts)?,
+                next_account_info(accounts)?,
+            ))
+        };
+
+        // Sweeps are scheduled, the tokens swept too early are skipped
+        if !treasury.is_sweep_due(now) {
+            msg!("The sweep of {} is not due yet", treasury.token);
+            continue;
+        }
+
+        let amount = match spl_accounts {
+            None => sweep_native(config_pda, destination, keep_minimum)?,
+            Some((config_pda_ata, mint, token_program)) => {
+                if mint.key != &treasury.token {
+                    msg!("Mint doesn't match the treasury");
+                    return Err(ProgramError::InvalidAccountData);
+                }
+
+                sweep_spl(
+                    &config,
+                    config_pda,
+                    config_pda_ata,
+                    destination,
+                    mint,
+                    token_program,
+                    keep_minimum,
+                )?
+            }
+        };
+
+        let mut data = treasury_pda.try_borrow_mut_data()?;
+        let treasury_state =
+            Treasury::read_mut(&mut data).ok_or(ProgramError::InvalidAccountData)?;
+        treasury_state.last_swept_at = now;
+        drop(data);
+
+        if amount > 0 {
+            sol_log_data(&[
+                event_prefixes::FEES_SWEPT,
+                &config_pda.key.to_bytes(),
+                &treasury.token.to_bytes(),
+                &destination.key.to_bytes(),
+                &amount.to_le_bytes(),
+            ]);
+        }
+    }
+
+    Ok(())
+}
+
+/// Sweeps the lamports of the config above its rent-exempt minimum and `keep_minimum`, and
+/// returns the amount swept.
+fn sweep_native(
+    config_pda: &AccountInfo<'_>,
+    destination: &AccountInfo<'_>,
+    keep_minimum: u64,
+) -> Result<u64, ProgramError> {
+    let rent_exempt_minimum = Rent::get()?.minimum_balance(config_pda.data_len());
+    let amount = config_pda
+        .lamports()
+        .saturating_sub(rent_exempt_minimum)
+        .saturating_sub(keep_minimum);
+
+    if amount > 0 {
+        transfer_lamports(config_pda, destination, amount)?;
+    }
+
+    Ok(amount)
+}
+
+/// Sweeps the tokens of the config above `keep_minimum`, and returns the amount swept.
+fn sweep_spl<'a>(
+    config: &Config,
+    config_pda: &AccountInfo<'a>,
+    config_pda_ata: &AccountInfo<'a>,
+    destination: &AccountInfo<'a>,
+    mint: &AccountInfo<'a>,
+    token_program: &AccountInfo<'a>,
+    keep_minimum: u64,
+) -> Result<u64, ProgramError> {
+    spl_token_2022::check_spl_token_program_account(token_program.key)?;
+    ensure_valid_config_pda_ata(config_pda_ata, token_program, mint, config_pda)?;
+
+    let balance = StateWithExtensions::<Account>::unpack(&config_pda_ata.try_borrow_data()?)?
+        .base
+        .amount;
+    let decimals = StateWithExtensions::<Mint>::unpack(&mint.try_borrow_data()?)?
+        .base
+        .decimals;
+    let amount = balance.saturating_sub(keep_minimum);
+
+    if amount > 0 {
+        let ix = transfer_tokens(
+            token_program,
+            config_pda_ata,
+            mint,
+            destination,
+            config_pda,
+            &[],
+            amount,
+            decimals,
+        )?;
+
+        invoke_signed(
+            &ix,
+            &[
+                config_pda.clone(),
+                mint.clone(),
+                config_pda_ata.clone(),
+                destination.clone(),
+                token_program.clone(),
+            ],
+            &[&[seed_prefixes::CONFIG_SEED, &config.salt, &[config.bump]]],
+        )?;
+    }
+
+    Ok(amount)
+}
+
+fn load_treasury(
+    program_id: &Pubkey,
+    treasury_pda: &AccountInfo<'_>,
+    config_pda: &Pubkey,
+) -> Result<Treasury, ProgramError> {
+    treasury_pda.check_initialized_pda_without_deserialization(program_id)?;
+    let data = treasury_pda.try_borrow_data()?;
+    let treasury = Treasury::read(&data).ok_or(ProgramError::InvalidAccountData)?;
+
+    assert_valid_treasury_pda(
+        treasury.bump,
+        &treasury.config_pda,
+        &treasury.token,
+        treasury_pda.key,
+    )?;
+
+    if &treasury.config_pda != config_pda {
+        msg!("Treasury doesn't belong to the config");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    Ok(*treasury)
+}
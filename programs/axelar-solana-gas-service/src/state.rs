@@ -1,5 +1,6 @@
 //! State module for the Axelar Solana Gas Service
 
+use borsh::{BorshDeserialize, BorshSerialize};
 use bytemuck::{Pod, Zeroable};
 use program_utils::pda::BytemuckedPda;
 use solana_program::pubkey::Pubkey;
@@ -17,3 +18,344 @@ pub struct Config {
 }
 
 impl BytemuckedPda for Config {}
+
+/// Ledger of the gas deposited and refunded for a single payment, in a single token.
+///
+/// The ledger is a PDA derived from the token, the contract call and the refund address of the
+/// payment (see [`crate::get_gas_payment_pda`]), created by the first `ForContractCall` payment,
+/// further identical payments accumulating in it. Later top ups and refunds of the payment refer
+/// to it, and refunds can only go to the refund address of the payment.
+///
+/// The message ID of the contract call is only known once the payment is made, so the first
+/// refund, issued by the refunder, binds it to the ledger, see [`GasMessage`].
+///
+/// The payment can only be refunded within [`crate::REFUND_WINDOW`] of its last payment or top
+/// up, after which what is left of it can be released from the gas held for refunds.
+#[repr(C)]
+#[derive(Zeroable, Pod, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct GasPayment {
+    /// The gas service config PDA the gas was paid to.
+    pub config_pda: Pubkey,
+    /// The token the gas was paid in, [`crate::NATIVE_GAS_TOKEN`] for native SOL.
+    pub token: Pubkey,
+    /// The key of the contract call the payment is for, see [`crate::gas_payment_key`].
+    pub payment_key: [u8; 32],
+    /// The only account gas paid for the payment can be refunded to.
+    pub refund_address: Pubkey,
+    /// Total amount paid through `ForContractCall`.
+    pub paid: u64,
+    /// Total amount added through `AddGas`.
+    pub added: u64,
+    /// Total amount refunded.
+    pub refunded: u64,
+    /// Total amount released from the gas held for refunds once the refund window was over.
+    pub released: u64,
+    /// Unix timestamp after which the payment can't be refunded anymore.
+    pub refundable_until: i64,
+    /// Hash of the message ID the payment is bound to (see [`crate::message_id_hash`]), zeroed
+    /// until its first refund.
+    pub message_id_hash: [u8; 32],
+    /// The bump seed used to derive the PDA, ensuring the address is valid.
+    pub bump: u8,
+    /// Explicit padding to keep the layout free of implicit padding bytes.
+    pub padding: [u8; 7],
+}
+
+impl GasPayment {
+    /// The amount that can still be refunded for this payment.
+    #[must_use]
+    pub fn refundable(&self) -> u64 {
+        self.paid
+            .saturating_add(self.added)
+            .saturating_sub(self.refunded)
+            .saturating_sub(self.released)
+    }
+}
+
+impl BytemuckedPda for GasPayment {}
+
+/// Binds an Axelar message ID to the ledger of the gas payment made for it, so that each message
+/// ID refers to a single payment.
+#[repr(C)]
+#[derive(Zeroable, Pod, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct GasMessage {
+    /// The ledger account of the payment, see [`GasPayment`].
+    pub gas_payment: Pubkey,
+    /// The bump seed used to derive the PDA, ensuring the address is valid.
+    pub bump: u8,
+    /// Explicit padding to keep the layout free of implicit padding bytes.
+    pub padding: [u8; 7],
+}
+
+impl BytemuckedPda for GasMessage {}
+
+/// Gas prices of a destination chain, used to quote the gas fee of contract calls.
+#[repr(C)]
+#[derive(Zeroable, Pod, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct GasOracle {
+    /// The gas service config PDA the oracle belongs to.
+    pub config_pda: Pubkey,
+    /// Hash of the destination chain name (see [`crate::chain_hash`]).
+    pub chain_hash: [u8; 32],
+    /// The account allowed to update the gas prices.
+    pub updater: Pubkey,
+    /// Fee charged for every contract call, in units of the destination chain gas token.
+    pub base_fee: u64,
+    /// Execution cost of every payload byte, in units of the destination chain gas token.
+    pub fee_per_byte: u64,
+    /// Lamports worth [`GasOracle::RATE_PRECISION`] units of the destination chain gas token.
+    pub token_to_sol_rate: u64,
+    /// Unix timestamp of the last update of the gas prices.
+    pub updated_at: i64,
+    /// The bump seed used to derive the PDA, ensuring the address is valid.
+    pub bump: u8,
+    /// Explicit padding to keep the layout free of implicit padding bytes.
+    pub padding: [u8; 7],
+}
+
+impl GasOracle {
+    /// Scale of [`GasOracle::token_to_sol_rate`].
+    pub const RATE_PRECISION: u128 = 1_000_000_000_000;
+
+    /// Seconds after their last update the gas prices are too stale to quote fees.
+    pub const MAX_PRICE_AGE: i64 = 3_600;
+
+    /// Whether the gas prices can quote fees at `now`: they must have been set at least once and
+    /// updated within [`GasOracle::MAX_PRICE_AGE`].
+    #[must_use]
+    pub fn is_fresh(&self, now: i64) -> bool {
+        self.updated_at != 0 && now.saturating_sub(self.updated_at) <= Self::MAX_PRICE_AGE
+    }
+
+    /// Quotes the gas fee, in lamports, of a contract call with a payload of `payload_len`
+    /// bytes. Rounds up, so paying the quote never underpays.
+    ///
+    /// Returns `None` if the fee doesn't fit in a `u64`.
+    #[must_use]
+    pub fn estimate_fee(&self, payload_len: u64) -> Option<u64> {
+        let cost = u128::from(self.fee_per_byte)
+            .checked_mul(u128::from(payload_len))?
+            .checked_add(u128::from(self.base_fee))?;
+        let fee = cost
+            .checked_mul(u128::from(self.token_to_sol_rate))?
+            .div_ceil(Self::RATE_PRECISION);
+
+        fee.try_into().ok()
+    }
+}
+
+impl BytemuckedPda for GasOracle {}
+
+/// An SPL token accepted to pay for gas. Payments in tokens without one are rejected.
+#[repr(C)]
+#[derive(Zeroable, Pod, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct GasToken {
+    /// The gas service config PDA accepting the token.
+    pub config_pda: Pubkey,
+    /// The mint of the token.
+    pub mint: Pubkey,
+    /// The minimum amount, in base units of the token, of a payment for a contract call.
+    pub min_payment: u64,
+    /// Base units of the token worth [`GasOracle::RATE_PRECISION`] lamports, used to check
+    /// payments against the gas oracle quotes. Zero if payments in the token can't be checked.
+    pub lamports_to_token_rate: u64,
+    /// The bump seed used to derive the PDA, ensuring the address is valid.
+    pub bump: u8,
+    /// Explicit padding to keep the layout free of implicit padding bytes.
+    pub padding: [u8; 7],
+}
+
+impl GasToken {
+    /// Converts a fee quoted in lamports to base units of the token. Rounds up, so paying the
+    /// converted fee never underpays.
+    ///
+    /// Returns `None` if the token has no rate or the amount doesn't fit in a `u64`.
+    #[must_use]
+    pub fn lamports_to_tokens(&self, lamports: u64) -> Option<u64> {
+        if self.lamports_to_token_rate == 0 {
+            return None;
+        }
+
+        u128::from(lamports)
+            .checked_mul(u128::from(self.lamports_to_token_rate))?
+            .div_ceil(GasOracle::RATE_PRECISION)
+            .try_into()
+            .ok()
+    }
+}
+
+impl BytemuckedPda for GasToken {}
+
+/// Where the fees accrued by a config in a single token are swept to, see
+/// [`crate::instructions::GasServiceInstruction::SweepFees`].
+#[repr(C)]
+#[derive(Zeroable, Pod, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct Treasury {
+    /// The gas service config PDA the fees are swept from.
+    pub config_pda: Pubkey,
+    /// The token, [`crate::NATIVE_GAS_TOKEN`] for native SOL.
+    pub token: Pubkey,
+    /// The account receiving the swept fees, a token account for SPL tokens.
+    pub destination: Pubkey,
+    /// The balance left to the config on top of the refundable gas of the token, in base units
+    /// of the token.
+    pub keep_minimum: u64,
+    /// The minimum number of seconds between two sweeps of the token.
+    pub sweep_interval: i64,
+    /// The unix timestamp of the last sweep of the token, zero if it was never swept.
+    pub last_swept_at: i64,
+    /// The bump seed used to derive the PDA, ensuring the address is valid.
+    pub bump: u8,
+    /// Explicit padding to keep the layout free of implicit padding bytes.
+    pub padding: [u8; 7],
+}
+
+impl Treasury {
+    /// Whether the token can be swept at the unix timestamp `now`, sweeps being scheduled at
+    /// least `sweep_interval` seconds apart.
+    #[must_use]
+    pub const fn is_sweep_due(&self, now: i64) -> bool {
+        self.last_swept_at == 0 || now.saturating_sub(self.last_swept_at) >= self.sweep_interval
+    }
+}
+
+impl BytemuckedPda for Treasury {}
+
+/// Gas held by the config for refunds in a single token: the gas paid and added for all the
+/// payments in the token, minus what was refunded or released once their refund window was over.
+/// Fees are only collected, and swept to the treasury of the token, above it, see [`Treasury`].
+#[repr(C)]
+#[derive(Zeroable, Pod, Clone, Copy, PartialEq, Eq, Debug)]
+pub struct GasReserve {
+    /// The gas service config PDA holding the gas.
+    pub config_pda: Pubkey,
+    /// The token, [`crate::NATIVE_GAS_TOKEN`] for native SOL.
+    pub token: Pubkey,
+    /// The amount held for refunds, in base units of the token.
+    pub refundable: u64,
+    /// The bump seed used to derive the PDA, ensuring the address is valid.
+    pub bump: u8,
+    /// Explicit padding to keep the layout free of implicit padding bytes.
+    pub padding: [u8; 7],
+}
+
+impl BytemuckedPda for GasReserve {}
+
+/// Balance held by the gas service in a single SPL token, as reported by
+/// [`crate::instructions::GasServiceInstruction::GasTokenBalances`].
+#[derive(Clone, Copy, PartialEq, Eq, Debug, BorshSerialize, BorshDeserialize)]
+pub struct GasTokenBalance {
+    /// The mint of the token.
+    pub mint: Pubkey,
+    /// The amount of the token held by the config, in base units.
+    pub amount: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_gas_oracle_estimate_fee() {
+        let oracle = GasOracle {
+            config_pda: Pubkey::new_unique(),
+            chain_hash: [1; 32],
+            updater: Pubkey::new_unique(),
+            // 1 gwei and 0.01 gwei per byte
+            base_fee: 1_000_000_000,
+            fee_per_byte: 10_000_000,
+            // 20 SOL per ETH
+            token_to_sol_rate: 20_000,
+            updated_at: 0,
+            bump: 255,
+            padding: [0; 7],
+        };
+
+        assert_eq!(oracle.estimate_fee(0), Some(20));
+        assert_eq!(oracle.estimate_fee(100), Some(40));
+        // Rounds up
+        assert_eq!(oracle.estimate_fee(1), Some(21));
+
+        let expensive = GasOracle {
+            base_fee: u64::MAX,
+            token_to_sol_rate: u64::MAX,
+            ..oracle
+        };
+        assert_eq!(expensive.estimate_fee(0), None);
+    }
+
+    #[test]
+    fn test_gas_oracle_is_fresh() {
+        let oracle = GasOracle {
+            config_pda: Pubkey::new_unique(),
+            chain_hash: [1; 32],
+            updater: Pubkey::new_unique(),
+            base_fee: 1,
+            fee_per_byte: 1,
+            token_to_sol_rate: 1,
+            updated_at: 0,
+            bump: 255,
+            padding: [0; 7],
+        };
+
+        // Never updated
+        assert!(!oracle.is_fresh(1_000));
+
+        let updated = GasOracle {
+            updated_at: 1_000,
+            ..oracle
+        };
+        assert!(updated.is_fresh(1_000));
+        assert!(updated.is_fresh(1_000 + GasOracle::MAX_PRICE_AGE));
+        assert!(!updated.is_fresh(1_001 + GasOracle::MAX_PRICE_AGE));
+    }
+
+    #[test]
+    fn test_gas_token_lamports_to_tokens() {
+        let gas_token = GasToken {
+            config_pda: Pubkey::new_unique(),
+            mint: Pubkey::new_unique(),
+            min_payment: 0,
+            // 1 token base unit per 1000 lamports
+            lamports_to_token_rate: 1_000_000_000,
+            bump: 255,
+            padding: [0; 7],
+        };
+
+        assert_eq!(gas_token.lamports_to_tokens(0), Some(0));
+        assert_eq!(gas_token.lamports_to_tokens(2_000), Some(2));
+        // Rounds up
+        assert_eq!(gas_token.lamports_to_tokens(2_001), Some(3));
+
+        let no_rate = GasToken {
+            lamports_to_token_rate: 0,
+            ..gas_token
+        };
+        assert_eq!(no_rate.lamports_to_tokens(2_000), None);
+    }
+
+    #[test]
+    fn test_treasury_is_sweep_due() {
+        let treasury = Treasury {
+            config_pda: Pubkey::new_unique(),
+            token: Pubkey::new_unique(),
+            destination: Pubkey::new_unique(),
+            keep_minimum: 0,
+            sweep_interval: 86_400,
+            last_swept_at: 0,
+            bump: 255,
+            padding: [0; 7],
+        };
+
+        // Never swept
+        assert!(treasury.is_sweep_due(1_000));
+
+        let swept = Treasury {
+            last_swept_at: 1_000,
+            ..treasury
+        };
+        assert!(!swept.is_sweep_due(1_000));
+        assert!(!swept.is_sweep_due(85_399 + 1_000));
+        assert!(swept.is_sweep_due(86_400 + 1_000));
+    }
+}
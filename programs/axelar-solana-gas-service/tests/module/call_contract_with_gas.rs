@@ -0,0 +1,220 @@
+use axelar_solana_gas_service_events::events::{
+    GasServiceEvent, NativeGasPaidForContractCallEvent, SplGasPaidForContractCallEvent,
+};
+use axelar_solana_gateway::processor::{CallContractEvent, GatewayEvent};
+use axelar_solana_gateway_test_fixtures::gas_service::get_gas_service_events;
+use axelar_solana_gateway_test_fixtures::gateway::get_gateway_events;
+use axelar_solana_gateway_test_fixtures::SolanaAxelarIntegration;
+use gateway_event_stack::ProgramInvocationState;
+use solana_program_test::tokio;
+use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer};
+
+#[tokio::test]
+async fn test_call_contract_with_native_gas() {
+    // Setup
+    let mut solana_chain = SolanaAxelarIntegration::builder()
+        .initial_signer_weights(vec![42])
+        .build()
+        .setup()
+        .await;
+    let gas_utils = solana_chain.fixture.deploy_gas_service().await;
+    solana_chain
+        .fixture
+        .init_gas_config(&gas_utils)
+        .await
+        .unwrap();
+    let sender = Keypair::new();
+    solana_chain
+        .fixture
+        .fund_account(&sender.pubkey(), 1_000_000_000)
+        .await;
+
+    // Action
+    let destination_chain = "ethereum".to_owned();
+    let destination_address = "0x destination addr 123".to_owned();
+    let payload = b"hello from solana".to_vec();
+    let refund_address = Pubkey::new_unique();
+    let ix = axelar_solana_gas_service::instructions::call_contract_with_native_gas_instruction(
+        &axelar_solana_gas_service::ID,
+        &solana_chain.gateway_root_pda,
+        &sender.pubkey(),
+        None,
+        &sender.pubkey(),
+        &gas_utils.config_pda,
+        destination_chain.clone(),
+        destination_address.clone(),
+        payload.clone(),
+        refund_address,
+        vec![],
+        1_000_000,
+    )
+    .unwrap();
+    let res = solana_chain
+        .fixture
+        .send_tx_with_custom_signers(
+            &[ix],
+            &[&solana_chain.fixture.payer.insecure_clone(), &sender],
+        )
+        .await
+        .unwrap();
+
+    // Assert: the gas is paid for the contract call sent through the gateway
+    let payload_hash = solana_sdk::keccak::hash(&payload).to_bytes();
+    let emitted_events = get_gas_service_events(&res).into_iter().next().unwrap();
+    let ProgramInvocationState::Succeeded(vec_events) = emitted_events else {
+        panic!("unexpected event")
+    };
+    let [(_, GasServiceEvent::NativeGasPaidForContractCall(emitted_event))] = vec_events.as_slice()
+    else {
+        panic!("unexpected event")
+    };
+    assert_eq!(
+        emitted_event,
+        &NativeGasPaidForContractCallEvent {
+            config_pda: gas_utils.config_pda,
+            destination_chain: destination_chain.clone(),
+            destination_address: destination_address.clone(),
+            payload_hash,
+            refund_address,
+            params: vec![],
+            gas_fee_amount: 1_000_000,
+        }
+    );
+
+    let emitted_events = get_gateway_events(&res).into_iter().next().unwrap();
+    let ProgramInvocationState::Succeeded(vec_events) = emitted_events else {
+        panic!("unexpected event")
+    };
+    let [(_, GatewayEvent::CallContract(emitted_event))] = vec_events.as_slice() else {
+        panic!("unexpected event")
+    };
+    assert_eq!(
+        emitted_event,
+        &CallContractEvent {
+            sender_key: sender.pubkey(),
+            destination_chain,
+            destination_contract_address: destination_address,
+            payload,
+            payload_hash,
+        }
+    );
+}
+
+#[tokio::test]
+async fn test_call_contract_with_spl_gas() {
+    // Setup
+    let mut solana_chain = SolanaAxelarIntegration::builder()
+        .initial_signer_weights(vec![42])
+        .build()
+        .setup()
+        .await;
+    let gas_utils = solana_chain.fixture.deploy_gas_service().await;
+    solana_chain
+        .fixture
+        .init_gas_config(&gas_utils)
+        .await
+        .unwrap();
+    let sender = Keypair::new();
+    solana_chain
+        .fixture
+        .fund_account(&sender.pubkey(), 1_000_000_000)
+        .await;
+    let mint_authority = Keypair::new();
+    let mint = solana_chain
+        .fixture
+        .init_new_mint(mint_authority.pubkey(), spl_token_2022::id(), 6)
+        .await;
+    let sender_ata = solana_chain
+        .fixture
+        .init_associated_token_account(&mint, &sender.pubkey(), &spl_token_2022::id())
+        .await;
+    solana_chain
+        .fixture
+        .mint_tokens_to(
+            &mint,
+            &sender_ata,
+            &mint_authority,
+            1_000_000,
+            &spl_token_2022::id(),
+        )
+        .await;
+    let config_pda_ata = solana_chain
+        .fixture
+        .init_associated_token_account(&mint, &gas_utils.config_pda, &spl_token_2022::id())
+        .await;
+    solana_chain
+        .fixture
+        .allow_gas_token(&gas_utils, &mint, 0, 0)
+        .await
+        .unwrap();
+
+    // Action
+    let destination_chain = "ethereum".to_owned();
+    let destination_address = "0x destination addr 123".to_owned();
+    let payload = b"hello from solana".to_vec();
+    let refund_address = Pubkey::new_unique();
+    let ix = axelar_solana_gas_service::instructions::call_contract_with_spl_gas_instruction(
+        &axelar_solana_gas_service::ID,
+        &solana_chain.gateway_root_pda,
+        &sender.pubkey(),
+        None,
+        &sender.pubkey(),
+        &sender_ata,
+        &gas_utils.config_pda,
+        &config_pda_ata,
+        &mint,
+        &spl_token_2022::id(),
+        destination_chain.clone(),
+        destination_address.clone(),
+        payload.clone(),
+        refund_address,
+        vec![],
+        5_000,
+        6,
+    )
+    .unwrap();
+    let res = solana_chain
+        .fixture
+        .send_tx_with_custom_signers(
+            &[ix],
+            &[&solana_chain.fixture.payer.insecure_clone(), &sender],
+        )
+        .await
+        .unwrap();
+
+    // Assert
+    let payload_hash = solana_sdk::keccak::hash(&payload).to_bytes();
+    let emitted_events = get_gas_service_events(&res).into_iter().next().unwrap();
+    let ProgramInvocationState::Succeeded(vec_events) = emitted_events else {
+        panic!("unexpected event")
+    };
+    let [(_, GasServiceEvent::SplGasPaidForContractCall(emitted_event))] = vec_events.as_slice()
+    else {
+        panic!("unexpected event")
+    };
+    assert_eq!(
+        emitted_event,
+        &SplGasPaidForContractCallEvent {
+            config_pda: gas_utils.config_pda,
+            config_pda_ata,
+            mint,
+            token_program_id: spl_token_2022::id(),
+            destination_chain: destination_chain.clone(),
+            destination_address: destination_address.clone(),
+            payload_hash,
+            refund_address,
+            params: vec![],
+            gas_fee_amount: 5_000,
+        }
+    );
+
+    let emitted_events = get_gateway_events(&res).into_iter().next().unwrap();
+    let ProgramInvocationState::Succeeded(vec_events) = emitted_events else {
+        panic!("unexpected event")
+    };
+    let [(_, GatewayEvent::CallContract(emitted_event))] = vec_events.as_slice() else {
+        panic!("unexpected event")
+    };
+    assert_eq!(emitted_event.payload_hash, payload_hash);
+    assert_eq!(emitted_event.sender_key, sender.pubkey());
+}
@@ -0,0 +1,232 @@
+use axelar_solana_gas_service::state::GasOracle;
+use axelar_solana_gateway_test_fixtures::base::{FindLog, TestFixture};
+use axelar_solana_gateway_test_fixtures::gas_service::GasServiceUtils;
+use solana_program_test::{tokio, ProgramTest};
+use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer};
+
+const DESTINATION_CHAIN: &str = "ethereum";
+// 1 gwei base fee, 0.01 gwei per byte at 20 SOL per ETH
+const BASE_FEE: u64 = 1_000_000_000;
+const FEE_PER_BYTE: u64 = 10_000_000;
+const TOKEN_TO_SOL_RATE: u64 = 20_000;
+
+pub(crate) async fn setup_gas_oracle(
+    test_fixture: &mut TestFixture,
+    gas_utils: &GasServiceUtils,
+) -> Keypair {
+    let updater = Keypair::new();
+    let set_updater_ix =
+        axelar_solana_gas_service::instructions::set_gas_oracle_updater_instruction(
+            &axelar_solana_gas_service::ID,
+            &test_fixture.payer.pubkey(),
+            &gas_utils.operator.pubkey(),
+            &gas_utils.config_pda,
+            DESTINATION_CHAIN.to_owned(),
+            updater.pubkey(),
+        )
+        .unwrap();
+    let update_ix = axelar_solana_gas_service::instructions::update_gas_oracle_instruction(
+        &axelar_solana_gas_service::ID,
+        &updater.pubkey(),
+        &gas_utils.config_pda,
+        DESTINATION_CHAIN.to_owned(),
+        BASE_FEE,
+        FEE_PER_BYTE,
+        TOKEN_TO_SOL_RATE,
+    )
+    .unwrap();
+
+    test_fixture
+        .send_tx_with_custom_signers(
+            &[set_updater_ix, update_ix],
+            &[
+                &test_fixture.payer.insecure_clone(),
+                &gas_utils.operator,
+                &updater,
+            ],
+        )
+        .await
+        .unwrap();
+
+    updater
+}
+
+#[tokio::test]
+async fn test_estimate_gas_fee() {
+    // Setup
+    let pt = ProgramTest::default();
+    let mut test_fixture = TestFixture::new(pt).await;
+    let gas_utils = test_fixture.deploy_gas_service().await;
+    test_fixture.init_gas_config(&gas_utils).await.unwrap();
+    setup_gas_oracle(&mut test_fixture, &gas_utils).await;
+
+    // Action
+    let ix = axelar_solana_gas_service::instructions::estimate_gas_fee_instruction(
+        &axelar_solana_gas_service::ID,
+        &gas_utils.config_pda,
+        DESTINATION_CHAIN.to_owned(),
+        100,
+    )
+    .unwrap();
+    let res = test_fixture.send_tx(&[ix]).await.unwrap();
+
+    // Assert
+    let return_data = res.metadata.unwrap().return_data.unwrap();
+    assert_eq!(return_data.program_id, axelar_solana_gas_service::ID);
+    assert_eq!(return_data.data, 40_u64.to_le_bytes().to_vec());
+}
+
+#[tokio::test]
+async fn test_estimate_gas_fee_fails_if_prices_not_set() {
+    // Setup: the updater is set, but never updated the gas prices
+    let pt = ProgramTest::default();
+    let mut test_fixture = TestFixture::new(pt).await;
+    let gas_utils = test_fixture.deploy_gas_service().await;
+    test_fixture.init_gas_config(&gas_utils).await.unwrap();
+    let set_updater_ix =
+        axelar_solana_gas_service::instructions::set_gas_oracle_updater_instruction(
+            &axelar_solana_gas_service::ID,
+            &test_fixture.payer.pubkey(),
+            &gas_utils.operator.pubkey(),
+            &gas_utils.config_pda,
+            DESTINATION_CHAIN.to_owned(),
+            Pubkey::new_unique(),
+        )
+        .unwrap();
+    test_fixture
+        .send_tx_with_custom_signers(
+            &[set_updater_ix],
+            &[&test_fixture.payer.insecure_clone(), &gas_utils.operator],
+        )
+        .await
+        .unwrap();
+
+    // Action
+    let ix = axelar_solana_gas_service::instructions::estimate_gas_fee_instruction(
+        &axelar_solana_gas_service::ID,
+        &gas_utils.config_pda,
+        DESTINATION_CHAIN.to_owned(),
+        100,
+    )
+    .unwrap();
+    let res = test_fixture.send_tx(&[ix]).await.unwrap_err();
+
+    // Assert
+    assert!(res
+        .find_log("Gas oracle prices are not set or are stale")
+        .is_some());
+}
+
+#[tokio::test]
+async fn test_estimate_gas_fee_fails_if_prices_stale() {
+    // Setup
+    let pt = ProgramTest::default();
+    let mut test_fixture = TestFixture::new(pt).await;
+    let gas_utils = test_fixture.deploy_gas_service().await;
+    test_fixture.init_gas_config(&gas_utils).await.unwrap();
+    setup_gas_oracle(&mut test_fixture, &gas_utils).await;
+    test_fixture
+        .forward_time(GasOracle::MAX_PRICE_AGE + 1)
+        .await;
+
+    // Action
+    let ix = axelar_solana_gas_service::instructions::estimate_gas_fee_instruction(
+        &axelar_solana_gas_service::ID,
+        &gas_utils.config_pda,
+        DESTINATION_CHAIN.to_owned(),
+        100,
+    )
+    .unwrap();
+    let res = test_fixture.send_tx(&[ix]).await.unwrap_err();
+
+    // Assert
+    assert!(res
+        .find_log("Gas oracle prices are not set or are stale")
+        .is_some());
+}
+
+#[tokio::test]
+async fn test_update_gas_oracle_fails_if_not_updater() {
+    // Setup
+    let pt = ProgramTest::default();
+    let mut test_fixture = TestFixture::new(pt).await;
+    let gas_utils = test_fixture.deploy_gas_service().await;
+    test_fixture.init_gas_config(&gas_utils).await.unwrap();
+    setup_gas_oracle(&mut test_fixture, &gas_utils).await;
+
+    // Action: the operator isn't the updater of the gas oracle
+    let ix = axelar_solana_gas_service::instructions::update_gas_oracle_instruction(
+        &axelar_solana_gas_service::ID,
+        &gas_utils.operator.pubkey(),
+        &gas_utils.config_pda,
+        DESTINATION_CHAIN.to_owned(),
+        0,
+        0,
+        0,
+    )
+    .unwrap();
+    let res = test_fixture
+        .send_tx_with_custom_signers(
+            &[ix],
+            &[&test_fixture.payer.insecure_clone(), &gas_utils.operator],
+        )
+        .await
+        .unwrap_err();
+
+    // Assert
+    assert!(res
+        .find_log("Only the gas oracle updater can update the gas prices")
+        .is_some());
+}
+
+#[tokio::test]
+async fn test_pay_native_rejects_underpayment() {
+    // Setup
+    let pt = ProgramTest::default();
+    let mut test_fixture = TestFixture::new(pt).await;
+    let gas_utils = test_fixture.deploy_gas_service().await;
+    test_fixture.init_gas_config(&gas_utils).await.unwrap();
+    setup_gas_oracle(&mut test_fixture, &gas_utils).await;
+
+    let payer = Keypair::new();
+    test_fixture
+        .fund_account(&payer.pubkey(), 1_000_000_000)
+        .await;
+
+    let pay_ix = |gas_fee_amount| {
+        axelar_solana_gas_service::instructions::pay_native_for_contract_call_instruction(
+            &axelar_solana_gas_service::ID,
+            &payer.pubkey(),
+            &gas_utils.config_pda,
+            DESTINATION_CHAIN.to_owned(),
+            "destination addr 123".to_owned(),
+            [42; 32],
+            Pubkey::new_unique(),
+            vec![],
+            gas_fee_amount,
+            Some(100),
+        )
+        .unwrap()
+    };
+
+    // Action: pay less than the quote
+    let res = test_fixture
+        .send_tx_with_custom_signers(
+            &[pay_ix(39)],
+            &[&test_fixture.payer.insecure_clone(), &payer],
+        )
+        .await
+        .unwrap_err();
+    assert!(res
+        .find_log("Gas fee amount is below the quote of 40")
+        .is_some());
+
+    // Action: pay the quote
+    test_fixture
+        .send_tx_with_custom_signers(
+            &[pay_ix(40)],
+            &[&test_fixture.payer.insecure_clone(), &payer],
+        )
+        .await
+        .unwrap();
+}
@@ -1,7 +1,43 @@
 use axelar_solana_gas_service::state::Config;
-use axelar_solana_gateway_test_fixtures::base::TestFixture;
+use axelar_solana_gas_service::Roles;
+use axelar_solana_gateway_test_fixtures::base::{FindLog, TestFixture};
+use axelar_solana_gateway_test_fixtures::gas_service::GasServiceUtils;
+use borsh::BorshDeserialize;
+use role_management::state::{RoleIndex, UserRoles};
 use solana_program_test::{tokio, ProgramTest};
-use solana_sdk::{keccak::hashv, signer::Signer};
+use solana_sdk::{account::Account, keccak::hashv, signature::Keypair, signer::Signer};
+
+async fn operator_roles(test_fixture: &mut TestFixture, gas_utils: &GasServiceUtils) -> Roles {
+    let (operator_roles_pda, _) = role_management::find_user_roles_pda(
+        &axelar_solana_gas_service::ID,
+        &gas_utils.config_pda,
+        &gas_utils.operator.pubkey(),
+    );
+    let data = test_fixture
+        .get_account(&operator_roles_pda, &axelar_solana_gas_service::ID)
+        .await
+        .data;
+
+    UserRoles::<Roles>::try_from_slice(&data)
+        .unwrap()
+        .all_roles()
+}
+
+async fn indexed_operator_roles(
+    test_fixture: &mut TestFixture,
+    gas_utils: &GasServiceUtils,
+) -> Option<Roles> {
+    let (role_index_pda, _) =
+        role_management::find_role_index_pda(&axelar_solana_gas_service::ID, &gas_utils.config_pda);
+    let data = test_fixture
+        .get_account(&role_index_pda, &axelar_solana_gas_service::ID)
+        .await
+        .data;
+
+    RoleIndex::<Roles>::try_from_slice(&data)
+        .unwrap()
+        .roles_of(&gas_utils.operator.pubkey())
+}
 
 #[tokio::test]
 async fn test_successfully_initialize_config() {
@@ -67,3 +103,101 @@ async fn test_different_salts_give_new_configs() {
         assert!(res.is_err());
     }
 }
+
+#[tokio::test]
+async fn test_initialize_indexes_operator_roles() {
+    // Setup
+    let pt = ProgramTest::default();
+    let mut test_fixture = TestFixture::new(pt).await;
+    let gas_utils = test_fixture.deploy_gas_service().await;
+
+    // Action
+    test_fixture.init_gas_config(&gas_utils).await.unwrap();
+
+    // Assert
+    let all_roles = Roles::OPERATOR | Roles::COLLECTOR | Roles::REFUNDER;
+    assert_eq!(
+        operator_roles(&mut test_fixture, &gas_utils).await,
+        all_roles
+    );
+    assert_eq!(
+        indexed_operator_roles(&mut test_fixture, &gas_utils).await,
+        Some(all_roles)
+    );
+}
+
+#[tokio::test]
+async fn test_migrate_operator_roles_of_config_without_roles() {
+    // Setup: a config initialized before roles existed has neither roles nor a role index
+    let pt = ProgramTest::default();
+    let mut test_fixture = TestFixture::new(pt).await;
+    let gas_utils = test_fixture.deploy_gas_service().await;
+    test_fixture.init_gas_config(&gas_utils).await.unwrap();
+    let (operator_roles_pda, _) = role_management::find_user_roles_pda(
+        &axelar_solana_gas_service::ID,
+        &gas_utils.config_pda,
+        &gas_utils.operator.pubkey(),
+    );
+    let (role_index_pda, _) =
+        role_management::find_role_index_pda(&axelar_solana_gas_service::ID, &gas_utils.config_pda);
+    test_fixture.set_account_state(&operator_roles_pda, Account::default());
+    test_fixture.set_account_state(&role_index_pda, Account::default());
+
+    // Action
+    let ix = axelar_solana_gas_service::instructions::migrate_operator_roles_instruction(
+        &axelar_solana_gas_service::ID,
+        &test_fixture.payer.pubkey(),
+        &gas_utils.operator.pubkey(),
+        &gas_utils.config_pda,
+    )
+    .unwrap();
+    test_fixture
+        .send_tx_with_custom_signers(
+            &[ix],
+            &[&test_fixture.payer.insecure_clone(), &gas_utils.operator],
+        )
+        .await
+        .unwrap();
+
+    // Assert
+    let all_roles = Roles::OPERATOR | Roles::COLLECTOR | Roles::REFUNDER;
+    assert_eq!(
+        operator_roles(&mut test_fixture, &gas_utils).await,
+        all_roles
+    );
+    assert_eq!(
+        indexed_operator_roles(&mut test_fixture, &gas_utils).await,
+        Some(all_roles)
+    );
+}
+
+#[tokio::test]
+async fn test_migrate_operator_roles_fails_if_not_operator() {
+    // Setup
+    let pt = ProgramTest::default();
+    let mut test_fixture = TestFixture::new(pt).await;
+    let gas_utils = test_fixture.deploy_gas_service().await;
+    test_fixture.init_gas_config(&gas_utils).await.unwrap();
+    let not_operator = Keypair::new();
+
+    // Action
+    let ix = axelar_solana_gas_service::instructions::migrate_operator_roles_instruction(
+        &axelar_solana_gas_service::ID,
+        &test_fixture.payer.pubkey(),
+        &not_operator.pubkey(),
+        &gas_utils.config_pda,
+    )
+    .unwrap();
+    let res = test_fixture
+        .send_tx_with_custom_signers(
+            &[ix],
+            &[&test_fixture.payer.insecure_clone(), &not_operator],
+        )
+        .await
+        .unwrap_err();
+
+    // Assert
+    assert!(res
+        .find_log("Only the operator of the config can migrate its roles")
+        .is_some());
+}
@@ -10,7 +10,11 @@
     unused_must_use
 )]
 
+mod call_contract_with_gas;
+mod gas_oracle;
 mod initialize;
+mod operatorship;
+mod treasury;
 
 mod native {
     mod add_gas;
@@ -22,6 +26,7 @@ mod native {
 mod spl {
     mod add_gas;
     mod collet_fees;
+    mod gas_token;
     mod pay_for_contract_call;
     mod refund_gas;
 }
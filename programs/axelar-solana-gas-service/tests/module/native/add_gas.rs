@@ -1,5 +1,10 @@
+use axelar_message_primitives::MessageId;
+use axelar_solana_gas_service::{get_gas_message_pda, message_id_hash, NATIVE_GAS_TOKEN};
 use axelar_solana_gas_service_events::events::{GasServiceEvent, NativeGasAddedEvent};
-use axelar_solana_gateway_test_fixtures::{base::TestFixture, gas_service::get_gas_service_events};
+use axelar_solana_gateway_test_fixtures::{
+    base::{FindLog, TestFixture},
+    gas_service::get_gas_service_events,
+};
 use gateway_event_stack::ProgramInvocationState;
 use solana_program_test::{tokio, ProgramTest};
 use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer};
@@ -33,14 +38,22 @@ async fn test_add_native_gas() {
     // Action
     let refund_address = Pubkey::new_unique();
     let gas_amount = 1_000_000;
-    let tx_hash = [42; 64];
-    let log_index = 1232;
+    let message_id = MessageId::new([42; 64], 0, 1232);
+    let gas_payment_account = test_fixture
+        .init_gas_payment(
+            &gas_utils,
+            &axelar_solana_gas_service::NATIVE_GAS_TOKEN,
+            [1; 32],
+            refund_address,
+            gas_amount,
+        )
+        .await;
     let ix = axelar_solana_gas_service::instructions::add_native_gas_instruction(
         &axelar_solana_gas_service::ID,
         &payer.pubkey(),
         &gas_utils.config_pda,
-        tx_hash,
-        log_index,
+        &gas_payment_account,
+        message_id.to_string(),
         gas_amount,
         refund_address,
     )
@@ -71,8 +84,7 @@ async fn test_add_native_gas() {
         emitted_event,
         &NativeGasAddedEvent {
             config_pda: gas_utils.config_pda,
-            tx_hash,
-            log_index,
+            message_id,
             refund_address,
             gas_fee_amount: gas_amount,
         }
@@ -97,6 +109,20 @@ async fn test_add_native_gas() {
         config_pda_balance_before + gas_amount
     );
     assert_eq!(payer_balance_after, payer_balance_before - gas_amount);
+    // both the payment and the top up are held for refunds
+    assert_eq!(
+        test_fixture
+            .gas_reserve_refundable(&gas_utils.config_pda, &NATIVE_GAS_TOKEN)
+            .await,
+        2 * gas_amount
+    );
+
+    // assert that the gas payment ledger records the added gas
+    let gas_payment = test_fixture.gas_payment_state(gas_payment_account).await;
+    assert_eq!(gas_payment.paid, gas_amount);
+    assert_eq!(gas_payment.added, gas_amount);
+    // only refunds bind the message ID to the payment
+    assert_eq!(gas_payment.message_id_hash, [0; 32]);
 }
 
 #[tokio::test]
@@ -116,14 +142,22 @@ async fn fails_if_payer_not_signer() {
     // Action
     let refund_address = Pubkey::new_unique();
     let gas_amount = 1_000_000;
-    let tx_hash = [42; 64];
-    let log_index = 1232;
+    let message_id = MessageId::new([42; 64], 0, 1232);
+    let gas_payment_account = test_fixture
+        .init_gas_payment(
+            &gas_utils,
+            &axelar_solana_gas_service::NATIVE_GAS_TOKEN,
+            [1; 32],
+            refund_address,
+            gas_amount,
+        )
+        .await;
     let mut ix = axelar_solana_gas_service::instructions::add_native_gas_instruction(
         &axelar_solana_gas_service::ID,
         &payer.pubkey(),
         &gas_utils.config_pda,
-        tx_hash,
-        log_index,
+        &gas_payment_account,
+        message_id.to_string(),
         gas_amount,
         refund_address,
     )
@@ -141,3 +175,334 @@ async fn fails_if_payer_not_signer() {
         .await;
     assert!(res.is_err());
 }
+
+#[tokio::test]
+async fn fails_without_gas_payment() {
+    // Setup
+    let pt = ProgramTest::default();
+    let mut test_fixture = TestFixture::new(pt).await;
+    let gas_utils = test_fixture.deploy_gas_service().await;
+    test_fixture.init_gas_config(&gas_utils).await.unwrap();
+
+    let payer = Keypair::new();
+    test_fixture
+        .fund_account(&payer.pubkey(), 1_000_000_000)
+        .await;
+
+    // Action: the payment was never made, so its ledger doesn't exist
+    let gas_payment_account = Pubkey::new_unique();
+    let ix = axelar_solana_gas_service::instructions::add_native_gas_instruction(
+        &axelar_solana_gas_service::ID,
+        &payer.pubkey(),
+        &gas_utils.config_pda,
+        &gas_payment_account,
+        MessageId::new([42; 64], 0, 1232).to_string(),
+        1_000_000,
+        Pubkey::new_unique(),
+    )
+    .unwrap();
+
+    let res = test_fixture
+        .send_tx_with_custom_signers(&[ix], &[&test_fixture.payer.insecure_clone(), &payer])
+        .await;
+    assert!(res.is_err());
+}
+
+#[tokio::test]
+async fn fails_with_another_refund_address() {
+    // Setup
+    let pt = ProgramTest::default();
+    let mut test_fixture = TestFixture::new(pt).await;
+    let gas_utils = test_fixture.deploy_gas_service().await;
+    test_fixture.init_gas_config(&gas_utils).await.unwrap();
+
+    let payer = Keypair::new();
+    test_fixture
+        .fund_account(&payer.pubkey(), 1_000_000_000)
+        .await;
+
+    let gas_amount = 1_000_000;
+    let gas_payment_account = test_fixture
+        .init_gas_payment(
+            &gas_utils,
+            &axelar_solana_gas_service::NATIVE_GAS_TOKEN,
+            [1; 32],
+            Pubkey::new_unique(),
+            gas_amount,
+        )
+        .await;
+
+    // Action: the gas would become refundable to another address than the one of the payment
+    let ix = axelar_solana_gas_service::instructions::add_native_gas_instruction(
+        &axelar_solana_gas_service::ID,
+        &payer.pubkey(),
+        &gas_utils.config_pda,
+        &gas_payment_account,
+        MessageId::new([42; 64], 0, 1232).to_string(),
+        gas_amount,
+        Pubkey::new_unique(),
+    )
+    .unwrap();
+
+    let res = test_fixture
+        .send_tx_with_custom_signers(&[ix], &[&test_fixture.payer.insecure_clone(), &payer])
+        .await
+        .unwrap_err();
+    assert!(res
+        .find_log("Gas can only be added with the refund address of the payment")
+        .is_some());
+}
+
+#[tokio::test]
+async fn fails_with_another_message_id() {
+    // Setup
+    let pt = ProgramTest::default();
+    let mut test_fixture = TestFixture::new(pt).await;
+    let gas_utils = test_fixture.deploy_gas_service().await;
+    test_fixture.init_gas_config(&gas_utils).await.unwrap();
+    test_fixture
+        .fund_account(&gas_utils.config_pda, 1_000_000_000)
+        .await;
+    test_fixture
+        .fund_account(&gas_utils.operator.pubkey(), 1_000_000_000)
+        .await;
+
+    let payer = Keypair::new();
+    test_fixture
+        .fund_account(&payer.pubkey(), 1_000_000_000)
+        .await;
+
+    let refund_address = Keypair::new().pubkey();
+    let gas_payment_account = test_fixture
+        .init_gas_payment(
+            &gas_utils,
+            &axelar_solana_gas_service::NATIVE_GAS_TOKEN,
+            [1; 32],
+            refund_address,
+            2_000_000,
+        )
+        .await;
+
+    // The refunder binds the message ID to the payment
+    let message_id = MessageId::new([42; 64], 0, 1232);
+    let refund_ix = axelar_solana_gas_service::instructions::refund_native_fees_instruction(
+        &axelar_solana_gas_service::ID,
+        &gas_utils.operator.pubkey(),
+        &refund_address,
+        &gas_utils.config_pda,
+        &gas_payment_account,
+        message_id.to_string(),
+        1_000_000,
+    )
+    .unwrap();
+    test_fixture
+        .send_tx_with_custom_signers(
+            &[refund_ix],
+            &[&test_fixture.payer.insecure_clone(), &gas_utils.operator],
+        )
+        .await
+        .unwrap();
+
+    // Action: gas is added to the same payment under another message ID
+    let ix = axelar_solana_gas_service::instructions::add_native_gas_instruction(
+        &axelar_solana_gas_service::ID,
+        &payer.pubkey(),
+        &gas_utils.config_pda,
+        &gas_payment_account,
+        MessageId::new([43; 64], 0, 1232).to_string(),
+        1_000,
+        refund_address,
+    )
+    .unwrap();
+    let res = test_fixture
+        .send_tx_with_custom_signers(&[ix], &[&test_fixture.payer.insecure_clone(), &payer])
+        .await
+        .unwrap_err();
+    assert!(res
+        .find_log("Gas payment is bound to another message ID")
+        .is_some());
+}
+
+#[tokio::test]
+async fn add_gas_cannot_front_run_message_id_binding() {
+    // Setup
+    let pt = ProgramTest::default();
+    let mut test_fixture = TestFixture::new(pt).await;
+    let gas_utils = test_fixture.deploy_gas_service().await;
+    test_fixture.init_gas_config(&gas_utils).await.unwrap();
+    test_fixture
+        .fund_account(&gas_utils.config_pda, 1_000_000_000)
+        .await;
+    test_fixture
+        .fund_account(&gas_utils.operator.pubkey(), 1_000_000_000)
+        .await;
+
+    let attacker = Keypair::new();
+    test_fixture
+        .fund_account(&attacker.pubkey(), 1_000_000_000)
+        .await;
+
+    let refund_address = Keypair::new().pubkey();
+    let gas_payment_account = test_fixture
+        .init_gas_payment(
+            &gas_utils,
+            &axelar_solana_gas_service::NATIVE_GAS_TOKEN,
+            [1; 32],
+            refund_address,
+            1_000_000,
+        )
+        .await;
+
+    // Action: an attacker tops up the payment under a made up message ID
+    let fake_message_id = MessageId::new([66; 64], 0, 1232);
+    let ix = axelar_solana_gas_service::instructions::add_native_gas_instruction(
+        &axelar_solana_gas_service::ID,
+        &attacker.pubkey(),
+        &gas_utils.config_pda,
+        &gas_payment_account,
+        fake_message_id.to_string(),
+        1_000,
+        refund_address,
+    )
+    .unwrap();
+    test_fixture
+        .send_tx_with_custom_signers(&[ix], &[&test_fixture.payer.insecure_clone(), &attacker])
+        .await
+        .unwrap();
+
+    // Assert: the made up message ID is not bound to the payment
+    let gas_payment = test_fixture.gas_payment_state(gas_payment_account).await;
+    assert_eq!(gas_payment.message_id_hash, [0; 32]);
+    let (fake_message_pda, _) = get_gas_message_pda(
+        &axelar_solana_gas_service::ID,
+        &gas_utils.config_pda,
+        &fake_message_id.to_string(),
+    );
+    assert!(test_fixture
+        .try_get_account_no_checks(&fake_message_pda)
+        .await
+        .unwrap()
+        .is_none());
+
+    // and the refunder can still refund the payment under its real message ID
+    let message_id = MessageId::new([42; 64], 0, 1232);
+    let refund_ix = axelar_solana_gas_service::instructions::refund_native_fees_instruction(
+        &axelar_solana_gas_service::ID,
+        &gas_utils.operator.pubkey(),
+        &refund_address,
+        &gas_utils.config_pda,
+        &gas_payment_account,
+        message_id.to_string(),
+        1_000_000,
+    )
+    .unwrap();
+    test_fixture
+        .send_tx_with_custom_signers(
+            &[refund_ix],
+            &[&test_fixture.payer.insecure_clone(), &gas_utils.operator],
+        )
+        .await
+        .unwrap();
+    let gas_payment = test_fixture.gas_payment_state(gas_payment_account).await;
+    assert_eq!(
+        gas_payment.message_id_hash,
+        message_id_hash(&message_id.to_string())
+    );
+}
+
+#[tokio::test]
+async fn add_gas_cannot_squat_message_id() {
+    // Setup
+    let pt = ProgramTest::default();
+    let mut test_fixture = TestFixture::new(pt).await;
+    let gas_utils = test_fixture.deploy_gas_service().await;
+    test_fixture.init_gas_config(&gas_utils).await.unwrap();
+    test_fixture
+        .fund_account(&gas_utils.config_pda, 1_000_000_000)
+        .await;
+    test_fixture
+        .fund_account(&gas_utils.operator.pubkey(), 1_000_000_000)
+        .await;
+
+    let attacker = Keypair::new();
+    test_fixture
+        .fund_account(&attacker.pubkey(), 1_000_000_000)
+        .await;
+
+    let refund_address = Keypair::new().pubkey();
+    let gas_payment_account = test_fixture
+        .init_gas_payment(
+            &gas_utils,
+            &axelar_solana_gas_service::NATIVE_GAS_TOKEN,
+            [1; 32],
+            refund_address,
+            1_000_000,
+        )
+        .await;
+    let attacker_gas_payment_account = test_fixture
+        .init_gas_payment(
+            &gas_utils,
+            &axelar_solana_gas_service::NATIVE_GAS_TOKEN,
+            [2; 32],
+            attacker.pubkey(),
+            1_000,
+        )
+        .await;
+
+    // Action: an attacker tops up their own payment under the message ID of the victim
+    let message_id = MessageId::new([42; 64], 0, 1232);
+    let ix = axelar_solana_gas_service::instructions::add_native_gas_instruction(
+        &axelar_solana_gas_service::ID,
+        &attacker.pubkey(),
+        &gas_utils.config_pda,
+        &attacker_gas_payment_account,
+        message_id.to_string(),
+        1_000,
+        attacker.pubkey(),
+    )
+    .unwrap();
+    test_fixture
+        .send_tx_with_custom_signers(&[ix], &[&test_fixture.payer.insecure_clone(), &attacker])
+        .await
+        .unwrap();
+
+    // Assert: the message ID is not bound to the payment of the attacker
+    let attacker_gas_payment = test_fixture
+        .gas_payment_state(attacker_gas_payment_account)
+        .await;
+    assert_eq!(attacker_gas_payment.message_id_hash, [0; 32]);
+    let (gas_message_pda, _) = get_gas_message_pda(
+        &axelar_solana_gas_service::ID,
+        &gas_utils.config_pda,
+        &message_id.to_string(),
+    );
+    assert!(test_fixture
+        .try_get_account_no_checks(&gas_message_pda)
+        .await
+        .unwrap()
+        .is_none());
+
+    // and the refunder can still bind it to the payment of the victim
+    let refund_ix = axelar_solana_gas_service::instructions::refund_native_fees_instruction(
+        &axelar_solana_gas_service::ID,
+        &gas_utils.operator.pubkey(),
+        &refund_address,
+        &gas_utils.config_pda,
+        &gas_payment_account,
+        message_id.to_string(),
+        1_000_000,
+    )
+    .unwrap();
+    test_fixture
+        .send_tx_with_custom_signers(
+            &[refund_ix],
+            &[&test_fixture.payer.insecure_clone(), &gas_utils.operator],
+        )
+        .await
+        .unwrap();
+    let gas_payment = test_fixture.gas_payment_state(gas_payment_account).await;
+    assert_eq!(
+        gas_payment.message_id_hash,
+        message_id_hash(&message_id.to_string())
+    );
+}
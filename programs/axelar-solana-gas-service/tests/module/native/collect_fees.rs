@@ -1,4 +1,4 @@
-use axelar_solana_gateway_test_fixtures::base::TestFixture;
+use axelar_solana_gateway_test_fixtures::base::{FindLog, TestFixture};
 use solana_program_test::{tokio, ProgramTest};
 use solana_sdk::{signature::Keypair, signer::Signer};
 
@@ -105,3 +105,80 @@ async fn test_refund_native_fails_if_not_signed_by_authority() {
 
     assert!(res.is_err());
 }
+
+#[tokio::test]
+async fn test_collect_fees_keeps_refundable_gas() {
+    // Setup
+    let pt = ProgramTest::default();
+    let mut test_fixture = TestFixture::new(pt).await;
+    let gas_utils = test_fixture.deploy_gas_service().await;
+    test_fixture.init_gas_config(&gas_utils).await.unwrap();
+
+    // a payment that can still be refunded, on top of the fees
+    let gas_amount = 500_000_000;
+    test_fixture
+        .init_gas_payment(
+            &gas_utils,
+            &axelar_solana_gas_service::NATIVE_GAS_TOKEN,
+            [1; 32],
+            Keypair::new().pubkey(),
+            gas_amount,
+        )
+        .await;
+    let fees = 1_000_000_000;
+    test_fixture
+        .fund_account(&gas_utils.config_pda, fees + gas_amount)
+        .await;
+    let receiver = Keypair::new();
+    let collect_ix = |amount| {
+        axelar_solana_gas_service::instructions::collect_native_fees_instruction(
+            &axelar_solana_gas_service::ID,
+            &gas_utils.operator.pubkey(),
+            &gas_utils.config_pda,
+            &receiver.pubkey(),
+            amount,
+        )
+        .unwrap()
+    };
+
+    // Action: the collected fees would dip into the gas held for refunds
+    let res = test_fixture
+        .send_tx_with_custom_signers(
+            &[collect_ix(fees + 1)],
+            &[&test_fixture.payer.insecure_clone(), &gas_utils.operator],
+        )
+        .await
+        .unwrap_err();
+
+    // Assert
+    assert!(res
+        .find_log("Collected fees exceed the balance not held for refunds")
+        .is_some());
+
+    // Action: the fees alone can be collected
+    test_fixture
+        .send_tx_with_custom_signers(
+            &[collect_ix(fees)],
+            &[&test_fixture.payer.insecure_clone(), &gas_utils.operator],
+        )
+        .await
+        .unwrap();
+
+    // Assert
+    let receiver_balance = test_fixture
+        .try_get_account_no_checks(&receiver.pubkey())
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    assert_eq!(receiver_balance, fees);
+    assert_eq!(
+        test_fixture
+            .gas_reserve_refundable(
+                &gas_utils.config_pda,
+                &axelar_solana_gas_service::NATIVE_GAS_TOKEN
+            )
+            .await,
+        gas_amount
+    );
+}
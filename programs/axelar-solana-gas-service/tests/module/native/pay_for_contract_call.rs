@@ -1,3 +1,7 @@
+use axelar_solana_gas_service::{
+    state::{GasPayment, GasReserve},
+    NATIVE_GAS_TOKEN,
+};
 use axelar_solana_gas_service_events::events::{
     GasServiceEvent, NativeGasPaidForContractCallEvent,
 };
@@ -51,6 +55,7 @@ async fn test_pay_native_for_contract_call() {
         refund_address,
         params.clone(),
         gas_amount,
+        None,
     )
     .unwrap();
 
@@ -107,7 +112,25 @@ async fn test_pay_native_for_contract_call() {
         config_pda_balance_after,
         config_pda_balance_before + gas_amount
     );
-    assert_eq!(payer_balance_after, payer_balance_before - gas_amount);
+    // the first payment of a token also creates its gas reserve
+    let gas_payment_rent = test_fixture
+        .get_rent(core::mem::size_of::<GasPayment>())
+        .await;
+    let gas_reserve_rent = test_fixture
+        .get_rent(core::mem::size_of::<GasReserve>())
+        .await;
+    assert_eq!(
+        payer_balance_after,
+        payer_balance_before - gas_amount - gas_payment_rent - gas_reserve_rent
+    );
+
+    // assert that the paid gas is held for refunds
+    assert_eq!(
+        test_fixture
+            .gas_reserve_refundable(&gas_utils.config_pda, &NATIVE_GAS_TOKEN)
+            .await,
+        gas_amount
+    );
 }
 
 #[tokio::test]
@@ -143,6 +166,7 @@ async fn fails_if_payer_not_signer() {
         refund_address,
         params.clone(),
         gas_amount,
+        None,
     )
     .unwrap();
     ix.accounts[0].is_signer = false;
@@ -158,3 +182,81 @@ async fn fails_if_payer_not_signer() {
         .await;
     assert!(res.is_err());
 }
+
+#[tokio::test]
+async fn test_pay_native_for_contract_call_records_gas_payment() {
+    // Setup
+    let pt = ProgramTest::default();
+    let mut test_fixture = TestFixture::new(pt).await;
+    let gas_utils = test_fixture.deploy_gas_service().await;
+    test_fixture.init_gas_config(&gas_utils).await.unwrap();
+
+    let payer = Keypair::new();
+    test_fixture
+        .fund_account(&payer.pubkey(), 1_000_000_000)
+        .await;
+
+    // Action
+    let gas_amount = 1_000_000;
+    let destination_chain = "ethereum".to_owned();
+    let destination_addr = "destination addr 123".to_owned();
+    let payload_hash = [42; 32];
+    let refund_address = Pubkey::new_unique();
+    let ix = axelar_solana_gas_service::instructions::pay_native_for_contract_call_instruction(
+        &axelar_solana_gas_service::ID,
+        &payer.pubkey(),
+        &gas_utils.config_pda,
+        destination_chain.clone(),
+        destination_addr.clone(),
+        payload_hash,
+        refund_address,
+        vec![],
+        gas_amount,
+        None,
+    )
+    .unwrap();
+
+    test_fixture
+        .send_tx_with_custom_signers(
+            &[ix.clone()],
+            &[&test_fixture.payer.insecure_clone(), &payer],
+        )
+        .await
+        .unwrap();
+
+    // Assert
+    let payment_key = axelar_solana_gas_service::gas_payment_key(
+        &destination_chain,
+        &destination_addr,
+        &payload_hash,
+    );
+    let (gas_payment_pda, _) = axelar_solana_gas_service::get_gas_payment_pda(
+        &axelar_solana_gas_service::ID,
+        &gas_utils.config_pda,
+        &axelar_solana_gas_service::NATIVE_GAS_TOKEN,
+        &payment_key,
+        &refund_address,
+    );
+    let gas_payment_state = test_fixture.gas_payment_state(gas_payment_pda).await;
+    assert_eq!(gas_payment_state.config_pda, gas_utils.config_pda);
+    assert_eq!(
+        gas_payment_state.token,
+        axelar_solana_gas_service::NATIVE_GAS_TOKEN
+    );
+    assert_eq!(gas_payment_state.payment_key, payment_key);
+    assert_eq!(gas_payment_state.refund_address, refund_address);
+    assert_eq!(gas_payment_state.paid, gas_amount);
+    assert_eq!(gas_payment_state.added, 0);
+    assert_eq!(gas_payment_state.refunded, 0);
+
+    // Paying again for the same contract call accumulates in the ledger of the first payment
+    test_fixture.refresh_blockhash().await;
+    test_fixture
+        .send_tx_with_custom_signers(&[ix], &[&test_fixture.payer.insecure_clone(), &payer])
+        .await
+        .unwrap();
+
+    let gas_payment_state = test_fixture.gas_payment_state(gas_payment_pda).await;
+    assert_eq!(gas_payment_state.paid, gas_amount * 2);
+    assert_eq!(gas_payment_state.refundable(), gas_amount * 2);
+}
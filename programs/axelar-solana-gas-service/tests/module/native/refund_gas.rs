@@ -1,5 +1,9 @@
+use axelar_message_primitives::MessageId;
 use axelar_solana_gas_service_events::events::{GasServiceEvent, NativeGasRefundedEvent};
-use axelar_solana_gateway_test_fixtures::{base::TestFixture, gas_service::get_gas_service_events};
+use axelar_solana_gateway_test_fixtures::{
+    base::{FindLog, TestFixture},
+    gas_service::get_gas_service_events,
+};
 use gateway_event_stack::ProgramInvocationState;
 use solana_program_test::{tokio, ProgramTest};
 use solana_sdk::{signature::Keypair, signer::Signer};
@@ -16,6 +20,10 @@ async fn test_refund_native() {
     test_fixture
         .fund_account(&gas_utils.config_pda, 1_000_000_000)
         .await;
+    // the refunder pays for the message ID PDA
+    test_fixture
+        .fund_account(&gas_utils.operator.pubkey(), 1_000_000_000)
+        .await;
     let refunded_user = Keypair::new();
     let refunder_balance_before = 0;
     let config_pda_balance_before = test_fixture
@@ -27,15 +35,23 @@ async fn test_refund_native() {
 
     // Action
     let gas_amount = 1_000_000;
-    let tx_hash = [42; 64];
-    let log_index = 1232;
+    let message_id = MessageId::new([42; 64], 0, 1232);
+    let gas_payment_account = test_fixture
+        .init_gas_payment(
+            &gas_utils,
+            &axelar_solana_gas_service::NATIVE_GAS_TOKEN,
+            [1; 32],
+            refunded_user.pubkey(),
+            gas_amount,
+        )
+        .await;
     let ix = axelar_solana_gas_service::instructions::refund_native_fees_instruction(
         &axelar_solana_gas_service::ID,
         &gas_utils.operator.pubkey(),
         &refunded_user.pubkey(),
         &gas_utils.config_pda,
-        tx_hash,
-        log_index,
+        &gas_payment_account,
+        message_id.to_string(),
         gas_amount,
     )
     .unwrap();
@@ -65,8 +81,7 @@ async fn test_refund_native() {
         emitted_event,
         &NativeGasRefundedEvent {
             config_pda: gas_utils.config_pda,
-            tx_hash,
-            log_index,
+            message_id,
             receiver: refunded_user.pubkey(),
             fees: gas_amount
         }
@@ -91,6 +106,10 @@ async fn test_refund_native() {
         config_pda_balance_before - gas_amount
     );
     assert_eq!(refunder_balance_after, refunder_balance_before + gas_amount);
+
+    // assert that the gas payment ledger records the refund
+    let gas_payment = test_fixture.gas_payment_state(gas_payment_account).await;
+    assert_eq!(gas_payment.refunded, gas_amount);
 }
 
 #[tokio::test]
@@ -107,15 +126,23 @@ async fn test_refund_native_fails_if_not_signed_by_authority() {
     // Action
     let refunded_user = Keypair::new();
     let gas_amount = 1_000_000;
-    let tx_hash = [42; 64];
-    let log_index = 1232;
+    let message_id = MessageId::new([42; 64], 0, 1232);
+    let gas_payment_account = test_fixture
+        .init_gas_payment(
+            &gas_utils,
+            &axelar_solana_gas_service::NATIVE_GAS_TOKEN,
+            [1; 32],
+            refunded_user.pubkey(),
+            gas_amount,
+        )
+        .await;
     let mut ix = axelar_solana_gas_service::instructions::refund_native_fees_instruction(
         &axelar_solana_gas_service::ID,
         &gas_utils.operator.pubkey(),
         &refunded_user.pubkey(),
         &gas_utils.config_pda,
-        tx_hash,
-        log_index,
+        &gas_payment_account,
+        message_id.to_string(),
         gas_amount,
     )
     .unwrap();
@@ -134,3 +161,213 @@ async fn test_refund_native_fails_if_not_signed_by_authority() {
         .await;
     assert!(res.is_err());
 }
+
+#[tokio::test]
+async fn test_refund_native_fails_if_exceeding_gas_payment() {
+    // Setup
+    let pt = ProgramTest::default();
+    let mut test_fixture = TestFixture::new(pt).await;
+    let gas_utils = test_fixture.deploy_gas_service().await;
+    test_fixture.init_gas_config(&gas_utils).await.unwrap();
+    test_fixture
+        .fund_account(&gas_utils.config_pda, 1_000_000_000)
+        .await;
+
+    // The pool holds enough funds, but only a part of them was paid for this payment
+    let refunded_user = Keypair::new();
+    let gas_amount = 1_000_000;
+    let gas_payment_account = test_fixture
+        .init_gas_payment(
+            &gas_utils,
+            &axelar_solana_gas_service::NATIVE_GAS_TOKEN,
+            [1; 32],
+            refunded_user.pubkey(),
+            gas_amount,
+        )
+        .await;
+
+    // Action
+    let ix = axelar_solana_gas_service::instructions::refund_native_fees_instruction(
+        &axelar_solana_gas_service::ID,
+        &gas_utils.operator.pubkey(),
+        &refunded_user.pubkey(),
+        &gas_utils.config_pda,
+        &gas_payment_account,
+        MessageId::new([42; 64], 0, 1232).to_string(),
+        gas_amount + 1,
+    )
+    .unwrap();
+
+    let res = test_fixture
+        .send_tx_with_custom_signers(
+            &[ix],
+            &[&test_fixture.payer.insecure_clone(), &gas_utils.operator],
+        )
+        .await
+        .unwrap_err();
+    assert!(res
+        .find_log("Refund exceeds the gas paid for this payment")
+        .is_some());
+}
+
+#[tokio::test]
+async fn test_refund_native_fails_if_not_to_the_refund_address() {
+    // Setup
+    let pt = ProgramTest::default();
+    let mut test_fixture = TestFixture::new(pt).await;
+    let gas_utils = test_fixture.deploy_gas_service().await;
+    test_fixture.init_gas_config(&gas_utils).await.unwrap();
+    test_fixture
+        .fund_account(&gas_utils.config_pda, 1_000_000_000)
+        .await;
+
+    let gas_amount = 1_000_000;
+    let gas_payment_account = test_fixture
+        .init_gas_payment(
+            &gas_utils,
+            &axelar_solana_gas_service::NATIVE_GAS_TOKEN,
+            [1; 32],
+            Keypair::new().pubkey(),
+            gas_amount,
+        )
+        .await;
+
+    // Action: the refunder picks another receiver than the refund address of the payment
+    let ix = axelar_solana_gas_service::instructions::refund_native_fees_instruction(
+        &axelar_solana_gas_service::ID,
+        &gas_utils.operator.pubkey(),
+        &Keypair::new().pubkey(),
+        &gas_utils.config_pda,
+        &gas_payment_account,
+        MessageId::new([42; 64], 0, 1232).to_string(),
+        gas_amount,
+    )
+    .unwrap();
+
+    let res = test_fixture
+        .send_tx_with_custom_signers(
+            &[ix],
+            &[&test_fixture.payer.insecure_clone(), &gas_utils.operator],
+        )
+        .await
+        .unwrap_err();
+    assert!(res
+        .find_log("Gas can only be refunded to the refund address of the payment")
+        .is_some());
+}
+
+#[tokio::test]
+async fn test_refund_native_fails_if_message_id_bound_to_another_payment() {
+    // Setup
+    let pt = ProgramTest::default();
+    let mut test_fixture = TestFixture::new(pt).await;
+    let gas_utils = test_fixture.deploy_gas_service().await;
+    test_fixture.init_gas_config(&gas_utils).await.unwrap();
+    test_fixture
+        .fund_account(&gas_utils.config_pda, 1_000_000_000)
+        .await;
+    test_fixture
+        .fund_account(&gas_utils.operator.pubkey(), 1_000_000_000)
+        .await;
+
+    let refunded_user = Keypair::new();
+    let gas_amount = 1_000_000;
+    let message_id = MessageId::new([42; 64], 0, 1232);
+    let mut refund_ixs = Vec::new();
+    for payment_key in [[1; 32], [2; 32]] {
+        let gas_payment_account = test_fixture
+            .init_gas_payment(
+                &gas_utils,
+                &axelar_solana_gas_service::NATIVE_GAS_TOKEN,
+                payment_key,
+                refunded_user.pubkey(),
+                gas_amount,
+            )
+            .await;
+        let ix = axelar_solana_gas_service::instructions::refund_native_fees_instruction(
+            &axelar_solana_gas_service::ID,
+            &gas_utils.operator.pubkey(),
+            &refunded_user.pubkey(),
+            &gas_utils.config_pda,
+            &gas_payment_account,
+            message_id.to_string(),
+            gas_amount,
+        )
+        .unwrap();
+        refund_ixs.push(ix);
+    }
+    let [first_payment_ix, second_payment_ix] = refund_ixs.try_into().unwrap();
+
+    test_fixture
+        .send_tx_with_custom_signers(
+            &[first_payment_ix],
+            &[&test_fixture.payer.insecure_clone(), &gas_utils.operator],
+        )
+        .await
+        .unwrap();
+
+    // Action: the same message ID is used to refund another payment
+    let res = test_fixture
+        .send_tx_with_custom_signers(
+            &[second_payment_ix],
+            &[&test_fixture.payer.insecure_clone(), &gas_utils.operator],
+        )
+        .await
+        .unwrap_err();
+    assert!(res
+        .find_log("Message ID is bound to another gas payment")
+        .is_some());
+}
+
+#[tokio::test]
+async fn test_refund_native_fails_after_refund_window() {
+    // Setup
+    let pt = ProgramTest::default();
+    let mut test_fixture = TestFixture::new(pt).await;
+    let gas_utils = test_fixture.deploy_gas_service().await;
+    test_fixture.init_gas_config(&gas_utils).await.unwrap();
+    test_fixture
+        .fund_account(&gas_utils.config_pda, 1_000_000_000)
+        .await;
+    test_fixture
+        .fund_account(&gas_utils.operator.pubkey(), 1_000_000_000)
+        .await;
+
+    let refunded_user = Keypair::new();
+    let gas_amount = 1_000_000;
+    let gas_payment_account = test_fixture
+        .init_gas_payment(
+            &gas_utils,
+            &axelar_solana_gas_service::NATIVE_GAS_TOKEN,
+            [1; 32],
+            refunded_user.pubkey(),
+            gas_amount,
+        )
+        .await;
+    test_fixture
+        .forward_time(axelar_solana_gas_service::REFUND_WINDOW + 1)
+        .await;
+
+    // Action
+    let ix = axelar_solana_gas_service::instructions::refund_native_fees_instruction(
+        &axelar_solana_gas_service::ID,
+        &gas_utils.operator.pubkey(),
+        &refunded_user.pubkey(),
+        &gas_utils.config_pda,
+        &gas_payment_account,
+        MessageId::new([42; 64], 0, 1232).to_string(),
+        gas_amount,
+    )
+    .unwrap();
+
+    let res = test_fixture
+        .send_tx_with_custom_signers(
+            &[ix],
+            &[&test_fixture.payer.insecure_clone(), &gas_utils.operator],
+        )
+        .await
+        .unwrap_err();
+    assert!(res
+        .find_log("Refund window of the gas payment is over")
+        .is_some());
+}
@@ -0,0 +1,306 @@
+use axelar_solana_gas_service::Roles;
+use axelar_solana_gas_service_events::events::{
+    GasServiceEvent, OperatorshipProposedEvent, OperatorshipTransferredEvent, RolesChangedEvent,
+};
+use axelar_solana_gateway_test_fixtures::{
+    base::{FindLog, TestFixture},
+    gas_service::get_gas_service_events,
+};
+use gateway_event_stack::ProgramInvocationState;
+use solana_program_test::{tokio, ProgramTest};
+use solana_sdk::{signature::Keypair, signer::Signer};
+
+#[tokio::test]
+async fn test_transfer_operatorship() {
+    // Setup
+    let pt = ProgramTest::default();
+    let mut test_fixture = TestFixture::new(pt).await;
+    let gas_utils = test_fixture.deploy_gas_service().await;
+    test_fixture.init_gas_config(&gas_utils).await.unwrap();
+    let new_operator = Keypair::new();
+    test_fixture
+        .fund_account(&gas_utils.operator.pubkey(), 1_000_000_000)
+        .await;
+    test_fixture
+        .fund_account(&new_operator.pubkey(), 1_000_000_000)
+        .await;
+
+    // Action: propose
+    let ix = axelar_solana_gas_service::instructions::propose_operatorship_instruction(
+        &axelar_solana_gas_service::ID,
+        &gas_utils.operator.pubkey(),
+        &gas_utils.config_pda,
+        &new_operator.pubkey(),
+        None,
+    )
+    .unwrap();
+    let res = test_fixture
+        .send_tx_with_custom_signers(
+            &[ix],
+            &[&test_fixture.payer.insecure_clone(), &gas_utils.operator],
+        )
+        .await
+        .unwrap();
+
+    // Assert
+    let emitted_events = get_gas_service_events(&res).into_iter().next().unwrap();
+    let ProgramInvocationState::Succeeded(vec_events) = emitted_events else {
+        panic!("unexpected event")
+    };
+    let [(_, GasServiceEvent::OperatorshipProposed(emitted_event))] = vec_events.as_slice() else {
+        panic!("unexpected event")
+    };
+    assert_eq!(
+        emitted_event,
+        &OperatorshipProposedEvent {
+            config_pda: gas_utils.config_pda,
+            operator: gas_utils.operator.pubkey(),
+            proposed_operator: new_operator.pubkey(),
+            expires_at: None,
+        }
+    );
+
+    // Action: accept
+    let ix = axelar_solana_gas_service::instructions::accept_operatorship_instruction(
+        &axelar_solana_gas_service::ID,
+        &new_operator.pubkey(),
+        &gas_utils.config_pda,
+        &gas_utils.operator.pubkey(),
+    )
+    .unwrap();
+    let res = test_fixture
+        .send_tx_with_custom_signers(
+            &[ix],
+            &[&test_fixture.payer.insecure_clone(), &new_operator],
+        )
+        .await
+        .unwrap();
+
+    // Assert
+    let emitted_events = get_gas_service_events(&res).into_iter().next().unwrap();
+    let ProgramInvocationState::Succeeded(vec_events) = emitted_events else {
+        panic!("unexpected event")
+    };
+    let [(_, GasServiceEvent::OperatorshipTransferred(emitted_event))] = vec_events.as_slice()
+    else {
+        panic!("unexpected event")
+    };
+    assert_eq!(
+        emitted_event,
+        &OperatorshipTransferredEvent {
+            config_pda: gas_utils.config_pda,
+            previous_operator: gas_utils.operator.pubkey(),
+            operator: new_operator.pubkey(),
+        }
+    );
+    let config = test_fixture
+        .gas_service_config_state(gas_utils.config_pda)
+        .await;
+    assert_eq!(config.operator, new_operator.pubkey());
+
+    // Assert: the previous operator can no longer propose a transfer
+    let ix = axelar_solana_gas_service::instructions::propose_operatorship_instruction(
+        &axelar_solana_gas_service::ID,
+        &gas_utils.operator.pubkey(),
+        &gas_utils.config_pda,
+        &Keypair::new().pubkey(),
+        None,
+    )
+    .unwrap();
+    let res = test_fixture
+        .send_tx_with_custom_signers(
+            &[ix],
+            &[&test_fixture.payer.insecure_clone(), &gas_utils.operator],
+        )
+        .await
+        .unwrap_err();
+    assert!(res
+        .find_log("User doesn't have the required roles")
+        .is_some());
+
+    // Assert: the previous operator can no longer configure the config
+    let ix = axelar_solana_gas_service::instructions::set_treasury_instruction(
+        &axelar_solana_gas_service::ID,
+        &test_fixture.payer.pubkey(),
+        &gas_utils.operator.pubkey(),
+        &gas_utils.config_pda,
+        &axelar_solana_gas_service::NATIVE_GAS_TOKEN,
+        Keypair::new().pubkey(),
+        0,
+        0,
+    )
+    .unwrap();
+    let res = test_fixture
+        .send_tx_with_custom_signers(
+            &[ix],
+            &[&test_fixture.payer.insecure_clone(), &gas_utils.operator],
+        )
+        .await
+        .unwrap_err();
+    assert!(res
+        .find_log("User doesn't have the required roles")
+        .is_some());
+
+    // Assert: the new operator can
+    let ix = axelar_solana_gas_service::instructions::set_treasury_instruction(
+        &axelar_solana_gas_service::ID,
+        &test_fixture.payer.pubkey(),
+        &new_operator.pubkey(),
+        &gas_utils.config_pda,
+        &axelar_solana_gas_service::NATIVE_GAS_TOKEN,
+        Keypair::new().pubkey(),
+        0,
+        0,
+    )
+    .unwrap();
+    test_fixture
+        .send_tx_with_custom_signers(
+            &[ix],
+            &[&test_fixture.payer.insecure_clone(), &new_operator],
+        )
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_collect_fees_requires_collector_role() {
+    // Setup
+    let pt = ProgramTest::default();
+    let mut test_fixture = TestFixture::new(pt).await;
+    let gas_utils = test_fixture.deploy_gas_service().await;
+    test_fixture.init_gas_config(&gas_utils).await.unwrap();
+    test_fixture
+        .fund_account(&gas_utils.operator.pubkey(), 1_000_000_000)
+        .await;
+    test_fixture
+        .fund_account(&gas_utils.config_pda, 1_000_000_000)
+        .await;
+    let collector = Keypair::new();
+    let receiver = Keypair::new();
+
+    let collect_ix = || {
+        axelar_solana_gas_service::instructions::collect_native_fees_instruction(
+            &axelar_solana_gas_service::ID,
+            &collector.pubkey(),
+            &gas_utils.config_pda,
+            &receiver.pubkey(),
+            1_000_000,
+        )
+        .unwrap()
+    };
+
+    // Action: collect before holding the role
+    let res = test_fixture
+        .send_tx_with_custom_signers(
+            &[collect_ix()],
+            &[&test_fixture.payer.insecure_clone(), &collector],
+        )
+        .await
+        .unwrap_err();
+    assert!(res.find_log("User roles account not found").is_some());
+
+    // Action: add the collector role
+    let ix = axelar_solana_gas_service::instructions::add_roles_instruction(
+        &axelar_solana_gas_service::ID,
+        &gas_utils.operator.pubkey(),
+        &gas_utils.config_pda,
+        &collector.pubkey(),
+        Roles::COLLECTOR,
+    )
+    .unwrap();
+    let res = test_fixture
+        .send_tx_with_custom_signers(
+            &[ix],
+            &[&test_fixture.payer.insecure_clone(), &gas_utils.operator],
+        )
+        .await
+        .unwrap();
+
+    // Assert
+    let emitted_events = get_gas_service_events(&res).into_iter().next().unwrap();
+    let ProgramInvocationState::Succeeded(vec_events) = emitted_events else {
+        panic!("unexpected event")
+    };
+    let [(_, GasServiceEvent::RolesAdded(emitted_event))] = vec_events.as_slice() else {
+        panic!("unexpected event")
+    };
+    assert_eq!(
+        emitted_event,
+        &RolesChangedEvent {
+            config_pda: gas_utils.config_pda,
+            user: collector.pubkey(),
+            roles: Roles::COLLECTOR.bits(),
+        }
+    );
+
+    test_fixture
+        .send_tx_with_custom_signers(
+            &[collect_ix()],
+            &[&test_fixture.payer.insecure_clone(), &collector],
+        )
+        .await
+        .unwrap();
+
+    // Action: remove the collector role
+    let ix = axelar_solana_gas_service::instructions::remove_roles_instruction(
+        &axelar_solana_gas_service::ID,
+        &gas_utils.operator.pubkey(),
+        &gas_utils.config_pda,
+        &collector.pubkey(),
+        Roles::COLLECTOR,
+    )
+    .unwrap();
+    test_fixture
+        .send_tx_with_custom_signers(
+            &[ix],
+            &[&test_fixture.payer.insecure_clone(), &gas_utils.operator],
+        )
+        .await
+        .unwrap();
+
+    // Assert
+    let res = test_fixture
+        .send_tx_with_custom_signers(
+            &[collect_ix()],
+            &[&test_fixture.payer.insecure_clone(), &collector],
+        )
+        .await
+        .unwrap_err();
+    assert!(res
+        .find_log("User doesn't have the required roles")
+        .is_some());
+}
+
+#[tokio::test]
+async fn test_add_roles_rejects_operator_role() {
+    // Setup
+    let pt = ProgramTest::default();
+    let mut test_fixture = TestFixture::new(pt).await;
+    let gas_utils = test_fixture.deploy_gas_service().await;
+    test_fixture.init_gas_config(&gas_utils).await.unwrap();
+    test_fixture
+        .fund_account(&gas_utils.operator.pubkey(), 1_000_000_000)
+        .await;
+
+    // Action
+    let ix = axelar_solana_gas_service::instructions::add_roles_instruction(
+        &axelar_solana_gas_service::ID,
+        &gas_utils.operator.pubkey(),
+        &gas_utils.config_pda,
+        &Keypair::new().pubkey(),
+        Roles::OPERATOR | Roles::REFUNDER,
+    )
+    .unwrap();
+    let res = test_fixture
+        .send_tx_with_custom_signers(
+            &[ix],
+            &[&test_fixture.payer.insecure_clone(), &gas_utils.operator],
+        )
+        .await
+        .unwrap_err();
+
+    // Assert
+    assert!(res
+        .find_log("Only the collector and refunder roles can be added or removed")
+        .is_some());
+}
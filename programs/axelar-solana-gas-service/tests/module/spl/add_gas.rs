@@ -1,3 +1,4 @@
+use axelar_message_primitives::MessageId;
 use axelar_solana_gateway_test_fixtures::{base::TestFixture, gas_service::get_gas_service_events};
 use gateway_event_stack::ProgramInvocationState;
 use solana_program_test::{tokio, ProgramTest};
@@ -24,6 +25,10 @@ async fn test_add_spl_gas(#[case] token_program_id: Pubkey) {
     let mint = test_fixture
         .init_new_mint(mint_authority.pubkey(), token_program_id, decimals)
         .await;
+    test_fixture
+        .allow_gas_token(&gas_utils, &mint, 0, 0)
+        .await
+        .unwrap();
     let payer_ata = test_fixture
         .init_associated_token_account(&mint, &payer.pubkey(), &token_program_id)
         .await;
@@ -49,8 +54,11 @@ async fn test_add_spl_gas(#[case] token_program_id: Pubkey) {
 
     // Prepare args
     let refund_address = Pubkey::new_unique();
-    let tx_hash = [42; 64];
-    let log_index = 123;
+    let message_id = MessageId::new([42; 64], 0, 123);
+
+    let gas_payment_account = test_fixture
+        .init_gas_payment(&gas_utils, &mint, [1; 32], refund_address, gas_amount)
+        .await;
 
     // Create the instruction for paying gas fees with SPL tokens
     let ix = axelar_solana_gas_service::instructions::add_spl_gas_instruction(
@@ -61,9 +69,10 @@ async fn test_add_spl_gas(#[case] token_program_id: Pubkey) {
         &config_pda_ata,
         &mint,
         &token_program_id,
+        &gas_payment_account,
+        &test_fixture.payer.pubkey(),
         &[],
-        tx_hash,
-        log_index,
+        message_id.to_string(),
         gas_amount,
         refund_address,
         decimals,
@@ -104,8 +113,7 @@ async fn test_add_spl_gas(#[case] token_program_id: Pubkey) {
             config_pda_ata,
             mint,
             token_program_id,
-            tx_hash,
-            log_index,
+            message_id,
             refund_address,
             gas_fee_amount: gas_amount
         }
@@ -118,4 +126,8 @@ async fn test_add_spl_gas(#[case] token_program_id: Pubkey) {
     // Assert that tokens got transferred
     assert_eq!(payer_token_after, payer_token_before - gas_amount);
     assert_eq!(config_pda_token_after, config_pda_token_before + gas_amount);
+
+    // Assert that the added gas got recorded in the payment ledger
+    let gas_payment = test_fixture.gas_payment_state(gas_payment_account).await;
+    assert_eq!(gas_payment.added, gas_amount);
 }
@@ -0,0 +1,284 @@
+use axelar_solana_gas_service::state::GasTokenBalance;
+use axelar_solana_gateway_test_fixtures::base::{FindLog, TestFixture};
+use axelar_solana_gateway_test_fixtures::gas_service::GasServiceUtils;
+use borsh::BorshDeserialize;
+use solana_program_test::{tokio, ProgramTest};
+use solana_sdk::{instruction::Instruction, pubkey::Pubkey, signature::Keypair, signer::Signer};
+
+use crate::gas_oracle::setup_gas_oracle;
+
+const DECIMALS: u8 = 6;
+const MIN_PAYMENT: u64 = 1_000;
+
+struct GasTokenSetup {
+    test_fixture: TestFixture,
+    gas_utils: GasServiceUtils,
+    payer: Keypair,
+    payer_ata: Pubkey,
+    mint: Pubkey,
+    config_pda_ata: Pubkey,
+}
+
+async fn setup() -> GasTokenSetup {
+    let pt = ProgramTest::default();
+    let mut test_fixture = TestFixture::new(pt).await;
+    let gas_utils = test_fixture.deploy_gas_service().await;
+    test_fixture.init_gas_config(&gas_utils).await.unwrap();
+
+    let payer = Keypair::new();
+    let mint_authority = Keypair::new();
+    let mint = test_fixture
+        .init_new_mint(mint_authority.pubkey(), spl_token_2022::id(), DECIMALS)
+        .await;
+    let payer_ata = test_fixture
+        .init_associated_token_account(&mint, &payer.pubkey(), &spl_token_2022::id())
+        .await;
+    test_fixture
+        .mint_tokens_to(
+            &mint,
+            &payer_ata,
+            &mint_authority,
+            1_000_000,
+            &spl_token_2022::id(),
+        )
+        .await;
+    let config_pda_ata = test_fixture
+        .init_associated_token_account(&mint, &gas_utils.config_pda, &spl_token_2022::id())
+        .await;
+
+    GasTokenSetup {
+        test_fixture,
+        gas_utils,
+        payer,
+        payer_ata,
+        mint,
+        config_pda_ata,
+    }
+}
+
+fn pay_ix(
+    setup: &GasTokenSetup,
+    gas_fee_amount: u64,
+    checked_payload_len: Option<u64>,
+) -> Instruction {
+    axelar_solana_gas_service::instructions::pay_spl_for_contract_call_instruction(
+        &axelar_solana_gas_service::ID,
+        &setup.payer.pubkey(),
+        &setup.payer_ata,
+        &setup.gas_utils.config_pda,
+        &setup.config_pda_ata,
+        &setup.mint,
+        &spl_token_2022::id(),
+        "ethereum".to_owned(),
+        "0x destination addr 123".to_owned(),
+        [42; 32],
+        Pubkey::new_unique(),
+        vec![],
+        gas_fee_amount,
+        &[],
+        DECIMALS,
+        &setup.test_fixture.payer.pubkey(),
+        checked_payload_len,
+    )
+    .unwrap()
+}
+
+#[tokio::test]
+async fn test_pay_spl_rejects_token_not_allowed() {
+    // Setup
+    let mut setup = setup().await;
+
+    // Action
+    let ix = pay_ix(&setup, MIN_PAYMENT, None);
+    let res = setup
+        .test_fixture
+        .send_tx_with_custom_signers(
+            &[ix],
+            &[&setup.test_fixture.payer.insecure_clone(), &setup.payer],
+        )
+        .await
+        .unwrap_err();
+
+    // Assert
+    assert!(res
+        .find_log("Token isn't accepted to pay for gas")
+        .is_some());
+}
+
+#[tokio::test]
+async fn test_pay_spl_rejects_payment_below_minimum() {
+    // Setup
+    let mut setup = setup().await;
+    setup
+        .test_fixture
+        .allow_gas_token(&setup.gas_utils, &setup.mint, MIN_PAYMENT, 0)
+        .await
+        .unwrap();
+
+    // Action: pay less than the minimum
+    let ix = pay_ix(&setup, MIN_PAYMENT - 1, None);
+    let res = setup
+        .test_fixture
+        .send_tx_with_custom_signers(
+            &[ix],
+            &[&setup.test_fixture.payer.insecure_clone(), &setup.payer],
+        )
+        .await
+        .unwrap_err();
+    assert!(res
+        .find_log("Gas fee amount is below the minimum payment of 1000 for this token")
+        .is_some());
+
+    // Action: pay the minimum
+    let ix = pay_ix(&setup, MIN_PAYMENT, None);
+    setup
+        .test_fixture
+        .send_tx_with_custom_signers(
+            &[ix],
+            &[&setup.test_fixture.payer.insecure_clone(), &setup.payer],
+        )
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_pay_spl_rejects_underpayment() {
+    // Setup: a 100 bytes payload is quoted 40 lamports, 200 tokens at 5 tokens per lamport
+    let mut setup = setup().await;
+    setup_gas_oracle(&mut setup.test_fixture, &setup.gas_utils).await;
+    setup
+        .test_fixture
+        .allow_gas_token(&setup.gas_utils, &setup.mint, 0, 0)
+        .await
+        .unwrap();
+
+    // Action: the token has no rate to convert the quote
+    let ix = pay_ix(&setup, 201, Some(100));
+    let res = setup
+        .test_fixture
+        .send_tx_with_custom_signers(
+            &[ix],
+            &[&setup.test_fixture.payer.insecure_clone(), &setup.payer],
+        )
+        .await
+        .unwrap_err();
+    assert!(res
+        .find_log("The gas oracle quote can't be converted to this token")
+        .is_some());
+
+    setup
+        .test_fixture
+        .allow_gas_token(&setup.gas_utils, &setup.mint, 0, 5_000_000_000_000)
+        .await
+        .unwrap();
+
+    // Action: pay less than the quote
+    let ix = pay_ix(&setup, 199, Some(100));
+    let res = setup
+        .test_fixture
+        .send_tx_with_custom_signers(
+            &[ix],
+            &[&setup.test_fixture.payer.insecure_clone(), &setup.payer],
+        )
+        .await
+        .unwrap_err();
+    assert!(res
+        .find_log("Gas fee amount is below the quote of 200")
+        .is_some());
+
+    // Action: pay the quote
+    let ix = pay_ix(&setup, 200, Some(100));
+    setup
+        .test_fixture
+        .send_tx_with_custom_signers(
+            &[ix],
+            &[&setup.test_fixture.payer.insecure_clone(), &setup.payer],
+        )
+        .await
+        .unwrap();
+}
+
+#[tokio::test]
+async fn test_pay_spl_fails_after_token_is_disallowed() {
+    // Setup
+    let mut setup = setup().await;
+    setup
+        .test_fixture
+        .allow_gas_token(&setup.gas_utils, &setup.mint, 0, 0)
+        .await
+        .unwrap();
+
+    // Action
+    let disallow_ix = axelar_solana_gas_service::instructions::disallow_gas_token_instruction(
+        &axelar_solana_gas_service::ID,
+        &setup.gas_utils.operator.pubkey(),
+        &setup.gas_utils.config_pda,
+        &setup.mint,
+    )
+    .unwrap();
+    setup
+        .test_fixture
+        .send_tx_with_custom_signers(
+            &[disallow_ix],
+            &[
+                &setup.test_fixture.payer.insecure_clone(),
+                &setup.gas_utils.operator,
+            ],
+        )
+        .await
+        .unwrap();
+
+    // Assert
+    let ix = pay_ix(&setup, MIN_PAYMENT, None);
+    let res = setup
+        .test_fixture
+        .send_tx_with_custom_signers(
+            &[ix],
+            &[&setup.test_fixture.payer.insecure_clone(), &setup.payer],
+        )
+        .await
+        .unwrap_err();
+    assert!(res
+        .find_log("Token isn't accepted to pay for gas")
+        .is_some());
+}
+
+#[tokio::test]
+async fn test_gas_token_balances() {
+    // Setup
+    let mut setup = setup().await;
+    setup
+        .test_fixture
+        .allow_gas_token(&setup.gas_utils, &setup.mint, 0, 0)
+        .await
+        .unwrap();
+    let ix = pay_ix(&setup, 5_000, None);
+    setup
+        .test_fixture
+        .send_tx_with_custom_signers(
+            &[ix],
+            &[&setup.test_fixture.payer.insecure_clone(), &setup.payer],
+        )
+        .await
+        .unwrap();
+
+    // Action
+    let ix = axelar_solana_gas_service::instructions::gas_token_balances_instruction(
+        &axelar_solana_gas_service::ID,
+        &setup.gas_utils.config_pda,
+        &[(setup.mint, setup.config_pda_ata)],
+    )
+    .unwrap();
+    let res = setup.test_fixture.send_tx(&[ix]).await.unwrap();
+
+    // Assert
+    let return_data = res.metadata.unwrap().return_data.unwrap();
+    let balances = Vec::<GasTokenBalance>::try_from_slice(&return_data.data).unwrap();
+    assert_eq!(
+        balances,
+        vec![GasTokenBalance {
+            mint: setup.mint,
+            amount: 5_000,
+        }]
+    );
+}
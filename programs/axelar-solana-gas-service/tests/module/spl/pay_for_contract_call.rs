@@ -26,6 +26,10 @@ async fn test_pay_spl_for_contract_call(#[case] token_program_id: Pubkey) {
     let mint = test_fixture
         .init_new_mint(mint_authority.pubkey(), token_program_id, decimals)
         .await;
+    test_fixture
+        .allow_gas_token(&gas_utils, &mint, 0, 0)
+        .await
+        .unwrap();
     let payer_ata = test_fixture
         .init_associated_token_account(&mint, &payer.pubkey(), &token_program_id)
         .await;
@@ -73,6 +77,8 @@ async fn test_pay_spl_for_contract_call(#[case] token_program_id: Pubkey) {
         gas_amount,
         &[],
         decimals,
+        &test_fixture.payer.pubkey(),
+        None,
     )
     .unwrap();
 
@@ -127,4 +133,21 @@ async fn test_pay_spl_for_contract_call(#[case] token_program_id: Pubkey) {
     // Assert that tokens got transferred
     assert_eq!(payer_token_after, payer_token_before - gas_amount);
     assert_eq!(config_pda_token_after, config_pda_token_before + gas_amount);
+
+    // Assert that the payment got recorded in its ledger
+    let (gas_payment_pda, _) = axelar_solana_gas_service::get_gas_payment_pda(
+        &axelar_solana_gas_service::ID,
+        &gas_utils.config_pda,
+        &mint,
+        &axelar_solana_gas_service::gas_payment_key(
+            &emitted_event.destination_chain,
+            &emitted_event.destination_address,
+            &payload_hash,
+        ),
+        &refund_address,
+    );
+    let gas_payment = test_fixture.gas_payment_state(gas_payment_pda).await;
+    assert_eq!(gas_payment.token, mint);
+    assert_eq!(gas_payment.refund_address, refund_address);
+    assert_eq!(gas_payment.paid, gas_amount);
 }
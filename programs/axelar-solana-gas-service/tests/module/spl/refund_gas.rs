@@ -1,4 +1,8 @@
-use axelar_solana_gateway_test_fixtures::{base::TestFixture, gas_service::get_gas_service_events};
+use axelar_message_primitives::MessageId;
+use axelar_solana_gateway_test_fixtures::{
+    base::{FindLog, TestFixture},
+    gas_service::get_gas_service_events,
+};
 use gateway_event_stack::ProgramInvocationState;
 use solana_program_test::{tokio, ProgramTest};
 use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer};
@@ -16,6 +20,10 @@ async fn test_refund_spl_fees(#[case] token_program_id: Pubkey) {
     let mut test_fixture = TestFixture::new(pt).await;
     let gas_utils = test_fixture.deploy_gas_service().await;
     test_fixture.init_gas_config(&gas_utils).await.unwrap();
+    // the refunder pays for the message ID PDA
+    test_fixture
+        .fund_account(&gas_utils.operator.pubkey(), 1_000_000_000)
+        .await;
 
     // Setup a mint and mint some tokens to the payer
     let receiver = Keypair::new();
@@ -47,9 +55,12 @@ async fn test_refund_spl_fees(#[case] token_program_id: Pubkey) {
     let payer_token_before = test_fixture.get_token_account(&receiver_ata).await.amount;
     let config_pda_token_before = test_fixture.get_token_account(&config_pda_ata).await.amount;
 
+    let gas_payment_account = test_fixture
+        .init_gas_payment(&gas_utils, &mint, [1; 32], receiver.pubkey(), gas_amount)
+        .await;
+
     // Create the instruction for paying gas fees with SPL tokens
-    let tx_hash = [132; 64];
-    let log_index = 42;
+    let message_id = MessageId::new([132; 64], 0, 42);
     let ix = axelar_solana_gas_service::instructions::refund_spl_fees_instruction(
         &axelar_solana_gas_service::ID,
         &gas_utils.operator.pubkey(),
@@ -58,8 +69,8 @@ async fn test_refund_spl_fees(#[case] token_program_id: Pubkey) {
         &gas_utils.config_pda,
         &config_pda_ata,
         &receiver_ata,
-        tx_hash,
-        log_index,
+        &gas_payment_account,
+        message_id.to_string(),
         gas_amount,
         decimals,
     )
@@ -98,9 +109,8 @@ async fn test_refund_spl_fees(#[case] token_program_id: Pubkey) {
             config_pda_ata,
             mint,
             token_program_id,
-            tx_hash,
+            message_id,
             config_pda: gas_utils.config_pda,
-            log_index,
             receiver: receiver_ata,
             fees: gas_amount,
         }
@@ -113,4 +123,72 @@ async fn test_refund_spl_fees(#[case] token_program_id: Pubkey) {
     // Assert that tokens got transferred
     assert_eq!(payer_token_after, payer_token_before + gas_amount);
     assert_eq!(config_pda_token_after, config_pda_token_before - gas_amount);
+
+    // Assert that the refund got recorded in the payment ledger
+    let gas_payment = test_fixture.gas_payment_state(gas_payment_account).await;
+    assert_eq!(gas_payment.refunded, gas_amount);
+}
+
+#[tokio::test]
+async fn test_refund_spl_fees_fails_if_not_to_the_refund_address() {
+    // Setup
+    let pt = ProgramTest::default();
+    let mut test_fixture = TestFixture::new(pt).await;
+    let gas_utils = test_fixture.deploy_gas_service().await;
+    test_fixture.init_gas_config(&gas_utils).await.unwrap();
+
+    let token_program_id = spl_token_2022::id();
+    let mint_authority = Keypair::new();
+    let decimals = 10;
+    let mint = test_fixture
+        .init_new_mint(mint_authority.pubkey(), token_program_id, decimals)
+        .await;
+    let gas_amount = 1_000_000;
+    let config_pda_ata = test_fixture
+        .init_associated_token_account(&mint, &gas_utils.config_pda, &token_program_id)
+        .await;
+    test_fixture
+        .mint_tokens_to(
+            &mint,
+            &config_pda_ata,
+            &mint_authority,
+            gas_amount,
+            &token_program_id,
+        )
+        .await;
+
+    let gas_payment_account = test_fixture
+        .init_gas_payment(&gas_utils, &mint, [1; 32], Pubkey::new_unique(), gas_amount)
+        .await;
+
+    // Action: the refunder picks a token account of another owner than the refund address
+    let other_receiver = Keypair::new();
+    let other_receiver_ata = test_fixture
+        .init_associated_token_account(&mint, &other_receiver.pubkey(), &token_program_id)
+        .await;
+    let ix = axelar_solana_gas_service::instructions::refund_spl_fees_instruction(
+        &axelar_solana_gas_service::ID,
+        &gas_utils.operator.pubkey(),
+        &token_program_id,
+        &mint,
+        &gas_utils.config_pda,
+        &config_pda_ata,
+        &other_receiver_ata,
+        &gas_payment_account,
+        MessageId::new([132; 64], 0, 42).to_string(),
+        gas_amount,
+        decimals,
+    )
+    .unwrap();
+
+    let res = test_fixture
+        .send_tx_with_custom_signers(
+            &[ix],
+            &[&test_fixture.payer.insecure_clone(), &gas_utils.operator],
+        )
+        .await
+        .unwrap_err();
+    assert!(res
+        .find_log("Gas can only be refunded to the refund address of the payment")
+        .is_some());
 }
@@ -0,0 +1,529 @@
+use axelar_solana_gas_service::instructions::SweptToken;
+use axelar_solana_gas_service_events::events::{FeesSweptEvent, GasServiceEvent};
+use axelar_solana_gateway_test_fixtures::base::{FindLog, TestFixture};
+use axelar_solana_gateway_test_fixtures::gas_service::get_gas_service_events;
+use gateway_event_stack::ProgramInvocationState;
+use solana_program_test::{tokio, ProgramTest};
+use solana_sdk::{pubkey::Pubkey, signature::Keypair, signer::Signer};
+
+#[tokio::test]
+async fn test_sweep_native_fees_above_keep_minimum() {
+    // Setup
+    let pt = ProgramTest::default();
+    let mut test_fixture = TestFixture::new(pt).await;
+    let gas_utils = test_fixture.deploy_gas_service().await;
+    test_fixture.init_gas_config(&gas_utils).await.unwrap();
+    let destination = Pubkey::new_unique();
+    let keep_minimum = 100_000_000;
+    let ix = axelar_solana_gas_service::instructions::set_treasury_instruction(
+        &axelar_solana_gas_service::ID,
+        &test_fixture.payer.pubkey(),
+        &gas_utils.operator.pubkey(),
+        &gas_utils.config_pda,
+        &axelar_solana_gas_service::NATIVE_GAS_TOKEN,
+        destination,
+        keep_minimum,
+        0,
+    )
+    .unwrap();
+    test_fixture
+        .send_tx_with_custom_signers(
+            &[ix],
+            &[&test_fixture.payer.insecure_clone(), &gas_utils.operator],
+        )
+        .await
+        .unwrap();
+    let config_pda_balance_before = test_fixture
+        .try_get_account_no_checks(&gas_utils.config_pda)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    let fees = 1_000_000_000;
+    test_fixture.fund_account(&gas_utils.config_pda, fees).await;
+
+    // Action: anyone can sweep the fees
+    let ix = axelar_solana_gas_service::instructions::sweep_fees_instruction(
+        &axelar_solana_gas_service::ID,
+        &gas_utils.config_pda,
+        &[SweptToken::Native { destination }],
+    )
+    .unwrap();
+    let res = test_fixture.send_tx(&[ix]).await.unwrap();
+
+    // Assert: the config keeps its rent-exempt balance and the keep-minimum
+    let swept = fees - keep_minimum;
+    let emitted_events = get_gas_service_events(&res).into_iter().next().unwrap();
+    let ProgramInvocationState::Succeeded(vec_events) = emitted_events else {
+        panic!("unexpected event")
+    };
+    let [(_, GasServiceEvent::FeesSwept(emitted_event))] = vec_events.as_slice() else {
+        panic!("unexpected event")
+    };
+    assert_eq!(
+        emitted_event,
+        &FeesSweptEvent {
+            config_pda: gas_utils.config_pda,
+            token: axelar_solana_gas_service::NATIVE_GAS_TOKEN,
+            destination,
+            amount: swept,
+        }
+    );
+
+    let destination_balance = test_fixture
+        .try_get_account_no_checks(&destination)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    let config_pda_balance_after = test_fixture
+        .try_get_account_no_checks(&gas_utils.config_pda)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    assert_eq!(destination_balance, swept);
+    assert_eq!(
+        config_pda_balance_after,
+        config_pda_balance_before + keep_minimum
+    );
+}
+
+#[tokio::test]
+async fn test_sweep_spl_fees_above_keep_minimum() {
+    // Setup
+    let pt = ProgramTest::default();
+    let mut test_fixture = TestFixture::new(pt).await;
+    let gas_utils = test_fixture.deploy_gas_service().await;
+    test_fixture.init_gas_config(&gas_utils).await.unwrap();
+    let token_program_id = spl_token_2022::id();
+    let mint_authority = Keypair::new();
+    let mint = test_fixture
+        .init_new_mint(mint_authority.pubkey(), token_program_id, 6)
+        .await;
+    let config_pda_ata = test_fixture
+        .init_associated_token_account(&mint, &gas_utils.config_pda, &token_program_id)
+        .await;
+    let treasury_owner = Pubkey::new_unique();
+    let destination = test_fixture
+        .init_associated_token_account(&mint, &treasury_owner, &token_program_id)
+        .await;
+    let fees = 1_000_000;
+    test_fixture
+        .mint_tokens_to(
+            &mint,
+            &config_pda_ata,
+            &mint_authority,
+            fees,
+            &token_program_id,
+        )
+        .await;
+    let keep_minimum = 250_000;
+    let ix = axelar_solana_gas_service::instructions::set_treasury_instruction(
+        &axelar_solana_gas_service::ID,
+        &test_fixture.payer.pubkey(),
+        &gas_utils.operator.pubkey(),
+        &gas_utils.config_pda,
+        &mint,
+        destination,
+        keep_minimum,
+        0,
+    )
+    .unwrap();
+    test_fixture
+        .send_tx_with_custom_signers(
+            &[ix],
+            &[&test_fixture.payer.insecure_clone(), &gas_utils.operator],
+        )
+        .await
+        .unwrap();
+
+    // Action
+    let ix = axelar_solana_gas_service::instructions::sweep_fees_instruction(
+        &axelar_solana_gas_service::ID,
+        &gas_utils.config_pda,
+        &[SweptToken::Spl {
+            mint,
+            token_program_id,
+            config_pda_ata,
+            destination,
+        }],
+    )
+    .unwrap();
+    let res = test_fixture.send_tx(&[ix]).await.unwrap();
+
+    // Assert
+    let swept = fees - keep_minimum;
+    let emitted_events = get_gas_service_events(&res).into_iter().next().unwrap();
+    let ProgramInvocationState::Succeeded(vec_events) = emitted_events else {
+        panic!("unexpected event")
+    };
+    let [(_, GasServiceEvent::FeesSwept(emitted_event))] = vec_events.as_slice() else {
+        panic!("unexpected event")
+    };
+    assert_eq!(
+        emitted_event,
+        &FeesSweptEvent {
+            config_pda: gas_utils.config_pda,
+            token: mint,
+            destination,
+            amount: swept,
+        }
+    );
+
+    let destination_account = test_fixture.get_token_account(&destination).await;
+    let config_pda_account = test_fixture.get_token_account(&config_pda_ata).await;
+    assert_eq!(destination_account.amount, swept);
+    assert_eq!(config_pda_account.amount, keep_minimum);
+}
+
+#[tokio::test]
+async fn test_sweep_keeps_refundable_gas() {
+    // Setup
+    let pt = ProgramTest::default();
+    let mut test_fixture = TestFixture::new(pt).await;
+    let gas_utils = test_fixture.deploy_gas_service().await;
+    test_fixture.init_gas_config(&gas_utils).await.unwrap();
+    let destination = Pubkey::new_unique();
+    let ix = axelar_solana_gas_service::instructions::set_treasury_instruction(
+        &axelar_solana_gas_service::ID,
+        &test_fixture.payer.pubkey(),
+        &gas_utils.operator.pubkey(),
+        &gas_utils.config_pda,
+        &axelar_solana_gas_service::NATIVE_GAS_TOKEN,
+        destination,
+        0,
+        0,
+    )
+    .unwrap();
+    test_fixture
+        .send_tx_with_custom_signers(
+            &[ix],
+            &[&test_fixture.payer.insecure_clone(), &gas_utils.operator],
+        )
+        .await
+        .unwrap();
+    let config_pda_balance_before = test_fixture
+        .try_get_account_no_checks(&gas_utils.config_pda)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+
+    // a payment that can still be refunded
+    let payer = Keypair::new();
+    test_fixture
+        .fund_account(&payer.pubkey(), 1_000_000_000)
+        .await;
+    let gas_amount = 500_000_000;
+    let ix = axelar_solana_gas_service::instructions::pay_native_for_contract_call_instruction(
+        &axelar_solana_gas_service::ID,
+        &payer.pubkey(),
+        &gas_utils.config_pda,
+        "ethereum".to_owned(),
+        "destination addr 123".to_owned(),
+        [42; 32],
+        Pubkey::new_unique(),
+        vec![],
+        gas_amount,
+        None,
+    )
+    .unwrap();
+    test_fixture
+        .send_tx_with_custom_signers(&[ix], &[&test_fixture.payer.insecure_clone(), &payer])
+        .await
+        .unwrap();
+    let fees = 1_000_000_000;
+    test_fixture.fund_account(&gas_utils.config_pda, fees).await;
+
+    // Action
+    let ix = axelar_solana_gas_service::instructions::sweep_fees_instruction(
+        &axelar_solana_gas_service::ID,
+        &gas_utils.config_pda,
+        &[SweptToken::Native { destination }],
+    )
+    .unwrap();
+    test_fixture.send_tx(&[ix]).await.unwrap();
+
+    // Assert: only the fees are swept, the paid gas stays for refunds
+    let destination_balance = test_fixture
+        .try_get_account_no_checks(&destination)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    let config_pda_balance_after = test_fixture
+        .try_get_account_no_checks(&gas_utils.config_pda)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    assert_eq!(destination_balance, fees);
+    assert_eq!(
+        config_pda_balance_after,
+        config_pda_balance_before + gas_amount
+    );
+}
+
+#[tokio::test]
+async fn test_sweep_released_gas() {
+    // Setup
+    let pt = ProgramTest::default();
+    let mut test_fixture = TestFixture::new(pt).await;
+    let gas_utils = test_fixture.deploy_gas_service().await;
+    test_fixture.init_gas_config(&gas_utils).await.unwrap();
+    let destination = Pubkey::new_unique();
+    let ix = axelar_solana_gas_service::instructions::set_treasury_instruction(
+        &axelar_solana_gas_service::ID,
+        &test_fixture.payer.pubkey(),
+        &gas_utils.operator.pubkey(),
+        &gas_utils.config_pda,
+        &axelar_solana_gas_service::NATIVE_GAS_TOKEN,
+        destination,
+        0,
+        0,
+    )
+    .unwrap();
+    test_fixture
+        .send_tx_with_custom_signers(
+            &[ix],
+            &[&test_fixture.payer.insecure_clone(), &gas_utils.operator],
+        )
+        .await
+        .unwrap();
+
+    // gas paid through the ledger of the payment
+    let payer = Keypair::new();
+    test_fixture
+        .fund_account(&payer.pubkey(), 1_000_000_000)
+        .await;
+    let gas_amount = 500_000_000;
+    let refund_address = Pubkey::new_unique();
+    let payload_hash = [42; 32];
+    let ix = axelar_solana_gas_service::instructions::pay_native_for_contract_call_instruction(
+        &axelar_solana_gas_service::ID,
+        &payer.pubkey(),
+        &gas_utils.config_pda,
+        "ethereum".to_owned(),
+        "destination addr 123".to_owned(),
+        payload_hash,
+        refund_address,
+        vec![],
+        gas_amount,
+        None,
+    )
+    .unwrap();
+    test_fixture
+        .send_tx_with_custom_signers(&[ix], &[&test_fixture.payer.insecure_clone(), &payer])
+        .await
+        .unwrap();
+    let (gas_payment_pda, _) = axelar_solana_gas_service::get_gas_payment_pda(
+        &axelar_solana_gas_service::ID,
+        &gas_utils.config_pda,
+        &axelar_solana_gas_service::NATIVE_GAS_TOKEN,
+        &axelar_solana_gas_service::gas_payment_key(
+            "ethereum",
+            "destination addr 123",
+            &payload_hash,
+        ),
+        &refund_address,
+    );
+    let release_ix = axelar_solana_gas_service::instructions::release_gas_instruction(
+        &axelar_solana_gas_service::ID,
+        &gas_utils.config_pda,
+        &gas_payment_pda,
+        &axelar_solana_gas_service::NATIVE_GAS_TOKEN,
+    )
+    .unwrap();
+    let sweep_ix = axelar_solana_gas_service::instructions::sweep_fees_instruction(
+        &axelar_solana_gas_service::ID,
+        &gas_utils.config_pda,
+        &[SweptToken::Native { destination }],
+    )
+    .unwrap();
+
+    // Action: the gas can't be released while it can still be refunded
+    let res = test_fixture
+        .send_tx(&[release_ix.clone()])
+        .await
+        .unwrap_err();
+
+    // Assert
+    assert!(res.find_log("Gas payment can still be refunded").is_some());
+
+    // Action: anyone can release it once the refund window is over, and sweep it
+    test_fixture
+        .forward_time(axelar_solana_gas_service::REFUND_WINDOW + 1)
+        .await;
+    test_fixture.send_tx(&[release_ix, sweep_ix]).await.unwrap();
+
+    // Assert
+    let destination_balance = test_fixture
+        .try_get_account_no_checks(&destination)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    assert_eq!(destination_balance, gas_amount);
+    assert_eq!(
+        test_fixture
+            .gas_reserve_refundable(
+                &gas_utils.config_pda,
+                &axelar_solana_gas_service::NATIVE_GAS_TOKEN
+            )
+            .await,
+        0
+    );
+    let gas_payment = test_fixture.gas_payment_state(gas_payment_pda).await;
+    assert_eq!(gas_payment.released, gas_amount);
+    assert_eq!(gas_payment.refundable(), 0);
+}
+
+#[tokio::test]
+async fn test_sweep_is_scheduled() {
+    // Setup
+    let pt = ProgramTest::default();
+    let mut test_fixture = TestFixture::new(pt).await;
+    let gas_utils = test_fixture.deploy_gas_service().await;
+    test_fixture.init_gas_config(&gas_utils).await.unwrap();
+    let destination = Pubkey::new_unique();
+    let sweep_interval = 3600;
+    let ix = axelar_solana_gas_service::instructions::set_treasury_instruction(
+        &axelar_solana_gas_service::ID,
+        &test_fixture.payer.pubkey(),
+        &gas_utils.operator.pubkey(),
+        &gas_utils.config_pda,
+        &axelar_solana_gas_service::NATIVE_GAS_TOKEN,
+        destination,
+        0,
+        sweep_interval,
+    )
+    .unwrap();
+    test_fixture
+        .send_tx_with_custom_signers(
+            &[ix],
+            &[&test_fixture.payer.insecure_clone(), &gas_utils.operator],
+        )
+        .await
+        .unwrap();
+    let fees = 1_000_000_000;
+    test_fixture.fund_account(&gas_utils.config_pda, fees).await;
+    let ix = axelar_solana_gas_service::instructions::sweep_fees_instruction(
+        &axelar_solana_gas_service::ID,
+        &gas_utils.config_pda,
+        &[SweptToken::Native { destination }],
+    )
+    .unwrap();
+    test_fixture.send_tx(&[ix.clone()]).await.unwrap();
+    test_fixture.fund_account(&gas_utils.config_pda, fees).await;
+
+    // Action: the next sweep is skipped until the interval has passed
+    let res = test_fixture.send_tx(&[ix.clone()]).await.unwrap();
+
+    // Assert
+    assert!(res.find_log("is not due yet").is_some());
+    assert!(get_gas_service_events(&res)
+        .into_iter()
+        .all(|state| matches!(
+            state,
+            ProgramInvocationState::Succeeded(events) if events.is_empty()
+        )));
+    let destination_balance = test_fixture
+        .try_get_account_no_checks(&destination)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    assert_eq!(destination_balance, fees);
+
+    // Action: the sweep is due again
+    test_fixture.forward_time(sweep_interval).await;
+    test_fixture.send_tx(&[ix]).await.unwrap();
+
+    // Assert
+    let destination_balance = test_fixture
+        .try_get_account_no_checks(&destination)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+    assert_eq!(destination_balance, 2 * fees);
+}
+
+#[tokio::test]
+async fn test_sweep_fails_for_wrong_destination() {
+    // Setup
+    let pt = ProgramTest::default();
+    let mut test_fixture = TestFixture::new(pt).await;
+    let gas_utils = test_fixture.deploy_gas_service().await;
+    test_fixture.init_gas_config(&gas_utils).await.unwrap();
+    let ix = axelar_solana_gas_service::instructions::set_treasury_instruction(
+        &axelar_solana_gas_service::ID,
+        &test_fixture.payer.pubkey(),
+        &gas_utils.operator.pubkey(),
+        &gas_utils.config_pda,
+        &axelar_solana_gas_service::NATIVE_GAS_TOKEN,
+        Pubkey::new_unique(),
+        0,
+        0,
+    )
+    .unwrap();
+    test_fixture
+        .send_tx_with_custom_signers(
+            &[ix],
+            &[&test_fixture.payer.insecure_clone(), &gas_utils.operator],
+        )
+        .await
+        .unwrap();
+    test_fixture
+        .fund_account(&gas_utils.config_pda, 1_000_000_000)
+        .await;
+
+    // Action
+    let ix = axelar_solana_gas_service::instructions::sweep_fees_instruction(
+        &axelar_solana_gas_service::ID,
+        &gas_utils.config_pda,
+        &[SweptToken::Native {
+            destination: Pubkey::new_unique(),
+        }],
+    )
+    .unwrap();
+    let res = test_fixture.send_tx(&[ix]).await.unwrap_err();
+
+    // Assert
+    assert!(res
+        .find_log("Destination doesn't match the treasury")
+        .is_some());
+}
+
+#[tokio::test]
+async fn test_set_treasury_fails_if_not_signed_by_operator() {
+    // Setup
+    let pt = ProgramTest::default();
+    let mut test_fixture = TestFixture::new(pt).await;
+    let gas_utils = test_fixture.deploy_gas_service().await;
+    test_fixture.init_gas_config(&gas_utils).await.unwrap();
+    let not_operator = Keypair::new();
+
+    // Action
+    let ix = axelar_solana_gas_service::instructions::set_treasury_instruction(
+        &axelar_solana_gas_service::ID,
+        &test_fixture.payer.pubkey(),
+        &not_operator.pubkey(),
+        &gas_utils.config_pda,
+        &axelar_solana_gas_service::NATIVE_GAS_TOKEN,
+        Pubkey::new_unique(),
+        0,
+        0,
+    )
+    .unwrap();
+    let res = test_fixture
+        .send_tx_with_custom_signers(
+            &[ix],
+            &[&test_fixture.payer.insecure_clone(), &not_operator],
+        )
+        .await;
+
+    // Assert
+    assert!(res.is_err());
+}
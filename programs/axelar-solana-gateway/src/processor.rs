@@ -23,7 +23,7 @@ mod validate_message;
 mod verify_signature;
 mod write_message_payload;
 
-pub use call_contract::CallContractEvent;
+pub use call_contract::{call_contract_message_id, CallContractEvent};
 pub use call_contract_offchain_data::CallContractOffchainDataEvent;
 pub use rotate_signers::VerifierSetRotated;
 pub use transfer_operatorship::OperatorshipTransferredEvent;
@@ -1,3 +1,6 @@
+use core::str::FromStr;
+
+use axelar_message_primitives::MessageId;
 use event_utils::{read_array, read_string, EventParseError};
 use program_utils::pda::{BytemuckedPda, ValidPDA};
 use solana_program::account_info::{next_account_info, AccountInfo};
@@ -97,7 +100,69 @@ impl Processor {
     }
 }
 
+/// Derives the Axelar message ID of the event logged at `log_index`, such as a
+/// [`CallContractEvent`], from the logs of the transaction with the given `signature`.
+///
+/// The message ID is made of the transaction signature and the position of the instruction
+/// that emitted the event, neither of which is known to the program at execution time, so it
+/// can't be part of the event itself. The instruction indexes are reconstructed from the
+/// program invocation logs, so the logs must not be truncated.
+///
+/// # Returns
+///
+/// `None` if `log_index` is out of bounds or not logged within a program invocation.
+#[must_use]
+pub fn call_contract_message_id<T: AsRef<str>>(
+    signature: [u8; 64],
+    logs: &[T],
+    log_index: usize,
+) -> Option<MessageId> {
+    let mut ix_count = 0_u32;
+    let mut inner_ix_count = 0_u32;
+    // The inner instruction index of each program invocation in progress
+    let mut invocations = Vec::new();
+
+    for log in logs.get(..=log_index)? {
+        let Some((program_id, status)) = log
+            .as_ref()
+            .strip_prefix("Program ")
+            .and_then(|log| log.split_once(' '))
+        else {
+            continue;
+        };
+        if Pubkey::from_str(program_id).is_err() {
+            continue;
+        }
+
+        match status {
+            "invoke [1]" => {
+                ix_count = ix_count.checked_add(1)?;
+                inner_ix_count = 0;
+                invocations.clear();
+                invocations.push(0);
+            }
+            _ if status.starts_with("invoke [") => {
+                inner_ix_count = inner_ix_count.checked_add(1)?;
+                invocations.push(inner_ix_count);
+            }
+            _ if status == "success" || status.starts_with("failed") => {
+                invocations.pop();
+            }
+            _ => {}
+        }
+    }
+
+    Some(MessageId::new(
+        signature,
+        ix_count.checked_sub(1)?,
+        *invocations.last()?,
+    ))
+}
+
 /// Represents a `CallContractEvent`.
+///
+/// Its message ID is derived from the transaction that logged it, see
+/// [`call_contract_message_id`].
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct CallContractEvent {
     /// Sender's public key.
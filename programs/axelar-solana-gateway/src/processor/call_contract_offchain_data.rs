@@ -94,6 +94,9 @@ impl Processor {
 }
 
 /// Represents a `CallContractEvent`.
+///
+/// Its message ID is derived from the transaction that logged it, see
+/// [`super::call_contract_message_id`].
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub struct CallContractOffchainDataEvent {
     /// Sender's public key.
@@ -16,17 +16,16 @@ pub enum GovernanceEvent {
     /// an incoming Axelar governance gmp message from the Axelar network.
     ProposalScheduled {
         /// The hash of the proposal in which the PDA derivation was based.
-        /// The hash is crafted from the target address, call data and native
-        /// value.
+        /// The hash is crafted from the instructions of the proposal.
         hash: [u8; 32],
-        /// The target address represented as a 32-byte array. It represents the
-        /// [`solana_program::pubkey::Pubkey`].
+        /// The target address represented as a 32-byte array. See
+        /// [`crate::state::proposal::ExecuteProposalData::target_address`].
         target_address: [u8; 32],
-        /// The call data required to execute the target program. The rkyv
-        /// encoded [`crate::proposal::ExecuteProposalCallData`].
+        /// The call data required to execute the proposal. See
+        /// [`crate::state::proposal::ExecuteProposalData::encoded_call_data`].
         call_data: Vec<u8>,
         /// This field represents how many native tokens (lamports) are being
-        /// sent to the target program. It's a little-endian U256 value.
+        /// sent by the instructions in total. It's a little-endian U256 value.
         native_value: [u8; 32],
         /// Unix timestamp in seconds from when the proposal can be executed.
         eta: [u8; 32],
@@ -37,17 +36,16 @@ pub enum GovernanceEvent {
     /// Axelar network.
     ProposalCancelled {
         /// The hash of the proposal in which the PDA derivation was based.
-        /// The hash is crafted from the target address, call data and native
-        /// value.
+        /// The hash is crafted from the instructions of the proposal.
         hash: [u8; 32],
-        /// The target address represented as a 32-byte array. It represents the
-        /// [`solana_program::pubkey::Pubkey`].
+        /// The target address represented as a 32-byte array. See
+        /// [`crate::state::proposal::ExecuteProposalData::target_address`].
         target_address: [u8; 32],
-        /// The call data required to execute the target program. The rkyv
-        /// encoded [`crate::proposal::ExecuteProposalCallData`].
+        /// The call data required to execute the proposal. See
+        /// [`crate::state::proposal::ExecuteProposalData::encoded_call_data`].
         call_data: Vec<u8>,
         /// This field represents how many native tokens (lamports) are being
-        /// sent to the target program. It's a little-endian U256 value.
+        /// sent by the instructions in total. It's a little-endian U256 value.
         native_value: [u8; 32],
         /// Unix timestamp in seconds from when the proposal can be executed
         /// little-endian U64 value. Limbs are in little-endian order.
@@ -57,17 +55,16 @@ pub enum GovernanceEvent {
     /// Logged when a previously scheduled proposal is executed.
     ProposalExecuted {
         /// The hash of the proposal in which the PDA derivation was based.
-        /// The hash is crafted from the target address, call data and native
-        /// value.
+        /// The hash is crafted from the instructions of the proposal.
         hash: [u8; 32],
-        /// The target address represented as a 32-byte array. It represents the
-        /// [`solana_program::pubkey::Pubkey`].
+        /// The target address represented as a 32-byte array. See
+        /// [`crate::state::proposal::ExecuteProposalData::target_address`].
         target_address: [u8; 32],
-        /// The call data required to execute the target program. The rkyv
-        /// encoded [`crate::proposal::ExecuteProposalCallData`].
+        /// The call data required to execute the proposal. See
+        /// [`crate::state::proposal::ExecuteProposalData::encoded_call_data`].
         call_data: Vec<u8>,
         /// This field represents how many native tokens (lamports) are being
-        /// sent to the target program. It's a little-endian U256 value.
+        /// sent by the instructions in total. It's a little-endian U256 value.
         native_value: [u8; 32],
         /// Unix timestamp in seconds from when the proposal can be executed
         /// little-endian U64 value. Limbs are in little-endian order.
@@ -78,17 +75,16 @@ pub enum GovernanceEvent {
     /// directly executable by the operator.
     OperatorProposalApproved {
         /// The hash of the proposal in which the PDA derivation was based.
-        /// The hash is crafted from the target address, call data and native
-        /// value.
+        /// The hash is crafted from the instructions of the proposal.
         hash: [u8; 32],
-        /// The target address represented as a 32-byte array. It represents the
-        /// [`solana_program::pubkey::Pubkey`].
+        /// The target address represented as a 32-byte array. See
+        /// [`crate::state::proposal::ExecuteProposalData::target_address`].
         target_address: [u8; 32],
-        /// The call data required to execute the target program. The rkyv
-        /// encoded [`crate::proposal::ExecuteProposalCallData`].
+        /// The call data required to execute the proposal. See
+        /// [`crate::state::proposal::ExecuteProposalData::encoded_call_data`].
         call_data: Vec<u8>,
         /// This field represents how many native tokens (lamports) are being
-        /// sent to the target program. It's a little-endian U256 value.
+        /// sent by the instructions in total. It's a little-endian U256 value.
         native_value: [u8; 32],
     },
 
@@ -96,17 +92,16 @@ pub enum GovernanceEvent {
     /// non directly executable by the operator.
     OperatorProposalCancelled {
         /// The hash of the proposal in which the PDA derivation was based.
-        /// The hash is crafted from the target address, call data and native
-        /// value.
+        /// The hash is crafted from the instructions of the proposal.
         hash: [u8; 32],
-        /// The target address represented as a 32-byte array. It represents the
-        /// [`solana_program::pubkey::Pubkey`].
+        /// The target address represented as a 32-byte array. See
+        /// [`crate::state::proposal::ExecuteProposalData::target_address`].
         target_address: [u8; 32],
-        /// The call data required to execute the target program. The rkyv
-        /// encoded [`crate::proposal::ExecuteProposalCallData`].
+        /// The call data required to execute the proposal. See
+        /// [`crate::state::proposal::ExecuteProposalData::encoded_call_data`].
         call_data: Vec<u8>,
         /// This field represents how many native tokens (lamports) are being
-        /// sent to the target program. It's a little-endian U256 value.
+        /// sent by the instructions in total. It's a little-endian U256 value.
         native_value: [u8; 32],
     },
 
@@ -114,17 +109,16 @@ pub enum GovernanceEvent {
     /// under it's approval by the Axelar governance infrastructure.
     OperatorProposalExecuted {
         /// The hash of the proposal in which the PDA derivation was based.
-        /// The hash is crafted from the target address, call data and native
-        /// value.
+        /// The hash is crafted from the instructions of the proposal.
         hash: [u8; 32],
-        /// The target address represented as a 32-byte array. It represents the
-        /// [`solana_program::pubkey::Pubkey`].
+        /// The target address represented as a 32-byte array. See
+        /// [`crate::state::proposal::ExecuteProposalData::target_address`].
         target_address: [u8; 32],
-        /// The call data required to execute the target program. The rkyv
-        /// encoded [`crate::proposal::ExecuteProposalCallData`].
+        /// The call data required to execute the proposal. See
+        /// [`crate::state::proposal::ExecuteProposalData::encoded_call_data`].
         call_data: Vec<u8>,
         /// This field represents how many native tokens (lamports) are being
-        /// sent to the target program. It's a little-endian U256 value.
+        /// sent by the instructions in total. It's a little-endian U256 value.
         native_value: [u8; 32],
     },
 
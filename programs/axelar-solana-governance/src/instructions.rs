@@ -3,7 +3,7 @@
 use axelar_solana_encoding::types::messages::Message;
 use borsh::{BorshDeserialize, BorshSerialize};
 
-use crate::state::proposal::ExecuteProposalData;
+use crate::state::proposal::{ExecuteProposalData, ProposalInstruction};
 use crate::state::GovernanceConfig;
 
 /// Instructions supported by the governance program.
@@ -59,18 +59,42 @@ pub enum GovernanceInstruction {
         message: Message,
     },
 
-    /// Execute a given proposal. Anyone from the Solana network can execute a
-    /// proposal.
+    /// Execute a given proposal with a single instruction. Anyone from the
+    /// Solana network can execute a proposal. See
+    /// [`Self::ExecuteMultiInstructionProposal`] for proposals with several
+    /// instructions. If acknowledgements are configured and the optional
+    /// acknowledgement accounts are provided, a
+    /// [`governance_gmp::GovernanceAcknowledgement::ProposalExecuted`] message
+    /// is sent to the governance chain afterwards.
     ///
     ///
     /// 0. [] System program account
-    /// 1. [] Payer account
-    /// 2. [WRITE] Config PDA account
-    /// 3. [WRITE] Prop PDA account
-    ExecuteProposal(ExecuteProposalData),
-
-    /// Execute a given proposal as operator. Only the designed operator can
-    /// execute the proposal.
+    /// 1. [WRITE] Config PDA account
+    /// 2. [WRITE] Prop PDA account
+    ///
+    /// Followed by the accounts of the proposal instruction.
+    ///
+    /// Optionally followed by the acknowledgement accounts:
+    ///
+    /// 0. [] Acknowledgement config PDA account
+    /// 1. [WRITE] Gas budget PDA account
+    /// 2. [] Call contract signing PDA account of this program
+    /// 3. [] Gateway root PDA account
+    /// 4. [] Gateway program account
+    /// 5. [WRITE] Gas service config PDA account
+    /// 6. [] Gas service program account
+    /// 7. [] Program ID account
+    /// 8. [WRITE] Gas payment ledger PDA account of the acknowledgement,
+    ///    funded by the gas budget, see
+    ///    [`crate::state::acknowledgement::AcknowledgementConfig::gas_payment_pda`]
+    /// 9. [WRITE] Gas reserve PDA account of native SOL of the gas service
+    ///    config
+    ExecuteProposal(ProposalInstruction),
+
+    /// Execute a given proposal with a single instruction as operator. Only
+    /// the designed operator can execute the proposal. See
+    /// [`Self::ExecuteOperatorMultiInstructionProposal`] for proposals with
+    /// several instructions.
     ///
     ///
     /// 0. [] System program account
@@ -79,7 +103,9 @@ pub enum GovernanceInstruction {
     /// 3. [WRITE] Prop PDA account
     /// 4. [] Operator PDA account
     /// 5. [WRITE] Prop operator account
-    ExecuteOperatorProposal(ExecuteProposalData),
+    ///
+    /// Followed by the accounts of the proposal instruction.
+    ExecuteOperatorProposal(ProposalInstruction),
 
     /// Withdraw governing tokens from this program config account.
     ///
@@ -103,6 +129,62 @@ pub enum GovernanceInstruction {
         /// The new operator pubkey bytes. See [`Pubkey::to_bytes`].
         new_operator: [u8; 32],
     },
+
+    /// Upgrade a program through the BPF upgradeable loader, using the config
+    /// PDA as upgrade authority. Only this program can call this instruction
+    /// via a previous scheduled GMP proposal. The buffer contents must match
+    /// the approved `buffer_hash`. See [`crate::state::upgrade::buffer_hash`].
+    ///
+    /// 0. [SIGNER] Config PDA account
+    /// 1. [WRITE] Program data account
+    /// 2. [WRITE] Program account
+    /// 3. [WRITE] Buffer account
+    /// 4. [WRITE] Spill account
+    /// 5. [] Rent sysvar account
+    /// 6. [] Clock sysvar account
+    /// 7. [] BPF upgradeable loader program account
+    /// 8. [] Program ID account
+    UpgradeProgram {
+        /// The pubkey bytes of the program to upgrade. See
+        /// [`Pubkey::to_bytes`].
+        program: [u8; 32],
+        /// The approved hash of the buffer holding the new program bytes.
+        buffer_hash: [u8; 32],
+    },
+
+    /// Configure the acknowledgements sent back to the governance chain when
+    /// proposals are executed. Only the upgrade authority of this program or
+    /// this program via a previous scheduled GMP proposal can configure them.
+    /// See [`crate::state::acknowledgement`].
+    ///
+    /// 0. [] System program account
+    /// 1. [WRITE, SIGNER] Payer account, the upgrade authority unless the
+    ///    config PDA signs
+    /// 2. [] Program data account
+    /// 3. [SIGNER] Config PDA account, only signing through proposals
+    /// 4. [WRITE] Acknowledgement config PDA account
+    ConfigureAcknowledgements {
+        /// The governance chain acknowledgements are sent to.
+        destination_chain: String,
+        /// The governance contract acknowledgements are sent to.
+        destination_address: String,
+        /// The gas service config PDA the gas is paid to.
+        gas_service_config: [u8; 32],
+        /// The gas (lamports) paid for each acknowledgement.
+        gas_value: u64,
+    },
+
+    /// Execute a given proposal with several instructions. All the
+    /// instructions of the proposal are executed, or none. The accounts are
+    /// the ones of [`Self::ExecuteProposal`], with the accounts of all the
+    /// proposal instructions in place of the ones of the single instruction.
+    ExecuteMultiInstructionProposal(ExecuteProposalData),
+
+    /// Execute a given proposal with several instructions as operator. All
+    /// the instructions of the proposal are executed, or none. The accounts
+    /// are the ones of [`Self::ExecuteOperatorProposal`], followed by the
+    /// accounts of all the proposal instructions.
+    ExecuteOperatorMultiInstructionProposal(ExecuteProposalData),
 }
 
 #[allow(clippy::unwrap_used)] // All the unwraps are safe.
@@ -154,13 +236,14 @@ pub mod builder {
     use solana_program::keccak::hash;
     use solana_program::program_error::ProgramError;
     use solana_program::pubkey::Pubkey;
-    use solana_program::{bpf_loader_upgradeable, msg, system_program};
+    use solana_program::{bpf_loader_upgradeable, msg, system_program, sysvar};
 
     use super::GovernanceInstruction;
     use crate::processor::gmp;
+    use crate::state::acknowledgement::AcknowledgementConfig;
     use crate::state::operator::derive_managed_proposal_pda;
     use crate::state::proposal::{
-        ExecutableProposal, ExecuteProposalCallData, ExecuteProposalData,
+        ExecutableProposal, ExecuteProposalCallData, ExecuteProposalData, ProposalInstruction,
     };
     use crate::state::GovernanceConfig;
 
@@ -240,11 +323,11 @@ pub mod builder {
         pub gmp_msg_meta: Option<Message>,
         /// The GMP command. Only used in the [`GmpBuild`] stage.
         pub gmp_command: Option<GovernanceCommand>,
-        /// The proposal target pubkey. Only used in the [`ProposalRelated`]
-        /// stage.
+        /// The proposal target pubkey, for proposals with a single
+        /// instruction. Only used in the [`ProposalRelated`] stage.
         pub prop_target: Option<Pubkey>,
-        /// The proposal native value. Only used in the [`ProposalRelated`]
-        /// stage.
+        /// The proposal native value, the total of its instructions. Only used
+        /// in the [`ProposalRelated`] stage.
         pub prop_native_value: Option<u64>,
         /// The proposal ETA. Only used in the [`ProposalRelated`] stage.
         pub prop_eta: Option<u64>,
@@ -255,8 +338,12 @@ pub mod builder {
         /// The proposal operator PDA. Only used in the [`ProposalRelated`]
         /// stage.
         pub prop_operator_pda: Option<Pubkey>,
-        /// The proposal call data. Only used in the [`ProposalRelated`] stage.
+        /// The proposal call data, for proposals with a single instruction.
+        /// Only used in the [`ProposalRelated`] stage.
         pub prop_call_data: Option<ExecuteProposalCallData>,
+        /// The proposal data, for proposals with several instructions. Only
+        /// used in the [`ProposalRelated`] stage.
+        pub prop_data: Option<ExecuteProposalData>,
     }
 
     impl IxBuilder<Init> {
@@ -276,11 +363,13 @@ pub mod builder {
                 prop_hash: None,
                 prop_operator_pda: None,
                 prop_call_data: None,
+                prop_data: None,
             }
         }
-        /// Sets the proposal data for the builder. All subsequent operations
-        /// for pdas and hashes calculations will be shared in next
-        /// stages. It provides access to next stage [`ProposalRelated`].
+        /// Sets the proposal data for the builder, for a proposal with a single
+        /// instruction. All subsequent operations for pdas and hashes
+        /// calculations will be shared in next stages. It provides access to
+        /// next stage [`ProposalRelated`].
         pub fn with_proposal_data(
             self,
             target: Pubkey,
@@ -290,28 +379,66 @@ pub mod builder {
             gmp_prop_target_accounts: &[AccountMeta],
             data: Vec<u8>,
         ) -> IxBuilder<ProposalRelated> {
-            let gmp_prop_target_accounts = gmp_prop_target_accounts
-                .iter()
-                .map(core::convert::Into::into)
-                .collect();
-
-            let gmp_prop_native_value_target_account =
-                native_value_target_account.map(core::convert::Into::into);
-
-            let call_data = ExecuteProposalCallData::new(
+            let instruction = proposal_instruction(
+                target,
+                native_value,
+                native_value_target_account,
                 gmp_prop_target_accounts,
-                gmp_prop_native_value_target_account,
                 data,
             );
 
-            let hash = ExecutableProposal::calculate_hash(
-                &target,
-                &call_data,
-                &from_u64_to_u256_le_bytes(native_value),
-            );
+            self.with_proposal(
+                ExecuteProposalData::new(vec![instruction]),
+                native_value,
+                eta,
+            )
+        }
+
+        /// Sets the proposal data for the builder, for a proposal with the
+        /// given instructions. All subsequent operations for pdas and hashes
+        /// calculations will be shared in next stages. It provides access to
+        /// next stage [`ProposalRelated`].
+        ///
+        /// The instructions are executed atomically, in the given order. See
+        /// [`proposal_instruction`] for building them.
+        ///
+        /// # Errors
+        ///
+        /// Returns a `ProgramError` if the total native value of the
+        /// instructions doesn't fit in a `u64`.
+        pub fn with_proposal_instructions(
+            self,
+            instructions: Vec<ProposalInstruction>,
+            eta: u64,
+        ) -> Result<IxBuilder<ProposalRelated>, ProgramError> {
+            let proposal_data = ExecuteProposalData::new(instructions);
+            let native_value = proposal_data.total_native_value()?;
+
+            Ok(self.with_proposal(proposal_data, native_value, eta))
+        }
+
+        fn with_proposal(
+            self,
+            proposal_data: ExecuteProposalData,
+            native_value: u64,
+            eta: u64,
+        ) -> IxBuilder<ProposalRelated> {
+            let hash = proposal_data.hash();
             let (gov_proposal_pda, _) = ExecutableProposal::pda(&hash);
             let (operator_proposal_managed_pda, _) = derive_managed_proposal_pda(&hash);
 
+            // Proposals with a single instruction keep their own format. See
+            // `ExecuteProposalData`.
+            let (prop_target, prop_call_data, prop_data) =
+                match <[ProposalInstruction; 1]>::try_from(proposal_data.instructions) {
+                    Ok([instruction]) => (
+                        Some(Pubkey::new_from_array(instruction.target_address)),
+                        Some(instruction.call_data),
+                        None,
+                    ),
+                    Err(instructions) => (None, None, Some(ExecuteProposalData::new(instructions))),
+                };
+
             IxBuilder {
                 accounts: self.accounts,
                 config: self.config,
@@ -319,13 +446,14 @@ pub mod builder {
                 stage: PhantomData::<ProposalRelated>,
                 gmp_command: None,
                 gmp_msg_meta: self.gmp_msg_meta,
-                prop_target: Some(target),
+                prop_target,
                 prop_native_value: Some(native_value),
                 prop_eta: Some(eta),
                 prop_pda: Some(gov_proposal_pda),
                 prop_hash: Some(hash),
                 prop_operator_pda: Some(operator_proposal_managed_pda),
-                prop_call_data: Some(call_data),
+                prop_call_data,
+                prop_data,
             }
         }
         /// Creates a new instruction for the governance config initialization.
@@ -358,6 +486,7 @@ pub mod builder {
                 prop_hash: self.prop_hash,
                 prop_operator_pda: self.prop_operator_pda,
                 prop_call_data: self.prop_call_data,
+                prop_data: self.prop_data,
             }
         }
         /// Creates a new instruction for transferring the operatorship of the
@@ -391,6 +520,7 @@ pub mod builder {
                 prop_hash: self.prop_hash,
                 prop_operator_pda: self.prop_operator_pda,
                 prop_call_data: self.prop_call_data,
+                prop_data: self.prop_data,
             }
         }
 
@@ -424,6 +554,34 @@ pub mod builder {
             )
         }
 
+        /// Prepares the builder for sending an scheduled time lock proposal
+        /// that upgrades `target_program` through the governance program
+        /// itself, which checks the buffer matches `buffer_hash` before
+        /// invoking the `bpf_loader_upgradeable` program. See
+        /// [`upgrade_program_instruction`].
+        ///
+        /// Hashing the buffer is expensive, so the transaction executing the
+        /// proposal should request the maximum compute budget of 1_400_000 CU
+        /// (see `ComputeBudgetInstruction::set_compute_unit_limit`).
+        pub fn builder_for_checked_program_upgrade(
+            target_program: &Pubkey,
+            buffer_address: &Pubkey,
+            buffer_hash: [u8; 32],
+            spill_address: &Pubkey,
+            proposal_eta: u64,
+        ) -> IxBuilder<ProposalRelated> {
+            Self::new().with_proposal(
+                ExecuteProposalData::new(vec![upgrade_program_instruction(
+                    target_program,
+                    buffer_address,
+                    buffer_hash,
+                    spill_address,
+                )]),
+                0,
+                proposal_eta,
+            )
+        }
+
         /// This is a builder of a builder. It loads into the builder a proposal
         /// that targets the governance program itself for operatorship
         /// transfer.
@@ -486,6 +644,25 @@ pub mod builder {
         }
     }
 
+    impl<Stage> IxBuilder<Stage> {
+        /// The instruction of a proposal with a single instruction. `None` for
+        /// proposals with several instructions.
+        fn prop_instruction(&self) -> Option<ProposalInstruction> {
+            Some(ProposalInstruction::new(
+                self.prop_target?.to_bytes(),
+                self.prop_call_data.clone()?,
+                from_u64_to_u256_le_bytes(self.prop_native_value?),
+            ))
+        }
+
+        /// The instructions of the proposal, whatever their number.
+        fn prop_instructions(&self) -> ExecuteProposalData {
+            self.prop_data.clone().unwrap_or_else(|| {
+                ExecuteProposalData::new(self.prop_instruction().into_iter().collect())
+            })
+        }
+    }
+
     impl IxBuilder<ProposalRelated> {
         /// Creates a GMP instruction for the previously provided proposal.
         ///
@@ -505,6 +682,7 @@ pub mod builder {
                 prop_hash: self.prop_hash,
                 prop_operator_pda: self.prop_operator_pda,
                 prop_call_data: self.prop_call_data,
+                prop_data: self.prop_data,
             }
         }
 
@@ -517,28 +695,8 @@ pub mod builder {
                 AccountMeta::new(self.prop_pda.unwrap(), false),
             ];
 
-            // Accounts needed for the target contract. Read them from the proposal data.
-            let call_data = self.prop_call_data.clone().unwrap();
-            let target_program_accounts: Vec<AccountMeta> = call_data
-                .solana_accounts
-                .iter()
-                .filter_map(|acc| {
-                    // Avoid a repeated config_pda account, that's normally specified in the
-                    // proposal data, for targeting the withdraw tokens instruction of
-                    // this contract. A CPI call to itself.
-                    if acc.pubkey == config_pda.to_bytes() {
-                        return None;
-                    }
-                    Some(AccountMeta::from(acc))
-                })
-                .collect();
-
-            accounts.extend(target_program_accounts);
-
-            // Add the account to receive the native value if it exists.
-            if let Some(ref fund_acc) = call_data.solana_native_value_receiver_account {
-                accounts.push(fund_acc.into());
-            }
+            // Accounts needed for the target contracts. Read them from the proposal data.
+            accounts.extend(proposal_target_accounts(&self.proposal_data(), config_pda));
 
             IxBuilder {
                 accounts: Some(accounts),
@@ -554,6 +712,7 @@ pub mod builder {
                 prop_hash: self.prop_hash,
                 prop_operator_pda: self.prop_operator_pda,
                 prop_call_data: self.prop_call_data,
+                prop_data: self.prop_data,
             }
         }
 
@@ -578,28 +737,8 @@ pub mod builder {
                 AccountMeta::new(self.prop_operator_pda.unwrap(), false),
             ];
 
-            // Accounts needed for the target contract. Read them from the proposal data.
-            let call_data = self.prop_call_data.clone().unwrap();
-            let target_program_accounts: Vec<AccountMeta> = call_data
-                .solana_accounts
-                .iter()
-                .filter_map(|acc| {
-                    // Avoid a repeated config_pda account, that's normally specified in the
-                    // proposal data, for targeting the withdraw tokens instruction of
-                    // this contract. A CPI call to itself.
-                    if acc.pubkey == config_pda.to_bytes() {
-                        return None;
-                    }
-                    Some(AccountMeta::from(acc))
-                })
-                .collect();
-
-            accounts.extend(target_program_accounts);
-
-            // Add the account to receive the native value if it exists.
-            if let Some(ref fund_acc) = call_data.solana_native_value_receiver_account {
-                accounts.push(fund_acc.into());
-            }
+            // Accounts needed for the target contracts. Read them from the proposal data.
+            accounts.extend(proposal_target_accounts(&self.proposal_data(), config_pda));
 
             IxBuilder {
                 accounts: Some(accounts),
@@ -615,6 +754,7 @@ pub mod builder {
                 prop_hash: self.prop_hash,
                 prop_operator_pda: self.prop_operator_pda,
                 prop_call_data: self.prop_call_data,
+                prop_data: self.prop_data,
             }
         }
 
@@ -630,15 +770,22 @@ pub mod builder {
         pub fn proposal_hash(&self) -> [u8; 32] {
             self.prop_hash.unwrap()
         }
-        /// The proposal target pubkey.
+        /// The proposal target pubkey. Proposals with several instructions
+        /// target the governance program, which executes them.
         pub fn proposal_target_address(&self) -> Pubkey {
-            self.prop_target.unwrap()
+            self.prop_target.unwrap_or(crate::ID)
         }
-        /// The proposal call data
-        pub fn proposal_call_data(&self) -> ExecuteProposalCallData {
-            self.prop_call_data.clone().unwrap()
+        /// The proposal call data, borsh encoded as in the GMP commands. See
+        /// [`ExecuteProposalData::encoded_call_data`].
+        pub fn proposal_call_data(&self) -> Vec<u8> {
+            self.proposal_data().encoded_call_data()
         }
-        /// The proposal native value. U256 le representation.
+        /// The proposal data, holding the instructions of the proposal.
+        pub fn proposal_data(&self) -> ExecuteProposalData {
+            self.prop_instructions()
+        }
+        /// The total native value of the proposal instructions. U256 le
+        /// representation.
         pub fn proposal_u256_le_native_value(&self) -> [u8; 32] {
             from_u64_to_u256_le_bytes(self.prop_native_value.unwrap())
         }
@@ -667,6 +814,7 @@ pub mod builder {
                 prop_hash: self.prop_hash,
                 prop_operator_pda: self.prop_operator_pda,
                 prop_call_data: self.prop_call_data,
+                prop_data: self.prop_data,
             }
         }
     }
@@ -701,6 +849,7 @@ pub mod builder {
                 prop_hash: self.prop_hash,
                 prop_operator_pda: self.prop_operator_pda,
                 prop_call_data: self.prop_call_data,
+                prop_data: self.prop_data,
             }
         }
 
@@ -738,6 +887,7 @@ pub mod builder {
                 prop_hash: self.prop_hash,
                 prop_operator_pda: self.prop_operator_pda,
                 prop_call_data: self.prop_call_data,
+                prop_data: self.prop_data,
             }
         }
 
@@ -772,6 +922,7 @@ pub mod builder {
                 prop_hash: self.prop_hash,
                 prop_operator_pda: self.prop_operator_pda,
                 prop_call_data: self.prop_call_data,
+                prop_data: self.prop_data,
             }
         }
 
@@ -806,24 +957,72 @@ pub mod builder {
                 prop_hash: self.prop_hash,
                 prop_operator_pda: self.prop_operator_pda,
                 prop_call_data: self.prop_call_data,
+                prop_data: self.prop_data,
             }
         }
     }
 
     impl IxBuilder<ExecuteProposalBuild> {
+        /// Adds the accounts needed for acknowledging the proposal execution
+        /// to the governance chain. Without them, the proposal is executed
+        /// but not acknowledged. See [`crate::state::acknowledgement`].
+        ///
+        /// The `acknowledgement_config` is the one stored in the
+        /// acknowledgement config PDA, telling where the acknowledgement is
+        /// sent to and its gas paid to.
+        #[must_use]
+        pub fn with_acknowledgement(
+            mut self,
+            gateway_root_pda: &Pubkey,
+            acknowledgement_config: &AcknowledgementConfig,
+        ) -> Self {
+            let gas_service_config_pda =
+                &Pubkey::new_from_array(acknowledgement_config.gas_service_config);
+            let payload =
+                AcknowledgementConfig::proposal_executed_payload(&self.prop_instructions())
+                    .expect("Unable to encode the acknowledgement payload");
+            let (gas_payment_pda, _) = acknowledgement_config.gas_payment_pda(&payload);
+            let acknowledgement_accounts = [
+                AccountMeta::new_readonly(AcknowledgementConfig::pda().0, false),
+                AccountMeta::new(AcknowledgementConfig::gas_budget_pda().0, false),
+                AccountMeta::new_readonly(
+                    axelar_solana_gateway::get_call_contract_signing_pda(crate::ID).0,
+                    false,
+                ),
+                AccountMeta::new_readonly(*gateway_root_pda, false),
+                AccountMeta::new_readonly(axelar_solana_gateway::ID, false),
+                AccountMeta::new(*gas_service_config_pda, false),
+                AccountMeta::new_readonly(axelar_solana_gas_service::ID, false),
+                AccountMeta::new_readonly(crate::ID, false),
+                AccountMeta::new(gas_payment_pda, false),
+                AccountMeta::new(
+                    axelar_solana_gas_service::get_gas_reserve_pda(
+                        &axelar_solana_gas_service::ID,
+                        gas_service_config_pda,
+                        &axelar_solana_gas_service::NATIVE_GAS_TOKEN,
+                    )
+                    .0,
+                    false,
+                ),
+            ];
+
+            if let Some(accounts) = self.accounts.as_mut() {
+                // Right after the accounts of the proposal instructions.
+                accounts.extend(acknowledgement_accounts);
+            }
+            self
+        }
+
         /// Builds the instruction for executing the proposal. This is a final
         /// builder stage.
         pub fn build(self) -> Instruction {
             let accounts = self.accounts.unwrap();
-            let target_address = self.prop_target.unwrap();
-            let call_data = self.prop_call_data.unwrap();
-            let native_value = from_u64_to_u256_le_bytes(self.prop_native_value.unwrap());
-
-            let gov_instruction = GovernanceInstruction::ExecuteProposal(ExecuteProposalData::new(
-                target_address.to_bytes(),
-                call_data,
-                native_value,
-            ));
+            let gov_instruction = match self.prop_instruction() {
+                Some(instruction) => GovernanceInstruction::ExecuteProposal(instruction),
+                None => {
+                    GovernanceInstruction::ExecuteMultiInstructionProposal(self.prop_data.unwrap())
+                }
+            };
 
             let data = to_vec(&gov_instruction).expect("Unable to encode GovernanceInstruction");
 
@@ -840,13 +1039,12 @@ pub mod builder {
         /// This is a final builder stage.
         pub fn build(self) -> Instruction {
             let accounts = self.accounts.unwrap();
-            let target_address = self.prop_target.unwrap();
-            let call_data = self.prop_call_data.unwrap();
-            let native_value = from_u64_to_u256_le_bytes(self.prop_native_value.unwrap());
-
-            let gov_instruction = GovernanceInstruction::ExecuteOperatorProposal(
-                ExecuteProposalData::new(target_address.to_bytes(), call_data, native_value),
-            );
+            let gov_instruction = match self.prop_instruction() {
+                Some(instruction) => GovernanceInstruction::ExecuteOperatorProposal(instruction),
+                None => GovernanceInstruction::ExecuteOperatorMultiInstructionProposal(
+                    self.prop_data.unwrap(),
+                ),
+            };
 
             let data = to_vec(&gov_instruction).expect("Unable to encode GovernanceInstruction");
 
@@ -903,15 +1101,14 @@ pub mod builder {
             let accounts = self.accounts.unwrap();
             let mut gmp_msg_meta = self.gmp_msg_meta.unwrap();
             let gmp_command = self.gmp_command.unwrap();
-            let gmp_prop_target = self.prop_target.unwrap();
-            let gmp_prop_native_value = self.prop_native_value.unwrap();
             let gmp_prop_eta = self.prop_eta.unwrap();
-            let gmp_prop_call_data = self.prop_call_data.unwrap();
+            let gmp_prop_native_value = self.prop_native_value.unwrap();
+            let gmp_prop_data = self.prop_instructions();
 
             let governance_command = GovernanceCommandPayload {
                 command: gmp_command,
-                target: gmp_prop_target.to_bytes().into(),
-                call_data: to_vec(&gmp_prop_call_data).unwrap().into(),
+                target: gmp_prop_data.target_address().into(),
+                call_data: gmp_prop_data.encoded_call_data().into(),
                 native_value: Uint::from(gmp_prop_native_value),
                 eta: Uint::from(gmp_prop_eta),
             };
@@ -976,29 +1173,13 @@ pub mod builder {
         payload: &[u8],
     ) -> Result<Instruction, ProgramError> {
         let payload = gmp::payload_conversions::decode_payload(payload)?;
-        let call_data = gmp::payload_conversions::decode_payload_call_data(&payload.call_data)?;
-        let target = gmp::payload_conversions::decode_payload_target(&payload.target)?;
-        let ix_builder = IxBuilder::new();
+        let proposal_data = gmp::payload_conversions::decode_payload_proposal_data(&payload)?;
 
-        let account = call_data
-            .solana_native_value_receiver_account
-            .map(AccountMeta::from);
-
-        let solana_accounts = call_data
-            .solana_accounts
-            .iter()
-            .map(AccountMeta::from)
-            .collect::<Vec<_>>();
-
-        let ix_builder = ix_builder
-            .with_proposal_data(
-                target,
-                checked_from_u256_le_bytes_to_u64(&payload.native_value.to_le_bytes())?,
+        let ix_builder = IxBuilder::new()
+            .with_proposal_instructions(
+                proposal_data.instructions,
                 checked_from_u256_le_bytes_to_u64(&payload.eta.to_le_bytes())?,
-                account,
-                &solana_accounts,
-                call_data.call_data,
-            )
+            )?
             .gmp_ix()
             .with_msg_metadata(message.clone());
 
@@ -1035,6 +1216,176 @@ pub mod builder {
         Ok(ix)
     }
 
+    /// Builds a proposal instruction, targeting the `target` program with the
+    /// given accounts and instruction data, and sending it `native_value`
+    /// lamports through `native_value_target_account`.
+    pub fn proposal_instruction(
+        target: Pubkey,
+        native_value: u64,
+        native_value_target_account: Option<AccountMeta>,
+        target_accounts: &[AccountMeta],
+        data: Vec<u8>,
+    ) -> ProposalInstruction {
+        let call_data = ExecuteProposalCallData::new(
+            target_accounts
+                .iter()
+                .map(core::convert::Into::into)
+                .collect(),
+            native_value_target_account.map(core::convert::Into::into),
+            data,
+        );
+
+        ProposalInstruction::new(
+            target.to_bytes(),
+            call_data,
+            from_u64_to_u256_le_bytes(native_value),
+        )
+    }
+
+    /// Builds a proposal instruction upgrading `program` with the contents of
+    /// `buffer`, which must match `buffer_hash` when the proposal is executed.
+    /// See [`crate::state::upgrade::buffer_hash`]. Several of them can be
+    /// combined in a single proposal for upgrading programs atomically, as
+    /// long as the buffers can be hashed within the 1_400_000 CU the
+    /// executing transaction can request at most.
+    pub fn upgrade_program_instruction(
+        program: &Pubkey,
+        buffer: &Pubkey,
+        buffer_hash: [u8; 32],
+        spill: &Pubkey,
+    ) -> ProposalInstruction {
+        let (config_pda, _) = GovernanceConfig::pda();
+        let target_accounts = &[
+            AccountMeta::new_readonly(config_pda, true),
+            AccountMeta::new(
+                bpf_loader_upgradeable::get_program_data_address(program),
+                false,
+            ),
+            AccountMeta::new(*program, false),
+            AccountMeta::new(*buffer, false),
+            AccountMeta::new(*spill, false),
+            AccountMeta::new_readonly(sysvar::rent::ID, false),
+            AccountMeta::new_readonly(sysvar::clock::ID, false),
+            AccountMeta::new_readonly(bpf_loader_upgradeable::ID, false),
+            AccountMeta::new_readonly(crate::ID, false),
+        ];
+
+        let data = to_vec(&GovernanceInstruction::UpgradeProgram {
+            program: program.to_bytes(),
+            buffer_hash,
+        })
+        .unwrap();
+
+        proposal_instruction(crate::ID, 0, None, target_accounts, data)
+    }
+
+    /// Builds the instruction for configuring the acknowledgements sent to the
+    /// governance chain. The `payer` must be the upgrade authority of this
+    /// program.
+    pub fn configure_acknowledgements(
+        payer: &Pubkey,
+        destination_chain: String,
+        destination_address: String,
+        gas_service_config: &Pubkey,
+        gas_value: u64,
+    ) -> Instruction {
+        let (config_pda, _) = GovernanceConfig::pda();
+        let accounts = vec![
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new(*payer, true),
+            AccountMeta::new_readonly(
+                bpf_loader_upgradeable::get_program_data_address(&crate::ID),
+                false,
+            ),
+            AccountMeta::new_readonly(config_pda, false),
+            AccountMeta::new(AcknowledgementConfig::pda().0, false),
+        ];
+
+        let data = to_vec(&GovernanceInstruction::ConfigureAcknowledgements {
+            destination_chain,
+            destination_address,
+            gas_service_config: gas_service_config.to_bytes(),
+            gas_value,
+        })
+        .expect("Unable to encode GovernanceInstruction");
+
+        Instruction {
+            program_id: crate::id(),
+            accounts,
+            data,
+        }
+    }
+
+    /// Builds a proposal instruction configuring the acknowledgements sent to
+    /// the governance chain, with the config PDA as signer. The `funder` pays
+    /// for the acknowledgement config PDA, so it must sign the transaction
+    /// executing the proposal.
+    pub fn configure_acknowledgements_proposal_instruction(
+        funder: &Pubkey,
+        destination_chain: String,
+        destination_address: String,
+        gas_service_config: &Pubkey,
+        gas_value: u64,
+    ) -> ProposalInstruction {
+        let (config_pda, _) = GovernanceConfig::pda();
+        let target_accounts = &[
+            AccountMeta::new_readonly(system_program::ID, false),
+            AccountMeta::new(*funder, true),
+            AccountMeta::new_readonly(
+                bpf_loader_upgradeable::get_program_data_address(&crate::ID),
+                false,
+            ),
+            AccountMeta::new_readonly(config_pda, true),
+            AccountMeta::new(AcknowledgementConfig::pda().0, false),
+            AccountMeta::new_readonly(crate::ID, false),
+        ];
+
+        let data = to_vec(&GovernanceInstruction::ConfigureAcknowledgements {
+            destination_chain,
+            destination_address,
+            gas_service_config: gas_service_config.to_bytes(),
+            gas_value,
+        })
+        .unwrap();
+
+        proposal_instruction(crate::ID, 0, None, target_accounts, data)
+    }
+
+    /// The accounts of all the proposal instructions, followed by the accounts
+    /// receiving their native value. Each account is only listed once, with
+    /// the privileges it has in any of the instructions.
+    fn proposal_target_accounts(
+        proposal_data: &ExecuteProposalData,
+        config_pda: &Pubkey,
+    ) -> Vec<AccountMeta> {
+        let solana_accounts = proposal_data
+            .instructions
+            .iter()
+            .flat_map(|ix| ix.call_data.solana_accounts.iter());
+        let native_value_receivers = proposal_data
+            .instructions
+            .iter()
+            .filter_map(|ix| ix.call_data.solana_native_value_receiver_account.as_ref());
+
+        let mut accounts: Vec<AccountMeta> = Vec::new();
+        for acc in solana_accounts.chain(native_value_receivers) {
+            // Avoid a repeated config_pda account, that's normally specified in the
+            // proposal data, for targeting the withdraw tokens instruction of
+            // this contract. A CPI call to itself.
+            if acc.pubkey == config_pda.to_bytes() {
+                continue;
+            }
+            let acc = AccountMeta::from(acc);
+            if let Some(existing) = accounts.iter_mut().find(|x| x.pubkey == acc.pubkey) {
+                existing.is_signer |= acc.is_signer;
+                existing.is_writable |= acc.is_writable;
+            } else {
+                accounts.push(acc);
+            }
+        }
+        accounts
+    }
+
     /// Prepends the gateway accounts to the instruction.
     /// This is useful for instructions that require the gateway accounts for
     /// message verification in GMP flows.
@@ -1168,6 +1519,132 @@ pub mod builder {
             // Send ix
         }
 
+        #[test]
+        fn upgrade_proposals_composed_from_evm_match_the_builder_ones() {
+            let upgrades = [
+                (Pubkey::new_unique(), Pubkey::new_unique(), [1_u8; 32]),
+                (Pubkey::new_unique(), Pubkey::new_unique(), [2_u8; 32]),
+            ];
+            let spill = Pubkey::new_unique();
+            let eta = 1;
+
+            let ix_builder = IxBuilder::new()
+                .with_proposal_instructions(
+                    upgrades
+                        .iter()
+                        .map(|(program, buffer, buffer_hash)| {
+                            upgrade_program_instruction(program, buffer, *buffer_hash, &spill)
+                        })
+                        .collect(),
+                    eta,
+                )
+                .unwrap();
+
+            let evm_upgrades = upgrades
+                .iter()
+                .map(
+                    |(program, buffer, buffer_hash)| governance_gmp::upgrade::ProgramUpgrade {
+                        program: program.to_bytes(),
+                        program_data: bpf_loader_upgradeable::get_program_data_address(program)
+                            .to_bytes(),
+                        buffer: buffer.to_bytes(),
+                        spill: spill.to_bytes(),
+                        buffer_hash: *buffer_hash,
+                    },
+                )
+                .collect::<Vec<_>>();
+            let payload = governance_gmp::upgrade::schedule_upgrade_payload(
+                &crate::ID.to_bytes(),
+                &GovernanceConfig::pda().0.to_bytes(),
+                &evm_upgrades,
+                eta,
+            )
+            .unwrap();
+
+            let proposal_data =
+                gmp::payload_conversions::decode_payload_proposal_data(&payload).unwrap();
+            assert_eq!(ix_builder.proposal_data(), proposal_data);
+            assert_eq!(ix_builder.proposal_hash(), proposal_data.hash());
+            assert_eq!(
+                ix_builder.proposal_target_address(),
+                gmp::payload_conversions::decode_payload_target(&payload.target).unwrap()
+            );
+        }
+
+        #[test]
+        fn upgrade_program_index_matches_the_evm_helpers() {
+            let data = to_vec(&GovernanceInstruction::UpgradeProgram {
+                program: [1; 32],
+                buffer_hash: [2; 32],
+            })
+            .unwrap();
+
+            assert_eq!(
+                data[0],
+                governance_gmp::upgrade::UPGRADE_PROGRAM_INSTRUCTION_INDEX
+            );
+        }
+
+        #[test]
+        fn single_instruction_proposals_keep_their_format() {
+            let target = Pubkey::new_unique();
+            let native_value = 1;
+            let ix_builder = IxBuilder::new().with_proposal_data(
+                target,
+                native_value,
+                1,
+                Some(AccountMeta::new(Pubkey::new_unique(), false)),
+                &[AccountMeta::new_readonly(Pubkey::new_unique(), false)],
+                vec![1, 2, 3],
+            );
+
+            let gmp_call_data = ix_builder
+                .clone()
+                .gmp_ix()
+                .with_msg_metadata(gmp_sample_metadata())
+                .schedule_time_lock_proposal(&Pubkey::new_unique(), &Pubkey::new_unique())
+                .build();
+            let payload =
+                gmp::payload_conversions::decode_payload(&gmp_call_data.msg_payload).unwrap();
+            assert_eq!(
+                gmp::payload_conversions::decode_payload_target(&payload.target).unwrap(),
+                target
+            );
+            let call_data =
+                gmp::payload_conversions::decode_payload_call_data(&payload.call_data).unwrap();
+            assert_eq!(Some(&call_data), ix_builder.prop_call_data.as_ref());
+            assert_eq!(
+                gmp::payload_conversions::decode_payload_proposal_data(&payload).unwrap(),
+                ix_builder.proposal_data()
+            );
+
+            // Hashed as the proposal instruction, as before proposals could hold
+            // several instructions.
+            let instruction = ProposalInstruction::new(
+                target.to_bytes(),
+                call_data,
+                from_u64_to_u256_le_bytes(native_value),
+            );
+            assert_eq!(ix_builder.proposal_hash(), instruction.hash());
+
+            let ix = ix_builder.execute_proposal(&Pubkey::new_unique()).build();
+            assert_eq!(
+                borsh::from_slice::<GovernanceInstruction>(&ix.data).unwrap(),
+                GovernanceInstruction::ExecuteProposal(instruction)
+            );
+        }
+
+        #[test]
+        fn proposals_with_an_overflowing_native_value_are_rejected() {
+            let instruction =
+                proposal_instruction(Pubkey::new_unique(), u64::MAX, None, &[], vec![]);
+
+            let res = IxBuilder::new()
+                .with_proposal_instructions(vec![instruction.clone(), instruction], 1);
+
+            assert_eq!(res.unwrap_err(), ProgramError::ArithmeticOverflow);
+        }
+
         #[test]
         fn builder_stages_can_have_convenient_getters_per_each_stage() {
             let target = Pubkey::new_unique();
@@ -50,4 +50,10 @@ pub mod seed_prefixes {
     /// signals an operator can operate a proposal (like executing it
     /// regardless of the ETA).
     pub const OPERATOR_MANAGED_PROPOSAL: &[u8] = b"operator-managed-proposal";
+    /// The seed that derives the PDA holding where acknowledgements are sent
+    /// to.
+    pub const ACKNOWLEDGEMENT_CONFIG: &[u8] = b"acknowledgement-config";
+    /// The seed that derives the system owned PDA whose lamports pay the gas
+    /// of the acknowledgements.
+    pub const GAS_BUDGET: &[u8] = b"gas-budget";
 }
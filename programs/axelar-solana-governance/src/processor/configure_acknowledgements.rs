@@ -0,0 +1,104 @@
+//! Configure the acknowledgements sent back to the governance chain when
+//! proposals are executed.
+//!
+//! They can be configured by the upgrade authority of this program, as it does
+//! with the governance config, or by a proposal, which makes the config PDA
+//! sign through a self CPI call.
+
+use program_utils::pda::{BorshPda, ValidPDA};
+use program_utils::validate_system_account_key;
+use role_management::processor::ensure_upgrade_authority;
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::keccak::hash;
+use solana_program::msg;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+use super::ensure_valid_governance_root_pda;
+use crate::seed_prefixes;
+use crate::state::acknowledgement::AcknowledgementConfig;
+use crate::state::GovernanceConfig;
+
+/// Creates or updates the [`AcknowledgementConfig`]. The destination chain and
+/// address must be the ones the governance program accepts commands from.
+///
+/// The payer funds the acknowledgement config PDA. It must be the upgrade
+/// authority of this program, unless the config PDA signs the instruction
+/// through a proposal.
+///
+/// # Errors
+///
+/// This function will return a [`ProgramError`] if any of the subcmds fail.
+pub(crate) fn process(
+    program_id: &Pubkey,
+    accounts: &[AccountInfo<'_>],
+    destination_chain: String,
+    destination_address: String,
+    gas_service_config: [u8; 32],
+    gas_value: u64,
+) -> Result<(), ProgramError> {
+    let accounts_iter = &mut accounts.iter();
+    let system_account = next_account_info(accounts_iter)?;
+    let payer = next_account_info(accounts_iter)?;
+    let program_data = next_account_info(accounts_iter)?;
+    let config_pda = next_account_info(accounts_iter)?;
+    let acknowledgement_config_pda = next_account_info(accounts_iter)?;
+
+    validate_system_account_key(system_account.key)?;
+
+    let config_data = config_pda.check_initialized_pda::<GovernanceConfig>(&crate::id())?;
+    ensure_valid_governance_root_pda(config_data.bump, config_pda.key)?;
+
+    if config_pda.is_signer {
+        if !payer.is_signer {
+            msg!("Payer account must sign the transaction");
+            return Err(ProgramError::MissingRequiredSignature);
+        }
+    } else {
+        ensure_upgrade_authority(program_id, payer, program_data)?;
+    }
+
+    if hash(destination_chain.as_bytes()).0 != config_data.chain_hash
+        || hash(destination_address.as_bytes()).0 != config_data.address_hash
+    {
+        msg!("Acknowledgements can only be sent to the governance chain and address");
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    if gas_value == 0 {
+        msg!("The gas value of the acknowledgements cannot be zero");
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let (_, gas_budget_bump) = AcknowledgementConfig::gas_budget_pda();
+    let existing_config = AcknowledgementConfig::load_if_configured(acknowledgement_config_pda)?;
+    let (_, bump) = AcknowledgementConfig::pda();
+
+    let acknowledgement_config = AcknowledgementConfig {
+        bump,
+        gas_budget_bump,
+        destination_chain,
+        destination_address,
+        gas_service_config,
+        gas_value,
+    };
+
+    if existing_config.is_some() {
+        acknowledgement_config.store(payer, acknowledgement_config_pda, system_account)?;
+    } else {
+        acknowledgement_config.init(
+            program_id,
+            system_account,
+            payer,
+            acknowledgement_config_pda,
+            &[seed_prefixes::ACKNOWLEDGEMENT_CONFIG, &[bump]],
+        )?;
+    }
+
+    msg!(
+        "Acknowledgements are sent to {} on {}",
+        acknowledgement_config.destination_address,
+        acknowledgement_config.destination_chain
+    );
+    Ok(())
+}
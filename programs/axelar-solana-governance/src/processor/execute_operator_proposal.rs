@@ -1,8 +1,7 @@
 //! Executes all logic for processing a `ExecuteOperatorProposal` command.
 //!
 //! See [original implementation](https://github.com/axelarnetwork/axelar-gmp-sdk-solidity/blob/main/contracts/governance/AxelarServiceGovernance.sol#L75).
-use borsh::to_vec;
-use program_utils::{pda::ValidPDA, validate_system_account_key};
+use program_utils::{from_u64_to_u256_le_bytes, pda::ValidPDA, validate_system_account_key};
 use solana_program::account_info::{next_account_info, AccountInfo};
 use solana_program::msg;
 use solana_program::program_error::ProgramError;
@@ -40,11 +39,7 @@ pub(crate) fn process(
         return Err(ProgramError::MissingRequiredSignature);
     }
 
-    let hash = ExecutableProposal::calculate_hash(
-        &Pubkey::new_from_array(execute_proposal_data.target_address),
-        &execute_proposal_data.call_data,
-        &execute_proposal_data.native_value,
-    );
+    let hash = execute_proposal_data.hash();
 
     operator::ensure_correct_managed_proposal_pda(
         proposal_account,
@@ -73,18 +68,15 @@ pub(crate) fn process(
         &target_program_accounts,
         config_pda,
         config_data.bump,
-        Pubkey::new_from_array(execute_proposal_data.target_address),
-        execute_proposal_data.call_data.clone(),
-        execute_proposal_data.find_target_native_value_account_info(accounts),
-        execute_proposal_data.native_value()?,
+        &execute_proposal_data.instructions,
     )?;
 
     // Send event
     let event = GovernanceEvent::OperatorProposalExecuted {
         hash,
-        target_address: execute_proposal_data.target_address,
-        call_data: to_vec(&execute_proposal_data.call_data).expect("Should serialize call data"),
-        native_value: execute_proposal_data.native_value,
+        target_address: execute_proposal_data.target_address(),
+        call_data: execute_proposal_data.encoded_call_data(),
+        native_value: from_u64_to_u256_le_bytes(execute_proposal_data.total_native_value()?),
     };
     event.emit()?;
     ExecutableProposal::remove(proposal_account, config_pda)?;
@@ -1,15 +1,26 @@
 //! Logic for executing a proposal.Anyone can execute a proposal if the proposal
 //! has reached its ETA.
 //!
+//! If acknowledgements are configured and the executor provides the
+//! acknowledgement accounts, the execution is reported back to the governance
+//! chain through the gateway.
+//!
 //! See [original implementation](https://github.com/axelarnetwork/axelar-gmp-sdk-solidity/blob/main/contracts/governance/InterchainGovernance.sol#L98).
 use crate::events::GovernanceEvent;
+use crate::seed_prefixes;
+use crate::state::acknowledgement::AcknowledgementConfig;
 use crate::state::proposal::{ExecutableProposal, ExecuteProposalData};
 use crate::state::GovernanceConfig;
-use borsh::to_vec;
+use axelar_solana_gas_service::state::{GasPayment, GasReserve};
+use core::mem::size_of;
 use program_utils::{from_u64_to_u256_le_bytes, pda::ValidPDA, validate_system_account_key};
 use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::msg;
+use solana_program::program::invoke_signed;
 use solana_program::program_error::ProgramError;
 use solana_program::pubkey::Pubkey;
+use solana_program::rent::Rent;
+use solana_program::sysvar::Sysvar;
 
 /// Executes a previously GMP received proposal if the proposal has reached its
 /// ETA.
@@ -22,6 +33,7 @@ pub(crate) fn process(
     accounts: &[AccountInfo<'_>],
     execute_proposal_data: &ExecuteProposalData,
 ) -> Result<(), ProgramError> {
+    let (accounts, acknowledgement_accounts) = split_acknowledgement_accounts(accounts);
     let accounts_iter = &mut accounts.iter();
     let system_account = next_account_info(accounts_iter)?;
     let config_pda = next_account_info(accounts_iter)?;
@@ -29,50 +41,215 @@ pub(crate) fn process(
 
     validate_system_account_key(system_account.key)?;
 
+    let acknowledgement = match acknowledgement_accounts {
+        Some(trailing_accounts) => {
+            let acknowledgement_iter = &mut trailing_accounts.iter();
+            let acknowledgement_config_account = next_account_info(acknowledgement_iter)?;
+            AcknowledgementConfig::load_if_configured(acknowledgement_config_account)?
+                .map(|acknowledgement_config| {
+                    AcknowledgementAccounts::from_account_info_iter(acknowledgement_iter).map(
+                        |acknowledgement_accounts| {
+                            (acknowledgement_config, acknowledgement_accounts)
+                        },
+                    )
+                })
+                .transpose()?
+        }
+        None => None,
+    };
+
     let config_data = config_pda.check_initialized_pda::<GovernanceConfig>(&crate::id())?;
 
     // Ensure the provided PDA matches the one obtained from the proposal data hash.
-    //let hash = ensure_valid_proposal_pda(execute_proposal_data,
-    // proposal_account)?;
-
-    let hash = ExecutableProposal::calculate_hash(
-        &Pubkey::new_from_array(execute_proposal_data.target_address),
-        &execute_proposal_data.call_data,
-        &execute_proposal_data.native_value,
-    );
+    let hash = execute_proposal_data.hash();
 
     ExecutableProposal::load_and_ensure_correct_proposal_pda(proposal_account, &hash)?;
 
     let proposal = ExecutableProposal::load_from(program_id, proposal_account)?;
 
     // Only invoke with target program accounts.
-    let mut target_program_accounts = accounts
-        .get(3..)
-        .ok_or(ProgramError::InvalidInstructionData)?
-        .as_ref()
-        .to_vec();
+    let mut target_program_accounts = accounts_iter.as_slice().to_vec();
     target_program_accounts.push(config_pda.clone());
 
     proposal.checked_execute(
         &target_program_accounts,
         config_pda,
         config_data.bump,
-        Pubkey::new_from_array(execute_proposal_data.target_address),
-        execute_proposal_data.call_data.clone(),
-        execute_proposal_data.find_target_native_value_account_info(accounts),
-        execute_proposal_data.native_value()?,
+        &execute_proposal_data.instructions,
     )?;
 
     // Send event
     let event = GovernanceEvent::ProposalExecuted {
         hash,
-        target_address: execute_proposal_data.target_address,
-        call_data: to_vec(&execute_proposal_data.call_data).expect("Should serialize call data"),
-        native_value: execute_proposal_data.native_value,
+        target_address: execute_proposal_data.target_address(),
+        call_data: execute_proposal_data.encoded_call_data(),
+        native_value: from_u64_to_u256_le_bytes(execute_proposal_data.total_native_value()?),
         // Todo: Maybe we should adopt this U256 type for the ETA field in the event.
         // Or just cast a u64 in a [u8;32] little endian.
         eta: from_u64_to_u256_le_bytes(proposal.eta()),
     };
     event.emit()?;
-    ExecutableProposal::remove(proposal_account, config_pda)
+    ExecutableProposal::remove(proposal_account, config_pda)?;
+
+    if let Some((acknowledgement_config, acknowledgement_accounts)) = acknowledgement {
+        send_acknowledgement(
+            &acknowledgement_config,
+            &acknowledgement_accounts,
+            system_account,
+            AcknowledgementConfig::proposal_executed_payload(execute_proposal_data)?,
+        )?;
+    } else if acknowledgement_accounts.is_none() {
+        msg!("No acknowledgement accounts provided, the acknowledgement is not sent");
+    }
+
+    Ok(())
+}
+
+/// The number of optional trailing accounts needed for acknowledgements: the
+/// acknowledgement config PDA followed by the [`AcknowledgementAccounts`].
+const ACKNOWLEDGEMENT_ACCOUNTS_LEN: usize = 10;
+
+/// Splits the optional trailing acknowledgement accounts from the `accounts`.
+/// They are told apart from the accounts of the proposal instructions by the
+/// acknowledgement config PDA, followed by the gas budget PDA.
+fn split_acknowledgement_accounts<'a, 'b>(
+    accounts: &'a [AccountInfo<'b>],
+) -> (&'a [AccountInfo<'b>], Option<&'a [AccountInfo<'b>]>) {
+    let Some(split_at) = accounts.len().checked_sub(ACKNOWLEDGEMENT_ACCOUNTS_LEN) else {
+        return (accounts, None);
+    };
+    let (head, tail) = accounts.split_at(split_at);
+    match tail {
+        [acknowledgement_config, gas_budget, ..]
+            if acknowledgement_config.key == &AcknowledgementConfig::pda().0
+                && gas_budget.key == &AcknowledgementConfig::gas_budget_pda().0 =>
+        {
+            (head, Some(tail))
+        }
+        _ => (accounts, None),
+    }
+}
+
+/// The accounts needed for sending an acknowledgement.
+struct AcknowledgementAccounts<'a, 'b> {
+    gas_budget: &'a AccountInfo<'b>,
+    signing_pda: &'a AccountInfo<'b>,
+    gateway_root_pda: &'a AccountInfo<'b>,
+    gateway_program: &'a AccountInfo<'b>,
+    gas_service_config: &'a AccountInfo<'b>,
+    gas_service: &'a AccountInfo<'b>,
+    program_account: &'a AccountInfo<'b>,
+    gas_payment_pda: &'a AccountInfo<'b>,
+    gas_reserve: &'a AccountInfo<'b>,
+}
+
+impl<'a, 'b> AcknowledgementAccounts<'a, 'b> {
+    fn from_account_info_iter<I>(accounts_iter: &mut I) -> Result<Self, ProgramError>
+    where
+        I: Iterator<Item = &'a AccountInfo<'b>>,
+    {
+        Ok(Self {
+            gas_budget: next_account_info(accounts_iter)?,
+            signing_pda: next_account_info(accounts_iter)?,
+            gateway_root_pda: next_account_info(accounts_iter)?,
+            gateway_program: next_account_info(accounts_iter)?,
+            gas_service_config: next_account_info(accounts_iter)?,
+            gas_service: next_account_info(accounts_iter)?,
+            program_account: next_account_info(accounts_iter)?,
+            gas_payment_pda: next_account_info(accounts_iter)?,
+            gas_reserve: next_account_info(accounts_iter)?,
+        })
+    }
+}
+
+/// Sends the acknowledgement `payload` to the governance chain, paying its gas
+/// from the gas budget PDA. The acknowledgement is skipped if the gas budget
+/// cannot afford it. Any other failure, like invalid acknowledgement accounts,
+/// fails the whole execution, so executors that cannot send it must omit the
+/// acknowledgement accounts instead.
+fn send_acknowledgement(
+    acknowledgement_config: &AcknowledgementConfig,
+    accounts: &AcknowledgementAccounts<'_, '_>,
+    system_account: &AccountInfo<'_>,
+    payload: Vec<u8>,
+) -> Result<(), ProgramError> {
+    acknowledgement_config.ensure_gas_budget_pda(accounts.gas_budget)?;
+
+    if accounts.gas_service.key != &axelar_solana_gas_service::ID {
+        msg!("Invalid gas service account");
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    if accounts.gas_service_config.key.to_bytes() != acknowledgement_config.gas_service_config {
+        msg!("Invalid gas service config account");
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    let (signing_pda, signing_pda_bump) =
+        axelar_solana_gateway::get_call_contract_signing_pda(crate::ID);
+    if accounts.signing_pda.key != &signing_pda {
+        msg!("Invalid call contract signing pda");
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // The gas budget is a system account, which must stay rent exempt. It also
+    // funds the gas payment ledger of the acknowledgement, and the gas reserve
+    // of native SOL on the first payment to the gas service config.
+    let rent = Rent::get()?;
+    let mut required_lamports = acknowledgement_config
+        .gas_value
+        .saturating_add(rent.minimum_balance(0));
+    if accounts.gas_payment_pda.data_is_empty() {
+        required_lamports =
+            required_lamports.saturating_add(rent.minimum_balance(size_of::<GasPayment>()));
+    }
+    if accounts.gas_reserve.data_is_empty() {
+        required_lamports =
+            required_lamports.saturating_add(rent.minimum_balance(size_of::<GasReserve>()));
+    }
+    if accounts.gas_budget.lamports() < required_lamports {
+        msg!("Not enough gas budget, the acknowledgement is not sent");
+        return Ok(());
+    }
+
+    let ix = axelar_solana_gas_service::instructions::call_contract_with_native_gas_instruction(
+        accounts.gas_service.key,
+        accounts.gateway_root_pda.key,
+        &crate::ID,
+        Some((signing_pda, signing_pda_bump)),
+        accounts.gas_budget.key,
+        accounts.gas_service_config.key,
+        acknowledgement_config.destination_chain.clone(),
+        acknowledgement_config.destination_address.clone(),
+        payload,
+        *accounts.gas_budget.key,
+        vec![],
+        acknowledgement_config.gas_value,
+    )?;
+
+    invoke_signed(
+        &ix,
+        &[
+            accounts.program_account.clone(),
+            accounts.signing_pda.clone(),
+            accounts.gateway_root_pda.clone(),
+            accounts.gateway_program.clone(),
+            accounts.gas_budget.clone(),
+            accounts.gas_service_config.clone(),
+            system_account.clone(),
+            accounts.gas_payment_pda.clone(),
+            accounts.gas_reserve.clone(),
+            accounts.gas_service.clone(),
+        ],
+        &[
+            &[
+                axelar_solana_gateway::seed_prefixes::CALL_CONTRACT_SIGNING_SEED,
+                &[signing_pda_bump],
+            ],
+            &[
+                seed_prefixes::GAS_BUDGET,
+                &[acknowledgement_config.gas_budget_bump],
+            ],
+        ],
+    )
 }
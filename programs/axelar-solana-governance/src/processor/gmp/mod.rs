@@ -11,7 +11,13 @@
 //! [`governance_gmp::GovernanceCommandPayload`]. Which is adapted to accomplish
 //! the [original eth implementation](https://github.com/axelarnetwork/axelar-gmp-sdk-solidity/blob/b5d0b7bdda0437fce983daffb776669437b809d0/contracts/governance/InterchainGovernance.sol#L134). Inside the
 //! [`governance_gmp::GovernanceCommandPayload::call_data`] field of such
-//! struct, the [`crate::proposal::ExecuteProposalCallData`]
+//! struct, the borsh encoded [`crate::state::proposal::ExecuteProposalCallData`]
+//! holds the accounts and data of the `target` program call. Proposals with
+//! several instructions target the governance program itself, which executes
+//! them, and their call data is the borsh encoded
+//! [`crate::state::proposal::ExecuteProposalData`]. In both cases, the
+//! `native_value` of the payload must be the total native value of the
+//! instructions.
 //!
 //! This is the main GMP governance processing unit. See sub-modules for
 //! each GMP command processing logic.
@@ -24,6 +30,7 @@ use governance_gmp::GovernanceCommand::{
     ScheduleTimeLockProposal,
 };
 use governance_gmp::GovernanceCommandPayload;
+use program_utils::checked_from_u256_le_bytes_to_u64;
 use program_utils::pda::ValidPDA;
 use solana_program::account_info::next_account_info;
 use solana_program::account_info::AccountInfo;
@@ -33,7 +40,7 @@ use solana_program::program_error::ProgramError;
 use solana_program::pubkey::Pubkey;
 
 use super::ensure_valid_governance_root_pda;
-use crate::state::proposal::{ExecutableProposal, ExecuteProposalCallData};
+use crate::state::proposal::{ExecuteProposalCallData, ExecuteProposalData};
 use crate::state::GovernanceConfig;
 
 mod approve_operator_proposal;
@@ -122,14 +129,17 @@ impl ProcessGMPContext {
 
         let target = payload_conversions::decode_payload_target(&cmd_payload.target)?;
 
-        let execute_proposal_call_data =
-            payload_conversions::decode_payload_call_data(&cmd_payload.call_data)?;
+        let execute_proposal_data =
+            payload_conversions::decode_payload_proposal_data(&cmd_payload)?;
 
-        let proposal_hash = ExecutableProposal::calculate_hash(
-            &target,
-            &execute_proposal_call_data,
-            &cmd_payload.native_value.to_le_bytes(),
-        );
+        let native_value =
+            checked_from_u256_le_bytes_to_u64(&cmd_payload.native_value.to_le_bytes())?;
+        if native_value != execute_proposal_data.total_native_value()? {
+            msg!("Proposal native value must be the total of its instructions");
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        let proposal_hash = execute_proposal_data.hash();
 
         Ok(Self {
             cmd_payload,
@@ -172,7 +182,8 @@ pub mod payload_conversions {
         Ok(Pubkey::from(target))
     }
 
-    /// Decodes the call data from the payload.
+    /// Decodes the call data from the payload of a proposal with a single
+    /// instruction.
     ///
     /// # Errors
     ///
@@ -185,6 +196,23 @@ pub mod payload_conversions {
             ProgramError::InvalidArgument
         })
     }
+
+    /// Decodes the proposal from the target, call data and native value of
+    /// the payload. See [`ExecuteProposalData::from_payload`].
+    ///
+    /// # Errors
+    ///
+    /// A `ProgramError` is returned if the target or the call data cannot be
+    /// deserialized.
+    pub fn decode_payload_proposal_data(
+        payload: &GovernanceCommandPayload,
+    ) -> Result<ExecuteProposalData, ProgramError> {
+        ExecuteProposalData::from_payload(
+            &decode_payload_target(&payload.target)?,
+            &payload.call_data,
+            payload.native_value.to_le_bytes(),
+        )
+    }
 }
 
 fn ensure_authorized_gmp_command(
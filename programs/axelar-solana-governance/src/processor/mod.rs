@@ -16,13 +16,16 @@ use solana_program::program_error::ProgramError;
 use solana_program::pubkey::Pubkey;
 
 use crate::instructions::GovernanceInstruction;
+use crate::state::proposal::ExecuteProposalData;
 use crate::{check_program_account, seed_prefixes};
 
+mod configure_acknowledgements;
 mod execute_operator_proposal;
 mod execute_proposal;
 pub mod gmp;
 mod init_config;
 mod transfer_operatorship;
+mod upgrade_program;
 mod withdraw_tokens;
 
 /// Program state handler.
@@ -69,11 +72,17 @@ impl Processor {
 
                 gmp::process(program_id, gmp_ctx, gmp_accounts)
             }
-            GovernanceInstruction::ExecuteProposal(execute_proposal) => {
-                execute_proposal::process(program_id, accounts, &execute_proposal)
-            }
-            GovernanceInstruction::ExecuteOperatorProposal(execute_proposal_data) => {
-                execute_operator_proposal::process(program_id, accounts, &execute_proposal_data)
+            GovernanceInstruction::ExecuteProposal(instruction) => execute_proposal::process(
+                program_id,
+                accounts,
+                &ExecuteProposalData::new(vec![instruction]),
+            ),
+            GovernanceInstruction::ExecuteOperatorProposal(instruction) => {
+                execute_operator_proposal::process(
+                    program_id,
+                    accounts,
+                    &ExecuteProposalData::new(vec![instruction]),
+                )
             }
 
             GovernanceInstruction::WithdrawTokens { amount } => {
@@ -82,6 +91,29 @@ impl Processor {
             GovernanceInstruction::TransferOperatorship { new_operator } => {
                 transfer_operatorship::process(program_id, accounts, new_operator)
             }
+            GovernanceInstruction::UpgradeProgram {
+                program,
+                buffer_hash,
+            } => upgrade_program::process(program_id, accounts, program, buffer_hash),
+            GovernanceInstruction::ConfigureAcknowledgements {
+                destination_chain,
+                destination_address,
+                gas_service_config,
+                gas_value,
+            } => configure_acknowledgements::process(
+                program_id,
+                accounts,
+                destination_chain,
+                destination_address,
+                gas_service_config,
+                gas_value,
+            ),
+            GovernanceInstruction::ExecuteMultiInstructionProposal(execute_proposal_data) => {
+                execute_proposal::process(program_id, accounts, &execute_proposal_data)
+            }
+            GovernanceInstruction::ExecuteOperatorMultiInstructionProposal(
+                execute_proposal_data,
+            ) => execute_operator_proposal::process(program_id, accounts, &execute_proposal_data),
         }
     }
 }
@@ -0,0 +1,86 @@
+//! Upgrade a program through the BPF upgradeable loader.
+//!
+//! Only this program can call this instruction via a previous scheduled GMP
+//! proposal, coming from the Axelar governance infrastructure. The config PDA
+//! must be the upgrade authority of both the program and the buffer.
+use program_utils::pda::ValidPDA;
+use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::bpf_loader_upgradeable;
+use solana_program::msg;
+use solana_program::program::invoke_signed;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+use crate::seed_prefixes;
+use crate::state::upgrade::ensure_buffer_matches_hash;
+use crate::state::GovernanceConfig;
+
+use super::ensure_valid_governance_root_pda;
+
+/// Upgrades the given program with the contents of the buffer account, if
+/// they match the approved buffer hash. Only the contract itself can call
+/// this instruction.
+///
+/// # Errors
+///
+/// This function will return a [`ProgramError`] if any of the subcmds fail.
+pub(crate) fn process(
+    _program_id: &Pubkey,
+    accounts: &[AccountInfo<'_>],
+    program: [u8; 32],
+    buffer_hash: [u8; 32],
+) -> Result<(), ProgramError> {
+    let accounts_iter = &mut accounts.iter();
+    let config_pda = next_account_info(accounts_iter)?;
+    let program_data = next_account_info(accounts_iter)?;
+    let program_account = next_account_info(accounts_iter)?;
+    let buffer = next_account_info(accounts_iter)?;
+    let spill = next_account_info(accounts_iter)?;
+    let rent_sysvar = next_account_info(accounts_iter)?;
+    let clock_sysvar = next_account_info(accounts_iter)?;
+    let loader_program = next_account_info(accounts_iter)?;
+
+    if !config_pda.is_signer {
+        msg!("Only the contract itself can call this instruction");
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    let config_data = config_pda.check_initialized_pda::<GovernanceConfig>(&crate::id())?;
+
+    ensure_valid_governance_root_pda(config_data.bump, config_pda.key)?;
+
+    if program_account.key.to_bytes() != program {
+        msg!("Program account doesn't match the program to upgrade");
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    if loader_program.key != &bpf_loader_upgradeable::ID {
+        msg!("Invalid upgradeable loader program account");
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    ensure_buffer_matches_hash(buffer, &buffer_hash)?;
+
+    invoke_signed(
+        &bpf_loader_upgradeable::upgrade(
+            program_account.key,
+            buffer.key,
+            config_pda.key,
+            spill.key,
+        ),
+        &[
+            program_data.clone(),
+            program_account.clone(),
+            buffer.clone(),
+            spill.clone(),
+            rent_sysvar.clone(),
+            clock_sysvar.clone(),
+            config_pda.clone(),
+            loader_program.clone(),
+        ],
+        &[&[seed_prefixes::GOVERNANCE_CONFIG, &[config_data.bump]]],
+    )?;
+
+    msg!("Program {} was upgraded", program_account.key);
+    Ok(())
+}
@@ -0,0 +1,137 @@
+//! Outbound acknowledgements configuration.
+//!
+//! When configured, the governance program reports back to the Axelar
+//! governance contract the proposals it executes, by sending a
+//! [`governance_gmp::GovernanceAcknowledgementPayload`] through the gateway.
+//! The gas of such messages is paid by the gas budget PDA, a system owned
+//! account anyone can fund.
+
+use alloy_sol_types::SolValue;
+use borsh::{BorshDeserialize, BorshSerialize};
+use governance_gmp::alloy_primitives::Uint;
+use governance_gmp::{GovernanceAcknowledgement, GovernanceAcknowledgementPayload};
+use program_utils::pda::BorshPda;
+use solana_program::account_info::AccountInfo;
+use solana_program::msg;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+use crate::seed_prefixes;
+use crate::state::proposal::ExecuteProposalData;
+
+/// Where acknowledgements are sent to, and how much gas is paid for each one.
+#[derive(Debug, Eq, PartialEq, Clone, BorshSerialize, BorshDeserialize)]
+pub struct AcknowledgementConfig {
+    /// The bump for this account.
+    pub bump: u8,
+    /// The bump of the gas budget PDA.
+    pub gas_budget_bump: u8,
+    /// The governance chain acknowledgements are sent to. Its hash must match
+    /// the [`crate::state::GovernanceConfig::chain_hash`].
+    pub destination_chain: String,
+    /// The governance contract acknowledgements are sent to. Its hash must
+    /// match the [`crate::state::GovernanceConfig::address_hash`].
+    pub destination_address: String,
+    /// The gas service config PDA the gas is paid to.
+    pub gas_service_config: [u8; 32],
+    /// The gas (lamports) paid for each acknowledgement.
+    pub gas_value: u64,
+}
+
+impl BorshPda for AcknowledgementConfig {}
+
+impl AcknowledgementConfig {
+    /// Calculate the acknowledgement config PDA.
+    #[must_use]
+    pub fn pda() -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[seed_prefixes::ACKNOWLEDGEMENT_CONFIG], &crate::ID)
+    }
+
+    /// Calculate the gas budget PDA.
+    #[must_use]
+    pub fn gas_budget_pda() -> (Pubkey, u8) {
+        Pubkey::find_program_address(&[seed_prefixes::GAS_BUDGET], &crate::ID)
+    }
+
+    /// Calculate the gas payment ledger PDA of the acknowledgement with the
+    /// given `payload`. Its gas is paid by the gas budget PDA, which is also
+    /// its refund address.
+    #[must_use]
+    pub fn gas_payment_pda(&self, payload: &[u8]) -> (Pubkey, u8) {
+        axelar_solana_gas_service::get_gas_payment_pda(
+            &axelar_solana_gas_service::ID,
+            &Pubkey::new_from_array(self.gas_service_config),
+            &axelar_solana_gas_service::NATIVE_GAS_TOKEN,
+            &axelar_solana_gas_service::gas_payment_key(
+                &self.destination_chain,
+                &self.destination_address,
+                &solana_program::keccak::hash(payload).to_bytes(),
+            ),
+            &Self::gas_budget_pda().0,
+        )
+    }
+
+    /// The payload acknowledging the execution of the proposal with the given
+    /// data.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ProgramError` if the total native value of the proposal
+    /// overflows.
+    pub fn proposal_executed_payload(
+        execute_proposal_data: &ExecuteProposalData,
+    ) -> Result<Vec<u8>, ProgramError> {
+        Ok(GovernanceAcknowledgementPayload {
+            acknowledgement: GovernanceAcknowledgement::ProposalExecuted,
+            proposal_hash: execute_proposal_data.hash().into(),
+            target: execute_proposal_data.target_address().into(),
+            call_data: execute_proposal_data.encoded_call_data().into(),
+            native_value: Uint::from(execute_proposal_data.total_native_value()?),
+        }
+        .abi_encode())
+    }
+
+    /// Loads the acknowledgement config from the given account, returning
+    /// `None` if acknowledgements were never configured.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ProgramError` and logs a message if the account is not the
+    /// acknowledgement config PDA, or its data cannot be deserialized.
+    pub fn load_if_configured(account: &AccountInfo<'_>) -> Result<Option<Self>, ProgramError> {
+        let (expected_pda, _) = Self::pda();
+        if account.key != &expected_pda {
+            msg!("Invalid acknowledgement config pda");
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        if account.data_is_empty() {
+            return Ok(None);
+        }
+
+        if account.owner != &crate::ID {
+            msg!("Acknowledgement config pda is not owned by the governance program");
+            return Err(ProgramError::IllegalOwner);
+        }
+
+        Self::load(account).map(Some)
+    }
+
+    /// Ensures the given account is the gas budget PDA.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ProgramError` and logs a message if the account is not the
+    /// gas budget PDA.
+    pub fn ensure_gas_budget_pda(&self, account: &AccountInfo<'_>) -> Result<(), ProgramError> {
+        let expected_pda = Pubkey::create_program_address(
+            &[seed_prefixes::GAS_BUDGET, &[self.gas_budget_bump]],
+            &crate::ID,
+        )?;
+        if account.key != &expected_pda {
+            msg!("Invalid gas budget pda");
+            return Err(ProgramError::InvalidArgument);
+        }
+        Ok(())
+    }
+}
@@ -12,8 +12,10 @@ use solana_program::{
     program_pack::{Pack, Sealed},
 };
 
+pub mod acknowledgement;
 pub mod operator;
 pub mod proposal;
+pub mod upgrade;
 
 type Hash = [u8; 32];
 /// The [`solana_program::pubkey::Pubkey`] bytes.
@@ -22,7 +22,6 @@ use solana_program::program_error::ProgramError;
 use solana_program::program_pack::{Pack, Sealed};
 use solana_program::pubkey::Pubkey;
 
-type Uint256 = [u8; 32];
 type Hash = [u8; 32];
 
 /// This represents a proposal at the moment of it's storage. As we are using
@@ -55,38 +54,37 @@ impl ExecutableProposal {
 
     /// Calculates the hash for the proposal.
     ///
+    /// A proposal with a single instruction is hashed as the instruction
+    /// itself, the way proposals were hashed before they could hold several
+    /// instructions, so the proposals scheduled before keep their PDAs.
+    /// Otherwise, each instruction is hashed on its own, and the proposal hash
+    /// is the hash of the instruction hashes, in the order the instructions are
+    /// executed.
+    ///
     /// # Arguments
     ///
-    /// * `target` - The target program's public key.
-    /// * `call_data` - The call data required to execute the proposal.
-    /// * `native_value` - The native value associated with the proposal.
+    /// * `instructions` - The instructions the proposal executes.
     ///
     /// # Returns
     ///
     /// A 32-byte array representing the hash of the proposal.
-    ///
-    /// # Panics
-    ///
-    /// Panics if the serialization of the call data fails. But it shouldn't.
     #[must_use]
-    pub fn calculate_hash(
-        target: &Pubkey,
-        call_data: &ExecuteProposalCallData,
-        native_value: &Uint256,
-    ) -> Hash {
-        let sol_accounts_ser = borsh::to_vec(&call_data.solana_accounts)
-            .expect("Solana accounts serialization failed");
-        let native_value_ser = borsh::to_vec(&call_data.solana_native_value_receiver_account)
-            .expect("Solana native value receiver account serialization failed");
-        let call_data_ser = &call_data.call_data;
+    pub fn calculate_hash(instructions: &[ProposalInstruction]) -> Hash {
+        if let [instruction] = instructions {
+            return instruction.hash();
+        }
 
-        hashv(&[
-            &target.to_bytes(),
-            sol_accounts_ser.as_ref(),
-            native_value_ser.as_ref(),
-            call_data_ser,
-            native_value,
-        ])
+        let instruction_hashes = instructions
+            .iter()
+            .map(ProposalInstruction::hash)
+            .collect::<Vec<_>>();
+
+        hashv(
+            &instruction_hashes
+                .iter()
+                .map(AsRef::as_ref)
+                .collect::<Vec<&[u8]>>(),
+        )
         .to_bytes()
     }
 
@@ -251,20 +249,16 @@ impl ExecutableProposal {
         Ok(current_time()? >= self.eta)
     }
 
-    /// Executes the proposal by invoking the target program.
-    /// This function will only execute the proposal if the proposal is
-    /// unlocked and any other security checks pass.
+    /// Executes the proposal by invoking the target programs of all its
+    /// instructions, in order. This function will only execute the proposal if
+    /// the proposal is unlocked and any other security checks pass.
     ///
     /// # Arguments
     ///
-    /// * `target_program_accounts` - The target program accounts.
+    /// * `target_program_accounts` - The accounts of all the instructions.
     /// * `config_pda` - The config PDA account information.
     /// * `config_pda_bump` - The config PDA bump value.
-    /// * `target_address` - The target address.
-    /// * `call_data` - The execute proposal call data.
-    /// * `target_native_value_account_info` - The target native value account
-    ///   information.
-    /// * `native_value` - The native value.
+    /// * `instructions` - The instructions of the proposal.
     ///
     /// # Returns
     ///
@@ -272,17 +266,13 @@ impl ExecutableProposal {
     ///
     /// # Errors
     ///
-    /// An error if the execution fails.
-    #[allow(clippy::too_many_arguments)] // Todo: Yeah, but fix it.
+    /// An error if the execution of any instruction fails.
     pub fn checked_execute(
         &self,
         target_program_accounts: &[AccountInfo<'_>],
         config_pda: &AccountInfo<'_>,
         config_pda_bump: u8,
-        target_address: Pubkey,
-        call_data: ExecuteProposalCallData,
-        target_native_value_account_info: Option<&AccountInfo<'_>>,
-        native_value: u64,
+        instructions: &[ProposalInstruction],
     ) -> Result<(), ProgramError> {
         if !self.is_unlocked()? {
             // Todo add in the err message WHEN should be able to execute.
@@ -294,65 +284,62 @@ impl ExecutableProposal {
             target_program_accounts,
             config_pda,
             config_pda_bump,
-            target_address,
-            call_data,
-            target_native_value_account_info,
-            native_value,
+            instructions,
         )
     }
 
-    #[allow(clippy::too_many_arguments)] // Todo: Yeah, but fix it.
     fn execute(
         target_program_accounts: &[AccountInfo<'_>],
         config_pda: &AccountInfo<'_>,
         config_pda_bump: u8,
-        target_address: Pubkey,
-        call_data: ExecuteProposalCallData,
-        target_native_value_account_info: Option<&AccountInfo<'_>>,
-        native_value: u64,
+        instructions: &[ProposalInstruction],
     ) -> Result<(), ProgramError> {
-        if native_value > 0 {
-            let target_native_value_account_info = target_native_value_account_info
-                .ok_or(ProgramError::InvalidArgument)
-                .map_err(|err| {
-                    msg!("Failed to get target native value account info: {}", err);
-                    err
-                })?;
-            transfer_lamports(config_pda, target_native_value_account_info, native_value)?;
+        // A failing instruction fails the whole transaction, so either all the
+        // instructions of the proposal are executed or none.
+        for instruction in instructions {
+            let native_value = instruction.native_value()?;
+            if native_value > 0 {
+                let target_native_value_account_info = instruction
+                    .find_target_native_value_account_info(target_program_accounts)
+                    .ok_or(ProgramError::InvalidArgument)
+                    .map_err(|err| {
+                        msg!("Failed to get target native value account info: {}", err);
+                        err
+                    })?;
+                transfer_lamports(config_pda, target_native_value_account_info, native_value)?;
+            }
+
+            let mut accounts = instruction
+                .call_data
+                .solana_accounts
+                .iter()
+                .map(AccountMeta::from)
+                .collect::<Vec<AccountMeta>>();
+            accounts.push(AccountMeta::new_readonly(*config_pda.key, false)); // See https://github.com/solana-labs/solana/issues/9711#issuecomment-2342809295
+
+            // Invoke the target program.
+            solana_program::program::invoke_signed(
+                &Instruction {
+                    program_id: Pubkey::new_from_array(instruction.target_address),
+                    accounts,
+                    data: instruction.call_data.call_data.clone(),
+                },
+                target_program_accounts,
+                &[&[seed_prefixes::GOVERNANCE_CONFIG, &[config_pda_bump]]],
+            )?;
         }
 
-        let mut accounts = call_data
-            .solana_accounts
-            .iter()
-            .map(AccountMeta::from)
-            .collect::<Vec<AccountMeta>>();
-        accounts.push(AccountMeta::new_readonly(*config_pda.key, false)); // See https://github.com/solana-labs/solana/issues/9711#issuecomment-2342809295
-
-        // Invoke the target program.
-        solana_program::program::invoke_signed(
-            &Instruction {
-                program_id: target_address,
-                accounts,
-                data: call_data.call_data,
-            },
-            target_program_accounts,
-            &[&[seed_prefixes::GOVERNANCE_CONFIG, &[config_pda_bump]]],
-        )
+        Ok(())
     }
 
     /// Executes the proposal without checking if it is unlocked.
     ///
     /// # Arguments
     ///
-    /// * `target_program_accounts` - The accounts required by the target
-    ///   program.
+    /// * `target_program_accounts` - The accounts of all the instructions.
     /// * `config_pda` - The account info for the governance config PDA.
     /// * `config_pda_bump` - The bump seed for the governance config PDA.
-    /// * `target_address` - The address of the target program.
-    /// * `call_data` - The call data for the target program.
-    /// * `target_native_value_account_info` - The account info for the target
-    ///   native value account (optional).
-    /// * `native_value` - The native value to transfer (optional).
+    /// * `instructions` - The instructions of the proposal.
     ///
     /// # Returns
     ///
@@ -360,26 +347,19 @@ impl ExecutableProposal {
     ///
     /// # Errors
     ///
-    /// Returns a `ProgramError` if the execution fails.
-    #[allow(clippy::too_many_arguments)] // Todo: Yeah, but fix it.
+    /// Returns a `ProgramError` if the execution of any instruction fails.
     pub fn unchecked_execute(
         &self,
         target_program_accounts: &[AccountInfo<'_>],
         config_pda: &AccountInfo<'_>,
         config_pda_bump: u8,
-        target_address: Pubkey,
-        call_data: ExecuteProposalCallData,
-        target_native_value_account_info: Option<&AccountInfo<'_>>,
-        native_value: u64,
+        instructions: &[ProposalInstruction],
     ) -> Result<(), ProgramError> {
         Self::execute(
             target_program_accounts,
             config_pda,
             config_pda_bump,
-            target_address,
-            call_data,
-            target_native_value_account_info,
-            native_value,
+            instructions,
         )
     }
 
@@ -443,25 +423,156 @@ impl Pack for ExecutableProposal {
 
 #[derive(Debug, Eq, PartialEq, Clone, BorshSerialize, BorshDeserialize)]
 /// Represents the data required to execute a proposal.
-/// This struct is only used by the execute proposal instruction (See
-/// [`crate::instructions::send_execute_proposal`]).
+/// This struct is used by the execute proposal instructions (See
+/// [`crate::instructions::GovernanceInstruction::ExecuteMultiInstructionProposal`]).
+///
+/// Proposals with a single instruction keep the format they had before they
+/// could hold several instructions: the
+/// [`governance_gmp::GovernanceCommandPayload`] of their GMP commands targets
+/// the program of the instruction, with the borsh encoded
+/// [`ExecuteProposalCallData`] as call data. Otherwise, the payload targets the
+/// governance program, which executes the instructions, with the borsh encoded
+/// `ExecuteProposalData` as call data. See [`Self::target_address`] and
+/// [`Self::encoded_call_data`].
 pub struct ExecuteProposalData {
-    /// The target program address for the proposal, represented as a 32-byte
-    /// array. Will be later converted to a [`solana_program::pubkey::Pubkey`].
+    /// The instructions of the proposal, executed atomically in this order.
+    pub instructions: Vec<ProposalInstruction>,
+}
+
+impl ExecuteProposalData {
+    /// # Returns
+    ///
+    /// A new `ExecuteProposalData` instance.
+    #[must_use]
+    pub const fn new(instructions: Vec<ProposalInstruction>) -> Self {
+        Self { instructions }
+    }
+
+    /// Calculates the hash of the proposal. See
+    /// [`ExecutableProposal::calculate_hash`].
+    #[must_use]
+    pub fn hash(&self) -> Hash {
+        ExecutableProposal::calculate_hash(&self.instructions)
+    }
+
+    /// Decodes a proposal from the target, call data and native value of the
+    /// [`governance_gmp::GovernanceCommandPayload`] of its GMP commands. See
+    /// [`Self`] for the formats.
+    ///
+    /// A payload targeting the governance program is decoded as a proposal
+    /// with several instructions, unless its call data is the one of a single
+    /// instruction, as in proposals calling the governance program itself.
+    /// Proposals with a single instruction only have the format they had
+    /// before, so that each proposal has a single payload.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ProgramError` and logs a message if the call data cannot be
+    /// deserialized, or holds fewer than two instructions in the format of
+    /// proposals with several instructions.
+    pub fn from_payload(
+        target: &Pubkey,
+        call_data: &[u8],
+        native_value: [u8; 32],
+    ) -> Result<Self, ProgramError> {
+        if *target == ID {
+            if let Ok(proposal_data) = borsh::from_slice::<Self>(call_data) {
+                if proposal_data.instructions.len() < 2 {
+                    msg!("A proposal with several instructions must have at least two");
+                    return Err(ProgramError::InvalidArgument);
+                }
+                return Ok(proposal_data);
+            }
+        }
+
+        let call_data = borsh::from_slice::<ExecuteProposalCallData>(call_data).map_err(|err| {
+            msg!("Cannot deserialize ExecuteProposalCallData: {}", err);
+            ProgramError::InvalidArgument
+        })?;
+
+        Ok(Self::new(vec![ProposalInstruction::new(
+            target.to_bytes(),
+            call_data,
+            native_value,
+        )]))
+    }
+
+    /// The target address of the proposal, as in the
+    /// [`governance_gmp::GovernanceCommandPayload::target`] of its GMP
+    /// commands. It's the program of the instruction for proposals with a
+    /// single instruction, and the governance program otherwise.
+    #[must_use]
+    pub fn target_address(&self) -> [u8; 32] {
+        match self.instructions.as_slice() {
+            [instruction] => instruction.target_address,
+            _ => ID.to_bytes(),
+        }
+    }
+
+    /// The call data of the proposal, as in the
+    /// [`governance_gmp::GovernanceCommandPayload::call_data`] of its GMP
+    /// commands. It's the borsh encoded [`ExecuteProposalCallData`] of the
+    /// instruction for proposals with a single instruction, and the borsh
+    /// encoded `ExecuteProposalData` otherwise.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the serialization of the call data fails. But it shouldn't.
+    #[must_use]
+    pub fn encoded_call_data(&self) -> Vec<u8> {
+        match self.instructions.as_slice() {
+            [instruction] => borsh::to_vec(&instruction.call_data),
+            _ => borsh::to_vec(self),
+        }
+        .expect("Proposal call data serialization failed")
+    }
+
+    /// The total native value (lamports) sent by the instructions of the
+    /// proposal.
+    ///
+    /// # Errors
+    ///
+    /// Returns a `ProgramError` and logs a message if a native value doesn't
+    /// fit in a `u64` or the total overflows.
+    pub fn total_native_value(&self) -> Result<u64, ProgramError> {
+        self.instructions
+            .iter()
+            .try_fold(0_u64, |total, instruction| {
+                total
+                    .checked_add(instruction.native_value()?)
+                    .ok_or_else(|| {
+                        msg!("Total native value of the proposal overflows");
+                        ProgramError::ArithmeticOverflow
+                    })
+            })
+    }
+}
+
+#[derive(Debug, Eq, PartialEq, Clone, BorshSerialize, BorshDeserialize)]
+/// A single instruction of a proposal, which the governance program invokes
+/// as a CPI when executing the proposal.
+///
+/// It's encoded as the proposal data was before proposals could hold several
+/// instructions, so it's the data of the execute proposal instructions of
+/// single-instruction proposals (See
+/// [`crate::instructions::GovernanceInstruction::ExecuteProposal`]).
+pub struct ProposalInstruction {
+    /// The target program address for the instruction, represented as a
+    /// 32-byte array. Will be later converted to a
     /// [`solana_program::pubkey::Pubkey`] when executing the proposal.
     pub target_address: [u8; 32],
     /// The data required to call the target program.
     pub call_data: ExecuteProposalCallData,
     /// A 32-byte array representing the native token U256 value (lamports)
-    /// associated with the proposal. This is a U256 value and should be casted
+    /// sent by the instruction. This is a U256 value and should be casted
     /// to u 64
     pub native_value: [u8; 32],
 }
 
-impl ExecuteProposalData {
+impl ProposalInstruction {
     /// # Returns
     ///
-    /// A new `ExecuteProposalData` instance.
+    /// A new `ProposalInstruction` instance.
     #[must_use]
     pub const fn new(
         target_address: [u8; 32],
@@ -475,6 +586,30 @@ impl ExecuteProposalData {
         }
     }
 
+    /// Calculates the hash of the instruction, from its target address, call
+    /// data and native value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the serialization of the call data fails. But it shouldn't.
+    #[must_use]
+    pub fn hash(&self) -> Hash {
+        let sol_accounts_ser = borsh::to_vec(&self.call_data.solana_accounts)
+            .expect("Solana accounts serialization failed");
+        let native_value_ser = borsh::to_vec(&self.call_data.solana_native_value_receiver_account)
+            .expect("Solana native value receiver account serialization failed");
+        let call_data_ser = &self.call_data.call_data;
+
+        hashv(&[
+            &self.target_address,
+            sol_accounts_ser.as_ref(),
+            native_value_ser.as_ref(),
+            call_data_ser,
+            &self.native_value,
+        ])
+        .to_bytes()
+    }
+
     /// Returns the target native value account info if the native value is
     /// greater than 0 and it can be found in the accounts slice. Otherwise,
     /// returns `None`.
@@ -514,8 +649,9 @@ impl ExecuteProposalData {
 }
 
 /// Represents the call data required to execute the target program of a
-/// proposal. This struct will be encoded as a byte array and added to the
-/// [`governance_gmp::GovernanceCommandPayload::call_data`] instruction.
+/// proposal instruction. This struct is encoded as part of the
+/// [`ExecuteProposalData`] in the
+/// [`governance_gmp::GovernanceCommandPayload::call_data`] field.
 ///
 /// The Axelar governance infrastructure will use this struct to craft the GMP
 /// message, meaning it should be aware of the Solana accounts needed by the
@@ -0,0 +1,57 @@
+//! Program upgrade related validations.
+//!
+//! Program upgrade proposals approve the hash of the buffer holding the new
+//! program bytes, so the buffer contents cannot be swapped between the
+//! proposal approval and its execution.
+
+use solana_program::account_info::AccountInfo;
+use solana_program::bpf_loader_upgradeable::{self, UpgradeableLoaderState};
+use solana_program::keccak::hash;
+use solana_program::msg;
+use solana_program::program_error::ProgramError;
+
+type Hash = [u8; 32];
+
+/// Calculates the hash of the program bytes stored in an upgradeable loader
+/// buffer. This is the hash a program upgrade proposal must approve.
+///
+/// The program bytes are all the buffer data after the buffer metadata. When
+/// the buffer was created with extra space, the trailing zeroes are part of
+/// them.
+#[must_use]
+pub fn buffer_hash(program_bytes: &[u8]) -> Hash {
+    hash(program_bytes).to_bytes()
+}
+
+/// Ensures the given buffer account is an upgradeable loader buffer whose
+/// program bytes match the approved hash.
+///
+/// # Errors
+///
+/// Returns a `ProgramError` and logs a message if the account is not owned by
+/// the upgradeable loader, its data is not a buffer or its hash doesn't match
+/// `expected_hash`.
+pub fn ensure_buffer_matches_hash(
+    buffer: &AccountInfo<'_>,
+    expected_hash: &Hash,
+) -> Result<(), ProgramError> {
+    if buffer.owner != &bpf_loader_upgradeable::ID {
+        msg!("Buffer account is not owned by the upgradeable loader");
+        return Err(ProgramError::IncorrectProgramId);
+    }
+
+    let data = buffer.try_borrow_data()?;
+    let program_bytes = data
+        .get(UpgradeableLoaderState::size_of_buffer_metadata()..)
+        .ok_or_else(|| {
+            msg!("Buffer account data is too small");
+            ProgramError::InvalidAccountData
+        })?;
+
+    if buffer_hash(program_bytes) != *expected_hash {
+        msg!("Buffer doesn't match the approved hash");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    Ok(())
+}
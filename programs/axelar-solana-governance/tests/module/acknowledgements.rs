@@ -0,0 +1,341 @@
+use axelar_solana_gas_service::state::{GasPayment, GasReserve};
+use axelar_solana_gateway_test_fixtures::gas_service::GasServiceUtils;
+use axelar_solana_gateway_test_fixtures::SolanaAxelarIntegrationMetadata;
+use axelar_solana_governance::instructions::builder::{
+    configure_acknowledgements, configure_acknowledgements_proposal_instruction, IxBuilder,
+};
+use axelar_solana_governance::state::acknowledgement::AcknowledgementConfig;
+use borsh::BorshDeserialize;
+use solana_program_test::tokio;
+use solana_sdk::instruction::AccountMeta;
+use solana_sdk::native_token::LAMPORTS_PER_SOL;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
+
+use crate::fixtures::{SOURCE_CHAIN_ADDRESS, SOURCE_CHAIN_NAME};
+use crate::helpers::{
+    approve_ix_at_gateway, assert_msg_present_in_logs, default_proposal_eta, gmp_memo_metadata,
+    ix_builder_with_memo_proposal_data, setup_programs,
+};
+
+const GAS_VALUE: u64 = 1_000_000;
+
+async fn setup_acknowledgements(
+    sol_integration: &mut SolanaAxelarIntegrationMetadata,
+) -> (GasServiceUtils, AcknowledgementConfig) {
+    let gas_utils = sol_integration.fixture.deploy_gas_service().await;
+    sol_integration
+        .fixture
+        .init_gas_config(&gas_utils)
+        .await
+        .unwrap();
+
+    let ix = configure_acknowledgements(
+        &sol_integration.fixture.payer.pubkey(),
+        SOURCE_CHAIN_NAME.to_string(),
+        SOURCE_CHAIN_ADDRESS.to_string(),
+        &gas_utils.config_pda,
+        GAS_VALUE,
+    );
+    let res = sol_integration.fixture.send_tx(&[ix]).await;
+    assert!(res.is_ok());
+
+    let acknowledgement_config = sol_integration
+        .fixture
+        .get_account(
+            &AcknowledgementConfig::pda().0,
+            &axelar_solana_governance::ID,
+        )
+        .await;
+    let acknowledgement_config =
+        AcknowledgementConfig::try_from_slice(&acknowledgement_config.data).unwrap();
+
+    (gas_utils, acknowledgement_config)
+}
+
+async fn schedule_memo_proposal(
+    sol_integration: &mut SolanaAxelarIntegrationMetadata,
+    config_pda: &Pubkey,
+    counter_pda: Pubkey,
+) -> IxBuilder<axelar_solana_governance::instructions::builder::ProposalRelated> {
+    let (memo_signing_pda, _) =
+        axelar_solana_gateway::get_call_contract_signing_pda(axelar_solana_memo_program::ID);
+    let memo_program_accounts = &[
+        AccountMeta::new_readonly(axelar_solana_memo_program::id(), false),
+        AccountMeta::new_readonly(counter_pda, false),
+        AccountMeta::new_readonly(memo_signing_pda, false),
+        AccountMeta::new_readonly(sol_integration.gateway_root_pda, false),
+        AccountMeta::new_readonly(axelar_solana_gateway::id(), false),
+        AccountMeta::new_readonly(sol_integration.fixture.payer.pubkey(), true),
+    ];
+
+    let ix_builder = ix_builder_with_memo_proposal_data(memo_program_accounts, 0, None);
+    let mut gmp_call_data = ix_builder
+        .clone()
+        .gmp_ix()
+        .with_msg_metadata(gmp_memo_metadata())
+        .schedule_time_lock_proposal(&sol_integration.fixture.payer.pubkey(), config_pda)
+        .build();
+    approve_ix_at_gateway(sol_integration, &mut gmp_call_data).await;
+    let res = sol_integration.fixture.send_tx(&[gmp_call_data.ix]).await;
+    assert!(res.is_ok());
+
+    sol_integration
+        .fixture
+        .set_time(default_proposal_eta() as i64)
+        .await;
+
+    ix_builder
+}
+
+#[tokio::test]
+async fn test_executed_proposal_is_acknowledged_to_the_governance_chain() {
+    let (mut sol_integration, config_pda, counter_pda) = Box::pin(setup_programs()).await;
+    let (gas_utils, acknowledgement_config) = setup_acknowledgements(&mut sol_integration).await;
+
+    let (gas_budget_pda, _) = AcknowledgementConfig::gas_budget_pda();
+    sol_integration
+        .fixture
+        .fund_account(&gas_budget_pda, LAMPORTS_PER_SOL)
+        .await;
+
+    let ix_builder = Box::pin(schedule_memo_proposal(
+        &mut sol_integration,
+        &config_pda,
+        counter_pda,
+    ))
+    .await;
+
+    let gas_service_balance_before = sol_integration
+        .fixture
+        .get_balance(&gas_utils.config_pda)
+        .await;
+
+    let payload =
+        AcknowledgementConfig::proposal_executed_payload(&ix_builder.proposal_data()).unwrap();
+    let (gas_payment_pda, _) = acknowledgement_config.gas_payment_pda(&payload);
+    let ix = ix_builder
+        .execute_proposal(&config_pda)
+        .with_acknowledgement(&sol_integration.gateway_root_pda, &acknowledgement_config)
+        .build();
+    let res = sol_integration.fixture.send_tx(&[ix]).await;
+    assert!(res.is_ok());
+
+    // The gas budget also funds the gas payment ledger of the acknowledgement
+    // and the gas reserve of native SOL on the first payment.
+    let gas_payment_rent = sol_integration
+        .fixture
+        .get_rent(core::mem::size_of::<GasPayment>())
+        .await;
+    let gas_reserve_rent = sol_integration
+        .fixture
+        .get_rent(core::mem::size_of::<GasReserve>())
+        .await;
+    assert_eq!(
+        sol_integration.fixture.get_balance(&gas_budget_pda).await,
+        LAMPORTS_PER_SOL - GAS_VALUE - gas_payment_rent - gas_reserve_rent
+    );
+    assert_eq!(
+        sol_integration
+            .fixture
+            .gas_payment_state(gas_payment_pda)
+            .await
+            .paid,
+        GAS_VALUE
+    );
+    assert_eq!(
+        sol_integration
+            .fixture
+            .get_balance(&gas_utils.config_pda)
+            .await,
+        gas_service_balance_before + GAS_VALUE
+    );
+}
+
+#[tokio::test]
+async fn test_proposal_is_executed_without_acknowledgement_if_gas_budget_is_not_enough() {
+    let (mut sol_integration, config_pda, counter_pda) = Box::pin(setup_programs()).await;
+    let (_, acknowledgement_config) = setup_acknowledgements(&mut sol_integration).await;
+
+    let ix_builder = Box::pin(schedule_memo_proposal(
+        &mut sol_integration,
+        &config_pda,
+        counter_pda,
+    ))
+    .await;
+
+    let ix = ix_builder
+        .execute_proposal(&config_pda)
+        .with_acknowledgement(&sol_integration.gateway_root_pda, &acknowledgement_config)
+        .build();
+    let res = sol_integration.fixture.send_tx(&[ix]).await;
+    assert!(res.is_ok());
+    assert_msg_present_in_logs(
+        res.unwrap(),
+        "Not enough gas budget, the acknowledgement is not sent",
+    );
+}
+
+#[tokio::test]
+async fn test_proposal_is_executed_without_acknowledgement_if_accounts_are_omitted() {
+    let (mut sol_integration, config_pda, counter_pda) = Box::pin(setup_programs()).await;
+    setup_acknowledgements(&mut sol_integration).await;
+
+    let (gas_budget_pda, _) = AcknowledgementConfig::gas_budget_pda();
+    sol_integration
+        .fixture
+        .fund_account(&gas_budget_pda, LAMPORTS_PER_SOL)
+        .await;
+
+    let ix_builder = Box::pin(schedule_memo_proposal(
+        &mut sol_integration,
+        &config_pda,
+        counter_pda,
+    ))
+    .await;
+
+    let ix = ix_builder.execute_proposal(&config_pda).build();
+    let res = sol_integration.fixture.send_tx(&[ix]).await;
+    assert!(res.is_ok());
+    assert_msg_present_in_logs(
+        res.unwrap(),
+        "No acknowledgement accounts provided, the acknowledgement is not sent",
+    );
+    assert_eq!(
+        sol_integration.fixture.get_balance(&gas_budget_pda).await,
+        LAMPORTS_PER_SOL
+    );
+}
+
+#[tokio::test]
+async fn test_proposal_is_not_executed_if_acknowledgement_accounts_are_invalid() {
+    let (mut sol_integration, config_pda, counter_pda) = Box::pin(setup_programs()).await;
+    let (_, acknowledgement_config) = setup_acknowledgements(&mut sol_integration).await;
+
+    let (gas_budget_pda, _) = AcknowledgementConfig::gas_budget_pda();
+    sol_integration
+        .fixture
+        .fund_account(&gas_budget_pda, LAMPORTS_PER_SOL)
+        .await;
+
+    let ix_builder = Box::pin(schedule_memo_proposal(
+        &mut sol_integration,
+        &config_pda,
+        counter_pda,
+    ))
+    .await;
+
+    let mut ix = ix_builder
+        .execute_proposal(&config_pda)
+        .with_acknowledgement(&sol_integration.gateway_root_pda, &acknowledgement_config)
+        .build();
+    // The gas service config PDA of the acknowledgement accounts.
+    let gas_service_config_index = ix.accounts.len() - 5;
+    ix.accounts[gas_service_config_index] = AccountMeta::new(Pubkey::new_unique(), false);
+    let res = sol_integration.fixture.send_tx(&[ix]).await;
+    assert!(res.is_err());
+    assert_msg_present_in_logs(res.err().unwrap(), "Invalid gas service config account");
+}
+
+#[tokio::test]
+async fn test_acknowledgements_can_only_be_sent_to_the_governance_chain() {
+    let (mut sol_integration, _, _) = Box::pin(setup_programs()).await;
+
+    let ix = configure_acknowledgements(
+        &sol_integration.fixture.payer.pubkey(),
+        "other-chain".to_string(),
+        SOURCE_CHAIN_ADDRESS.to_string(),
+        &Pubkey::new_unique(),
+        GAS_VALUE,
+    );
+    let res = sol_integration.fixture.send_tx(&[ix]).await;
+    assert!(res.is_err());
+    assert_msg_present_in_logs(
+        res.err().unwrap(),
+        "Acknowledgements can only be sent to the governance chain and address",
+    );
+}
+
+#[tokio::test]
+async fn test_acknowledgements_can_only_be_configured_by_the_upgrade_authority() {
+    let (mut sol_integration, _, _) = Box::pin(setup_programs()).await;
+
+    let not_upgrade_authority = Keypair::new();
+    sol_integration
+        .fixture
+        .fund_account(&not_upgrade_authority.pubkey(), LAMPORTS_PER_SOL)
+        .await;
+
+    let ix = configure_acknowledgements(
+        &not_upgrade_authority.pubkey(),
+        SOURCE_CHAIN_NAME.to_string(),
+        SOURCE_CHAIN_ADDRESS.to_string(),
+        &Pubkey::new_unique(),
+        GAS_VALUE,
+    );
+    let res = sol_integration
+        .fixture
+        .send_tx_with_custom_signers(
+            &[ix],
+            &[
+                not_upgrade_authority,
+                sol_integration.fixture.payer.insecure_clone(),
+            ],
+        )
+        .await;
+    assert!(res.is_err());
+}
+
+#[tokio::test]
+async fn test_acknowledgements_can_be_configured_through_a_proposal() {
+    let (mut sol_integration, config_pda, _) = Box::pin(setup_programs()).await;
+
+    let funder = Keypair::new();
+    sol_integration
+        .fixture
+        .fund_account(&funder.pubkey(), LAMPORTS_PER_SOL)
+        .await;
+
+    let ix_builder = IxBuilder::new()
+        .with_proposal_instructions(
+            vec![configure_acknowledgements_proposal_instruction(
+                &funder.pubkey(),
+                SOURCE_CHAIN_NAME.to_string(),
+                SOURCE_CHAIN_ADDRESS.to_string(),
+                &Pubkey::new_unique(),
+                GAS_VALUE,
+            )],
+            default_proposal_eta(),
+        )
+        .unwrap();
+    let mut gmp_call_data = ix_builder
+        .clone()
+        .gmp_ix()
+        .with_msg_metadata(gmp_memo_metadata())
+        .schedule_time_lock_proposal(&sol_integration.fixture.payer.pubkey(), &config_pda)
+        .build();
+    approve_ix_at_gateway(&mut sol_integration, &mut gmp_call_data).await;
+    let res = sol_integration.fixture.send_tx(&[gmp_call_data.ix]).await;
+    assert!(res.is_ok());
+
+    sol_integration
+        .fixture
+        .set_time(default_proposal_eta() as i64)
+        .await;
+
+    let ix = ix_builder.execute_proposal(&config_pda).build();
+    let payer = sol_integration.fixture.payer.insecure_clone();
+    let res = sol_integration
+        .fixture
+        .send_tx_with_custom_signers(&[ix], &[&payer, &funder])
+        .await;
+    assert!(res.is_ok());
+
+    let acknowledgement_config = sol_integration
+        .fixture
+        .try_get_account_no_checks(&AcknowledgementConfig::pda().0)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(acknowledgement_config.owner, axelar_solana_governance::ID);
+}
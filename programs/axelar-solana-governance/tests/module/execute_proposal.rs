@@ -1,16 +1,25 @@
+use alloy_sol_types::SolValue;
 use axelar_solana_gateway_test_fixtures::base::FindLog;
 use axelar_solana_governance::events::GovernanceEvent;
-use axelar_solana_governance::instructions::builder::{IxBuilder, ProposalRelated};
+use axelar_solana_governance::instructions::builder::{
+    proposal_instruction, IxBuilder, ProposalRelated,
+};
+use axelar_solana_governance::instructions::GovernanceInstruction;
+use axelar_solana_governance::state::proposal::{ExecuteProposalData, ProposalInstruction};
 use borsh::to_vec;
+use governance_gmp::alloy_primitives::Uint;
+use governance_gmp::{GovernanceCommand, GovernanceCommandPayload};
 use solana_program_test::tokio;
 use solana_sdk::instruction::AccountMeta;
+use solana_sdk::keccak::hash;
 use solana_sdk::native_token::LAMPORTS_PER_SOL;
-use solana_sdk::signature::Signer;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::{Keypair, Signer};
 
 use crate::helpers::{
     approve_ix_at_gateway, assert_msg_present_in_logs, default_proposal_eta, events,
     gmp_memo_metadata, gmp_sample_metadata, ix_builder_with_memo_proposal_data,
-    ix_builder_with_sample_proposal_data, setup_programs,
+    ix_builder_with_sample_proposal_data, memo_proposal_instruction, setup_programs,
 };
 
 #[tokio::test]
@@ -106,7 +115,7 @@ fn proposal_executed_event(builder: &IxBuilder<ProposalRelated>) -> GovernanceEv
     GovernanceEvent::ProposalExecuted {
         hash: builder.proposal_hash(),
         target_address: builder.proposal_target_address().to_bytes(),
-        call_data: to_vec(&builder.proposal_call_data()).unwrap(),
+        call_data: builder.proposal_call_data(),
         native_value: builder.proposal_u256_le_native_value(),
         eta: builder.proposal_u256_le_eta(),
     }
@@ -365,3 +374,211 @@ async fn test_cannot_create_proposal_twice() {
 
     assert_msg_present_in_logs(res.err().unwrap(), "Proposal already exists");
 }
+
+#[tokio::test]
+async fn test_multi_instruction_format_rejects_a_single_instruction() {
+    let (mut sol_integration, config_pda, counter_pda) = Box::pin(setup_programs()).await;
+
+    let memo_program_accounts = &[
+        AccountMeta::new_readonly(counter_pda, false),
+        AccountMeta::new_readonly(sol_integration.gateway_root_pda, false),
+        AccountMeta::new_readonly(axelar_solana_gateway::id(), false),
+        AccountMeta::new_readonly(axelar_solana_memo_program::id(), false),
+        AccountMeta::new_readonly(sol_integration.fixture.payer.pubkey(), true),
+    ];
+    let eta = default_proposal_eta();
+    let ix_builder = IxBuilder::new()
+        .with_proposal_instructions(vec![memo_proposal_instruction(memo_program_accounts)], eta)
+        .unwrap();
+
+    let meta = gmp_memo_metadata();
+    let mut gmp_call_data = ix_builder
+        .clone()
+        .gmp_ix()
+        .with_msg_metadata(meta.clone())
+        .schedule_time_lock_proposal(&sol_integration.fixture.payer.pubkey(), &config_pda)
+        .build();
+
+    // The single instruction is sent in the format of proposals with several
+    // instructions, which would hash as the proposal in its own format.
+    let payload = GovernanceCommandPayload {
+        command: GovernanceCommand::ScheduleTimeLockProposal,
+        target: axelar_solana_governance::ID.to_bytes().into(),
+        call_data: to_vec(&ExecuteProposalData::new(vec![memo_proposal_instruction(
+            memo_program_accounts,
+        )]))
+        .unwrap()
+        .into(),
+        native_value: Uint::from(0),
+        eta: Uint::from(eta),
+    }
+    .abi_encode();
+    gmp_call_data.msg_meta.payload_hash = hash(&payload).to_bytes();
+    gmp_call_data.msg_payload = payload;
+    gmp_call_data.ix.data = to_vec(&GovernanceInstruction::ProcessGmp {
+        message: gmp_call_data.msg_meta.clone(),
+    })
+    .unwrap();
+
+    approve_ix_at_gateway(&mut sol_integration, &mut gmp_call_data).await;
+    let res = sol_integration.fixture.send_tx(&[gmp_call_data.ix]).await;
+    assert!(res.is_err());
+    assert_msg_present_in_logs(
+        res.err().unwrap(),
+        "A proposal with several instructions must have at least two",
+    );
+}
+
+fn withdraw_tokens_proposal_instruction(
+    config_pda: &Pubkey,
+    funds_receiver: &Pubkey,
+    amount: u64,
+) -> ProposalInstruction {
+    proposal_instruction(
+        axelar_solana_governance::ID,
+        0,
+        None,
+        &[
+            AccountMeta::new_readonly(solana_sdk::system_program::ID, false),
+            AccountMeta::new(*config_pda, true),
+            AccountMeta::new(*funds_receiver, false),
+            AccountMeta::new_readonly(axelar_solana_governance::ID, false),
+        ],
+        to_vec(&GovernanceInstruction::WithdrawTokens { amount }).unwrap(),
+    )
+}
+
+#[tokio::test]
+async fn test_multi_instruction_proposal_executes_all_instructions() {
+    let (mut sol_integration, config_pda, counter_pda) = Box::pin(setup_programs()).await;
+    let funds_receiver = Keypair::new();
+
+    // Fund both accounts for avoiding rent exemption issues.
+    for account in [config_pda, funds_receiver.pubkey()] {
+        let ix = solana_sdk::system_instruction::transfer(
+            &sol_integration.fixture.payer.pubkey(),
+            &account,
+            LAMPORTS_PER_SOL,
+        );
+        let res = sol_integration.fixture.send_tx(&[ix]).await;
+        assert!(res.is_ok());
+    }
+
+    let (memo_signing_pda, _) =
+        axelar_solana_gateway::get_call_contract_signing_pda(axelar_solana_memo_program::ID);
+    let memo_program_accounts = &[
+        AccountMeta::new_readonly(axelar_solana_memo_program::id(), false),
+        AccountMeta::new_readonly(counter_pda, false),
+        AccountMeta::new_readonly(memo_signing_pda, false),
+        AccountMeta::new_readonly(sol_integration.gateway_root_pda, false),
+        AccountMeta::new_readonly(axelar_solana_gateway::id(), false),
+        AccountMeta::new_readonly(sol_integration.fixture.payer.pubkey(), true),
+    ];
+    let amount_to_withdraw = 1_000;
+    let ix_builder = IxBuilder::new()
+        .with_proposal_instructions(
+            vec![
+                memo_proposal_instruction(memo_program_accounts),
+                withdraw_tokens_proposal_instruction(
+                    &config_pda,
+                    &funds_receiver.pubkey(),
+                    amount_to_withdraw,
+                ),
+            ],
+            default_proposal_eta(),
+        )
+        .unwrap();
+
+    let meta = gmp_memo_metadata();
+    let mut gmp_call_data = ix_builder
+        .clone()
+        .gmp_ix()
+        .with_msg_metadata(meta.clone())
+        .schedule_time_lock_proposal(&sol_integration.fixture.payer.pubkey(), &config_pda)
+        .build();
+    approve_ix_at_gateway(&mut sol_integration, &mut gmp_call_data).await;
+    let res = sol_integration.fixture.send_tx(&[gmp_call_data.ix]).await;
+    assert!(res.is_ok());
+
+    sol_integration
+        .fixture
+        .set_time(default_proposal_eta() as i64)
+        .await;
+    let initial_receiver_funds = sol_integration.get_balance(&funds_receiver.pubkey()).await;
+
+    // Execute the proposal
+    let ix = ix_builder.clone().execute_proposal(&config_pda).build();
+    let res = sol_integration.fixture.send_tx(&[ix]).await;
+    assert!(res.is_ok());
+
+    // Assert both instructions were executed
+    let mut emitted_events = events(&res.clone().unwrap());
+    assert_eq!(emitted_events.len(), 1);
+    let got_event: GovernanceEvent = emitted_events.pop().unwrap().parse().unwrap();
+    assert_eq!(proposal_executed_event(&ix_builder), got_event);
+    assert_msg_present_in_logs(res.unwrap(), "Instruction: SendToGateway");
+    let new_receiver_funds = sol_integration.get_balance(&funds_receiver.pubkey()).await;
+    assert_eq!(
+        new_receiver_funds,
+        initial_receiver_funds + amount_to_withdraw
+    );
+}
+
+#[tokio::test]
+async fn test_multi_instruction_proposal_is_executed_atomically() {
+    let (mut sol_integration, config_pda, counter_pda) = Box::pin(setup_programs()).await;
+    let funds_receiver = Keypair::new();
+
+    let (memo_signing_pda, _) =
+        axelar_solana_gateway::get_call_contract_signing_pda(axelar_solana_memo_program::ID);
+    let memo_program_accounts = &[
+        AccountMeta::new_readonly(axelar_solana_memo_program::id(), false),
+        AccountMeta::new_readonly(counter_pda, false),
+        AccountMeta::new_readonly(memo_signing_pda, false),
+        AccountMeta::new_readonly(sol_integration.gateway_root_pda, false),
+        AccountMeta::new_readonly(axelar_solana_gateway::id(), false),
+        AccountMeta::new_readonly(sol_integration.fixture.payer.pubkey(), true),
+    ];
+    // The governance config PDA isn't funded, so the second instruction fails.
+    let ix_builder = IxBuilder::new()
+        .with_proposal_instructions(
+            vec![
+                memo_proposal_instruction(memo_program_accounts),
+                withdraw_tokens_proposal_instruction(&config_pda, &funds_receiver.pubkey(), 1),
+            ],
+            default_proposal_eta(),
+        )
+        .unwrap();
+
+    let meta = gmp_memo_metadata();
+    let mut gmp_call_data = ix_builder
+        .clone()
+        .gmp_ix()
+        .with_msg_metadata(meta.clone())
+        .schedule_time_lock_proposal(&sol_integration.fixture.payer.pubkey(), &config_pda)
+        .build();
+    approve_ix_at_gateway(&mut sol_integration, &mut gmp_call_data).await;
+    let res = sol_integration.fixture.send_tx(&[gmp_call_data.ix]).await;
+    assert!(res.is_ok());
+
+    sol_integration
+        .fixture
+        .set_time(default_proposal_eta() as i64)
+        .await;
+
+    // Execute the proposal
+    let ix = ix_builder.clone().execute_proposal(&config_pda).build();
+    let res = sol_integration.fixture.send_tx(&[ix]).await;
+    assert!(res.is_err());
+    assert_msg_present_in_logs(
+        res.err().unwrap(),
+        "Not enough lamports to keep the account alive",
+    );
+
+    // No instruction was executed, so the proposal is kept
+    let proposal_account = sol_integration
+        .try_get_account_no_checks(&ix_builder.proposal_pda())
+        .await
+        .unwrap();
+    assert!(proposal_account.is_some());
+}
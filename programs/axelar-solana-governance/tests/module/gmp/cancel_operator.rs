@@ -1,7 +1,6 @@
 use axelar_solana_gateway_test_fixtures::base::FindLog;
 use axelar_solana_governance::events::GovernanceEvent;
 use axelar_solana_governance::instructions::builder::{IxBuilder, ProposalRelated};
-use borsh::to_vec;
 use solana_program_test::tokio;
 use solana_sdk::instruction::AccountMeta;
 use solana_sdk::pubkey::Pubkey;
@@ -85,7 +84,7 @@ fn operator_proposal_cancelled_event(builder: &IxBuilder<ProposalRelated>) -> Go
     GovernanceEvent::OperatorProposalCancelled {
         hash: builder.proposal_hash(),
         target_address: builder.proposal_target_address().to_bytes(),
-        call_data: to_vec(&builder.proposal_call_data()).unwrap(),
+        call_data: builder.proposal_call_data(),
         native_value: builder.proposal_u256_le_native_value(),
     }
 }
@@ -1,7 +1,6 @@
 use axelar_solana_gateway_test_fixtures::base::FindLog;
 use axelar_solana_governance::events::GovernanceEvent;
 use axelar_solana_governance::instructions::builder::{IxBuilder, ProposalRelated};
-use borsh::to_vec;
 use solana_program_test::tokio;
 use solana_sdk::signature::Signer;
 
@@ -57,7 +56,7 @@ fn cancel_timelock_proposal_event(builder: &IxBuilder<ProposalRelated>) -> Gover
     GovernanceEvent::ProposalCancelled {
         hash: builder.proposal_hash(),
         target_address: builder.proposal_target_address().to_bytes(),
-        call_data: to_vec(&builder.proposal_call_data()).unwrap(),
+        call_data: builder.proposal_call_data(),
         native_value: builder.proposal_u256_le_native_value(),
         eta: builder.proposal_u256_le_eta(),
     }
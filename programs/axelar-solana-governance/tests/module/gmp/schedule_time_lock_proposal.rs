@@ -3,7 +3,6 @@ use axelar_solana_governance::events::GovernanceEvent;
 use axelar_solana_governance::instructions::builder::{IxBuilder, ProposalRelated};
 use axelar_solana_governance::state::operator;
 use axelar_solana_governance::state::proposal::ExecutableProposal;
-use borsh::to_vec;
 use solana_program_test::tokio;
 use solana_sdk::signature::Signer;
 
@@ -58,7 +57,7 @@ fn proposal_scheduled_event(builder: &IxBuilder<ProposalRelated>) -> GovernanceE
     GovernanceEvent::ProposalScheduled {
         hash: builder.proposal_hash(),
         target_address: builder.proposal_target_address().to_bytes(),
-        call_data: to_vec(&builder.proposal_call_data()).unwrap(),
+        call_data: builder.proposal_call_data(),
         native_value: builder.proposal_u256_le_native_value(),
         eta: builder.proposal_u256_le_eta(),
     }
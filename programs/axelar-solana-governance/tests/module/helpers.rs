@@ -10,6 +10,7 @@ use axelar_solana_governance::events::{EventContainer, GovernanceEvent};
 use axelar_solana_governance::instructions::builder::{
     prepend_gateway_accounts_to_ix, GmpCallData, IxBuilder,
 };
+use axelar_solana_governance::state::proposal::ProposalInstruction;
 use axelar_solana_governance::state::GovernanceConfig;
 use axelar_solana_memo_program::instruction::AxelarMemoInstruction;
 use borsh::to_vec;
@@ -185,6 +186,12 @@ pub(crate) fn ix_builder_with_memo_proposal_data(
     )
 }
 
+pub(crate) fn memo_proposal_instruction(solana_accounts: &[AccountMeta]) -> ProposalInstruction {
+    let mut proposal_data =
+        ix_builder_with_memo_proposal_data(solana_accounts, 0, None).proposal_data();
+    proposal_data.instructions.remove(0)
+}
+
 pub(crate) fn gmp_memo_metadata() -> Message {
     Message {
         cc_id: CrossChainId {
@@ -14,6 +14,7 @@
     clippy::cast_lossless
 )]
 
+mod acknowledgements;
 mod execute_operator_proposal;
 mod execute_proposal;
 mod fixtures;
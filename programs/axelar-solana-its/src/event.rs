@@ -12,6 +12,14 @@ pub struct InterchainTransfer {
     pub data_hash: [u8; 32],
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Event)]
+pub struct InterchainTransferBatchSent {
+    pub token_id: [u8; 32],
+    pub destination_chain: String,
+    /// The payload hashes of the messages of the batch, concatenated in order.
+    pub payload_hashes: Vec<u8>,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Event)]
 pub struct InterchainTransferReceived {
     pub command_id: [u8; 32],
@@ -81,6 +89,8 @@ pub struct DeployRemoteInterchainTokenApproval {
     pub token_id: [u8; 32],
     pub destination_chain: String,
     pub destination_minter: Vec<u8>,
+    /// The unix timestamp after which the approval can no longer be used, if any.
+    pub expires_at: Option<i64>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Event)]
@@ -91,11 +101,28 @@ pub struct RevokeRemoteInterchainTokenApproval {
     pub destination_chain: String,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Event)]
+pub struct RemoteInterchainTokenApprovalUsed {
+    pub minter: Pubkey,
+    pub token_id: [u8; 32],
+    pub destination_chain: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Event)]
+pub struct RemoteInterchainTokenApprovalExpired {
+    pub minter: Pubkey,
+    pub token_id: [u8; 32],
+    pub destination_chain: String,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Event)]
 pub struct FlowLimitSet {
     pub token_id: [u8; 32],
     pub operator: Pubkey,
-    pub flow_limit: u64,
+    pub inbound_flow_limit: u64,
+    pub outbound_flow_limit: u64,
+    pub epoch_duration: u64,
+    pub sliding_window: bool,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Event)]
@@ -108,9 +135,46 @@ pub struct TrustedChainRemoved {
     pub chain_name: String,
 }
 
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Event)]
+pub struct TrustedChainPauseStatusSet {
+    pub chain_name: String,
+    pub paused: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Event)]
+pub struct InterchainTokenMetadataUpdated {
+    pub token_id: [u8; 32],
+    pub name: String,
+    pub symbol: String,
+    pub uri: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Event)]
+pub struct SupplyCapSet {
+    pub token_id: [u8; 32],
+    pub operator: Pubkey,
+    pub supply_cap: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Event)]
+pub struct MinterAllowanceSet {
+    pub token_id: [u8; 32],
+    pub minter: Pubkey,
+    pub allowance: u64,
+    pub refill_period: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Event)]
+pub struct MultisigCreated {
+    pub multisig: Pubkey,
+    pub signers: Vec<Pubkey>,
+    pub threshold: u8,
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
 pub enum InterchainTokenServiceEvent {
     InterchainTransfer(InterchainTransfer),
+    InterchainTransferBatchSent(InterchainTransferBatchSent),
     InterchainTransferReceived(InterchainTransferReceived),
     TokenMetadataRegistered(TokenMetadataRegistered),
     LinkTokenStarted(LinkTokenStarted),
@@ -120,7 +184,14 @@ pub enum InterchainTokenServiceEvent {
     InterchainTokenIdClaimed(InterchainTokenIdClaimed),
     DeployRemoteInterchainTokenApproval(DeployRemoteInterchainTokenApproval),
     RevokeRemoteInterchainTokenApproval(RevokeRemoteInterchainTokenApproval),
+    RemoteInterchainTokenApprovalUsed(RemoteInterchainTokenApprovalUsed),
+    RemoteInterchainTokenApprovalExpired(RemoteInterchainTokenApprovalExpired),
     FlowLimitSet(FlowLimitSet),
     TrustedChainSet(TrustedChainSet),
     TrustedChainRemoved(TrustedChainRemoved),
+    TrustedChainPauseStatusSet(TrustedChainPauseStatusSet),
+    InterchainTokenMetadataUpdated(InterchainTokenMetadataUpdated),
+    SupplyCapSet(SupplyCapSet),
+    MinterAllowanceSet(MinterAllowanceSet),
+    MultisigCreated(MultisigCreated),
 }
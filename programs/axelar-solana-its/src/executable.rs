@@ -22,6 +22,10 @@ use crate::state::InterchainTokenService;
 /// 4. [writable] The Destination Program Associated Token Account.
 /// 5. [] The Metaplex Metadata Program account.
 /// 6. [writable] The Metaplex Metadata account associated with the mint.
+///
+/// Tokens using the Token-2022 metadata extensions keep their metadata in the
+/// mint itself, in which case the Metaplex accounts are still passed to keep
+/// the layout stable, but the Metaplex metadata account doesn't exist.
 pub const PROGRAM_ACCOUNTS_START_INDEX: usize = 7;
 
 /// This is the payload that the `executeWithInterchainToken` processor on the destinatoin program
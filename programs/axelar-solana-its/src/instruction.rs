@@ -16,7 +16,7 @@ use solana_program::{system_program, sysvar};
 use spl_associated_token_account::get_associated_token_address_with_program_id;
 use typed_builder::TypedBuilder;
 
-use crate::state::{self, flow_limit};
+use crate::state::{self, flow_limit, MetadataStandard};
 
 pub mod interchain_token;
 pub mod token_manager;
@@ -30,11 +30,12 @@ pub enum InterchainTokenServiceInstruction {
     ///
     /// 0. [writable,signer] The address of payer / sender
     /// 1. [] Program data account
-    /// 2. [] Gateway root account
-    /// 3. [writable] ITS root account
-    /// 4. [] System program account
-    /// 5. [] The account that will become the operator of the ITS
-    /// 6. [writable] The address of the account that will store the roles of the operator account.
+    /// 2. [writable] ITS root account
+    /// 3. [] System program account
+    /// 4. [] The account that will become the operator of the ITS
+    /// 5. [writable] The address of the account that will store the roles of the operator account.
+    /// 6. [writable] Role index PDA of the ITS root account, recording the roles of the operator.
+    ///    See [`role_management::state::RoleIndex`].
     Initialize {
         /// The name of the chain the ITS is running on.
         chain_name: String,
@@ -56,28 +57,39 @@ pub enum InterchainTokenServiceInstruction {
         paused: bool,
     },
     /// Sets a chain as trusted, allowing communication between this ITS and the ITS of that chain.
+    /// If the chain is already trusted, its configuration is updated.
     ///
     /// Accounts expected by this instruction:
     ///
-    /// 0. [writable,signer] The address of the payer, needs to be the ITS owner.
-    /// 1. [] The program data account.
-    /// 2. [] Gateway root account
-    /// 3. [writable] ITS root pda.
+    /// 0. [writable,signer] The address of the payer, needs to be the upgrade authority or the
+    ///    operator of the ITS.
+    /// 1. [] The account holding the roles of the payer on the ITS root account.
+    /// 2. [] The program data account.
+    /// 3. [] ITS root pda.
     /// 4. [] The system program account.
+    /// 5. [writable] The trusted chain pda.
     SetTrustedChain {
         /// The name of the chain to be trusted.
         chain_name: String,
+
+        /// The address of the ITS contract on the chain.
+        its_address: String,
+
+        /// How decimals of tokens deployed to the chain are handled.
+        decimals_policy: state::trusted_chain::DecimalsPolicy,
     },
 
     /// Unsets a chain as trusted, disallowing communication between this ITS and the ITS of that chain.
     ///
     /// Accounts expected by this instruction:
     ///
-    /// 0. [writable,signer] The address of the payer, needs to be the ITS owner.
-    /// 1. [] The program data account.
-    /// 2. [] Gateway root account
-    /// 3. [writable] ITS root pda.
+    /// 0. [writable,signer] The address of the payer, needs to be the upgrade authority or the
+    ///    operator of the ITS.
+    /// 1. [] The account holding the roles of the payer on the ITS root account.
+    /// 2. [] The program data account.
+    /// 3. [] ITS root pda.
     /// 4. [] The system program account.
+    /// 5. [writable] The trusted chain pda, which gets closed.
     RemoveTrustedChain {
         /// The name of the chain from which trust is removed.
         chain_name: String,
@@ -88,7 +100,8 @@ pub enum InterchainTokenServiceInstruction {
     /// 0. [writable,signer] The address of the payer, needs to have minter role on the token
     ///    manager.
     /// 1. [] The token manager account associated with the token
-    /// 2. [] The account that holds the payer roles on the token manager
+    /// 2. [writable] The account that holds the payer roles on the token manager, updated when
+    ///    the minter role is held through a grant with a limited number of uses
     /// 3. [writable] The account that will hold the approval of the deployment
     /// 4. [] The system program account
     ApproveDeployRemoteInterchainToken {
@@ -100,6 +113,9 @@ pub enum InterchainTokenServiceInstruction {
         destination_chain: String,
         /// The approved address of the minter on the destination chain
         destination_minter: Vec<u8>,
+        /// The unix timestamp after which the approval can no longer be used, if any. An expired
+        /// approval can be closed by anyone with [`Self::CloseExpiredDeployApproval`].
+        expires_at: Option<i64>,
     },
 
     /// Revokes an approval of a deployment of remote token with a destination minter
@@ -131,27 +147,39 @@ pub enum InterchainTokenServiceInstruction {
     /// 7. [] The token manager Associated Token Account
     /// 8. [] The token program account that was used to create the mint (`spl_token` vs `spl_token_2022`)
     /// 9. [] The Associated Token Account program account (`spl_associated_token_account`)
-    /// 10. [] The rent sysvar account
-    /// 11. [] The Metaplex metadata program account (`mpl_token_metadata`)
+    /// 10. [writable] The account holding the roles of the ITS root account on the token manager
+    /// 11. [writable] The role index PDA of the token manager, recording its initial roles
+    /// 12. [] The rent sysvar account
     RegisterCanonicalInterchainToken,
 
     /// Deploys a canonical interchain token on a remote chain.
     ///
     /// 0. [writable,signer] The account of the deployer, which is also paying for the transaction
-    /// 1. [] The Metaplex metadata account associated with the mint
-    /// 2. [] The GMP gateway root account
-    /// 3. [] The system program account
-    /// 4. [] The ITS root account
-    /// 5. [writable] The token manager account associated with the interchain token
-    /// 6. [writable] The mint account (token address) to deploy
-    /// 7. [writable] The token manager Associated Token Account associated with the mint
-    /// 8. [] The token program account that was used to create the mint (`spl_token` vs `spl_token_2022`)
-    /// 9. [] The Associated Token Account program account (`spl_associated_token_account`)
-    /// 10. [writable] The account holding the roles of the deployer on the ITS root account
-    /// 11. [] The rent sysvar account
-    /// 12. [] Optional account to set as operator on the `TokenManager`.
-    /// 13. [writable] In case an operator is being set, this should be the account holding the roles of
-    ///     the operator on the `TokenManager`
+    /// 1. [] The mint account (token address)
+    /// 2. [] The Metaplex metadata account associated with the mint
+    /// 3. [] The GMP gateway root account
+    /// 4. [] The GMP gateway program account
+    /// 5. [writable] The GMP gas configuration account
+    /// 6. [] The GMP gas service program account
+    /// 7. [] The system program account
+    /// 8. [] The ITS root account
+    /// 9. [] The GMP call contract signing account
+    /// 10. [] The ITS program account
+    /// 11. [] The trusted chain account of the destination chain
+    /// 12. [] The [`axelar_solana_gas_service::NATIVE_GAS_TOKEN`] account when paying for gas
+    ///    with native SOL, or the mint account of the token used to pay for gas, see
+    ///    [`with_spl_gas_payment`]
+    ///
+    /// When paying for gas with SPL tokens:
+    ///
+    /// 13. [writable] The token account of the payer the gas is paid from
+    /// 14. [writable] The token account of the GMP gas configuration account
+    /// 15. [] The token program account of the token used to pay for gas
+    /// 16. [] The account allowing the token to be used to pay for gas
+    ///
+    /// The [writable] gas reserve account of the token used to pay for gas follows, then the
+    /// [writable] gas payment ledger accounts when paying for gas, see
+    /// [`with_gas_payment_accounts`].
     DeployRemoteCanonicalInterchainToken {
         /// The remote chain where the `InterchainToken` should be deployed.
         destination_chain: String,
@@ -173,14 +201,33 @@ pub enum InterchainTokenServiceInstruction {
     /// 5. [writable] The token manager Associated Token Account associated with the mint
     /// 6. [] The token program account that was used to create the mint (`spl_token` vs `spl_token_2022`)
     /// 7. [writable] The account tracking the flow of this mint for the current epoch
-    /// 8. [] The GMP gateway root account
-    /// 9. [] The GMP gateway program account
-    /// 10. [writable] The GMP gas configuration account
-    /// 11. [] The GMP gas service program account
-    /// 12. [] The system program account
-    /// 13. [] The ITS root account
-    /// 14. [] The GMP call contract signing account
-    /// 15. [] The ITS program account
+    /// 8. [] The account tracking the flow of this mint for the previous epoch
+    /// 9. [] The GMP gateway root account
+    /// 10. [] The GMP gateway program account
+    /// 11. [writable] The GMP gas configuration account
+    /// 12. [] The GMP gas service program account
+    /// 13. [] The system program account
+    /// 14. [] The ITS root account
+    /// 15. [] The GMP call contract signing account
+    /// 16. [] The ITS program account
+    /// 17. [] The trusted chain account of the destination chain
+    /// 18. [] The [`axelar_solana_gas_service::NATIVE_GAS_TOKEN`] account when paying for gas
+    ///    with native SOL, or the mint account of the token used to pay for gas, see
+    ///    [`with_spl_gas_payment`]
+    ///
+    /// When paying for gas with SPL tokens:
+    ///
+    /// 19. [writable] The token account of the payer the gas is paid from
+    /// 20. [writable] The token account of the GMP gas configuration account
+    /// 21. [] The token program account of the token used to pay for gas
+    /// 22. [] The account allowing the token to be used to pay for gas
+    ///
+    /// The [writable] gas reserve account of the token used to pay for gas follows, then the
+    /// [writable] gas payment ledger accounts when paying for gas, see
+    /// [`with_gas_payment_accounts`].
+    ///
+    /// When the mint has a transfer hook and is locked by the `TokenManager`, the accounts required
+    /// by the hook come last, see [`with_transfer_hook_accounts`].
     InterchainTransfer {
         /// The token id associated with the token
         token_id: [u8; 32],
@@ -213,12 +260,15 @@ pub enum InterchainTokenServiceInstruction {
     /// 7. [] The token program account (`spl_token_2022`)
     /// 8. [] The Associated Token Account program account (`spl_associated_token_account`)
     /// 9. [writable] The account holding the roles of the deployer on the ITS root account
-    /// 10. [] The rent sysvar account
-    /// 11. [] The instructions sysvar account
-    /// 12. [] The Metaplex metadata program account (`mpl_token_metadata`)
-    /// 13. [writable] The Metaplex metadata account associated with the mint
-    /// 14. [] The account to set as minter of the token
-    /// 15. [writable] The account holding the roles of the minter account on the `TokenManager`
+    /// 10. [writable] The role index PDA of the `TokenManager`, recording its initial roles
+    /// 11. [] The rent sysvar account
+    /// 12. [] The instructions sysvar account
+    /// 13. [] The Metaplex metadata program account (`mpl_token_metadata`), only with
+    ///     [`MetadataStandard::Metaplex`]
+    /// 14. [writable] The Metaplex metadata account associated with the mint, only with
+    ///     [`MetadataStandard::Metaplex`]
+    /// 15. [] The account to set as minter of the token
+    /// 16. [writable] The account holding the roles of the minter account on the `TokenManager`
     DeployInterchainToken {
         /// The salt used to derive the tokenId associated with the token
         salt: [u8; 32],
@@ -234,6 +284,9 @@ pub enum InterchainTokenServiceInstruction {
 
         /// Initial supply
         initial_supply: u64,
+
+        /// Where the token metadata is stored
+        metadata_standard: MetadataStandard,
     },
 
     /// Deploys a remote interchain token
@@ -251,6 +304,21 @@ pub enum InterchainTokenServiceInstruction {
     /// 10. [] The ITS root account
     /// 11. [] The GMP call contract signing account
     /// 12. [] The ITS program account
+    /// 13. [] The trusted chain account of the destination chain
+    /// 14. [] The [`axelar_solana_gas_service::NATIVE_GAS_TOKEN`] account when paying for gas
+    ///    with native SOL, or the mint account of the token used to pay for gas, see
+    ///    [`with_spl_gas_payment`]
+    ///
+    /// When paying for gas with SPL tokens:
+    ///
+    /// 15. [writable] The token account of the payer the gas is paid from
+    /// 16. [writable] The token account of the GMP gas configuration account
+    /// 17. [] The token program account of the token used to pay for gas
+    /// 18. [] The account allowing the token to be used to pay for gas
+    ///
+    /// The [writable] gas reserve account of the token used to pay for gas follows, then the
+    /// [writable] gas payment ledger accounts when paying for gas, see
+    /// [`with_gas_payment_accounts`].
     DeployRemoteInterchainToken {
         /// The salt used to derive the tokenId associated with the token
         salt: [u8; 32],
@@ -272,8 +340,9 @@ pub enum InterchainTokenServiceInstruction {
     /// 2. [] The Metaplex metadata account associated with the mint
     /// 3. [] The account of the minter that approved the deployment
     /// 4. [writable] The account holding the approval for the deployment
-    /// 5. [] The account holding the roles of the minter on the token manager associated with the
-    ///    interchain token
+    /// 5. [writable] The account holding the roles of the minter on the token manager associated
+    ///    with the interchain token, updated when the role is held through a grant with a
+    ///    limited number of uses
     /// 6. [] The token manager account associated with the interchain token
     /// 7. [] The instructions sysvar account
     /// 8. [] The Metaplex metadata program account (`mpl_token_metadata`)
@@ -285,6 +354,21 @@ pub enum InterchainTokenServiceInstruction {
     /// 14. [] The ITS root account
     /// 15. [] The GMP call contract signing account
     /// 16. [] The ITS program account
+    /// 17. [] The trusted chain account of the destination chain
+    /// 18. [] The [`axelar_solana_gas_service::NATIVE_GAS_TOKEN`] account when paying for gas
+    ///    with native SOL, or the mint account of the token used to pay for gas, see
+    ///    [`with_spl_gas_payment`]
+    ///
+    /// When paying for gas with SPL tokens:
+    ///
+    /// 19. [writable] The token account of the payer the gas is paid from
+    /// 20. [writable] The token account of the GMP gas configuration account
+    /// 21. [] The token program account of the token used to pay for gas
+    /// 22. [] The account allowing the token to be used to pay for gas
+    ///
+    /// The [writable] gas reserve account of the token used to pay for gas follows, then the
+    /// [writable] gas payment ledger accounts when paying for gas, see
+    /// [`with_gas_payment_accounts`].
     DeployRemoteInterchainTokenWithMinter {
         /// The salt used to derive the tokenId associated with the token
         salt: [u8; 32],
@@ -315,6 +399,20 @@ pub enum InterchainTokenServiceInstruction {
     /// 8. [] The ITS root account
     /// 9. [] The GMP call contract signing account
     /// 10. [] The ITS program account
+    /// 11. [] The [`axelar_solana_gas_service::NATIVE_GAS_TOKEN`] account when paying for gas
+    ///    with native SOL, or the mint account of the token used to pay for gas, see
+    ///    [`with_spl_gas_payment`]
+    ///
+    /// When paying for gas with SPL tokens:
+    ///
+    /// 12. [writable] The token account of the payer the gas is paid from
+    /// 13. [writable] The token account of the GMP gas configuration account
+    /// 14. [] The token program account of the token used to pay for gas
+    /// 15. [] The account allowing the token to be used to pay for gas
+    ///
+    /// The [writable] gas reserve account of the token used to pay for gas follows, then the
+    /// [writable] gas payment ledger accounts when paying for gas, see
+    /// [`with_gas_payment_accounts`].
     RegisterTokenMetadata {
         /// The gas value to be paid for the GMP transaction
         gas_value: u64,
@@ -335,9 +433,10 @@ pub enum InterchainTokenServiceInstruction {
     /// 8. [] The token program account that was used to create the mint (`spl_token` vs `spl_token_2022`)
     /// 9. [] The Associated Token Account program account (`spl_associated_token_account`)
     /// 10. [writable] The account holding the roles of the deployer on the ITS root account
-    /// 11. [] The rent sysvar account
-    /// 12. [] Optional account to set as operator on the `TokenManager`.
-    /// 13. [writable] In case an operator is being set, this should be the account holding the roles of
+    /// 11. [writable] The role index PDA of the `TokenManager`, recording its initial roles
+    /// 12. [] The rent sysvar account
+    /// 13. [] Optional account to set as operator on the `TokenManager`.
+    /// 14. [writable] In case an operator is being set, this should be the account holding the roles of
     ///     the operator on the `TokenManager`
     RegisterCustomToken {
         /// Salt used to derive the `token_id` associated with the token.
@@ -361,6 +460,21 @@ pub enum InterchainTokenServiceInstruction {
     /// 7. [] The ITS root account
     /// 8. [] The GMP call contract signing account
     /// 9. [] The ITS program account
+    /// 10. [] The trusted chain account of the destination chain
+    /// 11. [] The [`axelar_solana_gas_service::NATIVE_GAS_TOKEN`] account when paying for gas
+    ///    with native SOL, or the mint account of the token used to pay for gas, see
+    ///    [`with_spl_gas_payment`]
+    ///
+    /// When paying for gas with SPL tokens:
+    ///
+    /// 12. [writable] The token account of the payer the gas is paid from
+    /// 13. [writable] The token account of the GMP gas configuration account
+    /// 14. [] The token program account of the token used to pay for gas
+    /// 15. [] The account allowing the token to be used to pay for gas
+    ///
+    /// The [writable] gas reserve account of the token used to pay for gas follows, then the
+    /// [writable] gas payment ledger accounts when paying for gas, see
+    /// [`with_gas_payment_accounts`].
     LinkToken {
         /// Salt used to derive the `token_id` associated with the token.
         salt: [u8; 32],
@@ -392,14 +506,33 @@ pub enum InterchainTokenServiceInstruction {
     /// 5. [writable] The token manager Associated Token Account associated with the mint
     /// 6. [] The token program account that was used to create the mint (`spl_token` vs `spl_token_2022`)
     /// 7. [writable] The account tracking the flow of this mint for the current epoch
-    /// 8. [] The GMP gateway root account
-    /// 9. [] The GMP gateway program account
-    /// 10. [writable] The GMP gas configuration account
-    /// 11. [] The GMP gas service program account
-    /// 12. [] The system program account
-    /// 13. [] The ITS root account
-    /// 14. [] The GMP call contract signing account
-    /// 15. [] The ITS program account
+    /// 8. [] The account tracking the flow of this mint for the previous epoch
+    /// 9. [] The GMP gateway root account
+    /// 10. [] The GMP gateway program account
+    /// 11. [writable] The GMP gas configuration account
+    /// 12. [] The GMP gas service program account
+    /// 13. [] The system program account
+    /// 14. [] The ITS root account
+    /// 15. [] The GMP call contract signing account
+    /// 16. [] The ITS program account
+    /// 17. [] The trusted chain account of the destination chain
+    /// 18. [] The [`axelar_solana_gas_service::NATIVE_GAS_TOKEN`] account when paying for gas
+    ///    with native SOL, or the mint account of the token used to pay for gas, see
+    ///    [`with_spl_gas_payment`]
+    ///
+    /// When paying for gas with SPL tokens:
+    ///
+    /// 19. [writable] The token account of the payer the gas is paid from
+    /// 20. [writable] The token account of the GMP gas configuration account
+    /// 21. [] The token program account of the token used to pay for gas
+    /// 22. [] The account allowing the token to be used to pay for gas
+    ///
+    /// The [writable] gas reserve account of the token used to pay for gas follows, then the
+    /// [writable] gas payment ledger accounts when paying for gas, see
+    /// [`with_gas_payment_accounts`].
+    ///
+    /// When the mint has a transfer hook and is locked by the `TokenManager`, the accounts required
+    /// by the hook come last, see [`with_transfer_hook_accounts`].
     CallContractWithInterchainToken {
         /// The token id associated with the token
         token_id: [u8; 32],
@@ -437,14 +570,33 @@ pub enum InterchainTokenServiceInstruction {
     /// 5. [writable] The token manager Associated Token Account associated with the mint
     /// 6. [] The token program account that was used to create the mint (`spl_token` vs `spl_token_2022`)
     /// 7. [writable] The account tracking the flow of this mint for the current epoch
-    /// 8. [] The GMP gateway root account
-    /// 9. [] The GMP gateway program account
-    /// 10. [writable] The GMP gas configuration account
-    /// 11. [] The GMP gas service program account
-    /// 12. [] The system program account
-    /// 13. [] The ITS root account
-    /// 14. [] The GMP call contract signing account
-    /// 15. [] The ITS program account
+    /// 8. [] The account tracking the flow of this mint for the previous epoch
+    /// 9. [] The GMP gateway root account
+    /// 10. [] The GMP gateway program account
+    /// 11. [writable] The GMP gas configuration account
+    /// 12. [] The GMP gas service program account
+    /// 13. [] The system program account
+    /// 14. [] The ITS root account
+    /// 15. [] The GMP call contract signing account
+    /// 16. [] The ITS program account
+    /// 17. [] The trusted chain account of the destination chain
+    /// 18. [] The [`axelar_solana_gas_service::NATIVE_GAS_TOKEN`] account when paying for gas
+    ///    with native SOL, or the mint account of the token used to pay for gas, see
+    ///    [`with_spl_gas_payment`]
+    ///
+    /// When paying for gas with SPL tokens:
+    ///
+    /// 19. [writable] The token account of the payer the gas is paid from
+    /// 20. [writable] The token account of the GMP gas configuration account
+    /// 21. [] The token program account of the token used to pay for gas
+    /// 22. [] The account allowing the token to be used to pay for gas
+    ///
+    /// The [writable] gas reserve account of the token used to pay for gas follows, then the
+    /// [writable] gas payment ledger accounts when paying for gas, see
+    /// [`with_gas_payment_accounts`].
+    ///
+    /// When the mint has a transfer hook and is locked by the `TokenManager`, the accounts required
+    /// by the hook come last, see [`with_transfer_hook_accounts`].
     CallContractWithInterchainTokenOffchainData {
         /// The token id associated with the token
         token_id: [u8; 32],
@@ -475,28 +627,39 @@ pub enum InterchainTokenServiceInstruction {
     /// 2. [writable] The token manager account associated with the interchain token
     /// 3. [writable] The account holding the roles of the payer on the ITS root account
     /// 4. [writable] The account holding the roles of the payer on the `TokenManager`
+    /// 5. [] The system program account
+    /// 6..N [signer] If the payer is a [`Multisig`](role_management::state::Multisig) holding the
+    ///    operator role, the members of the multisig approving the change. See
+    ///    [`with_multisig_signers`].
     SetFlowLimit {
-        /// The new flow limit.
-        flow_limit: u64,
+        /// The new flow limit configuration.
+        flow_limit: flow_limit::FlowLimit,
     },
 
     /// Transfers operatorship to another account.
     ///
     /// 0. [] System program account.
-    /// 1. [writable, signer] Payer account.
-    /// 2. [] PDA for the payer roles on the resource which the operatorship is being transferred
+    /// 1. [writable] Authority account, signing unless it's a
+    ///    [`Multisig`](role_management::state::Multisig).
+    /// 2. [] PDA for the authority roles on the resource which the operatorship is being transferred
     ///    from.
     /// 3. [] PDA for the resource.
     /// 4. [] Account to transfer operatorship to.
     /// 5. [writable] PDA with the roles on the resource the
     ///    operatorship is being transferred to.
+    /// 6. [writable] Role index PDA of the resource, updated with the new roles. See
+    ///    [`role_management::state::RoleIndex`].
+    /// 7. [writable, signer] Only when the authority is a multisig, the payer of any rent.
+    /// 8..N [signer] Only when the authority is a multisig, the members of the multisig
+    ///    approving the change. See [`with_multisig_authority`].
     TransferOperatorship,
 
     /// Proposes operatorship transfer to another account.
     ///
     /// 0. [] System program account.
-    /// 1. [writable, signer] Payer account.
-    /// 2. [] PDA for the payer roles on the resource.
+    /// 1. [writable] Authority account, signing unless it's a
+    ///    [`Multisig`](role_management::state::Multisig).
+    /// 2. [] PDA for the authority roles on the resource.
     /// 3. [] PDA for the resource.
     /// 4. [] Account to transfer operatorship to.
     /// 5. [writable] PDA with the roles on the resource for the accounts the
@@ -505,13 +668,21 @@ pub enum InterchainTokenServiceInstruction {
     /// 7. [writable] PDA with the roles on the resource for the account the
     ///    operatorship is being transferred from.
     /// 8. [writable] PDA for the proposal
-    ProposeOperatorship,
+    /// 9. [writable, signer] Only when the authority is a multisig, the payer of any rent.
+    /// 10..N [signer] Only when the authority is a multisig, the members of the multisig
+    ///    approving the change. See [`with_multisig_authority`].
+    ProposeOperatorship {
+        /// The unix timestamp after which the proposal can no longer be accepted, if any. An
+        /// expired proposal can be canceled by anyone.
+        expires_at: Option<i64>,
+    },
 
     /// Accepts operatorship transfer from another account.
     ///
     /// 0. [] System program account.
-    /// 1. [writable, signer] Payer account.
-    /// 2. [] PDA for the payer roles on the resource.
+    /// 1. [writable] Authority account, signing unless it's a
+    ///    [`Multisig`](role_management::state::Multisig).
+    /// 2. [] PDA for the authority roles on the resource.
     /// 3. [] PDA for the resource.
     /// 4. [] Account to transfer operatorship to.
     /// 5. [writable] PDA with the roles on the resource for the accounts the
@@ -520,26 +691,43 @@ pub enum InterchainTokenServiceInstruction {
     /// 7. [writable] PDA with the roles on the resource for the account the
     ///    operatorship is being transferred from.
     /// 8. [writable] PDA for the proposal
+    /// 9. [writable] Role index PDA of the resource, updated with the new roles. See
+    ///    [`role_management::state::RoleIndex`].
+    /// 10. [writable, signer] Only when the authority is a multisig, the payer of any rent.
+    /// 11..N [signer] Only when the authority is a multisig, the members of the multisig
+    ///    approving the change. See [`with_multisig_authority`].
     AcceptOperatorship,
 
     /// Adds a flow limiter to a [`TokenManager`].
     ///
     /// 0. [] System program account.
-    /// 1. [writable, signer] Payer account (must have operator role).
-    /// 2. [] PDA for the payer roles on the token manager.
+    /// 1. [writable] Authority account, signing unless it's a
+    ///    [`Multisig`](role_management::state::Multisig) (must have operator role).
+    /// 2. [] PDA for the authority roles on the token manager.
     /// 3. [] PDA for the token manager.
     /// 4. [] Account to add as flow limiter.
     /// 5. [writable] PDA with the roles on the token manager for the flow limiter being added.
+    /// 6. [writable] Role index PDA of the resource, updated with the new roles. See
+    ///    [`role_management::state::RoleIndex`].
+    /// 7. [writable, signer] Only when the authority is a multisig, the payer of any rent.
+    /// 8..N [signer] Only when the authority is a multisig, the members of the multisig
+    ///    approving the change. See [`with_multisig_authority`].
     AddTokenManagerFlowLimiter,
 
     /// Removes a flow limiter from a [`TokenManager`].
     ///
     /// 0. [] System program account.
-    /// 1. [writable, signer] Payer account (must have operator role).
-    /// 2. [] PDA for the payer roles on the token manager.
+    /// 1. [writable] Authority account, signing unless it's a
+    ///    [`Multisig`](role_management::state::Multisig) (must have operator role).
+    /// 2. [] PDA for the authority roles on the token manager.
     /// 3. [] PDA for the token manager.
     /// 4. [] Account to remove as flow limiter.
     /// 5. [writable] PDA with the roles on the token manager for the flow limiter being removed.
+    /// 6. [writable] Role index PDA of the resource, updated with the new roles. See
+    ///    [`role_management::state::RoleIndex`].
+    /// 7. [writable, signer] Only when the authority is a multisig, the payer of any rent.
+    /// 8..N [signer] Only when the authority is a multisig, the members of the multisig
+    ///    approving the change. See [`with_multisig_authority`].
     RemoveTokenManagerFlowLimiter,
 
     /// Sets the flow limit for an interchain token.
@@ -547,31 +735,43 @@ pub enum InterchainTokenServiceInstruction {
     /// 0. [signer] Payer account.
     /// 1. [] ITS root PDA account.
     /// 2. [writable] The [`TokenManager`] PDA account.
-    /// 3. [] The PDA account with the user roles on the [`TokenManager`].
+    /// 3. [writable] The PDA account with the user roles on the [`TokenManager`]. Only needs to
+    ///    be writable if the flow limiter role is granted with a limited number of uses.
     /// 4. [] The PDA account with the user roles on ITS.
+    /// 5. [] The system program account.
+    /// 6..N [signer] If the payer is a [`Multisig`](role_management::state::Multisig) holding the
+    ///    flow limiter role, the members of the multisig approving the change. See
+    ///    [`with_multisig_signers`].
     SetTokenManagerFlowLimit {
-        /// The new flow limit.
-        flow_limit: u64,
+        /// The new flow limit configuration.
+        flow_limit: flow_limit::FlowLimit,
     },
 
     /// Transfers operatorship to another account.
     ///
     /// 0. [] ITS root PDA.
     /// 1. [] System program account.
-    /// 2. [writable, signer] Payer account.
-    /// 3. [] PDA for the payer roles on the resource which the operatorship is being transferred
+    /// 2. [writable] Authority account, signing unless it's a
+    ///    [`Multisig`](role_management::state::Multisig).
+    /// 3. [] PDA for the authority roles on the resource which the operatorship is being transferred
     ///    from.
     /// 4. [] PDA for the resource.
     /// 5. [] Account to transfer operatorship to.
     /// 6. [writable] PDA with the roles on the resource the
     ///    operatorship is being transferred to.
+    /// 7. [writable] Role index PDA of the resource, updated with the new roles. See
+    ///    [`role_management::state::RoleIndex`].
+    /// 8. [writable, signer] Only when the authority is a multisig, the payer of any rent.
+    /// 9..N [signer] Only when the authority is a multisig, the members of the multisig
+    ///    approving the change. See [`with_multisig_authority`].
     TransferTokenManagerOperatorship,
 
     /// Proposes operatorship transfer to another account.
     ///
     /// 0. [] System program account.
-    /// 1. [writable, signer] Payer account.
-    /// 2. [] PDA for the payer roles on the resource.
+    /// 1. [writable] Authority account, signing unless it's a
+    ///    [`Multisig`](role_management::state::Multisig).
+    /// 2. [] PDA for the authority roles on the resource.
     /// 3. [] PDA for the resource.
     /// 4. [] Account to transfer operatorship to.
     /// 5. [writable] PDA with the roles on the resource for the accounts the
@@ -580,13 +780,21 @@ pub enum InterchainTokenServiceInstruction {
     /// 7. [writable] PDA with the roles on the resource for the account the
     ///    operatorship is being transferred from.
     /// 8. [writable] PDA for the proposal
-    ProposeTokenManagerOperatorship,
+    /// 9. [writable, signer] Only when the authority is a multisig, the payer of any rent.
+    /// 10..N [signer] Only when the authority is a multisig, the members of the multisig
+    ///    approving the change. See [`with_multisig_authority`].
+    ProposeTokenManagerOperatorship {
+        /// The unix timestamp after which the proposal can no longer be accepted, if any. An
+        /// expired proposal can be canceled by anyone.
+        expires_at: Option<i64>,
+    },
 
     /// Accepts operatorship transfer from another account.
     ///
     /// 0. [] System program account.
-    /// 1. [writable, signer] Payer account.
-    /// 2. [] PDA for the payer roles on the resource.
+    /// 1. [writable] Authority account, signing unless it's a
+    ///    [`Multisig`](role_management::state::Multisig).
+    /// 2. [] PDA for the authority roles on the resource.
     /// 3. [] PDA for the resource.
     /// 4. [] Account to transfer operatorship to.
     /// 5. [writable] PDA with the roles on the resource for the accounts the
@@ -595,6 +803,11 @@ pub enum InterchainTokenServiceInstruction {
     /// 7. [writable] PDA with the roles on the resource for the account the
     ///    operatorship is being transferred from.
     /// 8. [writable] PDA for the proposal
+    /// 9. [writable] Role index PDA of the resource, updated with the new roles. See
+    ///    [`role_management::state::RoleIndex`].
+    /// 10. [writable, signer] Only when the authority is a multisig, the payer of any rent.
+    /// 11..N [signer] Only when the authority is a multisig, the members of the multisig
+    ///    approving the change. See [`with_multisig_authority`].
     AcceptTokenManagerOperatorship,
 
     /// Transfers the mint authority to the token manager allowing it to mint tokens and manage
@@ -608,6 +821,8 @@ pub enum InterchainTokenServiceInstruction {
     /// 4. [] The account that will hold the roles of the former authority on the [`TokenManager`]
     /// 5. [] The token program used to create the mint
     /// 6. [] The system program account
+    /// 7. [writable] Role index PDA of the [`TokenManager`], updated with the new roles. See
+    ///    [`role_management::state::RoleIndex`].
     HandoverMintAuthority {
         /// The id of the token registered with ITS for which the authority is being handed over.
         token_id: [u8; 32],
@@ -617,12 +832,24 @@ pub enum InterchainTokenServiceInstruction {
     /// `TokenManager`. Only users with the `minter` role on the mint account
     /// can mint tokens.
     ///
+    /// Minting is subject to the supply cap of the [`TokenManager`] and to the
+    /// allowance of the minter, if any.
+    ///
     /// 0. [writable] The mint account
     /// 1. [writable] The account to mint tokens to
     /// 2. [] The interchain token PDA associated with the mint
     /// 3. [] The token manager PDA
     /// 4. [signer] The minter account
-    /// 5. [] The token program id
+    /// 5. [writable] The PDA with the roles of the minter on the token manager, updated when the
+    ///    role is held through a grant with a limited number of uses
+    /// 6. [] The token program id
+    /// 7. [writable] The [`MinterAllowance`] PDA of the minter
+    /// 8. [] The system program account
+    /// 9. [writable, signer] The payer of any rent needed by the [`MinterAllowance`] PDA
+    /// 10..N [signer] If the minter is a [`Multisig`](role_management::state::Multisig), the
+    ///    members of the multisig approving the mint. See [`with_multisig_signers`].
+    ///
+    /// [`MinterAllowance`]: crate::state::minter_allowance::MinterAllowance
     MintInterchainToken {
         /// The amount of tokens to mint.
         amount: u64,
@@ -632,20 +859,27 @@ pub enum InterchainTokenServiceInstruction {
     ///
     /// 0. [] ITS root PDA.
     /// 1. [] System program account.
-    /// 2. [writable, signer] Payer account.
-    /// 3. [] PDA for the payer roles on the resource which the mintership is being transferred
+    /// 2. [writable] Authority account, signing unless it's a
+    ///    [`Multisig`](role_management::state::Multisig).
+    /// 3. [] PDA for the authority roles on the resource which the mintership is being transferred
     ///    from.
     /// 4. [] PDA for the resource.
     /// 5. [] Account to transfer mintership to.
     /// 6. [writable] PDA with the roles on the resource the
     ///    mintership is being transferred to.
+    /// 7. [writable] Role index PDA of the resource, updated with the new roles. See
+    ///    [`role_management::state::RoleIndex`].
+    /// 8. [writable, signer] Only when the authority is a multisig, the payer of any rent.
+    /// 9..N [signer] Only when the authority is a multisig, the members of the multisig
+    ///    approving the change. See [`with_multisig_authority`].
     TransferInterchainTokenMintership,
 
     /// Proposes mintership transfer to another account.
     ///
     /// 0. [] System program account.
-    /// 1. [writable, signer] Payer account.
-    /// 2. [] PDA for the payer roles on the resource.
+    /// 1. [writable] Authority account, signing unless it's a
+    ///    [`Multisig`](role_management::state::Multisig).
+    /// 2. [] PDA for the authority roles on the resource.
     /// 3. [] PDA for the resource.
     /// 4. [] Account to transfer operatorship to.
     /// 5. [writable] PDA with the roles on the resource for the accounts the
@@ -654,13 +888,21 @@ pub enum InterchainTokenServiceInstruction {
     /// 7. [writable] PDA with the roles on the resource for the account the
     ///    operatorship is being transferred from.
     /// 8. [writable] PDA for the proposal
-    ProposeInterchainTokenMintership,
+    /// 9. [writable, signer] Only when the authority is a multisig, the payer of any rent.
+    /// 10..N [signer] Only when the authority is a multisig, the members of the multisig
+    ///    approving the change. See [`with_multisig_authority`].
+    ProposeInterchainTokenMintership {
+        /// The unix timestamp after which the proposal can no longer be accepted, if any. An
+        /// expired proposal can be canceled by anyone.
+        expires_at: Option<i64>,
+    },
 
     /// Accepts mintership transfer from another account.
     ///
     /// 0. [] System program account.
-    /// 1. [writable, signer] Payer account.
-    /// 2. [] PDA for the payer roles on the resource.
+    /// 1. [writable] Authority account, signing unless it's a
+    ///    [`Multisig`](role_management::state::Multisig).
+    /// 2. [] PDA for the authority roles on the resource.
     /// 3. [] PDA for the resource.
     /// 4. [] Account to transfer operatorship to.
     /// 5. [writable] PDA with the roles on the resource for the accounts the
@@ -669,19 +911,418 @@ pub enum InterchainTokenServiceInstruction {
     /// 7. [writable] PDA with the roles on the resource for the account the
     ///    operatorship is being transferred from.
     /// 8. [writable] PDA for the proposal
+    /// 9. [writable] Role index PDA of the resource, updated with the new roles. See
+    ///    [`role_management::state::RoleIndex`].
+    /// 10. [writable, signer] Only when the authority is a multisig, the payer of any rent.
+    /// 11..N [signer] Only when the authority is a multisig, the members of the multisig
+    ///    approving the change. See [`with_multisig_authority`].
     AcceptInterchainTokenMintership,
 
     /// A GMP Interchain Token Service instruction.
     ///
     /// 0. [writable,signer] The address of payer / sender
-    /// 1. [] gateway root pda
-    /// 2. [] ITS root pda
+    /// 1. [writable] The gateway incoming message pda
+    /// 2. [] The gateway message payload pda
+    /// 3. [] The gateway approved message signing pda
+    /// 4. [] The gateway program account
+    /// 5. [] The trusted chain pda of the source chain
+    /// 6. [] The system program account
+    /// 7. [] ITS root pda
     ///
-    /// 3..N Accounts depend on the inner ITS instruction.
+    /// 8..N Accounts depend on the inner ITS instruction.
     ItsGmpPayload {
         /// The GMP metadata
         message: Message,
     },
+
+    /// Pauses or unpauses the communication with a trusted chain.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. [writable,signer] The address of the payer, needs to be the upgrade authority or the
+    ///    operator of the ITS.
+    /// 1. [] The account holding the roles of the payer on the ITS root account.
+    /// 2. [] The program data account.
+    /// 3. [] ITS root pda.
+    /// 4. [] The system program account.
+    /// 5. [writable] The trusted chain pda.
+    SetTrustedChainPauseStatus {
+        /// The name of the trusted chain.
+        chain_name: String,
+
+        /// The new pause status.
+        paused: bool,
+    },
+
+    /// Migrates trusted chains stored in the ITS root account by older versions of the program
+    /// into their own PDAs. Once all chains are migrated, the ITS root account is rewritten in the
+    /// current layout. Until then, other instructions read the ITS root account without the
+    /// chains pending migration, which aren't trusted. Chains with names longer than
+    /// [`solana_program::pubkey::MAX_SEED_LEN`] can't have a PDA and are dropped.
+    ///
+    /// Accounts expected by this instruction:
+    ///
+    /// 0. [writable,signer] The address of the payer, needs to be the upgrade authority.
+    /// 1. [] The program data account.
+    /// 2. [writable] ITS root pda.
+    /// 3. [] The system program account.
+    /// 4..N [writable] The trusted chain pdas to create, one for each chain in `trusted_chains`,
+    ///    in the same order.
+    MigrateTrustedChains {
+        /// The names of the chains to migrate, along with the address of the ITS contract on
+        /// each of them.
+        trusted_chains: Vec<(String, String)>,
+    },
+
+    /// Closes a [`FlowSlot`] PDA of a past epoch, refunding its rent to the
+    /// account that paid for the deployment of the [`TokenManager`]. Anyone can
+    /// call this instruction.
+    ///
+    /// Whether the epoch is past is checked against the time window recorded
+    /// by the slot, as the epoch duration of the [`TokenManager`] may have
+    /// changed since the slot was created.
+    ///
+    /// Flow slots created by older versions of the program are closed as well.
+    /// [`TokenManager`]s created by older versions have no payer of record, the
+    /// rent of their flow slots is refunded to the [`TokenManager`] PDA itself.
+    ///
+    /// 0. [] ITS root PDA.
+    /// 1. [] The [`TokenManager`] PDA the flow slot belongs to.
+    /// 2. [writable] The [`FlowSlot`] PDA to close.
+    /// 3. [writable] The account that paid for the deployment of the [`TokenManager`].
+    ///
+    /// [`FlowSlot`]: crate::state::flow_limit::FlowSlot
+    CloseFlowSlot {
+        /// The epoch of the flow slot to close.
+        epoch: u64,
+    },
+
+    /// Updates the metadata of a native interchain token, either stored in a Metaplex metadata
+    /// account or in the Token-2022 metadata extension of the mint. If a destination chain is
+    /// given, the new name and symbol are also sent to the interchain token on that chain, as a
+    /// `RegisterTokenMetadata` message extended with the update.
+    ///
+    /// Only the metadata of tokens deployed on Solana can be sent to other chains, and updates
+    /// received from other chains are only applied if they come from the chain the token was
+    /// deployed from. Tokens deployed before metadata updates were supported have immutable
+    /// Metaplex metadata and fail with [`ProgramError::Immutable`].
+    ///
+    /// 0. [writable,signer] The address of the payer, which needs to be the operator of the
+    ///    `TokenManager`. Not signing when the operator is a
+    ///    [`Multisig`](role_management::state::Multisig), see [`with_multisig_authority`].
+    /// 1. [] The account holding the roles of the payer on the `TokenManager`
+    /// 2. [] The token manager account associated with the interchain token
+    /// 3. [writable] The mint account (token address)
+    /// 4. [writable] The Metaplex metadata account associated with the mint
+    /// 5. [] The Metaplex metadata program account (`mpl_token_metadata`)
+    /// 6. [] The instructions sysvar account
+    /// 7. [] The token program account (`spl_token_2022`)
+    /// 8. [] The GMP gateway root account
+    /// 9. [] The GMP gateway program account
+    /// 10. [writable] The GMP gas configuration account
+    /// 11. [] The GMP gas service program account
+    /// 12. [] The system program account
+    /// 13. [] The ITS root account
+    /// 14. [] The GMP call contract signing account
+    /// 15. [] The ITS program account
+    /// 16. [] The trusted chain account of the destination chain, only when a destination chain
+    ///     is given
+    /// 17. [] The [`axelar_solana_gas_service::NATIVE_GAS_TOKEN`] account when paying for gas
+    ///    with native SOL, or the mint account of the token used to pay for gas, see
+    ///    [`with_spl_gas_payment`]. This and the following accounts come one position earlier
+    ///    when no destination chain is given.
+    ///
+    /// When paying for gas with SPL tokens:
+    ///
+    /// 18. [writable] The token account of the payer the gas is paid from
+    /// 19. [writable] The token account of the GMP gas configuration account
+    /// 20. [] The token program account of the token used to pay for gas
+    /// 21. [] The account allowing the token to be used to pay for gas
+    ///
+    /// The [writable] gas reserve account of the token used to pay for gas follows, then the
+    /// [writable] gas payment ledger accounts when paying for gas, see
+    /// [`with_gas_payment_accounts`].
+    ///
+    /// When the operator is a multisig, the [writable, signer] payer of any rent and gas comes
+    /// last, followed by the [signer] members of the multisig approving the update.
+    UpdateInterchainTokenMetadata {
+        /// The token id associated with the interchain token
+        token_id: [u8; 32],
+        /// The new name of the token
+        name: String,
+        /// The new symbol of the token
+        symbol: String,
+        /// The new URI of the token metadata
+        uri: String,
+        /// The chain where the update should be sent to, if any
+        destination_chain: Option<String>,
+        /// The gas value to be paid for the GMP transaction
+        gas_value: u64,
+        /// The signing PDA bump
+        signing_pda_bump: u8,
+    },
+
+    /// Transfers interchain tokens to several addresses on the same destination chain. The tokens
+    /// are taken from the source account and accounted against the flow limit once for the whole
+    /// batch, then one GMP message is sent per transfer, see
+    /// [`crate::event::InterchainTransferBatchSent`]. The gas value is split between the messages
+    /// proportionally to the length of their payload, the remainder going to the last message, and
+    /// paid for each of them against its own payload hash. Paying per message keeps each payment
+    /// refundable and matched to its message by relayers like any other one, at the cost of one gas
+    /// service call per message. When paying with SPL tokens, each share must reach the minimum
+    /// payment of the token, or the whole batch is rejected.
+    ///
+    /// 0. [writable,signer] The address of the payer
+    /// 1. [writable] The source account from which the tokens are being transferred
+    /// 2. [] The mint account (token address)
+    /// 3. [] The token manager account associated with the interchain token
+    /// 4. [writable] The token manager Associated Token Account associated with the mint
+    /// 5. [] The token program account that was used to create the mint (`spl_token` vs `spl_token_2022`)
+    /// 6. [writable] The account tracking the flow of this mint for the current epoch
+    /// 7. [] The account tracking the flow of this mint for the previous epoch
+    /// 8. [] The GMP gateway root account
+    /// 9. [] The GMP gateway program account
+    /// 10. [writable] The GMP gas configuration account
+    /// 11. [] The GMP gas service program account
+    /// 12. [] The system program account
+    /// 13. [] The ITS root account
+    /// 14. [] The GMP call contract signing account
+    /// 15. [] The ITS program account
+    /// 16. [] The trusted chain account of the destination chain
+    /// 17. [] The [`axelar_solana_gas_service::NATIVE_GAS_TOKEN`] account when paying for gas
+    ///    with native SOL, or the mint account of the token used to pay for gas, see
+    ///    [`with_spl_gas_payment`]. This and the following accounts come one position earlier
+    ///    when no destination chain is given.
+    ///
+    /// When paying for gas with SPL tokens:
+    ///
+    /// 18. [writable] The token account of the payer the gas is paid from
+    /// 19. [writable] The token account of the GMP gas configuration account
+    /// 20. [] The token program account of the token used to pay for gas
+    /// 21. [] The account allowing the token to be used to pay for gas
+    ///
+    /// The [writable] gas reserve account of the token used to pay for gas follows, then the
+    /// [writable] gas payment ledger accounts when paying for gas, see
+    /// [`with_gas_payment_accounts`].
+    ///
+    /// When the mint has a transfer hook and is locked by the `TokenManager`, the accounts required
+    /// by the hook come last, see [`with_transfer_hook_accounts`].
+    InterchainTransferBatch {
+        /// The token id associated with the token
+        token_id: [u8; 32],
+
+        /// The chain where the tokens are being transferred to.
+        destination_chain: String,
+
+        /// The transfers of the batch.
+        transfers: Vec<BatchedTransfer>,
+
+        /// The gas value to be split between the messages of the batch
+        gas_value: u64,
+
+        /// The bump from the call contract signing account PDA derivation
+        signing_pda_bump: u8,
+    },
+
+    /// Sets the maximum supply an interchain token can reach by minting
+    /// through ITS, either with [`Self::MintInterchainToken`] or when tokens
+    /// are received from other chains. Lowering the cap below the current
+    /// supply prevents further minting.
+    ///
+    /// 0. [signer] The operator of the [`TokenManager`].
+    /// 1. [] ITS root PDA account.
+    /// 2. [writable] The [`TokenManager`] PDA account.
+    /// 3. [] The PDA account with the operator roles on the [`TokenManager`].
+    /// 4. [] The system program account.
+    /// 5..N [signer] If the operator is a [`Multisig`](role_management::state::Multisig), the
+    ///    members of the multisig approving the change. See [`with_multisig_signers`].
+    SetTokenManagerSupplyCap {
+        /// The new supply cap. Zero removes the cap.
+        supply_cap: u64,
+    },
+
+    /// Sets the amount of tokens a minter can mint with [`Self::MintInterchainToken`] within a
+    /// refill period.
+    ///
+    /// 0. [writable, signer] The operator of the [`TokenManager`], signing unless it's a
+    ///    [`Multisig`](role_management::state::Multisig).
+    /// 1. [] The PDA account with the operator roles on the [`TokenManager`].
+    /// 2. [] ITS root PDA account.
+    /// 3. [] The [`TokenManager`] PDA account.
+    /// 4. [] The minter account.
+    /// 5. [writable] The [`MinterAllowance`] PDA of the minter.
+    /// 6. [] The system program account.
+    /// 7. [writable, signer] Only when the operator is a multisig, the payer of any rent.
+    /// 8..N [signer] Only when the operator is a multisig, the members of the multisig
+    ///    approving the change. See [`with_multisig_authority`].
+    ///
+    /// [`MinterAllowance`]: crate::state::minter_allowance::MinterAllowance
+    SetInterchainTokenMinterAllowance {
+        /// The amount of tokens the minter can mint within a refill period.
+        allowance: u64,
+
+        /// The duration of a refill period, in seconds. Zero means the allowance is never
+        /// refilled.
+        refill_period: u64,
+    },
+
+    /// Grants the flow limiter role on a [`TokenManager`] for a limited time or a limited
+    /// number of [`Self::SetTokenManagerFlowLimit`] calls.
+    ///
+    /// 0. [] System program account.
+    /// 1. [writable] Authority account, signing unless it's a
+    ///    [`Multisig`](role_management::state::Multisig) (must have operator role).
+    /// 2. [] PDA for the authority roles on the token manager.
+    /// 3. [] PDA for the token manager.
+    /// 4. [] Account to grant the flow limiter role to.
+    /// 5. [writable] PDA with the roles on the token manager for the flow limiter.
+    /// 6. [writable] Role index PDA of the resource, updated with the new roles. See
+    ///    [`role_management::state::RoleIndex`].
+    /// 7. [writable, signer] Only when the authority is a multisig, the payer of any rent.
+    /// 8..N [signer] Only when the authority is a multisig, the members of the multisig
+    ///    approving the change. See [`with_multisig_authority`].
+    GrantTokenManagerFlowLimiter {
+        /// Unix timestamp from which the role is no longer granted. `None` means the grant
+        /// doesn't expire.
+        expires_at: Option<i64>,
+
+        /// The number of times the flow limit can be set with the role. `None` means the
+        /// number of uses is not limited.
+        max_uses: Option<u64>,
+    },
+
+    /// Removes the expired and exhausted role grants of a user, closing the roles PDA of the
+    /// user if no roles are left. Anyone can call this.
+    ///
+    /// 0. [] System program account.
+    /// 1. [signer] Payer account.
+    /// 2. [] The resource the roles are held on, either the ITS root PDA or a
+    ///    [`TokenManager`] PDA.
+    /// 3. [writable] The user account, which receives the rent of the roles PDA when it's
+    ///    closed.
+    /// 4. [writable] PDA with the roles of the user on the resource.
+    /// 5. [writable] Role index PDA of the resource, updated with the new roles. See
+    ///    [`role_management::state::RoleIndex`].
+    CleanupExpiredRoles,
+
+    /// Records the current roles of a user in the role index of a resource, creating the
+    /// index if needed. Anyone can call this, allowing the roles set up when ITS or a
+    /// [`TokenManager`] is created to be indexed.
+    ///
+    /// 0. [] System program account.
+    /// 1. [writable, signer] Payer account.
+    /// 2. [] The resource the roles are held on, either the ITS root PDA or a
+    ///    [`TokenManager`] PDA.
+    /// 3. [] The user account.
+    /// 4. [] PDA with the roles of the user on the resource.
+    /// 5. [writable] The role index PDA of the resource. See
+    ///    [`role_management::state::RoleIndex`].
+    SyncRoleIndex,
+
+    /// Creates a native M-of-N [`Multisig`](role_management::state::Multisig) which can hold
+    /// roles on ITS or on a [`TokenManager`] like any other account. Actions of the multisig
+    /// are approved by having enough of its members sign the instruction, see
+    /// [`with_multisig_signers`].
+    ///
+    /// 0. [] System program account.
+    /// 1. [writable, signer] Payer account.
+    /// 2. [writable] The multisig PDA, derived with [`role_management::find_multisig_pda`].
+    CreateMultisig {
+        /// The members of the multisig.
+        signers: Vec<Pubkey>,
+
+        /// The number of members required to approve an action.
+        threshold: u8,
+    },
+
+    /// Cancels an operatorship transfer proposal, returning its rent to the proposer. The
+    /// proposer can cancel the proposal at any time, anyone else only once it has expired.
+    ///
+    /// 0. [signer] Payer account.
+    /// 1. [] ITS root PDA.
+    /// 2. [writable] Account which the operatorship was proposed from.
+    /// 3. [] Account which the operatorship was proposed to.
+    /// 4. [writable] PDA for the proposal
+    /// 5..N [signer] If the operatorship was proposed from a
+    ///    [`Multisig`](role_management::state::Multisig), the members of the multisig approving
+    ///    the cancellation. See [`with_multisig_signers`].
+    CancelOperatorshipProposal,
+
+    /// Cancels a [`TokenManager`] operatorship transfer proposal, returning its rent to the
+    /// proposer. The proposer can cancel the proposal at any time, anyone else only once it
+    /// has expired.
+    ///
+    /// 0. [] ITS root PDA.
+    /// 1. [signer] Payer account.
+    /// 2. [] The [`TokenManager`] PDA.
+    /// 3. [writable] Account which the operatorship was proposed from.
+    /// 4. [] Account which the operatorship was proposed to.
+    /// 5. [writable] PDA for the proposal
+    /// 6..N [signer] If the operatorship was proposed from a
+    ///    [`Multisig`](role_management::state::Multisig), the members of the multisig approving
+    ///    the cancellation. See [`with_multisig_signers`].
+    CancelTokenManagerOperatorshipProposal,
+
+    /// Cancels a mintership transfer proposal, returning its rent to the proposer. The
+    /// proposer can cancel the proposal at any time, anyone else only once it has expired.
+    ///
+    /// 0. [] ITS root PDA.
+    /// 1. [signer] Payer account.
+    /// 2. [] The [`TokenManager`] PDA.
+    /// 3. [writable] Account which the mintership was proposed from.
+    /// 4. [] Account which the mintership was proposed to.
+    /// 5. [writable] PDA for the proposal
+    /// 6..N [signer] If the mintership was proposed from a
+    ///    [`Multisig`](role_management::state::Multisig), the members of the multisig approving
+    ///    the cancellation. See [`with_multisig_signers`].
+    CancelInterchainTokenMintershipProposal,
+
+    /// Closes an expired approval of a deployment of remote token with a destination minter,
+    /// returning its rent to the minter. Anyone can call this.
+    ///
+    /// 0. [writable] The minter which approved the deployment.
+    /// 1. [writable] The account holding the approval of the deployment.
+    CloseExpiredDeployApproval {
+        /// The id of the `InterchainToken` the deployment was approved for.
+        token_id: [u8; 32],
+        /// The remote chain where the `InterchainToken` would be deployed.
+        destination_chain: String,
+    },
+
+    /// Grants the minter role on an interchain token for a limited time or a limited number of
+    /// [`Self::MintInterchainToken`] calls.
+    ///
+    /// 0. [] System program account.
+    /// 1. [writable] Authority account, signing unless it's a
+    ///    [`Multisig`](role_management::state::Multisig) (must hold the minter role permanently).
+    /// 2. [] PDA for the authority roles on the token manager.
+    /// 3. [] PDA for the token manager.
+    /// 4. [] Account to grant the minter role to.
+    /// 5. [writable] PDA with the roles on the token manager for the minter.
+    /// 6. [writable] Role index PDA of the resource, updated with the new roles. See
+    ///    [`role_management::state::RoleIndex`].
+    /// 7. [writable, signer] Only when the authority is a multisig, the payer of any rent.
+    /// 8..N [signer] Only when the authority is a multisig, the members of the multisig
+    ///    approving the change. See [`with_multisig_authority`].
+    GrantInterchainTokenMinter {
+        /// Unix timestamp from which the role is no longer granted. `None` means the grant
+        /// doesn't expire.
+        expires_at: Option<i64>,
+
+        /// The number of times tokens can be minted with the role. `None` means the number of
+        /// uses is not limited.
+        max_uses: Option<u64>,
+    },
+}
+
+/// A single transfer of an [`InterchainTokenServiceInstruction::InterchainTransferBatch`].
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct BatchedTransfer {
+    /// The address on the destination chain to send the tokens to.
+    pub destination_address: Vec<u8>,
+
+    /// Amount of tokens being transferred.
+    pub amount: u64,
 }
 
 /// Inputs for the [`its_gmp_payload`] function.
@@ -717,7 +1358,7 @@ pub struct ItsGmpInstructionInputs {
     /// The Axelar GMP metadata.
     pub(crate) message: Message,
 
-    /// The ITS GMP payload.
+    /// The ITS GMP payload, as received from the ITS Hub (`ReceiveFromHub`).
     pub(crate) payload: GMPPayload,
 
     /// The token program required by the instruction (spl-token or
@@ -730,9 +1371,16 @@ pub struct ItsGmpInstructionInputs {
     #[builder(default, setter(strip_option(fallback = mint_opt)))]
     pub(crate) mint: Option<Pubkey>,
 
-    /// The current approximate timestamp. Required for `InterchainTransfer`s.
-    #[builder(default, setter(strip_option(fallback = timestamp_opt)))]
-    pub(crate) timestamp: Option<i64>,
+    /// The current flow epoch of the `TokenManager`, see
+    /// [`flow_limit::FlowLimit::flow_epoch`]. Required for `InterchainTransfer`s.
+    #[builder(default, setter(strip_option(fallback = flow_epoch_opt)))]
+    pub(crate) flow_epoch: Option<u64>,
+
+    /// The accounts required by the transfer hook of the mint, when tokens of
+    /// an `InterchainTransfer` are unlocked from a lock/unlock `TokenManager`.
+    /// See [`with_transfer_hook_accounts`].
+    #[builder(default)]
+    pub(crate) transfer_hook_accounts: Vec<AccountMeta>,
 }
 
 /// Creates an [`InterchainTokenServiceInstruction::Initialize`] instruction.
@@ -751,6 +1399,7 @@ pub fn initialize(
         Pubkey::find_program_address(&[crate::ID.as_ref()], &bpf_loader_upgradeable::ID);
     let (user_roles_pda, _) =
         role_management::find_user_roles_pda(&crate::ID, &its_root_pda, &operator);
+    let (role_index_pda, _) = role_management::find_role_index_pda(&crate::ID, &its_root_pda);
 
     let data = to_vec(&InterchainTokenServiceInstruction::Initialize {
         chain_name,
@@ -764,6 +1413,7 @@ pub fn initialize(
         AccountMeta::new_readonly(system_program::ID, false),
         AccountMeta::new_readonly(operator, false),
         AccountMeta::new(user_roles_pda, false),
+        AccountMeta::new(role_index_pda, false),
     ];
 
     Ok(Instruction {
@@ -804,24 +1454,94 @@ pub fn set_pause_status(payer: Pubkey, paused: bool) -> Result<Instruction, Prog
 /// # Errors
 ///
 /// [`ProgramError::BorshIoError`]: When instruction serialization fails.
-pub fn set_trusted_chain(payer: Pubkey, chain_name: String) -> Result<Instruction, ProgramError> {
+pub fn set_trusted_chain(
+    payer: Pubkey,
+    chain_name: String,
+    its_address: String,
+    decimals_policy: state::trusted_chain::DecimalsPolicy,
+) -> Result<Instruction, ProgramError> {
+    let accounts = trusted_chain_accounts(payer, &chain_name);
+    let data = to_vec(&InterchainTokenServiceInstruction::SetTrustedChain {
+        chain_name,
+        its_address,
+        decimals_policy,
+    })?;
+
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts,
+        data,
+    })
+}
+
+/// Creates an [`InterchainTokenServiceInstruction::RemoveTrustedChain`] instruction.
+///
+/// # Errors
+///
+/// [`ProgramError::BorshIoError`]: When instruction serialization fails.
+pub fn remove_trusted_chain(
+    payer: Pubkey,
+    chain_name: String,
+) -> Result<Instruction, ProgramError> {
+    let accounts = trusted_chain_accounts(payer, &chain_name);
+    let data = to_vec(&InterchainTokenServiceInstruction::RemoveTrustedChain { chain_name })?;
+
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts,
+        data,
+    })
+}
+
+/// Creates an [`InterchainTokenServiceInstruction::SetTrustedChainPauseStatus`] instruction.
+///
+/// # Errors
+///
+/// [`ProgramError::BorshIoError`]: When instruction serialization fails.
+pub fn set_trusted_chain_pause_status(
+    payer: Pubkey,
+    chain_name: String,
+    paused: bool,
+) -> Result<Instruction, ProgramError> {
+    let accounts = trusted_chain_accounts(payer, &chain_name);
+    let data = to_vec(
+        &InterchainTokenServiceInstruction::SetTrustedChainPauseStatus { chain_name, paused },
+    )?;
+
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts,
+        data,
+    })
+}
+
+/// Creates an [`InterchainTokenServiceInstruction::MigrateTrustedChains`] instruction.
+///
+/// # Errors
+///
+/// [`ProgramError::BorshIoError`]: When instruction serialization fails.
+pub fn migrate_trusted_chains(
+    payer: Pubkey,
+    trusted_chains: Vec<(String, String)>,
+) -> Result<Instruction, ProgramError> {
     let (program_data_address, _) =
         Pubkey::find_program_address(&[crate::ID.as_ref()], &bpf_loader_upgradeable::ID);
-
     let (its_root_pda, _) = crate::find_its_root_pda();
-    let (payer_roles_pda, _) =
-        role_management::find_user_roles_pda(&crate::ID, &its_root_pda, &payer);
-
-    let data = to_vec(&InterchainTokenServiceInstruction::SetTrustedChain { chain_name })?;
 
-    let accounts = vec![
+    let mut accounts = vec![
         AccountMeta::new(payer, true),
-        AccountMeta::new_readonly(payer_roles_pda, false),
         AccountMeta::new_readonly(program_data_address, false),
         AccountMeta::new(its_root_pda, false),
         AccountMeta::new_readonly(system_program::ID, false),
     ];
 
+    for (chain_name, _) in &trusted_chains {
+        let (trusted_chain_pda, _) = crate::find_trusted_chain_pda(&its_root_pda, chain_name);
+        accounts.push(AccountMeta::new(trusted_chain_pda, false));
+    }
+
+    let data = to_vec(&InterchainTokenServiceInstruction::MigrateTrustedChains { trusted_chains })?;
+
     Ok(Instruction {
         program_id: crate::ID,
         accounts,
@@ -829,31 +1549,101 @@ pub fn set_trusted_chain(payer: Pubkey, chain_name: String) -> Result<Instructio
     })
 }
 
-/// Creates an [`InterchainTokenServiceInstruction::RemoveTrustedChain`] instruction.
+/// Creates an [`InterchainTokenServiceInstruction::CloseFlowSlot`] instruction.
 ///
 /// # Errors
 ///
 /// [`ProgramError::BorshIoError`]: When instruction serialization fails.
-pub fn remove_trusted_chain(
+pub fn close_flow_slot(
+    token_id: [u8; 32],
+    token_manager_payer: Pubkey,
+    epoch: u64,
+) -> Result<Instruction, ProgramError> {
+    let (its_root_pda, _) = crate::find_its_root_pda();
+    let (token_manager_pda, _) = crate::find_token_manager_pda(&its_root_pda, &token_id);
+    let (flow_slot_pda, _) = crate::find_flow_slot_pda(&token_manager_pda, epoch);
+
+    let accounts = vec![
+        AccountMeta::new_readonly(its_root_pda, false),
+        AccountMeta::new_readonly(token_manager_pda, false),
+        AccountMeta::new(flow_slot_pda, false),
+        AccountMeta::new(token_manager_payer, false),
+    ];
+
+    let data = to_vec(&InterchainTokenServiceInstruction::CloseFlowSlot { epoch })?;
+
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts,
+        data,
+    })
+}
+
+/// Creates an [`InterchainTokenServiceInstruction::UpdateInterchainTokenMetadata`] instruction.
+///
+/// # Errors
+///
+/// [`ProgramError::BorshIoError`]: When instruction serialization fails.
+pub fn update_interchain_token_metadata(
     payer: Pubkey,
-    chain_name: String,
+    token_id: [u8; 32],
+    name: String,
+    symbol: String,
+    uri: String,
+    destination_chain: Option<String>,
+    gas_value: u64,
+    gas_service: Pubkey,
+    gas_config_pda: Pubkey,
 ) -> Result<Instruction, ProgramError> {
-    let (program_data_address, _) =
-        Pubkey::find_program_address(&[crate::ID.as_ref()], &bpf_loader_upgradeable::ID);
+    let (gateway_root_pda, _) = axelar_solana_gateway::get_gateway_root_config_pda();
     let (its_root_pda, _) = crate::find_its_root_pda();
+    let (token_manager_pda, _) = crate::find_token_manager_pda(&its_root_pda, &token_id);
     let (payer_roles_pda, _) =
-        role_management::find_user_roles_pda(&crate::ID, &its_root_pda, &payer);
-
-    let data = to_vec(&InterchainTokenServiceInstruction::RemoveTrustedChain { chain_name })?;
+        role_management::find_user_roles_pda(&crate::ID, &token_manager_pda, &payer);
+    let (mint, _) = crate::find_interchain_token_pda(&its_root_pda, &token_id);
+    let (metadata_account_key, _) = mpl_token_metadata::accounts::Metadata::find_pda(&mint);
+    let (call_contract_signing_pda, signing_pda_bump) =
+        axelar_solana_gateway::get_call_contract_signing_pda(crate::ID);
 
-    let accounts = vec![
+    let mut accounts = vec![
         AccountMeta::new(payer, true),
         AccountMeta::new_readonly(payer_roles_pda, false),
-        AccountMeta::new_readonly(program_data_address, false),
-        AccountMeta::new(its_root_pda, false),
+        AccountMeta::new_readonly(token_manager_pda, false),
+        AccountMeta::new(mint, false),
+        AccountMeta::new(metadata_account_key, false),
+        AccountMeta::new_readonly(mpl_token_metadata::ID, false),
+        AccountMeta::new_readonly(sysvar::instructions::ID, false),
+        AccountMeta::new_readonly(spl_token_2022::ID, false),
+        AccountMeta::new_readonly(gateway_root_pda, false),
+        AccountMeta::new_readonly(axelar_solana_gateway::ID, false),
+        AccountMeta::new(gas_config_pda, false),
+        AccountMeta::new_readonly(gas_service, false),
         AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new_readonly(its_root_pda, false),
+        AccountMeta::new_readonly(call_contract_signing_pda, false),
+        AccountMeta::new_readonly(crate::ID, false),
     ];
 
+    if let Some(ref destination_chain) = destination_chain {
+        let (trusted_chain_pda, _) =
+            crate::find_trusted_chain_pda(&its_root_pda, destination_chain);
+        accounts.push(AccountMeta::new_readonly(trusted_chain_pda, false));
+    }
+    accounts.push(native_gas_payment_account());
+    accounts.push(native_gas_reserve_account(&gas_config_pda));
+
+    let data = to_vec(
+        &InterchainTokenServiceInstruction::UpdateInterchainTokenMetadata {
+            token_id,
+            name,
+            symbol,
+            uri,
+            destination_chain,
+            gas_value,
+            signing_pda_bump,
+        },
+    )?;
+
     Ok(Instruction {
         program_id: crate::ID,
         accounts,
@@ -861,6 +1651,24 @@ pub fn remove_trusted_chain(
     })
 }
 
+fn trusted_chain_accounts(payer: Pubkey, chain_name: &str) -> Vec<AccountMeta> {
+    let (program_data_address, _) =
+        Pubkey::find_program_address(&[crate::ID.as_ref()], &bpf_loader_upgradeable::ID);
+    let (its_root_pda, _) = crate::find_its_root_pda();
+    let (payer_roles_pda, _) =
+        role_management::find_user_roles_pda(&crate::ID, &its_root_pda, &payer);
+    let (trusted_chain_pda, _) = crate::find_trusted_chain_pda(&its_root_pda, chain_name);
+
+    vec![
+        AccountMeta::new(payer, true),
+        AccountMeta::new_readonly(payer_roles_pda, false),
+        AccountMeta::new_readonly(program_data_address, false),
+        AccountMeta::new_readonly(its_root_pda, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new(trusted_chain_pda, false),
+    ]
+}
+
 /// Creates an [`InterchainTokenServiceInstruction::ApproveDeployRemoteInterchainToken`] instruction.
 ///
 /// Allow the minter to approve the deployer for a remote interchain token deployment that uses a
@@ -875,6 +1683,7 @@ pub fn remove_trusted_chain(
 /// `salt`: The unique salt for deploying the token.
 /// `destination_chain`: The name of the destination chain.
 /// `destination_minter`: The minter address to set on the deployed token on the destination chain. This can be arbitrary bytes since the encoding of the account is dependent on the destination chain.
+/// `expires_at`: The unix timestamp after which the approval can no longer be used, if any.
 ///
 /// # Errors
 ///
@@ -885,6 +1694,7 @@ pub fn approve_deploy_remote_interchain_token(
     salt: [u8; 32],
     destination_chain: String,
     destination_minter: Vec<u8>,
+    expires_at: Option<i64>,
 ) -> Result<Instruction, ProgramError> {
     let (its_root_pda, _) = crate::find_its_root_pda();
     let token_id = crate::interchain_token_id(&deployer, &salt);
@@ -897,7 +1707,7 @@ pub fn approve_deploy_remote_interchain_token(
     let accounts = vec![
         AccountMeta::new(payer, true),
         AccountMeta::new_readonly(token_manager_pda, false),
-        AccountMeta::new_readonly(roles_pda, false),
+        AccountMeta::new(roles_pda, false),
         AccountMeta::new(deploy_approval_pda, false),
         AccountMeta::new_readonly(system_program::ID, false),
     ];
@@ -908,6 +1718,7 @@ pub fn approve_deploy_remote_interchain_token(
             salt,
             destination_chain,
             destination_minter,
+            expires_at,
         },
     )?;
 
@@ -939,20 +1750,58 @@ pub fn revoke_deploy_remote_interchain_token(
     salt: [u8; 32],
     destination_chain: String,
 ) -> Result<Instruction, ProgramError> {
-    let token_id = crate::interchain_token_id(&deployer, &salt);
+    let token_id = crate::interchain_token_id(&deployer, &salt);
+    let (deploy_approval_pda, _) =
+        crate::find_deployment_approval_pda(&payer, &token_id, &destination_chain);
+
+    let accounts = vec![
+        AccountMeta::new(payer, true),
+        AccountMeta::new(deploy_approval_pda, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+    ];
+
+    let data = to_vec(
+        &InterchainTokenServiceInstruction::RevokeDeployRemoteInterchainToken {
+            deployer,
+            salt,
+            destination_chain,
+        },
+    )?;
+
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts,
+        data,
+    })
+}
+
+/// Creates an [`InterchainTokenServiceInstruction::CloseExpiredDeployApproval`] instruction.
+///
+/// # Parameters
+///
+/// `minter`: The minter which approved the deployment, receiving the rent of the approval.
+/// `token_id`: The id of the `InterchainToken` the deployment was approved for.
+/// `destination_chain`: The name of the destination chain.
+///
+/// # Errors
+///
+/// [`ProgramError::BorshIoError`]: When instruction serialization fails.
+pub fn close_expired_deploy_approval(
+    minter: Pubkey,
+    token_id: [u8; 32],
+    destination_chain: String,
+) -> Result<Instruction, ProgramError> {
     let (deploy_approval_pda, _) =
-        crate::find_deployment_approval_pda(&payer, &token_id, &destination_chain);
+        crate::find_deployment_approval_pda(&minter, &token_id, &destination_chain);
 
     let accounts = vec![
-        AccountMeta::new(payer, true),
+        AccountMeta::new(minter, false),
         AccountMeta::new(deploy_approval_pda, false),
-        AccountMeta::new_readonly(system_program::ID, false),
     ];
 
     let data = to_vec(
-        &InterchainTokenServiceInstruction::RevokeDeployRemoteInterchainToken {
-            deployer,
-            salt,
+        &InterchainTokenServiceInstruction::CloseExpiredDeployApproval {
+            token_id,
             destination_chain,
         },
     )?;
@@ -983,6 +1832,7 @@ pub fn register_canonical_interchain_token(
     let (token_metadata_account, _) = mpl_token_metadata::accounts::Metadata::find_pda(&mint);
     let (its_user_roles_pda, _) =
         role_management::find_user_roles_pda(&crate::ID, &token_manager_pda, &its_root_pda);
+    let (role_index_pda, _) = role_management::find_role_index_pda(&crate::ID, &token_manager_pda);
 
     let accounts = vec![
         AccountMeta::new(payer, true),
@@ -995,6 +1845,7 @@ pub fn register_canonical_interchain_token(
         AccountMeta::new_readonly(token_program, false),
         AccountMeta::new_readonly(spl_associated_token_account::ID, false),
         AccountMeta::new(its_user_roles_pda, false),
+        AccountMeta::new(role_index_pda, false),
         AccountMeta::new_readonly(sysvar::rent::ID, false),
     ];
 
@@ -1027,6 +1878,8 @@ pub fn deploy_remote_canonical_interchain_token(
         axelar_solana_gateway::get_call_contract_signing_pda(crate::ID);
     let (metadata_account_key, _) = mpl_token_metadata::accounts::Metadata::find_pda(&mint);
 
+    let (trusted_chain_pda, _) = crate::find_trusted_chain_pda(&its_root_pda, &destination_chain);
+
     let accounts = vec![
         AccountMeta::new(payer, true),
         AccountMeta::new_readonly(mint, false),
@@ -1039,6 +1892,9 @@ pub fn deploy_remote_canonical_interchain_token(
         AccountMeta::new_readonly(its_root_pda, false),
         AccountMeta::new_readonly(call_contract_signing_pda, false),
         AccountMeta::new_readonly(crate::ID, false),
+        AccountMeta::new_readonly(trusted_chain_pda, false),
+        native_gas_payment_account(),
+        native_gas_reserve_account(&gas_config_pda),
     ];
 
     let data = to_vec(
@@ -1057,7 +1913,7 @@ pub fn deploy_remote_canonical_interchain_token(
 }
 
 /// Creates an [`InterchainTokenServiceInstruction::DeployInterchainToken`]
-/// instruction.
+/// instruction which stores the token metadata in a Metaplex metadata account.
 ///
 /// # Errors
 ///
@@ -1070,6 +1926,35 @@ pub fn deploy_interchain_token(
     decimals: u8,
     initial_supply: u64,
     minter: Option<Pubkey>,
+) -> Result<Instruction, ProgramError> {
+    deploy_interchain_token_with_metadata_standard(
+        payer,
+        salt,
+        name,
+        symbol,
+        decimals,
+        initial_supply,
+        minter,
+        MetadataStandard::Metaplex,
+    )
+}
+
+/// Creates an [`InterchainTokenServiceInstruction::DeployInterchainToken`]
+/// instruction which stores the token metadata according to the given
+/// [`MetadataStandard`].
+///
+/// # Errors
+///
+/// [`ProgramError::BorshIoError`]: When instruction serialization fails.
+pub fn deploy_interchain_token_with_metadata_standard(
+    payer: Pubkey,
+    salt: [u8; 32],
+    name: String,
+    symbol: String,
+    decimals: u8,
+    initial_supply: u64,
+    minter: Option<Pubkey>,
+    metadata_standard: MetadataStandard,
 ) -> Result<Instruction, ProgramError> {
     let (its_root_pda, _) = crate::find_its_root_pda();
     let token_id = crate::interchain_token_id(&payer, &salt);
@@ -1084,7 +1969,7 @@ pub fn deploy_interchain_token(
         get_associated_token_address_with_program_id(&payer, &mint, &spl_token_2022::ID);
     let (its_user_roles_pda, _) =
         role_management::find_user_roles_pda(&crate::ID, &token_manager_pda, &its_root_pda);
-    let (metadata_account_key, _) = mpl_token_metadata::accounts::Metadata::find_pda(&mint);
+    let (role_index_pda, _) = role_management::find_role_index_pda(&crate::ID, &token_manager_pda);
 
     let mut accounts = vec![
         AccountMeta::new(payer, true),
@@ -1096,13 +1981,19 @@ pub fn deploy_interchain_token(
         AccountMeta::new_readonly(spl_token_2022::ID, false),
         AccountMeta::new_readonly(spl_associated_token_account::ID, false),
         AccountMeta::new(its_user_roles_pda, false),
+        AccountMeta::new(role_index_pda, false),
         AccountMeta::new_readonly(sysvar::rent::ID, false),
         AccountMeta::new_readonly(sysvar::instructions::ID, false),
-        AccountMeta::new_readonly(mpl_token_metadata::ID, false),
-        AccountMeta::new(metadata_account_key, false),
-        AccountMeta::new(payer_ata, false),
     ];
 
+    if metadata_standard == MetadataStandard::Metaplex {
+        let (metadata_account_key, _) = mpl_token_metadata::accounts::Metadata::find_pda(&mint);
+        accounts.push(AccountMeta::new_readonly(mpl_token_metadata::ID, false));
+        accounts.push(AccountMeta::new(metadata_account_key, false));
+    }
+
+    accounts.push(AccountMeta::new(payer_ata, false));
+
     if let Some(minter) = minter {
         let (minter_roles_pda, _) =
             role_management::find_user_roles_pda(&crate::ID, &token_manager_pda, &minter);
@@ -1116,6 +2007,7 @@ pub fn deploy_interchain_token(
         symbol,
         decimals,
         initial_supply,
+        metadata_standard,
     })?;
 
     Ok(Instruction {
@@ -1147,6 +2039,8 @@ pub fn deploy_remote_interchain_token(
         axelar_solana_gateway::get_call_contract_signing_pda(crate::ID);
     let (metadata_account_key, _) = mpl_token_metadata::accounts::Metadata::find_pda(&mint);
 
+    let (trusted_chain_pda, _) = crate::find_trusted_chain_pda(&its_root_pda, &destination_chain);
+
     let accounts = vec![
         AccountMeta::new(payer, true),
         AccountMeta::new_readonly(mint, false),
@@ -1159,6 +2053,9 @@ pub fn deploy_remote_interchain_token(
         AccountMeta::new_readonly(its_root_pda, false),
         AccountMeta::new_readonly(call_contract_signing_pda, false),
         AccountMeta::new_readonly(crate::ID, false),
+        AccountMeta::new_readonly(trusted_chain_pda, false),
+        native_gas_payment_account(),
+        native_gas_reserve_account(&gas_config_pda),
     ];
 
     let data = to_vec(
@@ -1206,13 +2103,15 @@ pub fn deploy_remote_interchain_token_with_minter(
     let (minter_roles_pda, _) =
         role_management::find_user_roles_pda(&crate::ID, &token_manager_pda, &minter);
 
+    let (trusted_chain_pda, _) = crate::find_trusted_chain_pda(&its_root_pda, &destination_chain);
+
     let accounts = vec![
         AccountMeta::new(payer, true),
         AccountMeta::new_readonly(mint, false),
         AccountMeta::new_readonly(metadata_account_key, false),
         AccountMeta::new_readonly(minter, false),
         AccountMeta::new(deploy_approval, false),
-        AccountMeta::new_readonly(minter_roles_pda, false),
+        AccountMeta::new(minter_roles_pda, false),
         AccountMeta::new_readonly(token_manager_pda, false),
         AccountMeta::new_readonly(gateway_root_pda, false),
         AccountMeta::new_readonly(axelar_solana_gateway::ID, false),
@@ -1222,6 +2121,9 @@ pub fn deploy_remote_interchain_token_with_minter(
         AccountMeta::new_readonly(its_root_pda, false),
         AccountMeta::new_readonly(call_contract_signing_pda, false),
         AccountMeta::new_readonly(crate::ID, false),
+        AccountMeta::new_readonly(trusted_chain_pda, false),
+        native_gas_payment_account(),
+        native_gas_reserve_account(&gas_config_pda),
     ];
 
     let data = to_vec(
@@ -1271,6 +2173,8 @@ pub fn register_token_metadata(
         AccountMeta::new_readonly(its_root_pda, false),
         AccountMeta::new_readonly(call_contract_signing_pda, false),
         AccountMeta::new_readonly(crate::ID, false),
+        native_gas_payment_account(),
+        native_gas_reserve_account(&gas_config_pda),
     ];
 
     let data = to_vec(&InterchainTokenServiceInstruction::RegisterTokenMetadata {
@@ -1307,6 +2211,7 @@ pub fn register_custom_token(
     let (token_metadata_account, _) = mpl_token_metadata::accounts::Metadata::find_pda(&mint);
     let (its_user_roles_pda, _) =
         role_management::find_user_roles_pda(&crate::ID, &token_manager_pda, &its_root_pda);
+    let (role_index_pda, _) = role_management::find_role_index_pda(&crate::ID, &token_manager_pda);
 
     let mut accounts = vec![
         AccountMeta::new(payer, true),
@@ -1319,6 +2224,7 @@ pub fn register_custom_token(
         AccountMeta::new_readonly(token_program, false),
         AccountMeta::new_readonly(spl_associated_token_account::ID, false),
         AccountMeta::new(its_user_roles_pda, false),
+        AccountMeta::new(role_index_pda, false),
         AccountMeta::new_readonly(sysvar::rent::ID, false),
     ];
 
@@ -1365,6 +2271,8 @@ pub fn link_token(
     let token_id = crate::linked_token_id(&payer, &salt);
     let (token_manager_pda, _) = crate::find_token_manager_pda(&its_root_pda, &token_id);
 
+    let (trusted_chain_pda, _) = crate::find_trusted_chain_pda(&its_root_pda, &destination_chain);
+
     let accounts = vec![
         AccountMeta::new(payer, true),
         AccountMeta::new_readonly(token_manager_pda, false),
@@ -1376,6 +2284,9 @@ pub fn link_token(
         AccountMeta::new_readonly(its_root_pda, false),
         AccountMeta::new_readonly(call_contract_signing_pda, false),
         AccountMeta::new_readonly(crate::ID, false),
+        AccountMeta::new_readonly(trusted_chain_pda, false),
+        native_gas_payment_account(),
+        native_gas_reserve_account(&gas_config_pda),
     ];
 
     let data = to_vec(&InterchainTokenServiceInstruction::LinkToken {
@@ -1413,18 +2324,21 @@ pub fn interchain_transfer(
     gas_value: u64,
     gas_service: Pubkey,
     gas_config_pda: Pubkey,
-    timestamp: i64,
+    flow_epoch: u64,
 ) -> Result<Instruction, ProgramError> {
     let (gateway_root_pda, _) = axelar_solana_gateway::get_gateway_root_config_pda();
     let (its_root_pda, _) = crate::find_its_root_pda();
     let (token_manager_pda, _) = crate::find_token_manager_pda(&its_root_pda, &token_id);
-    let flow_epoch = flow_limit::flow_epoch_with_timestamp(timestamp)?;
     let (flow_slot_pda, _) = crate::find_flow_slot_pda(&token_manager_pda, flow_epoch);
+    let (previous_flow_slot_pda, _) =
+        crate::find_flow_slot_pda(&token_manager_pda, flow_epoch.saturating_sub(1));
     let token_manager_ata =
         get_associated_token_address_with_program_id(&token_manager_pda, &mint, &token_program);
     let (call_contract_signing_pda, signing_pda_bump) =
         axelar_solana_gateway::get_call_contract_signing_pda(crate::ID);
 
+    let (trusted_chain_pda, _) = crate::find_trusted_chain_pda(&its_root_pda, &destination_chain);
+
     let accounts = vec![
         AccountMeta::new_readonly(payer, true),
         AccountMeta::new(source_account, false),
@@ -1433,6 +2347,7 @@ pub fn interchain_transfer(
         AccountMeta::new(token_manager_ata, false),
         AccountMeta::new_readonly(token_program, false),
         AccountMeta::new(flow_slot_pda, false),
+        AccountMeta::new_readonly(previous_flow_slot_pda, false),
         AccountMeta::new_readonly(gateway_root_pda, false),
         AccountMeta::new_readonly(axelar_solana_gateway::ID, false),
         AccountMeta::new(gas_config_pda, false),
@@ -1441,6 +2356,9 @@ pub fn interchain_transfer(
         AccountMeta::new_readonly(its_root_pda, false),
         AccountMeta::new_readonly(call_contract_signing_pda, false),
         AccountMeta::new_readonly(crate::ID, false),
+        AccountMeta::new_readonly(trusted_chain_pda, false),
+        native_gas_payment_account(),
+        native_gas_reserve_account(&gas_config_pda),
     ];
 
     let data = to_vec(&InterchainTokenServiceInstruction::InterchainTransfer {
@@ -1459,6 +2377,77 @@ pub fn interchain_transfer(
     })
 }
 
+/// Creates an [`InterchainTokenServiceInstruction::InterchainTransferBatch`]
+/// instruction.
+///
+/// # Errors
+///
+/// [`ProgramError::BorshIoError`]: When instruction serialization fails.
+pub fn interchain_transfer_batch(
+    payer: Pubkey,
+    source_account: Pubkey,
+    token_id: [u8; 32],
+    destination_chain: String,
+    transfers: Vec<BatchedTransfer>,
+    mint: Pubkey,
+    token_program: Pubkey,
+    gas_value: u64,
+    gas_service: Pubkey,
+    gas_config_pda: Pubkey,
+    flow_epoch: u64,
+) -> Result<Instruction, ProgramError> {
+    let (gateway_root_pda, _) = axelar_solana_gateway::get_gateway_root_config_pda();
+    let (its_root_pda, _) = crate::find_its_root_pda();
+    let (token_manager_pda, _) = crate::find_token_manager_pda(&its_root_pda, &token_id);
+    let (flow_slot_pda, _) = crate::find_flow_slot_pda(&token_manager_pda, flow_epoch);
+    let (previous_flow_slot_pda, _) =
+        crate::find_flow_slot_pda(&token_manager_pda, flow_epoch.saturating_sub(1));
+    let token_manager_ata =
+        get_associated_token_address_with_program_id(&token_manager_pda, &mint, &token_program);
+    let (call_contract_signing_pda, signing_pda_bump) =
+        axelar_solana_gateway::get_call_contract_signing_pda(crate::ID);
+
+    let (trusted_chain_pda, _) = crate::find_trusted_chain_pda(&its_root_pda, &destination_chain);
+
+    let accounts = vec![
+        AccountMeta::new(payer, true),
+        AccountMeta::new(source_account, false),
+        AccountMeta::new(mint, false),
+        AccountMeta::new_readonly(token_manager_pda, false),
+        AccountMeta::new(token_manager_ata, false),
+        AccountMeta::new_readonly(token_program, false),
+        AccountMeta::new(flow_slot_pda, false),
+        AccountMeta::new_readonly(previous_flow_slot_pda, false),
+        AccountMeta::new_readonly(gateway_root_pda, false),
+        AccountMeta::new_readonly(axelar_solana_gateway::ID, false),
+        AccountMeta::new(gas_config_pda, false),
+        AccountMeta::new_readonly(gas_service, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new_readonly(its_root_pda, false),
+        AccountMeta::new_readonly(call_contract_signing_pda, false),
+        AccountMeta::new_readonly(crate::ID, false),
+        AccountMeta::new_readonly(trusted_chain_pda, false),
+        native_gas_payment_account(),
+        native_gas_reserve_account(&gas_config_pda),
+    ];
+
+    let data = to_vec(
+        &InterchainTokenServiceInstruction::InterchainTransferBatch {
+            token_id,
+            destination_chain,
+            transfers,
+            gas_value,
+            signing_pda_bump,
+        },
+    )?;
+
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts,
+        data,
+    })
+}
+
 /// Creates an [`InterchainTokenServiceInstruction::CallContractWithInterchainToken`]
 /// instruction.
 ///
@@ -1478,17 +2467,21 @@ pub fn call_contract_with_interchain_token(
     gas_value: u64,
     gas_service: Pubkey,
     gas_config_pda: Pubkey,
-    timestamp: i64,
+    flow_epoch: u64,
 ) -> Result<Instruction, ProgramError> {
+    let (gateway_root_pda, _) = axelar_solana_gateway::get_gateway_root_config_pda();
     let (its_root_pda, _) = crate::find_its_root_pda();
     let (token_manager_pda, _) = crate::find_token_manager_pda(&its_root_pda, &token_id);
-    let flow_epoch = flow_limit::flow_epoch_with_timestamp(timestamp)?;
     let (flow_slot_pda, _) = crate::find_flow_slot_pda(&token_manager_pda, flow_epoch);
+    let (previous_flow_slot_pda, _) =
+        crate::find_flow_slot_pda(&token_manager_pda, flow_epoch.saturating_sub(1));
     let token_manager_ata =
         get_associated_token_address_with_program_id(&token_manager_pda, &mint, &token_program);
     let (call_contract_signing_pda, signing_pda_bump) =
         axelar_solana_gateway::get_call_contract_signing_pda(crate::ID);
 
+    let (trusted_chain_pda, _) = crate::find_trusted_chain_pda(&its_root_pda, &destination_chain);
+
     let accounts = vec![
         AccountMeta::new_readonly(payer, true),
         AccountMeta::new(source_account, false),
@@ -1497,6 +2490,8 @@ pub fn call_contract_with_interchain_token(
         AccountMeta::new(token_manager_ata, false),
         AccountMeta::new_readonly(token_program, false),
         AccountMeta::new(flow_slot_pda, false),
+        AccountMeta::new_readonly(previous_flow_slot_pda, false),
+        AccountMeta::new_readonly(gateway_root_pda, false),
         AccountMeta::new_readonly(axelar_solana_gateway::ID, false),
         AccountMeta::new(gas_config_pda, false),
         AccountMeta::new_readonly(gas_service, false),
@@ -1504,6 +2499,9 @@ pub fn call_contract_with_interchain_token(
         AccountMeta::new_readonly(its_root_pda, false),
         AccountMeta::new_readonly(call_contract_signing_pda, false),
         AccountMeta::new_readonly(crate::ID, false),
+        AccountMeta::new_readonly(trusted_chain_pda, false),
+        native_gas_payment_account(),
+        native_gas_reserve_account(&gas_config_pda),
     ];
 
     let data = to_vec(
@@ -1544,17 +2542,21 @@ pub fn call_contract_with_interchain_token_offchain_data(
     gas_value: u64,
     gas_service: Pubkey,
     gas_config_pda: Pubkey,
-    timestamp: i64,
+    flow_epoch: u64,
 ) -> Result<(Instruction, Vec<u8>), ProgramError> {
+    let (gateway_root_pda, _) = axelar_solana_gateway::get_gateway_root_config_pda();
     let (its_root_pda, _) = crate::find_its_root_pda();
     let (token_manager_pda, _) = crate::find_token_manager_pda(&its_root_pda, &token_id);
-    let flow_epoch = flow_limit::flow_epoch_with_timestamp(timestamp)?;
     let (flow_slot_pda, _) = crate::find_flow_slot_pda(&token_manager_pda, flow_epoch);
+    let (previous_flow_slot_pda, _) =
+        crate::find_flow_slot_pda(&token_manager_pda, flow_epoch.saturating_sub(1));
     let token_manager_ata =
         get_associated_token_address_with_program_id(&token_manager_pda, &mint, &token_program);
     let (call_contract_signing_pda, signing_pda_bump) =
         axelar_solana_gateway::get_call_contract_signing_pda(crate::ID);
 
+    let (trusted_chain_pda, _) = crate::find_trusted_chain_pda(&its_root_pda, &destination_chain);
+
     let accounts = vec![
         AccountMeta::new_readonly(payer, true),
         AccountMeta::new(source_account, false),
@@ -1563,6 +2565,8 @@ pub fn call_contract_with_interchain_token_offchain_data(
         AccountMeta::new(token_manager_ata, false),
         AccountMeta::new_readonly(token_program, false),
         AccountMeta::new(flow_slot_pda, false),
+        AccountMeta::new_readonly(previous_flow_slot_pda, false),
+        AccountMeta::new_readonly(gateway_root_pda, false),
         AccountMeta::new_readonly(axelar_solana_gateway::ID, false),
         AccountMeta::new(gas_config_pda, false),
         AccountMeta::new_readonly(gas_service, false),
@@ -1570,6 +2574,9 @@ pub fn call_contract_with_interchain_token_offchain_data(
         AccountMeta::new_readonly(its_root_pda, false),
         AccountMeta::new_readonly(call_contract_signing_pda, false),
         AccountMeta::new_readonly(crate::ID, false),
+        AccountMeta::new_readonly(trusted_chain_pda, false),
+        native_gas_payment_account(),
+        native_gas_reserve_account(&gas_config_pda),
     ];
 
     let payload = GMPPayload::SendToHub(SendToHub {
@@ -1614,6 +2621,131 @@ pub fn call_contract_with_interchain_token_offchain_data(
     ))
 }
 
+/// Appends the accounts required by the transfer hook of a mint to an instruction moving tokens
+/// from or to the [`TokenManager`] of a lock/unlock token. The accounts are resolved by the token
+/// program from the `ExtraAccountMetaList` of the hook, see
+/// [`spl_token_2022::offchain::add_extra_account_metas`], and must come last.
+///
+/// Supported instructions are the ones taking tokens from the payer:
+/// [`InterchainTokenServiceInstruction::InterchainTransfer`],
+/// [`InterchainTokenServiceInstruction::CallContractWithInterchainToken`],
+/// [`InterchainTokenServiceInstruction::CallContractWithInterchainTokenOffchainData`] and
+/// [`InterchainTokenServiceInstruction::InterchainTransferBatch`]. For incoming transfers, see
+/// [`ItsGmpInstructionInputs`].
+#[must_use]
+pub fn with_transfer_hook_accounts(
+    mut instruction: Instruction,
+    transfer_hook_accounts: Vec<AccountMeta>,
+) -> Instruction {
+    instruction.accounts.extend(transfer_hook_accounts);
+    instruction
+}
+
+/// Makes an outbound instruction pay for gas with SPL tokens instead of native SOL, in
+/// which case the `gas_value` of the instruction is denominated in units of `gas_token_mint`.
+/// The mint must be accepted to pay for gas by the gas service config.
+///
+/// Supported instructions are the ones sending a message through the Axelar network:
+/// [`InterchainTokenServiceInstruction::InterchainTransfer`],
+/// [`InterchainTokenServiceInstruction::CallContractWithInterchainToken`],
+/// [`InterchainTokenServiceInstruction::CallContractWithInterchainTokenOffchainData`],
+/// [`InterchainTokenServiceInstruction::DeployRemoteInterchainToken`],
+/// [`InterchainTokenServiceInstruction::DeployRemoteInterchainTokenWithMinter`],
+/// [`InterchainTokenServiceInstruction::DeployRemoteCanonicalInterchainToken`],
+/// [`InterchainTokenServiceInstruction::RegisterTokenMetadata`],
+/// [`InterchainTokenServiceInstruction::LinkToken`] and
+/// [`InterchainTokenServiceInstruction::UpdateInterchainTokenMetadata`].
+#[must_use]
+pub fn with_spl_gas_payment(
+    mut instruction: Instruction,
+    gas_config_pda: Pubkey,
+    payer_token_account: Pubkey,
+    gas_token_mint: Pubkey,
+    gas_token_program: Pubkey,
+) -> Instruction {
+    let gas_config_token_account = get_associated_token_address_with_program_id(
+        &gas_config_pda,
+        &gas_token_mint,
+        &gas_token_program,
+    );
+    let (gas_token_pda, _) = axelar_solana_gas_service::get_gas_token_pda(
+        &axelar_solana_gas_service::ID,
+        &gas_config_pda,
+        &gas_token_mint,
+    );
+
+    let (gas_reserve_pda, _) = axelar_solana_gas_service::get_gas_reserve_pda(
+        &axelar_solana_gas_service::ID,
+        &gas_config_pda,
+        &gas_token_mint,
+    );
+
+    // The accounts replace the native gas payment account and the gas reserve account of native
+    // SOL following it, which are the last ones before the gas payment ledger accounts and the
+    // accounts of the transfer hook, if any. It's the only system program account following the
+    // `program_account` of the outbound message accounts.
+    if let Some(index) = instruction
+        .accounts
+        .iter()
+        .rposition(|account| account == &native_gas_payment_account())
+    {
+        let end = index.saturating_add(2).min(instruction.accounts.len());
+        instruction.accounts.splice(
+            index..end,
+            [
+                AccountMeta::new_readonly(gas_token_mint, false),
+                AccountMeta::new(payer_token_account, false),
+                AccountMeta::new(gas_config_token_account, false),
+                AccountMeta::new_readonly(gas_token_program, false),
+                AccountMeta::new_readonly(gas_token_pda, false),
+                AccountMeta::new(gas_reserve_pda, false),
+            ],
+        );
+    }
+
+    instruction
+}
+
+/// Appends the gas payment ledger PDAs of an outbound instruction, one for each message paying
+/// for gas, to the instruction, see [`crate::find_gas_payment_pda`]. The ledger of a message is
+/// derived from the hash of its payload, see [`crate::hub_payload_hash`], and the ledgers are
+/// created by the gas service on the first payment, funded by the payer.
+///
+/// Single-message instructions take one account when their `gas_value` isn't zero.
+/// [`InterchainTokenServiceInstruction::InterchainTransferBatch`] takes one for each transfer
+/// its gas value is split to, in order. Supported instructions are the ones of
+/// [`with_spl_gas_payment`]. This must be applied after [`with_spl_gas_payment`] and before
+/// [`with_transfer_hook_accounts`].
+#[must_use]
+pub fn with_gas_payment_accounts(
+    mut instruction: Instruction,
+    gas_payment_accounts: &[Pubkey],
+) -> Instruction {
+    instruction.accounts.extend(
+        gas_payment_accounts
+            .iter()
+            .map(|gas_payment_account| AccountMeta::new(*gas_payment_account, false)),
+    );
+    instruction
+}
+
+/// The account standing for the payment of gas with native SOL in the outbound message accounts,
+/// which [`with_spl_gas_payment`] replaces with the accounts needed to pay gas with SPL tokens.
+fn native_gas_payment_account() -> AccountMeta {
+    AccountMeta::new_readonly(axelar_solana_gas_service::NATIVE_GAS_TOKEN, false)
+}
+
+/// The gas reserve account of native SOL, following [`native_gas_payment_account`] in the
+/// outbound message accounts.
+fn native_gas_reserve_account(gas_config_pda: &Pubkey) -> AccountMeta {
+    let (gas_reserve_pda, _) = axelar_solana_gas_service::get_gas_reserve_pda(
+        &axelar_solana_gas_service::ID,
+        gas_config_pda,
+        &axelar_solana_gas_service::NATIVE_GAS_TOKEN,
+    );
+    AccountMeta::new(gas_reserve_pda, false)
+}
+
 /// Creates an [`InterchainTokenServiceInstruction::SetFlowLimit`].
 ///
 /// # Errors
@@ -1622,7 +2754,7 @@ pub fn call_contract_with_interchain_token_offchain_data(
 pub fn set_flow_limit(
     payer: Pubkey,
     token_id: [u8; 32],
-    flow_limit: u64,
+    flow_limit: flow_limit::FlowLimit,
 ) -> Result<Instruction, ProgramError> {
     let (its_root_pda, _) = crate::find_its_root_pda();
     let (token_manager_pda, _) = crate::find_token_manager_pda(&its_root_pda, &token_id);
@@ -1651,8 +2783,13 @@ pub fn set_flow_limit(
 
 /// Creates an [`InterchainTokenServiceInstruction::ItsGmpPayload`] instruction.
 ///
+/// Only [`GMPPayload::ReceiveFromHub`] payloads are accepted, as the source chain they carry is
+/// needed to derive the trusted chain PDA.
+///
 /// # Errors
 ///
+/// [`ProgramError::InvalidInstructionData`]: When the payload is not a
+/// [`GMPPayload::ReceiveFromHub`] or its inner payload cannot be decoded.
 /// [`ProgramError::BorshIoError`]: When instruction serialization fails.
 pub fn its_gmp_payload(inputs: ItsGmpInstructionInputs) -> Result<Instruction, ProgramError> {
     let mut accounts = prefix_accounts(
@@ -1662,25 +2799,28 @@ pub fn its_gmp_payload(inputs: ItsGmpInstructionInputs) -> Result<Instruction, P
         &inputs.message,
     );
 
-    let unwrapped_payload = match inputs.payload {
-        GMPPayload::InterchainTransfer(_)
-        | GMPPayload::DeployInterchainToken(_)
-        | GMPPayload::LinkToken(_)
-        | GMPPayload::RegisterTokenMetadata(_) => inputs.payload,
-        GMPPayload::SendToHub(inner) => GMPPayload::decode(&inner.payload)
-            .map_err(|_err| ProgramError::InvalidInstructionData)?,
-        GMPPayload::ReceiveFromHub(inner) => GMPPayload::decode(&inner.payload)
-            .map_err(|_err| ProgramError::InvalidInstructionData)?,
+    // Inbound messages always come from the ITS Hub, which wraps the actual payload together with
+    // the chain it originated from.
+    let GMPPayload::ReceiveFromHub(inner) = inputs.payload else {
+        return Err(ProgramError::InvalidInstructionData);
     };
 
+    let unwrapped_payload =
+        GMPPayload::decode(&inner.payload).map_err(|_err| ProgramError::InvalidInstructionData)?;
+
+    let (its_root_pda, _) = crate::find_its_root_pda();
+    let (trusted_chain_pda, _) = crate::find_trusted_chain_pda(&its_root_pda, &inner.source_chain);
+    accounts.push(AccountMeta::new_readonly(trusted_chain_pda, false));
+
     let mut its_accounts = derive_its_accounts(
         &unwrapped_payload,
         inputs.token_program,
         inputs.mint,
-        inputs.timestamp,
+        inputs.flow_epoch,
     )?;
 
     accounts.append(&mut its_accounts);
+    accounts.extend(inputs.transfer_hook_accounts);
 
     let data = to_vec(&InterchainTokenServiceInstruction::ItsGmpPayload {
         message: inputs.message,
@@ -1706,6 +2846,7 @@ pub fn transfer_operatorship(payer: Pubkey, to: Pubkey) -> Result<Instruction, P
         role_management::find_user_roles_pda(&crate::id(), &its_root_pda, &payer);
     let (destination_roles_pda, _) =
         role_management::find_user_roles_pda(&crate::id(), &its_root_pda, &to);
+    let (role_index_pda, _) = role_management::find_role_index_pda(&crate::id(), &its_root_pda);
 
     let accounts = vec![
         AccountMeta::new_readonly(system_program::id(), false),
@@ -1714,6 +2855,7 @@ pub fn transfer_operatorship(payer: Pubkey, to: Pubkey) -> Result<Instruction, P
         AccountMeta::new_readonly(its_root_pda, false),
         AccountMeta::new_readonly(to, false),
         AccountMeta::new(destination_roles_pda, false),
+        AccountMeta::new(role_index_pda, false),
     ];
 
     let data = to_vec(&InterchainTokenServiceInstruction::TransferOperatorship)?;
@@ -1730,7 +2872,11 @@ pub fn transfer_operatorship(payer: Pubkey, to: Pubkey) -> Result<Instruction, P
 /// # Errors
 ///
 /// [`ProgramError::BorshIoError`]: When instruction serialization fails.
-pub fn propose_operatorship(payer: Pubkey, to: Pubkey) -> Result<Instruction, ProgramError> {
+pub fn propose_operatorship(
+    payer: Pubkey,
+    to: Pubkey,
+    expires_at: Option<i64>,
+) -> Result<Instruction, ProgramError> {
     let (its_root_pda, _) = crate::find_its_root_pda();
     let (payer_roles_pda, _) =
         role_management::find_user_roles_pda(&crate::id(), &its_root_pda, &payer);
@@ -1749,7 +2895,7 @@ pub fn propose_operatorship(payer: Pubkey, to: Pubkey) -> Result<Instruction, Pr
         AccountMeta::new(proposal_pda, false),
     ];
 
-    let data = to_vec(&InterchainTokenServiceInstruction::ProposeOperatorship)?;
+    let data = to_vec(&InterchainTokenServiceInstruction::ProposeOperatorship { expires_at })?;
 
     Ok(Instruction {
         program_id: crate::ID,
@@ -1771,6 +2917,7 @@ pub fn accept_operatorship(payer: Pubkey, from: Pubkey) -> Result<Instruction, P
         role_management::find_user_roles_pda(&crate::id(), &its_root_pda, &from);
     let (proposal_pda, _) =
         role_management::find_roles_proposal_pda(&crate::id(), &its_root_pda, &from, &payer);
+    let (role_index_pda, _) = role_management::find_role_index_pda(&crate::id(), &its_root_pda);
 
     let accounts = vec![
         AccountMeta::new_readonly(solana_program::system_program::id(), false),
@@ -1780,6 +2927,7 @@ pub fn accept_operatorship(payer: Pubkey, from: Pubkey) -> Result<Instruction, P
         AccountMeta::new_readonly(from, false),
         AccountMeta::new(origin_roles_pda, false),
         AccountMeta::new(proposal_pda, false),
+        AccountMeta::new(role_index_pda, false),
     ];
 
     let data = to_vec(&InterchainTokenServiceInstruction::AcceptOperatorship)?;
@@ -1791,6 +2939,191 @@ pub fn accept_operatorship(payer: Pubkey, from: Pubkey) -> Result<Instruction, P
     })
 }
 
+/// Creates an [`InterchainTokenServiceInstruction::CancelOperatorshipProposal`] instruction.
+///
+/// # Errors
+///
+/// [`ProgramError::BorshIoError`]: When instruction serialization fails.
+pub fn cancel_operatorship_proposal(
+    payer: Pubkey,
+    from: Pubkey,
+    to: Pubkey,
+) -> Result<Instruction, ProgramError> {
+    let (its_root_pda, _) = crate::find_its_root_pda();
+    let (proposal_pda, _) =
+        role_management::find_roles_proposal_pda(&crate::id(), &its_root_pda, &from, &to);
+
+    let accounts = vec![
+        AccountMeta::new_readonly(payer, true),
+        AccountMeta::new_readonly(its_root_pda, false),
+        AccountMeta::new(from, false),
+        AccountMeta::new_readonly(to, false),
+        AccountMeta::new(proposal_pda, false),
+    ];
+
+    let data = to_vec(&InterchainTokenServiceInstruction::CancelOperatorshipProposal)?;
+
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts,
+        data,
+    })
+}
+
+/// Creates an [`InterchainTokenServiceInstruction::CreateMultisig`] instruction.
+///
+/// # Errors
+///
+/// [`ProgramError::BorshIoError`]: When instruction serialization fails.
+pub fn create_multisig(
+    payer: Pubkey,
+    signers: Vec<Pubkey>,
+    threshold: u8,
+) -> Result<Instruction, ProgramError> {
+    let (multisig_pda, _) = role_management::find_multisig_pda(&crate::id(), &signers, threshold);
+
+    let accounts = vec![
+        AccountMeta::new_readonly(solana_program::system_program::id(), false),
+        AccountMeta::new(payer, true),
+        AccountMeta::new(multisig_pda, false),
+    ];
+
+    let data = to_vec(&InterchainTokenServiceInstruction::CreateMultisig { signers, threshold })?;
+
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts,
+        data,
+    })
+}
+
+/// Makes an instruction performed by a role holder be approved by a
+/// [`Multisig`](role_management::state::Multisig) holding the role instead. The multisig
+/// takes the place of the role holder, which must have been given to the builder of the
+/// instruction, and the signing members are appended to the accounts.
+///
+/// Supported instructions are [`InterchainTokenServiceInstruction::SetFlowLimit`],
+/// [`InterchainTokenServiceInstruction::SetTokenManagerFlowLimit`],
+/// [`InterchainTokenServiceInstruction::SetTokenManagerSupplyCap`],
+/// [`InterchainTokenServiceInstruction::MintInterchainToken`] and the instructions cancelling
+/// role transfer proposals, such as
+/// [`InterchainTokenServiceInstruction::CancelOperatorshipProposal`].
+#[must_use]
+pub fn with_multisig_signers(
+    mut instruction: Instruction,
+    multisig: Pubkey,
+    signers: &[Pubkey],
+) -> Instruction {
+    for account in &mut instruction.accounts {
+        if account.pubkey == multisig {
+            account.is_signer = false;
+        }
+    }
+
+    instruction.accounts.extend(
+        signers
+            .iter()
+            .map(|signer| AccountMeta::new_readonly(*signer, true)),
+    );
+
+    instruction
+}
+
+/// Makes a role change, such as adding a flow limiter or transferring operatorship, be
+/// approved by a [`Multisig`](role_management::state::Multisig) holding the required roles.
+/// The multisig takes the place of the authority given to the builder of the instruction, and
+/// as it can't pay for the rent of the change, `payer` is appended to the accounts before the
+/// signing members.
+///
+/// It also supports [`InterchainTokenServiceInstruction::SetInterchainTokenMinterAllowance`] and
+/// [`InterchainTokenServiceInstruction::UpdateInterchainTokenMetadata`], where `payer` pays for
+/// the gas as well. The latter must have its gas payment accounts appended first, see
+/// [`with_gas_payment_accounts`].
+#[must_use]
+pub fn with_multisig_authority(
+    mut instruction: Instruction,
+    multisig: Pubkey,
+    payer: Pubkey,
+    signers: &[Pubkey],
+) -> Instruction {
+    for account in &mut instruction.accounts {
+        if account.pubkey == multisig {
+            account.is_signer = false;
+        }
+    }
+
+    instruction.accounts.push(AccountMeta::new(payer, true));
+    instruction.accounts.extend(
+        signers
+            .iter()
+            .map(|signer| AccountMeta::new_readonly(*signer, true)),
+    );
+
+    instruction
+}
+
+/// Creates an [`InterchainTokenServiceInstruction::SyncRoleIndex`] instruction.
+///
+/// # Errors
+///
+/// [`ProgramError::BorshIoError`]: When instruction serialization fails.
+pub fn sync_role_index(
+    payer: Pubkey,
+    resource: Pubkey,
+    user: Pubkey,
+) -> Result<Instruction, ProgramError> {
+    let (user_roles_pda, _) = role_management::find_user_roles_pda(&crate::id(), &resource, &user);
+    let (role_index_pda, _) = role_management::find_role_index_pda(&crate::id(), &resource);
+
+    let accounts = vec![
+        AccountMeta::new_readonly(solana_program::system_program::id(), false),
+        AccountMeta::new(payer, true),
+        AccountMeta::new_readonly(resource, false),
+        AccountMeta::new_readonly(user, false),
+        AccountMeta::new_readonly(user_roles_pda, false),
+        AccountMeta::new(role_index_pda, false),
+    ];
+
+    let data = to_vec(&InterchainTokenServiceInstruction::SyncRoleIndex)?;
+
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts,
+        data,
+    })
+}
+
+/// Creates an [`InterchainTokenServiceInstruction::CleanupExpiredRoles`] instruction.
+///
+/// # Errors
+///
+/// [`ProgramError::BorshIoError`]: When instruction serialization fails.
+pub fn cleanup_expired_roles(
+    payer: Pubkey,
+    resource: Pubkey,
+    user: Pubkey,
+) -> Result<Instruction, ProgramError> {
+    let (user_roles_pda, _) = role_management::find_user_roles_pda(&crate::id(), &resource, &user);
+    let (role_index_pda, _) = role_management::find_role_index_pda(&crate::id(), &resource);
+
+    let accounts = vec![
+        AccountMeta::new_readonly(solana_program::system_program::id(), false),
+        AccountMeta::new(payer, true),
+        AccountMeta::new_readonly(resource, false),
+        AccountMeta::new(user, false),
+        AccountMeta::new(user_roles_pda, false),
+        AccountMeta::new(role_index_pda, false),
+    ];
+
+    let data = to_vec(&InterchainTokenServiceInstruction::CleanupExpiredRoles)?;
+
+    Ok(Instruction {
+        program_id: crate::ID,
+        accounts,
+        data,
+    })
+}
+
 fn prefix_accounts(
     payer: &Pubkey,
     gateway_incoming_message_pda: &Pubkey,
@@ -1814,7 +3147,7 @@ pub(crate) fn derive_its_accounts<'a, T>(
     payload: T,
     token_program: Pubkey,
     maybe_mint: Option<Pubkey>,
-    maybe_timestamp: Option<i64>,
+    maybe_flow_epoch: Option<u64>,
 ) -> Result<Vec<AccountMeta>, ProgramError>
 where
     T: TryInto<ItsMessageRef<'a>>,
@@ -1836,7 +3169,7 @@ where
         mint,
         token_manager_pda,
         token_program,
-        maybe_timestamp,
+        maybe_flow_epoch,
     )?;
 
     accounts.append(&mut message_specific_accounts);
@@ -1849,7 +3182,7 @@ fn derive_specific_its_accounts(
     mint_account: Pubkey,
     token_manager_pda: Pubkey,
     token_program: Pubkey,
-    maybe_timestamp: Option<i64>,
+    maybe_flow_epoch: Option<u64>,
 ) -> Result<Vec<AccountMeta>, ProgramError> {
     let mut specific_accounts = Vec::new();
 
@@ -1864,14 +3197,16 @@ fn derive_specific_its_accounts(
                     .try_into()
                     .map_err(|_err| ProgramError::InvalidInstructionData)?,
             );
-            let Some(timestamp) = maybe_timestamp else {
+            let Some(flow_epoch) = maybe_flow_epoch else {
                 return Err(ProgramError::InvalidInstructionData);
             };
-            let epoch = crate::state::flow_limit::flow_epoch_with_timestamp(timestamp)?;
-            let (flow_slot_pda, _) = crate::find_flow_slot_pda(&token_manager_pda, epoch);
+            let (flow_slot_pda, _) = crate::find_flow_slot_pda(&token_manager_pda, flow_epoch);
+            let (previous_flow_slot_pda, _) =
+                crate::find_flow_slot_pda(&token_manager_pda, flow_epoch.saturating_sub(1));
 
             specific_accounts.push(AccountMeta::new(destination_account, false));
             specific_accounts.push(AccountMeta::new(flow_slot_pda, false));
+            specific_accounts.push(AccountMeta::new_readonly(previous_flow_slot_pda, false));
 
             if !data.is_empty() {
                 let execute_data = DataPayload::decode(data)
@@ -1916,6 +3251,14 @@ fn derive_specific_its_accounts(
                 specific_accounts.push(AccountMeta::new(minter_roles_pda, false));
             }
         }
+        ItsMessageRef::RegisterTokenMetadataUpdate { .. } => {
+            let (metadata_account_key, _) =
+                mpl_token_metadata::accounts::Metadata::find_pda(&mint_account);
+
+            specific_accounts.push(AccountMeta::new_readonly(sysvar::instructions::ID, false));
+            specific_accounts.push(AccountMeta::new_readonly(mpl_token_metadata::ID, false));
+            specific_accounts.push(AccountMeta::new(metadata_account_key, false));
+        }
         ItsMessageRef::LinkToken { link_params, .. } => {
             if let Ok(operator) = Pubkey::try_from(*link_params) {
                 let (operator_roles_pda, _) =
@@ -1948,7 +3291,8 @@ fn try_retrieve_mint(
         ItsMessageRef::InterchainTransfer { .. } => {
             maybe_mint.ok_or(ProgramError::InvalidInstructionData)
         }
-        ItsMessageRef::DeployInterchainToken { .. } => Ok(*interchain_token_pda),
+        ItsMessageRef::DeployInterchainToken { .. }
+        | ItsMessageRef::RegisterTokenMetadataUpdate { .. } => Ok(*interchain_token_pda),
     }
 }
 
@@ -1971,6 +3315,7 @@ fn derive_common_its_accounts(
 
     let (its_user_roles_pda, _) =
         role_management::find_user_roles_pda(&crate::ID, &token_manager_pda, &its_root_pda);
+    let (role_index_pda, _) = role_management::find_role_index_pda(&crate::ID, &token_manager_pda);
 
     Ok((
         vec![
@@ -1982,6 +3327,7 @@ fn derive_common_its_accounts(
             AccountMeta::new_readonly(token_program, false),
             AccountMeta::new_readonly(spl_associated_token_account::ID, false),
             AccountMeta::new(its_user_roles_pda, false),
+            AccountMeta::new(role_index_pda, false),
             AccountMeta::new_readonly(sysvar::rent::ID, false),
         ],
         token_mint,
@@ -2012,6 +3358,11 @@ pub(crate) enum ItsMessageRef<'a> {
         token_manager_type: state::token_manager::Type,
         link_params: &'a [u8],
     },
+    RegisterTokenMetadataUpdate {
+        token_id: Cow<'a, [u8; 32]>,
+        name: &'a str,
+        symbol: &'a str,
+    },
 }
 
 impl ItsMessageRef<'_> {
@@ -2020,7 +3371,8 @@ impl ItsMessageRef<'_> {
         match self {
             ItsMessageRef::InterchainTransfer { token_id, .. }
             | ItsMessageRef::DeployInterchainToken { token_id, .. }
-            | ItsMessageRef::LinkToken { token_id, .. } => token_id,
+            | ItsMessageRef::LinkToken { token_id, .. }
+            | ItsMessageRef::RegisterTokenMetadataUpdate { token_id, .. } => token_id,
         }
     }
 }
@@ -2056,6 +3408,11 @@ impl<'a> TryFrom<&'a GMPPayload> for ItsMessageRef<'a> {
                     .map_err(|_err| ProgramError::InvalidInstructionData)?,
                 link_params: inner.link_params.as_ref(),
             },
+            GMPPayload::RegisterTokenMetadataUpdate(inner) => Self::RegisterTokenMetadataUpdate {
+                token_id: Cow::Borrowed(&inner.token_id.0),
+                name: &inner.name,
+                symbol: &inner.symbol,
+            },
             GMPPayload::RegisterTokenMetadata(_)
             | GMPPayload::SendToHub(_)
             | GMPPayload::ReceiveFromHub(_) => return Err(ProgramError::InvalidArgument),
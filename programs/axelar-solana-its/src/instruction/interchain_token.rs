@@ -12,6 +12,7 @@ use super::InterchainTokenServiceInstruction;
 /// # Errors
 /// If serialization fails.
 pub fn mint(
+    payer: Pubkey,
     token_id: [u8; 32],
     mint: Pubkey,
     to: Pubkey,
@@ -23,6 +24,7 @@ pub fn mint(
     let (token_manager_pda, _) = crate::find_token_manager_pda(&its_root_pda, &token_id);
     let (minter_roles_pda, _) =
         role_management::find_user_roles_pda(&crate::id(), &token_manager_pda, &minter);
+    let (minter_allowance_pda, _) = crate::find_minter_allowance_pda(&token_manager_pda, &minter);
     let data = to_vec(&InterchainTokenServiceInstruction::MintInterchainToken { amount })?;
 
     Ok(solana_program::instruction::Instruction {
@@ -33,13 +35,104 @@ pub fn mint(
             AccountMeta::new_readonly(its_root_pda, false),
             AccountMeta::new_readonly(token_manager_pda, false),
             AccountMeta::new_readonly(minter, true),
-            AccountMeta::new_readonly(minter_roles_pda, false),
+            AccountMeta::new(minter_roles_pda, false),
             AccountMeta::new_readonly(token_program, false),
+            AccountMeta::new(minter_allowance_pda, false),
+            AccountMeta::new_readonly(solana_program::system_program::id(), false),
+            AccountMeta::new(payer, true),
         ],
         data,
     })
 }
 
+/// Creates an [`InterchainTokenServiceInstruction::SetInterchainTokenMinterAllowance`]
+/// instruction.
+///
+/// # Errors
+///
+/// If serialization fails.
+pub fn set_minter_allowance(
+    payer: Pubkey,
+    token_id: [u8; 32],
+    minter: Pubkey,
+    allowance: u64,
+    refill_period: u64,
+) -> Result<solana_program::instruction::Instruction, ProgramError> {
+    let (its_root_pda, _) = crate::find_its_root_pda();
+    let (token_manager_pda, _) = crate::find_token_manager_pda(&its_root_pda, &token_id);
+    let (payer_roles_pda, _) =
+        role_management::find_user_roles_pda(&crate::id(), &token_manager_pda, &payer);
+    let (minter_allowance_pda, _) = crate::find_minter_allowance_pda(&token_manager_pda, &minter);
+
+    let accounts = vec![
+        AccountMeta::new(payer, true),
+        AccountMeta::new_readonly(payer_roles_pda, false),
+        AccountMeta::new_readonly(its_root_pda, false),
+        AccountMeta::new_readonly(token_manager_pda, false),
+        AccountMeta::new_readonly(minter, false),
+        AccountMeta::new(minter_allowance_pda, false),
+        AccountMeta::new_readonly(solana_program::system_program::id(), false),
+    ];
+
+    let data = to_vec(
+        &InterchainTokenServiceInstruction::SetInterchainTokenMinterAllowance {
+            allowance,
+            refill_period,
+        },
+    )?;
+
+    Ok(solana_program::instruction::Instruction {
+        program_id: crate::id(),
+        accounts,
+        data,
+    })
+}
+
+/// Creates an [`InterchainTokenServiceInstruction::GrantInterchainTokenMinter`] instruction.
+///
+/// # Errors
+///
+/// If serialization fails.
+pub fn grant_minter(
+    payer: Pubkey,
+    token_id: [u8; 32],
+    minter: Pubkey,
+    expires_at: Option<i64>,
+    max_uses: Option<u64>,
+) -> Result<solana_program::instruction::Instruction, ProgramError> {
+    let (its_root_pda, _) = crate::find_its_root_pda();
+    let (token_manager_pda, _) = crate::find_token_manager_pda(&its_root_pda, &token_id);
+    let (payer_roles_pda, _) =
+        role_management::find_user_roles_pda(&crate::id(), &token_manager_pda, &payer);
+    let (minter_roles_pda, _) =
+        role_management::find_user_roles_pda(&crate::id(), &token_manager_pda, &minter);
+    let (role_index_pda, _) =
+        role_management::find_role_index_pda(&crate::id(), &token_manager_pda);
+
+    let accounts = vec![
+        AccountMeta::new_readonly(solana_program::system_program::id(), false),
+        AccountMeta::new(payer, true),
+        AccountMeta::new_readonly(payer_roles_pda, false),
+        AccountMeta::new_readonly(token_manager_pda, false),
+        AccountMeta::new_readonly(minter, false),
+        AccountMeta::new(minter_roles_pda, false),
+        AccountMeta::new(role_index_pda, false),
+    ];
+
+    let data = to_vec(
+        &InterchainTokenServiceInstruction::GrantInterchainTokenMinter {
+            expires_at,
+            max_uses,
+        },
+    )?;
+
+    Ok(solana_program::instruction::Instruction {
+        program_id: crate::id(),
+        accounts,
+        data,
+    })
+}
+
 /// Creates an [`InterchainTokenServiceInstruction::TransferInterchainTokenMintership`]
 /// instruction.
 ///
@@ -57,6 +150,8 @@ pub fn transfer_mintership(
         role_management::find_user_roles_pda(&crate::id(), &token_manager_pda, &to);
     let (payer_roles_pda, _) =
         role_management::find_user_roles_pda(&crate::id(), &token_manager_pda, &payer);
+    let (role_index_pda, _) =
+        role_management::find_role_index_pda(&crate::id(), &token_manager_pda);
 
     let accounts = vec![
         AccountMeta::new_readonly(its_root_pda, false),
@@ -66,6 +161,7 @@ pub fn transfer_mintership(
         AccountMeta::new_readonly(token_manager_pda, false),
         AccountMeta::new_readonly(to, false),
         AccountMeta::new(destination_roles_pda, false),
+        AccountMeta::new(role_index_pda, false),
     ];
 
     let data = to_vec(&InterchainTokenServiceInstruction::TransferInterchainTokenMintership)?;
@@ -86,6 +182,7 @@ pub fn propose_mintership(
     payer: Pubkey,
     token_id: [u8; 32],
     to: Pubkey,
+    expires_at: Option<i64>,
 ) -> Result<solana_program::instruction::Instruction, ProgramError> {
     let (its_root_pda, _) = crate::find_its_root_pda();
     let (token_manager_pda, _) = crate::find_token_manager_pda(&its_root_pda, &token_id);
@@ -107,7 +204,9 @@ pub fn propose_mintership(
         AccountMeta::new(proposal_pda, false),
     ];
 
-    let data = to_vec(&InterchainTokenServiceInstruction::ProposeInterchainTokenMintership)?;
+    let data = to_vec(
+        &InterchainTokenServiceInstruction::ProposeInterchainTokenMintership { expires_at },
+    )?;
 
     Ok(solana_program::instruction::Instruction {
         program_id: crate::id(),
@@ -135,6 +234,8 @@ pub fn accept_mintership(
         role_management::find_user_roles_pda(&crate::id(), &token_manager_pda, &from);
     let (proposal_pda, _) =
         role_management::find_roles_proposal_pda(&crate::id(), &token_manager_pda, &from, &payer);
+    let (role_index_pda, _) =
+        role_management::find_role_index_pda(&crate::id(), &token_manager_pda);
 
     let accounts = vec![
         AccountMeta::new_readonly(its_root_pda, false),
@@ -145,6 +246,7 @@ pub fn accept_mintership(
         AccountMeta::new_readonly(from, false),
         AccountMeta::new(origin_roles_pda, false),
         AccountMeta::new(proposal_pda, false),
+        AccountMeta::new(role_index_pda, false),
     ];
 
     let data = to_vec(&InterchainTokenServiceInstruction::AcceptInterchainTokenMintership)?;
@@ -155,3 +257,37 @@ pub fn accept_mintership(
         data,
     })
 }
+
+/// Creates an [`InterchainTokenServiceInstruction::CancelInterchainTokenMintershipProposal`] instruction.
+///
+/// # Errors
+///
+/// If serialization fails.
+pub fn cancel_mintership_proposal(
+    payer: Pubkey,
+    token_id: [u8; 32],
+    from: Pubkey,
+    to: Pubkey,
+) -> Result<solana_program::instruction::Instruction, ProgramError> {
+    let (its_root_pda, _) = crate::find_its_root_pda();
+    let (token_manager_pda, _) = crate::find_token_manager_pda(&its_root_pda, &token_id);
+    let (proposal_pda, _) =
+        role_management::find_roles_proposal_pda(&crate::id(), &token_manager_pda, &from, &to);
+
+    let accounts = vec![
+        AccountMeta::new_readonly(its_root_pda, false),
+        AccountMeta::new_readonly(payer, true),
+        AccountMeta::new_readonly(token_manager_pda, false),
+        AccountMeta::new(from, false),
+        AccountMeta::new_readonly(to, false),
+        AccountMeta::new(proposal_pda, false),
+    ];
+
+    let data = to_vec(&InterchainTokenServiceInstruction::CancelInterchainTokenMintershipProposal)?;
+
+    Ok(solana_program::instruction::Instruction {
+        program_id: crate::id(),
+        accounts,
+        data,
+    })
+}
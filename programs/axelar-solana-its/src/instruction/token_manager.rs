@@ -6,6 +6,8 @@ use solana_program::program_error::ProgramError;
 use solana_program::pubkey::Pubkey;
 use solana_program::system_program;
 
+use crate::state::flow_limit::FlowLimit;
+
 use super::InterchainTokenServiceInstruction;
 
 /// Creates an [`TokenManagerInstructions::SetFlowLimit`] wrapped in an
@@ -17,7 +19,7 @@ use super::InterchainTokenServiceInstruction;
 pub fn set_flow_limit(
     payer: Pubkey,
     token_id: [u8; 32],
-    flow_limit: u64,
+    flow_limit: FlowLimit,
 ) -> Result<solana_program::instruction::Instruction, ProgramError> {
     let (its_root_pda, _) = crate::find_its_root_pda();
     let (token_manager_pda, _) = crate::find_token_manager_pda(&its_root_pda, &token_id);
@@ -32,7 +34,7 @@ pub fn set_flow_limit(
         AccountMeta::new_readonly(payer, true),
         AccountMeta::new_readonly(its_root_pda, false),
         AccountMeta::new(token_manager_pda, false),
-        AccountMeta::new_readonly(token_manager_user_roles_pda, false),
+        AccountMeta::new(token_manager_user_roles_pda, false),
         AccountMeta::new_readonly(its_user_roles_pda, false),
         AccountMeta::new_readonly(system_program::ID, false),
     ];
@@ -44,6 +46,38 @@ pub fn set_flow_limit(
     })
 }
 
+/// Creates an [`InterchainTokenServiceInstruction::SetTokenManagerSupplyCap`] instruction.
+///
+/// # Errors
+///
+/// If serialization fails.
+pub fn set_supply_cap(
+    payer: Pubkey,
+    token_id: [u8; 32],
+    supply_cap: u64,
+) -> Result<solana_program::instruction::Instruction, ProgramError> {
+    let (its_root_pda, _) = crate::find_its_root_pda();
+    let (token_manager_pda, _) = crate::find_token_manager_pda(&its_root_pda, &token_id);
+    let (token_manager_user_roles_pda, _) =
+        role_management::find_user_roles_pda(&crate::id(), &token_manager_pda, &payer);
+
+    let data = to_vec(&InterchainTokenServiceInstruction::SetTokenManagerSupplyCap { supply_cap })?;
+
+    let accounts = vec![
+        AccountMeta::new_readonly(payer, true),
+        AccountMeta::new_readonly(its_root_pda, false),
+        AccountMeta::new(token_manager_pda, false),
+        AccountMeta::new_readonly(token_manager_user_roles_pda, false),
+        AccountMeta::new_readonly(system_program::ID, false),
+    ];
+
+    Ok(solana_program::instruction::Instruction {
+        program_id: crate::id(),
+        accounts,
+        data,
+    })
+}
+
 /// Creates a [`TokenManagerInstructions::AddFlowLimiter`] instruction.
 ///
 /// # Errors
@@ -60,6 +94,8 @@ pub fn add_flow_limiter(
         role_management::find_user_roles_pda(&crate::id(), &token_manager_pda, &payer);
     let (flow_limiter_roles_pda, _) =
         role_management::find_user_roles_pda(&crate::id(), &token_manager_pda, &flow_limiter);
+    let (role_index_pda, _) =
+        role_management::find_role_index_pda(&crate::id(), &token_manager_pda);
 
     let accounts = vec![
         AccountMeta::new_readonly(system_program::ID, false),
@@ -68,6 +104,7 @@ pub fn add_flow_limiter(
         AccountMeta::new_readonly(token_manager_pda, false),
         AccountMeta::new_readonly(flow_limiter, false),
         AccountMeta::new(flow_limiter_roles_pda, false),
+        AccountMeta::new(role_index_pda, false),
     ];
 
     let data = to_vec(&InterchainTokenServiceInstruction::AddTokenManagerFlowLimiter)?;
@@ -79,6 +116,51 @@ pub fn add_flow_limiter(
     })
 }
 
+/// Creates an [`InterchainTokenServiceInstruction::GrantTokenManagerFlowLimiter`] instruction.
+///
+/// # Errors
+///
+/// If serialization fails.
+pub fn grant_flow_limiter(
+    payer: Pubkey,
+    token_id: [u8; 32],
+    flow_limiter: Pubkey,
+    expires_at: Option<i64>,
+    max_uses: Option<u64>,
+) -> Result<solana_program::instruction::Instruction, ProgramError> {
+    let (its_root_pda, _) = crate::find_its_root_pda();
+    let (token_manager_pda, _) = crate::find_token_manager_pda(&its_root_pda, &token_id);
+    let (payer_roles_pda, _) =
+        role_management::find_user_roles_pda(&crate::id(), &token_manager_pda, &payer);
+    let (flow_limiter_roles_pda, _) =
+        role_management::find_user_roles_pda(&crate::id(), &token_manager_pda, &flow_limiter);
+    let (role_index_pda, _) =
+        role_management::find_role_index_pda(&crate::id(), &token_manager_pda);
+
+    let accounts = vec![
+        AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new(payer, true),
+        AccountMeta::new_readonly(payer_roles_pda, false),
+        AccountMeta::new_readonly(token_manager_pda, false),
+        AccountMeta::new_readonly(flow_limiter, false),
+        AccountMeta::new(flow_limiter_roles_pda, false),
+        AccountMeta::new(role_index_pda, false),
+    ];
+
+    let data = to_vec(
+        &InterchainTokenServiceInstruction::GrantTokenManagerFlowLimiter {
+            expires_at,
+            max_uses,
+        },
+    )?;
+
+    Ok(solana_program::instruction::Instruction {
+        program_id: crate::id(),
+        accounts,
+        data,
+    })
+}
+
 /// Creates a [`TokenManagerInstructions::RemoveFlowLimiter`] instruction.
 ///
 /// # Errors
@@ -95,6 +177,8 @@ pub fn remove_flow_limiter(
         role_management::find_user_roles_pda(&crate::id(), &token_manager_pda, &payer);
     let (flow_limiter_roles_pda, _) =
         role_management::find_user_roles_pda(&crate::id(), &token_manager_pda, &flow_limiter);
+    let (role_index_pda, _) =
+        role_management::find_role_index_pda(&crate::id(), &token_manager_pda);
 
     let accounts = vec![
         AccountMeta::new_readonly(system_program::ID, false),
@@ -103,6 +187,7 @@ pub fn remove_flow_limiter(
         AccountMeta::new_readonly(token_manager_pda, false),
         AccountMeta::new_readonly(flow_limiter, false),
         AccountMeta::new(flow_limiter_roles_pda, false),
+        AccountMeta::new(role_index_pda, false),
     ];
 
     let data = to_vec(&InterchainTokenServiceInstruction::RemoveTokenManagerFlowLimiter)?;
@@ -130,6 +215,8 @@ pub fn transfer_operatorship(
         role_management::find_user_roles_pda(&crate::id(), &token_manager_pda, &to);
     let (payer_roles_pda, _) =
         role_management::find_user_roles_pda(&crate::id(), &token_manager_pda, &payer);
+    let (role_index_pda, _) =
+        role_management::find_role_index_pda(&crate::id(), &token_manager_pda);
 
     let accounts = vec![
         AccountMeta::new_readonly(its_root_pda, false),
@@ -139,6 +226,7 @@ pub fn transfer_operatorship(
         AccountMeta::new_readonly(token_manager_pda, false),
         AccountMeta::new_readonly(to, false),
         AccountMeta::new(destination_roles_pda, false),
+        AccountMeta::new(role_index_pda, false),
     ];
 
     let data = to_vec(&InterchainTokenServiceInstruction::TransferTokenManagerOperatorship)?;
@@ -159,6 +247,7 @@ pub fn propose_operatorship(
     payer: Pubkey,
     token_id: [u8; 32],
     to: Pubkey,
+    expires_at: Option<i64>,
 ) -> Result<solana_program::instruction::Instruction, ProgramError> {
     let (its_root_pda, _) = crate::find_its_root_pda();
     let (token_manager_pda, _) = crate::find_token_manager_pda(&its_root_pda, &token_id);
@@ -180,7 +269,8 @@ pub fn propose_operatorship(
         AccountMeta::new(proposal_pda, false),
     ];
 
-    let data = to_vec(&InterchainTokenServiceInstruction::ProposeTokenManagerOperatorship)?;
+    let data =
+        to_vec(&InterchainTokenServiceInstruction::ProposeTokenManagerOperatorship { expires_at })?;
 
     Ok(solana_program::instruction::Instruction {
         program_id: crate::id(),
@@ -207,6 +297,8 @@ pub fn accept_operatorship(
         role_management::find_user_roles_pda(&crate::id(), &token_manager_pda, &from);
     let (proposal_pda, _) =
         role_management::find_roles_proposal_pda(&crate::id(), &token_manager_pda, &from, &payer);
+    let (role_index_pda, _) =
+        role_management::find_role_index_pda(&crate::id(), &token_manager_pda);
 
     let accounts = vec![
         AccountMeta::new_readonly(its_root_pda, false),
@@ -217,6 +309,7 @@ pub fn accept_operatorship(
         AccountMeta::new_readonly(from, false),
         AccountMeta::new(origin_roles_pda, false),
         AccountMeta::new(proposal_pda, false),
+        AccountMeta::new(role_index_pda, false),
     ];
 
     let data = to_vec(&InterchainTokenServiceInstruction::AcceptTokenManagerOperatorship)?;
@@ -243,6 +336,8 @@ pub fn handover_mint_authority(
     let (token_manager_pda, _) = crate::find_token_manager_pda(&its_root_pda, &token_id);
     let (minter_roles_pda, _) =
         role_management::find_user_roles_pda(&crate::ID, &token_manager_pda, &payer);
+    let (role_index_pda, _) =
+        role_management::find_role_index_pda(&crate::id(), &token_manager_pda);
 
     let accounts = vec![
         AccountMeta::new(payer, true),
@@ -252,6 +347,7 @@ pub fn handover_mint_authority(
         AccountMeta::new(minter_roles_pda, false),
         AccountMeta::new_readonly(token_program, false),
         AccountMeta::new_readonly(system_program::ID, false),
+        AccountMeta::new(role_index_pda, false),
     ];
 
     let data = to_vec(&InterchainTokenServiceInstruction::HandoverMintAuthority { token_id })?;
@@ -262,3 +358,37 @@ pub fn handover_mint_authority(
         data,
     })
 }
+
+/// Creates an [`InterchainTokenServiceInstruction::CancelTokenManagerOperatorshipProposal`] instruction.
+///
+/// # Errors
+///
+/// If serialization fails.
+pub fn cancel_operatorship_proposal(
+    payer: Pubkey,
+    token_id: [u8; 32],
+    from: Pubkey,
+    to: Pubkey,
+) -> Result<solana_program::instruction::Instruction, ProgramError> {
+    let (its_root_pda, _) = crate::find_its_root_pda();
+    let (token_manager_pda, _) = crate::find_token_manager_pda(&its_root_pda, &token_id);
+    let (proposal_pda, _) =
+        role_management::find_roles_proposal_pda(&crate::id(), &token_manager_pda, &from, &to);
+
+    let accounts = vec![
+        AccountMeta::new_readonly(its_root_pda, false),
+        AccountMeta::new_readonly(payer, true),
+        AccountMeta::new_readonly(token_manager_pda, false),
+        AccountMeta::new(from, false),
+        AccountMeta::new_readonly(to, false),
+        AccountMeta::new(proposal_pda, false),
+    ];
+
+    let data = to_vec(&InterchainTokenServiceInstruction::CancelTokenManagerOperatorshipProposal)?;
+
+    Ok(solana_program::instruction::Instruction {
+        program_id: crate::id(),
+        accounts,
+        data,
+    })
+}
@@ -88,6 +88,12 @@ pub mod seed_prefixes {
 
     /// The seed prefix for deriving the deployment approval PDA
     pub const DEPLOYMENT_APPROVAL_SEED: &[u8] = b"deployment-approval";
+
+    /// The seed prefix for deriving the trusted chain PDA
+    pub const TRUSTED_CHAIN_SEED: &[u8] = b"trusted-chain";
+
+    /// The seed prefix for deriving the minter allowance PDA
+    pub const MINTER_ALLOWANCE_SEED: &[u8] = b"minter-allowance";
 }
 
 bitflags! {
@@ -490,6 +496,131 @@ pub fn deployment_approval_pda(
     }
 }
 
+/// Tries to create the PDA for a `TrustedChain` using the provided bump,
+/// falling back to `find_program_address` if the bump is invalid.
+///
+/// # Errors
+///
+/// If the bump is invalid.
+#[inline]
+pub fn create_trusted_chain_pda(
+    its_root_pda: &Pubkey,
+    chain_name: &str,
+    bump: u8,
+) -> Result<Pubkey, ProgramError> {
+    Ok(Pubkey::create_program_address(
+        &[
+            seed_prefixes::TRUSTED_CHAIN_SEED,
+            its_root_pda.as_ref(),
+            chain_name.as_bytes(),
+            &[bump],
+        ],
+        &crate::id(),
+    )?)
+}
+
+/// Derives the PDA for a `TrustedChain`.
+#[inline]
+#[must_use]
+pub fn find_trusted_chain_pda(its_root_pda: &Pubkey, chain_name: &str) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            seed_prefixes::TRUSTED_CHAIN_SEED,
+            its_root_pda.as_ref(),
+            chain_name.as_bytes(),
+        ],
+        &crate::id(),
+    )
+}
+
+/// Tries to create the PDA for a `TrustedChain` using the provided bump,
+/// falling back to `find_program_address` if the bump is `None` or invalid.
+///
+/// # Errors
+///
+/// If the bump is invalid.
+pub fn trusted_chain_pda(
+    its_root_pda: &Pubkey,
+    chain_name: &str,
+    maybe_bump: Option<u8>,
+) -> Result<(Pubkey, u8), ProgramError> {
+    if let Some(bump) = maybe_bump {
+        create_trusted_chain_pda(its_root_pda, chain_name, bump).map(|pubkey| (pubkey, bump))
+    } else {
+        Ok(find_trusted_chain_pda(its_root_pda, chain_name))
+    }
+}
+
+pub(crate) fn assert_valid_trusted_chain_pda(
+    trusted_chain_pda_account: &AccountInfo<'_>,
+    its_root_pda: &Pubkey,
+    chain_name: &str,
+    canonical_bump: u8,
+) -> ProgramResult {
+    let expected_trusted_chain_pda =
+        create_trusted_chain_pda(its_root_pda, chain_name, canonical_bump)?;
+
+    if expected_trusted_chain_pda.ne(trusted_chain_pda_account.key) {
+        msg!("Invalid TrustedChain PDA provided");
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    Ok(())
+}
+
+/// Tries to create the PDA for a `MinterAllowance` using the provided bump.
+///
+/// # Errors
+///
+/// If the bump is invalid.
+#[inline]
+pub fn create_minter_allowance_pda(
+    token_manager_pda: &Pubkey,
+    minter: &Pubkey,
+    bump: u8,
+) -> Result<Pubkey, ProgramError> {
+    Ok(Pubkey::create_program_address(
+        &[
+            seed_prefixes::MINTER_ALLOWANCE_SEED,
+            token_manager_pda.as_ref(),
+            minter.as_ref(),
+            &[bump],
+        ],
+        &crate::id(),
+    )?)
+}
+
+/// Derives the PDA for a `MinterAllowance`.
+#[inline]
+#[must_use]
+pub fn find_minter_allowance_pda(token_manager_pda: &Pubkey, minter: &Pubkey) -> (Pubkey, u8) {
+    Pubkey::find_program_address(
+        &[
+            seed_prefixes::MINTER_ALLOWANCE_SEED,
+            token_manager_pda.as_ref(),
+            minter.as_ref(),
+        ],
+        &crate::id(),
+    )
+}
+
+pub(crate) fn assert_valid_minter_allowance_pda(
+    minter_allowance_pda_account: &AccountInfo<'_>,
+    token_manager_pda: &Pubkey,
+    minter: &Pubkey,
+    canonical_bump: u8,
+) -> ProgramResult {
+    let expected_minter_allowance_pda =
+        create_minter_allowance_pda(token_manager_pda, minter, canonical_bump)?;
+
+    if expected_minter_allowance_pda.ne(minter_allowance_pda_account.key) {
+        msg!("Invalid MinterAllowance PDA provided");
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    Ok(())
+}
+
 /// Creates an associated token account for the given wallet address and token
 /// mint.
 ///
@@ -615,3 +746,43 @@ pub fn linked_token_id(deployer: &Pubkey, salt: &[u8; 32]) -> [u8; 32] {
 
     interchain_token_id_internal(&salt)
 }
+
+/// Computes the hash of the payload of a message sent by ITS to `destination_chain` through the
+/// ITS Hub, that is of `payload` wrapped for the hub.
+///
+/// # Errors
+///
+/// If the payload can't be wrapped for the hub.
+pub fn hub_payload_hash(
+    payload: &interchain_token_transfer_gmp::GMPPayload,
+    destination_chain: &str,
+) -> Result<[u8; 32], ProgramError> {
+    let payload = processor::gmp::wrap_for_hub(payload, destination_chain.to_owned())?;
+
+    Ok(solana_program::keccak::hash(&payload).to_bytes())
+}
+
+/// Derives the ledger PDA of the gas paid in `gas_token` by `payer` for a message sent by ITS
+/// through the ITS Hub at `its_hub_address`, `hub_payload_hash` being the hash of the payload of
+/// the message, see [`hub_payload_hash`]. `gas_token` is
+/// [`axelar_solana_gas_service::NATIVE_GAS_TOKEN`] for native SOL.
+#[must_use]
+pub fn find_gas_payment_pda(
+    gas_config_pda: &Pubkey,
+    gas_token: &Pubkey,
+    payer: &Pubkey,
+    its_hub_address: &str,
+    hub_payload_hash: &[u8; 32],
+) -> (Pubkey, u8) {
+    axelar_solana_gas_service::get_gas_payment_pda(
+        &axelar_solana_gas_service::ID,
+        gas_config_pda,
+        gas_token,
+        &axelar_solana_gas_service::gas_payment_key(
+            ITS_HUB_CHAIN_NAME,
+            its_hub_address,
+            hub_payload_hash,
+        ),
+        payer,
+    )
+}
@@ -1,25 +1,38 @@
 //! Program state processor
 use axelar_executable::{validate_with_gmp_metadata, PROGRAM_ACCOUNTS_START_INDEX};
 use axelar_solana_encoding::types::messages::Message;
+use axelar_solana_gas_service::state::GasToken;
+use axelar_solana_gas_service::NATIVE_GAS_TOKEN;
 use axelar_solana_gateway::state::message_payload::ImmutMessagePayload;
 use interchain_token_transfer_gmp::{GMPPayload, SendToHub};
 use itertools::{self, Itertools};
-use program_utils::{pda::BorshPda, validate_system_account_key};
+use program_utils::{
+    pda::{BorshPda, BytemuckedPda, ValidPDA},
+    validate_system_account_key,
+};
 use solana_program::account_info::{next_account_info, AccountInfo};
 use solana_program::clock::Clock;
 use solana_program::entrypoint::ProgramResult;
+use solana_program::instruction::Instruction;
 use solana_program::msg;
 use solana_program::program::invoke;
 use solana_program::program::invoke_signed;
 use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
 use solana_program::sysvar::Sysvar;
+use spl_token_2022::extension::{transfer_hook, StateWithExtensions};
+use spl_token_2022::state::Mint;
 
 use crate::processor::interchain_token::{self, DeployInterchainTokenAccounts};
 use crate::processor::interchain_transfer::process_inbound_transfer;
 use crate::processor::link_token;
 use crate::state::token_manager::TokenManager;
-use crate::state::InterchainTokenService;
-use crate::{assert_its_not_paused, assert_valid_its_root_pda, Validate, ITS_HUB_CHAIN_NAME};
+use crate::state::trusted_chain::TrustedChain;
+use crate::state::{InterchainTokenService, MetadataStandard};
+use crate::{
+    assert_its_not_paused, assert_valid_its_root_pda, assert_valid_trusted_chain_pda, Validate,
+    ITS_HUB_CHAIN_NAME,
+};
 use crate::{instruction, FromAccountInfoSlice};
 
 pub(crate) fn process_inbound<'a>(
@@ -29,7 +42,7 @@ pub(crate) fn process_inbound<'a>(
     let accounts_iter = &mut accounts.iter();
     let payer = next_account_info(accounts_iter)?;
 
-    let (gateway_accounts, instruction_accounts) = accounts_iter
+    let (gateway_accounts, _) = accounts_iter
         .as_slice()
         .split_at(PROGRAM_ACCOUNTS_START_INDEX);
 
@@ -39,6 +52,8 @@ pub(crate) fn process_inbound<'a>(
     let payload_account = next_account_info(accounts_iter)?;
     let _signing_pda = next_account_info(accounts_iter)?;
     let _gateway_program_id = next_account_info(accounts_iter)?;
+    let trusted_chain_account = next_account_info(accounts_iter)?;
+    let instruction_accounts = accounts_iter.as_slice();
     let system_program = next_account_info(accounts_iter)?;
     let its_root_pda_account = next_account_info(accounts_iter)?;
 
@@ -63,10 +78,11 @@ pub(crate) fn process_inbound<'a>(
         return Err(ProgramError::InvalidInstructionData);
     };
 
-    if !its_root_config.is_trusted_chain(&inner.source_chain) {
-        msg!("Untrusted source chain: {}", inner.source_chain);
-        return Err(ProgramError::InvalidInstructionData);
-    }
+    ensure_trusted_chain(
+        its_root_pda_account.key,
+        trusted_chain_account,
+        &inner.source_chain,
+    )?;
 
     let payload =
         GMPPayload::decode(&inner.payload).map_err(|_err| ProgramError::InvalidInstructionData)?;
@@ -83,8 +99,12 @@ pub(crate) fn process_inbound<'a>(
             inner.source_chain,
         ),
         GMPPayload::DeployInterchainToken(deploy) => {
-            let parsed_accounts =
-                DeployInterchainTokenAccounts::from_account_info_slice(instruction_accounts, &())?;
+            // Tokens deployed from other chains keep using Metaplex metadata, as the accounts
+            // relayers derive for the message don't depend on the token.
+            let parsed_accounts = DeployInterchainTokenAccounts::from_account_info_slice(
+                instruction_accounts,
+                &MetadataStandard::Metaplex,
+            )?;
             interchain_token::process_inbound_deploy(
                 payer,
                 parsed_accounts,
@@ -93,11 +113,21 @@ pub(crate) fn process_inbound<'a>(
                 deploy.symbol,
                 deploy.decimals,
                 0,
+                MetadataStandard::Metaplex,
+                Some(inner.source_chain),
             )
         }
         GMPPayload::LinkToken(payload) => {
             link_token::process_inbound(payer, instruction_accounts, &payload)
         }
+        GMPPayload::RegisterTokenMetadataUpdate(payload) => {
+            interchain_token::process_inbound_metadata_update(
+                payer,
+                instruction_accounts,
+                payload,
+                &inner.source_chain,
+            )
+        }
         GMPPayload::SendToHub(_)
         | GMPPayload::ReceiveFromHub(_)
         | GMPPayload::RegisterTokenMetadata(_) => Err(ProgramError::InvalidInstructionData),
@@ -114,6 +144,24 @@ pub(crate) struct GmpAccounts<'a> {
     pub(crate) its_root_account: &'a AccountInfo<'a>,
     pub(crate) call_contract_signing_account: &'a AccountInfo<'a>,
     pub(crate) program_account: &'a AccountInfo<'a>,
+    pub(crate) trusted_chain_account: Option<&'a AccountInfo<'a>>,
+    pub(crate) gas_token_accounts: Option<GasTokenAccounts<'a>>,
+    /// The gas reserve PDA of the token gas is paid with.
+    pub(crate) gas_reserve_account: &'a AccountInfo<'a>,
+    /// The gas payment ledger PDAs, one for each message paying for gas, in order, see
+    /// [`crate::find_gas_payment_pda`]. The accounts of the transfer hook of the mint, if any,
+    /// come after them.
+    pub(crate) gas_payment_accounts: &'a [AccountInfo<'a>],
+}
+
+/// Accounts used to pay for gas with SPL tokens instead of native SOL.
+#[derive(Debug)]
+pub(crate) struct GasTokenAccounts<'a> {
+    pub(crate) payer_token_account: &'a AccountInfo<'a>,
+    pub(crate) gas_service_config_token_account: &'a AccountInfo<'a>,
+    pub(crate) mint: &'a AccountInfo<'a>,
+    pub(crate) token_program: &'a AccountInfo<'a>,
+    pub(crate) gas_token_pda: &'a AccountInfo<'a>,
 }
 
 impl Validate for GmpAccounts<'_> {
@@ -124,26 +172,63 @@ impl Validate for GmpAccounts<'_> {
 }
 
 impl<'a> FromAccountInfoSlice<'a> for GmpAccounts<'a> {
-    type Context = ();
+    /// Whether the accounts include the trusted chain account of the destination chain.
+    type Context = bool;
 
     fn extract_accounts(
         accounts: &'a [AccountInfo<'a>],
-        _context: &Self::Context,
+        has_trusted_chain_account: &Self::Context,
     ) -> Result<Self, ProgramError>
     where
         Self: Sized + Validate,
     {
         let accounts_iter = &mut accounts.iter();
 
+        let gateway_root_account = next_account_info(accounts_iter)?;
+        let gateway_program_id = next_account_info(accounts_iter)?;
+        let gas_service_config_account = next_account_info(accounts_iter)?;
+        let gas_service = next_account_info(accounts_iter)?;
+        let system_program = next_account_info(accounts_iter)?;
+        let its_root_account = next_account_info(accounts_iter)?;
+        let call_contract_signing_account = next_account_info(accounts_iter)?;
+        let program_account = next_account_info(accounts_iter)?;
+        let trusted_chain_account = if *has_trusted_chain_account {
+            Some(next_account_info(accounts_iter)?)
+        } else {
+            None
+        };
+
+        // The mint to pay gas with comes next, the gas service's native gas token standing for
+        // native SOL. It's followed by the rest of the accounts needed to pay gas with SPL tokens,
+        // if any.
+        let gas_token_mint = next_account_info(accounts_iter)?;
+        let gas_token_accounts = if gas_token_mint.key == &NATIVE_GAS_TOKEN {
+            None
+        } else {
+            Some(GasTokenAccounts {
+                payer_token_account: next_account_info(accounts_iter)?,
+                gas_service_config_token_account: next_account_info(accounts_iter)?,
+                mint: gas_token_mint,
+                token_program: next_account_info(accounts_iter)?,
+                gas_token_pda: next_account_info(accounts_iter)?,
+            })
+        };
+        let gas_reserve_account = next_account_info(accounts_iter)?;
+        let gas_payment_accounts = accounts_iter.as_slice();
+
         Ok(Self {
-            gateway_root_account: next_account_info(accounts_iter)?,
-            _gateway_program_id: next_account_info(accounts_iter)?,
-            gas_service_config_account: next_account_info(accounts_iter)?,
-            gas_service: next_account_info(accounts_iter)?,
-            system_program: next_account_info(accounts_iter)?,
-            its_root_account: next_account_info(accounts_iter)?,
-            call_contract_signing_account: next_account_info(accounts_iter)?,
-            program_account: next_account_info(accounts_iter)?,
+            gateway_root_account,
+            _gateway_program_id: gateway_program_id,
+            gas_service_config_account,
+            gas_service,
+            system_program,
+            its_root_account,
+            call_contract_signing_account,
+            program_account,
+            trusted_chain_account,
+            gas_token_accounts,
+            gas_reserve_account,
+            gas_payment_accounts,
         })
     }
 }
@@ -158,23 +243,22 @@ pub(crate) fn process_outbound<'a>(
     payload_hash: Option<[u8; 32]>,
     wrapped: bool,
 ) -> ProgramResult {
-    let its_root_config = InterchainTokenService::load(accounts.its_root_account)?;
-    assert_valid_its_root_pda(accounts.its_root_account, its_root_config.bump)?;
-    assert_its_not_paused(&its_root_config)?;
-    if !its_root_config.is_trusted_chain(&destination_chain)
-        && destination_chain != ITS_HUB_CHAIN_NAME
+    let (its_root_config, maybe_trusted_chain) =
+        ensure_outbound_allowed(accounts, &destination_chain)?;
+
+    if let (Some(trusted_chain), GMPPayload::DeployInterchainToken(deploy)) =
+        (maybe_trusted_chain, payload)
     {
-        msg!("Untrusted destination chain: {}", destination_chain);
-        return Err(ProgramError::InvalidInstructionData);
+        if !trusted_chain.decimals_policy.allows(deploy.decimals) {
+            msg!(
+                "Token decimals not allowed by the policy of: {}",
+                destination_chain
+            );
+            return Err(ProgramError::InvalidArgument);
+        }
     }
 
-    let signing_pda =
-        axelar_solana_gateway::create_call_contract_signing_pda(crate::ID, signing_pda_bump)?;
-
-    if signing_pda.ne(accounts.call_contract_signing_account.key) {
-        msg!("invalid call contract signing account / signing pda bump");
-        return Err(ProgramError::InvalidAccountData);
-    }
+    let signing_pda = ensure_call_contract_signing_pda(accounts, signing_pda_bump)?;
 
     let (payload_hash, call_contract_ix) = if let Some(payload_hash) = payload_hash {
         let ix = axelar_solana_gateway::instructions::call_contract_offchain_data(
@@ -190,14 +274,7 @@ pub(crate) fn process_outbound<'a>(
         (payload_hash, ix)
     } else {
         let payload = if wrapped {
-            GMPPayload::SendToHub(SendToHub {
-                selector: SendToHub::MESSAGE_TYPE_ID
-                    .try_into()
-                    .map_err(|_err| ProgramError::ArithmeticOverflow)?,
-                destination_chain,
-                payload: payload.encode().into(),
-            })
-            .encode()
+            wrap_for_hub(payload, destination_chain)?
         } else {
             payload.encode()
         };
@@ -222,14 +299,228 @@ pub(crate) fn process_outbound<'a>(
             accounts.gas_service,
             accounts.gas_service_config_account,
             accounts.system_program,
+            accounts.gas_token_accounts.as_ref(),
+            accounts.gas_reserve_account,
+            gas_payment_account(accounts, 0)?,
             payload_hash,
             its_root_config.its_hub_address,
             gas_value,
         )?;
     }
 
+    invoke_call_contract(accounts, &call_contract_ix, signing_pda_bump)
+}
+
+/// Sends one message through the ITS Hub for each of the given payloads, all of them to the same
+/// destination chain. Gas is paid for each message against its own payload hash, the given gas
+/// value being split between the messages proportionally to the length of their payload. The
+/// remainder of the split goes to the last message. When paying with SPL tokens, each share must
+/// reach the minimum payment of the token.
+///
+/// Returns the payload hashes of the messages, in order.
+pub(crate) fn process_outbound_batch<'a>(
+    payer: &'a AccountInfo<'a>,
+    accounts: &GmpAccounts<'a>,
+    payloads: &[GMPPayload],
+    destination_chain: &str,
+    gas_value: u64,
+    signing_pda_bump: u8,
+) -> Result<Vec<[u8; 32]>, ProgramError> {
+    let (its_root_config, _trusted_chain) = ensure_outbound_allowed(accounts, destination_chain)?;
+    let signing_pda = ensure_call_contract_signing_pda(accounts, signing_pda_bump)?;
+
+    let wrapped_payloads = payloads
+        .iter()
+        .map(|payload| wrap_for_hub(payload, destination_chain.to_owned()))
+        .collect::<Result<Vec<_>, _>>()?;
+    let gas_values = split_gas_value(
+        gas_value,
+        &wrapped_payloads.iter().map(Vec::len).collect::<Vec<_>>(),
+    )?;
+    ensure_min_gas_payments(accounts, &gas_values)?;
+
+    let mut payload_hashes = Vec::with_capacity(wrapped_payloads.len());
+    let mut gas_payments = 0;
+    for (payload, message_gas_value) in wrapped_payloads.into_iter().zip(gas_values) {
+        let payload_hash = solana_program::keccak::hashv(&[&payload]).to_bytes();
+        let call_contract_ix = axelar_solana_gateway::instructions::call_contract(
+            axelar_solana_gateway::id(),
+            *accounts.gateway_root_account.key,
+            crate::ID,
+            Some((signing_pda, signing_pda_bump)),
+            crate::ITS_HUB_CHAIN_NAME.to_owned(),
+            its_root_config.its_hub_address.clone(),
+            payload,
+        )?;
+
+        if message_gas_value > 0 {
+            pay_gas(
+                payer,
+                accounts.gas_service,
+                accounts.gas_service_config_account,
+                accounts.system_program,
+                accounts.gas_token_accounts.as_ref(),
+                accounts.gas_reserve_account,
+                gas_payment_account(accounts, gas_payments)?,
+                payload_hash,
+                its_root_config.its_hub_address.clone(),
+                message_gas_value,
+            )?;
+            gas_payments = gas_payments.saturating_add(1);
+        }
+
+        invoke_call_contract(accounts, &call_contract_ix, signing_pda_bump)?;
+        payload_hashes.push(payload_hash);
+    }
+
+    Ok(payload_hashes)
+}
+
+/// Makes sure each of the non-zero gas values reaches the minimum payment of the token gas is paid
+/// with, so that a batch is rejected before any of its messages is sent rather than by the gas
+/// service halfway through.
+fn ensure_min_gas_payments(accounts: &GmpAccounts<'_>, gas_values: &[u64]) -> ProgramResult {
+    let Some(gas_token_accounts) = accounts.gas_token_accounts.as_ref() else {
+        return Ok(());
+    };
+
+    gas_token_accounts
+        .gas_token_pda
+        .check_initialized_pda_without_deserialization(&axelar_solana_gas_service::ID)?;
+    let data = gas_token_accounts.gas_token_pda.try_borrow_data()?;
+    let gas_token = GasToken::read(&data).ok_or(ProgramError::InvalidAccountData)?;
+    if &gas_token.mint != gas_token_accounts.mint.key {
+        msg!("Gas token PDA doesn't match the mint");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    if let Some(gas_value) = gas_values
+        .iter()
+        .find(|gas_value| (1..gas_token.min_payment).contains(*gas_value))
+    {
+        msg!(
+            "Gas value share of {} is below the minimum payment of {} for this token",
+            gas_value,
+            gas_token.min_payment
+        );
+        return Err(ProgramError::InsufficientFunds);
+    }
+
+    Ok(())
+}
+
+/// The ledger PDA of the `index`-th gas payment of the instruction.
+fn gas_payment_account<'a>(
+    accounts: &GmpAccounts<'a>,
+    index: usize,
+) -> Result<&'a AccountInfo<'a>, ProgramError> {
+    accounts.gas_payment_accounts.get(index).ok_or_else(|| {
+        msg!("Missing gas payment account");
+        ProgramError::NotEnoughAccountKeys
+    })
+}
+
+/// Splits the gas value between messages proportionally to the given payload
+/// lengths, the remainder of the split going to the last message.
+fn split_gas_value(gas_value: u64, payload_lengths: &[usize]) -> Result<Vec<u64>, ProgramError> {
+    let total_length = payload_lengths
+        .iter()
+        .try_fold(0_u128, |total, length| {
+            u128::try_from(*length)
+                .ok()
+                .and_then(|length| total.checked_add(length))
+        })
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    let mut gas_values = Vec::with_capacity(payload_lengths.len());
+    let mut remaining_gas_value = gas_value;
+    for (index, length) in payload_lengths.iter().enumerate() {
+        let message_gas_value = if index.saturating_add(1) == payload_lengths.len() {
+            remaining_gas_value
+        } else {
+            u128::from(gas_value)
+                .checked_mul(
+                    u128::try_from(*length).map_err(|_err| ProgramError::ArithmeticOverflow)?,
+                )
+                .and_then(|share| share.checked_div(total_length))
+                .and_then(|share| u64::try_from(share).ok())
+                .ok_or(ProgramError::ArithmeticOverflow)?
+        };
+
+        remaining_gas_value = remaining_gas_value
+            .checked_sub(message_gas_value)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        gas_values.push(message_gas_value);
+    }
+
+    Ok(gas_values)
+}
+
+/// Makes sure messages can be sent to the given chain, returning the ITS
+/// configuration and, unless the destination is the ITS Hub itself, the
+/// [`TrustedChain`] of the destination chain.
+fn ensure_outbound_allowed(
+    accounts: &GmpAccounts<'_>,
+    destination_chain: &str,
+) -> Result<(InterchainTokenService, Option<TrustedChain>), ProgramError> {
+    let its_root_config = InterchainTokenService::load(accounts.its_root_account)?;
+    assert_valid_its_root_pda(accounts.its_root_account, its_root_config.bump)?;
+    assert_its_not_paused(&its_root_config)?;
+
+    if destination_chain == ITS_HUB_CHAIN_NAME {
+        return Ok((its_root_config, None));
+    }
+
+    let Some(trusted_chain_account) = accounts.trusted_chain_account else {
+        msg!("Missing trusted chain account for: {}", destination_chain);
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    let trusted_chain = ensure_trusted_chain(
+        accounts.its_root_account.key,
+        trusted_chain_account,
+        destination_chain,
+    )?;
+
+    Ok((its_root_config, Some(trusted_chain)))
+}
+
+fn ensure_call_contract_signing_pda(
+    accounts: &GmpAccounts<'_>,
+    signing_pda_bump: u8,
+) -> Result<Pubkey, ProgramError> {
+    let signing_pda =
+        axelar_solana_gateway::create_call_contract_signing_pda(crate::ID, signing_pda_bump)?;
+
+    if signing_pda.ne(accounts.call_contract_signing_account.key) {
+        msg!("invalid call contract signing account / signing pda bump");
+        return Err(ProgramError::InvalidAccountData);
+    }
+
+    Ok(signing_pda)
+}
+
+pub(crate) fn wrap_for_hub(
+    payload: &GMPPayload,
+    destination_chain: String,
+) -> Result<Vec<u8>, ProgramError> {
+    Ok(GMPPayload::SendToHub(SendToHub {
+        selector: SendToHub::MESSAGE_TYPE_ID
+            .try_into()
+            .map_err(|_err| ProgramError::ArithmeticOverflow)?,
+        destination_chain,
+        payload: payload.encode().into(),
+    })
+    .encode())
+}
+
+fn invoke_call_contract(
+    accounts: &GmpAccounts<'_>,
+    call_contract_ix: &Instruction,
+    signing_pda_bump: u8,
+) -> ProgramResult {
     invoke_signed(
-        &call_contract_ix,
+        call_contract_ix,
         &[
             accounts.program_account.clone(),
             accounts.call_contract_signing_account.clone(),
@@ -239,16 +530,49 @@ pub(crate) fn process_outbound<'a>(
             axelar_solana_gateway::seed_prefixes::CALL_CONTRACT_SIGNING_SEED,
             &[signing_pda_bump],
         ]],
+    )
+}
+
+/// Loads the [`TrustedChain`] stored in the given account, making sure it's the
+/// canonical PDA for `chain_name` and that communication with it isn't paused.
+pub(crate) fn ensure_trusted_chain(
+    its_root_pda: &Pubkey,
+    trusted_chain_account: &AccountInfo<'_>,
+    chain_name: &str,
+) -> Result<TrustedChain, ProgramError> {
+    if trusted_chain_account
+        .check_initialized_pda_without_deserialization(&crate::id())
+        .is_err()
+    {
+        msg!("Untrusted chain: {}", chain_name);
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let trusted_chain = TrustedChain::load(trusted_chain_account)?;
+    assert_valid_trusted_chain_pda(
+        trusted_chain_account,
+        its_root_pda,
+        chain_name,
+        trusted_chain.bump,
     )?;
 
-    Ok(())
+    if trusted_chain.paused {
+        msg!("Communication with chain {} is paused", chain_name);
+        return Err(ProgramError::Immutable);
+    }
+
+    Ok(trusted_chain)
 }
 
+#[allow(clippy::too_many_arguments)]
 fn pay_gas<'a>(
     payer: &'a AccountInfo<'a>,
     gas_service: &'a AccountInfo<'a>,
     gas_service_config: &'a AccountInfo<'a>,
     system_program: &'a AccountInfo<'a>,
+    gas_token_accounts: Option<&GasTokenAccounts<'a>>,
+    gas_reserve_account: &'a AccountInfo<'a>,
+    gas_payment_account: &'a AccountInfo<'a>,
     payload_hash: [u8; 32],
     its_hub_address: String,
     gas_value: u64,
@@ -258,6 +582,52 @@ fn pay_gas<'a>(
         return Err(ProgramError::IncorrectProgramId);
     }
 
+    if let Some(gas_token_accounts) = gas_token_accounts {
+        let decimals = {
+            let mint_data = gas_token_accounts.mint.try_borrow_data()?;
+            StateWithExtensions::<Mint>::unpack(&mint_data)?
+                .base
+                .decimals
+        };
+
+        let gas_payment_ix =
+            axelar_solana_gas_service::instructions::pay_spl_for_contract_call_instruction(
+                gas_service.key,
+                payer.key,
+                gas_token_accounts.payer_token_account.key,
+                gas_service_config.key,
+                gas_token_accounts.gas_service_config_token_account.key,
+                gas_token_accounts.mint.key,
+                gas_token_accounts.token_program.key,
+                crate::ITS_HUB_CHAIN_NAME.to_owned(),
+                its_hub_address,
+                payload_hash,
+                *payer.key,
+                vec![],
+                gas_value,
+                &[],
+                decimals,
+                payer.key,
+                None,
+            )?;
+
+        return invoke(
+            &gas_payment_ix,
+            &[
+                payer.clone(),
+                gas_token_accounts.payer_token_account.clone(),
+                gas_service_config.clone(),
+                gas_token_accounts.gas_service_config_token_account.clone(),
+                gas_token_accounts.mint.clone(),
+                gas_token_accounts.token_program.clone(),
+                gas_token_accounts.gas_token_pda.clone(),
+                gas_payment_account.clone(),
+                system_program.clone(),
+                gas_reserve_account.clone(),
+            ],
+        );
+    }
+
     let gas_payment_ix =
         axelar_solana_gas_service::instructions::pay_native_for_contract_call_instruction(
             gas_service.key,
@@ -269,6 +639,7 @@ fn pay_gas<'a>(
             *payer.key,
             vec![],
             gas_value,
+            None,
         )?;
 
     invoke(
@@ -277,6 +648,8 @@ fn pay_gas<'a>(
             payer.clone(),
             gas_service_config.clone(),
             system_program.clone(),
+            gas_payment_account.clone(),
+            gas_reserve_account.clone(),
         ],
     )
 }
@@ -287,24 +660,53 @@ fn validate_its_accounts(accounts: &[AccountInfo<'_>], payload: &GMPPayload) ->
     const TOKEN_PROGRAM_INDEX: usize = 5;
 
     // In this case we cannot derive the mint account, so we just use what we got
-    // and check later against the mint within the `TokenManager` PDA.
-    let maybe_mint = if let GMPPayload::InterchainTransfer(_) = payload {
-        accounts.get(TOKEN_MINT_INDEX).map(|account| *account.key)
+    // and check later against the mint within the `TokenManager` PDA. The flow
+    // epoch depends on the configuration of the `TokenManager` as well.
+    let maybe_token_manager = if let GMPPayload::InterchainTransfer(_) = payload {
+        let token_manager_pda = accounts
+            .get(TOKEN_MANAGER_PDA_INDEX)
+            .ok_or(ProgramError::InvalidAccountData)?;
+
+        Some(TokenManager::load(token_manager_pda)?)
     } else {
         None
     };
 
+    let maybe_mint = maybe_token_manager
+        .as_ref()
+        .and_then(|_| accounts.get(TOKEN_MINT_INDEX).map(|account| *account.key));
+
+    let maybe_flow_epoch = match maybe_token_manager {
+        Some(ref token_manager) => Some(
+            token_manager
+                .flow_limit
+                .flow_epoch(Clock::get()?.unix_timestamp)?,
+        ),
+        None => None,
+    };
+
     let token_program = accounts
         .get(TOKEN_PROGRAM_INDEX)
         .map(|account| *account.key)
         .ok_or(ProgramError::InvalidAccountData)?;
 
-    let derived_its_accounts = instruction::derive_its_accounts(
-        payload,
-        token_program,
-        maybe_mint,
-        Some(Clock::get()?.unix_timestamp),
-    )?;
+    let derived_its_accounts =
+        instruction::derive_its_accounts(payload, token_program, maybe_mint, maybe_flow_epoch)?;
+
+    // The accounts required by the transfer hook of the mint, if any, come last. They're not
+    // derived here but resolved by the token program when tokens are transferred.
+    let has_transfer_hook = match accounts.get(TOKEN_MINT_INDEX) {
+        Some(mint_account) if maybe_mint.is_some() => {
+            let mint_data = mint_account.try_borrow_data()?;
+            let mint = StateWithExtensions::<Mint>::unpack(&mint_data)?;
+            transfer_hook::get_program_id(&mint).is_some()
+        }
+        _ => false,
+    };
+    let accounts = match accounts.split_at_checked(derived_its_accounts.len()) {
+        Some((its_accounts, _transfer_hook_accounts)) if has_transfer_hook => its_accounts,
+        _ => accounts,
+    };
 
     for element in accounts.iter().zip_longest(derived_its_accounts.iter()) {
         match element {
@@ -320,13 +722,7 @@ fn validate_its_accounts(accounts: &[AccountInfo<'_>], payload: &GMPPayload) ->
     }
 
     // Now we validate the mint account passed for `InterchainTransfer`
-    if let Some(mint) = maybe_mint {
-        let token_manager_pda = accounts
-            .get(TOKEN_MANAGER_PDA_INDEX)
-            .ok_or(ProgramError::InvalidAccountData)?;
-
-        let token_manager = TokenManager::load(token_manager_pda)?;
-
+    if let (Some(mint), Some(token_manager)) = (maybe_mint, maybe_token_manager) {
         if token_manager.token_address.as_ref() != mint.as_ref() {
             return Err(ProgramError::InvalidAccountData);
         }
@@ -334,3 +730,19 @@ fn validate_its_accounts(accounts: &[AccountInfo<'_>], payload: &GMPPayload) ->
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::split_gas_value;
+
+    #[test]
+    fn test_split_gas_value() {
+        assert_eq!(split_gas_value(1_000, &[100, 100]).unwrap(), vec![500, 500]);
+        assert_eq!(
+            split_gas_value(1_000, &[100, 200, 100]).unwrap(),
+            vec![250, 500, 250]
+        );
+        assert_eq!(split_gas_value(10, &[1, 1, 1]).unwrap(), vec![3, 3, 4]);
+        assert_eq!(split_gas_value(0, &[1, 2]).unwrap(), vec![0, 0]);
+    }
+}
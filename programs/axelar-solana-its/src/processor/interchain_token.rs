@@ -3,40 +3,50 @@
 use alloy_primitives::Bytes;
 use axelar_solana_gateway::num_traits::Zero;
 use event_utils::Event as _;
-use interchain_token_transfer_gmp::{DeployInterchainToken, GMPPayload};
+use interchain_token_transfer_gmp::{
+    DeployInterchainToken, GMPPayload, RegisterTokenMetadataUpdate,
+};
 use mpl_token_metadata::accounts::Metadata;
-use mpl_token_metadata::instructions::CreateV1CpiBuilder;
-use mpl_token_metadata::types::TokenStandard;
+use mpl_token_metadata::instructions::{CreateV1CpiBuilder, UpdateV1CpiBuilder};
+use mpl_token_metadata::types::{Data, TokenStandard};
 use program_utils::pda::init_pda_raw;
 use program_utils::{
-    pda::BorshPda, validate_mpl_token_metadata_key, validate_rent_key,
-    validate_spl_associated_token_account_key, validate_system_account_key,
-    validate_sysvar_instructions_key,
+    pda::{BorshPda, ValidPDA},
+    validate_mpl_token_metadata_key, validate_rent_key, validate_spl_associated_token_account_key,
+    validate_system_account_key, validate_sysvar_instructions_key,
 };
 use role_management::processor::{
-    ensure_roles, ensure_signer_roles, RoleAddAccounts, RoleRemoveAccounts,
+    ensure_roles, ensure_signer_or_multisig_roles, ensure_signer_roles, RoleAddAccounts,
+    RoleCancelProposalAccounts, RoleProposeAccounts, RoleRemoveAccounts,
     RoleTransferWithProposalAccounts,
 };
+use role_management::state::RoleGrant;
 use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::clock::Clock;
 use solana_program::entrypoint::ProgramResult;
 use solana_program::msg;
 use solana_program::program::{invoke, invoke_signed, set_return_data};
 use solana_program::program_error::ProgramError;
-use solana_program::program_pack::Pack as _;
 use solana_program::pubkey::Pubkey;
+use solana_program::system_instruction;
+use solana_program::sysvar::{rent::Rent, Sysvar as _};
 use spl_token_2022::check_spl_token_program_account;
+use spl_token_2022::extension::metadata_pointer::{self, MetadataPointer};
+use spl_token_2022::extension::{BaseStateWithExtensions, ExtensionType, StateWithExtensions};
 use spl_token_2022::instruction::initialize_mint;
 use spl_token_2022::state::Mint;
+use spl_token_metadata_interface::state::{Field, TokenMetadata};
 
 use super::gmp::{self, GmpAccounts};
 use super::token_manager::{DeployTokenManagerAccounts, DeployTokenManagerInternal};
 use crate::state::deploy_approval::DeployApproval;
+use crate::state::minter_allowance::MinterAllowance;
 use crate::state::token_manager::{self, TokenManager};
-use crate::state::InterchainTokenService;
+use crate::state::{InterchainTokenService, MetadataStandard};
 use crate::{assert_valid_deploy_approval_pda, event, find_its_root_pda, Validate};
 use crate::{
-    assert_valid_its_root_pda, assert_valid_token_manager_pda, seed_prefixes, FromAccountInfoSlice,
-    Roles,
+    assert_valid_its_root_pda, assert_valid_minter_allowance_pda, assert_valid_token_manager_pda,
+    seed_prefixes, FromAccountInfoSlice, Roles,
 };
 
 #[derive(Debug)]
@@ -49,10 +59,11 @@ pub(crate) struct DeployInterchainTokenAccounts<'a> {
     pub(crate) token_program: &'a AccountInfo<'a>,
     pub(crate) ata_program: &'a AccountInfo<'a>,
     pub(crate) its_roles_pda: &'a AccountInfo<'a>,
+    pub(crate) role_index_pda: &'a AccountInfo<'a>,
     pub(crate) rent_sysvar: &'a AccountInfo<'a>,
     pub(crate) sysvar_instructions: &'a AccountInfo<'a>,
-    pub(crate) mpl_token_metadata_program: &'a AccountInfo<'a>,
-    pub(crate) mpl_token_metadata_account: &'a AccountInfo<'a>,
+    pub(crate) mpl_token_metadata_program: Option<&'a AccountInfo<'a>>,
+    pub(crate) mpl_token_metadata_account: Option<&'a AccountInfo<'a>>,
     pub(crate) payer_ata: &'a AccountInfo<'a>,
     pub(crate) minter: Option<&'a AccountInfo<'a>>,
     pub(crate) minter_roles_pda: Option<&'a AccountInfo<'a>>,
@@ -65,21 +76,24 @@ impl Validate for DeployInterchainTokenAccounts<'_> {
         validate_spl_associated_token_account_key(self.ata_program.key)?;
         validate_rent_key(self.rent_sysvar.key)?;
         validate_sysvar_instructions_key(self.sysvar_instructions.key)?;
-        validate_mpl_token_metadata_key(self.mpl_token_metadata_program.key)?;
+        if let Some(mpl_token_metadata_program) = self.mpl_token_metadata_program {
+            validate_mpl_token_metadata_key(mpl_token_metadata_program.key)?;
+        }
         Ok(())
     }
 }
 
 impl<'a> FromAccountInfoSlice<'a> for DeployInterchainTokenAccounts<'a> {
-    type Context = ();
+    type Context = MetadataStandard;
     fn extract_accounts(
         accounts: &'a [AccountInfo<'a>],
-        _context: &Self::Context,
+        metadata_standard: &Self::Context,
     ) -> Result<Self, ProgramError>
     where
         Self: Sized + Validate,
     {
         let accounts_iter = &mut accounts.iter();
+        let uses_metaplex = *metadata_standard == MetadataStandard::Metaplex;
 
         Ok(Self {
             system_account: next_account_info(accounts_iter)?,
@@ -90,10 +104,15 @@ impl<'a> FromAccountInfoSlice<'a> for DeployInterchainTokenAccounts<'a> {
             token_program: next_account_info(accounts_iter)?,
             ata_program: next_account_info(accounts_iter)?,
             its_roles_pda: next_account_info(accounts_iter)?,
+            role_index_pda: next_account_info(accounts_iter)?,
             rent_sysvar: next_account_info(accounts_iter)?,
             sysvar_instructions: next_account_info(accounts_iter)?,
-            mpl_token_metadata_program: next_account_info(accounts_iter)?,
-            mpl_token_metadata_account: next_account_info(accounts_iter)?,
+            mpl_token_metadata_program: uses_metaplex
+                .then(|| next_account_info(accounts_iter))
+                .transpose()?,
+            mpl_token_metadata_account: uses_metaplex
+                .then(|| next_account_info(accounts_iter))
+                .transpose()?,
             payer_ata: next_account_info(accounts_iter)?,
             minter: next_account_info(accounts_iter).ok(),
             minter_roles_pda: next_account_info(accounts_iter).ok(),
@@ -112,6 +131,7 @@ impl<'a> From<DeployInterchainTokenAccounts<'a>> for DeployTokenManagerAccounts<
             token_program: value.token_program,
             ata_program: value.ata_program,
             its_roles_pda: value.its_roles_pda,
+            role_index_pda: value.role_index_pda,
             rent_sysvar: value.rent_sysvar,
             operator: value.minter,
             operator_roles_pda: value.minter_roles_pda,
@@ -126,6 +146,7 @@ pub(crate) fn process_deploy<'a>(
     symbol: String,
     decimals: u8,
     initial_supply: u64,
+    metadata_standard: MetadataStandard,
 ) -> ProgramResult {
     let (payer, other_accounts) = accounts
         .split_first()
@@ -134,7 +155,7 @@ pub(crate) fn process_deploy<'a>(
     let deploy_salt = crate::interchain_token_deployer_salt(payer.key, &salt);
     let token_id = crate::interchain_token_id_internal(&deploy_salt);
     let parsed_accounts =
-        DeployInterchainTokenAccounts::from_account_info_slice(other_accounts, &())?;
+        DeployInterchainTokenAccounts::from_account_info_slice(other_accounts, &metadata_standard)?;
     if initial_supply.is_zero() && parsed_accounts.minter.is_none() {
         return Err(ProgramError::InvalidArgument);
     }
@@ -154,6 +175,8 @@ pub(crate) fn process_deploy<'a>(
         symbol,
         decimals,
         initial_supply,
+        metadata_standard,
+        None,
     )?;
 
     set_return_data(&token_id);
@@ -169,6 +192,8 @@ pub(crate) fn process_inbound_deploy<'a>(
     symbol: String,
     decimals: u8,
     initial_supply: u64,
+    metadata_standard: MetadataStandard,
+    origin_chain: Option<String>,
 ) -> ProgramResult {
     msg!("Instruction: InboundDeploy");
     let its_root_pda_bump = InterchainTokenService::load(accounts.its_root_pda)?.bump;
@@ -196,27 +221,42 @@ pub(crate) fn process_inbound_deploy<'a>(
         interchain_token_pda_bump,
         token_manager_pda_bump,
         initial_supply,
+        metadata_standard,
     )?;
-    setup_metadata(
-        payer,
-        &accounts,
-        &token_id,
-        name.clone(),
-        symbol.clone(),
-        String::new(),
-        token_manager_pda_bump,
-    )?;
+    match metadata_standard {
+        MetadataStandard::Metaplex => setup_metadata(
+            payer,
+            &accounts,
+            &token_id,
+            name.clone(),
+            symbol.clone(),
+            String::new(),
+            token_manager_pda_bump,
+        )?,
+        MetadataStandard::Token2022 => setup_token_2022_metadata(
+            payer,
+            &accounts,
+            &token_id,
+            name.clone(),
+            symbol.clone(),
+            String::new(),
+            token_manager_pda_bump,
+        )?,
+    }
 
     // The minter passed in the DeployInterchainToken call is used as the
     // `TokenManager` operator as well, see:
     // https://github.com/axelarnetwork/interchain-token-service/blob/v2.0.1/contracts/InterchainTokenService.sol#L758
-    let deploy_token_manager = DeployTokenManagerInternal::new(
+    let mut deploy_token_manager = DeployTokenManagerInternal::new(
         token_manager::Type::NativeInterchainToken,
         token_id,
         *accounts.token_mint.key,
         accounts.minter.map(|account| *account.key),
         accounts.minter.map(|account| *account.key),
     );
+    if let Some(origin_chain) = origin_chain {
+        deploy_token_manager = deploy_token_manager.with_origin_chain(origin_chain);
+    }
 
     let deploy_token_manager_accounts = DeployTokenManagerAccounts::from(accounts);
     super::token_manager::deploy(
@@ -280,25 +320,19 @@ pub(crate) fn process_outbound_deploy<'a>(
     };
 
     let (_other, outbound_message_accounts) = accounts.split_at(outbound_message_accounts_index);
-    let gmp_accounts = GmpAccounts::from_account_info_slice(outbound_message_accounts, &())?;
+    let gmp_accounts = GmpAccounts::from_account_info_slice(outbound_message_accounts, &true)?;
     msg!("Instruction: OutboundDeploy");
 
-    let token_metadata = Metadata::from_bytes(&metadata.try_borrow_data()?)?;
-    let mint_data = Mint::unpack(&mint.try_borrow_data()?)?;
-
-    if token_metadata.mint != *mint.key {
-        msg!("The metadata and mint accounts passed don't match");
-        return Err(ProgramError::InvalidArgument);
-    }
-
-    let name = token_metadata.name.trim_end_matches('\0').to_owned();
-    let symbol = token_metadata.symbol.trim_end_matches('\0').to_owned();
+    let (name, symbol) = token_name_and_symbol(mint, metadata)?;
+    let decimals = StateWithExtensions::<Mint>::unpack(&mint.try_borrow_data()?)?
+        .base
+        .decimals;
 
     let deployment_started_event = event::InterchainTokenDeploymentStarted {
         token_id,
         token_name: name,
         token_symbol: symbol,
-        token_decimals: mint_data.decimals,
+        token_decimals: decimals,
         minter: destination_minter_data
             .as_ref()
             .map(|data| data.0.to_vec())
@@ -314,7 +348,7 @@ pub(crate) fn process_outbound_deploy<'a>(
         token_id: token_id.into(),
         name: deployment_started_event.token_name,
         symbol: deployment_started_event.token_symbol,
-        decimals: mint_data.decimals,
+        decimals,
         minter: destination_minter_data
             .as_ref()
             .map(|data| data.0.clone())
@@ -399,8 +433,12 @@ pub(crate) fn process_mint<'a>(accounts: &'a [AccountInfo<'a>], amount: u64) ->
     let minter = next_account_info(accounts_iter)?;
     let minter_roles_pda = next_account_info(accounts_iter)?;
     let token_program = next_account_info(accounts_iter)?;
+    let minter_allowance_pda = next_account_info(accounts_iter)?;
+    let system_account = next_account_info(accounts_iter)?;
+    let payer = next_account_info(accounts_iter)?;
 
     msg!("Instruction: MintInterchainToken");
+    validate_system_account_key(system_account.key)?;
     let token_manager = TokenManager::load(token_manager_pda)?;
     assert_valid_token_manager_pda(
         token_manager_pda,
@@ -416,12 +454,26 @@ pub(crate) fn process_mint<'a>(accounts: &'a [AccountInfo<'a>], amount: u64) ->
         return Err(ProgramError::IncorrectProgramId);
     }
 
-    ensure_signer_roles(
+    ensure_signer_or_multisig_roles(
         &crate::id(),
         token_manager_pda,
         minter,
         minter_roles_pda,
         Roles::MINTER,
+        accounts,
+    )?;
+
+    let supply = StateWithExtensions::<Mint>::unpack(&mint.try_borrow_data()?)?
+        .base
+        .supply;
+    token_manager.ensure_within_supply_cap(supply, amount)?;
+    consume_minter_allowance(
+        payer,
+        token_manager_pda,
+        minter,
+        minter_allowance_pda,
+        system_account,
+        amount,
     )?;
 
     invoke_signed(
@@ -449,6 +501,139 @@ pub(crate) fn process_mint<'a>(accounts: &'a [AccountInfo<'a>], amount: u64) ->
     Ok(())
 }
 
+fn consume_minter_allowance<'a>(
+    payer: &AccountInfo<'a>,
+    token_manager_pda: &AccountInfo<'a>,
+    minter: &AccountInfo<'a>,
+    minter_allowance_pda: &AccountInfo<'a>,
+    system_account: &AccountInfo<'a>,
+    amount: u64,
+) -> ProgramResult {
+    if minter_allowance_pda.data_is_empty() {
+        let (expected_minter_allowance_pda, _) =
+            crate::find_minter_allowance_pda(token_manager_pda.key, minter.key);
+        if expected_minter_allowance_pda.ne(minter_allowance_pda.key) {
+            msg!("Invalid MinterAllowance PDA provided");
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        // Minters without an allowance are not limited.
+        return Ok(());
+    }
+
+    minter_allowance_pda.check_initialized_pda_without_deserialization(&crate::id())?;
+    let mut minter_allowance = MinterAllowance::load(minter_allowance_pda)?;
+    assert_valid_minter_allowance_pda(
+        minter_allowance_pda,
+        token_manager_pda.key,
+        minter.key,
+        minter_allowance.bump,
+    )?;
+
+    minter_allowance.consume(amount, Clock::get()?.unix_timestamp)?;
+    minter_allowance.store(payer, minter_allowance_pda, system_account)
+}
+
+pub(crate) fn process_set_minter_allowance<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    allowance: u64,
+    refill_period: u64,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let operator = next_account_info(accounts_iter)?;
+    let operator_roles_pda = next_account_info(accounts_iter)?;
+    let its_root_pda = next_account_info(accounts_iter)?;
+    let token_manager_pda = next_account_info(accounts_iter)?;
+    let minter = next_account_info(accounts_iter)?;
+    let minter_allowance_pda = next_account_info(accounts_iter)?;
+    let system_account = next_account_info(accounts_iter)?;
+    let payer = super::role_change_payer(operator, accounts_iter)?;
+
+    msg!("Instruction: SetInterchainTokenMinterAllowance");
+    validate_system_account_key(system_account.key)?;
+
+    let its_root_config = InterchainTokenService::load(its_root_pda)?;
+    assert_valid_its_root_pda(its_root_pda, its_root_config.bump)?;
+
+    let token_manager = TokenManager::load(token_manager_pda)?;
+    assert_valid_token_manager_pda(
+        token_manager_pda,
+        its_root_pda.key,
+        &token_manager.token_id,
+        token_manager.bump,
+    )?;
+
+    ensure_signer_or_multisig_roles(
+        &crate::id(),
+        token_manager_pda,
+        operator,
+        operator_roles_pda,
+        Roles::OPERATOR,
+        accounts,
+    )?;
+
+    let timestamp = Clock::get()?.unix_timestamp;
+
+    if minter_allowance_pda.data_is_empty() {
+        let (expected_minter_allowance_pda, bump) =
+            crate::find_minter_allowance_pda(token_manager_pda.key, minter.key);
+        if expected_minter_allowance_pda.ne(minter_allowance_pda.key) {
+            msg!("Invalid MinterAllowance PDA provided");
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        MinterAllowance::new(
+            *token_manager_pda.key,
+            *minter.key,
+            allowance,
+            refill_period,
+            timestamp,
+            bump,
+        )
+        .init(
+            &crate::id(),
+            system_account,
+            payer,
+            minter_allowance_pda,
+            &[
+                seed_prefixes::MINTER_ALLOWANCE_SEED,
+                token_manager_pda.key.as_ref(),
+                minter.key.as_ref(),
+                &[bump],
+            ],
+        )?;
+    } else {
+        minter_allowance_pda.check_initialized_pda_without_deserialization(&crate::id())?;
+        let mut minter_allowance = MinterAllowance::load(minter_allowance_pda)?;
+        assert_valid_minter_allowance_pda(
+            minter_allowance_pda,
+            token_manager_pda.key,
+            minter.key,
+            minter_allowance.bump,
+        )?;
+
+        // Tokens already minted within the current period keep counting
+        // towards the new allowance, unless the refill period changes.
+        if minter_allowance.refill_period != refill_period {
+            minter_allowance.minted = 0;
+            minter_allowance.period_start = timestamp;
+        }
+        minter_allowance.allowance = allowance;
+        minter_allowance.refill_period = refill_period;
+        minter_allowance.store(payer, minter_allowance_pda, system_account)?;
+    }
+
+    event::MinterAllowanceSet {
+        token_id: token_manager.token_id,
+        minter: *minter.key,
+        allowance,
+        refill_period,
+    }
+    .emit();
+
+    Ok(())
+}
+
 fn setup_mint<'a>(
     payer: &AccountInfo<'a>,
     accounts: &DeployInterchainTokenAccounts<'a>,
@@ -457,13 +642,19 @@ fn setup_mint<'a>(
     interchain_token_pda_bump: u8,
     token_manager_pda_bump: u8,
     initial_supply: u64,
+    metadata_standard: MetadataStandard,
 ) -> ProgramResult {
+    let extensions: &[ExtensionType] = match metadata_standard {
+        MetadataStandard::Metaplex => &[],
+        MetadataStandard::Token2022 => &[ExtensionType::MetadataPointer],
+    };
+
     init_pda_raw(
         payer,
         accounts.token_mint,
         accounts.token_program.key,
         accounts.system_account,
-        spl_token_2022::state::Mint::LEN
+        ExtensionType::try_calculate_account_len::<Mint>(extensions)?
             .try_into()
             .map_err(|_err| ProgramError::ArithmeticOverflow)?,
         &[
@@ -474,6 +665,20 @@ fn setup_mint<'a>(
         ],
     )?;
 
+    // Extensions need to be initialized before the mint itself. The metadata is stored in the
+    // mint account, thus the pointer refers to the mint.
+    if metadata_standard == MetadataStandard::Token2022 {
+        invoke(
+            &metadata_pointer::instruction::initialize(
+                accounts.token_program.key,
+                accounts.token_mint.key,
+                Some(*accounts.token_manager_pda.key),
+                Some(*accounts.token_mint.key),
+            )?,
+            &[accounts.token_mint.clone(), accounts.token_program.clone()],
+        )?;
+    }
+
     invoke(
         &initialize_mint(
             &spl_token_2022::id(),
@@ -537,14 +742,21 @@ fn setup_metadata<'a>(
     uri: String,
     token_manager_pda_bump: u8,
 ) -> ProgramResult {
-    CreateV1CpiBuilder::new(accounts.mpl_token_metadata_program)
-        .metadata(accounts.mpl_token_metadata_account)
+    let (Some(mpl_token_metadata_program), Some(mpl_token_metadata_account)) = (
+        accounts.mpl_token_metadata_program,
+        accounts.mpl_token_metadata_account,
+    ) else {
+        return Err(ProgramError::NotEnoughAccountKeys);
+    };
+
+    CreateV1CpiBuilder::new(mpl_token_metadata_program)
+        .metadata(mpl_token_metadata_account)
         .token_standard(TokenStandard::Fungible)
         .mint(accounts.token_mint, false)
         .authority(accounts.token_manager_pda)
         .update_authority(accounts.token_manager_pda, true)
         .payer(payer)
-        .is_mutable(false)
+        .is_mutable(true)
         .name(name)
         .symbol(symbol)
         .uri(uri)
@@ -561,12 +773,475 @@ fn setup_metadata<'a>(
     Ok(())
 }
 
+fn setup_token_2022_metadata<'a>(
+    payer: &AccountInfo<'a>,
+    accounts: &DeployInterchainTokenAccounts<'a>,
+    token_id: &[u8],
+    name: String,
+    symbol: String,
+    uri: String,
+    token_manager_pda_bump: u8,
+) -> ProgramResult {
+    let token_metadata = TokenMetadata {
+        update_authority: Some(*accounts.token_manager_pda.key).try_into()?,
+        mint: *accounts.token_mint.key,
+        name,
+        symbol,
+        uri,
+        additional_metadata: Vec::new(),
+    };
+
+    // The token program reallocates the mint to fit the metadata, but the lamports to keep it
+    // rent exempt need to be there beforehand.
+    let new_mint_len = accounts
+        .token_mint
+        .data_len()
+        .checked_add(token_metadata.tlv_size_of()?)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    fund_rent_exemption(
+        payer,
+        accounts.token_mint,
+        accounts.system_account,
+        new_mint_len,
+    )?;
+
+    invoke_signed(
+        &spl_token_metadata_interface::instruction::initialize(
+            accounts.token_program.key,
+            accounts.token_mint.key,
+            accounts.token_manager_pda.key,
+            accounts.token_mint.key,
+            accounts.token_manager_pda.key,
+            token_metadata.name,
+            token_metadata.symbol,
+            token_metadata.uri,
+        ),
+        &[
+            accounts.token_mint.clone(),
+            accounts.token_manager_pda.clone(),
+            accounts.token_program.clone(),
+        ],
+        &[&[
+            seed_prefixes::TOKEN_MANAGER_SEED,
+            accounts.its_root_pda.key.as_ref(),
+            token_id,
+            &[token_manager_pda_bump],
+        ]],
+    )?;
+
+    Ok(())
+}
+
+fn fund_rent_exemption<'a>(
+    payer: &AccountInfo<'a>,
+    account: &AccountInfo<'a>,
+    system_account: &AccountInfo<'a>,
+    data_len: usize,
+) -> ProgramResult {
+    let lamports_diff = Rent::get()?
+        .minimum_balance(data_len)
+        .saturating_sub(account.lamports());
+
+    if lamports_diff > 0 {
+        invoke(
+            &system_instruction::transfer(payer.key, account.key, lamports_diff),
+            &[payer.clone(), account.clone(), system_account.clone()],
+        )?;
+    }
+
+    Ok(())
+}
+
+/// Reads the Token-2022 `TokenMetadata` extension of the given mint. Returns `None` when the
+/// `MetadataPointer` of the mint doesn't point to the mint itself, in which case the metadata, if
+/// any, lives in a Metaplex metadata account.
+pub(crate) fn token_2022_metadata(
+    mint: &AccountInfo<'_>,
+) -> Result<Option<TokenMetadata>, ProgramError> {
+    if *mint.owner != spl_token_2022::ID {
+        return Ok(None);
+    }
+
+    let mint_data = mint.try_borrow_data()?;
+    let mint_state = StateWithExtensions::<Mint>::unpack(&mint_data)?;
+    let points_to_mint = mint_state
+        .get_extension::<MetadataPointer>()
+        .ok()
+        .and_then(|pointer| Option::<Pubkey>::from(pointer.metadata_address))
+        .is_some_and(|metadata_address| metadata_address == *mint.key);
+
+    if !points_to_mint {
+        return Ok(None);
+    }
+
+    Ok(mint_state
+        .get_variable_len_extension::<TokenMetadata>()
+        .ok())
+}
+
+/// Returns the name and symbol of the token, taken from the Token-2022 metadata extension of the
+/// mint if present, otherwise from the given Metaplex metadata account.
+pub(crate) fn token_name_and_symbol(
+    mint: &AccountInfo<'_>,
+    metaplex_metadata: &AccountInfo<'_>,
+) -> Result<(String, String), ProgramError> {
+    if let Some(token_metadata) = token_2022_metadata(mint)? {
+        return Ok((token_metadata.name, token_metadata.symbol));
+    }
+
+    let token_metadata = Metadata::from_bytes(&metaplex_metadata.try_borrow_data()?)?;
+    if token_metadata.mint != *mint.key {
+        msg!("The metadata and mint accounts passed don't match");
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    Ok((
+        token_metadata.name.trim_end_matches('\0').to_owned(),
+        token_metadata.symbol.trim_end_matches('\0').to_owned(),
+    ))
+}
+
+#[derive(Debug)]
+struct UpdateMetadataAccounts<'a> {
+    system_account: &'a AccountInfo<'a>,
+    its_root_pda: &'a AccountInfo<'a>,
+    token_manager_pda: &'a AccountInfo<'a>,
+    token_mint: &'a AccountInfo<'a>,
+    token_program: &'a AccountInfo<'a>,
+    sysvar_instructions: &'a AccountInfo<'a>,
+    mpl_token_metadata_program: &'a AccountInfo<'a>,
+    mpl_token_metadata_account: &'a AccountInfo<'a>,
+}
+
+impl Validate for UpdateMetadataAccounts<'_> {
+    fn validate(&self) -> Result<(), ProgramError> {
+        validate_system_account_key(self.system_account.key)?;
+        check_spl_token_program_account(self.token_program.key)?;
+        validate_sysvar_instructions_key(self.sysvar_instructions.key)?;
+        validate_mpl_token_metadata_key(self.mpl_token_metadata_program.key)?;
+        Ok(())
+    }
+}
+
+pub(crate) fn process_update_metadata<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    token_id: [u8; 32],
+    name: String,
+    symbol: String,
+    uri: String,
+    destination_chain: Option<String>,
+    gas_value: u64,
+    signing_pda_bump: u8,
+) -> ProgramResult {
+    const OUTBOUND_MESSAGE_ACCOUNTS_INDEX: usize = 8;
+    let accounts_iter = &mut accounts.iter();
+    let operator = next_account_info(accounts_iter)?;
+    let operator_roles_account = next_account_info(accounts_iter)?;
+    let token_manager_account = next_account_info(accounts_iter)?;
+    let mint = next_account_info(accounts_iter)?;
+    let metadata = next_account_info(accounts_iter)?;
+    let mpl_token_metadata_program = next_account_info(accounts_iter)?;
+    let sysvar_instructions = next_account_info(accounts_iter)?;
+    let token_program = next_account_info(accounts_iter)?;
+
+    let (_other, outbound_message_accounts) = accounts.split_at(OUTBOUND_MESSAGE_ACCOUNTS_INDEX);
+    let gmp_accounts = GmpAccounts::from_account_info_slice(
+        outbound_message_accounts,
+        &destination_chain.is_some(),
+    )?;
+    msg!("Instruction: UpdateInterchainTokenMetadata");
+
+    ensure_signer_or_multisig_roles(
+        &crate::id(),
+        token_manager_account,
+        operator,
+        operator_roles_account,
+        Roles::OPERATOR,
+        accounts,
+    )?;
+
+    // A multisig operator can't pay, the payer then follows the gas payment ledger account, if
+    // any.
+    let gas_payments = usize::from(destination_chain.is_some() && gas_value > 0);
+    let payer = super::role_change_payer(
+        operator,
+        &mut gmp_accounts
+            .gas_payment_accounts
+            .get(gas_payments..)
+            .unwrap_or_default()
+            .iter(),
+    )?;
+
+    let update_metadata_accounts = UpdateMetadataAccounts {
+        system_account: gmp_accounts.system_program,
+        its_root_pda: gmp_accounts.its_root_account,
+        token_manager_pda: token_manager_account,
+        token_mint: mint,
+        token_program,
+        sysvar_instructions,
+        mpl_token_metadata_program,
+        mpl_token_metadata_account: metadata,
+    };
+    update_metadata_accounts.validate()?;
+    update_metadata(
+        payer,
+        &update_metadata_accounts,
+        &token_id,
+        name.clone(),
+        symbol.clone(),
+        Some(uri),
+        None,
+    )?;
+
+    if let Some(destination_chain) = destination_chain {
+        if TokenManager::load(token_manager_account)?
+            .origin_chain
+            .is_some()
+        {
+            msg!("Only the metadata of tokens deployed on Solana can be propagated");
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        let decimals = StateWithExtensions::<Mint>::unpack(&mint.try_borrow_data()?)?
+            .base
+            .decimals;
+        let message = GMPPayload::RegisterTokenMetadataUpdate(RegisterTokenMetadataUpdate {
+            selector: RegisterTokenMetadataUpdate::MESSAGE_TYPE_ID
+                .try_into()
+                .map_err(|_err| ProgramError::ArithmeticOverflow)?,
+            token_address: mint.key.to_bytes().into(),
+            decimals,
+            token_id: token_id.into(),
+            name,
+            symbol,
+        });
+
+        gmp::process_outbound(
+            payer,
+            &gmp_accounts,
+            &message,
+            destination_chain,
+            gas_value,
+            signing_pda_bump,
+            None,
+            true,
+        )?;
+    }
+
+    Ok(())
+}
+
+pub(crate) fn process_inbound_metadata_update<'a>(
+    payer: &'a AccountInfo<'a>,
+    accounts: &'a [AccountInfo<'a>],
+    payload: RegisterTokenMetadataUpdate,
+    source_chain: &str,
+) -> ProgramResult {
+    msg!("Instruction: InboundMetadataUpdate");
+    let accounts_iter = &mut accounts.iter();
+    let system_account = next_account_info(accounts_iter)?;
+    let its_root_pda = next_account_info(accounts_iter)?;
+    let token_manager_pda = next_account_info(accounts_iter)?;
+    let token_mint = next_account_info(accounts_iter)?;
+    let _token_manager_ata = next_account_info(accounts_iter)?;
+    let token_program = next_account_info(accounts_iter)?;
+
+    // The remaining common ITS accounts (ATA program, ITS roles, role index and rent sysvar) aren't
+    // needed to update the metadata.
+    let accounts_iter = &mut accounts_iter.skip(4);
+    let update_metadata_accounts = UpdateMetadataAccounts {
+        system_account,
+        its_root_pda,
+        token_manager_pda,
+        token_mint,
+        token_program,
+        sysvar_instructions: next_account_info(accounts_iter)?,
+        mpl_token_metadata_program: next_account_info(accounts_iter)?,
+        mpl_token_metadata_account: next_account_info(accounts_iter)?,
+    };
+    update_metadata_accounts.validate()?;
+
+    // The URI isn't part of the message, so it's kept as is.
+    update_metadata(
+        payer,
+        &update_metadata_accounts,
+        &payload.token_id.0,
+        payload.name,
+        payload.symbol,
+        None,
+        Some(source_chain),
+    )
+}
+
+/// Updates the metadata of a native interchain token, locally when
+/// `maybe_source_chain` is `None` or on behalf of the chain the token was
+/// deployed from otherwise.
+fn update_metadata<'a>(
+    payer: &'a AccountInfo<'a>,
+    accounts: &UpdateMetadataAccounts<'a>,
+    token_id: &[u8; 32],
+    name: String,
+    symbol: String,
+    maybe_uri: Option<String>,
+    maybe_source_chain: Option<&str>,
+) -> ProgramResult {
+    let its_root_pda_bump = InterchainTokenService::load(accounts.its_root_pda)?.bump;
+    assert_valid_its_root_pda(accounts.its_root_pda, its_root_pda_bump)?;
+
+    let token_manager = TokenManager::load(accounts.token_manager_pda)?;
+    assert_valid_token_manager_pda(
+        accounts.token_manager_pda,
+        accounts.its_root_pda.key,
+        token_id,
+        token_manager.bump,
+    )?;
+
+    if token_manager.ty != token_manager::Type::NativeInterchainToken {
+        msg!("Only the metadata of native interchain tokens can be updated");
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    if token_manager.token_address.ne(accounts.token_mint.key) {
+        msg!("Invalid mint account provided");
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    if let Some(source_chain) = maybe_source_chain {
+        if token_manager.origin_chain.as_deref() != Some(source_chain) {
+            msg!("Metadata updates are only accepted from the chain the token was deployed from");
+            return Err(ProgramError::InvalidArgument);
+        }
+    }
+
+    let signers_seeds: &[&[u8]] = &[
+        seed_prefixes::TOKEN_MANAGER_SEED,
+        accounts.its_root_pda.key.as_ref(),
+        token_id,
+        &[token_manager.bump],
+    ];
+
+    if let Some(current_metadata) = token_2022_metadata(accounts.token_mint)? {
+        let uri = maybe_uri.unwrap_or_else(|| current_metadata.uri.clone());
+        update_token_2022_metadata(
+            payer,
+            accounts,
+            current_metadata,
+            [
+                (Field::Name, name.clone()),
+                (Field::Symbol, symbol.clone()),
+                (Field::Uri, uri.clone()),
+            ],
+            signers_seeds,
+        )?;
+
+        event::InterchainTokenMetadataUpdated {
+            token_id: *token_id,
+            name,
+            symbol,
+            uri,
+        }
+        .emit();
+
+        return Ok(());
+    }
+
+    let current_metadata =
+        Metadata::from_bytes(&accounts.mpl_token_metadata_account.try_borrow_data()?)?;
+    if current_metadata.mint != *accounts.token_mint.key {
+        msg!("The metadata and mint accounts passed don't match");
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    // Tokens deployed before metadata updates were supported have immutable
+    // Metaplex metadata.
+    if !current_metadata.is_mutable {
+        msg!("The metadata of the token is immutable");
+        return Err(ProgramError::Immutable);
+    }
+
+    let uri = maybe_uri.unwrap_or_else(|| current_metadata.uri.trim_end_matches('\0').to_owned());
+
+    UpdateV1CpiBuilder::new(accounts.mpl_token_metadata_program)
+        .authority(accounts.token_manager_pda)
+        .mint(accounts.token_mint)
+        .metadata(accounts.mpl_token_metadata_account)
+        .payer(payer)
+        .system_program(accounts.system_account)
+        .sysvar_instructions(accounts.sysvar_instructions)
+        .data(Data {
+            name: name.clone(),
+            symbol: symbol.clone(),
+            uri: uri.clone(),
+            seller_fee_basis_points: current_metadata.seller_fee_basis_points,
+            creators: current_metadata.creators,
+        })
+        .invoke_signed(&[signers_seeds])?;
+
+    event::InterchainTokenMetadataUpdated {
+        token_id: *token_id,
+        name,
+        symbol,
+        uri,
+    }
+    .emit();
+
+    Ok(())
+}
+
+fn update_token_2022_metadata<'a>(
+    payer: &'a AccountInfo<'a>,
+    accounts: &UpdateMetadataAccounts<'a>,
+    current_metadata: TokenMetadata,
+    fields: [(Field, String); 3],
+    signers_seeds: &[&[u8]],
+) -> ProgramResult {
+    let current_len = current_metadata.tlv_size_of()?;
+    let mut updated_metadata = current_metadata;
+    for (field, value) in fields.clone() {
+        updated_metadata.update(field, value);
+    }
+
+    let new_mint_len = accounts
+        .token_mint
+        .data_len()
+        .saturating_sub(current_len)
+        .checked_add(updated_metadata.tlv_size_of()?)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    fund_rent_exemption(
+        payer,
+        accounts.token_mint,
+        accounts.system_account,
+        new_mint_len,
+    )?;
+
+    for (field, value) in fields {
+        invoke_signed(
+            &spl_token_metadata_interface::instruction::update_field(
+                accounts.token_program.key,
+                accounts.token_mint.key,
+                accounts.token_manager_pda.key,
+                field,
+                value,
+            ),
+            &[
+                accounts.token_mint.clone(),
+                accounts.token_manager_pda.clone(),
+                accounts.token_program.clone(),
+            ],
+            &[signers_seeds],
+        )?;
+    }
+
+    Ok(())
+}
+
 pub(crate) fn approve_deploy_remote_interchain_token(
     accounts: &[AccountInfo<'_>],
     deployer: Pubkey,
     salt: [u8; 32],
     destination_chain: String,
     destination_minter: Vec<u8>,
+    expires_at: Option<i64>,
 ) -> ProgramResult {
     let accounts_iter = &mut accounts.iter();
 
@@ -602,8 +1277,14 @@ pub(crate) fn approve_deploy_remote_interchain_token(
     let approval = DeployApproval {
         approved_destination_minter: solana_program::keccak::hash(&destination_minter).to_bytes(),
         bump,
+        expires_at,
     };
 
+    if approval.is_expired(Clock::get()?.unix_timestamp) {
+        msg!("Trying to create an approval that is already expired");
+        return Err(ProgramError::InvalidArgument);
+    }
+
     approval.init(
         &crate::id(),
         system_account,
@@ -624,6 +1305,7 @@ pub(crate) fn approve_deploy_remote_interchain_token(
         token_id,
         destination_chain,
         destination_minter,
+        expires_at,
     }
     .emit();
 
@@ -691,20 +1373,108 @@ pub(crate) fn use_deploy_approval(
         return Err(ProgramError::InvalidArgument);
     }
 
+    if approval.is_expired(Clock::get()?.unix_timestamp) {
+        msg!("Deploy approval has expired");
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    event::RemoteInterchainTokenApprovalUsed {
+        minter: *minter.key,
+        token_id: *token_id,
+        destination_chain: destination_chain.to_owned(),
+    }
+    .emit();
+
+    program_utils::pda::close_pda(minter, deploy_approval_account)
+}
+
+pub(crate) fn process_close_expired_deploy_approval(
+    accounts: &[AccountInfo<'_>],
+    token_id: [u8; 32],
+    destination_chain: String,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let minter = next_account_info(accounts_iter)?;
+    let deploy_approval_account = next_account_info(accounts_iter)?;
+
+    msg!("Instruction: CloseExpiredDeployApproval");
+
+    deploy_approval_account.check_initialized_pda_without_deserialization(&crate::id())?;
+    let approval = DeployApproval::load(deploy_approval_account)?;
+
+    assert_valid_deploy_approval_pda(
+        deploy_approval_account,
+        minter.key,
+        &token_id,
+        &destination_chain,
+        approval.bump,
+    )?;
+
+    if !approval.is_expired(Clock::get()?.unix_timestamp) {
+        msg!("Deploy approval hasn't expired");
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    event::RemoteInterchainTokenApprovalExpired {
+        minter: *minter.key,
+        token_id,
+        destination_chain,
+    }
+    .emit();
+
     program_utils::pda::close_pda(minter, deploy_approval_account)
 }
 
+pub(crate) fn process_grant_minter<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    expires_at: Option<i64>,
+    max_uses: Option<u64>,
+) -> ProgramResult {
+    msg!("Instruction: GrantInterchainTokenMinter");
+
+    let accounts_iter = &mut accounts.iter();
+    let system_account = next_account_info(accounts_iter)?;
+    let authority = next_account_info(accounts_iter)?;
+    let authority_roles_account = next_account_info(accounts_iter)?;
+    let resource = next_account_info(accounts_iter)?;
+    let destination_user_account = next_account_info(accounts_iter)?;
+    let destination_roles_account = next_account_info(accounts_iter)?;
+    let index_account = next_account_info(accounts_iter)?;
+    let payer = super::role_change_payer(authority, accounts_iter)?;
+
+    let role_management_accounts = RoleAddAccounts {
+        system_account,
+        payer,
+        authority,
+        authority_roles_account,
+        signers: accounts,
+        resource,
+        destination_user_account,
+        destination_roles_account,
+        index_account,
+    };
+
+    role_management::processor::grant(
+        &crate::id(),
+        role_management_accounts,
+        RoleGrant::new(Roles::MINTER, expires_at, max_uses),
+        Roles::MINTER,
+    )
+}
+
 pub(crate) fn process_transfer_mintership<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
     msg!("Instruction: TransferInterchainTokenMintership");
 
     let accounts_iter = &mut accounts.iter();
     let its_config_account = next_account_info(accounts_iter)?;
     let system_account = next_account_info(accounts_iter)?;
-    let payer = next_account_info(accounts_iter)?;
-    let payer_roles_account = next_account_info(accounts_iter)?;
+    let authority = next_account_info(accounts_iter)?;
+    let authority_roles_account = next_account_info(accounts_iter)?;
     let token_manager_account = next_account_info(accounts_iter)?;
     let destination_user_account = next_account_info(accounts_iter)?;
     let destination_roles_account = next_account_info(accounts_iter)?;
+    let index_account = next_account_info(accounts_iter)?;
+    let payer = super::role_change_payer(authority, accounts_iter)?;
 
     let its_config = InterchainTokenService::load(its_config_account)?;
     let token_manager = TokenManager::load(token_manager_account)?;
@@ -720,19 +1490,25 @@ pub(crate) fn process_transfer_mintership<'a>(accounts: &'a [AccountInfo<'a>]) -
     let role_add_accounts = RoleAddAccounts {
         system_account,
         payer,
-        payer_roles_account,
+        authority,
+        authority_roles_account,
+        signers: accounts,
         resource: token_manager_account,
         destination_user_account,
         destination_roles_account,
+        index_account,
     };
 
     let role_remove_accounts = RoleRemoveAccounts {
         system_account,
         payer,
-        payer_roles_account,
+        authority,
+        authority_roles_account,
+        signers: accounts,
         resource: token_manager_account,
-        origin_user_account: payer,
-        origin_roles_account: payer_roles_account,
+        origin_user_account: authority,
+        origin_roles_account: authority_roles_account,
+        index_account,
     };
 
     role_management::processor::add(
@@ -750,18 +1526,22 @@ pub(crate) fn process_transfer_mintership<'a>(accounts: &'a [AccountInfo<'a>]) -
     )
 }
 
-pub(crate) fn process_propose_mintership<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+pub(crate) fn process_propose_mintership<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    expires_at: Option<i64>,
+) -> ProgramResult {
     msg!("Instruction: ProposeInterchainTokenMintership");
 
     let accounts_iter = &mut accounts.iter();
     let its_config_account = next_account_info(accounts_iter)?;
     let system_account = next_account_info(accounts_iter)?;
-    let payer = next_account_info(accounts_iter)?;
-    let payer_roles_account = next_account_info(accounts_iter)?;
+    let authority = next_account_info(accounts_iter)?;
+    let authority_roles_account = next_account_info(accounts_iter)?;
     let token_manager_account = next_account_info(accounts_iter)?;
     let destination_user_account = next_account_info(accounts_iter)?;
     let destination_roles_account = next_account_info(accounts_iter)?;
     let proposal_account = next_account_info(accounts_iter)?;
+    let payer = super::role_change_payer(authority, accounts_iter)?;
 
     let its_config = InterchainTokenService::load(its_config_account)?;
     let token_manager = TokenManager::load(token_manager_account)?;
@@ -774,15 +1554,17 @@ pub(crate) fn process_propose_mintership<'a>(accounts: &'a [AccountInfo<'a>]) ->
         token_manager.bump,
     )?;
 
-    let role_management_accounts = RoleTransferWithProposalAccounts {
+    let role_management_accounts = RoleProposeAccounts {
         system_account,
         payer,
-        payer_roles_account,
+        authority,
+        authority_roles_account,
+        signers: accounts,
         resource: token_manager_account,
         destination_user_account,
         destination_roles_account,
-        origin_user_account: payer,
-        origin_roles_account: payer_roles_account,
+        origin_user_account: authority,
+        origin_roles_account: authority_roles_account,
         proposal_account,
     };
 
@@ -791,21 +1573,59 @@ pub(crate) fn process_propose_mintership<'a>(accounts: &'a [AccountInfo<'a>]) ->
         role_management_accounts,
         Roles::MINTER,
         Roles::MINTER,
+        expires_at,
     )
 }
 
+pub(crate) fn process_cancel_mintership_proposal<'a>(
+    accounts: &'a [AccountInfo<'a>],
+) -> ProgramResult {
+    msg!("Instruction: CancelInterchainTokenMintershipProposal");
+
+    let accounts_iter = &mut accounts.iter();
+    let its_config_account = next_account_info(accounts_iter)?;
+    let payer = next_account_info(accounts_iter)?;
+    let token_manager_account = next_account_info(accounts_iter)?;
+    let origin_user_account = next_account_info(accounts_iter)?;
+    let destination_user_account = next_account_info(accounts_iter)?;
+    let proposal_account = next_account_info(accounts_iter)?;
+
+    let its_config = InterchainTokenService::load(its_config_account)?;
+    assert_valid_its_root_pda(its_config_account, its_config.bump)?;
+    let token_manager = TokenManager::load(token_manager_account)?;
+    assert_valid_token_manager_pda(
+        token_manager_account,
+        its_config_account.key,
+        &token_manager.token_id,
+        token_manager.bump,
+    )?;
+
+    let role_management_accounts = RoleCancelProposalAccounts {
+        payer,
+        signers: accounts,
+        resource: token_manager_account,
+        origin_user_account,
+        destination_user_account,
+        proposal_account,
+    };
+
+    role_management::processor::cancel::<Roles>(&crate::id(), role_management_accounts)
+}
+
 pub(crate) fn process_accept_mintership<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
     msg!("Instruction: AcceptInterchainTokenMintership");
 
     let accounts_iter = &mut accounts.iter();
     let its_config_account = next_account_info(accounts_iter)?;
     let system_account = next_account_info(accounts_iter)?;
-    let payer = next_account_info(accounts_iter)?;
-    let payer_roles_account = next_account_info(accounts_iter)?;
+    let authority = next_account_info(accounts_iter)?;
+    let authority_roles_account = next_account_info(accounts_iter)?;
     let token_manager_account = next_account_info(accounts_iter)?;
     let origin_user_account = next_account_info(accounts_iter)?;
     let origin_roles_account = next_account_info(accounts_iter)?;
     let proposal_account = next_account_info(accounts_iter)?;
+    let index_account = next_account_info(accounts_iter)?;
+    let payer = super::role_change_payer(authority, accounts_iter)?;
 
     let its_config = InterchainTokenService::load(its_config_account)?;
     let token_manager = TokenManager::load(token_manager_account)?;
@@ -821,13 +1641,16 @@ pub(crate) fn process_accept_mintership<'a>(accounts: &'a [AccountInfo<'a>]) ->
     let role_management_accounts = RoleTransferWithProposalAccounts {
         system_account,
         payer,
-        payer_roles_account,
+        authority,
+        authority_roles_account,
+        signers: accounts,
         resource: token_manager_account,
-        destination_user_account: payer,
-        destination_roles_account: payer_roles_account,
+        destination_user_account: authority,
+        destination_roles_account: authority_roles_account,
         origin_user_account,
         origin_roles_account,
         proposal_account,
+        index_account,
     };
 
     role_management::processor::accept(
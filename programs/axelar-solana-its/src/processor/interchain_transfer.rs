@@ -24,8 +24,9 @@ use spl_token_2022::extension::{BaseStateWithExtensions, StateWithExtensions};
 use spl_token_2022::state::Mint;
 
 use crate::executable::{AxelarInterchainTokenExecutablePayload, AXELAR_INTERCHAIN_TOKEN_EXECUTE};
+use crate::instruction::BatchedTransfer;
 use crate::processor::token_manager as token_manager_processor;
-use crate::state::flow_limit::{self, FlowDirection, FlowSlot};
+use crate::state::flow_limit::{self, FlowDirection, FlowLimit, FlowSlot};
 use crate::state::token_manager::{self, TokenManager};
 use crate::state::InterchainTokenService;
 use crate::{
@@ -71,8 +72,10 @@ pub(crate) fn process_inbound_transfer<'a>(
     payload: &InterchainTransfer,
     source_chain: String,
 ) -> ProgramResult {
-    let parsed_accounts =
-        GiveTokenAccounts::from_account_info_slice(accounts, &(payer, message_payload_account))?;
+    let parsed_accounts = GiveTokenAccounts::from_account_info_slice(
+        accounts,
+        &(payer, message_payload_account, !payload.data.is_empty()),
+    )?;
     let token_manager = TokenManager::load(parsed_accounts.token_manager_pda)?;
     assert_valid_token_manager_pda(
         parsed_accounts.token_manager_pda,
@@ -229,10 +232,10 @@ pub(crate) fn process_outbound_transfer<'a>(
     data: Option<Vec<u8>>,
     payload_hash: Option<[u8; 32]>,
 ) -> ProgramResult {
-    const GMP_ACCOUNTS_IDX: usize = 7;
+    const GMP_ACCOUNTS_IDX: usize = 8;
     let take_token_accounts = TakeTokenAccounts::from_account_info_slice(accounts, &())?;
     let (_other, outbound_message_accounts) = accounts.split_at(GMP_ACCOUNTS_IDX);
-    let gmp_accounts = GmpAccounts::from_account_info_slice(outbound_message_accounts, &())?;
+    let gmp_accounts = GmpAccounts::from_account_info_slice(outbound_message_accounts, &true)?;
 
     msg!("Instruction: OutboundTransfer");
     let token_manager = TokenManager::load(take_token_accounts.token_manager_pda)?;
@@ -287,6 +290,93 @@ pub(crate) fn process_outbound_transfer<'a>(
     )
 }
 
+pub(crate) fn process_outbound_transfer_batch<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    token_id: [u8; 32],
+    destination_chain: String,
+    transfers: Vec<BatchedTransfer>,
+    gas_value: u64,
+    signing_pda_bump: u8,
+) -> ProgramResult {
+    const GMP_ACCOUNTS_IDX: usize = 8;
+    let take_token_accounts = TakeTokenAccounts::from_account_info_slice(accounts, &())?;
+    let (_other, outbound_message_accounts) = accounts.split_at(GMP_ACCOUNTS_IDX);
+    let gmp_accounts = GmpAccounts::from_account_info_slice(outbound_message_accounts, &true)?;
+
+    msg!("Instruction: OutboundTransferBatch");
+    if transfers.is_empty() {
+        msg!("The batch doesn't contain any transfer");
+        return Err(ProgramError::InvalidInstructionData);
+    }
+
+    let token_manager = TokenManager::load(take_token_accounts.token_manager_pda)?;
+    assert_valid_token_manager_pda(
+        take_token_accounts.token_manager_pda,
+        take_token_accounts.its_root_pda.key,
+        &token_manager.token_id,
+        token_manager.bump,
+    )?;
+
+    let total_amount = transfers
+        .iter()
+        .try_fold(0_u64, |total, transfer| total.checked_add(transfer.amount))
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    let total_amount_minus_fees = take_token(&take_token_accounts, &token_manager, total_amount)?;
+
+    let mut payloads = Vec::with_capacity(transfers.len());
+    for transfer in transfers {
+        // Transfer fees are charged once on the whole batch, each transfer bears its share.
+        let amount = if total_amount_minus_fees == total_amount {
+            transfer.amount
+        } else {
+            u128::from(transfer.amount)
+                .checked_mul(total_amount_minus_fees.into())
+                .and_then(|amount| amount.checked_div(total_amount.into()))
+                .and_then(|amount| u64::try_from(amount).ok())
+                .ok_or(ProgramError::ArithmeticOverflow)?
+        };
+
+        event::InterchainTransfer {
+            token_id,
+            source_address: *take_token_accounts.token_manager_ata.key,
+            destination_chain: destination_chain.clone(),
+            destination_address: transfer.destination_address.clone(),
+            amount,
+            data_hash: [0; 32],
+        }
+        .emit();
+
+        payloads.push(GMPPayload::InterchainTransfer(InterchainTransfer {
+            selector: InterchainTransfer::MESSAGE_TYPE_ID
+                .try_into()
+                .map_err(|_err| ProgramError::ArithmeticOverflow)?,
+            token_id: token_id.into(),
+            source_address: take_token_accounts.token_mint.key.to_bytes().into(),
+            destination_address: transfer.destination_address.into(),
+            amount: alloy_primitives::U256::from(amount),
+            data: alloy_primitives::Bytes::new(),
+        }));
+    }
+
+    let payload_hashes = gmp::process_outbound_batch(
+        take_token_accounts.payer,
+        &gmp_accounts,
+        &payloads,
+        &destination_chain,
+        gas_value,
+        signing_pda_bump,
+    )?;
+
+    event::InterchainTransferBatchSent {
+        token_id,
+        destination_chain,
+        payload_hashes: payload_hashes.concat(),
+    }
+    .emit();
+
+    Ok(())
+}
+
 pub(crate) fn take_token(
     accounts: &TakeTokenAccounts<'_>,
     token_manager: &TokenManager,
@@ -330,16 +420,33 @@ fn give_token(
 
 fn track_token_flow(
     accounts: &FlowTrackingAccounts<'_>,
-    flow_limit: u64,
+    flow_limit: &FlowLimit,
     amount: u64,
     direction: FlowDirection,
 ) -> ProgramResult {
-    if flow_limit == 0 {
+    let limit = flow_limit.limit(direction);
+    if limit == 0 {
         return Ok(());
     }
 
-    let current_flow_epoch = flow_limit::current_flow_epoch()?;
-    if let Ok(mut flow_slot) = FlowSlot::load(accounts.flow_slot_pda) {
+    let (current_flow_epoch, elapsed) = flow_limit::current_flow_epoch(flow_limit.epoch_duration)?;
+    let carried_flow = if flow_limit.sliding_window {
+        previous_epoch_carried_flow(
+            accounts,
+            current_flow_epoch,
+            elapsed,
+            flow_limit.epoch_duration,
+            direction,
+        )?
+    } else {
+        0
+    };
+
+    if let Ok(mut flow_slot) = FlowSlot::load_for(
+        accounts.flow_slot_pda,
+        accounts.token_manager_pda.key,
+        current_flow_epoch,
+    ) {
         assert_valid_flow_slot_pda(
             accounts.flow_slot_pda,
             accounts.token_manager_pda.key,
@@ -347,7 +454,12 @@ fn track_token_flow(
             flow_slot.bump,
         )?;
 
-        flow_slot.add_flow(flow_limit, amount, direction)?;
+        // A slot left from epochs of another duration tracks another window
+        if !flow_slot.matches(flow_limit) {
+            flow_slot.reset(flow_limit);
+        }
+
+        flow_slot.add_flow(limit, amount, direction, carried_flow)?;
         flow_slot.store(
             accounts.payer,
             accounts.flow_slot_pda,
@@ -362,8 +474,13 @@ fn track_token_flow(
             return Err(ProgramError::InvalidArgument);
         }
 
-        let mut flow_slot = FlowSlot::new(flow_slot_pda_bump);
-        flow_slot.add_flow(flow_limit, amount, direction)?;
+        let mut flow_slot = FlowSlot::new(
+            *accounts.token_manager_pda.key,
+            current_flow_epoch,
+            flow_limit.epoch_duration,
+            flow_slot_pda_bump,
+        );
+        flow_slot.add_flow(limit, amount, direction, carried_flow)?;
         flow_slot.init(
             &crate::id(),
             accounts.system_account,
@@ -381,6 +498,48 @@ fn track_token_flow(
     Ok(())
 }
 
+fn previous_epoch_carried_flow(
+    accounts: &FlowTrackingAccounts<'_>,
+    current_flow_epoch: u64,
+    elapsed: u64,
+    epoch_duration: u64,
+    direction: FlowDirection,
+) -> Result<u64, ProgramError> {
+    let previous_flow_epoch = current_flow_epoch
+        .checked_sub(1)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    let Ok(previous_flow_slot) = FlowSlot::load_for(
+        accounts.previous_flow_slot_pda,
+        accounts.token_manager_pda.key,
+        previous_flow_epoch,
+    ) else {
+        let (previous_flow_slot_pda, _) =
+            crate::find_flow_slot_pda(accounts.token_manager_pda.key, previous_flow_epoch);
+
+        if previous_flow_slot_pda.ne(accounts.previous_flow_slot_pda.key) {
+            msg!("Invalid previous flow slot PDA provided");
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        return Ok(0);
+    };
+
+    assert_valid_flow_slot_pda(
+        accounts.previous_flow_slot_pda,
+        accounts.token_manager_pda.key,
+        previous_flow_epoch,
+        previous_flow_slot.bump,
+    )?;
+
+    // A slot left from epochs of another duration isn't the previous epoch
+    if previous_flow_slot.epoch_duration != epoch_duration {
+        return Ok(0);
+    }
+
+    previous_flow_slot.carried_flow(direction, elapsed, epoch_duration)
+}
+
 fn handle_give_token_transfer(
     accounts: &GiveTokenAccounts<'_>,
     token_manager: &TokenManager,
@@ -394,7 +553,7 @@ fn handle_give_token_transfer(
 
     track_token_flow(
         &accounts.into(),
-        token_manager.flow_limit,
+        &token_manager.flow_limit,
         amount,
         FlowDirection::In,
     )?;
@@ -448,7 +607,7 @@ fn handle_take_token_transfer(
 
     track_token_flow(
         &accounts.into(),
-        token_manager.flow_limit,
+        &token_manager.flow_limit,
         amount,
         FlowDirection::Out,
     )?;
@@ -520,6 +679,7 @@ const fn create_take_token_transfer_info<'a, 'b>(
         destination_ata: accounts.token_manager_ata,
         authority: accounts.payer,
         source_ata: accounts.source_account,
+        transfer_hook_accounts: accounts.transfer_hook_accounts,
         signers_seeds,
         amount,
         decimals,
@@ -540,6 +700,7 @@ fn create_give_token_transfer_info<'a, 'b>(
         destination_ata: accounts.program_ata.unwrap_or(accounts.destination_account),
         authority: accounts.token_manager_pda,
         source_ata: accounts.token_manager_ata,
+        transfer_hook_accounts: accounts.remaining_accounts,
         signers_seeds,
         amount,
         decimals,
@@ -556,6 +717,11 @@ fn mint_to<'a>(
     token_manager: &TokenManager,
     amount: u64,
 ) -> ProgramResult {
+    let supply = StateWithExtensions::<Mint>::unpack(&token_mint.try_borrow_data()?)?
+        .base
+        .supply;
+    token_manager.ensure_within_supply_cap(supply, amount)?;
+
     invoke_signed(
         &spl_token_2022::instruction::mint_to(
             token_program.key,
@@ -614,6 +780,7 @@ struct TransferInfo<'a, 'b> {
     destination_ata: &'b AccountInfo<'a>,
     authority: &'b AccountInfo<'a>,
     source_ata: &'b AccountInfo<'a>,
+    transfer_hook_accounts: &'b [AccountInfo<'a>],
     signers_seeds: &'b [&'b [u8]],
     amount: u64,
     decimals: u8,
@@ -621,50 +788,33 @@ struct TransferInfo<'a, 'b> {
 }
 
 fn transfer_to(info: &TransferInfo<'_, '_>) -> ProgramResult {
-    invoke_signed(
-        &spl_token_2022::instruction::transfer_checked(
-            info.token_program.key,
-            info.source_ata.key,
-            info.token_mint.key,
-            info.destination_ata.key,
-            info.authority.key,
-            &[],
-            info.amount,
-            info.decimals,
-        )?,
-        &[
-            info.token_mint.clone(),
-            info.source_ata.clone(),
-            info.authority.clone(),
-            info.destination_ata.clone(),
-        ],
+    // Resolves the accounts required by the transfer hook of the mint, if any.
+    spl_token_2022::onchain::invoke_transfer_checked(
+        info.token_program.key,
+        info.source_ata.clone(),
+        info.token_mint.clone(),
+        info.destination_ata.clone(),
+        info.authority.clone(),
+        info.transfer_hook_accounts,
+        info.amount,
+        info.decimals,
         &[info.signers_seeds],
-    )?;
-    Ok(())
+    )
 }
 
 fn transfer_with_fee_to(info: &TransferInfo<'_, '_>) -> ProgramResult {
-    invoke_signed(
-        &spl_token_2022::extension::transfer_fee::instruction::transfer_checked_with_fee(
-            info.token_program.key,
-            info.source_ata.key,
-            info.token_mint.key,
-            info.destination_ata.key,
-            info.authority.key,
-            &[],
-            info.amount,
-            info.decimals,
-            info.fee.ok_or(ProgramError::InvalidArgument)?,
-        )?,
-        &[
-            info.token_mint.clone(),
-            info.source_ata.clone(),
-            info.authority.clone(),
-            info.destination_ata.clone(),
-        ],
+    spl_token_2022::onchain::invoke_transfer_checked_with_fee(
+        info.token_program.key,
+        info.source_ata.clone(),
+        info.token_mint.clone(),
+        info.destination_ata.clone(),
+        info.authority.clone(),
+        info.transfer_hook_accounts,
+        info.amount,
+        info.decimals,
+        info.fee.ok_or(ProgramError::InvalidArgument)?,
         &[info.signers_seeds],
-    )?;
-    Ok(())
+    )
 }
 
 #[derive(Debug)]
@@ -676,8 +826,10 @@ pub(crate) struct TakeTokenAccounts<'a> {
     pub(crate) token_manager_ata: &'a AccountInfo<'a>,
     pub(crate) token_program: &'a AccountInfo<'a>,
     pub(crate) flow_slot_pda: &'a AccountInfo<'a>,
+    pub(crate) previous_flow_slot_pda: &'a AccountInfo<'a>,
     pub(crate) system_account: &'a AccountInfo<'a>,
     pub(crate) its_root_pda: &'a AccountInfo<'a>,
+    pub(crate) transfer_hook_accounts: &'a [AccountInfo<'a>],
 }
 
 impl Validate for TakeTokenAccounts<'_> {
@@ -703,6 +855,7 @@ impl<'a> FromAccountInfoSlice<'a> for TakeTokenAccounts<'a> {
             token_manager_ata: next_account_info(accounts_iter)?,
             token_program: next_account_info(accounts_iter)?,
             flow_slot_pda: next_account_info(accounts_iter)?,
+            previous_flow_slot_pda: next_account_info(accounts_iter)?,
             system_account: {
                 next_account_info(accounts_iter)?;
                 next_account_info(accounts_iter)?;
@@ -711,6 +864,9 @@ impl<'a> FromAccountInfoSlice<'a> for TakeTokenAccounts<'a> {
                 next_account_info(accounts_iter)?
             },
             its_root_pda: next_account_info(accounts_iter)?,
+            // The accounts required by the transfer hook of the mint, if any, are looked up by key
+            // among the remaining accounts.
+            transfer_hook_accounts: accounts_iter.as_slice(),
         })
     }
 }
@@ -727,12 +883,18 @@ struct GiveTokenAccounts<'a> {
     token_program: &'a AccountInfo<'a>,
     ata_program: &'a AccountInfo<'a>,
     _its_roles_pda: &'a AccountInfo<'a>,
+    _role_index_pda: &'a AccountInfo<'a>,
     rent_sysvar: &'a AccountInfo<'a>,
     destination_account: &'a AccountInfo<'a>,
     flow_slot_pda: &'a AccountInfo<'a>,
+    previous_flow_slot_pda: &'a AccountInfo<'a>,
     program_ata: Option<&'a AccountInfo<'a>>,
     mpl_token_metadata_program: Option<&'a AccountInfo<'a>>,
     mpl_token_metadata_account: Option<&'a AccountInfo<'a>>,
+    /// The accounts of the destination program, if the transfer carries data,
+    /// followed by the accounts required by the transfer hook of the mint, if
+    /// any.
+    remaining_accounts: &'a [AccountInfo<'a>],
 }
 
 impl Validate for GiveTokenAccounts<'_> {
@@ -748,17 +910,20 @@ impl Validate for GiveTokenAccounts<'_> {
 }
 
 impl<'a> FromAccountInfoSlice<'a> for GiveTokenAccounts<'a> {
-    type Context = (&'a AccountInfo<'a>, &'a AccountInfo<'a>);
+    /// The payer, the message payload PDA and whether the transfer carries data
+    /// to be executed by the destination program.
+    type Context = (&'a AccountInfo<'a>, &'a AccountInfo<'a>, bool);
 
     fn extract_accounts(
         accounts: &'a [AccountInfo<'a>],
-        payer_and_payload: &Self::Context,
+        context: &Self::Context,
     ) -> Result<Self, ProgramError> {
+        let (payer, message_payload_pda, has_data) = *context;
         let accounts_iter = &mut accounts.iter();
 
-        Ok(GiveTokenAccounts {
-            payer: payer_and_payload.0,
-            message_payload_pda: payer_and_payload.1,
+        let mut give_token_accounts = GiveTokenAccounts {
+            payer,
+            message_payload_pda,
             system_account: next_account_info(accounts_iter)?,
             its_root_pda: next_account_info(accounts_iter)?,
             token_manager_pda: next_account_info(accounts_iter)?,
@@ -767,13 +932,28 @@ impl<'a> FromAccountInfoSlice<'a> for GiveTokenAccounts<'a> {
             token_program: next_account_info(accounts_iter)?,
             ata_program: next_account_info(accounts_iter)?,
             _its_roles_pda: next_account_info(accounts_iter)?,
+            _role_index_pda: next_account_info(accounts_iter)?,
             rent_sysvar: next_account_info(accounts_iter)?,
             destination_account: next_account_info(accounts_iter)?,
             flow_slot_pda: next_account_info(accounts_iter)?,
-            program_ata: next_account_info(accounts_iter).ok(),
-            mpl_token_metadata_program: next_account_info(accounts_iter).ok(),
-            mpl_token_metadata_account: next_account_info(accounts_iter).ok(),
-        })
+            previous_flow_slot_pda: next_account_info(accounts_iter)?,
+            program_ata: None,
+            mpl_token_metadata_program: None,
+            mpl_token_metadata_account: None,
+            remaining_accounts: &[],
+        };
+
+        if has_data {
+            give_token_accounts.program_ata = Some(next_account_info(accounts_iter)?);
+            give_token_accounts.mpl_token_metadata_program =
+                Some(next_account_info(accounts_iter)?);
+            give_token_accounts.mpl_token_metadata_account =
+                Some(next_account_info(accounts_iter)?);
+        }
+
+        give_token_accounts.remaining_accounts = accounts_iter.as_slice();
+
+        Ok(give_token_accounts)
     }
 }
 
@@ -799,7 +979,7 @@ impl<'a> FromAccountInfoSlice<'a> for AxelarInterchainTokenExecutableAccounts<'a
     type Context = (GiveTokenAccounts<'a>, usize);
 
     fn extract_accounts(
-        accounts: &'a [AccountInfo<'a>],
+        _accounts: &'a [AccountInfo<'a>],
         context: &Self::Context,
     ) -> Result<Self, ProgramError>
     where
@@ -807,13 +987,12 @@ impl<'a> FromAccountInfoSlice<'a> for AxelarInterchainTokenExecutableAccounts<'a
     {
         let give_token_accounts = &context.0;
         let destination_accounts_len = context.1;
-        let destination_accounts_index = accounts
-            .len()
-            .checked_sub(destination_accounts_len)
-            .ok_or(ProgramError::ArithmeticOverflow)?;
 
-        let destination_program_accounts = accounts
-            .get(destination_accounts_index..)
+        // The accounts required by the transfer hook of the mint, if any, follow the accounts of
+        // the destination program.
+        let destination_program_accounts = give_token_accounts
+            .remaining_accounts
+            .get(..destination_accounts_len)
             .ok_or(ProgramError::NotEnoughAccountKeys)?;
 
         Ok(Self {
@@ -840,6 +1019,7 @@ struct FlowTrackingAccounts<'a> {
     payer: &'a AccountInfo<'a>,
     token_manager_pda: &'a AccountInfo<'a>,
     flow_slot_pda: &'a AccountInfo<'a>,
+    previous_flow_slot_pda: &'a AccountInfo<'a>,
 }
 
 impl<'a> From<&TakeTokenAccounts<'a>> for FlowTrackingAccounts<'a> {
@@ -849,6 +1029,7 @@ impl<'a> From<&TakeTokenAccounts<'a>> for FlowTrackingAccounts<'a> {
             payer: value.payer,
             token_manager_pda: value.token_manager_pda,
             flow_slot_pda: value.flow_slot_pda,
+            previous_flow_slot_pda: value.previous_flow_slot_pda,
         }
     }
 }
@@ -860,6 +1041,7 @@ impl<'a> From<&GiveTokenAccounts<'a>> for FlowTrackingAccounts<'a> {
             payer: value.payer,
             token_manager_pda: value.token_manager_pda,
             flow_slot_pda: value.flow_slot_pda,
+            previous_flow_slot_pda: value.previous_flow_slot_pda,
         }
     }
 }
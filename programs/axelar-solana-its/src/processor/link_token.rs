@@ -9,8 +9,8 @@ use solana_program::entrypoint::ProgramResult;
 use solana_program::msg;
 use solana_program::program::set_return_data;
 use solana_program::program_error::ProgramError;
-use solana_program::program_pack::Pack;
 use solana_program::pubkey::Pubkey;
+use spl_token_2022::extension::StateWithExtensions;
 use spl_token_2022::state::Mint;
 
 use crate::processor::gmp::GmpAccounts;
@@ -21,8 +21,8 @@ use crate::{
     FromAccountInfoSlice,
 };
 
-use super::gmp;
 use super::token_manager::{DeployTokenManagerAccounts, DeployTokenManagerInternal};
+use super::{gmp, interchain_token};
 
 pub(crate) fn process_inbound<'a>(
     payer: &'a AccountInfo<'a>,
@@ -84,7 +84,7 @@ pub(crate) fn process_outbound<'a>(
 
     let (link_token_accounts, outbound_message_accounts) =
         accounts.split_at(OUTBOUND_MESSAGE_ACCOUNTS_IDX);
-    let gmp_accounts = GmpAccounts::from_account_info_slice(outbound_message_accounts, &())?;
+    let gmp_accounts = GmpAccounts::from_account_info_slice(outbound_message_accounts, &true)?;
 
     let accounts_iter = &mut link_token_accounts.iter();
     let payer = next_account_info(accounts_iter)?;
@@ -160,21 +160,24 @@ pub(crate) fn register_token_metadata<'a>(
     let _token_program = next_account_info(accounts_iter)?;
 
     let (_other, outbound_message_accounts) = accounts.split_at(OUTBOUND_MESSAGE_ACCOUNTS_IDX);
-    let gmp_accounts = GmpAccounts::from_account_info_slice(outbound_message_accounts, &())?;
+    // Token metadata is always registered with the ITS Hub, no trusted chain account is needed.
+    let gmp_accounts = GmpAccounts::from_account_info_slice(outbound_message_accounts, &false)?;
     msg!("Instruction: RegisterTokenMetadata");
 
-    let mint = Mint::unpack(&mint_account.data.borrow())?;
+    let decimals = StateWithExtensions::<Mint>::unpack(&mint_account.try_borrow_data()?)?
+        .base
+        .decimals;
     let payload = GMPPayload::RegisterTokenMetadata(RegisterTokenMetadata {
         selector: RegisterTokenMetadata::MESSAGE_TYPE_ID
             .try_into()
             .map_err(|_err| ProgramError::ArithmeticOverflow)?,
         token_address: mint_account.key.to_bytes().into(),
-        decimals: mint.decimals,
+        decimals,
     });
 
     event::TokenMetadataRegistered {
         token_address: *mint_account.key,
-        decimals: mint.decimals,
+        decimals,
     }
     .emit();
 
@@ -247,19 +250,23 @@ fn register_token<'a>(
     let its_config = InterchainTokenService::load(parsed_accounts.its_root_pda)?;
     assert_its_not_paused(&its_config)?;
 
-    match Metadata::from_bytes(&metadata_account.try_borrow_data()?) {
-        Ok(metadata) => {
-            if metadata.mint.ne(parsed_accounts.token_mint.key) {
-                msg!("Metadata and mint mismatch");
-                return Err(ProgramError::InvalidAccountData);
+    // Tokens can either carry their metadata through the Token-2022 metadata extension or
+    // through a Metaplex metadata account.
+    if interchain_token::token_2022_metadata(parsed_accounts.token_mint)?.is_none() {
+        match Metadata::from_bytes(&metadata_account.try_borrow_data()?) {
+            Ok(metadata) => {
+                if metadata.mint.ne(parsed_accounts.token_mint.key) {
+                    msg!("Metadata and mint mismatch");
+                    return Err(ProgramError::InvalidAccountData);
+                }
             }
-        }
-        Err(error) => {
-            msg!("Failed to fetch required Metaplex metadata for the token");
+            Err(error) => {
+                msg!("Failed to fetch required token metadata");
 
-            return Err(error.into());
-        }
-    };
+                return Err(error.into());
+            }
+        };
+    }
 
     let (token_manager_type, operator, deploy_salt) = match *registration {
         TokenRegistration::Canonical => (
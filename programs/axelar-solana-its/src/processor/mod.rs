@@ -7,20 +7,28 @@ use program_utils::{
     validate_system_account_key,
 };
 use role_management::processor::{
-    ensure_signer_roles, ensure_upgrade_authority, RoleAddAccounts, RoleRemoveAccounts,
+    ensure_signer_or_multisig_roles, ensure_signer_roles, ensure_upgrade_authority,
+    MultisigCreateAccounts, RoleAddAccounts, RoleCancelProposalAccounts, RoleCleanupAccounts,
+    RoleProposeAccounts, RoleRemoveAccounts, RoleSyncIndexAccounts,
     RoleTransferWithProposalAccounts,
 };
 use role_management::state::UserRoles;
 use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::clock::Clock;
 use solana_program::entrypoint::ProgramResult;
 use solana_program::program_error::ProgramError;
-use solana_program::pubkey::Pubkey;
+use solana_program::pubkey::{Pubkey, MAX_SEED_LEN};
+use solana_program::sysvar::Sysvar;
 use solana_program::{msg, system_program};
 use token_manager::{handover_mint_authority, SetFlowLimitAccounts};
 
 use crate::instruction::InterchainTokenServiceInstruction;
-use crate::state::InterchainTokenService;
-use crate::{assert_valid_its_root_pda, check_program_account, event, FromAccountInfoSlice, Roles};
+use crate::state::trusted_chain::{DecimalsPolicy, TrustedChain};
+use crate::state::{InterchainTokenService, LegacyInterchainTokenService};
+use crate::{
+    assert_valid_its_root_pda, assert_valid_trusted_chain_pda, check_program_account, event,
+    seed_prefixes, FromAccountInfoSlice, Roles,
+};
 
 pub(crate) mod gmp;
 pub(crate) mod interchain_token;
@@ -60,9 +68,38 @@ pub fn process_instruction<'a>(
         InterchainTokenServiceInstruction::ItsGmpPayload { message } => {
             gmp::process_inbound(accounts, message)
         }
-        InterchainTokenServiceInstruction::SetTrustedChain { chain_name } => {
-            process_set_trusted_chain(accounts, chain_name)
+        InterchainTokenServiceInstruction::SetTrustedChainPauseStatus { chain_name, paused } => {
+            process_set_trusted_chain_pause_status(accounts, chain_name, paused)
+        }
+        InterchainTokenServiceInstruction::MigrateTrustedChains { trusted_chains } => {
+            process_migrate_trusted_chains(accounts, trusted_chains)
         }
+        InterchainTokenServiceInstruction::CloseFlowSlot { epoch } => {
+            token_manager::process_close_flow_slot(accounts, epoch)
+        }
+        InterchainTokenServiceInstruction::UpdateInterchainTokenMetadata {
+            token_id,
+            name,
+            symbol,
+            uri,
+            destination_chain,
+            gas_value,
+            signing_pda_bump,
+        } => interchain_token::process_update_metadata(
+            accounts,
+            token_id,
+            name,
+            symbol,
+            uri,
+            destination_chain,
+            gas_value,
+            signing_pda_bump,
+        ),
+        InterchainTokenServiceInstruction::SetTrustedChain {
+            chain_name,
+            its_address,
+            decimals_policy,
+        } => process_set_trusted_chain(accounts, chain_name, its_address, decimals_policy),
         InterchainTokenServiceInstruction::RemoveTrustedChain { chain_name } => {
             process_remove_trusted_chain(accounts, &chain_name)
         }
@@ -71,12 +108,14 @@ pub fn process_instruction<'a>(
             salt,
             destination_chain,
             destination_minter,
+            expires_at,
         } => interchain_token::approve_deploy_remote_interchain_token(
             accounts,
             deployer,
             salt,
             destination_chain,
             destination_minter,
+            expires_at,
         ),
         InterchainTokenServiceInstruction::RevokeDeployRemoteInterchainToken {
             deployer,
@@ -107,9 +146,16 @@ pub fn process_instruction<'a>(
             symbol,
             decimals,
             initial_supply,
-        } => {
-            interchain_token::process_deploy(accounts, salt, name, symbol, decimals, initial_supply)
-        }
+            metadata_standard,
+        } => interchain_token::process_deploy(
+            accounts,
+            salt,
+            name,
+            symbol,
+            decimals,
+            initial_supply,
+            metadata_standard,
+        ),
         InterchainTokenServiceInstruction::DeployRemoteInterchainToken {
             salt,
             destination_chain,
@@ -187,22 +233,24 @@ pub fn process_instruction<'a>(
                 SetFlowLimitAccounts::from_account_info_slice(accounts, &())?;
 
             msg!("Instruction: SetFlowLimit");
-            ensure_signer_roles(
+            ensure_signer_or_multisig_roles(
                 &crate::id(),
                 instruction_accounts.its_root_pda,
                 instruction_accounts.flow_limiter,
                 instruction_accounts.its_user_roles_pda,
                 Roles::OPERATOR,
+                accounts,
             )?;
 
+            let operator = instruction_accounts.flow_limiter.key;
             instruction_accounts.flow_limiter = instruction_accounts.its_root_pda;
-            token_manager::set_flow_limit(&instruction_accounts, flow_limit)
+            token_manager::set_flow_limit(&instruction_accounts, operator, flow_limit)
         }
         InterchainTokenServiceInstruction::TransferOperatorship => {
             process_transfer_operatorship(accounts)
         }
-        InterchainTokenServiceInstruction::ProposeOperatorship => {
-            process_propose_operatorship(accounts)
+        InterchainTokenServiceInstruction::ProposeOperatorship { expires_at } => {
+            process_propose_operatorship(accounts, expires_at)
         }
         InterchainTokenServiceInstruction::AcceptOperatorship => {
             process_accept_operatorship(accounts)
@@ -219,8 +267,8 @@ pub fn process_instruction<'a>(
         InterchainTokenServiceInstruction::TransferTokenManagerOperatorship => {
             token_manager::process_transfer_operatorship(accounts)
         }
-        InterchainTokenServiceInstruction::ProposeTokenManagerOperatorship => {
-            token_manager::process_propose_operatorship(accounts)
+        InterchainTokenServiceInstruction::ProposeTokenManagerOperatorship { expires_at } => {
+            token_manager::process_propose_operatorship(accounts, expires_at)
         }
         InterchainTokenServiceInstruction::AcceptTokenManagerOperatorship => {
             token_manager::process_accept_operatorship(accounts)
@@ -234,8 +282,8 @@ pub fn process_instruction<'a>(
         InterchainTokenServiceInstruction::TransferInterchainTokenMintership => {
             interchain_token::process_transfer_mintership(accounts)
         }
-        InterchainTokenServiceInstruction::ProposeInterchainTokenMintership => {
-            interchain_token::process_propose_mintership(accounts)
+        InterchainTokenServiceInstruction::ProposeInterchainTokenMintership { expires_at } => {
+            interchain_token::process_propose_mintership(accounts, expires_at)
         }
         InterchainTokenServiceInstruction::AcceptInterchainTokenMintership => {
             interchain_token::process_accept_mintership(accounts)
@@ -278,12 +326,65 @@ pub fn process_instruction<'a>(
             None,
             Some(payload_hash),
         ),
+        InterchainTokenServiceInstruction::InterchainTransferBatch {
+            token_id,
+            destination_chain,
+            transfers,
+            gas_value,
+            signing_pda_bump,
+        } => interchain_transfer::process_outbound_transfer_batch(
+            accounts,
+            token_id,
+            destination_chain,
+            transfers,
+            gas_value,
+            signing_pda_bump,
+        ),
+        InterchainTokenServiceInstruction::SetTokenManagerSupplyCap { supply_cap } => {
+            token_manager::process_set_supply_cap(accounts, supply_cap)
+        }
+        InterchainTokenServiceInstruction::SetInterchainTokenMinterAllowance {
+            allowance,
+            refill_period,
+        } => interchain_token::process_set_minter_allowance(accounts, allowance, refill_period),
+        InterchainTokenServiceInstruction::GrantTokenManagerFlowLimiter {
+            expires_at,
+            max_uses,
+        } => token_manager::process_grant_flow_limiter(accounts, expires_at, max_uses),
+        InterchainTokenServiceInstruction::GrantInterchainTokenMinter {
+            expires_at,
+            max_uses,
+        } => interchain_token::process_grant_minter(accounts, expires_at, max_uses),
+        InterchainTokenServiceInstruction::CleanupExpiredRoles => {
+            process_cleanup_expired_roles(accounts)
+        }
+        InterchainTokenServiceInstruction::SyncRoleIndex => process_sync_role_index(accounts),
+        InterchainTokenServiceInstruction::CreateMultisig { signers, threshold } => {
+            process_create_multisig(accounts, signers, threshold)
+        }
+        InterchainTokenServiceInstruction::CancelOperatorshipProposal => {
+            process_cancel_operatorship_proposal(accounts)
+        }
+        InterchainTokenServiceInstruction::CancelTokenManagerOperatorshipProposal => {
+            token_manager::process_cancel_operatorship_proposal(accounts)
+        }
+        InterchainTokenServiceInstruction::CancelInterchainTokenMintershipProposal => {
+            interchain_token::process_cancel_mintership_proposal(accounts)
+        }
+        InterchainTokenServiceInstruction::CloseExpiredDeployApproval {
+            token_id,
+            destination_chain,
+        } => interchain_token::process_close_expired_deploy_approval(
+            accounts,
+            token_id,
+            destination_chain,
+        ),
     }
 }
 
-fn process_initialize(
+fn process_initialize<'a>(
     program_id: &Pubkey,
-    accounts: &[AccountInfo<'_>],
+    accounts: &'a [AccountInfo<'a>],
     chain_name: String,
     its_hub_address: String,
 ) -> ProgramResult {
@@ -294,6 +395,7 @@ fn process_initialize(
     let system_account = next_account_info(account_info_iter)?;
     let operator = next_account_info(account_info_iter)?;
     let user_roles_account = next_account_info(account_info_iter)?;
+    let role_index_account = next_account_info(account_info_iter)?;
 
     // Check: System Program Account
     if !system_program::check_id(system_account.key) {
@@ -336,18 +438,30 @@ fn process_initialize(
         signer_seeds,
     )?;
 
-    Ok(())
+    role_management::processor::sync_index::<Roles>(
+        program_id,
+        RoleSyncIndexAccounts {
+            system_account,
+            payer,
+            resource: its_root_pda_account,
+            user_account: operator,
+            roles_account: user_roles_account,
+            index_account: role_index_account,
+        },
+    )
 }
 
 fn process_transfer_operatorship<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
     let accounts_iter = &mut accounts.iter();
 
     let system_account = next_account_info(accounts_iter)?;
-    let payer = next_account_info(accounts_iter)?;
-    let payer_roles_account = next_account_info(accounts_iter)?;
+    let authority = next_account_info(accounts_iter)?;
+    let authority_roles_account = next_account_info(accounts_iter)?;
     let resource = next_account_info(accounts_iter)?;
     let destination_user_account = next_account_info(accounts_iter)?;
     let destination_roles_account = next_account_info(accounts_iter)?;
+    let index_account = next_account_info(accounts_iter)?;
+    let payer = role_change_payer(authority, accounts_iter)?;
 
     msg!("Instruction: TransferOperatorship");
 
@@ -357,19 +471,25 @@ fn process_transfer_operatorship<'a>(accounts: &'a [AccountInfo<'a>]) -> Program
     let role_add_accounts = RoleAddAccounts {
         system_account,
         payer,
-        payer_roles_account,
+        authority,
+        authority_roles_account,
+        signers: accounts,
         resource,
         destination_user_account,
         destination_roles_account,
+        index_account,
     };
 
     let role_remove_accounts = RoleRemoveAccounts {
         system_account,
         payer,
-        payer_roles_account,
+        authority,
+        authority_roles_account,
+        signers: accounts,
         resource,
-        origin_user_account: payer,
-        origin_roles_account: payer_roles_account,
+        origin_user_account: authority,
+        origin_roles_account: authority_roles_account,
+        index_account,
     };
 
     role_management::processor::add(
@@ -387,31 +507,123 @@ fn process_transfer_operatorship<'a>(accounts: &'a [AccountInfo<'a>]) -> Program
     )
 }
 
-fn process_propose_operatorship<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+fn process_cleanup_expired_roles<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+    msg!("Instruction: CleanupExpiredRoles");
+
+    let accounts_iter = &mut accounts.iter();
+    let system_account = next_account_info(accounts_iter)?;
+    let payer = next_account_info(accounts_iter)?;
+    let resource = next_account_info(accounts_iter)?;
+    let user_account = next_account_info(accounts_iter)?;
+    let roles_account = next_account_info(accounts_iter)?;
+    let index_account = next_account_info(accounts_iter)?;
+
+    validate_system_account_key(system_account.key)?;
+
+    let role_cleanup_accounts = RoleCleanupAccounts {
+        system_account,
+        payer,
+        resource,
+        user_account,
+        roles_account,
+        index_account,
+    };
+
+    role_management::processor::cleanup::<Roles>(&crate::id(), role_cleanup_accounts)
+}
+
+fn process_sync_role_index<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+    msg!("Instruction: SyncRoleIndex");
+
     let accounts_iter = &mut accounts.iter();
+    let system_account = next_account_info(accounts_iter)?;
+    let payer = next_account_info(accounts_iter)?;
+    let resource = next_account_info(accounts_iter)?;
+    let user_account = next_account_info(accounts_iter)?;
+    let roles_account = next_account_info(accounts_iter)?;
+    let index_account = next_account_info(accounts_iter)?;
+
+    validate_system_account_key(system_account.key)?;
+
+    let role_sync_index_accounts = RoleSyncIndexAccounts {
+        system_account,
+        payer,
+        resource,
+        user_account,
+        roles_account,
+        index_account,
+    };
+
+    role_management::processor::sync_index::<Roles>(&crate::id(), role_sync_index_accounts)
+}
 
+fn process_create_multisig<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    signers: Vec<Pubkey>,
+    threshold: u8,
+) -> ProgramResult {
+    msg!("Instruction: CreateMultisig");
+
+    let accounts_iter = &mut accounts.iter();
     let system_account = next_account_info(accounts_iter)?;
     let payer = next_account_info(accounts_iter)?;
-    let payer_roles_account = next_account_info(accounts_iter)?;
+    let multisig_account = next_account_info(accounts_iter)?;
+
+    validate_system_account_key(system_account.key)?;
+
+    let event = event::MultisigCreated {
+        multisig: *multisig_account.key,
+        signers: signers.clone(),
+        threshold,
+    };
+
+    role_management::processor::create_multisig(
+        &crate::id(),
+        MultisigCreateAccounts {
+            system_account,
+            payer,
+            multisig_account,
+        },
+        signers,
+        threshold,
+    )?;
+
+    event.emit();
+
+    Ok(())
+}
+
+fn process_propose_operatorship<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    expires_at: Option<i64>,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+
+    let system_account = next_account_info(accounts_iter)?;
+    let authority = next_account_info(accounts_iter)?;
+    let authority_roles_account = next_account_info(accounts_iter)?;
     let resource = next_account_info(accounts_iter)?;
     let destination_user_account = next_account_info(accounts_iter)?;
     let destination_roles_account = next_account_info(accounts_iter)?;
     let proposal_account = next_account_info(accounts_iter)?;
+    let payer = role_change_payer(authority, accounts_iter)?;
 
     msg!("Instruction: ProposeOperatorship");
 
     let its_config = InterchainTokenService::load(resource)?;
     assert_valid_its_root_pda(resource, its_config.bump)?;
 
-    let role_management_accounts = RoleTransferWithProposalAccounts {
+    let role_management_accounts = RoleProposeAccounts {
         system_account,
         payer,
-        payer_roles_account,
+        authority,
+        authority_roles_account,
+        signers: accounts,
         resource,
         destination_user_account,
         destination_roles_account,
-        origin_user_account: payer,
-        origin_roles_account: payer_roles_account,
+        origin_user_account: authority,
+        origin_roles_account: authority_roles_account,
         proposal_account,
     };
 
@@ -420,31 +632,37 @@ fn process_propose_operatorship<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramR
         role_management_accounts,
         Roles::OPERATOR,
         Roles::OPERATOR,
+        expires_at,
     )
 }
 
 fn process_accept_operatorship<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
     let accounts_iter = &mut accounts.iter();
     let system_account = next_account_info(accounts_iter)?;
-    let payer = next_account_info(accounts_iter)?;
-    let payer_roles_account = next_account_info(accounts_iter)?;
+    let authority = next_account_info(accounts_iter)?;
+    let authority_roles_account = next_account_info(accounts_iter)?;
     let resource = next_account_info(accounts_iter)?;
     let origin_user_account = next_account_info(accounts_iter)?;
     let origin_roles_account = next_account_info(accounts_iter)?;
     let proposal_account = next_account_info(accounts_iter)?;
+    let index_account = next_account_info(accounts_iter)?;
+    let payer = role_change_payer(authority, accounts_iter)?;
 
     msg!("Instruction: AcceptOperatorship");
 
     let role_management_accounts = RoleTransferWithProposalAccounts {
         system_account,
         payer,
-        payer_roles_account,
+        authority,
+        authority_roles_account,
+        signers: accounts,
         resource,
-        destination_user_account: payer,
-        destination_roles_account: payer_roles_account,
+        destination_user_account: authority,
+        destination_roles_account: authority_roles_account,
         origin_user_account,
         origin_roles_account,
         proposal_account,
+        index_account,
     };
 
     role_management::processor::accept(
@@ -455,6 +673,31 @@ fn process_accept_operatorship<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramRe
     )
 }
 
+fn process_cancel_operatorship_proposal<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let payer = next_account_info(accounts_iter)?;
+    let resource = next_account_info(accounts_iter)?;
+    let origin_user_account = next_account_info(accounts_iter)?;
+    let destination_user_account = next_account_info(accounts_iter)?;
+    let proposal_account = next_account_info(accounts_iter)?;
+
+    msg!("Instruction: CancelOperatorshipProposal");
+
+    let its_config = InterchainTokenService::load(resource)?;
+    assert_valid_its_root_pda(resource, its_config.bump)?;
+
+    let role_management_accounts = RoleCancelProposalAccounts {
+        payer,
+        signers: accounts,
+        resource,
+        origin_user_account,
+        destination_user_account,
+        proposal_account,
+    };
+
+    role_management::processor::cancel::<Roles>(&crate::id(), role_management_accounts)
+}
+
 fn process_set_pause_status<'a>(accounts: &'a [AccountInfo<'a>], paused: bool) -> ProgramResult {
     let accounts_iter = &mut accounts.iter();
     let payer = next_account_info(accounts_iter)?;
@@ -468,6 +711,14 @@ fn process_set_pause_status<'a>(accounts: &'a [AccountInfo<'a>], paused: bool) -
 
     ensure_upgrade_authority(&crate::id(), payer, program_data_account)?;
 
+    // Keep the trusted chains of a root account pending migration
+    if let Some(mut legacy_config) = LegacyInterchainTokenService::try_load(its_root_pda) {
+        assert_valid_its_root_pda(its_root_pda, legacy_config.bump)?;
+
+        legacy_config.paused = paused;
+        return legacy_config.store(payer, its_root_pda, system_account);
+    }
+
     let mut its_root_config = InterchainTokenService::load(its_root_pda)?;
     assert_valid_its_root_pda(its_root_pda, its_root_config.bump)?;
 
@@ -480,32 +731,58 @@ fn process_set_pause_status<'a>(accounts: &'a [AccountInfo<'a>], paused: bool) -
 fn process_set_trusted_chain<'a>(
     accounts: &'a [AccountInfo<'a>],
     chain_name: String,
+    its_address: String,
+    decimals_policy: DecimalsPolicy,
 ) -> ProgramResult {
-    let (payer, payer_roles, program_data_account, its_root_pda, system_account) =
+    let (payer, payer_roles, program_data_account, its_root_pda, system_account, trusted_chain_pda) =
         get_trusted_chain_accounts(accounts)?;
     msg!("Instruction: SetTrustedChain");
 
-    if ensure_upgrade_authority(&crate::id(), payer, program_data_account).is_err()
-        && ensure_signer_roles(
+    ensure_trusted_chain_authority(payer, payer_roles, program_data_account, its_root_pda)?;
+
+    if chain_name.len() > MAX_SEED_LEN {
+        msg!("Chain name is too long: {}", chain_name);
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    if trusted_chain_pda.is_initialized_pda(&crate::id()) {
+        let mut trusted_chain = TrustedChain::load(trusted_chain_pda)?;
+        assert_valid_trusted_chain_pda(
+            trusted_chain_pda,
+            its_root_pda.key,
+            &chain_name,
+            trusted_chain.bump,
+        )?;
+
+        trusted_chain.its_address = its_address;
+        trusted_chain.decimals_policy = decimals_policy;
+        trusted_chain.store(payer, trusted_chain_pda, system_account)?;
+    } else {
+        let (_, bump) = crate::find_trusted_chain_pda(its_root_pda.key, &chain_name);
+        assert_valid_trusted_chain_pda(trusted_chain_pda, its_root_pda.key, &chain_name, bump)?;
+
+        TrustedChain::new(
+            chain_name.clone(),
+            its_address,
+            decimals_policy,
+            Clock::get()?.unix_timestamp,
+            bump,
+        )
+        .init(
             &crate::id(),
-            its_root_pda,
+            system_account,
             payer,
-            payer_roles,
-            Roles::OPERATOR,
-        )
-        .is_err()
-    {
-        msg!("Payer is neither upgrade authority nor operator");
-        return Err(ProgramError::MissingRequiredSignature);
+            trusted_chain_pda,
+            &[
+                seed_prefixes::TRUSTED_CHAIN_SEED,
+                its_root_pda.key.as_ref(),
+                chain_name.as_bytes(),
+                &[bump],
+            ],
+        )?;
     }
 
-    let mut its_root_config = InterchainTokenService::load(its_root_pda)?;
-    assert_valid_its_root_pda(its_root_pda, its_root_config.bump)?;
-
-    let trusted_chain_event = event::TrustedChainSet { chain_name };
-    trusted_chain_event.emit();
-    its_root_config.add_trusted_chain(trusted_chain_event.chain_name);
-    its_root_config.store(payer, its_root_pda, system_account)?;
+    event::TrustedChainSet { chain_name }.emit();
 
     Ok(())
 }
@@ -514,11 +791,171 @@ fn process_remove_trusted_chain<'a>(
     accounts: &'a [AccountInfo<'a>],
     chain_name: &str,
 ) -> ProgramResult {
-    let (payer, payer_roles, program_data_account, its_root_pda, system_account) =
-        get_trusted_chain_accounts(accounts)?;
+    let (
+        payer,
+        payer_roles,
+        program_data_account,
+        its_root_pda,
+        _system_account,
+        trusted_chain_pda,
+    ) = get_trusted_chain_accounts(accounts)?;
 
     msg!("Instruction: RemoveTrustedChain");
 
+    ensure_trusted_chain_authority(payer, payer_roles, program_data_account, its_root_pda)?;
+
+    trusted_chain_pda.check_initialized_pda_without_deserialization(&crate::id())?;
+    let trusted_chain = TrustedChain::load(trusted_chain_pda)?;
+    assert_valid_trusted_chain_pda(
+        trusted_chain_pda,
+        its_root_pda.key,
+        chain_name,
+        trusted_chain.bump,
+    )?;
+
+    event::TrustedChainRemoved {
+        chain_name: chain_name.to_owned(),
+    }
+    .emit();
+
+    program_utils::pda::close_pda(payer, trusted_chain_pda)
+}
+
+fn process_set_trusted_chain_pause_status<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    chain_name: String,
+    paused: bool,
+) -> ProgramResult {
+    let (payer, payer_roles, program_data_account, its_root_pda, system_account, trusted_chain_pda) =
+        get_trusted_chain_accounts(accounts)?;
+
+    msg!("Instruction: SetTrustedChainPauseStatus");
+
+    ensure_trusted_chain_authority(payer, payer_roles, program_data_account, its_root_pda)?;
+
+    trusted_chain_pda.check_initialized_pda_without_deserialization(&crate::id())?;
+    let mut trusted_chain = TrustedChain::load(trusted_chain_pda)?;
+    assert_valid_trusted_chain_pda(
+        trusted_chain_pda,
+        its_root_pda.key,
+        &chain_name,
+        trusted_chain.bump,
+    )?;
+
+    trusted_chain.paused = paused;
+    trusted_chain.store(payer, trusted_chain_pda, system_account)?;
+
+    event::TrustedChainPauseStatusSet { chain_name, paused }.emit();
+
+    Ok(())
+}
+
+fn process_migrate_trusted_chains<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    trusted_chains: Vec<(String, String)>,
+) -> ProgramResult {
+    let accounts_iter = &mut accounts.iter();
+    let payer = next_account_info(accounts_iter)?;
+    let program_data_account = next_account_info(accounts_iter)?;
+    let its_root_pda = next_account_info(accounts_iter)?;
+    let system_account = next_account_info(accounts_iter)?;
+
+    validate_system_account_key(system_account.key)?;
+
+    msg!("Instruction: MigrateTrustedChains");
+
+    ensure_upgrade_authority(&crate::id(), payer, program_data_account)?;
+
+    let Some(mut legacy_config) = LegacyInterchainTokenService::try_load(its_root_pda) else {
+        msg!("The ITS root account has no trusted chains pending migration");
+        return Err(ProgramError::InvalidAccountData);
+    };
+    assert_valid_its_root_pda(its_root_pda, legacy_config.bump)?;
+
+    // Chains with names too long to derive their PDA can't be migrated
+    legacy_config.trusted_chains.retain(|chain_name| {
+        let fits_in_seed = chain_name.len() <= MAX_SEED_LEN;
+        if !fits_in_seed {
+            msg!(
+                "Dropping trusted chain with a name too long: {}",
+                chain_name
+            );
+        }
+        fits_in_seed
+    });
+
+    let added_at = Clock::get()?.unix_timestamp;
+
+    for (chain_name, its_address) in trusted_chains {
+        let trusted_chain_pda = next_account_info(accounts_iter)?;
+
+        if !legacy_config.trusted_chains.remove(&chain_name) {
+            msg!("Chain is not pending migration: {}", chain_name);
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        let (_, bump) = crate::find_trusted_chain_pda(its_root_pda.key, &chain_name);
+        assert_valid_trusted_chain_pda(trusted_chain_pda, its_root_pda.key, &chain_name, bump)?;
+
+        TrustedChain::new(
+            chain_name.clone(),
+            its_address,
+            DecimalsPolicy::default(),
+            added_at,
+            bump,
+        )
+        .init(
+            &crate::id(),
+            system_account,
+            payer,
+            trusted_chain_pda,
+            &[
+                seed_prefixes::TRUSTED_CHAIN_SEED,
+                its_root_pda.key.as_ref(),
+                chain_name.as_bytes(),
+                &[bump],
+            ],
+        )?;
+
+        event::TrustedChainSet { chain_name }.emit();
+    }
+
+    if legacy_config.trusted_chains.is_empty() {
+        legacy_config
+            .into_current()
+            .store(payer, its_root_pda, system_account)
+    } else {
+        legacy_config.store(payer, its_root_pda, system_account)
+    }
+}
+
+/// Returns the account paying the rent of a role change, or of any other change
+/// made by a role holder: the authority itself when it signs, or the account
+/// following the accounts of the instruction when the authority is a multisig,
+/// as a multisig can't pay.
+pub(crate) fn role_change_payer<'a>(
+    authority: &'a AccountInfo<'a>,
+    accounts_iter: &mut core::slice::Iter<'a, AccountInfo<'a>>,
+) -> Result<&'a AccountInfo<'a>, ProgramError> {
+    if authority.is_signer {
+        return Ok(authority);
+    }
+
+    let payer = next_account_info(accounts_iter)?;
+    if !payer.is_signer {
+        msg!("The payer of a change made by a multisig needs to sign");
+        return Err(ProgramError::MissingRequiredSignature);
+    }
+
+    Ok(payer)
+}
+
+fn ensure_trusted_chain_authority<'a>(
+    payer: &'a AccountInfo<'a>,
+    payer_roles: &'a AccountInfo<'a>,
+    program_data_account: &'a AccountInfo<'a>,
+    its_root_pda: &'a AccountInfo<'a>,
+) -> ProgramResult {
     if ensure_upgrade_authority(&crate::id(), payer, program_data_account).is_err()
         && ensure_signer_roles(
             &crate::id(),
@@ -532,16 +969,9 @@ fn process_remove_trusted_chain<'a>(
         msg!("Payer is neither upgrade authority nor operator");
         return Err(ProgramError::MissingRequiredSignature);
     }
-    let mut its_root_config = InterchainTokenService::load(its_root_pda)?;
-    assert_valid_its_root_pda(its_root_pda, its_root_config.bump)?;
-
-    event::TrustedChainRemoved {
-        chain_name: chain_name.to_owned(),
-    }
-    .emit();
 
-    its_root_config.remove_trusted_chain(chain_name);
-    its_root_config.store(payer, its_root_pda, system_account)?;
+    let its_root_config = InterchainTokenService::load(its_root_pda)?;
+    assert_valid_its_root_pda(its_root_pda, its_root_config.bump)?;
 
     Ok(())
 }
@@ -555,6 +985,7 @@ fn get_trusted_chain_accounts<'a>(
         &'a AccountInfo<'a>,
         &'a AccountInfo<'a>,
         &'a AccountInfo<'a>,
+        &'a AccountInfo<'a>,
     ),
     ProgramError,
 > {
@@ -564,6 +995,7 @@ fn get_trusted_chain_accounts<'a>(
     let program_data_account = next_account_info(accounts_iter)?;
     let its_root_pda = next_account_info(accounts_iter)?;
     let system_account = next_account_info(accounts_iter)?;
+    let trusted_chain_pda = next_account_info(accounts_iter)?;
 
     validate_system_account_key(system_account.key)?;
     Ok((
@@ -572,5 +1004,6 @@ fn get_trusted_chain_accounts<'a>(
         program_data_account,
         its_root_pda,
         system_account,
+        trusted_chain_pda,
     ))
 }
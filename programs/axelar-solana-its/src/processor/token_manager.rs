@@ -1,36 +1,46 @@
 //! Processor for [`TokenManager`] related requests.
 
+use bitflags::bitflags;
 use event_utils::Event as _;
 use program_utils::{
     pda::{BorshPda, ValidPDA},
     validate_rent_key, validate_spl_associated_token_account_key, validate_system_account_key,
 };
 use role_management::processor::{
-    ensure_roles, RoleAddAccounts, RoleRemoveAccounts, RoleTransferWithProposalAccounts,
+    ensure_multisig_approval, ensure_roles, ensure_signer_or_multisig_roles, RoleAddAccounts,
+    RoleCancelProposalAccounts, RoleProposeAccounts, RoleRemoveAccounts, RoleSyncIndexAccounts,
+    RoleTransferWithProposalAccounts,
 };
-use role_management::state::UserRoles;
+use role_management::state::{RoleGrant, UserRoles};
 use solana_program::account_info::{next_account_info, AccountInfo};
+use solana_program::clock::Clock;
 use solana_program::entrypoint::ProgramResult;
 use solana_program::program::invoke;
 use solana_program::program_error::ProgramError;
 use solana_program::program_option::COption;
 use solana_program::program_pack::Pack;
 use solana_program::pubkey::Pubkey;
+use solana_program::sysvar::Sysvar;
 use solana_program::{msg, system_program};
 use spl_token_2022::check_spl_token_program_account;
+use spl_token_2022::extension::default_account_state::DefaultAccountState;
 use spl_token_2022::extension::{BaseStateWithExtensions, ExtensionType, StateWithExtensions};
 use spl_token_2022::instruction::AuthorityType;
-use spl_token_2022::state::Mint;
+use spl_token_2022::state::{AccountState, Mint};
 
+use crate::state::flow_limit::{FlowLimit, FlowSlot};
 use crate::state::token_manager::{self, TokenManager};
 use crate::state::InterchainTokenService;
-use crate::{assert_valid_its_root_pda, event, Validate};
+use crate::{assert_valid_flow_slot_pda, assert_valid_its_root_pda, event, Validate};
 use crate::{assert_valid_token_manager_pda, seed_prefixes, FromAccountInfoSlice, Roles};
 
 pub(crate) fn set_flow_limit(
     accounts: &SetFlowLimitAccounts<'_>,
-    flow_limit: u64,
+    operator: &Pubkey,
+    flow_limit: FlowLimit,
 ) -> ProgramResult {
+    flow_limit.validate()?;
+
     ensure_roles(
         &crate::id(),
         accounts.token_manager_pda,
@@ -47,6 +57,16 @@ pub(crate) fn set_flow_limit(
         accounts.system_account,
     )?;
 
+    event::FlowLimitSet {
+        token_id: token_manager.token_id,
+        operator: *operator,
+        inbound_flow_limit: flow_limit.inbound,
+        outbound_flow_limit: flow_limit.outbound,
+        epoch_duration: flow_limit.epoch_duration,
+        sliding_window: flow_limit.sliding_window,
+    }
+    .emit();
+
     Ok(())
 }
 
@@ -56,6 +76,7 @@ pub(crate) struct DeployTokenManagerInternal {
     token_address: Pubkey,
     operator: Option<Pubkey>,
     minter: Option<Pubkey>,
+    origin_chain: Option<String>,
 }
 
 impl DeployTokenManagerInternal {
@@ -72,8 +93,16 @@ impl DeployTokenManagerInternal {
             token_address,
             operator,
             minter,
+            origin_chain: None,
         }
     }
+
+    /// Records the chain the interchain token backed by the token manager was
+    /// deployed from.
+    pub(crate) fn with_origin_chain(mut self, origin_chain: String) -> Self {
+        self.origin_chain = Some(origin_chain);
+        self
+    }
 }
 
 /// Deploys a new [`TokenManager`] PDA.
@@ -123,8 +152,9 @@ pub(crate) fn deploy<'a>(
         setup_roles(
             payer,
             accounts.token_manager_pda,
-            operator.key,
+            operator,
             operator_roles_pda,
+            accounts.role_index_pda,
             accounts.system_account,
             roles,
         )?;
@@ -133,19 +163,24 @@ pub(crate) fn deploy<'a>(
     setup_roles(
         payer,
         accounts.token_manager_pda,
-        accounts.its_root_pda.key,
+        accounts.its_root_pda,
         accounts.its_roles_pda,
+        accounts.role_index_pda,
         accounts.system_account,
         Roles::OPERATOR | Roles::FLOW_LIMITER,
     )?;
 
-    let token_manager = TokenManager::new(
+    let mut token_manager = TokenManager::new(
         deploy_token_manager.manager_type,
         deploy_token_manager.token_id,
         deploy_token_manager.token_address,
         *accounts.token_manager_ata.key,
+        *payer.key,
         token_manager_pda_bump,
     );
+    token_manager
+        .origin_chain
+        .clone_from(&deploy_token_manager.origin_chain);
     token_manager.init(
         &crate::id(),
         accounts.system_account,
@@ -173,16 +208,19 @@ pub(crate) fn deploy<'a>(
     Ok(())
 }
 
+/// Adds roles to a user on a newly set up [`TokenManager`] and records them in
+/// its role index.
 fn setup_roles<'a>(
-    payer: &AccountInfo<'a>,
-    token_manager_pda: &AccountInfo<'a>,
-    user: &Pubkey,
-    user_roles_pda: &AccountInfo<'a>,
-    system_account: &AccountInfo<'a>,
+    payer: &'a AccountInfo<'a>,
+    token_manager_pda: &'a AccountInfo<'a>,
+    user: &'a AccountInfo<'a>,
+    user_roles_pda: &'a AccountInfo<'a>,
+    role_index_pda: &'a AccountInfo<'a>,
+    system_account: &'a AccountInfo<'a>,
     roles: Roles,
 ) -> ProgramResult {
     let (derived_user_roles_pda, user_roles_pda_bump) =
-        role_management::find_user_roles_pda(&crate::id(), token_manager_pda.key, user);
+        role_management::find_user_roles_pda(&crate::id(), token_manager_pda.key, user.key);
 
     if derived_user_roles_pda.ne(user_roles_pda.key) {
         msg!("Invalid user roles PDA provided");
@@ -202,13 +240,23 @@ fn setup_roles<'a>(
             &[
                 role_management::seed_prefixes::USER_ROLES_SEED,
                 token_manager_pda.key.as_ref(),
-                user.as_ref(),
+                user.key.as_ref(),
                 &[user_roles_pda_bump],
             ],
         )?;
     }
 
-    Ok(())
+    role_management::processor::sync_index::<Roles>(
+        &crate::id(),
+        RoleSyncIndexAccounts {
+            system_account,
+            payer,
+            resource: token_manager_pda,
+            user_account: user,
+            roles_account: user_roles_pda,
+            index_account: role_index_pda,
+        },
+    )
 }
 
 fn check_accounts(accounts: &DeployTokenManagerAccounts<'_>) -> ProgramResult {
@@ -244,6 +292,84 @@ fn check_accounts(accounts: &DeployTokenManagerAccounts<'_>) -> ProgramResult {
     Ok(())
 }
 
+bitflags! {
+    /// The Token-2022 mint extensions that affect how ITS can move tokens.
+    ///
+    /// Confidential transfers are out of scope, ITS only moves the public
+    /// balance of token accounts.
+    #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+    pub(crate) struct MintExtensions: u8 {
+        const TRANSFER_FEE = 0b0000_0001;
+        const TRANSFER_HOOK = 0b0000_0010;
+        const PERMANENT_DELEGATE = 0b0000_0100;
+        const NON_TRANSFERABLE = 0b0000_1000;
+        /// Token accounts are frozen when created.
+        const DEFAULT_FROZEN = 0b0001_0000;
+    }
+}
+
+impl MintExtensions {
+    pub(crate) fn classify(mint: &StateWithExtensions<'_, Mint>) -> Result<Self, ProgramError> {
+        let extension_types = mint.get_extension_types()?;
+        let mut extensions = Self::empty();
+
+        for (extension, extension_type) in [
+            (Self::TRANSFER_FEE, ExtensionType::TransferFeeConfig),
+            (Self::TRANSFER_HOOK, ExtensionType::TransferHook),
+            (Self::PERMANENT_DELEGATE, ExtensionType::PermanentDelegate),
+            (Self::NON_TRANSFERABLE, ExtensionType::NonTransferable),
+        ] {
+            extensions.set(extension, extension_types.contains(&extension_type));
+        }
+
+        if let Ok(default_account_state) = mint.get_extension::<DefaultAccountState>() {
+            extensions.set(
+                Self::DEFAULT_FROZEN,
+                default_account_state.state == u8::from(AccountState::Frozen),
+            );
+        }
+
+        Ok(extensions)
+    }
+
+    /// Checks whether tokens with these extensions can be managed by a
+    /// [`TokenManager`] of the given type.
+    ///
+    /// Transfer hooks are supported by all types, the accounts required by
+    /// the hook are expected to be passed when tokens are locked or unlocked.
+    pub(crate) fn validate(self, ty: token_manager::Type) -> ProgramResult {
+        use token_manager::Type::{
+            LockUnlock, LockUnlockFee, MintBurn, MintBurnFrom, NativeInterchainToken,
+        };
+
+        if self.contains(Self::NON_TRANSFERABLE) {
+            msg!("Mints with the NonTransferable extension cannot be bridged");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        if self.contains(Self::DEFAULT_FROZEN) {
+            msg!("Mints whose token accounts are frozen by default cannot be bridged");
+            return Err(ProgramError::InvalidAccountData);
+        }
+
+        match ty {
+            LockUnlock | LockUnlockFee if self.contains(Self::PERMANENT_DELEGATE) => {
+                msg!("Mints with the PermanentDelegate extension are not compatible with lock/unlock TokenManager types, as the locked tokens could be moved by the delegate");
+                Err(ProgramError::InvalidAccountData)
+            }
+            LockUnlock if self.contains(Self::TRANSFER_FEE) => {
+                msg!("Mints with the TransferFeeConfig extension require the LockUnlockFee TokenManager type");
+                Err(ProgramError::InvalidAccountData)
+            }
+            LockUnlockFee if !self.contains(Self::TRANSFER_FEE) => {
+                msg!("The mint is not compatible with the LockUnlockFee TokenManager type, please make sure the mint has the TransferFeeConfig extension initialized");
+                Err(ProgramError::InvalidAccountData)
+            }
+            NativeInterchainToken | MintBurnFrom | LockUnlock | LockUnlockFee | MintBurn => Ok(()),
+        }
+    }
+}
+
 pub(crate) fn validate_mint_extensions(
     ty: token_manager::Type,
     token_mint: &AccountInfo<'_>,
@@ -251,17 +377,9 @@ pub(crate) fn validate_mint_extensions(
     let mint_data = token_mint.try_borrow_data()?;
     let mint = StateWithExtensions::<Mint>::unpack(&mint_data)?;
 
-    if ty == token_manager::Type::LockUnlockFee
-        && !mint
-            .get_extension_types()?
-            .contains(&ExtensionType::TransferFeeConfig)
-    {
-        msg!("The mint is not compatible with the LockUnlockFee TokenManager type, please make sure the mint has the TransferFeeConfig extension initialized");
-        return Err(ProgramError::InvalidAccountData);
-    }
-
-    Ok(())
+    MintExtensions::classify(&mint)?.validate(ty)
 }
+
 pub(crate) fn validate_token_manager_type(
     ty: token_manager::Type,
     token_mint: &AccountInfo<'_>,
@@ -293,8 +411,8 @@ pub(crate) fn validate_token_manager_type(
     }
 }
 
-pub(crate) fn handover_mint_authority(
-    accounts: &[AccountInfo<'_>],
+pub(crate) fn handover_mint_authority<'a>(
+    accounts: &'a [AccountInfo<'a>],
     token_id: [u8; 32],
 ) -> ProgramResult {
     let accounts_iter = &mut accounts.iter();
@@ -305,6 +423,7 @@ pub(crate) fn handover_mint_authority(
     let minter_roles = next_account_info(accounts_iter)?;
     let token_program = next_account_info(accounts_iter)?;
     let system_account = next_account_info(accounts_iter)?;
+    let role_index = next_account_info(accounts_iter)?;
 
     validate_system_account_key(system_account.key)?;
     msg!("Instruction: HandoverMintAuthority");
@@ -355,8 +474,9 @@ pub(crate) fn handover_mint_authority(
             setup_roles(
                 payer,
                 token_manager,
-                payer.key,
+                payer,
                 minter_roles,
+                role_index,
                 system_account,
                 Roles::MINTER,
             )?;
@@ -382,6 +502,7 @@ pub(crate) struct DeployTokenManagerAccounts<'a> {
     pub(crate) token_program: &'a AccountInfo<'a>,
     pub(crate) ata_program: &'a AccountInfo<'a>,
     pub(crate) its_roles_pda: &'a AccountInfo<'a>,
+    pub(crate) role_index_pda: &'a AccountInfo<'a>,
     pub(crate) rent_sysvar: &'a AccountInfo<'a>,
     pub(crate) operator: Option<&'a AccountInfo<'a>>,
     pub(crate) operator_roles_pda: Option<&'a AccountInfo<'a>>,
@@ -418,6 +539,7 @@ impl<'a> FromAccountInfoSlice<'a> for DeployTokenManagerAccounts<'a> {
             token_program: next_account_info(accounts_iter)?,
             ata_program: next_account_info(accounts_iter)?,
             its_roles_pda: next_account_info(accounts_iter)?,
+            role_index_pda: next_account_info(accounts_iter)?,
             rent_sysvar: next_account_info(accounts_iter)?,
             operator: next_account_info(accounts_iter).ok(),
             operator_roles_pda: next_account_info(accounts_iter).ok(),
@@ -470,19 +592,24 @@ pub(crate) fn process_add_flow_limiter<'a>(accounts: &'a [AccountInfo<'a>]) -> P
 
     let accounts_iter = &mut accounts.iter();
     let system_account = next_account_info(accounts_iter)?;
-    let payer = next_account_info(accounts_iter)?;
-    let payer_roles_account = next_account_info(accounts_iter)?;
+    let authority = next_account_info(accounts_iter)?;
+    let authority_roles_account = next_account_info(accounts_iter)?;
     let resource = next_account_info(accounts_iter)?;
     let destination_user_account = next_account_info(accounts_iter)?;
     let destination_roles_account = next_account_info(accounts_iter)?;
+    let index_account = next_account_info(accounts_iter)?;
+    let payer = super::role_change_payer(authority, accounts_iter)?;
 
     let role_management_accounts = RoleAddAccounts {
         system_account,
         payer,
-        payer_roles_account,
+        authority,
+        authority_roles_account,
+        signers: accounts,
         resource,
         destination_user_account,
         destination_roles_account,
+        index_account,
     };
 
     role_management::processor::add(
@@ -493,24 +620,66 @@ pub(crate) fn process_add_flow_limiter<'a>(accounts: &'a [AccountInfo<'a>]) -> P
     )
 }
 
+pub(crate) fn process_grant_flow_limiter<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    expires_at: Option<i64>,
+    max_uses: Option<u64>,
+) -> ProgramResult {
+    msg!("Instruction: GrantTokenManagerFlowLimiter");
+
+    let accounts_iter = &mut accounts.iter();
+    let system_account = next_account_info(accounts_iter)?;
+    let authority = next_account_info(accounts_iter)?;
+    let authority_roles_account = next_account_info(accounts_iter)?;
+    let resource = next_account_info(accounts_iter)?;
+    let destination_user_account = next_account_info(accounts_iter)?;
+    let destination_roles_account = next_account_info(accounts_iter)?;
+    let index_account = next_account_info(accounts_iter)?;
+    let payer = super::role_change_payer(authority, accounts_iter)?;
+
+    let role_management_accounts = RoleAddAccounts {
+        system_account,
+        payer,
+        authority,
+        authority_roles_account,
+        signers: accounts,
+        resource,
+        destination_user_account,
+        destination_roles_account,
+        index_account,
+    };
+
+    role_management::processor::grant(
+        &crate::id(),
+        role_management_accounts,
+        RoleGrant::new(Roles::FLOW_LIMITER, expires_at, max_uses),
+        Roles::OPERATOR,
+    )
+}
+
 pub(crate) fn process_remove_flow_limiter<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
     msg!("Instruction: RemoveTokenManagerFlowLimiter");
 
     let accounts_iter = &mut accounts.iter();
     let system_account = next_account_info(accounts_iter)?;
-    let payer = next_account_info(accounts_iter)?;
-    let payer_roles_account = next_account_info(accounts_iter)?;
+    let authority = next_account_info(accounts_iter)?;
+    let authority_roles_account = next_account_info(accounts_iter)?;
     let resource = next_account_info(accounts_iter)?;
     let origin_user_account = next_account_info(accounts_iter)?;
     let origin_roles_account = next_account_info(accounts_iter)?;
+    let index_account = next_account_info(accounts_iter)?;
+    let payer = super::role_change_payer(authority, accounts_iter)?;
 
     let role_management_accounts = RoleRemoveAccounts {
         system_account,
         payer,
-        payer_roles_account,
+        authority,
+        authority_roles_account,
+        signers: accounts,
         resource,
         origin_user_account,
         origin_roles_account,
+        index_account,
     };
 
     role_management::processor::remove(
@@ -523,16 +692,111 @@ pub(crate) fn process_remove_flow_limiter<'a>(accounts: &'a [AccountInfo<'a>]) -
 
 pub(crate) fn process_set_flow_limit<'a>(
     accounts: &'a [AccountInfo<'a>],
-    flow_limit: u64,
+    flow_limit: FlowLimit,
 ) -> ProgramResult {
     msg!("Instruction: SetTokenManagerFlowLimit");
 
     let instruction_accounts = SetFlowLimitAccounts::from_account_info_slice(accounts, &())?;
     if !instruction_accounts.flow_limiter.is_signer {
-        return Err(ProgramError::MissingRequiredSignature);
+        ensure_multisig_approval(&crate::id(), instruction_accounts.flow_limiter, accounts)?;
+    }
+
+    set_flow_limit(
+        &instruction_accounts,
+        instruction_accounts.flow_limiter.key,
+        flow_limit,
+    )
+}
+
+pub(crate) fn process_set_supply_cap<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    supply_cap: u64,
+) -> ProgramResult {
+    msg!("Instruction: SetTokenManagerSupplyCap");
+
+    let accounts_iter = &mut accounts.iter();
+    let operator = next_account_info(accounts_iter)?;
+    let its_root_pda = next_account_info(accounts_iter)?;
+    let token_manager_pda = next_account_info(accounts_iter)?;
+    let operator_roles_pda = next_account_info(accounts_iter)?;
+    let system_account = next_account_info(accounts_iter)?;
+
+    validate_system_account_key(system_account.key)?;
+
+    let its_root_config = InterchainTokenService::load(its_root_pda)?;
+    assert_valid_its_root_pda(its_root_pda, its_root_config.bump)?;
+
+    let mut token_manager = TokenManager::load(token_manager_pda)?;
+    assert_valid_token_manager_pda(
+        token_manager_pda,
+        its_root_pda.key,
+        &token_manager.token_id,
+        token_manager.bump,
+    )?;
+
+    ensure_signer_or_multisig_roles(
+        &crate::id(),
+        token_manager_pda,
+        operator,
+        operator_roles_pda,
+        Roles::OPERATOR,
+        accounts,
+    )?;
+
+    token_manager.supply_cap = supply_cap;
+    token_manager.store(operator, token_manager_pda, system_account)?;
+
+    event::SupplyCapSet {
+        token_id: token_manager.token_id,
+        operator: *operator.key,
+        supply_cap,
+    }
+    .emit();
+
+    Ok(())
+}
+
+pub(crate) fn process_close_flow_slot<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    epoch: u64,
+) -> ProgramResult {
+    msg!("Instruction: CloseFlowSlot");
+
+    let accounts_iter = &mut accounts.iter();
+    let its_root_pda = next_account_info(accounts_iter)?;
+    let token_manager_pda = next_account_info(accounts_iter)?;
+    let flow_slot_pda = next_account_info(accounts_iter)?;
+    let token_manager_payer = next_account_info(accounts_iter)?;
+
+    let its_root_config = InterchainTokenService::load(its_root_pda)?;
+    assert_valid_its_root_pda(its_root_pda, its_root_config.bump)?;
+
+    token_manager_pda.check_initialized_pda_without_deserialization(&crate::id())?;
+    let token_manager = TokenManager::load(token_manager_pda)?;
+    assert_valid_token_manager_pda(
+        token_manager_pda,
+        its_root_pda.key,
+        &token_manager.token_id,
+        token_manager.bump,
+    )?;
+
+    if token_manager.payer.ne(token_manager_payer.key) {
+        msg!("The refund destination must be the payer of the TokenManager");
+        return Err(ProgramError::InvalidArgument);
     }
 
-    set_flow_limit(&instruction_accounts, flow_limit)
+    flow_slot_pda.check_initialized_pda_without_deserialization(&crate::id())?;
+    let flow_slot = FlowSlot::load_for(flow_slot_pda, token_manager_pda.key, epoch)?;
+    assert_valid_flow_slot_pda(flow_slot_pda, token_manager_pda.key, epoch, flow_slot.bump)?;
+
+    // The epoch number alone can't tell, as the epoch duration may have
+    // changed since the slot was created.
+    if !flow_slot.is_closable(&token_manager.flow_limit, Clock::get()?.unix_timestamp)? {
+        msg!("Flow slot of epoch {} is still in use", epoch);
+        return Err(ProgramError::InvalidArgument);
+    }
+
+    program_utils::pda::close_pda(token_manager_payer, flow_slot_pda)
 }
 
 pub(crate) fn process_transfer_operatorship<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
@@ -541,11 +805,13 @@ pub(crate) fn process_transfer_operatorship<'a>(accounts: &'a [AccountInfo<'a>])
     let accounts_iter = &mut accounts.iter();
     let its_config_account = next_account_info(accounts_iter)?;
     let system_account = next_account_info(accounts_iter)?;
-    let payer = next_account_info(accounts_iter)?;
-    let payer_roles_account = next_account_info(accounts_iter)?;
+    let authority = next_account_info(accounts_iter)?;
+    let authority_roles_account = next_account_info(accounts_iter)?;
     let token_manager_account = next_account_info(accounts_iter)?;
     let destination_user_account = next_account_info(accounts_iter)?;
     let destination_roles_account = next_account_info(accounts_iter)?;
+    let index_account = next_account_info(accounts_iter)?;
+    let payer = super::role_change_payer(authority, accounts_iter)?;
 
     let its_config = InterchainTokenService::load(its_config_account)?;
     let token_manager = TokenManager::load(token_manager_account)?;
@@ -561,18 +827,24 @@ pub(crate) fn process_transfer_operatorship<'a>(accounts: &'a [AccountInfo<'a>])
     let role_add_accounts = RoleAddAccounts {
         system_account,
         payer,
-        payer_roles_account,
+        authority,
+        authority_roles_account,
+        signers: accounts,
         resource: token_manager_account,
         destination_user_account,
         destination_roles_account,
+        index_account,
     };
     let role_remove_accounts = RoleRemoveAccounts {
         system_account,
         payer,
-        payer_roles_account,
+        authority,
+        authority_roles_account,
+        signers: accounts,
         resource: token_manager_account,
-        origin_user_account: payer,
-        origin_roles_account: payer_roles_account,
+        origin_user_account: authority,
+        origin_roles_account: authority_roles_account,
+        index_account,
     };
 
     role_management::processor::add(
@@ -590,28 +862,34 @@ pub(crate) fn process_transfer_operatorship<'a>(accounts: &'a [AccountInfo<'a>])
     )
 }
 
-pub(crate) fn process_propose_operatorship<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
+pub(crate) fn process_propose_operatorship<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    expires_at: Option<i64>,
+) -> ProgramResult {
     msg!("Instruction: ProposeTokenManagerOperatorship");
 
     let accounts_iter = &mut accounts.iter();
     let its_config_account = next_account_info(accounts_iter)?;
     let system_account = next_account_info(accounts_iter)?;
-    let payer = next_account_info(accounts_iter)?;
-    let payer_roles_account = next_account_info(accounts_iter)?;
+    let authority = next_account_info(accounts_iter)?;
+    let authority_roles_account = next_account_info(accounts_iter)?;
     let token_manager_account = next_account_info(accounts_iter)?;
     let destination_user_account = next_account_info(accounts_iter)?;
     let destination_roles_account = next_account_info(accounts_iter)?;
     let proposal_account = next_account_info(accounts_iter)?;
+    let payer = super::role_change_payer(authority, accounts_iter)?;
 
-    let role_management_accounts = RoleTransferWithProposalAccounts {
+    let role_management_accounts = RoleProposeAccounts {
         system_account,
         payer,
-        payer_roles_account,
+        authority,
+        authority_roles_account,
+        signers: accounts,
         resource: token_manager_account,
         destination_user_account,
         destination_roles_account,
-        origin_user_account: payer,
-        origin_roles_account: payer_roles_account,
+        origin_user_account: authority,
+        origin_roles_account: authority_roles_account,
         proposal_account,
     };
 
@@ -630,32 +908,73 @@ pub(crate) fn process_propose_operatorship<'a>(accounts: &'a [AccountInfo<'a>])
         role_management_accounts,
         Roles::OPERATOR,
         Roles::OPERATOR,
+        expires_at,
     )
 }
 
+pub(crate) fn process_cancel_operatorship_proposal<'a>(
+    accounts: &'a [AccountInfo<'a>],
+) -> ProgramResult {
+    msg!("Instruction: CancelTokenManagerOperatorshipProposal");
+
+    let accounts_iter = &mut accounts.iter();
+    let its_config_account = next_account_info(accounts_iter)?;
+    let payer = next_account_info(accounts_iter)?;
+    let token_manager_account = next_account_info(accounts_iter)?;
+    let origin_user_account = next_account_info(accounts_iter)?;
+    let destination_user_account = next_account_info(accounts_iter)?;
+    let proposal_account = next_account_info(accounts_iter)?;
+
+    let its_config = InterchainTokenService::load(its_config_account)?;
+    assert_valid_its_root_pda(its_config_account, its_config.bump)?;
+    let token_manager = TokenManager::load(token_manager_account)?;
+    assert_valid_token_manager_pda(
+        token_manager_account,
+        its_config_account.key,
+        &token_manager.token_id,
+        token_manager.bump,
+    )?;
+
+    let role_management_accounts = RoleCancelProposalAccounts {
+        payer,
+        signers: accounts,
+        resource: token_manager_account,
+        origin_user_account,
+        destination_user_account,
+        proposal_account,
+    };
+
+    role_management::processor::cancel::<Roles>(&crate::id(), role_management_accounts)
+}
+
 pub(crate) fn process_accept_operatorship<'a>(accounts: &'a [AccountInfo<'a>]) -> ProgramResult {
     msg!("Instruction: AcceptTokenManagerOperatorship");
 
     let accounts_iter = &mut accounts.iter();
     let its_config_account = next_account_info(accounts_iter)?;
     let system_account = next_account_info(accounts_iter)?;
-    let payer = next_account_info(accounts_iter)?;
-    let payer_roles_account = next_account_info(accounts_iter)?;
+    let authority = next_account_info(accounts_iter)?;
+    let authority_roles_account = next_account_info(accounts_iter)?;
     let token_manager_account = next_account_info(accounts_iter)?;
     let origin_user_account = next_account_info(accounts_iter)?;
     let origin_roles_account = next_account_info(accounts_iter)?;
     let proposal_account = next_account_info(accounts_iter)?;
+    let index_account = next_account_info(accounts_iter)?;
+    let payer = super::role_change_payer(authority, accounts_iter)?;
 
     let role_management_accounts = RoleTransferWithProposalAccounts {
         system_account,
         payer,
-        payer_roles_account,
+        authority,
+        authority_roles_account,
+        signers: accounts,
         resource: token_manager_account,
-        destination_user_account: payer,
-        destination_roles_account: payer_roles_account,
+        destination_user_account: authority,
+        destination_roles_account: authority_roles_account,
         origin_user_account,
         origin_roles_account,
         proposal_account,
+        index_account,
     };
 
     let its_config = InterchainTokenService::load(its_config_account)?;
@@ -675,3 +994,41 @@ pub(crate) fn process_accept_operatorship<'a>(accounts: &'a [AccountInfo<'a>]) -
         Roles::empty(),
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::MintExtensions;
+    use crate::state::token_manager::Type;
+
+    #[test]
+    fn test_mint_extensions_validation() {
+        let plain = MintExtensions::empty();
+        assert!(plain.validate(Type::LockUnlock).is_ok());
+        assert!(plain.validate(Type::LockUnlockFee).is_err());
+
+        let transfer_fee = MintExtensions::TRANSFER_FEE;
+        assert!(transfer_fee.validate(Type::LockUnlock).is_err());
+        assert!(transfer_fee.validate(Type::LockUnlockFee).is_ok());
+        assert!(transfer_fee.validate(Type::MintBurn).is_ok());
+
+        let permanent_delegate = MintExtensions::PERMANENT_DELEGATE;
+        assert!(permanent_delegate.validate(Type::LockUnlock).is_err());
+        assert!(permanent_delegate.validate(Type::MintBurnFrom).is_ok());
+
+        let transfer_hook = MintExtensions::TRANSFER_HOOK;
+        assert!(transfer_hook.validate(Type::LockUnlock).is_ok());
+        assert!((transfer_hook | MintExtensions::TRANSFER_FEE)
+            .validate(Type::LockUnlockFee)
+            .is_ok());
+
+        for unsafe_extensions in [
+            MintExtensions::NON_TRANSFERABLE,
+            MintExtensions::DEFAULT_FROZEN,
+        ] {
+            assert!(unsafe_extensions
+                .validate(Type::NativeInterchainToken)
+                .is_err());
+            assert!(unsafe_extensions.validate(Type::LockUnlock).is_err());
+        }
+    }
+}
@@ -3,19 +3,43 @@ use core::any::type_name;
 use core::mem::size_of;
 
 use borsh::{BorshDeserialize, BorshSerialize};
-use program_utils::pda::BorshPda;
+use program_utils::pda::{deserialize_or_default, BorshPda};
 use solana_program::msg;
 use solana_program::program_error::ProgramError;
 use solana_program::program_pack::{Pack, Sealed};
 
-#[derive(Debug, Eq, PartialEq, Clone, BorshSerialize, BorshDeserialize)]
+#[derive(Debug, Eq, PartialEq, Clone, BorshSerialize)]
 pub(crate) struct DeployApproval {
     pub(crate) approved_destination_minter: [u8; 32],
     pub(crate) bump: u8,
+    /// The unix timestamp after which the approval can no longer be used, if any.
+    pub(crate) expires_at: Option<i64>,
+}
+
+impl DeployApproval {
+    pub(crate) fn is_expired(&self, timestamp: i64) -> bool {
+        self.expires_at
+            .is_some_and(|expires_at| timestamp >= expires_at)
+    }
+}
+
+impl BorshDeserialize for DeployApproval {
+    fn deserialize_reader<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let approved_destination_minter = <[u8; 32]>::deserialize_reader(reader)?;
+        let bump = u8::deserialize_reader(reader)?;
+        // Approvals created before they could expire end here.
+        let expires_at = deserialize_or_default(reader)?;
+
+        Ok(Self {
+            approved_destination_minter,
+            bump,
+            expires_at,
+        })
+    }
 }
 
 impl Pack for DeployApproval {
-    const LEN: usize = size_of::<u8>() + size_of::<[u8; 32]>();
+    const LEN: usize = size_of::<u8>() + size_of::<[u8; 32]>() + size_of::<u8>() + size_of::<i64>();
 
     #[allow(clippy::unwrap_used)]
     fn pack_into_slice(&self, mut dst: &mut [u8]) {
@@ -36,3 +60,38 @@ impl Pack for DeployApproval {
 }
 impl Sealed for DeployApproval {}
 impl BorshPda for DeployApproval {}
+
+#[cfg(test)]
+mod tests {
+    use solana_program::program_pack::Pack;
+
+    #[test]
+    fn test_deploy_approval_without_expiry_is_loaded() {
+        let mut data = vec![7; 32];
+        data.push(254);
+
+        let approval = super::DeployApproval::unpack_from_slice(&data).unwrap();
+
+        assert_eq!(
+            approval,
+            super::DeployApproval {
+                approved_destination_minter: [7; 32],
+                bump: 254,
+                expires_at: None,
+            }
+        );
+        assert!(!approval.is_expired(i64::MAX));
+
+        let approval = super::DeployApproval {
+            expires_at: Some(100),
+            ..approval
+        };
+        let data = borsh::to_vec(&approval).unwrap();
+
+        assert_eq!(
+            super::DeployApproval::unpack_from_slice(&data).unwrap(),
+            approval
+        );
+        assert!(approval.is_expired(100));
+    }
+}
@@ -7,18 +7,111 @@ use core::time::Duration;
 
 use borsh::{BorshDeserialize, BorshSerialize};
 use program_utils::pda::BorshPda;
+use solana_program::account_info::AccountInfo;
 use solana_program::clock::Clock;
 use solana_program::entrypoint::ProgramResult;
 use solana_program::msg;
 use solana_program::program_error::ProgramError;
 use solana_program::program_pack::{Pack, Sealed};
+use solana_program::pubkey::Pubkey;
 use solana_program::sysvar::Sysvar;
 
-const EPOCH_TIME: Duration = Duration::from_secs(6 * 60 * 60);
+/// Duration of a flow epoch used unless the `TokenManager` configures a
+/// different one.
+pub const DEFAULT_EPOCH_TIME: Duration = Duration::from_secs(6 * 60 * 60);
+
+/// Flow limit configuration of a `TokenManager`.
+#[derive(Debug, Eq, PartialEq, Clone, Copy, BorshSerialize, BorshDeserialize)]
+pub struct FlowLimit {
+    /// The limit on the net amount of tokens flowing into Solana within an
+    /// epoch. Zero means no limit.
+    pub inbound: u64,
+
+    /// The limit on the net amount of tokens flowing out of Solana within an
+    /// epoch. Zero means no limit.
+    pub outbound: u64,
+
+    /// The duration of a flow epoch, in seconds.
+    pub epoch_duration: u64,
+
+    /// Whether the net flow of the previous epoch is carried into the current
+    /// one, weighted by the portion of the current epoch that is yet to elapse.
+    /// This prevents moving up to twice the limit around an epoch boundary.
+    pub sliding_window: bool,
+}
+
+impl FlowLimit {
+    /// The length of the serialized `FlowLimit`.
+    pub const LEN: usize = 3 * size_of::<u64>() + size_of::<bool>();
+
+    /// Creates a new `FlowLimit` with the default epoch duration and the
+    /// sliding window disabled.
+    #[must_use]
+    pub const fn new(inbound: u64, outbound: u64) -> Self {
+        Self {
+            inbound,
+            outbound,
+            epoch_duration: DEFAULT_EPOCH_TIME.as_secs(),
+            sliding_window: false,
+        }
+    }
+
+    /// Sets the epoch duration, in seconds.
+    #[must_use]
+    pub const fn with_epoch_duration(mut self, epoch_duration: u64) -> Self {
+        self.epoch_duration = epoch_duration;
+        self
+    }
+
+    /// Enables or disables the sliding window.
+    #[must_use]
+    pub const fn with_sliding_window(mut self, sliding_window: bool) -> Self {
+        self.sliding_window = sliding_window;
+        self
+    }
+
+    /// Returns the flow epoch at the given timestamp.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if conversion from timestamp to flow epoch fails.
+    pub fn flow_epoch(&self, timestamp: i64) -> Result<u64, ProgramError> {
+        flow_epoch_with_timestamp(timestamp, self.epoch_duration)
+    }
+
+    pub(crate) const fn limit(&self, direction: FlowDirection) -> u64 {
+        match direction {
+            FlowDirection::In => self.inbound,
+            FlowDirection::Out => self.outbound,
+        }
+    }
+
+    pub(crate) fn validate(&self) -> ProgramResult {
+        if self.epoch_duration == 0 {
+            msg!("Flow epoch duration must be greater than zero");
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        Ok(())
+    }
+}
+
+impl Default for FlowLimit {
+    fn default() -> Self {
+        Self::new(0, 0)
+    }
+}
 
 #[derive(Debug, Eq, PartialEq, Clone, BorshSerialize, BorshDeserialize)]
 /// Struct containing flow information for a specific epoch.
 pub struct FlowSlot {
+    /// The `TokenManager` PDA whose flow is tracked by this slot.
+    pub token_manager: Pubkey,
+    /// The flow epoch this slot refers to.
+    pub epoch: u64,
+    /// The epoch duration, in seconds, the slot was created with. Along with
+    /// the epoch, it gives the time window the slot tracks the flow of.
+    pub epoch_duration: u64,
     pub flow_in: u64,
     pub flow_out: u64,
     pub bump: u8,
@@ -26,26 +119,148 @@ pub struct FlowSlot {
 
 /// Module for handling flow limits on interchain tokens.
 impl FlowSlot {
-    pub(crate) fn new(bump: u8) -> Self {
+    pub(crate) const fn new(
+        token_manager: Pubkey,
+        epoch: u64,
+        epoch_duration: u64,
+        bump: u8,
+    ) -> Self {
         Self {
+            token_manager,
+            epoch,
+            epoch_duration,
             flow_in: 0,
             flow_out: 0,
             bump,
         }
     }
 
+    /// The length of the `FlowSlot` accounts created before flow slots
+    /// recorded their token manager and epoch.
+    pub const LEGACY_LEN: usize = 2 * size_of::<u64>() + size_of::<u8>();
+
+    /// Deserializes the `FlowSlot` of `token_manager` for `epoch`.
+    ///
+    /// Accounts created before flow slots recorded their token manager and
+    /// epoch are told apart by their length and completed with the given
+    /// ones, and with the epoch duration used at the time. They are rewritten
+    /// in the current layout the next time they are stored.
+    ///
+    /// # Errors
+    ///
+    /// If the data cannot be deserialized.
+    pub fn from_account_data(
+        token_manager: &Pubkey,
+        epoch: u64,
+        data: &[u8],
+    ) -> Result<Self, ProgramError> {
+        if data.len() != Self::LEGACY_LEN {
+            return Ok(Self::try_from_slice(data)?);
+        }
+
+        let mut legacy_data = data;
+        Ok(Self {
+            token_manager: *token_manager,
+            epoch,
+            epoch_duration: DEFAULT_EPOCH_TIME.as_secs(),
+            flow_in: u64::deserialize(&mut legacy_data)?,
+            flow_out: u64::deserialize(&mut legacy_data)?,
+            bump: u8::deserialize(&mut legacy_data)?,
+        })
+    }
+
+    /// Loads the `FlowSlot` of `token_manager` for `epoch` from the given
+    /// account. See [`FlowSlot::from_account_data`].
+    pub(crate) fn load_for(
+        account: &AccountInfo<'_>,
+        token_manager: &Pubkey,
+        epoch: u64,
+    ) -> Result<Self, ProgramError> {
+        let account_data = account.try_borrow_data()?;
+        Self::from_account_data(token_manager, epoch, &account_data)
+    }
+
+    /// Whether the slot tracks the flow of `flow_limit` epochs. Slots created
+    /// before the epoch duration changed track windows that don't match the
+    /// current epochs anymore.
+    #[must_use]
+    pub const fn matches(&self, flow_limit: &FlowLimit) -> bool {
+        self.epoch_duration == flow_limit.epoch_duration
+    }
+
+    /// Resets the slot to track the flow of the current `flow_limit` epochs.
+    pub(crate) fn reset(&mut self, flow_limit: &FlowLimit) {
+        self.epoch_duration = flow_limit.epoch_duration;
+        self.flow_in = 0;
+        self.flow_out = 0;
+    }
+
+    /// Whether the slot can be closed at `timestamp`. The window the slot was
+    /// created for must be over, as well as the following one if the sliding
+    /// window of `flow_limit` still carries its flow.
+    ///
+    /// # Errors
+    ///
+    /// If the end of the window overflows.
+    pub fn is_closable(
+        &self,
+        flow_limit: &FlowLimit,
+        timestamp: i64,
+    ) -> Result<bool, ProgramError> {
+        let carried_epochs = if flow_limit.sliding_window && self.matches(flow_limit) {
+            2
+        } else {
+            1
+        };
+        let closes_at = self
+            .epoch
+            .checked_add(carried_epochs)
+            .and_then(|epoch| epoch.checked_mul(self.epoch_duration))
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+        let timestamp: u64 = timestamp
+            .try_into()
+            .map_err(|_err| ProgramError::ArithmeticOverflow)?;
+
+        Ok(timestamp >= closes_at)
+    }
+
     pub(crate) fn add_flow(
         &mut self,
         flow_limit: u64,
         amount: u64,
         direction: FlowDirection,
+        carried_flow: u64,
     ) -> ProgramResult {
         let (to_add, to_compare) = match direction {
             FlowDirection::In => (&mut self.flow_in, self.flow_out),
             FlowDirection::Out => (&mut self.flow_out, self.flow_in),
         };
 
-        Self::update_flow(flow_limit, to_add, to_compare, amount)
+        Self::update_flow(flow_limit, to_add, to_compare, amount, carried_flow)
+    }
+
+    /// Returns the net flow of this slot in the given direction, weighted by
+    /// the portion of the following epoch that is yet to elapse.
+    pub(crate) fn carried_flow(
+        &self,
+        direction: FlowDirection,
+        elapsed: u64,
+        epoch_duration: u64,
+    ) -> Result<u64, ProgramError> {
+        let net_flow = match direction {
+            FlowDirection::In => self.flow_in.saturating_sub(self.flow_out),
+            FlowDirection::Out => self.flow_out.saturating_sub(self.flow_in),
+        };
+        let remaining = epoch_duration.saturating_sub(elapsed);
+
+        let carried_flow = u128::from(net_flow)
+            .checked_mul(u128::from(remaining))
+            .and_then(|weighted| weighted.checked_div(u128::from(epoch_duration)))
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+
+        carried_flow
+            .try_into()
+            .map_err(|_err| ProgramError::ArithmeticOverflow)
     }
 
     fn update_flow(
@@ -53,6 +268,7 @@ impl FlowSlot {
         to_add: &mut u64,
         to_compare: u64,
         amount: u64,
+        carried_flow: u64,
     ) -> ProgramResult {
         // As the flow limit can be updated and set to 0, we need to handle the
         // case.
@@ -64,7 +280,8 @@ impl FlowSlot {
         // transferred from one chain to another within a six hours time window. Thus,
         // if the limit is 100 and 30 tokens have been transferred in one
         // direction, one could still transfer 130 tokens in the other direction
-        // within the same epoch, for instance.
+        // within the same epoch, for instance. When using a sliding window, the
+        // flow carried from the previous epoch counts towards the limit as well.
         let new_total = to_add
             .checked_add(amount)
             .ok_or(ProgramError::ArithmeticOverflow)?;
@@ -73,7 +290,11 @@ impl FlowSlot {
             .checked_add(flow_limit)
             .ok_or(ProgramError::ArithmeticOverflow)?;
 
-        if new_total > max_allowed_flow || amount > flow_limit {
+        let new_total_with_carry = new_total
+            .checked_add(carried_flow)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+
+        if new_total_with_carry > max_allowed_flow || amount > flow_limit {
             msg!("Flow limit exceeded");
             return Err(ProgramError::InvalidArgument);
         }
@@ -85,7 +306,7 @@ impl FlowSlot {
 }
 
 impl Pack for FlowSlot {
-    const LEN: usize = 2 * size_of::<u64>() + size_of::<u8>();
+    const LEN: usize = size_of::<Pubkey>() + 4 * size_of::<u64>() + size_of::<u8>();
 
     #[allow(clippy::unwrap_used)]
     fn pack_into_slice(&self, mut dst: &mut [u8]) {
@@ -114,22 +335,35 @@ pub(crate) enum FlowDirection {
     Out,
 }
 
-pub(crate) fn current_flow_epoch() -> Result<u64, ProgramError> {
-    flow_epoch_with_timestamp(Clock::get()?.unix_timestamp)
+/// Returns the current flow epoch and the seconds elapsed since it started.
+pub(crate) fn current_flow_epoch(epoch_duration: u64) -> Result<(u64, u64), ProgramError> {
+    let unix_timestamp: u64 = Clock::get()?
+        .unix_timestamp
+        .try_into()
+        .map_err(|_err| ProgramError::ArithmeticOverflow)?;
+
+    let epoch = unix_timestamp
+        .checked_div(epoch_duration)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+    let elapsed = unix_timestamp
+        .checked_rem(epoch_duration)
+        .ok_or(ProgramError::ArithmeticOverflow)?;
+
+    Ok((epoch, elapsed))
 }
 
-/// Returns the current flow epoch based on the provided clock.
+/// Returns the flow epoch for the given timestamp and epoch duration.
 ///
 /// # Errors
 ///
 /// Returns an error if conversion from clock to internal flow epoch fails.
-pub fn flow_epoch_with_timestamp(timestamp: i64) -> Result<u64, ProgramError> {
+pub fn flow_epoch_with_timestamp(timestamp: i64, epoch_duration: u64) -> Result<u64, ProgramError> {
     let unix_timestamp: u64 = timestamp
         .try_into()
         .map_err(|_err| ProgramError::ArithmeticOverflow)?;
 
     unix_timestamp
-        .checked_div(EPOCH_TIME.as_secs())
+        .checked_div(epoch_duration)
         .ok_or(ProgramError::ArithmeticOverflow)
 }
 
@@ -143,7 +377,7 @@ mod tests {
         let expected_flow_in = 0;
         let expected_flow_out = 0;
 
-        let slot = FlowSlot::new(0);
+        let slot = FlowSlot::new(Pubkey::new_unique(), 0, 600, 0);
         assert_eq!(slot.flow_in, expected_flow_in);
         assert_eq!(slot.flow_out, expected_flow_out);
     }
@@ -152,12 +386,13 @@ mod tests {
     fn test_add_flow_in_valid() {
         // Test adding flow_in within limits
         let flow_limit = 100;
-        let mut slot = FlowSlot::new(0);
-        slot.add_flow(flow_limit, 20, FlowDirection::In).unwrap();
-        slot.add_flow(flow_limit, 30, FlowDirection::Out).unwrap();
+        let mut slot = FlowSlot::new(Pubkey::new_unique(), 0, 600, 0);
+        slot.add_flow(flow_limit, 20, FlowDirection::In, 0).unwrap();
+        slot.add_flow(flow_limit, 30, FlowDirection::Out, 0)
+            .unwrap();
         let amount = 40;
 
-        let result = slot.add_flow(flow_limit, amount, FlowDirection::In);
+        let result = slot.add_flow(flow_limit, amount, FlowDirection::In, 0);
         assert!(result.is_ok());
         assert_eq!(slot.flow_in, 60);
     }
@@ -166,11 +401,11 @@ mod tests {
     fn test_add_flow_in_exceeds_limit() {
         // Test adding flow_in that exceeds limit should fail
         let flow_limit = 100;
-        let mut slot = FlowSlot::new(0);
-        slot.add_flow(flow_limit, 80, FlowDirection::In).unwrap();
+        let mut slot = FlowSlot::new(Pubkey::new_unique(), 0, 600, 0);
+        slot.add_flow(flow_limit, 80, FlowDirection::In, 0).unwrap();
         let amount = 30; // This would make flow_in 110, exceeding the limit
 
-        let result = slot.add_flow(flow_limit, amount, FlowDirection::In);
+        let result = slot.add_flow(flow_limit, amount, FlowDirection::In, 0);
         assert_eq!(result, Err(ProgramError::InvalidArgument));
         assert_eq!(slot.flow_in, 80); // Ensure flow_in hasn't changed
     }
@@ -179,12 +414,13 @@ mod tests {
     fn test_add_flow_out_valid() {
         // Test adding flow_out within limits
         let flow_limit = 100;
-        let mut slot = FlowSlot::new(0);
-        slot.add_flow(flow_limit, 30, FlowDirection::In).unwrap();
-        slot.add_flow(flow_limit, 20, FlowDirection::Out).unwrap();
+        let mut slot = FlowSlot::new(Pubkey::new_unique(), 0, 600, 0);
+        slot.add_flow(flow_limit, 30, FlowDirection::In, 0).unwrap();
+        slot.add_flow(flow_limit, 20, FlowDirection::Out, 0)
+            .unwrap();
         let amount = 50;
 
-        let result = slot.add_flow(flow_limit, amount, FlowDirection::Out);
+        let result = slot.add_flow(flow_limit, amount, FlowDirection::Out, 0);
         assert!(result.is_ok());
         assert_eq!(slot.flow_out, 70);
     }
@@ -193,11 +429,12 @@ mod tests {
     fn test_add_flow_out_exceeds_limit() {
         // Test adding flow_out that exceeds limit should fail
         let flow_limit = 100;
-        let mut slot = FlowSlot::new(0);
-        slot.add_flow(flow_limit, 90, FlowDirection::Out).unwrap();
+        let mut slot = FlowSlot::new(Pubkey::new_unique(), 0, 600, 0);
+        slot.add_flow(flow_limit, 90, FlowDirection::Out, 0)
+            .unwrap();
         let amount = 20; // This would make flow_out 110, exceeding the limit
 
-        let result = slot.add_flow(flow_limit, amount, FlowDirection::Out);
+        let result = slot.add_flow(flow_limit, amount, FlowDirection::Out, 0);
         assert_eq!(result, Err(ProgramError::InvalidArgument));
         assert_eq!(slot.flow_out, 90); // Ensure flow_out hasn't changed
     }
@@ -206,12 +443,12 @@ mod tests {
     fn test_add_flow_in_overflow() {
         // Test arithmetic overflow in add_flow_in
         let flow_limit = u64::MAX;
-        let mut slot = FlowSlot::new(0);
-        slot.add_flow(flow_limit, u64::MAX - 10, FlowDirection::In)
+        let mut slot = FlowSlot::new(Pubkey::new_unique(), 0, 600, 0);
+        slot.add_flow(flow_limit, u64::MAX - 10, FlowDirection::In, 0)
             .unwrap();
         let amount = 20;
 
-        let result = slot.add_flow(flow_limit, amount, FlowDirection::In);
+        let result = slot.add_flow(flow_limit, amount, FlowDirection::In, 0);
         assert_eq!(result, Err(ProgramError::ArithmeticOverflow));
         assert_eq!(slot.flow_in, u64::MAX - 10); // Ensure flow_in hasn't
                                                  // changed
@@ -221,12 +458,12 @@ mod tests {
     fn test_add_flow_out_overflow() {
         // Test arithmetic overflow in add_flow_out
         let flow_limit = u64::MAX;
-        let mut slot = FlowSlot::new(0);
-        slot.add_flow(flow_limit, u64::MAX - 10, FlowDirection::Out)
+        let mut slot = FlowSlot::new(Pubkey::new_unique(), 0, 600, 0);
+        slot.add_flow(flow_limit, u64::MAX - 10, FlowDirection::Out, 0)
             .unwrap();
         let amount = 20;
 
-        let result = slot.add_flow(flow_limit, amount, FlowDirection::Out);
+        let result = slot.add_flow(flow_limit, amount, FlowDirection::Out, 0);
         assert_eq!(result, Err(ProgramError::ArithmeticOverflow));
         assert_eq!(slot.flow_out, u64::MAX - 10); // Ensure flow_out hasn't
                                                   // changed
@@ -236,10 +473,10 @@ mod tests {
     fn test_add_flow_zero_flow_limit() {
         // Test behavior when flow_limit is zero in add_flow methods
         let flow_limit = 0;
-        let mut slot = FlowSlot::new(0);
+        let mut slot = FlowSlot::new(Pubkey::new_unique(), 0, 600, 0);
 
-        let result_in = slot.add_flow(flow_limit, 10, FlowDirection::In);
-        let result_out = slot.add_flow(flow_limit, 10, FlowDirection::Out);
+        let result_in = slot.add_flow(flow_limit, 10, FlowDirection::In, 0);
+        let result_out = slot.add_flow(flow_limit, 10, FlowDirection::Out, 0);
 
         // Since flow_limit is zero, the methods should return Ok without modifying
         // flow_in or flow_out
@@ -253,11 +490,11 @@ mod tests {
     fn test_add_flow_amount_exceeds_flow_limit() {
         // Test when amount exceeds flow_limit in add_flow methods
         let flow_limit = 50;
-        let mut slot = FlowSlot::new(0);
+        let mut slot = FlowSlot::new(Pubkey::new_unique(), 0, 600, 0);
         let amount = 60; // Exceeds flow_limit
 
-        let result_in = slot.add_flow(flow_limit, amount, FlowDirection::In);
-        let result_out = slot.add_flow(flow_limit, amount, FlowDirection::Out);
+        let result_in = slot.add_flow(flow_limit, amount, FlowDirection::In, 0);
+        let result_out = slot.add_flow(flow_limit, amount, FlowDirection::Out, 0);
 
         assert_eq!(result_in, Err(ProgramError::InvalidArgument));
         assert_eq!(result_out, Err(ProgramError::InvalidArgument));
@@ -269,13 +506,14 @@ mod tests {
     fn test_add_flow_new_total_exceeds_max_allowed_flow() {
         // Test when new_total exceeds max_allowed_flow in add_flow methods
         let flow_limit = 100;
-        let mut slot = FlowSlot::new(0);
-        slot.add_flow(flow_limit, 80, FlowDirection::In).unwrap();
-        slot.add_flow(flow_limit, 50, FlowDirection::Out).unwrap();
+        let mut slot = FlowSlot::new(Pubkey::new_unique(), 0, 600, 0);
+        slot.add_flow(flow_limit, 80, FlowDirection::In, 0).unwrap();
+        slot.add_flow(flow_limit, 50, FlowDirection::Out, 0)
+            .unwrap();
         let amount = 80; // This would make flow_in 160, which exceeds max_allowed_flow (flow_out +
                          // flow_limit = 150)
 
-        let result = slot.add_flow(flow_limit, amount, FlowDirection::In);
+        let result = slot.add_flow(flow_limit, amount, FlowDirection::In, 0);
 
         assert_eq!(result, Err(ProgramError::InvalidArgument));
         assert_eq!(slot.flow_in, 80); // Ensure flow_in hasn't changed
@@ -285,20 +523,21 @@ mod tests {
     fn test_add_flow_new_total_exceeds_max_allowed_flow_over_multiple_updates() {
         // Test when new_total exceeds max_allowed_flow in add_flow methods
         let flow_limit = 100;
-        let mut slot = FlowSlot::new(0);
-        slot.add_flow(flow_limit, 80, FlowDirection::In).unwrap();
-        slot.add_flow(flow_limit, 50, FlowDirection::Out).unwrap();
+        let mut slot = FlowSlot::new(Pubkey::new_unique(), 0, 600, 0);
+        slot.add_flow(flow_limit, 80, FlowDirection::In, 0).unwrap();
+        slot.add_flow(flow_limit, 50, FlowDirection::Out, 0)
+            .unwrap();
         let amount = 20; // This would make flow_in 100, which does not exceed max_allowed_flow (flow_out
                          // + flow_limit = 150)
 
-        let result = slot.add_flow(flow_limit, amount, FlowDirection::In);
+        let result = slot.add_flow(flow_limit, amount, FlowDirection::In, 0);
 
         assert!(result.is_ok());
 
         let amount = 60; // This would make flow_in 160, which exceeds max_allowed_flow (flow_out +
                          // flow_limit = 150)
 
-        let result = slot.add_flow(flow_limit, amount, FlowDirection::In);
+        let result = slot.add_flow(flow_limit, amount, FlowDirection::In, 0);
 
         assert_eq!(result, Err(ProgramError::InvalidArgument));
         assert_eq!(slot.flow_in, 100); // Ensure flow_in hasn't changed
@@ -311,19 +550,122 @@ mod tests {
         let amount = 50;
 
         // Test incoming transfer initialization
-        let mut slot_in = FlowSlot::new(0);
+        let mut slot_in = FlowSlot::new(Pubkey::new_unique(), 0, 600, 0);
         slot_in
-            .add_flow(flow_limit, amount, FlowDirection::In)
+            .add_flow(flow_limit, amount, FlowDirection::In, 0)
             .unwrap();
         assert_eq!(slot_in.flow_in, amount);
         assert_eq!(slot_in.flow_out, 0);
 
         // Test outgoing transfer initialization
-        let mut slot_out = FlowSlot::new(0);
+        let mut slot_out = FlowSlot::new(Pubkey::new_unique(), 0, 600, 0);
         slot_out
-            .add_flow(flow_limit, amount, FlowDirection::Out)
+            .add_flow(flow_limit, amount, FlowDirection::Out, 0)
             .unwrap();
         assert_eq!(slot_out.flow_in, 0);
         assert_eq!(slot_out.flow_out, amount);
     }
+
+    #[test]
+    fn test_carried_flow_is_weighted_by_remaining_epoch_time() {
+        let flow_limit = 100;
+        let epoch_duration = 600;
+        let mut previous_slot = FlowSlot::new(Pubkey::new_unique(), 0, 600, 0);
+        previous_slot
+            .add_flow(flow_limit, 90, FlowDirection::In, 0)
+            .unwrap();
+        previous_slot
+            .add_flow(flow_limit, 30, FlowDirection::Out, 0)
+            .unwrap();
+
+        // Net inbound flow of 60, of which 2/3 is carried 200 seconds into the
+        // following epoch.
+        let carried = previous_slot
+            .carried_flow(FlowDirection::In, 200, epoch_duration)
+            .unwrap();
+        assert_eq!(carried, 40);
+
+        // There's no net outbound flow to carry.
+        let carried = previous_slot
+            .carried_flow(FlowDirection::Out, 200, epoch_duration)
+            .unwrap();
+        assert_eq!(carried, 0);
+
+        // Nothing is carried once the epoch elapses.
+        let carried = previous_slot
+            .carried_flow(FlowDirection::In, epoch_duration, epoch_duration)
+            .unwrap();
+        assert_eq!(carried, 0);
+    }
+
+    #[test]
+    fn test_add_flow_with_carried_flow_exceeds_limit() {
+        let flow_limit = 100;
+        let mut slot = FlowSlot::new(Pubkey::new_unique(), 0, 600, 0);
+
+        let result = slot.add_flow(flow_limit, 70, FlowDirection::In, 40);
+        assert_eq!(result, Err(ProgramError::InvalidArgument));
+        assert_eq!(slot.flow_in, 0);
+
+        slot.add_flow(flow_limit, 60, FlowDirection::In, 40)
+            .unwrap();
+        assert_eq!(slot.flow_in, 60);
+    }
+
+    #[test]
+    fn test_flow_limit_configuration() {
+        let flow_limit = FlowLimit::new(100, 200);
+        assert_eq!(flow_limit.limit(FlowDirection::In), 100);
+        assert_eq!(flow_limit.limit(FlowDirection::Out), 200);
+        assert_eq!(flow_limit.epoch_duration, DEFAULT_EPOCH_TIME.as_secs());
+        assert!(!flow_limit.sliding_window);
+        assert!(flow_limit.validate().is_ok());
+
+        let flow_limit = flow_limit
+            .with_epoch_duration(3600)
+            .with_sliding_window(true);
+        assert_eq!(flow_limit.flow_epoch(7200).unwrap(), 2);
+        assert!(flow_limit.sliding_window);
+
+        let flow_limit = flow_limit.with_epoch_duration(0);
+        assert_eq!(flow_limit.validate(), Err(ProgramError::InvalidArgument));
+    }
+
+    #[test]
+    fn test_legacy_flow_slot_is_loaded() {
+        let token_manager = Pubkey::new_unique();
+        let mut legacy_data = 30_u64.to_le_bytes().to_vec();
+        legacy_data.extend_from_slice(&20_u64.to_le_bytes());
+        legacy_data.push(253);
+        assert_eq!(legacy_data.len(), FlowSlot::LEGACY_LEN);
+
+        let flow_slot = FlowSlot::from_account_data(&token_manager, 7, &legacy_data).unwrap();
+
+        let mut expected = FlowSlot::new(token_manager, 7, DEFAULT_EPOCH_TIME.as_secs(), 253);
+        expected.flow_in = 30;
+        expected.flow_out = 20;
+        assert_eq!(flow_slot, expected);
+    }
+
+    #[test]
+    fn test_flow_slot_is_closable_once_its_window_is_over() {
+        let flow_limit = FlowLimit::new(100, 100).with_epoch_duration(600);
+        let slot = FlowSlot::new(Pubkey::new_unique(), 10, 600, 0);
+
+        assert!(!slot.is_closable(&flow_limit, 6_000).unwrap());
+        assert!(!slot.is_closable(&flow_limit, 6_599).unwrap());
+        assert!(slot.is_closable(&flow_limit, 6_600).unwrap());
+
+        // The sliding window carries its flow into the following epoch.
+        let sliding = flow_limit.with_sliding_window(true);
+        assert!(!slot.is_closable(&sliding, 6_600).unwrap());
+        assert!(slot.is_closable(&sliding, 7_200).unwrap());
+
+        // Once the epochs are longer, the slot isn't carried anymore, even
+        // though its epoch number is past the current one.
+        let longer = sliding.with_epoch_duration(86_400);
+        assert!(!slot.matches(&longer));
+        assert!(longer.flow_epoch(6_600).unwrap() < slot.epoch);
+        assert!(slot.is_closable(&longer, 6_600).unwrap());
+    }
 }
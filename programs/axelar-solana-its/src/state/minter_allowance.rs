@@ -0,0 +1,168 @@
+//! Module with data structure definition for the mint allowances of minters of
+//! interchain tokens.
+
+use borsh::{BorshDeserialize, BorshSerialize};
+use program_utils::pda::BorshPda;
+use solana_program::entrypoint::ProgramResult;
+use solana_program::msg;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+
+/// Limits the amount of tokens a minter can mint through
+/// `MintInterchainToken`. Minters without a `MinterAllowance` PDA are not
+/// limited.
+#[derive(Debug, Eq, PartialEq, Clone, BorshSerialize, BorshDeserialize)]
+pub struct MinterAllowance {
+    /// The `TokenManager` PDA the allowance applies to.
+    pub token_manager: Pubkey,
+
+    /// The minter the allowance applies to.
+    pub minter: Pubkey,
+
+    /// The amount of tokens the minter can mint within a refill period.
+    pub allowance: u64,
+
+    /// The duration of a refill period, in seconds. Zero means the allowance
+    /// is never refilled.
+    pub refill_period: u64,
+
+    /// The amount of tokens minted within the current refill period.
+    pub minted: u64,
+
+    /// Unix timestamp of the start of the current refill period.
+    pub period_start: i64,
+
+    /// Bump used to derive the minter allowance PDA.
+    pub bump: u8,
+}
+
+impl MinterAllowance {
+    /// Creates a new `MinterAllowance` with its refill period starting at the
+    /// given timestamp.
+    #[must_use]
+    pub const fn new(
+        token_manager: Pubkey,
+        minter: Pubkey,
+        allowance: u64,
+        refill_period: u64,
+        period_start: i64,
+        bump: u8,
+    ) -> Self {
+        Self {
+            token_manager,
+            minter,
+            allowance,
+            refill_period,
+            minted: 0,
+            period_start,
+            bump,
+        }
+    }
+
+    /// Returns the amount of tokens that can still be minted at the given
+    /// timestamp.
+    ///
+    /// # Errors
+    ///
+    /// If the timestamp is before the start of the current refill period.
+    pub fn remaining(&self, timestamp: i64) -> Result<u64, ProgramError> {
+        let minted = if self.period_elapsed(timestamp)? {
+            0
+        } else {
+            self.minted
+        };
+
+        Ok(self.allowance.saturating_sub(minted))
+    }
+
+    /// Accounts the given amount against the allowance, refilling it first if
+    /// the refill period has elapsed.
+    pub(crate) fn consume(&mut self, amount: u64, timestamp: i64) -> ProgramResult {
+        if self.period_elapsed(timestamp)? {
+            let elapsed = timestamp
+                .checked_sub(self.period_start)
+                .and_then(|elapsed| u64::try_from(elapsed).ok())
+                .ok_or(ProgramError::ArithmeticOverflow)?;
+            let into_period = elapsed
+                .checked_rem(self.refill_period)
+                .and_then(|into_period| i64::try_from(into_period).ok())
+                .ok_or(ProgramError::ArithmeticOverflow)?;
+
+            self.period_start = timestamp
+                .checked_sub(into_period)
+                .ok_or(ProgramError::ArithmeticOverflow)?;
+            self.minted = 0;
+        }
+
+        let minted = self
+            .minted
+            .checked_add(amount)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+
+        if minted > self.allowance {
+            msg!("Minter allowance exceeded");
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        self.minted = minted;
+
+        Ok(())
+    }
+
+    fn period_elapsed(&self, timestamp: i64) -> Result<bool, ProgramError> {
+        if self.refill_period == 0 {
+            return Ok(false);
+        }
+
+        let elapsed = timestamp
+            .checked_sub(self.period_start)
+            .and_then(|elapsed| u64::try_from(elapsed).ok())
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+
+        Ok(elapsed >= self.refill_period)
+    }
+}
+
+impl BorshPda for MinterAllowance {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_allowance_is_refilled_after_period() {
+        let mut allowance = MinterAllowance::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            100,
+            60,
+            1_000,
+            255,
+        );
+
+        allowance.consume(70, 1_010).unwrap();
+        assert_eq!(allowance.remaining(1_010).unwrap(), 30);
+        assert!(allowance.consume(31, 1_059).is_err());
+
+        allowance.consume(100, 1_130).unwrap();
+        assert_eq!(allowance.period_start, 1_120);
+        assert_eq!(allowance.remaining(1_179).unwrap(), 0);
+        assert_eq!(allowance.remaining(1_180).unwrap(), 100);
+    }
+
+    #[test]
+    fn test_allowance_without_refill_period() {
+        let mut allowance = MinterAllowance::new(
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            100,
+            0,
+            1_000,
+            255,
+        );
+
+        allowance.consume(100, 1_000).unwrap();
+        assert!(allowance.consume(1, i64::MAX).is_err());
+        assert_eq!(allowance.remaining(i64::MAX).unwrap(), 0);
+    }
+}
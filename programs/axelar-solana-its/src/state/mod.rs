@@ -1,14 +1,20 @@
 //! State module contains data structures that keep state within the ITS
 //! program.
 
+use core::any::type_name;
 use std::collections::HashSet;
 
 use borsh::{BorshDeserialize, BorshSerialize};
 use program_utils::pda::BorshPda;
+use solana_program::account_info::AccountInfo;
+use solana_program::msg;
+use solana_program::program_error::ProgramError;
 
 pub mod deploy_approval;
 pub mod flow_limit;
+pub mod minter_allowance;
 pub mod token_manager;
+pub mod trusted_chain;
 
 /// Struct containing state of the ITS program.
 #[derive(Debug, Eq, PartialEq, Clone, BorshSerialize, BorshDeserialize)]
@@ -21,9 +27,6 @@ pub struct InterchainTokenService {
     /// Whether the ITS is paused.
     pub paused: bool,
 
-    /// Trusted chains
-    pub trusted_chains: HashSet<String>,
-
     /// Bump used to derive the ITS PDA.
     pub bump: u8,
 }
@@ -31,12 +34,11 @@ pub struct InterchainTokenService {
 impl InterchainTokenService {
     /// Create a new `InterchainTokenService` instance.
     #[must_use]
-    pub fn new(bump: u8, chain_name: String, its_hub_address: String) -> Self {
+    pub const fn new(bump: u8, chain_name: String, its_hub_address: String) -> Self {
         Self {
             its_hub_address,
             chain_name,
             paused: false,
-            trusted_chains: HashSet::new(),
             bump,
         }
     }
@@ -57,21 +59,106 @@ impl InterchainTokenService {
         self.bump
     }
 
-    /// Add a chain as trusted
-    pub fn add_trusted_chain(&mut self, chain_id: String) {
-        self.trusted_chains.insert(chain_id);
+    /// Deserializes the `InterchainTokenService` stored in the ITS root
+    /// account.
+    ///
+    /// Accounts still holding the trusted chains, as stored by older versions
+    /// of the program, are converted so that ITS keeps working until they are
+    /// migrated. The trusted chains are left out, see
+    /// [`crate::instruction::InterchainTokenServiceInstruction::MigrateTrustedChains`].
+    ///
+    /// # Errors
+    ///
+    /// If the data cannot be deserialized in either layout.
+    pub fn from_account_data(data: &[u8]) -> Result<Self, ProgramError> {
+        if let Ok(its_root_config) = Self::try_from_slice(data) {
+            return Ok(its_root_config);
+        }
+
+        Ok(LegacyInterchainTokenService::try_from_slice(data)?.into_current())
     }
+}
 
-    /// Remove a chain from trusted
-    pub fn remove_trusted_chain(&mut self, chain_id: &str) {
-        self.trusted_chains.remove(chain_id);
+impl BorshPda for InterchainTokenService {
+    fn load(source_account: &AccountInfo<'_>) -> Result<Self, ProgramError> {
+        let account_data = source_account.try_borrow_data()?;
+        Self::from_account_data(&account_data).inspect_err(|err| {
+            msg!(
+                "Warning: failed to deserialize account as {}: {}. The account might not have been initialized.",
+                type_name::<Self>(),
+                err,
+            );
+        })
     }
+}
 
-    /// Checks whether or not a given chain is trusted
-    #[must_use]
-    pub fn is_trusted_chain(&self, chain_id: &str) -> bool {
-        self.trusted_chains.contains(chain_id)
+/// Layout of the ITS root account used by versions of the program that kept
+/// the trusted chains within it. Only used to migrate existing deployments.
+#[derive(Debug, Eq, PartialEq, Clone, BorshSerialize, BorshDeserialize)]
+pub(crate) struct LegacyInterchainTokenService {
+    pub(crate) its_hub_address: String,
+    pub(crate) chain_name: String,
+    pub(crate) paused: bool,
+    pub(crate) trusted_chains: HashSet<String>,
+    pub(crate) bump: u8,
+}
+
+impl LegacyInterchainTokenService {
+    /// Converts into the current layout, dropping the trusted chains.
+    pub(crate) fn into_current(self) -> InterchainTokenService {
+        InterchainTokenService {
+            its_hub_address: self.its_hub_address,
+            chain_name: self.chain_name,
+            paused: self.paused,
+            bump: self.bump,
+        }
     }
+
+    /// Deserializes the ITS root account if it still holds trusted chains
+    /// pending migration.
+    pub(crate) fn try_load(source_account: &AccountInfo<'_>) -> Option<Self> {
+        let account_data = source_account.try_borrow_data().ok()?;
+        Self::try_from_slice(&account_data).ok()
+    }
+}
+
+impl BorshPda for LegacyInterchainTokenService {}
+
+/// Where the metadata (name, symbol and URI) of an interchain token is stored.
+#[derive(Debug, Default, Eq, PartialEq, Clone, Copy, BorshSerialize, BorshDeserialize)]
+pub enum MetadataStandard {
+    /// A Metaplex metadata account associated with the mint.
+    #[default]
+    Metaplex,
+
+    /// The Token-2022 `TokenMetadata` extension on the mint itself, which the `MetadataPointer`
+    /// extension of the mint points to.
+    Token2022,
 }
 
-impl BorshPda for InterchainTokenService {}
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_legacy_its_root_config_is_loaded() {
+        let legacy = LegacyInterchainTokenService {
+            its_hub_address: "hub".to_owned(),
+            chain_name: "solana".to_owned(),
+            paused: true,
+            trusted_chains: HashSet::from(["ethereum".to_owned()]),
+            bump: 254,
+        };
+        let legacy_data = borsh::to_vec(&legacy).unwrap();
+
+        let its_root_config = InterchainTokenService::from_account_data(&legacy_data).unwrap();
+        assert_eq!(its_root_config, legacy.into_current());
+
+        let current_data = borsh::to_vec(&its_root_config).unwrap();
+        assert_eq!(
+            InterchainTokenService::from_account_data(&current_data).unwrap(),
+            its_root_config
+        );
+        assert!(LegacyInterchainTokenService::try_from_slice(&current_data).is_err());
+    }
+}
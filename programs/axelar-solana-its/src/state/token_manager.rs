@@ -7,12 +7,16 @@ use core::mem::size_of;
 use alloy_primitives::{Bytes, FixedBytes, U256};
 use alloy_sol_types::SolValue;
 use borsh::{BorshDeserialize, BorshSerialize};
-use program_utils::pda::BorshPda;
+use program_utils::pda::{deserialize_or_default, BorshPda};
+use solana_program::account_info::AccountInfo;
+use solana_program::entrypoint::ProgramResult;
 use solana_program::msg;
 use solana_program::program_error::ProgramError;
 use solana_program::program_pack::{Pack, Sealed};
 use solana_program::pubkey::Pubkey;
 
+use crate::state::flow_limit::FlowLimit;
+
 /// There are different types of token managers available for developers to
 /// offer different types of integrations to ITS.
 ///
@@ -138,7 +142,7 @@ impl TryFrom<u8> for Type {
 }
 
 /// Struct containing state of a `TokenManager`
-#[derive(Debug, Eq, PartialEq, Clone, BorshSerialize, BorshDeserialize)]
+#[derive(Debug, Eq, PartialEq, Clone, BorshSerialize)]
 pub struct TokenManager {
     /// The type of `TokenManager`.
     pub ty: Type,
@@ -152,11 +156,25 @@ pub struct TokenManager {
     /// The associated token account owned by the token manager.
     pub associated_token_account: Pubkey,
 
-    /// The flow limit for the token manager
-    pub flow_limit: u64,
+    /// The account that paid for the deployment of the token manager. Rent
+    /// reclaimed from accounts tied to the token manager is refunded to it.
+    pub payer: Pubkey,
+
+    /// The flow limit configuration for the token manager
+    pub flow_limit: FlowLimit,
 
     /// The token manager PDA bump seed.
     pub bump: u8,
+
+    /// The maximum supply of the token that can be reached by minting through
+    /// ITS. Zero means no cap.
+    pub supply_cap: u64,
+
+    /// The chain the interchain token was deployed from, the only one whose
+    /// metadata updates are accepted. `None` for tokens deployed on Solana,
+    /// token managers not backing a remotely deployed interchain token and
+    /// those created before the origin chain was recorded.
+    pub origin_chain: Option<String>,
 }
 
 impl TokenManager {
@@ -167,6 +185,7 @@ impl TokenManager {
         token_id: [u8; 32],
         token_address: Pubkey,
         associated_token_account: Pubkey,
+        payer: Pubkey,
         bump: u8,
     ) -> Self {
         Self {
@@ -174,17 +193,129 @@ impl TokenManager {
             token_id,
             token_address,
             associated_token_account,
-            flow_limit: 0,
+            payer,
+            flow_limit: FlowLimit::new(0, 0),
             bump,
+            supply_cap: 0,
+            origin_chain: None,
+        }
+    }
+
+    /// Checks that minting the given amount on top of the current supply
+    /// doesn't exceed the supply cap.
+    ///
+    /// # Errors
+    ///
+    /// If the supply cap would be exceeded.
+    pub fn ensure_within_supply_cap(&self, current_supply: u64, amount: u64) -> ProgramResult {
+        if self.supply_cap == 0 {
+            return Ok(());
+        }
+
+        let new_supply = current_supply
+            .checked_add(amount)
+            .ok_or(ProgramError::ArithmeticOverflow)?;
+
+        if new_supply > self.supply_cap {
+            msg!("Supply cap exceeded");
+            return Err(ProgramError::InvalidArgument);
+        }
+
+        Ok(())
+    }
+}
+
+/// Layout of the `TokenManager` accounts created before flow limits were
+/// configurable.
+#[derive(BorshDeserialize)]
+struct LegacyTokenManager {
+    ty: Type,
+    token_id: [u8; 32],
+    token_address: Pubkey,
+    associated_token_account: Pubkey,
+    flow_limit: u64,
+    bump: u8,
+}
+
+impl TokenManager {
+    /// The length of the `TokenManager` accounts created before flow limits
+    /// were configurable.
+    pub const LEGACY_LEN: usize = size_of::<Type>()
+        + size_of::<[u8; 32]>()
+        + size_of::<Pubkey>()
+        + size_of::<Pubkey>()
+        + size_of::<u64>()
+        + size_of::<u8>();
+
+    /// Deserializes the `TokenManager` stored in the account at `address`.
+    ///
+    /// Accounts created before flow limits were configurable are told apart
+    /// by their length and converted: their flow limit applies in both
+    /// directions and, as they have no payer of record, the token manager
+    /// itself is used as such. They are rewritten in the current layout the
+    /// next time they are stored.
+    ///
+    /// # Errors
+    ///
+    /// If the data cannot be deserialized.
+    pub fn from_account_data(address: &Pubkey, data: &[u8]) -> Result<Self, ProgramError> {
+        if data.len() != Self::LEGACY_LEN {
+            return Ok(Self::try_from_slice(data)?);
         }
+
+        let legacy = LegacyTokenManager::try_from_slice(data)?;
+
+        Ok(Self {
+            ty: legacy.ty,
+            token_id: legacy.token_id,
+            token_address: legacy.token_address,
+            associated_token_account: legacy.associated_token_account,
+            payer: *address,
+            flow_limit: FlowLimit::new(legacy.flow_limit, legacy.flow_limit),
+            bump: legacy.bump,
+            supply_cap: 0,
+            origin_chain: None,
+        })
+    }
+}
+
+impl BorshDeserialize for TokenManager {
+    fn deserialize_reader<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        let ty = Type::deserialize_reader(reader)?;
+        let token_id = <[u8; 32]>::deserialize_reader(reader)?;
+        let token_address = Pubkey::deserialize_reader(reader)?;
+        let associated_token_account = Pubkey::deserialize_reader(reader)?;
+        let payer = Pubkey::deserialize_reader(reader)?;
+        let flow_limit = FlowLimit::deserialize_reader(reader)?;
+        let bump = u8::deserialize_reader(reader)?;
+        // Accounts created before supply caps were introduced end here.
+        let supply_cap = deserialize_or_default(reader)?;
+        // Accounts created before the origin chain was recorded end here.
+        let origin_chain = deserialize_or_default(reader)?;
+
+        Ok(Self {
+            ty,
+            token_id,
+            token_address,
+            associated_token_account,
+            payer,
+            flow_limit,
+            bump,
+            supply_cap,
+            origin_chain,
+        })
     }
 }
 
 impl Pack for TokenManager {
+    /// The length of a `TokenManager` without an origin chain.
     const LEN: usize = size_of::<Type>()
         + size_of::<[u8; 32]>()
         + size_of::<Pubkey>()
         + size_of::<Pubkey>()
+        + size_of::<Pubkey>()
+        + FlowLimit::LEN
+        + size_of::<u8>()
         + size_of::<u64>()
         + size_of::<u8>();
 
@@ -207,7 +338,18 @@ impl Pack for TokenManager {
 }
 
 impl Sealed for TokenManager {}
-impl BorshPda for TokenManager {}
+impl BorshPda for TokenManager {
+    fn load(source_account: &AccountInfo<'_>) -> Result<Self, ProgramError> {
+        let account_data = source_account.try_borrow_data()?;
+        Self::from_account_data(source_account.key, &account_data).inspect_err(|err| {
+            msg!(
+                "Warning: failed to deserialize account as {}: {}. The account might not have been initialized.",
+                type_name::<Self>(),
+                err,
+            );
+        })
+    }
+}
 
 /// Decodes the operator and token address from the given data.
 ///
@@ -273,6 +415,8 @@ pub fn encode_params(
 
 #[cfg(test)]
 mod tests {
+    use core::mem::size_of;
+
     use solana_program::pubkey::Pubkey;
 
     #[test]
@@ -288,4 +432,115 @@ mod tests {
         assert_eq!(Some(mint_authority), decoded_mint_authority);
         assert_eq!(token_address, decoded_token_address);
     }
+
+    #[test]
+    fn test_legacy_token_manager_is_loaded() {
+        let token_manager_pda = Pubkey::new_unique();
+        let token_address = Pubkey::new_unique();
+        let associated_token_account = Pubkey::new_unique();
+
+        let mut legacy_data = vec![u8::from(super::Type::LockUnlock)];
+        legacy_data.extend_from_slice(&[1; 32]);
+        legacy_data.extend_from_slice(token_address.as_ref());
+        legacy_data.extend_from_slice(associated_token_account.as_ref());
+        legacy_data.extend_from_slice(&500_u64.to_le_bytes());
+        legacy_data.push(254);
+        assert_eq!(legacy_data.len(), super::TokenManager::LEGACY_LEN);
+
+        let token_manager =
+            super::TokenManager::from_account_data(&token_manager_pda, &legacy_data).unwrap();
+
+        let mut expected = super::TokenManager::new(
+            super::Type::LockUnlock,
+            [1; 32],
+            token_address,
+            associated_token_account,
+            token_manager_pda,
+            254,
+        );
+        expected.flow_limit = super::FlowLimit::new(500, 500);
+        assert_eq!(token_manager, expected);
+
+        let current_data = borsh::to_vec(&expected).unwrap();
+        assert_eq!(
+            super::TokenManager::from_account_data(&token_manager_pda, &current_data).unwrap(),
+            expected
+        );
+    }
+
+    #[test]
+    fn test_token_manager_without_supply_cap_is_loaded() {
+        let token_manager_pda = Pubkey::new_unique();
+        let mut token_manager = super::TokenManager::new(
+            super::Type::NativeInterchainToken,
+            [1; 32],
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            255,
+        );
+        token_manager.flow_limit = super::FlowLimit::new(10, 20);
+
+        let mut data = borsh::to_vec(&token_manager).unwrap();
+        data.truncate(data.len() - size_of::<u64>() - size_of::<u8>());
+        assert_eq!(
+            super::TokenManager::from_account_data(&token_manager_pda, &data).unwrap(),
+            token_manager
+        );
+
+        token_manager.supply_cap = 1_000;
+        let data = borsh::to_vec(&token_manager).unwrap();
+        assert_eq!(
+            super::TokenManager::from_account_data(&token_manager_pda, &data).unwrap(),
+            token_manager
+        );
+    }
+
+    #[test]
+    fn test_token_manager_without_origin_chain_is_loaded() {
+        let token_manager_pda = Pubkey::new_unique();
+        let mut token_manager = super::TokenManager::new(
+            super::Type::NativeInterchainToken,
+            [1; 32],
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            255,
+        );
+
+        let mut data = borsh::to_vec(&token_manager).unwrap();
+        data.pop();
+        assert_eq!(data.len(), <super::TokenManager as super::Pack>::LEN - 1);
+        assert_eq!(
+            super::TokenManager::from_account_data(&token_manager_pda, &data).unwrap(),
+            token_manager
+        );
+
+        token_manager.origin_chain = Some("ethereum".to_owned());
+        let data = borsh::to_vec(&token_manager).unwrap();
+        assert_eq!(
+            super::TokenManager::from_account_data(&token_manager_pda, &data).unwrap(),
+            token_manager
+        );
+    }
+
+    #[test]
+    fn test_supply_cap() {
+        let mut token_manager = super::TokenManager::new(
+            super::Type::NativeInterchainToken,
+            [1; 32],
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            255,
+        );
+        assert!(token_manager
+            .ensure_within_supply_cap(u64::MAX - 1, 1)
+            .is_ok());
+
+        token_manager.supply_cap = 1_000;
+        assert!(token_manager.ensure_within_supply_cap(900, 100).is_ok());
+        assert!(token_manager.ensure_within_supply_cap(900, 101).is_err());
+        assert!(token_manager.ensure_within_supply_cap(u64::MAX, 1).is_err());
+    }
 }
@@ -0,0 +1,108 @@
+//! Module with data structure definition for the per-chain trust configuration.
+use borsh::{BorshDeserialize, BorshSerialize};
+use program_utils::pda::BorshPda;
+
+/// How decimals of tokens deployed to a trusted chain are handled.
+#[derive(Debug, Default, Eq, PartialEq, Clone, Copy, BorshSerialize, BorshDeserialize)]
+pub enum DecimalsPolicy {
+    /// Tokens are deployed with the same decimals they have on Solana.
+    #[default]
+    Preserve,
+
+    /// Deployments of tokens with more decimals than the given maximum are
+    /// rejected.
+    Max(u8),
+}
+
+impl DecimalsPolicy {
+    /// Whether a token with the given decimals can be deployed under this
+    /// policy.
+    #[must_use]
+    pub const fn allows(&self, decimals: u8) -> bool {
+        match *self {
+            Self::Preserve => true,
+            Self::Max(max_decimals) => decimals <= max_decimals,
+        }
+    }
+}
+
+/// Struct containing the state of a chain trusted by the ITS program.
+#[derive(Debug, Eq, PartialEq, Clone, BorshSerialize, BorshDeserialize)]
+pub struct TrustedChain {
+    /// Name of the trusted chain.
+    pub chain_name: String,
+
+    /// The address of the ITS contract on the trusted chain.
+    pub its_address: String,
+
+    /// How decimals of tokens deployed to this chain are handled.
+    pub decimals_policy: DecimalsPolicy,
+
+    /// Whether communication with this chain is paused.
+    pub paused: bool,
+
+    /// Unix timestamp of when the chain was added as trusted.
+    pub added_at: i64,
+
+    /// Bump used to derive the trusted chain PDA.
+    pub bump: u8,
+}
+
+impl TrustedChain {
+    /// Create a new `TrustedChain` instance.
+    #[must_use]
+    pub const fn new(
+        chain_name: String,
+        its_address: String,
+        decimals_policy: DecimalsPolicy,
+        added_at: i64,
+        bump: u8,
+    ) -> Self {
+        Self {
+            chain_name,
+            its_address,
+            decimals_policy,
+            paused: false,
+            added_at,
+            bump,
+        }
+    }
+
+    /// Returns the bump used to derive the trusted chain PDA.
+    #[must_use]
+    pub const fn bump(&self) -> u8 {
+        self.bump
+    }
+}
+
+impl BorshPda for TrustedChain {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_decimals_policy() {
+        assert!(DecimalsPolicy::Preserve.allows(u8::MAX));
+        assert!(DecimalsPolicy::Max(9).allows(6));
+        assert!(DecimalsPolicy::Max(9).allows(9));
+        assert!(!DecimalsPolicy::Max(9).allows(18));
+    }
+
+    #[test]
+    fn test_trusted_chain_borsh_roundtrip() {
+        let trusted_chain = TrustedChain::new(
+            "ethereum".to_owned(),
+            "0x1234".to_owned(),
+            DecimalsPolicy::Max(18),
+            1_700_000_000,
+            254,
+        );
+
+        let serialized = borsh::to_vec(&trusted_chain).unwrap();
+        let deserialized = TrustedChain::try_from_slice(&serialized).unwrap();
+
+        assert_eq!(trusted_chain, deserialized);
+        assert!(!deserialized.paused);
+    }
+}
@@ -1,8 +1,12 @@
 use anyhow::anyhow;
 use axelar_solana_its::instruction::InterchainTokenServiceInstruction;
+use axelar_solana_its::state::MetadataStandard;
 use borsh::to_vec;
 use event_utils::Event as _;
+use mpl_token_metadata::accounts::Metadata;
+use solana_program::clock::Clock;
 use solana_program::instruction::{AccountMeta, Instruction};
+use solana_program::pubkey::Pubkey;
 use solana_program::system_program;
 use solana_program_test::tokio;
 use solana_sdk::program_pack::Pack;
@@ -10,6 +14,10 @@ use solana_sdk::signature::Keypair;
 use solana_sdk::signer::Signer;
 use solana_sdk::system_instruction;
 use spl_associated_token_account::get_associated_token_address_with_program_id;
+use spl_token_2022::extension::metadata_pointer::MetadataPointer;
+use spl_token_2022::extension::{BaseStateWithExtensions, StateWithExtensions};
+use spl_token_2022::state::Mint;
+use spl_token_metadata_interface::state::TokenMetadata;
 use test_context::test_context;
 
 use axelar_solana_gateway_test_fixtures::base::FindLog;
@@ -124,6 +132,7 @@ async fn test_deploy_interchain_token_with_minter_but_no_initial_supply(
 
     let mint_amount = 500u64;
     let mint_ix = axelar_solana_its::instruction::interchain_token::mint(
+        ctx.solana_wallet,
         token_id,
         interchain_token_pda,
         payer_ata,
@@ -281,6 +290,7 @@ async fn test_deploy_interchain_token_with_no_minter_but_initial_supply(
     );
 
     let mint_ix = axelar_solana_its::instruction::interchain_token::mint(
+        ctx.solana_wallet,
         token_id,
         interchain_token_pda,
         payer_ata,
@@ -355,6 +365,7 @@ async fn test_prevent_deploy_approval_bypass(ctx: &mut ItsTestContext) -> anyhow
             token_a_salt,
             destination_chain.to_string(),
             destination_minter.clone(),
+            None,
         )?;
 
     ctx.send_solana_tx(&[approve_deploy_a_ix]).await.unwrap();
@@ -446,6 +457,92 @@ async fn test_prevent_deploy_approval_bypass(ctx: &mut ItsTestContext) -> anyhow
     Ok(())
 }
 
+#[test_context(ItsTestContext)]
+#[tokio::test]
+async fn test_close_expired_deploy_approval(ctx: &mut ItsTestContext) -> anyhow::Result<()> {
+    let salt = solana_sdk::keccak::hash(b"ExpiringApprovalToken").0;
+    let destination_chain = "ethereum";
+
+    let deploy_local_ix = axelar_solana_its::instruction::deploy_interchain_token(
+        ctx.solana_wallet,
+        salt,
+        "Expiring Approval Token".to_owned(),
+        "EAT".to_owned(),
+        9,
+        0,
+        Some(ctx.solana_wallet),
+    )?;
+
+    ctx.send_solana_tx(&[deploy_local_ix])
+        .await
+        .expect("InterchainToken deployment failed");
+
+    let token_id = axelar_solana_its::interchain_token_id(&ctx.solana_wallet, &salt);
+    let clock = ctx.solana_chain.get_sysvar::<Clock>().await;
+
+    let approve_ix = axelar_solana_its::instruction::approve_deploy_remote_interchain_token(
+        ctx.solana_wallet,
+        ctx.solana_wallet,
+        salt,
+        destination_chain.to_string(),
+        vec![1, 2, 3, 4],
+        Some(clock.unix_timestamp + 60),
+    )?;
+
+    let tx = ctx.send_solana_tx(&[approve_ix]).await.unwrap();
+    let approval_event = tx
+        .metadata
+        .unwrap()
+        .log_messages
+        .iter()
+        .find_map(|log| {
+            axelar_solana_its::event::DeployRemoteInterchainTokenApproval::try_from_log(log).ok()
+        })
+        .unwrap();
+    assert_eq!(approval_event.expires_at, Some(clock.unix_timestamp + 60));
+
+    let close_ix = axelar_solana_its::instruction::close_expired_deploy_approval(
+        ctx.solana_wallet,
+        token_id,
+        destination_chain.to_string(),
+    )?;
+
+    assert!(ctx
+        .send_solana_tx(&[close_ix.clone()])
+        .await
+        .unwrap_err()
+        .find_log("Deploy approval hasn't expired")
+        .is_some());
+
+    ctx.solana_chain.fixture.forward_time(60).await;
+
+    let tx = ctx.send_solana_tx(&[close_ix]).await.unwrap();
+    let expired_event = tx
+        .metadata
+        .unwrap()
+        .log_messages
+        .iter()
+        .find_map(|log| {
+            axelar_solana_its::event::RemoteInterchainTokenApprovalExpired::try_from_log(log).ok()
+        })
+        .unwrap();
+    assert_eq!(expired_event.token_id, token_id);
+    assert_eq!(expired_event.destination_chain, destination_chain);
+
+    let (approval_pda, _) = axelar_solana_its::find_deployment_approval_pda(
+        &ctx.solana_wallet,
+        &token_id,
+        destination_chain,
+    );
+    assert!(ctx
+        .solana_chain
+        .try_get_account_no_checks(&approval_pda)
+        .await?
+        .is_none());
+
+    Ok(())
+}
+
 #[test_context(ItsTestContext)]
 #[tokio::test]
 async fn test_prevent_deploy_approval_created_by_anyone(
@@ -522,6 +619,7 @@ async fn test_prevent_deploy_approval_created_by_anyone(
             salt: token_b_salt,
             destination_chain: destination_chain.to_string(),
             destination_minter,
+            expires_at: None,
         },
     )?;
 
@@ -552,3 +650,86 @@ async fn test_prevent_deploy_approval_created_by_anyone(
     );
     Ok(())
 }
+
+#[test_context(ItsTestContext)]
+#[tokio::test]
+async fn test_deploy_interchain_token_with_token_2022_metadata(
+    ctx: &mut ItsTestContext,
+) -> anyhow::Result<()> {
+    let (its_root_pda, _) = axelar_solana_its::find_its_root_pda();
+    let salt = solana_sdk::keccak::hash(b"Token2022MetadataToken").0;
+    let token_id = axelar_solana_its::interchain_token_id(&ctx.solana_wallet, &salt);
+    let (interchain_token_pda, _) =
+        axelar_solana_its::find_interchain_token_pda(&its_root_pda, &token_id);
+
+    let deploy_local_ix =
+        axelar_solana_its::instruction::deploy_interchain_token_with_metadata_standard(
+            ctx.solana_wallet,
+            salt,
+            "Token 2022 Metadata Token".to_owned(),
+            "T22".to_owned(),
+            9,
+            1000,
+            Some(ctx.solana_wallet),
+            MetadataStandard::Token2022,
+        )?;
+    ctx.send_solana_tx(&[deploy_local_ix])
+        .await
+        .expect("InterchainToken deployment failed");
+
+    let mint_account = ctx
+        .solana_chain
+        .try_get_account_no_checks(&interchain_token_pda)
+        .await?
+        .ok_or_else(|| anyhow!("mint account not found"))?;
+    let mint = StateWithExtensions::<Mint>::unpack(&mint_account.data)?;
+    let metadata_pointer = mint.get_extension::<MetadataPointer>()?;
+    let token_metadata = mint.get_variable_len_extension::<TokenMetadata>()?;
+
+    assert_eq!(
+        Option::<Pubkey>::from(metadata_pointer.metadata_address),
+        Some(interchain_token_pda)
+    );
+    assert_eq!(token_metadata.name, "Token 2022 Metadata Token");
+    assert_eq!(token_metadata.symbol, "T22");
+    assert_eq!(mint.base.supply, 1000);
+
+    let (metaplex_metadata, _) = Metadata::find_pda(&interchain_token_pda);
+    assert!(ctx
+        .solana_chain
+        .try_get_account_no_checks(&metaplex_metadata)
+        .await?
+        .is_none());
+
+    let deploy_remote_ix = axelar_solana_its::instruction::deploy_remote_interchain_token(
+        ctx.solana_wallet,
+        salt,
+        ctx.evm_chain_name.clone(),
+        0,
+        axelar_solana_gas_service::id(),
+        ctx.solana_gas_utils.config_pda,
+    )?;
+    let tx = ctx
+        .send_solana_tx(&[deploy_remote_ix])
+        .await
+        .expect("remote InterchainToken deployment failed");
+
+    let deployment_started_event = tx
+        .metadata
+        .unwrap()
+        .log_messages
+        .iter()
+        .find_map(|log| {
+            axelar_solana_its::event::InterchainTokenDeploymentStarted::try_from_log(log).ok()
+        })
+        .unwrap();
+
+    assert_eq!(
+        deployment_started_event.token_name,
+        "Token 2022 Metadata Token"
+    );
+    assert_eq!(deployment_started_event.token_symbol, "T22");
+    assert_eq!(deployment_started_event.token_decimals, 9);
+
+    Ok(())
+}
@@ -51,6 +51,7 @@ async fn test_deploy_remote_interchain_token_with_valid_metadata(
             salt,
             "ethereum".to_string(),
             vec![1, 2, 3, 4],
+            None,
         )?;
 
     ctx.send_solana_tx(&[approve_remote_deployment])
@@ -168,6 +169,7 @@ async fn test_deploy_remote_interchain_token_with_mismatched_metadata(
             salt,
             "ethereum".to_string(),
             vec![5, 6, 7, 8],
+            None,
         )?;
 
     ctx.send_solana_tx(&[approve_remote_deployment])
@@ -1,17 +1,21 @@
 use alloy_primitives::Bytes;
 use anyhow::anyhow;
 use borsh::BorshDeserialize;
+use event_utils::Event as _;
 use interchain_token_transfer_gmp::SendToHub;
 use solana_program_test::tokio;
-use solana_sdk::clock::Clock;
 use solana_sdk::program_pack::Pack as _;
+use solana_sdk::pubkey::Pubkey;
 use spl_associated_token_account::get_associated_token_address_with_program_id;
 use spl_associated_token_account::instruction::create_associated_token_account;
 use test_context::test_context;
 
 use axelar_solana_gateway::processor::GatewayEvent;
+use axelar_solana_gateway_test_fixtures::base::FindLog;
 use axelar_solana_gateway_test_fixtures::gateway::get_gateway_events;
 use axelar_solana_gateway_test_fixtures::gateway::ProgramInvocationState;
+use axelar_solana_its::state::flow_limit::{FlowLimit, FlowSlot, DEFAULT_EPOCH_TIME};
+use axelar_solana_its::state::token_manager::TokenManager;
 use evm_contracts_test_suite::ethers::signers::Signer as EvmSigner;
 use evm_contracts_test_suite::ethers::types::U256;
 use interchain_token_transfer_gmp::GMPPayload;
@@ -33,7 +37,7 @@ async fn test_incoming_interchain_transfer_within_limit(
     let flow_limit_ix = axelar_solana_its::instruction::set_flow_limit(
         ctx.solana_wallet,
         ctx.deployed_interchain_token,
-        flow_limit,
+        FlowLimit::new(flow_limit, flow_limit),
     )?;
 
     ctx.send_solana_tx(&[flow_limit_ix]).await;
@@ -112,7 +116,7 @@ async fn test_incoming_interchain_transfer_beyond_limit(ctx: &mut ItsTestContext
     let flow_limit_ix = axelar_solana_its::instruction::set_flow_limit(
         ctx.solana_wallet,
         ctx.deployed_interchain_token,
-        flow_limit,
+        FlowLimit::new(flow_limit, flow_limit),
     )
     .unwrap();
 
@@ -185,8 +189,11 @@ async fn test_outgoing_interchain_transfer_within_limit(
     let token_id = ctx.deployed_interchain_token;
     let flow_limit = 800;
 
-    let flow_limit_ix =
-        axelar_solana_its::instruction::set_flow_limit(ctx.solana_wallet, token_id, flow_limit)?;
+    let flow_limit_ix = axelar_solana_its::instruction::set_flow_limit(
+        ctx.solana_wallet,
+        token_id,
+        FlowLimit::new(flow_limit, flow_limit),
+    )?;
 
     ctx.send_solana_tx(&[flow_limit_ix]).await;
 
@@ -210,6 +217,7 @@ async fn test_outgoing_interchain_transfer_within_limit(
     ctx.send_solana_tx(&[create_token_account_ix]).await;
 
     let mint_ix = axelar_solana_its::instruction::interchain_token::mint(
+        ctx.solana_wallet,
         token_id,
         interchain_token_pda,
         associated_account_address,
@@ -220,7 +228,7 @@ async fn test_outgoing_interchain_transfer_within_limit(
 
     ctx.send_solana_tx(&[mint_ix]).await;
 
-    let clock_sysvar = ctx.solana_chain.get_sysvar::<Clock>().await;
+    let flow_epoch = ctx.flow_epoch(&token_id).await;
 
     let transfer_ix = axelar_solana_its::instruction::interchain_transfer(
         ctx.solana_wallet,
@@ -234,7 +242,7 @@ async fn test_outgoing_interchain_transfer_within_limit(
         0,
         axelar_solana_gas_service::id(),
         ctx.solana_gas_utils.config_pda,
-        clock_sysvar.unix_timestamp,
+        flow_epoch,
     )?;
 
     let tx = ctx.send_solana_tx(&[transfer_ix]).await.unwrap();
@@ -270,9 +278,12 @@ async fn test_outgoing_interchain_transfer_within_limit(
 async fn test_outgoing_interchain_transfer_outside_limit(ctx: &mut ItsTestContext) {
     let token_id = ctx.deployed_interchain_token;
     let flow_limit = 800;
-    let flow_limit_ix =
-        axelar_solana_its::instruction::set_flow_limit(ctx.solana_wallet, token_id, flow_limit)
-            .unwrap();
+    let flow_limit_ix = axelar_solana_its::instruction::set_flow_limit(
+        ctx.solana_wallet,
+        token_id,
+        FlowLimit::new(flow_limit, flow_limit),
+    )
+    .unwrap();
 
     ctx.send_solana_tx(&[flow_limit_ix]).await;
 
@@ -296,6 +307,7 @@ async fn test_outgoing_interchain_transfer_outside_limit(ctx: &mut ItsTestContex
     ctx.send_solana_tx(&[create_token_account_ix]).await;
 
     let mint_ix = axelar_solana_its::instruction::interchain_token::mint(
+        ctx.solana_wallet,
         token_id,
         interchain_token_pda,
         associated_account_address,
@@ -307,7 +319,7 @@ async fn test_outgoing_interchain_transfer_outside_limit(ctx: &mut ItsTestContex
 
     ctx.send_solana_tx(&[mint_ix]).await;
 
-    let clock_sysvar = ctx.solana_chain.get_sysvar::<Clock>().await;
+    let flow_epoch = ctx.flow_epoch(&token_id).await;
 
     let transfer_ix = axelar_solana_its::instruction::interchain_transfer(
         ctx.solana_wallet,
@@ -321,7 +333,7 @@ async fn test_outgoing_interchain_transfer_outside_limit(ctx: &mut ItsTestContex
         0,
         axelar_solana_gas_service::id(),
         ctx.solana_gas_utils.config_pda,
-        clock_sysvar.unix_timestamp,
+        flow_epoch,
     )
     .unwrap();
 
@@ -344,7 +356,7 @@ async fn test_flow_slot_initialization_incoming_transfer(
     let flow_limit_ix = axelar_solana_its::instruction::set_flow_limit(
         ctx.solana_wallet,
         ctx.deployed_interchain_token,
-        flow_limit,
+        FlowLimit::new(flow_limit, flow_limit),
     )?;
 
     ctx.send_solana_tx(&[flow_limit_ix]).await;
@@ -451,9 +463,7 @@ async fn test_flow_slot_initialization_incoming_transfer(
     // Check FlowSlot values on-chain
     let (token_manager_pda, _) =
         axelar_solana_its::find_token_manager_pda(&its_root_pda, &ctx.deployed_interchain_token);
-    let current_timestamp = ctx.solana_chain.get_sysvar::<Clock>().await.unix_timestamp;
-    let current_epoch =
-        axelar_solana_its::state::flow_limit::flow_epoch_with_timestamp(current_timestamp)?;
+    let current_epoch = ctx.flow_epoch(&ctx.deployed_interchain_token).await;
     let (flow_slot_pda, _) =
         axelar_solana_its::find_flow_slot_pda(&token_manager_pda, current_epoch);
 
@@ -490,8 +500,11 @@ async fn test_flow_slot_initialization_outgoing_transfer(
     let transfer_amount = 300;
 
     // Set flow limit
-    let flow_limit_ix =
-        axelar_solana_its::instruction::set_flow_limit(ctx.solana_wallet, token_id, flow_limit)?;
+    let flow_limit_ix = axelar_solana_its::instruction::set_flow_limit(
+        ctx.solana_wallet,
+        token_id,
+        FlowLimit::new(flow_limit, flow_limit),
+    )?;
 
     ctx.send_solana_tx(&[flow_limit_ix]).await;
 
@@ -516,6 +529,7 @@ async fn test_flow_slot_initialization_outgoing_transfer(
 
     // Mint tokens to transfer
     let mint_ix = axelar_solana_its::instruction::interchain_token::mint(
+        ctx.solana_wallet,
         token_id,
         interchain_token_pda,
         associated_account_address,
@@ -526,7 +540,7 @@ async fn test_flow_slot_initialization_outgoing_transfer(
 
     ctx.send_solana_tx(&[mint_ix]).await;
 
-    let clock_sysvar = ctx.solana_chain.get_sysvar::<Clock>().await;
+    let flow_epoch = ctx.flow_epoch(&token_id).await;
 
     // First outgoing transfer - this should create a new flow slot with flow_out=transfer_amount
     let transfer_ix = axelar_solana_its::instruction::interchain_transfer(
@@ -541,7 +555,7 @@ async fn test_flow_slot_initialization_outgoing_transfer(
         0,
         axelar_solana_gas_service::id(),
         ctx.solana_gas_utils.config_pda,
-        clock_sysvar.unix_timestamp,
+        flow_epoch,
     )?;
 
     let tx = ctx.send_solana_tx(&[transfer_ix]).await.unwrap();
@@ -582,7 +596,7 @@ async fn test_flow_slot_initialization_outgoing_transfer(
         0,
         axelar_solana_gas_service::id(),
         ctx.solana_gas_utils.config_pda,
-        clock_sysvar.unix_timestamp,
+        flow_epoch,
     )?;
 
     let tx_2 = ctx.send_solana_tx(&[transfer_ix_2]).await.unwrap();
@@ -606,9 +620,7 @@ async fn test_flow_slot_initialization_outgoing_transfer(
     // Check FlowSlot values on-chain
     let (token_manager_pda, _) =
         axelar_solana_its::find_token_manager_pda(&its_root_pda, &token_id);
-    let current_timestamp = ctx.solana_chain.get_sysvar::<Clock>().await.unix_timestamp;
-    let current_epoch =
-        axelar_solana_its::state::flow_limit::flow_epoch_with_timestamp(current_timestamp)?;
+    let current_epoch = ctx.flow_epoch(&token_id).await;
     let (flow_slot_pda, _) =
         axelar_solana_its::find_flow_slot_pda(&token_manager_pda, current_epoch);
 
@@ -634,3 +646,404 @@ async fn test_flow_slot_initialization_outgoing_transfer(
 
     Ok(())
 }
+
+#[test_context(ItsTestContext)]
+#[tokio::test]
+async fn test_set_flow_limit_with_custom_configuration(
+    ctx: &mut ItsTestContext,
+) -> anyhow::Result<()> {
+    let token_id = ctx.deployed_interchain_token;
+    let flow_limit = FlowLimit::new(500, 800)
+        .with_epoch_duration(3600)
+        .with_sliding_window(true);
+
+    let flow_limit_ix =
+        axelar_solana_its::instruction::set_flow_limit(ctx.solana_wallet, token_id, flow_limit)?;
+
+    let tx = ctx.send_solana_tx(&[flow_limit_ix]).await.unwrap();
+    let flow_limit_set_event = tx
+        .metadata
+        .unwrap()
+        .log_messages
+        .iter()
+        .find_map(|log| axelar_solana_its::event::FlowLimitSet::try_from_log(log).ok())
+        .unwrap();
+
+    assert_eq!(flow_limit_set_event.token_id, token_id);
+    assert_eq!(flow_limit_set_event.operator, ctx.solana_wallet);
+    assert_eq!(flow_limit_set_event.inbound_flow_limit, 500);
+    assert_eq!(flow_limit_set_event.outbound_flow_limit, 800);
+    assert_eq!(flow_limit_set_event.epoch_duration, 3600);
+    assert!(flow_limit_set_event.sliding_window);
+
+    let (its_root_pda, _) = axelar_solana_its::find_its_root_pda();
+    let (token_manager_pda, _) =
+        axelar_solana_its::find_token_manager_pda(&its_root_pda, &token_id);
+    let data = ctx
+        .solana_chain
+        .fixture
+        .get_account(&token_manager_pda, &axelar_solana_its::id())
+        .await
+        .data;
+    let token_manager = TokenManager::try_from_slice(&data)?;
+
+    assert_eq!(token_manager.flow_limit, flow_limit);
+
+    Ok(())
+}
+
+#[test_context(ItsTestContext)]
+#[tokio::test]
+async fn test_set_flow_limit_fails_with_zero_epoch_duration(ctx: &mut ItsTestContext) {
+    let flow_limit = FlowLimit::new(500, 800).with_epoch_duration(0);
+    let flow_limit_ix = axelar_solana_its::instruction::set_flow_limit(
+        ctx.solana_wallet,
+        ctx.deployed_interchain_token,
+        flow_limit,
+    )
+    .unwrap();
+
+    let tx = ctx.send_solana_tx(&[flow_limit_ix]).await.unwrap_err();
+
+    assert!(tx
+        .find_log("Flow epoch duration must be greater than zero")
+        .is_some());
+}
+
+#[test_context(ItsTestContext)]
+#[tokio::test]
+async fn test_outgoing_interchain_transfer_uses_outbound_limit(ctx: &mut ItsTestContext) {
+    let token_id = ctx.deployed_interchain_token;
+    let flow_limit_ix = axelar_solana_its::instruction::set_flow_limit(
+        ctx.solana_wallet,
+        token_id,
+        FlowLimit::new(1000, 100),
+    )
+    .unwrap();
+
+    ctx.send_solana_tx(&[flow_limit_ix]).await.unwrap();
+
+    let (its_root_pda, _) = axelar_solana_its::find_its_root_pda();
+    let (interchain_token_pda, _) =
+        axelar_solana_its::find_interchain_token_pda(&its_root_pda, &token_id);
+
+    let associated_account_address = get_associated_token_address_with_program_id(
+        &ctx.solana_wallet,
+        &interchain_token_pda,
+        &spl_token_2022::id(),
+    );
+
+    let create_token_account_ix = create_associated_token_account(
+        &ctx.solana_wallet,
+        &ctx.solana_wallet,
+        &interchain_token_pda,
+        &spl_token_2022::id(),
+    );
+
+    ctx.send_solana_tx(&[create_token_account_ix]).await;
+
+    let mint_ix = axelar_solana_its::instruction::interchain_token::mint(
+        ctx.solana_wallet,
+        token_id,
+        interchain_token_pda,
+        associated_account_address,
+        ctx.solana_wallet,
+        spl_token_2022::id(),
+        900,
+    )
+    .unwrap();
+
+    ctx.send_solana_tx(&[mint_ix]).await;
+
+    let flow_epoch = ctx.flow_epoch(&token_id).await;
+
+    let transfer_ix = axelar_solana_its::instruction::interchain_transfer(
+        ctx.solana_wallet,
+        associated_account_address,
+        token_id,
+        ctx.evm_chain_name.clone(),
+        ctx.evm_signer.wallet.address().as_bytes().to_vec(),
+        200,
+        interchain_token_pda,
+        spl_token_2022::id(),
+        0,
+        axelar_solana_gas_service::id(),
+        ctx.solana_gas_utils.config_pda,
+        flow_epoch,
+    )
+    .unwrap();
+
+    let tx = ctx.send_solana_tx(&[transfer_ix]).await.unwrap_err();
+
+    assert!(tx.find_log("Flow limit exceeded").is_some());
+}
+
+/// Sets a flow limit with the given epoch duration on the deployed interchain
+/// token and transfers some of it out, creating the flow slot of the current
+/// epoch. Returns the `TokenManager` PDA and the epoch of the flow slot.
+async fn transfer_out_with_flow_limit(
+    ctx: &mut ItsTestContext,
+    epoch_duration: u64,
+) -> anyhow::Result<(Pubkey, u64)> {
+    let token_id = ctx.deployed_interchain_token;
+    let flow_limit_ix = axelar_solana_its::instruction::set_flow_limit(
+        ctx.solana_wallet,
+        token_id,
+        FlowLimit::new(500, 500).with_epoch_duration(epoch_duration),
+    )?;
+
+    ctx.send_solana_tx(&[flow_limit_ix]).await.unwrap();
+
+    let (its_root_pda, _) = axelar_solana_its::find_its_root_pda();
+    let (interchain_token_pda, _) =
+        axelar_solana_its::find_interchain_token_pda(&its_root_pda, &token_id);
+    let (token_manager_pda, _) =
+        axelar_solana_its::find_token_manager_pda(&its_root_pda, &token_id);
+    let associated_account_address = get_associated_token_address_with_program_id(
+        &ctx.solana_wallet,
+        &interchain_token_pda,
+        &spl_token_2022::id(),
+    );
+
+    let create_token_account_ix = create_associated_token_account(
+        &ctx.solana_wallet,
+        &ctx.solana_wallet,
+        &interchain_token_pda,
+        &spl_token_2022::id(),
+    );
+    let mint_ix = axelar_solana_its::instruction::interchain_token::mint(
+        ctx.solana_wallet,
+        token_id,
+        interchain_token_pda,
+        associated_account_address,
+        ctx.solana_wallet,
+        spl_token_2022::id(),
+        100,
+    )?;
+
+    ctx.send_solana_tx(&[create_token_account_ix, mint_ix])
+        .await
+        .unwrap();
+
+    let flow_epoch = ctx.flow_epoch(&token_id).await;
+    let transfer_ix = axelar_solana_its::instruction::interchain_transfer(
+        ctx.solana_wallet,
+        associated_account_address,
+        token_id,
+        ctx.evm_chain_name.clone(),
+        ctx.evm_signer.wallet.address().as_bytes().to_vec(),
+        100,
+        interchain_token_pda,
+        spl_token_2022::id(),
+        0,
+        axelar_solana_gas_service::id(),
+        ctx.solana_gas_utils.config_pda,
+        flow_epoch,
+    )?;
+
+    ctx.send_solana_tx(&[transfer_ix]).await.unwrap();
+
+    Ok((token_manager_pda, flow_epoch))
+}
+
+#[test_context(ItsTestContext)]
+#[tokio::test]
+async fn test_close_flow_slot_of_past_epoch(ctx: &mut ItsTestContext) -> anyhow::Result<()> {
+    let token_id = ctx.deployed_interchain_token;
+    let epoch_duration = 3600;
+    let (token_manager_pda, flow_epoch) = transfer_out_with_flow_limit(ctx, epoch_duration).await?;
+
+    let token_manager = TokenManager::try_from_slice(
+        &ctx.solana_chain
+            .fixture
+            .get_account(&token_manager_pda, &axelar_solana_its::id())
+            .await
+            .data,
+    )?;
+    let (flow_slot_pda, _) = axelar_solana_its::find_flow_slot_pda(&token_manager_pda, flow_epoch);
+
+    let close_ix =
+        axelar_solana_its::instruction::close_flow_slot(token_id, token_manager.payer, flow_epoch)?;
+    let tx = ctx.send_solana_tx(&[close_ix.clone()]).await.unwrap_err();
+    assert!(tx.find_log("is still in use").is_some());
+
+    ctx.solana_chain
+        .fixture
+        .forward_time(i64::try_from(epoch_duration)?)
+        .await;
+
+    let wrong_refund_ix = axelar_solana_its::instruction::close_flow_slot(
+        token_id,
+        Pubkey::new_unique(),
+        flow_epoch,
+    )?;
+    let tx = ctx.send_solana_tx(&[wrong_refund_ix]).await.unwrap_err();
+    assert!(tx
+        .find_log("The refund destination must be the payer of the TokenManager")
+        .is_some());
+
+    let flow_slot_lamports = ctx
+        .solana_chain
+        .fixture
+        .get_account(&flow_slot_pda, &axelar_solana_its::id())
+        .await
+        .lamports;
+    let payer_lamports_before = ctx
+        .solana_chain
+        .fixture
+        .try_get_account_no_checks(&token_manager.payer)
+        .await?
+        .ok_or_else(|| anyhow!("payer account not found"))?
+        .lamports;
+
+    ctx.send_solana_tx(&[close_ix]).await.unwrap();
+
+    let payer_lamports_after = ctx
+        .solana_chain
+        .fixture
+        .try_get_account_no_checks(&token_manager.payer)
+        .await?
+        .ok_or_else(|| anyhow!("payer account not found"))?
+        .lamports;
+
+    // The payer of record also pays for the transaction fee here.
+    assert!(payer_lamports_after > payer_lamports_before);
+    assert!(payer_lamports_after <= payer_lamports_before + flow_slot_lamports);
+    assert!(ctx
+        .solana_chain
+        .try_get_account_no_checks(&flow_slot_pda)
+        .await?
+        .is_none());
+
+    Ok(())
+}
+
+#[test_context(ItsTestContext)]
+#[tokio::test]
+async fn test_close_flow_slot_after_epoch_duration_grows(
+    ctx: &mut ItsTestContext,
+) -> anyhow::Result<()> {
+    let token_id = ctx.deployed_interchain_token;
+    let epoch_duration = 3600;
+    let (token_manager_pda, flow_epoch) = transfer_out_with_flow_limit(ctx, epoch_duration).await?;
+    let (flow_slot_pda, _) = axelar_solana_its::find_flow_slot_pda(&token_manager_pda, flow_epoch);
+
+    // Longer epochs make the epoch number of the slot greater than the
+    // current one.
+    let flow_limit_ix = axelar_solana_its::instruction::set_flow_limit(
+        ctx.solana_wallet,
+        token_id,
+        FlowLimit::new(500, 500)
+            .with_epoch_duration(epoch_duration * 24 * 365)
+            .with_sliding_window(true),
+    )?;
+    ctx.send_solana_tx(&[flow_limit_ix]).await.unwrap();
+    assert!(ctx.flow_epoch(&token_id).await < flow_epoch);
+
+    ctx.solana_chain
+        .fixture
+        .forward_time(i64::try_from(epoch_duration)?)
+        .await;
+
+    let token_manager = TokenManager::try_from_slice(
+        &ctx.solana_chain
+            .fixture
+            .get_account(&token_manager_pda, &axelar_solana_its::id())
+            .await
+            .data,
+    )?;
+    let close_ix =
+        axelar_solana_its::instruction::close_flow_slot(token_id, token_manager.payer, flow_epoch)?;
+    ctx.send_solana_tx(&[close_ix]).await.unwrap();
+
+    assert!(ctx
+        .solana_chain
+        .try_get_account_no_checks(&flow_slot_pda)
+        .await?
+        .is_none());
+
+    Ok(())
+}
+
+#[test_context(ItsTestContext)]
+#[tokio::test]
+async fn test_close_legacy_flow_slot_refunds_legacy_token_manager(
+    ctx: &mut ItsTestContext,
+) -> anyhow::Result<()> {
+    let token_id = ctx.deployed_interchain_token;
+    // Legacy token managers use the default epoch duration.
+    let epoch_duration = DEFAULT_EPOCH_TIME.as_secs();
+    let (token_manager_pda, flow_epoch) = transfer_out_with_flow_limit(ctx, epoch_duration).await?;
+    let (flow_slot_pda, _) = axelar_solana_its::find_flow_slot_pda(&token_manager_pda, flow_epoch);
+
+    // Rewrite both accounts in the layouts used before flow slots recorded
+    // their token manager and token managers recorded their payer.
+    let mut token_manager_account = ctx
+        .solana_chain
+        .fixture
+        .get_account(&token_manager_pda, &axelar_solana_its::id())
+        .await;
+    let token_manager = TokenManager::try_from_slice(&token_manager_account.data)?;
+    let mut legacy_token_manager_data = vec![u8::from(token_manager.ty)];
+    legacy_token_manager_data.extend_from_slice(&token_manager.token_id);
+    legacy_token_manager_data.extend_from_slice(token_manager.token_address.as_ref());
+    legacy_token_manager_data.extend_from_slice(token_manager.associated_token_account.as_ref());
+    legacy_token_manager_data.extend_from_slice(&0_u64.to_le_bytes());
+    legacy_token_manager_data.push(token_manager.bump);
+    token_manager_account.data = legacy_token_manager_data;
+    ctx.solana_chain
+        .fixture
+        .set_account_state(&token_manager_pda, token_manager_account);
+
+    let mut flow_slot_account = ctx
+        .solana_chain
+        .fixture
+        .get_account(&flow_slot_pda, &axelar_solana_its::id())
+        .await;
+    let flow_slot = FlowSlot::try_from_slice(&flow_slot_account.data)?;
+    let mut legacy_flow_slot_data = flow_slot.flow_in.to_le_bytes().to_vec();
+    legacy_flow_slot_data.extend_from_slice(&flow_slot.flow_out.to_le_bytes());
+    legacy_flow_slot_data.push(flow_slot.bump);
+    let flow_slot_lamports = flow_slot_account.lamports;
+    flow_slot_account.data = legacy_flow_slot_data;
+    ctx.solana_chain
+        .fixture
+        .set_account_state(&flow_slot_pda, flow_slot_account);
+
+    ctx.solana_chain
+        .fixture
+        .forward_time(i64::try_from(epoch_duration)?)
+        .await;
+
+    let token_manager_lamports_before = ctx
+        .solana_chain
+        .fixture
+        .get_account(&token_manager_pda, &axelar_solana_its::id())
+        .await
+        .lamports;
+
+    // Legacy token managers have no payer of record, the rent goes back to
+    // the token manager itself.
+    let close_ix =
+        axelar_solana_its::instruction::close_flow_slot(token_id, token_manager_pda, flow_epoch)?;
+    ctx.send_solana_tx(&[close_ix]).await.unwrap();
+
+    let token_manager_lamports_after = ctx
+        .solana_chain
+        .fixture
+        .get_account(&token_manager_pda, &axelar_solana_its::id())
+        .await
+        .lamports;
+
+    assert_eq!(
+        token_manager_lamports_after,
+        token_manager_lamports_before + flow_slot_lamports
+    );
+    assert!(ctx
+        .solana_chain
+        .try_get_account_no_checks(&flow_slot_pda)
+        .await?
+        .is_none());
+
+    Ok(())
+}
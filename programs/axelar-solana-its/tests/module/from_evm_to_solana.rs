@@ -253,6 +253,7 @@ async fn test_custom_token_mint_burn_link_transfer(ctx: &mut ItsTestContext) ->
 
     let initial_balance = 300;
     let mint_ix = axelar_solana_its::instruction::interchain_token::mint(
+        ctx.solana_wallet,
         token_id,
         solana_token,
         token_account,
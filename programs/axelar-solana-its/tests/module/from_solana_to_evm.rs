@@ -1,29 +1,65 @@
+use axelar_solana_gateway::processor::GatewayEvent;
 use axelar_solana_gateway_test_fixtures::base::FindLog;
+use axelar_solana_gateway_test_fixtures::gateway::{get_gateway_events, ProgramInvocationState};
 use borsh::BorshDeserialize;
+use event_utils::Event as _;
 use evm_contracts_test_suite::ethers::signers::Signer;
 use mpl_token_metadata::accounts::Metadata;
 use mpl_token_metadata::instructions::CreateV1Builder;
 use mpl_token_metadata::types::TokenStandard;
 use solana_program_test::tokio;
-use solana_sdk::clock::Clock;
 use solana_sdk::program_pack::Pack as _;
 use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
 use solana_sdk::signer::Signer as _;
 use spl_associated_token_account::get_associated_token_address_with_program_id;
 use spl_associated_token_account::instruction::create_associated_token_account;
 use test_context::test_context;
 
+use axelar_solana_its::event::InterchainTransferBatchSent;
+use axelar_solana_its::instruction::BatchedTransfer;
 use axelar_solana_its::state::token_manager::{TokenManager, Type as TokenManagerType};
 use evm_contracts_test_suite::evm_contracts_rs::contracts::{
     custom_test_token::CustomTestToken, interchain_token::InterchainToken,
 };
 use evm_contracts_test_suite::ContractMiddleware;
-use interchain_token_transfer_gmp::GMPPayload;
+use interchain_token_transfer_gmp::{GMPPayload, InterchainTransfer};
 
 use crate::{
     fetch_first_call_contract_event_from_tx, retrieve_evm_log_with_filter, ItsTestContext,
+    ITS_HUB_TRUSTED_CONTRACT_ADDRESS,
 };
 
+/// The gas payment ledger PDA of a transfer of `amount` tokens of `token_id`, from `mint` to
+/// `destination_address` on the EVM chain, when the Solana wallet pays for gas in `gas_token`.
+fn transfer_gas_payment_pda(
+    ctx: &ItsTestContext,
+    token_id: [u8; 32],
+    mint: &Pubkey,
+    destination_address: Vec<u8>,
+    amount: u64,
+    gas_token: &Pubkey,
+) -> anyhow::Result<Pubkey> {
+    let payload = GMPPayload::InterchainTransfer(InterchainTransfer {
+        selector: InterchainTransfer::MESSAGE_TYPE_ID.try_into()?,
+        token_id: token_id.into(),
+        source_address: mint.to_bytes().into(),
+        destination_address: destination_address.into(),
+        amount: alloy_primitives::U256::from(amount),
+        data: alloy_primitives::Bytes::new(),
+    });
+    let payload_hash = axelar_solana_its::hub_payload_hash(&payload, &ctx.evm_chain_name)?;
+
+    Ok(axelar_solana_its::find_gas_payment_pda(
+        &ctx.solana_gas_utils.config_pda,
+        gas_token,
+        &ctx.solana_wallet,
+        ITS_HUB_TRUSTED_CONTRACT_ADDRESS,
+        &payload_hash,
+    )
+    .0)
+}
+
 async fn custom_token(
     ctx: &mut ItsTestContext,
     token_manager_type: TokenManagerType,
@@ -396,6 +432,7 @@ async fn test_custom_token_mint_burn_link_transfer(ctx: &mut ItsTestContext) ->
     let initial_balance = 300;
     // As the mint authority was handed over, we need to mint through ITS.
     let mint_ix = axelar_solana_its::instruction::interchain_token::mint(
+        ctx.solana_wallet,
         token_id,
         solana_token,
         token_account,
@@ -513,7 +550,7 @@ async fn transfer_fails_with_wrong_gas_service(ctx: &mut ItsTestContext) -> anyh
     )?;
 
     ctx.send_solana_tx(&[create_ata_ix, mint_ix]).await.unwrap();
-    let clock_sysvar = ctx.solana_chain.get_sysvar::<Clock>().await;
+    let flow_epoch = ctx.flow_epoch(&token_id).await;
     let transfer_ix = axelar_solana_its::instruction::interchain_transfer(
         ctx.solana_wallet,
         token_account,
@@ -526,12 +563,19 @@ async fn transfer_fails_with_wrong_gas_service(ctx: &mut ItsTestContext) -> anyh
         1000,                 // gas_value needs to be greater than 0 for pay_gas to be called
         Pubkey::new_unique(), // Invalid gas service id
         ctx.solana_gas_utils.config_pda,
-        clock_sysvar.unix_timestamp,
+        flow_epoch,
     )
     .unwrap();
+    let transfer_ix = axelar_solana_its::instruction::with_gas_payment_accounts(
+        transfer_ix,
+        &[Pubkey::new_unique()],
+    );
 
+    let payer = ctx.solana_chain.fixture.payer.insecure_clone();
     assert!(ctx
-        .send_solana_tx(&[transfer_ix])
+        .solana_chain
+        .fixture
+        .send_tx_with_custom_signers(&[transfer_ix], &[&payer])
         .await
         .unwrap_err()
         .find_log("Invalid gas service account")
@@ -540,6 +584,430 @@ async fn transfer_fails_with_wrong_gas_service(ctx: &mut ItsTestContext) -> anyh
     Ok(())
 }
 
+#[test_context(ItsTestContext)]
+#[tokio::test]
+async fn test_transfer_with_spl_gas_payment(ctx: &mut ItsTestContext) -> anyhow::Result<()> {
+    let (token_id, _evm_token, solana_token) = canonical_token(ctx).await?;
+
+    let token_account = get_associated_token_address_with_program_id(
+        &ctx.solana_wallet,
+        &solana_token,
+        &spl_token_2022::id(),
+    );
+
+    let create_ata_ix = create_associated_token_account(
+        &ctx.solana_wallet,
+        &ctx.solana_wallet,
+        &solana_token,
+        &spl_token_2022::id(),
+    );
+
+    let initial_balance = 300;
+    let mint_ix = spl_token_2022::instruction::mint_to(
+        &spl_token_2022::id(),
+        &solana_token,
+        &token_account,
+        &ctx.solana_wallet,
+        &[],
+        initial_balance,
+    )?;
+
+    ctx.send_solana_tx(&[create_ata_ix, mint_ix]).await.unwrap();
+
+    // Setup the token used to pay for gas
+    let gas_token_authority = Keypair::new();
+    let gas_value = 1_000;
+    let gas_token = ctx
+        .solana_chain
+        .fixture
+        .init_new_mint(gas_token_authority.pubkey(), spl_token_2022::id(), 6)
+        .await;
+    ctx.solana_chain
+        .fixture
+        .allow_gas_token(&ctx.solana_gas_utils, &gas_token, 0, 0)
+        .await
+        .unwrap();
+    let gas_token_account = ctx
+        .solana_chain
+        .fixture
+        .init_associated_token_account(&gas_token, &ctx.solana_wallet, &spl_token_2022::id())
+        .await;
+    let gas_config_token_account = ctx
+        .solana_chain
+        .fixture
+        .init_associated_token_account(
+            &gas_token,
+            &ctx.solana_gas_utils.config_pda,
+            &spl_token_2022::id(),
+        )
+        .await;
+    ctx.solana_chain
+        .fixture
+        .mint_tokens_to(
+            &gas_token,
+            &gas_token_account,
+            &gas_token_authority,
+            gas_value,
+            &spl_token_2022::id(),
+        )
+        .await;
+
+    let flow_epoch = ctx.flow_epoch(&token_id).await;
+    let transfer_ix = axelar_solana_its::instruction::interchain_transfer(
+        ctx.solana_wallet,
+        token_account,
+        token_id,
+        ctx.evm_chain_name.clone(),
+        ctx.evm_signer.wallet.address().as_bytes().to_vec(),
+        initial_balance,
+        solana_token,
+        spl_token_2022::id(),
+        gas_value,
+        axelar_solana_gas_service::id(),
+        ctx.solana_gas_utils.config_pda,
+        flow_epoch,
+    )?;
+    let transfer_ix = axelar_solana_its::instruction::with_spl_gas_payment(
+        transfer_ix,
+        ctx.solana_gas_utils.config_pda,
+        gas_token_account,
+        gas_token,
+        spl_token_2022::id(),
+    );
+    let gas_payment_pda = transfer_gas_payment_pda(
+        ctx,
+        token_id,
+        &solana_token,
+        ctx.evm_signer.wallet.address().as_bytes().to_vec(),
+        initial_balance,
+        &gas_token,
+    )?;
+    let transfer_ix =
+        axelar_solana_its::instruction::with_gas_payment_accounts(transfer_ix, &[gas_payment_pda]);
+
+    let payer = ctx.solana_chain.fixture.payer.insecure_clone();
+    ctx.solana_chain
+        .fixture
+        .send_tx_with_custom_signers(&[transfer_ix], &[&payer])
+        .await
+        .unwrap();
+
+    let gas_token_balance = ctx
+        .solana_chain
+        .fixture
+        .get_token_account(&gas_token_account)
+        .await
+        .amount;
+    let gas_config_token_balance = ctx
+        .solana_chain
+        .fixture
+        .get_token_account(&gas_config_token_account)
+        .await
+        .amount;
+
+    assert_eq!(gas_token_balance, 0);
+    assert_eq!(gas_config_token_balance, gas_value);
+
+    // The payment is recorded in its ledger, refundable to the payer
+    let gas_payment = ctx
+        .solana_chain
+        .fixture
+        .gas_payment_state(gas_payment_pda)
+        .await;
+    assert_eq!(gas_payment.token, gas_token);
+    assert_eq!(gas_payment.refund_address, ctx.solana_wallet);
+    assert_eq!(gas_payment.paid, gas_value);
+
+    Ok(())
+}
+
+#[test_context(ItsTestContext)]
+#[tokio::test]
+async fn test_interchain_transfer_batch(ctx: &mut ItsTestContext) -> anyhow::Result<()> {
+    let (token_id, _evm_token, solana_token) = canonical_token(ctx).await?;
+
+    let token_account = get_associated_token_address_with_program_id(
+        &ctx.solana_wallet,
+        &solana_token,
+        &spl_token_2022::id(),
+    );
+
+    let create_ata_ix = create_associated_token_account(
+        &ctx.solana_wallet,
+        &ctx.solana_wallet,
+        &solana_token,
+        &spl_token_2022::id(),
+    );
+
+    let initial_balance = 300;
+    let mint_ix = spl_token_2022::instruction::mint_to(
+        &spl_token_2022::id(),
+        &solana_token,
+        &token_account,
+        &ctx.solana_wallet,
+        &[],
+        initial_balance,
+    )?;
+
+    ctx.send_solana_tx(&[create_ata_ix, mint_ix]).await.unwrap();
+
+    let transfers = [100, 50, 150]
+        .into_iter()
+        .map(|amount| BatchedTransfer {
+            destination_address: Keypair::new().pubkey().to_bytes()[..20].to_vec(),
+            amount,
+        })
+        .collect::<Vec<_>>();
+
+    let gas_value = 1_000;
+    let gas_service_balance_before = ctx
+        .solana_chain
+        .fixture
+        .get_balance(&ctx.solana_gas_utils.config_pda)
+        .await;
+    let flow_epoch = ctx.flow_epoch(&token_id).await;
+    let batch_ix = axelar_solana_its::instruction::interchain_transfer_batch(
+        ctx.solana_wallet,
+        token_account,
+        token_id,
+        ctx.evm_chain_name.clone(),
+        transfers.clone(),
+        solana_token,
+        spl_token_2022::id(),
+        gas_value,
+        axelar_solana_gas_service::id(),
+        ctx.solana_gas_utils.config_pda,
+        flow_epoch,
+    )?;
+    // Each message pays for gas, recording the payment in a ledger of its own.
+    let gas_payment_pdas = transfers
+        .iter()
+        .map(|transfer| {
+            transfer_gas_payment_pda(
+                ctx,
+                token_id,
+                &solana_token,
+                transfer.destination_address.clone(),
+                transfer.amount,
+                &axelar_solana_gas_service::NATIVE_GAS_TOKEN,
+            )
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    let batch_ix =
+        axelar_solana_its::instruction::with_gas_payment_accounts(batch_ix, &gas_payment_pdas);
+
+    let payer = ctx.solana_chain.fixture.payer.insecure_clone();
+    let tx = ctx
+        .solana_chain
+        .fixture
+        .send_tx_with_custom_signers(&[batch_ix], &[&payer])
+        .await
+        .unwrap();
+
+    // The gas is paid for each message, the shares adding up to the gas value.
+    assert_eq!(
+        ctx.solana_chain
+            .fixture
+            .get_balance(&ctx.solana_gas_utils.config_pda)
+            .await,
+        gas_service_balance_before + gas_value
+    );
+
+    let ProgramInvocationState::Succeeded(gateway_events) = get_gateway_events(&tx).pop().unwrap()
+    else {
+        panic!("unexpected event")
+    };
+    let call_contract_events = gateway_events
+        .into_iter()
+        .filter_map(|(_, event)| match event {
+            GatewayEvent::CallContract(call_contract) => Some(call_contract),
+            _ => None,
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(call_contract_events.len(), transfers.len());
+
+    for (call_contract, transfer) in call_contract_events.iter().zip(&transfers) {
+        let GMPPayload::SendToHub(send_to_hub) = GMPPayload::decode(&call_contract.payload)? else {
+            panic!("unexpected payload");
+        };
+        let GMPPayload::InterchainTransfer(interchain_transfer) =
+            GMPPayload::decode(&send_to_hub.payload)?
+        else {
+            panic!("unexpected payload");
+        };
+
+        assert_eq!(send_to_hub.destination_chain, ctx.evm_chain_name);
+        assert_eq!(
+            interchain_transfer.destination_address.to_vec(),
+            transfer.destination_address
+        );
+        assert_eq!(
+            interchain_transfer.amount,
+            alloy_primitives::U256::from(transfer.amount)
+        );
+    }
+
+    let batch_event = tx
+        .metadata
+        .unwrap()
+        .log_messages
+        .iter()
+        .find_map(|log| InterchainTransferBatchSent::try_from_log(log).ok())
+        .unwrap();
+    let payload_hashes = call_contract_events
+        .iter()
+        .flat_map(|call_contract| call_contract.payload_hash)
+        .collect::<Vec<_>>();
+    assert_eq!(batch_event.payload_hashes, payload_hashes);
+
+    let token_account_balance = ctx
+        .solana_chain
+        .fixture
+        .get_token_account(&token_account)
+        .await
+        .amount;
+    assert_eq!(token_account_balance, 0);
+
+    Ok(())
+}
+
+#[test_context(ItsTestContext)]
+#[tokio::test]
+async fn test_interchain_transfer_batch_rejects_spl_gas_shares_below_minimum(
+    ctx: &mut ItsTestContext,
+) -> anyhow::Result<()> {
+    let (token_id, _evm_token, solana_token) = canonical_token(ctx).await?;
+
+    let token_account = get_associated_token_address_with_program_id(
+        &ctx.solana_wallet,
+        &solana_token,
+        &spl_token_2022::id(),
+    );
+
+    let create_ata_ix = create_associated_token_account(
+        &ctx.solana_wallet,
+        &ctx.solana_wallet,
+        &solana_token,
+        &spl_token_2022::id(),
+    );
+
+    let initial_balance = 300;
+    let mint_ix = spl_token_2022::instruction::mint_to(
+        &spl_token_2022::id(),
+        &solana_token,
+        &token_account,
+        &ctx.solana_wallet,
+        &[],
+        initial_balance,
+    )?;
+
+    ctx.send_solana_tx(&[create_ata_ix, mint_ix]).await.unwrap();
+
+    // The gas value is above the minimum payment, but none of its three shares is.
+    let gas_token_authority = Keypair::new();
+    let gas_value = 1_000;
+    let gas_token = ctx
+        .solana_chain
+        .fixture
+        .init_new_mint(gas_token_authority.pubkey(), spl_token_2022::id(), 6)
+        .await;
+    ctx.solana_chain
+        .fixture
+        .allow_gas_token(&ctx.solana_gas_utils, &gas_token, 400, 0)
+        .await
+        .unwrap();
+    let gas_token_account = ctx
+        .solana_chain
+        .fixture
+        .init_associated_token_account(&gas_token, &ctx.solana_wallet, &spl_token_2022::id())
+        .await;
+    ctx.solana_chain
+        .fixture
+        .init_associated_token_account(
+            &gas_token,
+            &ctx.solana_gas_utils.config_pda,
+            &spl_token_2022::id(),
+        )
+        .await;
+    ctx.solana_chain
+        .fixture
+        .mint_tokens_to(
+            &gas_token,
+            &gas_token_account,
+            &gas_token_authority,
+            gas_value,
+            &spl_token_2022::id(),
+        )
+        .await;
+
+    let transfers = [100, 50, 150]
+        .into_iter()
+        .map(|amount| BatchedTransfer {
+            destination_address: Keypair::new().pubkey().to_bytes()[..20].to_vec(),
+            amount,
+        })
+        .collect::<Vec<_>>();
+
+    let flow_epoch = ctx.flow_epoch(&token_id).await;
+    let batch_ix = axelar_solana_its::instruction::interchain_transfer_batch(
+        ctx.solana_wallet,
+        token_account,
+        token_id,
+        ctx.evm_chain_name.clone(),
+        transfers.clone(),
+        solana_token,
+        spl_token_2022::id(),
+        gas_value,
+        axelar_solana_gas_service::id(),
+        ctx.solana_gas_utils.config_pda,
+        flow_epoch,
+    )?;
+    let batch_ix = axelar_solana_its::instruction::with_spl_gas_payment(
+        batch_ix,
+        ctx.solana_gas_utils.config_pda,
+        gas_token_account,
+        gas_token,
+        spl_token_2022::id(),
+    );
+    let gas_payment_pdas = transfers
+        .iter()
+        .map(|transfer| {
+            transfer_gas_payment_pda(
+                ctx,
+                token_id,
+                &solana_token,
+                transfer.destination_address.clone(),
+                transfer.amount,
+                &gas_token,
+            )
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    let batch_ix =
+        axelar_solana_its::instruction::with_gas_payment_accounts(batch_ix, &gas_payment_pdas);
+
+    let payer = ctx.solana_chain.fixture.payer.insecure_clone();
+    let tx = ctx
+        .solana_chain
+        .fixture
+        .send_tx_with_custom_signers(&[batch_ix], &[&payer])
+        .await
+        .unwrap_err();
+    assert!(tx
+        .find_log("is below the minimum payment of 400 for this token")
+        .is_some());
+
+    // Nothing was paid nor sent.
+    let gas_token_balance = ctx
+        .solana_chain
+        .fixture
+        .get_token_account(&gas_token_account)
+        .await
+        .amount;
+    assert_eq!(gas_token_balance, gas_value);
+
+    Ok(())
+}
+
 #[test_context(ItsTestContext)]
 #[tokio::test]
 async fn test_lock_unlock_transfer_fails_with_token_manager_as_authority(
@@ -585,7 +1053,7 @@ async fn test_lock_unlock_transfer_fails_with_token_manager_as_authority(
     ctx.send_solana_tx(&[create_ata_ix, mint_ix]).await.unwrap();
 
     // Try to transfer from the TokenManager to payer. This should fail after the fix
-    let clock_sysvar = ctx.solana_chain.get_sysvar::<Clock>().await;
+    let flow_epoch = ctx.flow_epoch(&token_id).await;
     let transfer_ix = axelar_solana_its::instruction::interchain_transfer(
         ctx.solana_chain.fixture.payer.pubkey(),
         token_manager_ata,
@@ -598,7 +1066,7 @@ async fn test_lock_unlock_transfer_fails_with_token_manager_as_authority(
         0,
         axelar_solana_gas_service::id(),
         ctx.solana_gas_utils.config_pda,
-        clock_sysvar.unix_timestamp,
+        flow_epoch,
     )
     .unwrap();
 
@@ -751,6 +1219,7 @@ async fn test_mint_burn_from_interchain_transfer_with_approval(
     // Mint tokens to bob through ITS
     let mint_amount = 1000;
     let mint_to_bob_ix = axelar_solana_its::instruction::interchain_token::mint(
+        ctx.solana_wallet,
         token_id,
         solana_token,
         bob_token_account,
@@ -787,7 +1256,7 @@ async fn test_mint_burn_from_interchain_transfer_with_approval(
 
     // Make solana_wallet perform an interchain transfer from bob's account using approved amount
     let transfer_amount = 300;
-    let clock_sysvar = ctx.solana_chain.get_sysvar::<Clock>().await;
+    let flow_epoch = ctx.flow_epoch(&token_id).await;
     let interchain_transfer_ix = axelar_solana_its::instruction::interchain_transfer(
         ctx.solana_wallet,
         bob_token_account,
@@ -800,7 +1269,7 @@ async fn test_mint_burn_from_interchain_transfer_with_approval(
         0,
         axelar_solana_gas_service::id(),
         ctx.solana_gas_utils.config_pda,
-        clock_sysvar.unix_timestamp,
+        flow_epoch,
     )?;
 
     let tx = ctx.send_solana_tx(&[interchain_transfer_ix]).await.unwrap();
@@ -391,6 +391,7 @@ async fn test_successful_handover_mint_authority(ctx: &mut ItsTestContext) {
 
     let mint_amount = 1000u64;
     let mint_ix = axelar_solana_its::instruction::interchain_token::mint(
+        alice.pubkey(),
         alice_token_id,
         alice_token_mint,
         alice_ata,
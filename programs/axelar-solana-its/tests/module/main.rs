@@ -25,19 +25,24 @@ mod flow_limits;
 mod from_evm_to_solana;
 mod from_solana_to_evm;
 mod handover_mint_authority;
+mod mint_extensions;
+mod mint_limits;
 mod pause_unpause;
 mod role_management;
+mod update_metadata;
 
+use borsh::BorshDeserialize;
 use event_utils::Event;
-use solana_program_test::BanksTransactionResultWithMetadata;
+use solana_program_test::{processor, BanksTransactionResultWithMetadata, ProgramTest};
 use solana_sdk::account::Account;
 use solana_sdk::account_info::Account as AccountTrait;
 use solana_sdk::account_info::IntoAccountInfo;
 use solana_sdk::clock::Clock;
-use solana_sdk::instruction::Instruction;
+use solana_sdk::instruction::{AccountMeta, Instruction};
 use solana_sdk::program_error::ProgramError;
 use solana_sdk::program_pack::Pack as _;
 use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
 use solana_sdk::signer::Signer;
 use solana_sdk::system_instruction;
 use test_context::AsyncTestContext;
@@ -45,7 +50,7 @@ use test_context::AsyncTestContext;
 use axelar_solana_encoding::types::messages::Message;
 use axelar_solana_gateway::processor::{CallContractEvent, GatewayEvent};
 use axelar_solana_gateway::state::incoming_message::command_id;
-use axelar_solana_gateway_test_fixtures::base::workspace_root_dir;
+use axelar_solana_gateway_test_fixtures::base::{workspace_root_dir, TestFixture};
 use axelar_solana_gateway_test_fixtures::gas_service::GasServiceUtils;
 use axelar_solana_gateway_test_fixtures::gateway::{
     get_gateway_events, random_message, ProgramInvocationState,
@@ -54,6 +59,8 @@ use axelar_solana_gateway_test_fixtures::{
     SolanaAxelarIntegration, SolanaAxelarIntegrationMetadata,
 };
 use axelar_solana_its::instruction::ItsGmpInstructionInputs;
+use axelar_solana_its::state::token_manager::TokenManager;
+use axelar_solana_its::state::trusted_chain::DecimalsPolicy;
 use evm_contracts_test_suite::chain::TestBlockchain;
 use evm_contracts_test_suite::ethers::abi::Detokenize;
 use evm_contracts_test_suite::ethers::contract::{ContractCall, EthLogDecode, Event as EvmEvent};
@@ -75,6 +82,9 @@ const EVM_CHAIN_NAME: &str = "ethereum";
 const ITS_HUB_TRUSTED_CHAIN_NAME: &str = "axelar";
 const ITS_HUB_TRUSTED_CONTRACT_ADDRESS: &str =
     "axelar157hl7gpuknjmhtac2qnphuazv2yerfagva7lsu9vuj2pgn32z22qa26dk4";
+/// The program id the SPL transfer hook example is deployed with.
+const TRANSFER_HOOK_PROGRAM_ID: Pubkey =
+    solana_sdk::pubkey!("7tS1ztosgwskEh7o4VCwzpxPrnG1XKcAyTcbF44HXvVu");
 
 pub struct ItsTestContext {
     pub solana_chain: SolanaAxelarIntegrationMetadata,
@@ -134,6 +144,22 @@ impl ItsTestContext {
         payload: &[u8],
         maybe_mint: Option<Pubkey>,
         token_program: Pubkey,
+    ) -> BanksTransactionResultWithMetadata {
+        self.relay_to_solana_with_transfer_hook_accounts(
+            payload,
+            maybe_mint,
+            token_program,
+            Vec::new(),
+        )
+        .await
+    }
+
+    async fn relay_to_solana_with_transfer_hook_accounts(
+        &mut self,
+        payload: &[u8],
+        maybe_mint: Option<Pubkey>,
+        token_program: Pubkey,
+        transfer_hook_accounts: Vec<AccountMeta>,
     ) -> BanksTransactionResultWithMetadata {
         let payload = route_its_hub(
             GMPPayload::decode(payload).unwrap(),
@@ -173,7 +199,10 @@ impl ItsTestContext {
             .unwrap()
             .clone();
 
-        let clock_sysvar = self.solana_chain.get_sysvar::<Clock>().await;
+        let flow_epoch = match payload.token_id() {
+            Ok(token_id) => Some(self.flow_epoch(&token_id).await),
+            Err(_) => None,
+        };
 
         let its_ix_inputs = ItsGmpInstructionInputs::builder()
             .payer(self.solana_chain.fixture.payer.pubkey())
@@ -182,8 +211,9 @@ impl ItsTestContext {
             .message(merkelised_message.leaf.message)
             .payload(payload)
             .token_program(token_program)
-            .timestamp(clock_sysvar.unix_timestamp)
+            .flow_epoch_opt(flow_epoch)
             .mint_opt(maybe_mint)
+            .transfer_hook_accounts(transfer_hook_accounts)
             .build();
 
         let instruction = axelar_solana_its::instruction::its_gmp_payload(its_ix_inputs)
@@ -201,6 +231,30 @@ impl ItsTestContext {
         self.solana_chain.fixture.send_tx(ixs).await
     }
 
+    /// Returns the current flow epoch of the `TokenManager` for the given token,
+    /// falling back to the default flow limit configuration if the
+    /// `TokenManager` doesn't exist yet.
+    async fn flow_epoch(&mut self, token_id: &[u8; 32]) -> u64 {
+        let (its_root_pda, _) = axelar_solana_its::find_its_root_pda();
+        let (token_manager_pda, _) =
+            axelar_solana_its::find_token_manager_pda(&its_root_pda, token_id);
+        let flow_limit = self
+            .solana_chain
+            .fixture
+            .try_get_account_no_checks(&token_manager_pda)
+            .await
+            .unwrap()
+            .map(|account| {
+                TokenManager::try_from_slice(&account.data)
+                    .unwrap()
+                    .flow_limit
+            })
+            .unwrap_or_default();
+        let clock_sysvar = self.solana_chain.get_sysvar::<Clock>().await;
+
+        flow_limit.flow_epoch(clock_sysvar.unix_timestamp).unwrap()
+    }
+
     async fn relay_to_evm(&mut self, payload: &[u8]) {
         let payload = route_its_hub(
             GMPPayload::decode(payload).unwrap(),
@@ -300,6 +354,7 @@ impl ItsTestContext {
                 salt,
                 self.evm_chain_name.clone(),
                 self.evm_signer.wallet.address().as_bytes().to_vec(),
+                None,
             )
             .unwrap();
 
@@ -348,7 +403,7 @@ impl ItsTestContext {
     ) {
         let amount = 100;
 
-        let clock_sysvar = self.solana_chain.get_sysvar::<Clock>().await;
+        let flow_epoch = self.flow_epoch(&token_id).await;
         let transfer_ix = axelar_solana_its::instruction::interchain_transfer(
             self.solana_wallet,
             token_account,
@@ -361,7 +416,7 @@ impl ItsTestContext {
             0,
             axelar_solana_gas_service::id(),
             self.solana_gas_utils.config_pda,
-            clock_sysvar.unix_timestamp,
+            flow_epoch,
         )
         .unwrap();
 
@@ -476,11 +531,19 @@ async fn axelar_solana_setup() -> (SolanaAxelarIntegrationMetadata, Pubkey) {
         ),
     ];
 
+    let mut program_test = ProgramTest::default();
+    program_test.add_program(
+        "spl_transfer_hook_example",
+        TRANSFER_HOOK_PROGRAM_ID,
+        processor!(spl_transfer_hook_example::processor::process),
+    );
+    let fixture = TestFixture::new(program_test).await;
+
     let mut solana_chain = SolanaAxelarIntegration::builder()
         .initial_signer_weights(vec![555, 222])
         .programs_to_deploy(programs)
         .build()
-        .setup()
+        .setup_with_fixture_and_authority(fixture, Keypair::new())
         .await;
 
     let (counter_pda, counter_bump) = axelar_solana_memo_program::get_counter_pda();
@@ -513,6 +576,8 @@ async fn axelar_solana_setup() -> (SolanaAxelarIntegrationMetadata, Pubkey) {
                 axelar_solana_its::instruction::set_trusted_chain(
                     solana_chain.upgrade_authority.pubkey(),
                     EVM_CHAIN_NAME.to_owned(),
+                    String::new(),
+                    DecimalsPolicy::Preserve,
                 )
                 .unwrap(),
             ],
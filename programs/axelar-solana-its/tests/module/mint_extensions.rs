@@ -0,0 +1,279 @@
+use axelar_solana_gateway_test_fixtures::base::FindLog;
+use evm_contracts_test_suite::ethers::signers::Signer as _;
+use interchain_token_transfer_gmp::{GMPPayload, InterchainTransfer, SendToHub};
+use solana_program_test::tokio;
+use solana_sdk::instruction::AccountMeta;
+use solana_sdk::native_token::LAMPORTS_PER_SOL;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer as _;
+use solana_sdk::system_instruction;
+use spl_associated_token_account::get_associated_token_address_with_program_id;
+use spl_associated_token_account::instruction::create_associated_token_account;
+use spl_token_2022::extension::ExtensionType;
+use spl_token_2022::state::Mint;
+use test_context::test_context;
+
+use axelar_solana_its::state::token_manager::Type as TokenManagerType;
+
+use crate::{ItsTestContext, TRANSFER_HOOK_PROGRAM_ID};
+
+/// Creates a Token-2022 mint whose transfers are checked by the SPL transfer
+/// hook example, which doesn't require any extra account.
+async fn init_transfer_hook_mint(ctx: &mut ItsTestContext) -> Pubkey {
+    let mint = Keypair::new();
+    let space =
+        ExtensionType::try_calculate_account_len::<Mint>(&[ExtensionType::TransferHook]).unwrap();
+    let rent = ctx.solana_chain.fixture.get_rent(space).await;
+
+    let create_mint_ixs = [
+        system_instruction::create_account(
+            &ctx.solana_wallet,
+            &mint.pubkey(),
+            rent,
+            space as u64,
+            &spl_token_2022::id(),
+        ),
+        spl_token_2022::extension::transfer_hook::instruction::initialize(
+            &spl_token_2022::id(),
+            &mint.pubkey(),
+            Some(ctx.solana_wallet),
+            Some(TRANSFER_HOOK_PROGRAM_ID),
+        )
+        .unwrap(),
+        spl_token_2022::instruction::initialize_mint2(
+            &spl_token_2022::id(),
+            &mint.pubkey(),
+            &ctx.solana_wallet,
+            None,
+            9,
+        )
+        .unwrap(),
+    ];
+    ctx.solana_chain
+        .fixture
+        .send_tx_with_custom_signers(
+            &create_mint_ixs,
+            &[
+                ctx.solana_chain.fixture.payer.insecure_clone(),
+                mint.insecure_clone(),
+            ],
+        )
+        .await
+        .unwrap();
+
+    let extra_account_metas = extra_account_metas_address(&mint.pubkey());
+    ctx.solana_chain
+        .fixture
+        .fund_account(&extra_account_metas, LAMPORTS_PER_SOL)
+        .await;
+    let init_extra_account_metas_ix =
+        spl_transfer_hook_interface::instruction::initialize_extra_account_meta_list(
+            &TRANSFER_HOOK_PROGRAM_ID,
+            &extra_account_metas,
+            &mint.pubkey(),
+            &ctx.solana_wallet,
+            &[],
+        );
+    ctx.send_solana_tx(&[init_extra_account_metas_ix])
+        .await
+        .unwrap();
+
+    mint.pubkey()
+}
+
+fn extra_account_metas_address(mint: &Pubkey) -> Pubkey {
+    spl_transfer_hook_interface::get_extra_account_metas_address(mint, &TRANSFER_HOOK_PROGRAM_ID)
+}
+
+/// The accounts the token program needs to invoke the hook, as resolved by
+/// `spl_token_2022::offchain::add_extra_account_metas` for a hook without extra
+/// accounts.
+fn transfer_hook_accounts(mint: &Pubkey) -> Vec<AccountMeta> {
+    vec![
+        AccountMeta::new_readonly(extra_account_metas_address(mint), false),
+        AccountMeta::new_readonly(TRANSFER_HOOK_PROGRAM_ID, false),
+    ]
+}
+
+#[test_context(ItsTestContext)]
+#[tokio::test]
+async fn test_transfer_fee_mint_requires_lock_unlock_fee(
+    ctx: &mut ItsTestContext,
+) -> anyhow::Result<()> {
+    let mint = ctx
+        .solana_chain
+        .fixture
+        .init_new_mint_with_fee(
+            &ctx.solana_wallet,
+            &spl_token_2022::id(),
+            100,
+            1_000,
+            9,
+            None,
+            None,
+        )
+        .await;
+
+    let register_lock_unlock_ix = axelar_solana_its::instruction::register_custom_token(
+        ctx.solana_wallet,
+        solana_sdk::keccak::hash(b"lock-unlock-salt").to_bytes(),
+        mint,
+        TokenManagerType::LockUnlock,
+        spl_token_2022::id(),
+        None,
+    )?;
+
+    assert!(ctx
+        .send_solana_tx(&[register_lock_unlock_ix])
+        .await
+        .unwrap_err()
+        .find_log("require the LockUnlockFee TokenManager type")
+        .is_some());
+
+    let register_lock_unlock_fee_ix = axelar_solana_its::instruction::register_custom_token(
+        ctx.solana_wallet,
+        solana_sdk::keccak::hash(b"lock-unlock-fee-salt").to_bytes(),
+        mint,
+        TokenManagerType::LockUnlockFee,
+        spl_token_2022::id(),
+        None,
+    )?;
+
+    ctx.send_solana_tx(&[register_lock_unlock_fee_ix])
+        .await
+        .unwrap();
+
+    Ok(())
+}
+
+#[test_context(ItsTestContext)]
+#[tokio::test]
+async fn test_lock_unlock_with_transfer_hook(ctx: &mut ItsTestContext) -> anyhow::Result<()> {
+    let mint = init_transfer_hook_mint(ctx).await;
+    let token_account = get_associated_token_address_with_program_id(
+        &ctx.solana_wallet,
+        &mint,
+        &spl_token_2022::id(),
+    );
+    let initial_balance = 300;
+    ctx.send_solana_tx(&[
+        create_associated_token_account(
+            &ctx.solana_wallet,
+            &ctx.solana_wallet,
+            &mint,
+            &spl_token_2022::id(),
+        ),
+        spl_token_2022::instruction::mint_to(
+            &spl_token_2022::id(),
+            &mint,
+            &token_account,
+            &ctx.solana_wallet,
+            &[],
+            initial_balance,
+        )?,
+    ])
+    .await
+    .unwrap();
+
+    let salt = solana_sdk::keccak::hash(b"transfer-hook-salt").to_bytes();
+    let token_id = axelar_solana_its::linked_token_id(&ctx.solana_wallet, &salt);
+    let register_ix = axelar_solana_its::instruction::register_custom_token(
+        ctx.solana_wallet,
+        salt,
+        mint,
+        TokenManagerType::LockUnlock,
+        spl_token_2022::id(),
+        None,
+    )?;
+    ctx.send_solana_tx(&[register_ix]).await.unwrap();
+
+    let (its_root_pda, _) = axelar_solana_its::find_its_root_pda();
+    let (token_manager_pda, _) =
+        axelar_solana_its::find_token_manager_pda(&its_root_pda, &token_id);
+    let token_manager_ata = get_associated_token_address_with_program_id(
+        &token_manager_pda,
+        &mint,
+        &spl_token_2022::id(),
+    );
+
+    // Lock the tokens, which invokes the hook with the accounts it requires.
+    let flow_epoch = ctx.flow_epoch(&token_id).await;
+    let transfer_ix = axelar_solana_its::instruction::interchain_transfer(
+        ctx.solana_wallet,
+        token_account,
+        token_id,
+        ctx.evm_chain_name.clone(),
+        ctx.evm_signer.wallet.address().as_bytes().to_vec(),
+        initial_balance,
+        mint,
+        spl_token_2022::id(),
+        0,
+        axelar_solana_gas_service::id(),
+        ctx.solana_gas_utils.config_pda,
+        flow_epoch,
+    )?;
+
+    assert!(ctx.send_solana_tx(&[transfer_ix.clone()]).await.is_err());
+
+    let transfer_ix = axelar_solana_its::instruction::with_transfer_hook_accounts(
+        transfer_ix,
+        transfer_hook_accounts(&mint),
+    );
+    ctx.send_solana_tx(&[transfer_ix]).await.unwrap();
+
+    assert_eq!(
+        ctx.solana_chain
+            .fixture
+            .get_token_account(&token_manager_ata)
+            .await
+            .amount,
+        initial_balance
+    );
+
+    // Unlock the tokens coming back, the hook accounts are passed to the ITS
+    // instruction of the incoming message.
+    let payload = GMPPayload::SendToHub(SendToHub {
+        selector: SendToHub::MESSAGE_TYPE_ID.try_into()?,
+        destination_chain: ctx.solana_chain_name.clone(),
+        payload: GMPPayload::InterchainTransfer(InterchainTransfer {
+            selector: InterchainTransfer::MESSAGE_TYPE_ID.try_into()?,
+            token_id: token_id.into(),
+            source_address: ctx.evm_signer.wallet.address().as_bytes().to_vec().into(),
+            destination_address: token_account.to_bytes().into(),
+            amount: alloy_primitives::U256::from(initial_balance),
+            data: Vec::new().into(),
+        })
+        .encode()
+        .into(),
+    });
+
+    let tx = ctx
+        .relay_to_solana_with_transfer_hook_accounts(
+            &payload.encode(),
+            Some(mint),
+            spl_token_2022::id(),
+            transfer_hook_accounts(&mint),
+        )
+        .await;
+    assert!(tx.result.is_ok());
+
+    assert_eq!(
+        ctx.solana_chain
+            .fixture
+            .get_token_account(&token_account)
+            .await
+            .amount,
+        initial_balance
+    );
+    assert_eq!(
+        ctx.solana_chain
+            .fixture
+            .get_token_account(&token_manager_ata)
+            .await
+            .amount,
+        0
+    );
+
+    Ok(())
+}
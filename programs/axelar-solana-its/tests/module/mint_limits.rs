@@ -0,0 +1,177 @@
+use anyhow::anyhow;
+use axelar_solana_gateway_test_fixtures::base::FindLog;
+use event_utils::Event as _;
+use solana_program_test::tokio;
+use solana_sdk::program_pack::Pack;
+use solana_sdk::pubkey::Pubkey;
+use spl_associated_token_account::get_associated_token_address_with_program_id;
+use test_context::test_context;
+
+use crate::ItsTestContext;
+
+async fn deploy_token_with_minter(
+    ctx: &mut ItsTestContext,
+    salt: &[u8],
+) -> anyhow::Result<([u8; 32], Pubkey, Pubkey)> {
+    let (its_root_pda, _) = axelar_solana_its::find_its_root_pda();
+    let salt = solana_sdk::keccak::hash(salt).0;
+
+    let deploy_local_ix = axelar_solana_its::instruction::deploy_interchain_token(
+        ctx.solana_wallet,
+        salt,
+        "Capped Token".to_owned(),
+        "CAP".to_owned(),
+        9,
+        0,
+        Some(ctx.solana_wallet),
+    )?;
+
+    let token_id = axelar_solana_its::interchain_token_id(&ctx.solana_wallet, &salt);
+    let (interchain_token_pda, _) =
+        axelar_solana_its::find_interchain_token_pda(&its_root_pda, &token_id);
+    let payer_ata = get_associated_token_address_with_program_id(
+        &ctx.solana_wallet,
+        &interchain_token_pda,
+        &spl_token_2022::id(),
+    );
+    let create_token_account_ix =
+        spl_associated_token_account::instruction::create_associated_token_account(
+            &ctx.solana_wallet,
+            &ctx.solana_wallet,
+            &interchain_token_pda,
+            &spl_token_2022::id(),
+        );
+
+    ctx.send_solana_tx(&[deploy_local_ix, create_token_account_ix])
+        .await
+        .expect("InterchainToken deployment failed");
+
+    Ok((token_id, interchain_token_pda, payer_ata))
+}
+
+async fn token_balance(ctx: &mut ItsTestContext, token_account: &Pubkey) -> anyhow::Result<u64> {
+    let token_account_data = ctx
+        .solana_chain
+        .try_get_account_no_checks(token_account)
+        .await?
+        .ok_or_else(|| anyhow!("token account not found"))?
+        .data;
+
+    Ok(spl_token_2022::state::Account::unpack_from_slice(&token_account_data)?.amount)
+}
+
+#[test_context(ItsTestContext)]
+#[tokio::test]
+async fn test_mint_respects_supply_cap(ctx: &mut ItsTestContext) -> anyhow::Result<()> {
+    let (token_id, mint, payer_ata) = deploy_token_with_minter(ctx, b"SupplyCapToken").await?;
+
+    let set_supply_cap_ix = axelar_solana_its::instruction::token_manager::set_supply_cap(
+        ctx.solana_wallet,
+        token_id,
+        1_000,
+    )?;
+    let tx = ctx
+        .send_solana_tx(&[set_supply_cap_ix])
+        .await
+        .expect("Setting the supply cap failed");
+
+    let supply_cap_event = tx
+        .metadata
+        .unwrap()
+        .log_messages
+        .iter()
+        .find_map(|log| axelar_solana_its::event::SupplyCapSet::try_from_log(log).ok())
+        .unwrap();
+    assert_eq!(supply_cap_event.token_id, token_id);
+    assert_eq!(supply_cap_event.supply_cap, 1_000);
+
+    let mint_ix = axelar_solana_its::instruction::interchain_token::mint(
+        ctx.solana_wallet,
+        token_id,
+        mint,
+        payer_ata,
+        ctx.solana_wallet,
+        spl_token_2022::id(),
+        800,
+    )?;
+    ctx.send_solana_tx(&[mint_ix])
+        .await
+        .expect("Minting within the supply cap failed");
+
+    let mint_ix = axelar_solana_its::instruction::interchain_token::mint(
+        ctx.solana_wallet,
+        token_id,
+        mint,
+        payer_ata,
+        ctx.solana_wallet,
+        spl_token_2022::id(),
+        201,
+    )?;
+    let err = ctx.send_solana_tx(&[mint_ix]).await.unwrap_err();
+    assert!(err.find_log("Supply cap exceeded").is_some());
+
+    assert_eq!(token_balance(ctx, &payer_ata).await?, 800);
+
+    Ok(())
+}
+
+#[test_context(ItsTestContext)]
+#[tokio::test]
+async fn test_mint_respects_minter_allowance(ctx: &mut ItsTestContext) -> anyhow::Result<()> {
+    let (token_id, mint, payer_ata) =
+        deploy_token_with_minter(ctx, b"MinterAllowanceToken").await?;
+
+    let set_allowance_ix = axelar_solana_its::instruction::interchain_token::set_minter_allowance(
+        ctx.solana_wallet,
+        token_id,
+        ctx.solana_wallet,
+        500,
+        60 * 60,
+    )?;
+    let tx = ctx
+        .send_solana_tx(&[set_allowance_ix])
+        .await
+        .expect("Setting the minter allowance failed");
+
+    let allowance_event = tx
+        .metadata
+        .unwrap()
+        .log_messages
+        .iter()
+        .find_map(|log| axelar_solana_its::event::MinterAllowanceSet::try_from_log(log).ok())
+        .unwrap();
+    assert_eq!(allowance_event.minter, ctx.solana_wallet);
+    assert_eq!(allowance_event.allowance, 500);
+    assert_eq!(allowance_event.refill_period, 60 * 60);
+
+    for amount in [300, 200] {
+        let mint_ix = axelar_solana_its::instruction::interchain_token::mint(
+            ctx.solana_wallet,
+            token_id,
+            mint,
+            payer_ata,
+            ctx.solana_wallet,
+            spl_token_2022::id(),
+            amount,
+        )?;
+        ctx.send_solana_tx(&[mint_ix])
+            .await
+            .expect("Minting within the allowance failed");
+    }
+
+    let mint_ix = axelar_solana_its::instruction::interchain_token::mint(
+        ctx.solana_wallet,
+        token_id,
+        mint,
+        payer_ata,
+        ctx.solana_wallet,
+        spl_token_2022::id(),
+        1,
+    )?;
+    let err = ctx.send_solana_tx(&[mint_ix]).await.unwrap_err();
+    assert!(err.find_log("Minter allowance exceeded").is_some());
+
+    assert_eq!(token_balance(ctx, &payer_ata).await?, 500);
+
+    Ok(())
+}
@@ -1,4 +1,5 @@
 use borsh::BorshDeserialize;
+use solana_program::clock::Clock;
 use solana_program::instruction::AccountMeta;
 use solana_program_test::tokio;
 use solana_sdk::signature::Keypair;
@@ -11,9 +12,16 @@ use test_context::test_context;
 
 use axelar_solana_gateway_test_fixtures::base::FindLog;
 use axelar_solana_its::{
-    instruction::InterchainTokenServiceInstruction, state::token_manager::TokenManager, Roles,
+    instruction::InterchainTokenServiceInstruction,
+    state::{
+        flow_limit::FlowLimit,
+        minter_allowance::MinterAllowance,
+        token_manager::TokenManager,
+        trusted_chain::{DecimalsPolicy, TrustedChain},
+    },
+    Roles,
 };
-use role_management::state::UserRoles;
+use role_management::state::{RoleIndex, UserRoles};
 
 use crate::ItsTestContext;
 
@@ -100,7 +108,7 @@ async fn test_successful_proposal_acceptance(ctx: &mut ItsTestContext) {
     let roles_to_transfer = Roles::OPERATOR;
 
     let proposal_ix =
-        axelar_solana_its::instruction::propose_operatorship(ctx.solana_wallet, bob.pubkey())
+        axelar_solana_its::instruction::propose_operatorship(ctx.solana_wallet, bob.pubkey(), None)
             .unwrap();
 
     ctx.send_solana_tx(&[proposal_ix]).await.unwrap();
@@ -231,6 +239,602 @@ async fn test_successful_add_and_remove_flow_limiter(ctx: &mut ItsTestContext) {
     assert!(!bob_roles.contains(Roles::FLOW_LIMITER));
 }
 
+#[test_context(ItsTestContext)]
+#[tokio::test]
+async fn test_flow_limiter_grant_with_limited_uses(ctx: &mut ItsTestContext) {
+    let token_id = ctx.deployed_interchain_token;
+    let (its_root_pda, _) = axelar_solana_its::find_its_root_pda();
+    let (token_manager_pda, _) =
+        axelar_solana_its::find_token_manager_pda(&its_root_pda, &token_id);
+    let bob = Keypair::new();
+    let payer_keys = ctx.solana_chain.fixture.payer.insecure_clone();
+
+    let grant_flow_limiter_ix = axelar_solana_its::instruction::token_manager::grant_flow_limiter(
+        ctx.solana_chain.fixture.payer.pubkey(),
+        token_id,
+        bob.pubkey(),
+        None,
+        Some(1),
+    )
+    .unwrap();
+
+    ctx.send_solana_tx(&[grant_flow_limiter_ix]).await.unwrap();
+
+    let set_flow_limit_ix = axelar_solana_its::instruction::token_manager::set_flow_limit(
+        bob.pubkey(),
+        token_id,
+        FlowLimit::new(100, 100),
+    )
+    .unwrap();
+
+    ctx.solana_chain
+        .send_tx_with_custom_signers(
+            &[set_flow_limit_ix],
+            &[bob.insecure_clone(), payer_keys.insecure_clone()],
+        )
+        .await
+        .unwrap();
+
+    let set_flow_limit_ix = axelar_solana_its::instruction::token_manager::set_flow_limit(
+        bob.pubkey(),
+        token_id,
+        FlowLimit::new(200, 200),
+    )
+    .unwrap();
+
+    assert!(ctx
+        .solana_chain
+        .send_tx_with_custom_signers(
+            &[set_flow_limit_ix],
+            &[bob.insecure_clone(), payer_keys.insecure_clone()],
+        )
+        .await
+        .unwrap_err()
+        .find_log("User doesn't have the required roles")
+        .is_some());
+
+    // Anyone can clean up the exhausted grant, which closes Bob's roles account.
+    let cleanup_ix = axelar_solana_its::instruction::cleanup_expired_roles(
+        ctx.solana_chain.fixture.payer.pubkey(),
+        token_manager_pda,
+        bob.pubkey(),
+    )
+    .unwrap();
+
+    ctx.send_solana_tx(&[cleanup_ix]).await.unwrap();
+
+    let (bob_roles_pda, _) = role_management::find_user_roles_pda(
+        &axelar_solana_its::id(),
+        &token_manager_pda,
+        &bob.pubkey(),
+    );
+
+    assert!(ctx
+        .solana_chain
+        .fixture
+        .try_get_account_no_checks(&bob_roles_pda)
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[test_context(ItsTestContext)]
+#[tokio::test]
+async fn test_minter_grant_with_limited_uses(ctx: &mut ItsTestContext) {
+    let token_id = ctx.deployed_interchain_token;
+    let (its_root_pda, _) = axelar_solana_its::find_its_root_pda();
+    let (interchain_token_pda, _) =
+        axelar_solana_its::find_interchain_token_pda(&its_root_pda, &token_id);
+    let bob = Keypair::new();
+    let payer_keys = ctx.solana_chain.fixture.payer.insecure_clone();
+    let bob_ata = get_associated_token_address_with_program_id(
+        &bob.pubkey(),
+        &interchain_token_pda,
+        &spl_token_2022::id(),
+    );
+
+    let grant_minter_ix = axelar_solana_its::instruction::interchain_token::grant_minter(
+        ctx.solana_chain.fixture.payer.pubkey(),
+        token_id,
+        bob.pubkey(),
+        None,
+        Some(1),
+    )
+    .unwrap();
+    let create_ata_ix = create_associated_token_account(
+        &ctx.solana_chain.fixture.payer.pubkey(),
+        &bob.pubkey(),
+        &interchain_token_pda,
+        &spl_token_2022::id(),
+    );
+
+    ctx.send_solana_tx(&[grant_minter_ix, create_ata_ix])
+        .await
+        .unwrap();
+
+    let mint_ix = axelar_solana_its::instruction::interchain_token::mint(
+        ctx.solana_chain.fixture.payer.pubkey(),
+        token_id,
+        interchain_token_pda,
+        bob_ata,
+        bob.pubkey(),
+        spl_token_2022::id(),
+        100,
+    )
+    .unwrap();
+
+    // Bob can't hand out the minter role he only holds through a grant.
+    let regrant_ix = axelar_solana_its::instruction::interchain_token::grant_minter(
+        bob.pubkey(),
+        token_id,
+        Keypair::new().pubkey(),
+        None,
+        None,
+    )
+    .unwrap();
+
+    assert!(ctx
+        .solana_chain
+        .send_tx_with_custom_signers(
+            &[regrant_ix],
+            &[bob.insecure_clone(), payer_keys.insecure_clone()],
+        )
+        .await
+        .is_err());
+
+    // Nor can he make it permanent by transferring it.
+    let transfer_mintership_ix =
+        axelar_solana_its::instruction::interchain_token::transfer_mintership(
+            bob.pubkey(),
+            token_id,
+            Keypair::new().pubkey(),
+        )
+        .unwrap();
+
+    assert!(ctx
+        .solana_chain
+        .send_tx_with_custom_signers(
+            &[transfer_mintership_ix],
+            &[bob.insecure_clone(), payer_keys.insecure_clone()],
+        )
+        .await
+        .unwrap_err()
+        .find_log("Only roles held permanently allow handing over roles")
+        .is_some());
+
+    ctx.solana_chain
+        .send_tx_with_custom_signers(
+            &[mint_ix.clone()],
+            &[bob.insecure_clone(), payer_keys.insecure_clone()],
+        )
+        .await
+        .unwrap();
+
+    assert!(ctx
+        .solana_chain
+        .send_tx_with_custom_signers(
+            &[mint_ix],
+            &[bob.insecure_clone(), payer_keys.insecure_clone()],
+        )
+        .await
+        .unwrap_err()
+        .find_log("User doesn't have the required roles")
+        .is_some());
+}
+
+#[test_context(ItsTestContext)]
+#[tokio::test]
+async fn test_flow_limiter_grant_expires(ctx: &mut ItsTestContext) {
+    let token_id = ctx.deployed_interchain_token;
+    let bob = Keypair::new();
+    let payer_keys = ctx.solana_chain.fixture.payer.insecure_clone();
+    let clock = ctx.solana_chain.get_sysvar::<Clock>().await;
+
+    let grant_flow_limiter_ix = axelar_solana_its::instruction::token_manager::grant_flow_limiter(
+        ctx.solana_chain.fixture.payer.pubkey(),
+        token_id,
+        bob.pubkey(),
+        Some(clock.unix_timestamp + 60),
+        None,
+    )
+    .unwrap();
+
+    ctx.send_solana_tx(&[grant_flow_limiter_ix]).await.unwrap();
+
+    let set_flow_limit_ix = axelar_solana_its::instruction::token_manager::set_flow_limit(
+        bob.pubkey(),
+        token_id,
+        FlowLimit::new(100, 100),
+    )
+    .unwrap();
+
+    ctx.solana_chain
+        .send_tx_with_custom_signers(
+            &[set_flow_limit_ix],
+            &[bob.insecure_clone(), payer_keys.insecure_clone()],
+        )
+        .await
+        .unwrap();
+
+    ctx.solana_chain.fixture.forward_time(60).await;
+
+    let set_flow_limit_ix = axelar_solana_its::instruction::token_manager::set_flow_limit(
+        bob.pubkey(),
+        token_id,
+        FlowLimit::new(200, 200),
+    )
+    .unwrap();
+
+    assert!(ctx
+        .solana_chain
+        .send_tx_with_custom_signers(
+            &[set_flow_limit_ix],
+            &[bob.insecure_clone(), payer_keys.insecure_clone()],
+        )
+        .await
+        .unwrap_err()
+        .find_log("User doesn't have the required roles")
+        .is_some());
+}
+
+#[test_context(ItsTestContext)]
+#[tokio::test]
+async fn test_multisig_operator_sets_supply_cap(ctx: &mut ItsTestContext) {
+    let token_id = ctx.deployed_interchain_token;
+    let (its_root_pda, _) = axelar_solana_its::find_its_root_pda();
+    let (token_manager_pda, _) =
+        axelar_solana_its::find_token_manager_pda(&its_root_pda, &token_id);
+    let payer_keys = ctx.solana_chain.fixture.payer.insecure_clone();
+    let alice = Keypair::new();
+    let bob = Keypair::new();
+    let carol = Keypair::new();
+    let members = vec![alice.pubkey(), bob.pubkey(), carol.pubkey()];
+    let (multisig, _) = role_management::find_multisig_pda(&axelar_solana_its::id(), &members, 2);
+
+    let create_multisig_ix =
+        axelar_solana_its::instruction::create_multisig(payer_keys.pubkey(), members, 2).unwrap();
+    let transfer_operatorship_ix =
+        axelar_solana_its::instruction::token_manager::transfer_operatorship(
+            payer_keys.pubkey(),
+            token_id,
+            multisig,
+        )
+        .unwrap();
+
+    ctx.send_solana_tx(&[create_multisig_ix, transfer_operatorship_ix])
+        .await
+        .unwrap();
+
+    let set_supply_cap_ix =
+        axelar_solana_its::instruction::token_manager::set_supply_cap(multisig, token_id, 1_000)
+            .unwrap();
+
+    // A single member isn't enough to reach the threshold.
+    assert!(ctx
+        .solana_chain
+        .send_tx_with_custom_signers(
+            &[axelar_solana_its::instruction::with_multisig_signers(
+                set_supply_cap_ix.clone(),
+                multisig,
+                &[alice.pubkey()],
+            )],
+            &[alice.insecure_clone(), payer_keys.insecure_clone()],
+        )
+        .await
+        .unwrap_err()
+        .find_log("Multisig threshold not reached")
+        .is_some());
+
+    ctx.solana_chain
+        .send_tx_with_custom_signers(
+            &[axelar_solana_its::instruction::with_multisig_signers(
+                set_supply_cap_ix,
+                multisig,
+                &[alice.pubkey(), carol.pubkey()],
+            )],
+            &[
+                alice.insecure_clone(),
+                carol.insecure_clone(),
+                payer_keys.insecure_clone(),
+            ],
+        )
+        .await
+        .unwrap();
+
+    let data = ctx
+        .solana_chain
+        .fixture
+        .get_account(&token_manager_pda, &axelar_solana_its::id())
+        .await
+        .data;
+    let token_manager = TokenManager::try_from_slice(&data).unwrap();
+
+    assert_eq!(token_manager.supply_cap, 1_000);
+
+    // The previous operator doesn't hold the role anymore.
+    let set_supply_cap_ix = axelar_solana_its::instruction::token_manager::set_supply_cap(
+        payer_keys.pubkey(),
+        token_id,
+        2_000,
+    )
+    .unwrap();
+
+    assert!(ctx
+        .send_solana_tx(&[set_supply_cap_ix])
+        .await
+        .unwrap_err()
+        .find_log("User doesn't have the required roles")
+        .is_some());
+}
+
+#[test_context(ItsTestContext)]
+#[tokio::test]
+async fn test_multisig_operator_sets_minter_allowance(ctx: &mut ItsTestContext) {
+    let token_id = ctx.deployed_interchain_token;
+    let (its_root_pda, _) = axelar_solana_its::find_its_root_pda();
+    let (token_manager_pda, _) =
+        axelar_solana_its::find_token_manager_pda(&its_root_pda, &token_id);
+    let payer_keys = ctx.solana_chain.fixture.payer.insecure_clone();
+    let alice = Keypair::new();
+    let bob = Keypair::new();
+    let minter = Keypair::new();
+    let members = vec![alice.pubkey(), bob.pubkey()];
+    let (multisig, _) = role_management::find_multisig_pda(&axelar_solana_its::id(), &members, 2);
+
+    let create_multisig_ix =
+        axelar_solana_its::instruction::create_multisig(payer_keys.pubkey(), members, 2).unwrap();
+    let transfer_operatorship_ix =
+        axelar_solana_its::instruction::token_manager::transfer_operatorship(
+            payer_keys.pubkey(),
+            token_id,
+            multisig,
+        )
+        .unwrap();
+
+    ctx.send_solana_tx(&[create_multisig_ix, transfer_operatorship_ix])
+        .await
+        .unwrap();
+
+    let set_allowance_ix = axelar_solana_its::instruction::interchain_token::set_minter_allowance(
+        multisig,
+        token_id,
+        minter.pubkey(),
+        500,
+        60,
+    )
+    .unwrap();
+
+    // A single member isn't enough to reach the threshold.
+    assert!(ctx
+        .solana_chain
+        .send_tx_with_custom_signers(
+            &[axelar_solana_its::instruction::with_multisig_authority(
+                set_allowance_ix.clone(),
+                multisig,
+                payer_keys.pubkey(),
+                &[alice.pubkey()],
+            )],
+            &[alice.insecure_clone(), payer_keys.insecure_clone()],
+        )
+        .await
+        .unwrap_err()
+        .find_log("Multisig threshold not reached")
+        .is_some());
+
+    // The payer pays for the rent of the new allowance.
+    ctx.solana_chain
+        .send_tx_with_custom_signers(
+            &[axelar_solana_its::instruction::with_multisig_authority(
+                set_allowance_ix,
+                multisig,
+                payer_keys.pubkey(),
+                &[alice.pubkey(), bob.pubkey()],
+            )],
+            &[
+                alice.insecure_clone(),
+                bob.insecure_clone(),
+                payer_keys.insecure_clone(),
+            ],
+        )
+        .await
+        .unwrap();
+
+    let (minter_allowance_pda, _) =
+        axelar_solana_its::find_minter_allowance_pda(&token_manager_pda, &minter.pubkey());
+    let data = ctx
+        .solana_chain
+        .fixture
+        .get_account(&minter_allowance_pda, &axelar_solana_its::id())
+        .await
+        .data;
+    let minter_allowance = MinterAllowance::try_from_slice(&data).unwrap();
+
+    assert_eq!(minter_allowance.allowance, 500);
+    assert_eq!(minter_allowance.refill_period, 60);
+}
+
+#[test_context(ItsTestContext)]
+#[tokio::test]
+async fn test_multisig_operator_adds_flow_limiter(ctx: &mut ItsTestContext) {
+    let token_id = ctx.deployed_interchain_token;
+    let (its_root_pda, _) = axelar_solana_its::find_its_root_pda();
+    let (token_manager_pda, _) =
+        axelar_solana_its::find_token_manager_pda(&its_root_pda, &token_id);
+    let payer_keys = ctx.solana_chain.fixture.payer.insecure_clone();
+    let alice = Keypair::new();
+    let bob = Keypair::new();
+    let dave = Keypair::new();
+    let members = vec![alice.pubkey(), bob.pubkey()];
+    let (multisig, _) = role_management::find_multisig_pda(&axelar_solana_its::id(), &members, 2);
+
+    let create_multisig_ix =
+        axelar_solana_its::instruction::create_multisig(payer_keys.pubkey(), members, 2).unwrap();
+    let transfer_operatorship_ix =
+        axelar_solana_its::instruction::token_manager::transfer_operatorship(
+            payer_keys.pubkey(),
+            token_id,
+            multisig,
+        )
+        .unwrap();
+
+    ctx.send_solana_tx(&[create_multisig_ix, transfer_operatorship_ix])
+        .await
+        .unwrap();
+
+    let add_flow_limiter_ix = axelar_solana_its::instruction::token_manager::add_flow_limiter(
+        multisig,
+        token_id,
+        dave.pubkey(),
+    )
+    .unwrap();
+
+    // A single member isn't enough to reach the threshold.
+    assert!(ctx
+        .solana_chain
+        .send_tx_with_custom_signers(
+            &[axelar_solana_its::instruction::with_multisig_authority(
+                add_flow_limiter_ix.clone(),
+                multisig,
+                payer_keys.pubkey(),
+                &[alice.pubkey()],
+            )],
+            &[alice.insecure_clone(), payer_keys.insecure_clone()],
+        )
+        .await
+        .unwrap_err()
+        .find_log("Multisig threshold not reached")
+        .is_some());
+
+    ctx.solana_chain
+        .send_tx_with_custom_signers(
+            &[axelar_solana_its::instruction::with_multisig_authority(
+                add_flow_limiter_ix,
+                multisig,
+                payer_keys.pubkey(),
+                &[alice.pubkey(), bob.pubkey()],
+            )],
+            &[
+                alice.insecure_clone(),
+                bob.insecure_clone(),
+                payer_keys.insecure_clone(),
+            ],
+        )
+        .await
+        .unwrap();
+
+    let (dave_roles_pda, _) = role_management::find_user_roles_pda(
+        &axelar_solana_its::id(),
+        &token_manager_pda,
+        &dave.pubkey(),
+    );
+    let data = ctx
+        .solana_chain
+        .fixture
+        .get_account(&dave_roles_pda, &axelar_solana_its::id())
+        .await
+        .data;
+    let dave_roles = UserRoles::<Roles>::try_from_slice(&data).unwrap();
+
+    assert!(dave_roles.contains(Roles::FLOW_LIMITER));
+}
+
+#[test_context(ItsTestContext)]
+#[tokio::test]
+async fn test_role_index_records_its_operator_on_initialization(ctx: &mut ItsTestContext) {
+    let (its_root_pda, _) = axelar_solana_its::find_its_root_pda();
+    let (role_index_pda, _) =
+        role_management::find_role_index_pda(&axelar_solana_its::id(), &its_root_pda);
+
+    let data = ctx
+        .solana_chain
+        .fixture
+        .get_account(&role_index_pda, &axelar_solana_its::id())
+        .await
+        .data;
+    let role_index = RoleIndex::<Roles>::try_from_slice(&data).unwrap();
+
+    assert_eq!(
+        role_index.roles_of(&ctx.solana_chain.fixture.payer.pubkey()),
+        Some(Roles::OPERATOR)
+    );
+}
+
+#[test_context(ItsTestContext)]
+#[tokio::test]
+async fn test_role_index_tracks_token_manager_roles(ctx: &mut ItsTestContext) {
+    let token_id = ctx.deployed_interchain_token;
+    let (its_root_pda, _) = axelar_solana_its::find_its_root_pda();
+    let (token_manager_pda, _) =
+        axelar_solana_its::find_token_manager_pda(&its_root_pda, &token_id);
+    let (role_index_pda, _) =
+        role_management::find_role_index_pda(&axelar_solana_its::id(), &token_manager_pda);
+    let bob = Keypair::new();
+
+    // The roles set up when the token manager was deployed are indexed right away.
+    let data = ctx
+        .solana_chain
+        .fixture
+        .get_account(&role_index_pda, &axelar_solana_its::id())
+        .await
+        .data;
+    let role_index = RoleIndex::<Roles>::try_from_slice(&data).unwrap();
+
+    assert_eq!(
+        role_index.roles_of(&its_root_pda),
+        Some(Roles::OPERATOR | Roles::FLOW_LIMITER)
+    );
+    assert_eq!(
+        role_index.roles_of(&ctx.solana_wallet),
+        Some(Roles::OPERATOR | Roles::FLOW_LIMITER | Roles::MINTER)
+    );
+
+    let add_flow_limiter_ix = axelar_solana_its::instruction::token_manager::add_flow_limiter(
+        ctx.solana_chain.fixture.payer.pubkey(),
+        token_id,
+        bob.pubkey(),
+    )
+    .unwrap();
+
+    ctx.send_solana_tx(&[add_flow_limiter_ix]).await.unwrap();
+
+    let data = ctx
+        .solana_chain
+        .fixture
+        .get_account(&role_index_pda, &axelar_solana_its::id())
+        .await
+        .data;
+    let role_index = RoleIndex::<Roles>::try_from_slice(&data).unwrap();
+
+    assert_eq!(
+        role_index.roles_of(&bob.pubkey()),
+        Some(Roles::FLOW_LIMITER)
+    );
+
+    let remove_flow_limiter_ix =
+        axelar_solana_its::instruction::token_manager::remove_flow_limiter(
+            ctx.solana_chain.fixture.payer.pubkey(),
+            token_id,
+            bob.pubkey(),
+        )
+        .unwrap();
+
+    ctx.send_solana_tx(&[remove_flow_limiter_ix]).await.unwrap();
+
+    let data = ctx
+        .solana_chain
+        .fixture
+        .get_account(&role_index_pda, &axelar_solana_its::id())
+        .await
+        .data;
+    let role_index = RoleIndex::<Roles>::try_from_slice(&data).unwrap();
+
+    assert_eq!(role_index.roles_of(&bob.pubkey()), None);
+    assert_eq!(
+        role_index.roles_of(&its_root_pda),
+        Some(Roles::OPERATOR | Roles::FLOW_LIMITER)
+    );
+}
+
 #[test_context(ItsTestContext)]
 #[tokio::test]
 async fn test_successful_token_manager_operator_transfer(ctx: &mut ItsTestContext) {
@@ -340,6 +944,7 @@ async fn test_successful_token_manager_operator_proposal_acceptance(ctx: &mut It
             ctx.solana_chain.fixture.payer.pubkey(),
             token_id,
             bob.pubkey(),
+            None,
         )
         .unwrap();
 
@@ -497,6 +1102,7 @@ async fn test_successful_token_manager_minter_proposal_acceptance(ctx: &mut ItsT
             ctx.solana_chain.fixture.payer.pubkey(),
             token_id,
             bob.pubkey(),
+            None,
         )
         .unwrap();
 
@@ -551,6 +1157,249 @@ async fn test_successful_token_manager_minter_proposal_acceptance(ctx: &mut ItsT
     assert!(bob_roles.contains(Roles::MINTER));
 }
 
+#[test_context(ItsTestContext)]
+#[tokio::test]
+async fn test_proposer_can_cancel_operatorship_proposal(ctx: &mut ItsTestContext) {
+    let (its_root_pda, _) = axelar_solana_its::find_its_root_pda();
+    let bob = Keypair::new();
+    let payer_keys = ctx.solana_chain.fixture.payer.insecure_clone();
+    let (proposal_pda, _) = role_management::find_roles_proposal_pda(
+        &axelar_solana_its::id(),
+        &its_root_pda,
+        &ctx.solana_wallet,
+        &bob.pubkey(),
+    );
+
+    let proposal_ix =
+        axelar_solana_its::instruction::propose_operatorship(ctx.solana_wallet, bob.pubkey(), None)
+            .unwrap();
+
+    ctx.send_solana_tx(&[proposal_ix]).await.unwrap();
+
+    // Bob can't cancel a proposal that hasn't expired.
+    let cancel_ix = axelar_solana_its::instruction::cancel_operatorship_proposal(
+        bob.pubkey(),
+        ctx.solana_wallet,
+        bob.pubkey(),
+    )
+    .unwrap();
+
+    assert!(ctx
+        .solana_chain
+        .send_tx_with_custom_signers(
+            &[cancel_ix],
+            &[bob.insecure_clone(), payer_keys.insecure_clone()],
+        )
+        .await
+        .unwrap_err()
+        .find_log("Only the proposer can cancel a proposal before it expires")
+        .is_some());
+
+    let cancel_ix = axelar_solana_its::instruction::cancel_operatorship_proposal(
+        ctx.solana_wallet,
+        ctx.solana_wallet,
+        bob.pubkey(),
+    )
+    .unwrap();
+
+    ctx.send_solana_tx(&[cancel_ix]).await.unwrap();
+
+    assert!(ctx
+        .solana_chain
+        .fixture
+        .try_get_account_no_checks(&proposal_pda)
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[test_context(ItsTestContext)]
+#[tokio::test]
+async fn test_multisig_proposer_can_cancel_operatorship_proposal(ctx: &mut ItsTestContext) {
+    let token_id = ctx.deployed_interchain_token;
+    let (its_root_pda, _) = axelar_solana_its::find_its_root_pda();
+    let (token_manager_pda, _) =
+        axelar_solana_its::find_token_manager_pda(&its_root_pda, &token_id);
+    let payer_keys = ctx.solana_chain.fixture.payer.insecure_clone();
+    let alice = Keypair::new();
+    let bob = Keypair::new();
+    let dave = Keypair::new();
+    let members = vec![alice.pubkey(), bob.pubkey()];
+    let (multisig, _) = role_management::find_multisig_pda(&axelar_solana_its::id(), &members, 2);
+    let (proposal_pda, _) = role_management::find_roles_proposal_pda(
+        &axelar_solana_its::id(),
+        &token_manager_pda,
+        &multisig,
+        &dave.pubkey(),
+    );
+
+    let create_multisig_ix =
+        axelar_solana_its::instruction::create_multisig(payer_keys.pubkey(), members, 2).unwrap();
+    let transfer_operatorship_ix =
+        axelar_solana_its::instruction::token_manager::transfer_operatorship(
+            payer_keys.pubkey(),
+            token_id,
+            multisig,
+        )
+        .unwrap();
+
+    ctx.send_solana_tx(&[create_multisig_ix, transfer_operatorship_ix])
+        .await
+        .unwrap();
+
+    let proposal_ix = axelar_solana_its::instruction::token_manager::propose_operatorship(
+        multisig,
+        token_id,
+        dave.pubkey(),
+        None,
+    )
+    .unwrap();
+
+    ctx.solana_chain
+        .send_tx_with_custom_signers(
+            &[axelar_solana_its::instruction::with_multisig_authority(
+                proposal_ix,
+                multisig,
+                payer_keys.pubkey(),
+                &[alice.pubkey(), bob.pubkey()],
+            )],
+            &[
+                alice.insecure_clone(),
+                bob.insecure_clone(),
+                payer_keys.insecure_clone(),
+            ],
+        )
+        .await
+        .unwrap();
+
+    let cancel_ix = axelar_solana_its::instruction::token_manager::cancel_operatorship_proposal(
+        payer_keys.pubkey(),
+        token_id,
+        multisig,
+        dave.pubkey(),
+    )
+    .unwrap();
+
+    // A single member isn't enough to cancel a proposal that hasn't expired.
+    assert!(ctx
+        .solana_chain
+        .send_tx_with_custom_signers(
+            &[axelar_solana_its::instruction::with_multisig_signers(
+                cancel_ix.clone(),
+                multisig,
+                &[alice.pubkey()],
+            )],
+            &[alice.insecure_clone(), payer_keys.insecure_clone()],
+        )
+        .await
+        .unwrap_err()
+        .find_log("Only the proposer can cancel a proposal before it expires")
+        .is_some());
+
+    ctx.solana_chain
+        .send_tx_with_custom_signers(
+            &[axelar_solana_its::instruction::with_multisig_signers(
+                cancel_ix,
+                multisig,
+                &[alice.pubkey(), bob.pubkey()],
+            )],
+            &[
+                alice.insecure_clone(),
+                bob.insecure_clone(),
+                payer_keys.insecure_clone(),
+            ],
+        )
+        .await
+        .unwrap();
+
+    assert!(ctx
+        .solana_chain
+        .fixture
+        .try_get_account_no_checks(&proposal_pda)
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[test_context(ItsTestContext)]
+#[tokio::test]
+async fn test_expired_mintership_proposal(ctx: &mut ItsTestContext) {
+    let bob = Keypair::new();
+    let token_id = ctx.deployed_interchain_token;
+    let (its_root_pda, _) = axelar_solana_its::find_its_root_pda();
+    let (token_manager_pda, _) =
+        axelar_solana_its::find_token_manager_pda(&its_root_pda, &token_id);
+    let (proposal_pda, _) = role_management::find_roles_proposal_pda(
+        &axelar_solana_its::id(),
+        &token_manager_pda,
+        &ctx.solana_chain.fixture.payer.pubkey(),
+        &bob.pubkey(),
+    );
+    let payer_keys = ctx.solana_chain.fixture.payer.insecure_clone();
+    let clock = ctx.solana_chain.get_sysvar::<Clock>().await;
+
+    let propose_mintership_ix =
+        axelar_solana_its::instruction::interchain_token::propose_mintership(
+            payer_keys.pubkey(),
+            token_id,
+            bob.pubkey(),
+            Some(clock.unix_timestamp + 60),
+        )
+        .unwrap();
+
+    ctx.send_solana_tx(&[
+        system_instruction::transfer(&payer_keys.pubkey(), &bob.pubkey(), u32::MAX.into()),
+        propose_mintership_ix,
+    ])
+    .await
+    .unwrap();
+
+    ctx.solana_chain.fixture.forward_time(60).await;
+
+    let accept_mintership_ix = axelar_solana_its::instruction::interchain_token::accept_mintership(
+        bob.pubkey(),
+        token_id,
+        payer_keys.pubkey(),
+    )
+    .unwrap();
+
+    assert!(ctx
+        .solana_chain
+        .send_tx_with_custom_signers(
+            &[accept_mintership_ix],
+            &[bob.insecure_clone(), payer_keys.insecure_clone()],
+        )
+        .await
+        .unwrap_err()
+        .find_log("Role proposal has expired")
+        .is_some());
+
+    // Once expired, anyone can cancel the proposal, returning the rent to the proposer.
+    let cancel_ix = axelar_solana_its::instruction::interchain_token::cancel_mintership_proposal(
+        bob.pubkey(),
+        token_id,
+        payer_keys.pubkey(),
+        bob.pubkey(),
+    )
+    .unwrap();
+
+    ctx.solana_chain
+        .send_tx_with_custom_signers(
+            &[cancel_ix],
+            &[bob.insecure_clone(), payer_keys.insecure_clone()],
+        )
+        .await
+        .unwrap();
+
+    assert!(ctx
+        .solana_chain
+        .fixture
+        .try_get_account_no_checks(&proposal_pda)
+        .await
+        .unwrap()
+        .is_none());
+}
+
 #[test_context(ItsTestContext)]
 #[tokio::test]
 async fn test_fail_token_manager_minter_proposal_acceptance(ctx: &mut ItsTestContext) {
@@ -660,6 +1509,7 @@ async fn test_fail_mint_without_minter_role(ctx: &mut ItsTestContext) {
         .unwrap();
 
     let mint_ix = axelar_solana_its::instruction::interchain_token::mint(
+        ctx.solana_chain.fixture.payer.pubkey(),
         token_id,
         token_address,
         ata,
@@ -693,6 +1543,8 @@ async fn test_set_trusted_chain_with_upgrade_authority(ctx: &mut ItsTestContext)
     let set_trusted_chain_ix = axelar_solana_its::instruction::set_trusted_chain(
         ctx.solana_chain.upgrade_authority.pubkey(),
         chain_name.clone(),
+        String::new(),
+        DecimalsPolicy::Preserve,
     )
     .unwrap();
 
@@ -709,17 +1561,7 @@ async fn test_set_trusted_chain_with_upgrade_authority(ctx: &mut ItsTestContext)
         .unwrap();
 
     // Verify the chain was added as trusted
-    let (its_root_pda, _) = axelar_solana_its::find_its_root_pda();
-    let data = ctx
-        .solana_chain
-        .fixture
-        .get_account(&its_root_pda, &axelar_solana_its::id())
-        .await
-        .data;
-
-    let its_root = axelar_solana_its::state::InterchainTokenService::try_from_slice(&data).unwrap();
-
-    assert!(its_root.trusted_chains.contains(&chain_name));
+    assert_eq!(is_trusted_chain(ctx, &chain_name).await, true);
 }
 
 #[test_context(ItsTestContext)]
@@ -747,9 +1589,13 @@ async fn test_set_trusted_chain_with_operator_role(ctx: &mut ItsTestContext) {
         .unwrap();
 
     // Bob sets trusted chain using operator role
-    let set_trusted_chain_ix =
-        axelar_solana_its::instruction::set_trusted_chain(bob.pubkey(), chain_name.clone())
-            .unwrap();
+    let set_trusted_chain_ix = axelar_solana_its::instruction::set_trusted_chain(
+        bob.pubkey(),
+        chain_name.clone(),
+        String::new(),
+        DecimalsPolicy::Preserve,
+    )
+    .unwrap();
 
     ctx.solana_chain
         .fixture
@@ -764,17 +1610,7 @@ async fn test_set_trusted_chain_with_operator_role(ctx: &mut ItsTestContext) {
         .unwrap();
 
     // Verify the chain was added as trusted
-    let (its_root_pda, _) = axelar_solana_its::find_its_root_pda();
-    let data = ctx
-        .solana_chain
-        .fixture
-        .get_account(&its_root_pda, &axelar_solana_its::id())
-        .await
-        .data;
-
-    let its_root = axelar_solana_its::state::InterchainTokenService::try_from_slice(&data).unwrap();
-
-    assert!(its_root.trusted_chains.contains(&chain_name));
+    assert_eq!(is_trusted_chain(ctx, &chain_name).await, true);
 }
 
 #[test_context(ItsTestContext)]
@@ -793,9 +1629,13 @@ async fn test_set_trusted_chain_failure_without_authority(ctx: &mut ItsTestConte
     .unwrap();
 
     // Charlie has neither upgrade authority nor operator role
-    let set_trusted_chain_ix =
-        axelar_solana_its::instruction::set_trusted_chain(charlie.pubkey(), chain_name.clone())
-            .unwrap();
+    let set_trusted_chain_ix = axelar_solana_its::instruction::set_trusted_chain(
+        charlie.pubkey(),
+        chain_name.clone(),
+        String::new(),
+        DecimalsPolicy::Preserve,
+    )
+    .unwrap();
 
     let tx_metadata = ctx
         .solana_chain
@@ -816,17 +1656,7 @@ async fn test_set_trusted_chain_failure_without_authority(ctx: &mut ItsTestConte
         .is_some());
 
     // Verify the chain was NOT added as trusted
-    let (its_root_pda, _) = axelar_solana_its::find_its_root_pda();
-    let data = ctx
-        .solana_chain
-        .fixture
-        .get_account(&its_root_pda, &axelar_solana_its::id())
-        .await
-        .data;
-
-    let its_root = axelar_solana_its::state::InterchainTokenService::try_from_slice(&data).unwrap();
-
-    assert!(!its_root.trusted_chains.contains(&chain_name));
+    assert_eq!(is_trusted_chain(ctx, &chain_name).await, false);
 }
 
 #[test_context(ItsTestContext)]
@@ -847,6 +1677,8 @@ async fn test_remove_trusted_chain_with_upgrade_authority(ctx: &mut ItsTestConte
     let set_trusted_chain_ix = axelar_solana_its::instruction::set_trusted_chain(
         ctx.solana_chain.upgrade_authority.pubkey(),
         chain_name.clone(),
+        String::new(),
+        DecimalsPolicy::Preserve,
     )
     .unwrap();
 
@@ -863,16 +1695,7 @@ async fn test_remove_trusted_chain_with_upgrade_authority(ctx: &mut ItsTestConte
         .unwrap();
 
     // Verify the chain was added
-    let (its_root_pda, _) = axelar_solana_its::find_its_root_pda();
-    let data = ctx
-        .solana_chain
-        .fixture
-        .get_account(&its_root_pda, &axelar_solana_its::id())
-        .await
-        .data;
-
-    let its_root = axelar_solana_its::state::InterchainTokenService::try_from_slice(&data).unwrap();
-    assert!(its_root.trusted_chains.contains(&chain_name));
+    assert_eq!(is_trusted_chain(ctx, &chain_name).await, true);
 
     // Now remove the chain using upgrade authority
     let remove_trusted_chain_ix = axelar_solana_its::instruction::remove_trusted_chain(
@@ -894,16 +1717,7 @@ async fn test_remove_trusted_chain_with_upgrade_authority(ctx: &mut ItsTestConte
         .unwrap();
 
     // Verify the chain was removed
-    let data = ctx
-        .solana_chain
-        .fixture
-        .get_account(&its_root_pda, &axelar_solana_its::id())
-        .await
-        .data;
-
-    let its_root = axelar_solana_its::state::InterchainTokenService::try_from_slice(&data).unwrap();
-
-    assert!(!its_root.trusted_chains.contains(&chain_name));
+    assert_eq!(is_trusted_chain(ctx, &chain_name).await, false);
 }
 
 #[test_context(ItsTestContext)]
@@ -932,6 +1746,8 @@ async fn test_remove_trusted_chain_with_operator_role(ctx: &mut ItsTestContext)
     let set_trusted_chain_ix = axelar_solana_its::instruction::set_trusted_chain(
         ctx.solana_chain.upgrade_authority.pubkey(),
         chain_name.clone(),
+        String::new(),
+        DecimalsPolicy::Preserve,
     )
     .unwrap();
 
@@ -974,17 +1790,7 @@ async fn test_remove_trusted_chain_with_operator_role(ctx: &mut ItsTestContext)
         .unwrap();
 
     // Verify the chain was removed
-    let (its_root_pda, _) = axelar_solana_its::find_its_root_pda();
-    let data = ctx
-        .solana_chain
-        .fixture
-        .get_account(&its_root_pda, &axelar_solana_its::id())
-        .await
-        .data;
-
-    let its_root = axelar_solana_its::state::InterchainTokenService::try_from_slice(&data).unwrap();
-
-    assert!(!its_root.trusted_chains.contains(&chain_name));
+    assert_eq!(is_trusted_chain(ctx, &chain_name).await, false);
 }
 
 #[test_context(ItsTestContext)]
@@ -1013,6 +1819,8 @@ async fn test_remove_trusted_chain_failure_without_authority(ctx: &mut ItsTestCo
     let set_trusted_chain_ix = axelar_solana_its::instruction::set_trusted_chain(
         ctx.solana_chain.upgrade_authority.pubkey(),
         chain_name.clone(),
+        String::new(),
+        DecimalsPolicy::Preserve,
     )
     .unwrap();
 
@@ -1052,17 +1860,7 @@ async fn test_remove_trusted_chain_failure_without_authority(ctx: &mut ItsTestCo
         .is_some());
 
     // Verify the chain was NOT removed
-    let (its_root_pda, _) = axelar_solana_its::find_its_root_pda();
-    let data = ctx
-        .solana_chain
-        .fixture
-        .get_account(&its_root_pda, &axelar_solana_its::id())
-        .await
-        .data;
-
-    let its_root = axelar_solana_its::state::InterchainTokenService::try_from_slice(&data).unwrap();
-
-    assert!(its_root.trusted_chains.contains(&chain_name));
+    assert_eq!(is_trusted_chain(ctx, &chain_name).await, true);
 }
 
 #[test_context(ItsTestContext)]
@@ -1246,3 +2044,24 @@ async fn test_prevent_privilege_escalation_through_different_token(ctx: &mut Its
     let bob_roles_token_a = UserRoles::<Roles>::try_from_slice(&data).unwrap();
     assert!(!bob_roles_token_a.contains(Roles::MINTER));
 }
+
+async fn is_trusted_chain(ctx: &mut ItsTestContext, chain_name: &str) -> bool {
+    let (its_root_pda, _) = axelar_solana_its::find_its_root_pda();
+    let (trusted_chain_pda, _) =
+        axelar_solana_its::find_trusted_chain_pda(&its_root_pda, chain_name);
+
+    let Some(account) = ctx
+        .solana_chain
+        .fixture
+        .try_get_account_no_checks(&trusted_chain_pda)
+        .await
+        .unwrap()
+    else {
+        return false;
+    };
+
+    let trusted_chain = TrustedChain::try_from_slice(&account.data).unwrap();
+    assert_eq!(trusted_chain.chain_name, chain_name);
+
+    true
+}
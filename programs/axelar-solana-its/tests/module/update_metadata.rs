@@ -0,0 +1,338 @@
+use axelar_solana_gateway_test_fixtures::base::FindLog;
+use axelar_solana_its::state::token_manager::TokenManager;
+use axelar_solana_its::state::MetadataStandard;
+use borsh::BorshDeserialize;
+use event_utils::Event as _;
+use interchain_token_transfer_gmp::{
+    DeployInterchainToken, GMPPayload, RegisterTokenMetadataUpdate, SendToHub,
+};
+use mpl_token_metadata::accounts::Metadata;
+use solana_program_test::{tokio, BanksTransactionResultWithMetadata};
+use solana_sdk::signature::Keypair;
+use solana_sdk::signer::Signer as _;
+use spl_token_2022::extension::{BaseStateWithExtensions, StateWithExtensions};
+use spl_token_2022::state::Mint;
+use spl_token_metadata_interface::state::TokenMetadata;
+use test_context::test_context;
+
+use crate::{fetch_first_call_contract_event_from_tx, ItsTestContext};
+
+async fn fetch_metadata(ctx: &mut ItsTestContext, token_id: &[u8; 32]) -> Metadata {
+    let (its_root_pda, _) = axelar_solana_its::find_its_root_pda();
+    let (mint, _) = axelar_solana_its::find_interchain_token_pda(&its_root_pda, token_id);
+    let (metadata_account, _) = Metadata::find_pda(&mint);
+    let data = ctx
+        .solana_chain
+        .fixture
+        .get_account(&metadata_account, &mpl_token_metadata::ID)
+        .await
+        .data;
+
+    Metadata::from_bytes(&data).unwrap()
+}
+
+async fn relay_from_evm(
+    ctx: &mut ItsTestContext,
+    payload: GMPPayload,
+) -> BanksTransactionResultWithMetadata {
+    let payload = GMPPayload::SendToHub(SendToHub {
+        selector: SendToHub::MESSAGE_TYPE_ID.try_into().unwrap(),
+        destination_chain: ctx.solana_chain_name.clone(),
+        payload: payload.encode().into(),
+    });
+
+    ctx.relay_to_solana(&payload.encode(), None, spl_token_2022::id())
+        .await
+}
+
+fn metadata_update(token_id: [u8; 32]) -> GMPPayload {
+    GMPPayload::RegisterTokenMetadataUpdate(RegisterTokenMetadataUpdate {
+        selector: RegisterTokenMetadataUpdate::MESSAGE_TYPE_ID
+            .try_into()
+            .unwrap(),
+        token_address: vec![1; 20].into(),
+        decimals: 18,
+        token_id: token_id.into(),
+        name: "Remote Name".to_owned(),
+        symbol: "RN".to_owned(),
+    })
+}
+
+async fn deploy_token_from_evm(ctx: &mut ItsTestContext, token_id: [u8; 32]) {
+    let payload = GMPPayload::DeployInterchainToken(DeployInterchainToken {
+        selector: DeployInterchainToken::MESSAGE_TYPE_ID.try_into().unwrap(),
+        token_id: token_id.into(),
+        name: "Remote Token".to_owned(),
+        symbol: "RT".to_owned(),
+        decimals: 9,
+        minter: ctx.solana_wallet.to_bytes().to_vec().into(),
+    });
+
+    let tx = relay_from_evm(ctx, payload).await;
+    assert!(tx.result.is_ok());
+}
+
+#[test_context(ItsTestContext)]
+#[tokio::test]
+async fn test_update_interchain_token_metadata_locally(ctx: &mut ItsTestContext) {
+    let token_id = ctx.deployed_interchain_token;
+    let update_ix = axelar_solana_its::instruction::update_interchain_token_metadata(
+        ctx.solana_wallet,
+        token_id,
+        "Updated Token".to_owned(),
+        "UT".to_owned(),
+        "https://example.com/ut.json".to_owned(),
+        None,
+        0,
+        axelar_solana_gas_service::id(),
+        ctx.solana_gas_utils.config_pda,
+    )
+    .unwrap();
+
+    let tx = ctx.send_solana_tx(&[update_ix]).await.unwrap();
+    let update_event = tx
+        .metadata
+        .unwrap()
+        .log_messages
+        .iter()
+        .find_map(|log| {
+            axelar_solana_its::event::InterchainTokenMetadataUpdated::try_from_log(log).ok()
+        })
+        .unwrap();
+
+    assert_eq!(update_event.token_id, token_id);
+    assert_eq!(update_event.name, "Updated Token");
+    assert_eq!(update_event.symbol, "UT");
+    assert_eq!(update_event.uri, "https://example.com/ut.json");
+
+    let metadata = fetch_metadata(ctx, &token_id).await;
+    assert_eq!(metadata.name.trim_end_matches('\0'), "Updated Token");
+    assert_eq!(metadata.symbol.trim_end_matches('\0'), "UT");
+    assert_eq!(
+        metadata.uri.trim_end_matches('\0'),
+        "https://example.com/ut.json"
+    );
+}
+
+#[test_context(ItsTestContext)]
+#[tokio::test]
+async fn test_update_interchain_token_metadata_sends_gmp_message(ctx: &mut ItsTestContext) {
+    let token_id = ctx.deployed_interchain_token;
+    let update_ix = axelar_solana_its::instruction::update_interchain_token_metadata(
+        ctx.solana_wallet,
+        token_id,
+        "Updated Token".to_owned(),
+        "UT".to_owned(),
+        String::new(),
+        Some(ctx.evm_chain_name.clone()),
+        0,
+        axelar_solana_gas_service::id(),
+        ctx.solana_gas_utils.config_pda,
+    )
+    .unwrap();
+
+    let tx = ctx.send_solana_tx(&[update_ix]).await.unwrap();
+    let call_contract_event = fetch_first_call_contract_event_from_tx(&tx);
+
+    let GMPPayload::SendToHub(send_to_hub) =
+        GMPPayload::decode(&call_contract_event.payload).unwrap()
+    else {
+        panic!("unexpected payload");
+    };
+    assert_eq!(send_to_hub.destination_chain, ctx.evm_chain_name);
+
+    let GMPPayload::RegisterTokenMetadataUpdate(update) =
+        GMPPayload::decode(&send_to_hub.payload).unwrap()
+    else {
+        panic!("unexpected payload");
+    };
+    let (its_root_pda, _) = axelar_solana_its::find_its_root_pda();
+    let (mint, _) = axelar_solana_its::find_interchain_token_pda(&its_root_pda, &token_id);
+    assert_eq!(update.token_address.as_ref(), mint.as_ref());
+    assert_eq!(update.token_id.0, token_id);
+    assert_eq!(update.name, "Updated Token");
+    assert_eq!(update.symbol, "UT");
+}
+
+#[test_context(ItsTestContext)]
+#[tokio::test]
+async fn test_update_interchain_token_metadata_fails_without_operator_role(
+    ctx: &mut ItsTestContext,
+) {
+    let token_id = ctx.deployed_interchain_token;
+    let not_operator = Keypair::new();
+    let update_ix = axelar_solana_its::instruction::update_interchain_token_metadata(
+        not_operator.pubkey(),
+        token_id,
+        "Updated Token".to_owned(),
+        "UT".to_owned(),
+        String::new(),
+        None,
+        0,
+        axelar_solana_gas_service::id(),
+        ctx.solana_gas_utils.config_pda,
+    )
+    .unwrap();
+
+    let result = ctx
+        .solana_chain
+        .fixture
+        .send_tx_with_custom_signers(
+            &[update_ix],
+            &[
+                &not_operator,
+                &ctx.solana_chain.fixture.payer.insecure_clone(),
+            ],
+        )
+        .await;
+
+    assert!(result.is_err());
+
+    let metadata = fetch_metadata(ctx, &token_id).await;
+    assert_eq!(metadata.name.trim_end_matches('\0'), "Test Token");
+}
+
+#[test_context(ItsTestContext)]
+#[tokio::test]
+async fn test_inbound_interchain_token_metadata_update(ctx: &mut ItsTestContext) {
+    let token_id = [7; 32];
+    deploy_token_from_evm(ctx, token_id).await;
+    let uri_before = fetch_metadata(ctx, &token_id).await.uri;
+
+    let tx = relay_from_evm(ctx, metadata_update(token_id)).await;
+    assert!(tx.result.is_ok());
+
+    let metadata = fetch_metadata(ctx, &token_id).await;
+    assert_eq!(metadata.name.trim_end_matches('\0'), "Remote Name");
+    assert_eq!(metadata.symbol.trim_end_matches('\0'), "RN");
+    assert_eq!(metadata.uri, uri_before);
+}
+
+#[test_context(ItsTestContext)]
+#[tokio::test]
+async fn test_inbound_metadata_update_from_other_chain_is_rejected(ctx: &mut ItsTestContext) {
+    let token_id = [8; 32];
+    deploy_token_from_evm(ctx, token_id).await;
+
+    // Pretend the token was deployed from a chain other than the one the
+    // update comes from.
+    let (its_root_pda, _) = axelar_solana_its::find_its_root_pda();
+    let (token_manager_pda, _) =
+        axelar_solana_its::find_token_manager_pda(&its_root_pda, &token_id);
+    let mut token_manager_account = ctx
+        .solana_chain
+        .fixture
+        .get_account(&token_manager_pda, &axelar_solana_its::id())
+        .await;
+    let mut token_manager = TokenManager::try_from_slice(&token_manager_account.data).unwrap();
+    assert_eq!(
+        token_manager.origin_chain.as_deref(),
+        Some(ctx.evm_chain_name.as_str())
+    );
+    token_manager.origin_chain = Some("other-chain".to_owned());
+    token_manager_account.data = borsh::to_vec(&token_manager).unwrap();
+    ctx.solana_chain
+        .fixture
+        .set_account_state(&token_manager_pda, token_manager_account);
+
+    let tx = relay_from_evm(ctx, metadata_update(token_id)).await;
+    assert!(tx
+        .find_log("Metadata updates are only accepted from the chain the token was deployed from")
+        .is_some());
+
+    let metadata = fetch_metadata(ctx, &token_id).await;
+    assert_eq!(metadata.name.trim_end_matches('\0'), "Remote Token");
+}
+
+#[test_context(ItsTestContext)]
+#[tokio::test]
+async fn test_inbound_metadata_update_is_rejected_for_tokens_deployed_on_solana(
+    ctx: &mut ItsTestContext,
+) {
+    let token_id = ctx.deployed_interchain_token;
+
+    let tx = relay_from_evm(ctx, metadata_update(token_id)).await;
+    assert!(tx
+        .find_log("Metadata updates are only accepted from the chain the token was deployed from")
+        .is_some());
+
+    let metadata = fetch_metadata(ctx, &token_id).await;
+    assert_eq!(metadata.name.trim_end_matches('\0'), "Test Token");
+}
+
+#[test_context(ItsTestContext)]
+#[tokio::test]
+async fn test_metadata_update_of_remote_token_is_not_propagated(ctx: &mut ItsTestContext) {
+    let token_id = [9; 32];
+    deploy_token_from_evm(ctx, token_id).await;
+
+    let update_ix = axelar_solana_its::instruction::update_interchain_token_metadata(
+        ctx.solana_wallet,
+        token_id,
+        "Updated Token".to_owned(),
+        "UT".to_owned(),
+        String::new(),
+        Some(ctx.evm_chain_name.clone()),
+        0,
+        axelar_solana_gas_service::id(),
+        ctx.solana_gas_utils.config_pda,
+    )
+    .unwrap();
+
+    let result = ctx.send_solana_tx(&[update_ix]).await;
+    assert!(result
+        .unwrap_err()
+        .find_log("Only the metadata of tokens deployed on Solana can be propagated")
+        .is_some());
+}
+
+#[test_context(ItsTestContext)]
+#[tokio::test]
+async fn test_update_token_2022_metadata_locally(ctx: &mut ItsTestContext) {
+    let (its_root_pda, _) = axelar_solana_its::find_its_root_pda();
+    let salt = solana_sdk::keccak::hash(b"Token2022MetadataUpdate").0;
+    let token_id = axelar_solana_its::interchain_token_id(&ctx.solana_wallet, &salt);
+    let (mint, _) = axelar_solana_its::find_interchain_token_pda(&its_root_pda, &token_id);
+
+    let deploy_ix = axelar_solana_its::instruction::deploy_interchain_token_with_metadata_standard(
+        ctx.solana_wallet,
+        salt,
+        "Short".to_owned(),
+        "S".to_owned(),
+        9,
+        0,
+        Some(ctx.solana_wallet),
+        MetadataStandard::Token2022,
+    )
+    .unwrap();
+    ctx.send_solana_tx(&[deploy_ix]).await.unwrap();
+
+    let update_ix = axelar_solana_its::instruction::update_interchain_token_metadata(
+        ctx.solana_wallet,
+        token_id,
+        "A considerably longer token name".to_owned(),
+        "LONGER".to_owned(),
+        "https://example.com/longer.json".to_owned(),
+        None,
+        0,
+        axelar_solana_gas_service::id(),
+        ctx.solana_gas_utils.config_pda,
+    )
+    .unwrap();
+    ctx.send_solana_tx(&[update_ix]).await.unwrap();
+
+    let mint_data = ctx
+        .solana_chain
+        .fixture
+        .get_account(&mint, &spl_token_2022::ID)
+        .await
+        .data;
+    let token_metadata = StateWithExtensions::<Mint>::unpack(&mint_data)
+        .unwrap()
+        .get_variable_len_extension::<TokenMetadata>()
+        .unwrap();
+
+    assert_eq!(token_metadata.name, "A considerably longer token name");
+    assert_eq!(token_metadata.symbol, "LONGER");
+    assert_eq!(token_metadata.uri, "https://example.com/longer.json");
+}